@@ -0,0 +1,122 @@
+#ifndef AERUGO_H
+#define AERUGO_H
+
+/* This file is generated with cbindgen. Do not modify it manually. */
+
+#include <stdint.h>
+
+/**
+ * Major version of the C API. Changed on every ABI-breaking change.
+ */
+#define AERUGO_CAPI_VERSION_MAJOR 1
+
+/**
+ * Minor version of the C API. Changed when new functions or status codes are added.
+ */
+#define AERUGO_CAPI_VERSION_MINOR 0
+
+/**
+ * Status of the C API call.
+ */
+typedef enum AerugoStatus {
+  /**
+   * Call was successful.
+   */
+  AERUGO_STATUS_OK = 0,
+  /**
+   * One of the parameters was invalid (ex. null pointer).
+   */
+  AERUGO_STATUS_INVALID_PARAMETER = 1,
+  /**
+   * System was not initialized yet.
+   */
+  AERUGO_STATUS_NOT_INITIALIZED = 2,
+  /**
+   * System was already initialized.
+   */
+  AERUGO_STATUS_ALREADY_INITIALIZED = 3,
+  /**
+   * Event with given ID doesn't exist.
+   */
+  AERUGO_STATUS_EVENT_NOT_FOUND = 4,
+  /**
+   * There is no free storage for a new element.
+   */
+  AERUGO_STATUS_STORAGE_FULL = 5,
+} AerugoStatus;
+
+/**
+ * Version of the C API.
+ */
+typedef struct AerugoVersion {
+  /**
+   * Major version.
+   */
+  uint16_t major;
+  /**
+   * Minor version.
+   */
+  uint16_t minor;
+} AerugoVersion;
+
+/**
+ * Opaque runtime API passed to the C tasklet step function.
+ */
+typedef struct AerugoRuntime AerugoRuntime;
+
+/**
+ * Opaque handle to the C tasklet.
+ */
+typedef struct AerugoTasklet AerugoTasklet;
+
+/**
+ * Step function of the C tasklet.
+ *
+ * # Parameters
+ * * `context` - User context, given when tasklet was created.
+ * * `runtime` - Runtime API, valid only for the duration of the call.
+ */
+typedef void (*AerugoStepFn)(void *context, const struct AerugoRuntime *runtime);
+
+/**
+ * Emits event.
+ */
+enum AerugoStatus aerugo_emit_event(const struct AerugoRuntime *runtime, uint32_t event_id);
+
+/**
+ * Returns current system time in microseconds.
+ */
+uint64_t aerugo_get_system_time_us(const struct AerugoRuntime *runtime);
+
+/**
+ * Initializes the system with default hardware configuration.
+ */
+enum AerugoStatus aerugo_initialize(void);
+
+/**
+ * Starts the system.
+ */
+enum AerugoStatus aerugo_start(void);
+
+/**
+ * Returns version of the C API that the library was built with.
+ */
+struct AerugoVersion aerugo_capi_version(void);
+
+/**
+ * Creates new C tasklet.
+ */
+enum AerugoStatus aerugo_create_tasklet(const char *name,
+                                        uint8_t priority,
+                                        AerugoStepFn step_fn,
+                                        void *context,
+                                        const struct AerugoTasklet **tasklet);
+
+/**
+ * Subscribes C tasklet to the cyclic execution.
+ */
+enum AerugoStatus aerugo_subscribe_tasklet_to_cyclic(const struct AerugoTasklet *tasklet,
+                                                     uint64_t period_us,
+                                                     uint64_t offset_us);
+
+#endif /* AERUGO_H */
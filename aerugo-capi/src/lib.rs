@@ -0,0 +1,45 @@
+/*!
+C API for `aerugo`.
+
+This crate exposes a C-compatible layer over the system API, so the scheduler can be used from
+C code. Tasklets created with this API have C callbacks as their step functions, and are referenced
+from C with opaque handles.
+
+C declarations of this API are available in `include/aerugo.h`.
+
+# Limitations
+* C tasklets process no data, so they can be only subscribed to the cyclic execution.
+* Storage for C tasklets is allocated statically. Maximum number of C tasklets is configured with
+  `AERUGO_CAPI_TASKLET_COUNT` environmental variable.
+* This crate doesn't provide a panic handler. It has to be linked into the final binary by a Rust
+  crate that does.
+
+# ABI stability
+C API is versioned with [major](AERUGO_CAPI_VERSION_MAJOR) and [minor](AERUGO_CAPI_VERSION_MINOR)
+version, available at runtime via [aerugo_capi_version]. Within the same major version:
+* Signatures of existing functions are not changed.
+* Layout of structures shared with C code is not changed.
+* Values of existing [status codes](AerugoStatus) are not changed. New codes can be added with a minor
+  version change, so C code should handle unknown status values.
+* Handles stay opaque, and their content is not a part of the ABI.
+*/
+#![no_std]
+#![warn(missing_docs)]
+#![warn(clippy::missing_docs_in_private_items)]
+#![warn(rustdoc::missing_crate_level_docs)]
+
+mod runtime;
+mod status;
+mod system;
+mod tasklet;
+mod version;
+
+pub use self::runtime::{aerugo_emit_event, aerugo_get_system_time_us, AerugoRuntime};
+pub use self::status::AerugoStatus;
+pub use self::system::{aerugo_initialize, aerugo_start};
+pub use self::tasklet::{
+    aerugo_create_tasklet, aerugo_subscribe_tasklet_to_cyclic, AerugoStepFn, AerugoTasklet,
+};
+pub use self::version::{
+    aerugo_capi_version, AerugoVersion, AERUGO_CAPI_VERSION_MAJOR, AERUGO_CAPI_VERSION_MINOR,
+};
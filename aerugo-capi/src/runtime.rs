@@ -0,0 +1,70 @@
+//! Runtime API for C tasklets.
+
+use aerugo::RuntimeApi;
+
+use crate::status::AerugoStatus;
+
+/// Opaque runtime API passed to the C tasklet step function.
+pub struct AerugoRuntime {
+    /// System runtime API.
+    api: &'static dyn RuntimeApi,
+}
+
+impl AerugoRuntime {
+    /// Creates new runtime API wrapper.
+    ///
+    /// # Parameters
+    /// * `api` - System runtime API.
+    pub(crate) fn new(api: &'static dyn RuntimeApi) -> Self {
+        AerugoRuntime { api }
+    }
+}
+
+/// Emits event.
+///
+/// # Parameters
+/// * `runtime` - Runtime API passed to the step function.
+/// * `event_id` - ID of the event to emit.
+///
+/// # Return
+/// [`AerugoStatus::Ok`] if successful, error status otherwise.
+///
+/// # Safety
+/// `runtime` has to be a pointer passed to the currently executed step function.
+#[no_mangle]
+pub unsafe extern "C" fn aerugo_emit_event(
+    runtime: *const AerugoRuntime,
+    event_id: u32,
+) -> AerugoStatus {
+    let runtime = match runtime.as_ref() {
+        Some(runtime) => runtime,
+        None => return AerugoStatus::InvalidParameter,
+    };
+
+    match runtime.api.emit_event(event_id) {
+        Ok(_) => AerugoStatus::Ok,
+        Err(_) => AerugoStatus::EventNotFound,
+    }
+}
+
+/// Returns current system time in microseconds.
+///
+/// # Parameters
+/// * `runtime` - Runtime API passed to the step function.
+///
+/// # Return
+/// System time, or 0 if `runtime` is null.
+///
+/// # Safety
+/// `runtime` has to be a pointer passed to the currently executed step function.
+#[no_mangle]
+pub unsafe extern "C" fn aerugo_get_system_time_us(runtime: *const AerugoRuntime) -> u64 {
+    match runtime.as_ref() {
+        Some(runtime) => runtime
+            .api
+            .get_system_time()
+            .duration_since_epoch()
+            .to_micros(),
+        None => 0,
+    }
+}
@@ -0,0 +1,25 @@
+//! Status codes returned by the C API.
+
+/// Status of the C API call.
+///
+/// Values of the status codes are a part of the ABI, and can't be changed.
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum AerugoStatus {
+    /// Call was successful.
+    Ok = 0,
+    /// One of the parameters was invalid (ex. null pointer).
+    InvalidParameter = 1,
+    /// System was not initialized yet.
+    NotInitialized = 2,
+    /// System was already initialized.
+    AlreadyInitialized = 3,
+    /// Event with given ID doesn't exist.
+    EventNotFound = 4,
+    /// There is no free storage for a new element.
+    StorageFull = 5,
+}
+
+// Status is passed to C code as an `int` sized enum.
+const _: () =
+    assert!(core::mem::size_of::<AerugoStatus>() == core::mem::size_of::<core::ffi::c_int>());
@@ -0,0 +1,153 @@
+//! System initialization and start.
+//!
+//! [Aerugo::initialize] returns system API as an opaque type, so it can't be stored directly.
+//! Instead, this module stores a hand-made virtual table with functions of that API that are used
+//! by the C API, created for the concrete type during initialization.
+
+use aerugo::{Aerugo, Duration, InitApi, Mutex, SystemHardwareConfig, TaskletConfig};
+
+use crate::status::AerugoStatus;
+use crate::tasklet::{tasklet_step, CTaskletContext, CTaskletStorage};
+
+/// Initialization API virtual table.
+pub(crate) struct InitApiVTable {
+    /// Pointer to the system API.
+    api: *const (),
+    /// Pointer to the [create_tasklet](create_tasklet()) function.
+    create_tasklet: fn(*const (), TaskletConfig, CTaskletContext, &'static CTaskletStorage),
+    /// Pointer to the [subscribe_tasklet_to_cyclic](subscribe_tasklet_to_cyclic()) function.
+    subscribe_tasklet_to_cyclic:
+        fn(*const (), &'static CTaskletStorage, Option<Duration>, Option<Duration>),
+    /// Pointer to the [start](start()) function.
+    start: fn(*const ()) -> !,
+}
+
+/// Virtual table is created only once, during initialization, and the stored API pointer is valid
+/// for the whole system lifetime.
+unsafe impl Send for InitApiVTable {}
+
+/// Initialization API of the system, `None` before initialization.
+static INIT_API: Mutex<Option<InitApiVTable>> = Mutex::new(None);
+
+impl InitApiVTable {
+    /// Creates virtual table for given API.
+    ///
+    /// # Generic Parameters
+    /// * `A` - Type of the system API.
+    ///
+    /// # Parameters
+    /// * `api` - System API.
+    fn new<A: InitApi + 'static>(api: &'static A) -> Self {
+        InitApiVTable {
+            api: api as *const A as *const (),
+            create_tasklet: create_tasklet::<A>,
+            subscribe_tasklet_to_cyclic: subscribe_tasklet_to_cyclic::<A>,
+            start: start::<A>,
+        }
+    }
+
+    /// See: [create_tasklet](create_tasklet())
+    pub(crate) fn create_tasklet(
+        &self,
+        config: TaskletConfig,
+        context: CTaskletContext,
+        storage: &'static CTaskletStorage,
+    ) {
+        (self.create_tasklet)(self.api, config, context, storage)
+    }
+
+    /// See: [subscribe_tasklet_to_cyclic](subscribe_tasklet_to_cyclic())
+    pub(crate) fn subscribe_tasklet_to_cyclic(
+        &self,
+        storage: &'static CTaskletStorage,
+        period: Option<Duration>,
+        offset: Option<Duration>,
+    ) {
+        (self.subscribe_tasklet_to_cyclic)(self.api, storage, period, offset)
+    }
+}
+
+/// Executes given closure with the initialization API.
+///
+/// # Parameters
+/// * `f` - Closure to execute.
+///
+/// # Return
+/// Closure result, or [`AerugoStatus::NotInitialized`] if system is not initialized.
+pub(crate) fn with_init_api(f: impl FnOnce(&InitApiVTable) -> AerugoStatus) -> AerugoStatus {
+    INIT_API.lock(|api| match api {
+        Some(api) => f(api),
+        None => AerugoStatus::NotInitialized,
+    })
+}
+
+/// Initializes the system with default hardware configuration.
+///
+/// User peripherals are not available via C API.
+///
+/// # Return
+/// [`AerugoStatus::Ok`] if successful, [`AerugoStatus::AlreadyInitialized`] if called more than
+/// once.
+#[no_mangle]
+pub extern "C" fn aerugo_initialize() -> AerugoStatus {
+    if INIT_API.lock(|api| api.is_some()) {
+        return AerugoStatus::AlreadyInitialized;
+    }
+
+    let (api, _) = Aerugo::initialize(SystemHardwareConfig::default());
+    INIT_API.lock(|stored_api| stored_api.replace(InitApiVTable::new(api)));
+
+    AerugoStatus::Ok
+}
+
+/// Starts the system.
+///
+/// # Return
+/// This function never returns if successful. [`AerugoStatus::NotInitialized`] is returned if the
+/// system was not initialized.
+#[no_mangle]
+pub extern "C" fn aerugo_start() -> AerugoStatus {
+    let start_fn = INIT_API.lock(|api| api.as_ref().map(|api| (api.start, api.api)));
+
+    match start_fn {
+        Some((start, api)) => start(api),
+        None => AerugoStatus::NotInitialized,
+    }
+}
+
+/// "Virtual" call to the [create_tasklet_with_context](InitApi::create_tasklet_with_context)
+/// function.
+fn create_tasklet<A: InitApi + 'static>(
+    api: *const (),
+    config: TaskletConfig,
+    context: CTaskletContext,
+    storage: &'static CTaskletStorage,
+) {
+    // SAFETY: This is safe, because virtual table is created for the type of the stored API.
+    let api = unsafe { &*(api as *const A) };
+    api.create_tasklet_with_context(config, tasklet_step, context, storage)
+}
+
+/// "Virtual" call to the [subscribe_tasklet_to_cyclic](InitApi::subscribe_tasklet_to_cyclic)
+/// function.
+fn subscribe_tasklet_to_cyclic<A: InitApi + 'static>(
+    api: *const (),
+    storage: &'static CTaskletStorage,
+    period: Option<Duration>,
+    offset: Option<Duration>,
+) {
+    // SAFETY: This is safe, because virtual table is created for the type of the stored API.
+    let api = unsafe { &*(api as *const A) };
+    let handle = storage
+        .create_handle()
+        .expect("Tasklet storage not initialized");
+
+    api.subscribe_tasklet_to_cyclic(&handle, period, offset)
+}
+
+/// "Virtual" call to the [start](InitApi::start) function.
+fn start<A: InitApi + 'static>(api: *const ()) -> ! {
+    // SAFETY: This is safe, because virtual table is created for the type of the stored API.
+    let api = unsafe { &*(api as *const A) };
+    api.start()
+}
@@ -0,0 +1,148 @@
+//! C tasklets.
+
+use core::ffi::{c_char, c_void, CStr};
+use core::sync::atomic::{AtomicUsize, Ordering};
+
+use aerugo::{Duration, RuntimeApi, TaskletConfig, TaskletStorage};
+use env_parser::read_env;
+
+use crate::runtime::AerugoRuntime;
+use crate::status::AerugoStatus;
+use crate::system::with_init_api;
+
+/// Step function of the C tasklet.
+///
+/// # Parameters
+/// * `context` - User context, given when tasklet was created.
+/// * `runtime` - Runtime API, valid only for the duration of the call.
+pub type AerugoStepFn = extern "C" fn(context: *mut c_void, runtime: *const AerugoRuntime);
+
+/// Opaque handle to the C tasklet.
+pub struct AerugoTasklet {
+    /// Prevents creation of this structure.
+    _private: [u8; 0],
+}
+
+/// Context of the C tasklet.
+pub(crate) struct CTaskletContext {
+    /// C step function.
+    step_fn: AerugoStepFn,
+    /// User context.
+    context: *mut c_void,
+}
+
+/// Type of the storage for C tasklets.
+pub(crate) type CTaskletStorage = TaskletStorage<(), CTaskletContext, 0>;
+
+/// Maximum number of C tasklets.
+#[read_env("AERUGO_CAPI_TASKLET_COUNT")]
+const TASKLET_COUNT: usize = 0;
+
+/// Initial value of the C tasklet storage.
+#[allow(clippy::declare_interior_mutable_const)]
+const EMPTY_STORAGE: CTaskletStorage = CTaskletStorage::new();
+
+/// Storages for C tasklets.
+static TASKLET_STORAGES: [CTaskletStorage; TASKLET_COUNT] = [EMPTY_STORAGE; TASKLET_COUNT];
+/// Index of the next free storage.
+static NEXT_STORAGE: AtomicUsize = AtomicUsize::new(0);
+
+/// Step function of all C tasklets, calling the C step function.
+pub(crate) fn tasklet_step(_: (), context: &mut CTaskletContext, api: &'static dyn RuntimeApi) {
+    let runtime = AerugoRuntime::new(api);
+    (context.step_fn)(context.context, &runtime);
+}
+
+/// Creates new C tasklet.
+///
+/// # Parameters
+/// * `name` - Tasklet name. Has to be a null-terminated string valid for the whole system lifetime.
+/// * `priority` - Tasklet priority.
+/// * `step_fn` - Tasklet step function.
+/// * `context` - User context passed to the step function.
+/// * `tasklet` - Pointer where the tasklet handle will be written.
+///
+/// # Return
+/// [`AerugoStatus::Ok`] if successful, error status otherwise.
+///
+/// # Safety
+/// `name` and `tasklet` have to be valid pointers. This shouldn't be called after the system was
+/// started.
+#[no_mangle]
+pub unsafe extern "C" fn aerugo_create_tasklet(
+    name: *const c_char,
+    priority: u8,
+    step_fn: Option<AerugoStepFn>,
+    context: *mut c_void,
+    tasklet: *mut *const AerugoTasklet,
+) -> AerugoStatus {
+    if name.is_null() || tasklet.is_null() {
+        return AerugoStatus::InvalidParameter;
+    }
+
+    let step_fn = match step_fn {
+        Some(step_fn) => step_fn,
+        None => return AerugoStatus::InvalidParameter,
+    };
+
+    let name: &'static str = match CStr::from_ptr(name).to_str() {
+        Ok(name) => name,
+        Err(_) => return AerugoStatus::InvalidParameter,
+    };
+
+    with_init_api(|api| {
+        let storage = match TASKLET_STORAGES.get(NEXT_STORAGE.fetch_add(1, Ordering::SeqCst)) {
+            Some(storage) => storage,
+            None => return AerugoStatus::StorageFull,
+        };
+
+        let config = TaskletConfig {
+            name,
+            priority,
+            deadline: None,
+            yield_budget: None,
+            finalize: None,
+        };
+        api.create_tasklet(config, CTaskletContext { step_fn, context }, storage);
+
+        *tasklet = storage as *const CTaskletStorage as *const AerugoTasklet;
+        AerugoStatus::Ok
+    })
+}
+
+/// Subscribes C tasklet to the cyclic execution.
+///
+/// # Parameters
+/// * `tasklet` - Tasklet handle.
+/// * `period_us` - Period of execution in microseconds, 0 if should be woken whenever possible.
+/// * `offset_us` - Offset of the first execution in microseconds.
+///
+/// # Return
+/// [`AerugoStatus::Ok`] if successful, error status otherwise.
+///
+/// # Safety
+/// `tasklet` has to be a handle returned by [aerugo_create_tasklet]. This shouldn't be called after
+/// the system was started.
+#[no_mangle]
+pub unsafe extern "C" fn aerugo_subscribe_tasklet_to_cyclic(
+    tasklet: *const AerugoTasklet,
+    period_us: u64,
+    offset_us: u64,
+) -> AerugoStatus {
+    if tasklet.is_null() {
+        return AerugoStatus::InvalidParameter;
+    }
+
+    let storage = &*(tasklet as *const CTaskletStorage);
+    if !storage.is_initialized() {
+        return AerugoStatus::InvalidParameter;
+    }
+
+    let period = (period_us != 0).then(|| Duration::micros(period_us));
+    let offset = (offset_us != 0).then(|| Duration::micros(offset_us));
+
+    with_init_api(|api| {
+        api.subscribe_tasklet_to_cyclic(storage, period, offset);
+        AerugoStatus::Ok
+    })
+}
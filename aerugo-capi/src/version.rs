@@ -0,0 +1,32 @@
+//! Version of the C API.
+
+/// Major version of the C API. Changed on every ABI-breaking change.
+pub const AERUGO_CAPI_VERSION_MAJOR: u16 = 1;
+/// Minor version of the C API. Changed when new functions or status codes are added.
+pub const AERUGO_CAPI_VERSION_MINOR: u16 = 0;
+
+/// Version of the C API.
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct AerugoVersion {
+    /// Major version.
+    pub major: u16,
+    /// Minor version.
+    pub minor: u16,
+}
+
+// Layout of the structures shared with C code is a part of the ABI.
+const _: () = assert!(core::mem::size_of::<AerugoVersion>() == 4);
+const _: () = assert!(core::mem::align_of::<AerugoVersion>() == 2);
+
+/// Returns version of the C API that the library was built with.
+///
+/// C code should check that the major version is equal, and the minor version is not lower than
+/// the one from the header it was compiled with.
+#[no_mangle]
+pub extern "C" fn aerugo_capi_version() -> AerugoVersion {
+    AerugoVersion {
+        major: AERUGO_CAPI_VERSION_MAJOR,
+        minor: AERUGO_CAPI_VERSION_MINOR,
+    }
+}
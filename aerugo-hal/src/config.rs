@@ -6,12 +6,54 @@ use crate::time;
 pub struct SystemHardwareConfig {
     /// Timeout for the watchdog.
     pub watchdog_timeout: time::MillisDurationU32,
+    /// Configuration of the heartbeat output, `None` if it's not used.
+    pub heartbeat: Option<HeartbeatConfig>,
+    /// Policy of FPU context handling.
+    pub fpu_context_policy: FpuContextPolicy,
 }
 
 impl Default for SystemHardwareConfig {
     fn default() -> Self {
         SystemHardwareConfig {
             watchdog_timeout: time::MillisDurationU32::secs(3),
+            heartbeat: None,
+            fpu_context_policy: FpuContextPolicy::LazyStacking,
         }
     }
 }
+
+/// Heartbeat output configuration.
+///
+/// Heartbeat output is a pin toggled by the system with a fixed period, as long as the scheduler
+/// is healthy. It can be monitored by an external supervisor to detect system failure.
+pub struct HeartbeatConfig {
+    /// ID of the I/O port of the heartbeat pin (ex. `'A'`).
+    pub port: char,
+    /// Number of the heartbeat pin in the port.
+    pub pin: u8,
+    /// Time between consecutive toggles of the heartbeat pin.
+    pub period: time::MillisDurationU32,
+}
+
+/// Policy of FPU context handling.
+///
+/// Tasklets are executed one after another, so they never interrupt each other and FPU context
+/// doesn't have to be saved between them. It has to be saved when an interrupt handler that uses
+/// the FPU preempts a tasklet that uses it.
+///
+/// Targets without FPU ignore this policy.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum FpuContextPolicy {
+    /// FPU is disabled. Any floating-point instruction causes a fault.
+    Disabled,
+    /// FPU context is saved on exception entry, but only if the handler uses the FPU. This is
+    /// the default, as it's safe and adds latency only to handlers that use the FPU.
+    LazyStacking,
+    /// FPU context is always saved on exception entry, if it's active. This makes interrupt latency
+    /// independent of the handler's FPU usage.
+    AlwaysStacking,
+    /// FPU context is never saved on exception entry. Interrupt handlers must not use the FPU
+    /// (ex. they can use fixed-point math instead), otherwise they silently corrupt FPU state
+    /// of the interrupted tasklet.
+    NoStacking,
+}
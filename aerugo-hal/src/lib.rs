@@ -9,9 +9,13 @@ HAL (Hardware Abstract Layer) for Aerugo system.
 #![warn(rustdoc::missing_crate_level_docs)]
 
 mod config;
+mod reset_cause;
+mod stack;
 
-pub use config::SystemHardwareConfig;
+pub use config::{FpuContextPolicy, HeartbeatConfig, SystemHardwareConfig};
 pub use fugit as time;
+pub use reset_cause::ResetCause;
+pub use stack::StackPaint;
 
 /// Constant representing system timer frequency.
 ///
@@ -22,6 +26,16 @@ pub type Instant = time::TimerInstantU64<SYSTEM_TIMER_FREQUENCY>;
 /// Type representing Aerugo duration.
 pub type Duration = time::TimerDurationU64<SYSTEM_TIMER_FREQUENCY>;
 
+/// External watchdog trait.
+///
+/// External watchdog is an independent supervisor chip (ex. strobed with a GPIO pin or fed via SPI)
+/// that resets the system if it's not fed periodically. Registered external watchdogs are fed by
+/// the system in the same place as the internal watchdog.
+pub trait ExternalWatchdog {
+    /// Feeds the external watchdog.
+    fn feed(&mut self);
+}
+
 /// System HAL trait.
 pub trait AerugoHal {
     /// Type for system HAL error.
@@ -40,4 +54,108 @@ pub trait AerugoHal {
 
     /// Feeds the system watchdog.
     fn feed_watchdog();
+
+    /// Checks that FPU configuration matches the policy set during hardware configuration.
+    ///
+    /// FPU can be reconfigured by the user with its driver, so this should be checked before
+    /// the scheduler is started.
+    ///
+    /// # Return
+    /// `()` if FPU configuration matches the policy, `Self::Error` otherwise.
+    fn verify_fpu_context_policy() -> Result<(), Self::Error>;
+
+    /// Puts the CPU to sleep until given time, or until any interrupt becomes pending.
+    ///
+    /// This should be called with interrupts disabled, so an interrupt that happens right before
+    /// going to sleep is not missed - it wakes the CPU immediately, and is handled after interrupts
+    /// are enabled again. Implementation can return before `wakeup_time` if the wakeup timer
+    /// cannot be programmed that far, or doesn't sleep at all if sleep is not supported.
+    ///
+    /// # Parameters
+    /// * `wakeup_time` - Time at which the CPU should be woken up.
+    fn sleep_until(wakeup_time: Instant);
+
+    /// Toggles the heartbeat output.
+    ///
+    /// Implementation should do nothing if heartbeat output was not configured.
+    fn toggle_heartbeat();
+
+    /// Resets the system.
+    fn reset_system() -> !;
+
+    /// Returns cause of the last system reset.
+    fn reset_cause() -> ResetCause;
+
+    /// Stores code of the shutdown reason in memory preserved over the reset.
+    ///
+    /// Implementation should do nothing if there is no such memory on this platform.
+    ///
+    /// # Parameters
+    /// * `code` - Code of the shutdown reason.
+    fn store_shutdown_reason(code: u32);
+
+    /// Takes code of the shutdown reason stored before the last reset.
+    ///
+    /// Stored code is cleared, so it's not reported again after an uncontrolled reset.
+    ///
+    /// # Return
+    /// Code of the shutdown reason, or `None` if it wasn't stored before the last reset.
+    fn take_shutdown_reason() -> Option<u32>;
+
+    /// Paints unused stack below the current stack pointer.
+    ///
+    /// Painted region is limited to `depth` bytes and to the bottom of the stack.
+    ///
+    /// # Parameters
+    /// * `depth` - Maximum size of painted region in bytes.
+    ///
+    /// # Return
+    /// Painted region, or `None` if stack painting is not supported on this platform.
+    fn paint_stack(depth: usize) -> Option<StackPaint>;
+
+    /// Measures stack usage in the painted region.
+    ///
+    /// # Parameters
+    /// * `paint` - Region painted with [`paint_stack`](AerugoHal::paint_stack).
+    ///
+    /// # Return
+    /// Number of bytes below the top of the painted region that were written since painting. If
+    /// the whole region was used, returned value is equal to its size, and actual usage may be
+    /// higher.
+    fn stack_usage(paint: &StackPaint) -> usize;
+
+    /// Returns address of the start of the firmware image in memory.
+    ///
+    /// # Return
+    /// Start of the image, or `None` if firmware image is not available in memory on this
+    /// platform.
+    fn firmware_image_start() -> Option<*const u8>;
+
+    /// Returns random delay of the next cyclic wakeup.
+    ///
+    /// Delay is used to test robustness of the application timing assumptions, by varying moments
+    /// at which cyclically executed tasklets are woken up. Implementation should return zero if
+    /// timing variation is not supported or not enabled.
+    ///
+    /// # Parameters
+    /// * `period` - Period of the cyclic execution, returned delay is lower than it.
+    ///
+    /// # Return
+    /// Delay added to the wakeup time.
+    fn wakeup_jitter(period: Duration) -> Duration;
+
+    /// Returns `true` if called from an interrupt handler.
+    fn in_interrupt() -> bool;
+
+    /// Starts calling given handler periodically from a timer interrupt.
+    ///
+    /// Handler is used to monitor the scheduler, so it has to be called even if the scheduler
+    /// loop never returns. Period of the calls is platform specific.
+    ///
+    /// # Parameters
+    /// * `handler` - Function called from the timer interrupt.
+    ///
+    /// # Return
+    /// `()` if successful, `Self::Error` if timer interrupt is not available.
+    fn start_monitor_timer(handler: fn()) -> Result<(), Self::Error>;
 }
@@ -0,0 +1,28 @@
+//! Cause of the last system reset.
+
+/// Cause of the last system reset.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum ResetCause {
+    /// First power-up of the system.
+    PowerOn,
+    /// Wake-up from the backup mode.
+    Backup,
+    /// Watchdog didn't get fed in time.
+    Watchdog,
+    /// Reset was requested by the software.
+    Software,
+    /// Reset was requested with the external reset pin.
+    External,
+    /// Cause of the reset couldn't be determined.
+    Unknown,
+}
+
+impl ResetCause {
+    /// Checks if the system can be warm-started after this reset.
+    ///
+    /// Warm start is possible after resets caused by the running system itself (watchdog and
+    /// software resets), as external devices were not power-cycled and kept their configuration.
+    pub fn is_warm(&self) -> bool {
+        matches!(self, ResetCause::Watchdog | ResetCause::Software)
+    }
+}
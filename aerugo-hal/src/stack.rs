@@ -0,0 +1,23 @@
+//! Stack painting items.
+
+/// Region of the stack filled with the paint pattern.
+///
+/// Stack usage is measured by painting unused stack below the stack pointer and checking, after
+/// the code of interest was executed, how deep the paint was overwritten.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct StackPaint {
+    /// Stack pointer at the time of painting. Usage is measured relative to this address.
+    pub top: usize,
+    /// Lowest painted address.
+    pub bottom: usize,
+}
+
+impl StackPaint {
+    /// Pattern that the stack is painted with.
+    pub const PATTERN: u32 = 0xA5A5_A5A5;
+
+    /// Returns size of the painted region in bytes.
+    pub fn size(&self) -> usize {
+        self.top - self.bottom
+    }
+}
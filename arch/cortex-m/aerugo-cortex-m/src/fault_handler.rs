@@ -0,0 +1,247 @@
+//! Fault handlers for the Cortex-M target.
+//!
+//! This module installs `HardFault`, `MemoryManagement`, `BusFault` and `UsageFault` exception
+//! handlers. Each handler captures a [FaultReport] with registers stacked on exception entry, fault
+//! status and address registers, and name of the tasklet that was executed when the fault
+//! occurred. Report is stored in the RAM region that is not initialized on startup, passed to the
+//! [user callback](set_fault_callback), if one is set, and the MCU is reset. After the reset, report
+//! can be retrieved with [take_fault_report] for post-mortem analysis.
+//!
+//! `MemoryManagement`, `BusFault` and `UsageFault` are escalated to `HardFault` unless they are
+//! enabled in SCB (ex. with `SCB::enable`). Application using this module can't define its own
+//! handlers for these exceptions.
+//!
+//! Report survives only a reset that doesn't remove power from the RAM, and its content is
+//! undefined after a power-on reset, which is detected with a marker value.
+
+use core::cell::Cell;
+use core::mem::MaybeUninit;
+use core::ptr::{addr_of, addr_of_mut, read_volatile, write_volatile};
+
+use cortex_m::peripheral::SCB;
+use critical_section::Mutex;
+
+/// Maximum length of the tasklet name stored in the report, longer names are truncated.
+pub const TASKLET_NAME_LENGTH: usize = 32;
+
+/// Marker value of a valid report stored in RAM.
+const REPORT_MARKER: u32 = 0xFA17_AE60;
+
+/// Function called with the fault report before the MCU is reset.
+pub type FaultCallback = fn(&FaultReport);
+
+/// Exception that captured the fault.
+#[repr(u32)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum FaultKind {
+    /// HardFault exception.
+    HardFault = 0,
+    /// MemManage exception.
+    MemoryManagement = 1,
+    /// BusFault exception.
+    BusFault = 2,
+    /// UsageFault exception.
+    UsageFault = 3,
+}
+
+/// Registers stacked by the processor on exception entry.
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct ExceptionFrame {
+    /// Register R0.
+    pub r0: u32,
+    /// Register R1.
+    pub r1: u32,
+    /// Register R2.
+    pub r2: u32,
+    /// Register R3.
+    pub r3: u32,
+    /// Register R12.
+    pub r12: u32,
+    /// Link register.
+    pub lr: u32,
+    /// Address of the faulting instruction.
+    pub pc: u32,
+    /// Program status register.
+    pub xpsr: u32,
+}
+
+/// Report of the fault, captured by the fault handler.
+#[repr(C)]
+#[derive(Debug, Copy, Clone)]
+pub struct FaultReport {
+    /// Exception that captured the fault.
+    pub kind: FaultKind,
+    /// Registers stacked on exception entry.
+    pub frame: ExceptionFrame,
+    /// Configurable Fault Status Register.
+    pub cfsr: u32,
+    /// HardFault Status Register.
+    pub hfsr: u32,
+    /// MemManage Fault Address Register, valid if `MMARVALID` bit is set in CFSR.
+    pub mmfar: u32,
+    /// BusFault Address Register, valid if `BFARVALID` bit is set in CFSR.
+    pub bfar: u32,
+    /// Name of the executed tasklet, truncated to [TASKLET_NAME_LENGTH] bytes.
+    tasklet_name: [u8; TASKLET_NAME_LENGTH],
+    /// Length of the tasklet name, `0` if no tasklet was executed.
+    tasklet_name_length: usize,
+}
+
+impl FaultReport {
+    /// Returns name of the tasklet executed when the fault occurred.
+    ///
+    /// # Return
+    /// Tasklet name, or `None` if fault occurred outside of a tasklet.
+    pub fn tasklet_name(&self) -> Option<&str> {
+        let length = self.tasklet_name_length.min(TASKLET_NAME_LENGTH);
+        if length == 0 {
+            return None;
+        }
+
+        // Name could be truncated in the middle of a character.
+        let name = &self.tasklet_name[..length];
+        match core::str::from_utf8(name) {
+            Ok(name) => Some(name),
+            Err(error) => core::str::from_utf8(&name[..error.valid_up_to()]).ok(),
+        }
+    }
+}
+
+/// Report stored in the RAM region that is not initialized on startup.
+#[repr(C)]
+struct PersistentReport {
+    /// [REPORT_MARKER] if the report is valid.
+    marker: u32,
+    /// Captured report.
+    report: MaybeUninit<FaultReport>,
+}
+
+/// Report of the last fault, preserved through the reset.
+#[link_section = ".uninit.aerugo_fault_report"]
+static mut PERSISTENT_REPORT: MaybeUninit<PersistentReport> = MaybeUninit::uninit();
+
+/// Name of the currently executed tasklet.
+static CURRENT_TASKLET: Mutex<Cell<Option<&'static str>>> = Mutex::new(Cell::new(None));
+
+/// Function called with the fault report.
+static FAULT_CALLBACK: Mutex<Cell<Option<FaultCallback>>> = Mutex::new(Cell::new(None));
+
+/// Sets name of the currently executed tasklet, stored in the fault report.
+///
+/// # Parameters
+/// * `name` - Name of the tasklet, `None` if no tasklet is executed.
+pub fn set_current_tasklet(name: Option<&'static str>) {
+    critical_section::with(|cs| CURRENT_TASKLET.borrow(cs).set(name));
+}
+
+/// Sets function called with the fault report before the MCU is reset.
+///
+/// Callback is executed in the fault handler, so it should only do minimal work, like logging
+/// the report or storing it in non-volatile memory.
+///
+/// # Parameters
+/// * `callback` - Fault callback.
+pub fn set_fault_callback(callback: FaultCallback) {
+    critical_section::with(|cs| FAULT_CALLBACK.borrow(cs).set(Some(callback)));
+}
+
+/// Returns report of the fault that caused the last reset, and invalidates it.
+///
+/// # Return
+/// Fault report, or `None` if last reset wasn't caused by a fault.
+pub fn take_fault_report() -> Option<FaultReport> {
+    // SAFETY: Report is accessed with volatile operations, as it's written by the fault handler
+    // in the previous run. Content is read only if the marker was stored together with it.
+    unsafe {
+        let persistent = addr_of_mut!(PERSISTENT_REPORT).cast::<PersistentReport>();
+        let marker = addr_of_mut!((*persistent).marker);
+        if read_volatile(marker) != REPORT_MARKER {
+            return None;
+        }
+
+        write_volatile(marker, 0);
+
+        // Kind is the only field with invalid bit patterns, and it's the first field of the report.
+        let report = addr_of!((*persistent).report);
+        if read_volatile(report.cast::<u32>()) > FaultKind::UsageFault as u32 {
+            return None;
+        }
+
+        Some(read_volatile(report).assume_init())
+    }
+}
+
+/// Captures the fault report, stores it, calls the user callback and resets the MCU.
+///
+/// # Parameters
+/// * `frame` - Registers stacked on exception entry.
+/// * `kind` - Exception that captured the fault.
+///
+/// # Safety
+/// This must be called only from the fault handler trampolines.
+#[no_mangle]
+unsafe extern "C" fn aerugo_fault_handler(frame: &ExceptionFrame, kind: FaultKind) -> ! {
+    let scb = &*SCB::PTR;
+
+    let mut report = FaultReport {
+        kind,
+        frame: *frame,
+        cfsr: scb.cfsr.read(),
+        hfsr: scb.hfsr.read(),
+        mmfar: scb.mmfar.read(),
+        bfar: scb.bfar.read(),
+        tasklet_name: [0; TASKLET_NAME_LENGTH],
+        tasklet_name_length: 0,
+    };
+
+    let (tasklet, callback) = critical_section::with(|cs| {
+        (
+            CURRENT_TASKLET.borrow(cs).get(),
+            FAULT_CALLBACK.borrow(cs).get(),
+        )
+    });
+
+    if let Some(name) = tasklet {
+        let length = name.len().min(TASKLET_NAME_LENGTH);
+        report.tasklet_name[..length].copy_from_slice(&name.as_bytes()[..length]);
+        report.tasklet_name_length = length;
+    }
+
+    let persistent = addr_of_mut!(PERSISTENT_REPORT).cast::<PersistentReport>();
+    write_volatile(addr_of_mut!((*persistent).report), MaybeUninit::new(report));
+    write_volatile(addr_of_mut!((*persistent).marker), REPORT_MARKER);
+
+    if let Some(callback) = callback {
+        callback(&report);
+    }
+
+    SCB::sys_reset()
+}
+
+/// Defines exception handler which passes the stacked registers and fault kind to
+/// [aerugo_fault_handler]. Stacked registers are on the stack that was active when the fault
+/// occurred, as indicated by the `EXC_RETURN` value in the link register.
+macro_rules! fault_trampoline {
+    ($name:literal, $kind:literal) => {
+        #[cfg(target_arch = "arm")]
+        core::arch::global_asm!(
+            concat!(".section .text.", $name, ",\"ax\",%progbits"),
+            concat!(".global ", $name),
+            concat!(".type ", $name, ",%function"),
+            ".thumb_func",
+            concat!($name, ":"),
+            "tst lr, #4",
+            "ite eq",
+            "mrseq r0, msp",
+            "mrsne r0, psp",
+            concat!("movs r1, #", $kind),
+            "b aerugo_fault_handler",
+        );
+    };
+}
+
+fault_trampoline!("HardFault", "0");
+fault_trampoline!("MemoryManagement", "1");
+fault_trampoline!("BusFault", "2");
+fault_trampoline!("UsageFault", "3");
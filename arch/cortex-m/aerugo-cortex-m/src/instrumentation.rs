@@ -0,0 +1,67 @@
+//! Scheduler instrumentation for the Cortex-M target.
+//!
+//! Executions of the tasklets are streamed over a dedicated RTT channel, as fixed-size binary
+//! records. Each record consists of the following little-endian fields:
+//! * `u32` - ID of the executed tasklet,
+//! * `u64` - time when the tasklet was put into the execution queue, in microseconds,
+//! * `u64` - time when the execution started, in microseconds,
+//! * `u64` - time when the execution ended, in microseconds.
+//!
+//! Records are written in non-blocking mode, so when the host doesn't keep up, whole records are
+//! dropped and the stream stays aligned.
+
+use core::cell::RefCell;
+
+use critical_section::Mutex;
+use rtt_target::UpChannel;
+
+/// Size of a single trace record in bytes.
+const TRACE_RECORD_SIZE: usize = 28;
+
+/// RTT channel used for the execution trace.
+static TRACE_CHANNEL: Mutex<RefCell<Option<UpChannel>>> = Mutex::new(RefCell::new(None));
+
+/// Function used to initialize instrumentation facilities. Should be called once, on init.
+///
+/// This initializes RTT with terminal channel, used by logging, and trace channel.
+#[inline(never)]
+pub fn init_instrumentation() {
+    let channels = rtt_target::rtt_init! {
+        up: {
+            0: {
+                size: 1024
+                mode: NoBlockSkip
+                name: "Terminal"
+            }
+            1: {
+                size: 4096
+                mode: NoBlockSkip
+                name: "Trace"
+            }
+        }
+    };
+
+    rtt_target::set_print_channel(channels.up.0);
+    critical_section::with(|cs| TRACE_CHANNEL.borrow(cs).replace(Some(channels.up.1)));
+}
+
+/// Writes tasklet execution record to the trace channel.
+///
+/// # Parameters
+/// * `tasklet_id` - ID of the executed tasklet.
+/// * `enqueue_time` - Time when the tasklet was put into the execution queue, in microseconds.
+/// * `start_time` - Time when the execution started, in microseconds.
+/// * `end_time` - Time when the execution ended, in microseconds.
+pub fn trace_execution(tasklet_id: u32, enqueue_time: u64, start_time: u64, end_time: u64) {
+    let mut record = [0u8; TRACE_RECORD_SIZE];
+    record[0..4].copy_from_slice(&tasklet_id.to_le_bytes());
+    record[4..12].copy_from_slice(&enqueue_time.to_le_bytes());
+    record[12..20].copy_from_slice(&start_time.to_le_bytes());
+    record[20..28].copy_from_slice(&end_time.to_le_bytes());
+
+    critical_section::with(|cs| {
+        if let Some(channel) = TRACE_CHANNEL.borrow(cs).borrow_mut().as_mut() {
+            channel.write(&record);
+        }
+    });
+}
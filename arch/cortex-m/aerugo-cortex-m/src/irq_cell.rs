@@ -0,0 +1,164 @@
+//! Cell for passing values to the interrupt handlers.
+//!
+//! Peripheral drivers often have to be shared with an interrupt handler, ex. status reader of the
+//! DMA controller. Such value is created during the initialization, before the interrupt is
+//! enabled, and afterwards is accessed only from the interrupt handler. [`IrqCell`] holds such
+//! value in a `static` and checks that it was initialized, and that it's not accessed from two
+//! places at once, instead of relying on `static mut` and comments describing when it's safe to
+//! access it.
+
+use core::cell::UnsafeCell;
+use core::ops::{Deref, DerefMut};
+use core::sync::atomic::{AtomicU8, Ordering};
+
+/// Cell doesn't hold a value.
+const EMPTY: u8 = 0;
+/// Value is being placed in the cell, or taken out of it.
+const BUSY: u8 = 1;
+/// Cell holds a value, that is not borrowed.
+const READY: u8 = 2;
+/// Value held by the cell is borrowed.
+const BORROWED: u8 = 3;
+
+/// Cell for a value initialized before the interrupt is enabled, and accessed only from the
+/// interrupt handler afterwards.
+///
+/// Value can be borrowed by only one context at a time. Borrowing value that is already borrowed
+/// (ex. from an interrupt that preempted the borrowing code) fails instead of creating an aliased
+/// mutable reference.
+///
+/// # Generic Parameters
+/// * `T` - Type of the stored value.
+pub struct IrqCell<T> {
+    /// Stored value.
+    value: UnsafeCell<Option<T>>,
+    /// State of the cell.
+    state: AtomicU8,
+}
+
+/// Access to the stored value is guarded by the cell state, which is modified atomically, so only
+/// one context can access the value at a time. `Send` is not required, because Cortex-M target is
+/// single-core and value is only passed between the main context and interrupt handlers, so
+/// peripheral drivers holding raw register pointers can be stored.
+unsafe impl<T> Sync for IrqCell<T> {}
+
+impl<T> IrqCell<T> {
+    /// Creates new, empty cell.
+    pub const fn new() -> Self {
+        IrqCell {
+            value: UnsafeCell::new(None),
+            state: AtomicU8::new(EMPTY),
+        }
+    }
+
+    /// Places value in the cell.
+    ///
+    /// This should be called before the interrupt using the value is enabled.
+    ///
+    /// # Parameters
+    /// * `value` - Value to store.
+    ///
+    /// # Return
+    /// `()` if successful, given value if the cell already holds one.
+    pub fn init(&self, value: T) -> Result<(), T> {
+        if !self.transition(EMPTY, BUSY) {
+            return Err(value);
+        }
+
+        // SAFETY: Cell is in `BUSY` state, so no one else accesses the value.
+        unsafe { *self.value.get() = Some(value) };
+        self.state.store(READY, Ordering::Release);
+
+        Ok(())
+    }
+
+    /// Takes value out of the cell.
+    ///
+    /// This can be used to get the value back after the interrupt was disabled.
+    ///
+    /// # Return
+    /// Stored value, `None` if the cell is empty or the value is borrowed.
+    pub fn take(&self) -> Option<T> {
+        if !self.transition(READY, BUSY) {
+            return None;
+        }
+
+        // SAFETY: Cell is in `BUSY` state, so no one else accesses the value.
+        let value = unsafe { (*self.value.get()).take() };
+        self.state.store(EMPTY, Ordering::Release);
+
+        value
+    }
+
+    /// Borrows stored value.
+    ///
+    /// Value is borrowed until returned reference is dropped.
+    ///
+    /// # Return
+    /// Reference to the stored value, `None` if the cell is empty or the value is already
+    /// borrowed.
+    pub fn borrow_mut(&self) -> Option<IrqCellRef<'_, T>> {
+        if !self.transition(READY, BORROWED) {
+            return None;
+        }
+
+        Some(IrqCellRef { cell: self })
+    }
+
+    /// Returns `true` if the cell holds a value.
+    pub fn is_initialized(&self) -> bool {
+        matches!(self.state.load(Ordering::Acquire), READY | BORROWED)
+    }
+
+    /// Atomically changes state of the cell.
+    ///
+    /// # Parameters
+    /// * `current` - Expected current state.
+    /// * `new` - New state.
+    ///
+    /// # Return
+    /// `true` if state was changed, `false` if cell wasn't in the expected state.
+    fn transition(&self, current: u8, new: u8) -> bool {
+        self.state
+            .compare_exchange(current, new, Ordering::Acquire, Ordering::Relaxed)
+            .is_ok()
+    }
+}
+
+impl<T> Default for IrqCell<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Reference to the value borrowed from [`IrqCell`].
+///
+/// Value is released when this reference is dropped.
+pub struct IrqCellRef<'a, T> {
+    /// Cell holding the value.
+    cell: &'a IrqCell<T>,
+}
+
+impl<T> Deref for IrqCellRef<'_, T> {
+    type Target = T;
+
+    fn deref(&self) -> &T {
+        // SAFETY: Cell is in `BORROWED` state, so it holds a value that is accessed only through
+        // this reference.
+        unsafe { (*self.cell.value.get()).as_ref().unwrap_unchecked() }
+    }
+}
+
+impl<T> DerefMut for IrqCellRef<'_, T> {
+    fn deref_mut(&mut self) -> &mut T {
+        // SAFETY: Cell is in `BORROWED` state, so it holds a value that is accessed only through
+        // this reference.
+        unsafe { (*self.cell.value.get()).as_mut().unwrap_unchecked() }
+    }
+}
+
+impl<T> Drop for IrqCellRef<'_, T> {
+    fn drop(&mut self) {
+        self.cell.state.store(READY, Ordering::Release);
+    }
+}
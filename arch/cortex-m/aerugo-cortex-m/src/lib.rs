@@ -6,8 +6,18 @@ Cortex-M specific implementation for Aerugo.
 #![warn(clippy::missing_docs_in_private_items)]
 #![warn(rustdoc::missing_crate_level_docs)]
 
+#[cfg(feature = "fault-handler")]
+pub mod fault_handler;
+#[cfg(feature = "instrumented")]
+mod instrumentation;
+mod irq_cell;
 #[cfg(feature = "log")]
 mod logger;
+mod static_dma_cell;
 
+#[cfg(feature = "instrumented")]
+pub use self::instrumentation::{init_instrumentation, trace_execution};
+pub use self::irq_cell::{IrqCell, IrqCellRef};
 #[cfg(feature = "log")]
 pub use self::logger::{init_log, log, logln};
+pub use self::static_dma_cell::StaticDmaCell;
@@ -15,5 +15,7 @@ pub use rtt_target::rprintln as logln;
 /// Function used to initialize logging facilities. Should be called once, on init.
 #[inline(never)]
 pub fn init_log() {
+    // With instrumentation enabled, RTT is initialized together with the trace channel.
+    #[cfg(not(feature = "instrumented"))]
     rtt_target::rtt_init_print!();
 }
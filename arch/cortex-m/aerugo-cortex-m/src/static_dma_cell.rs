@@ -0,0 +1,96 @@
+//! Statically allocated DMA buffer.
+//!
+//! Buffers used by the DMA controller have to live for the whole transfer, so they are usually
+//! `static mut` arrays, which address is passed to the transfer configuration and which are read
+//! from the interrupt handler after the transfer ends. [`StaticDmaCell`] holds such buffer in a
+//! `static` and tracks whether it's used by a transfer, so it's not read or written by the CPU
+//! while the DMA controller accesses it.
+
+use core::cell::UnsafeCell;
+use core::sync::atomic::{AtomicBool, Ordering};
+
+/// Statically allocated buffer for DMA transfers.
+///
+/// Buffer is either owned by the CPU, which can read and write it, or by a DMA transfer, which
+/// gets its address. Ownership is passed with [`start_transfer`](StaticDmaCell::start_transfer)
+/// and [`finish_transfer`](StaticDmaCell::finish_transfer).
+///
+/// # Generic Parameters
+/// * `T` - Type of the buffer.
+pub struct StaticDmaCell<T: Copy> {
+    /// Buffer.
+    buffer: UnsafeCell<T>,
+    /// `true` if buffer is used by a DMA transfer.
+    in_transfer: AtomicBool,
+}
+
+/// Buffer is accessed by the CPU only through volatile copies, and only when it's not used by a
+/// DMA transfer.
+unsafe impl<T: Copy> Sync for StaticDmaCell<T> {}
+
+impl<T: Copy> StaticDmaCell<T> {
+    /// Creates new buffer.
+    ///
+    /// # Parameters
+    /// * `value` - Initial content of the buffer.
+    pub const fn new(value: T) -> Self {
+        StaticDmaCell {
+            buffer: UnsafeCell::new(value),
+            in_transfer: AtomicBool::new(false),
+        }
+    }
+
+    /// Passes buffer to a DMA transfer.
+    ///
+    /// # Return
+    /// Address of the buffer, `None` if the buffer is already used by a transfer.
+    pub fn start_transfer(&'static self) -> Option<*mut T> {
+        self.in_transfer
+            .compare_exchange(false, true, Ordering::AcqRel, Ordering::Relaxed)
+            .ok()
+            .map(|_| self.buffer.get())
+    }
+
+    /// Returns buffer back to the CPU, after the DMA transfer has ended.
+    pub fn finish_transfer(&self) {
+        self.in_transfer.store(false, Ordering::Release);
+    }
+
+    /// Returns `true` if buffer is used by a DMA transfer.
+    pub fn is_in_transfer(&self) -> bool {
+        self.in_transfer.load(Ordering::Acquire)
+    }
+
+    /// Reads content of the buffer.
+    ///
+    /// # Return
+    /// Content of the buffer, `None` if the buffer is used by a transfer.
+    pub fn read(&self) -> Option<T> {
+        if self.is_in_transfer() {
+            return None;
+        }
+
+        // SAFETY: Buffer is not used by a transfer, and it's read with a volatile copy, so it's
+        // not cached across transfers.
+        Some(unsafe { self.buffer.get().read_volatile() })
+    }
+
+    /// Writes content of the buffer.
+    ///
+    /// # Parameters
+    /// * `value` - New content of the buffer.
+    ///
+    /// # Return
+    /// `()` if successful, given value if the buffer is used by a transfer.
+    pub fn write(&self, value: T) -> Result<(), T> {
+        if self.is_in_transfer() {
+            return Err(value);
+        }
+
+        // SAFETY: Buffer is not used by a transfer, and it's written with a volatile copy, so the
+        // write isn't elided before the next transfer.
+        unsafe { self.buffer.get().write_volatile(value) };
+
+        Ok(())
+    }
+}
@@ -9,4 +9,11 @@ pub enum HalError {
     HalAlreadyInitialized,
     /// Error indicating that system was tried to be initialized twice.
     HardwareAlreadyInitialized,
+    /// Error indicating that heartbeat pin from the configuration doesn't exist.
+    InvalidHeartbeatPin,
+    /// Error indicating that FPU configuration doesn't match the configured FPU context policy.
+    FpuContextPolicyViolated,
+    /// Error indicating that monitor timer interrupt handler is not available, as HAL was built
+    /// without `rt` feature.
+    MonitorTimerUnavailable,
 }
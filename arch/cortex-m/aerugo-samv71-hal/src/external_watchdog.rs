@@ -0,0 +1,31 @@
+//! External watchdog drivers.
+
+use aerugo_hal::ExternalWatchdog;
+use samv71_hal::embedded_hal::digital::ToggleableOutputPin;
+use samv71_hal::pio::pin::OutputMode;
+use samv71_hal::pio::Pin;
+
+/// External watchdog strobed with a GPIO pin.
+///
+/// Supervisor chip is fed by changing state of its input pin, so each feed toggles the pin.
+pub struct StrobedWatchdog {
+    /// Pin connected to the supervisor's input.
+    pin: Pin<OutputMode>,
+}
+
+impl StrobedWatchdog {
+    /// Creates new strobed watchdog.
+    ///
+    /// # Parameters
+    /// * `pin` - Pin connected to the supervisor's input.
+    pub fn new(pin: Pin<OutputMode>) -> Self {
+        StrobedWatchdog { pin }
+    }
+}
+
+impl ExternalWatchdog for StrobedWatchdog {
+    fn feed(&mut self) {
+        // Toggling SAMV71 output pin can't fail.
+        let _ = self.pin.toggle();
+    }
+}
@@ -0,0 +1,59 @@
+//! FPU context policy.
+
+use aerugo_hal::FpuContextPolicy;
+
+/// Configures the FPU according to the policy.
+///
+/// # Safety
+/// This function steals core peripherals, so it should be called only during hardware
+/// configuration, before user peripherals are created.
+///
+/// # Parameters
+/// * `policy` - FPU context policy.
+#[cfg(has_fpu)]
+pub(crate) unsafe fn apply(policy: FpuContextPolicy) {
+    let mut core_peripherals = samv71_hal::pac::CorePeripherals::steal();
+    let mut fpu = samv71_hal::fpu::Fpu::new(core_peripherals.FPU);
+
+    if policy == FpuContextPolicy::Disabled {
+        fpu.disable(&mut core_peripherals.SCB);
+        return;
+    }
+
+    fpu.enable(&mut core_peripherals.SCB);
+    fpu.set_context_preservation(policy != FpuContextPolicy::NoStacking);
+    fpu.set_lazy_stacking(policy == FpuContextPolicy::LazyStacking);
+}
+
+/// Configures the FPU according to the policy.
+///
+/// Target doesn't have FPU, so the policy is ignored.
+#[cfg(not(has_fpu))]
+pub(crate) unsafe fn apply(_policy: FpuContextPolicy) {}
+
+/// Checks if current FPU configuration matches the policy.
+///
+/// # Parameters
+/// * `policy` - FPU context policy.
+#[cfg(has_fpu)]
+pub(crate) fn matches(policy: FpuContextPolicy) -> bool {
+    // SAFETY: FPU driver is used only to read the configuration.
+    let core_peripherals = unsafe { samv71_hal::pac::CorePeripherals::steal() };
+    let fpu = samv71_hal::fpu::Fpu::new(core_peripherals.FPU);
+
+    if policy == FpuContextPolicy::Disabled {
+        return !fpu.is_enabled();
+    }
+
+    fpu.is_enabled()
+        && fpu.is_context_preserved_on_exception() == (policy != FpuContextPolicy::NoStacking)
+        && fpu.is_lazy_stacking_enabled() == (policy == FpuContextPolicy::LazyStacking)
+}
+
+/// Checks if current FPU configuration matches the policy.
+///
+/// Target doesn't have FPU, so any policy matches.
+#[cfg(not(has_fpu))]
+pub(crate) fn matches(_policy: FpuContextPolicy) -> bool {
+    true
+}
@@ -1,18 +1,33 @@
 //! System HAL implementation for Cortex-M SAMV71 target.
 
-use aerugo_hal::{AerugoHal, Instant, SystemHardwareConfig};
+use core::cell::Cell;
+
+use aerugo_hal::{
+    AerugoHal, Duration, FpuContextPolicy, Instant, ResetCause, StackPaint, SystemHardwareConfig,
+};
+use critical_section::Mutex;
+use samv71_hal::cortex_m::asm;
+use samv71_hal::cortex_m::peripheral::scb::VectActive;
+use samv71_hal::cortex_m::peripheral::NVIC;
+use samv71_hal::cortex_m::register;
+#[cfg(feature = "rt")]
+use samv71_hal::interrupt;
 use samv71_hal::pmc::config::pck::{PCKConfig, PCKPrescaler, PCKSource, PCK};
 use samv71_hal::pmc::config::PeripheralId;
 
 use crate::error::HalError;
+use crate::fpu_policy;
+use crate::heartbeat::HeartbeatPin;
 use crate::system_peripherals::SystemPeripherals;
-use crate::user_peripherals::UserPeripherals;
-use samv71_hal::pac::{self, TC0};
+use crate::user_peripherals::{UserPeripherals, PERIPHERAL_COUNT};
+use samv71_hal::pac::rstc::sr::RSTTYPSELECT_A;
+use samv71_hal::pac::{self, Interrupt, GPBR, RSTC, TC0};
 use samv71_hal::pmc::PMC;
-use samv71_hal::timer::channel_config::ChannelClock;
+use samv71_hal::timer::channel_config::{ChannelClock, ChannelInterrupts};
 use samv71_hal::timer::timer_config::{ExternalClock, ExternalClockSource};
 use samv71_hal::timer::waveform_config::{
-    ComparisonEffect, OutputSignalEffects, WaveformModeConfig,
+    ComparisonEffect, ExternalEventConfig, ExternalEventSignal, OutputSignalEffects,
+    WaveformModeConfig,
 };
 use samv71_hal::timer::{Ch0, Ch1, Ch2, Channel, Timer, Waveform};
 use samv71_hal::watchdog::{Watchdog, WatchdogConfig};
@@ -24,6 +39,48 @@ use samv71_hal::watchdog::{Watchdog, WatchdogConfig};
 /// Safety of this instance is managed by HAL instead, guaranteeing that undefined behavior will not occur.
 static mut HAL_SYSTEM_PERIPHERALS: Option<SystemPeripherals> = None;
 
+/// Size of the stack word in bytes.
+const WORD_SIZE: usize = core::mem::size_of::<u32>();
+
+/// Index of the general purpose backup register storing the shutdown reason. Next register stores
+/// its complement.
+const SHUTDOWN_REASON_GPBR: usize = 6;
+
+/// Shortest sleep in microseconds. Waking up takes a few microseconds, so shorter sleeps are
+/// skipped.
+const MINIMUM_SLEEP_TIME_US: u64 = 50;
+
+/// System timer interrupts used to wake up the CPU from sleep.
+const WAKEUP_INTERRUPTS: ChannelInterrupts = ChannelInterrupts {
+    counter_overflow: false,
+    load_overrun: false,
+    ra_compare: false,
+    rb_compare: true,
+    rc_compare: false,
+    ra_load: false,
+    rb_load: false,
+    external_trigger: false,
+};
+
+/// System timer interrupts used to call the monitor handler. RC compare of channel 0 happens each
+/// time its counter wraps around, every 65.536 ms.
+const MONITOR_INTERRUPTS: ChannelInterrupts = ChannelInterrupts {
+    counter_overflow: false,
+    load_overrun: false,
+    ra_compare: false,
+    rb_compare: false,
+    rc_compare: true,
+    ra_load: false,
+    rb_load: false,
+    external_trigger: false,
+};
+
+/// Type of the cell holding the monitor handler.
+type MonitorHandlerCell = Mutex<Cell<Option<fn()>>>;
+
+/// Handler called from the system timer interrupt, `None` if monitor timer is not started.
+static MONITOR_HANDLER: MonitorHandlerCell = Mutex::new(Cell::new(None));
+
 /// HAL implementation for Cortex-M based SAMV71 MCU.
 pub struct Hal;
 
@@ -57,9 +114,17 @@ impl Hal {
                 system_peripherals.pmc.as_ref()?;
 
                 Some(UserPeripherals {
+                    afec_0: Some(mcu_peripherals.AFEC0),
+                    afec_1: Some(mcu_peripherals.AFEC1),
                     chip_id: Some(mcu_peripherals.CHIPID),
                     cpu_id: Some(core_peripherals.CPUID),
+                    dacc: Some(mcu_peripherals.DACC),
                     fpu: Some(core_peripherals.FPU),
+                    gmac: Some(mcu_peripherals.GMAC),
+                    gpbr: Some(mcu_peripherals.GPBR),
+                    mcan_0: Some(mcu_peripherals.MCAN0),
+                    mcan_1: Some(mcu_peripherals.MCAN1),
+                    mpu: Some(core_peripherals.MPU),
                     nvic: Some(core_peripherals.NVIC),
                     pio_a: Some(mcu_peripherals.PIOA),
                     pio_b: Some(mcu_peripherals.PIOB),
@@ -67,6 +132,7 @@ impl Hal {
                     pio_d: Some(mcu_peripherals.PIOD),
                     pio_e: Some(mcu_peripherals.PIOE),
                     pmc: system_peripherals.pmc.take(),
+                    rstc: Some(mcu_peripherals.RSTC),
                     scb: Some(core_peripherals.SCB),
                     spi_0: Some(mcu_peripherals.SPI0),
                     spi_1: Some(mcu_peripherals.SPI1),
@@ -80,6 +146,7 @@ impl Hal {
                     uart_3: Some(mcu_peripherals.UART3),
                     uart_4: Some(mcu_peripherals.UART4),
                     xdmac: Some(mcu_peripherals.XDMAC),
+                    ownership: [None; PERIPHERAL_COUNT],
                 })
             } else {
                 None
@@ -87,6 +154,25 @@ impl Hal {
         })
     }
 
+    /// Triggers watchdog reset.
+    ///
+    /// This function disables interrupts and waits without feeding the watchdog, until it
+    /// resets the MCU. It can be used to demonstrate that the watchdog is working.
+    ///
+    /// Watchdog is configured during [`AerugoHal::configure_hardware`] call, so this function
+    /// will wait up to [`SystemHardwareConfig::watchdog_timeout`] before the reset happens.
+    ///
+    /// # Safety
+    /// This function never returns. It should be called only after HAL initialization, as
+    /// watchdog may not be configured to reset the MCU before that.
+    pub fn trigger_watchdog_reset() -> ! {
+        samv71_hal::cortex_m::interrupt::disable();
+
+        loop {
+            samv71_hal::cortex_m::asm::nop();
+        }
+    }
+
     /// Initializes global HAL instance using PAC peripherals.
     ///
     /// Calling this function begins HAL initialization process. This process must be finished
@@ -133,6 +219,8 @@ impl Hal {
             timer_ch1: None,
             timer_ch2: None,
             pmc: Some(PMC::new(mcu_peripherals.PMC)),
+            heartbeat: None,
+            fpu_context_policy: FpuContextPolicy::LazyStacking,
         }
     }
 }
@@ -198,6 +286,21 @@ impl AerugoHal for Hal {
             peripherals.timer_ch1.replace(ch1);
             peripherals.timer_ch2.replace(ch2);
 
+            // Configure FPU
+            // SAFETY: This is safe, because user peripherals are not created yet.
+            unsafe { fpu_policy::apply(config.fpu_context_policy) };
+            peripherals.fpu_context_policy = config.fpu_context_policy;
+
+            // Configure heartbeat output
+            if let Some(heartbeat_config) = config.heartbeat {
+                let (heartbeat, peripheral_id) =
+                    HeartbeatPin::new(heartbeat_config.port, heartbeat_config.pin)?;
+
+                pmc.enable_peripheral_clock(peripheral_id);
+                heartbeat.configure();
+                peripherals.heartbeat.replace(heartbeat);
+            }
+
             // Start system timer
             peripherals.timer.trigger_all_channels();
 
@@ -246,6 +349,233 @@ impl AerugoHal for Hal {
 
         peripherals.watchdog.feed();
     }
+
+    fn verify_fpu_context_policy() -> Result<(), HalError> {
+        // SAFETY: This is safe, because this is a single-core system, and no other references to
+        // system peripherals should exist during this call.
+        let peripherals = unsafe {
+            HAL_SYSTEM_PERIPHERALS
+                .as_ref()
+                .expect("HAL cannot be accessed before initialization")
+        };
+
+        if fpu_policy::matches(peripherals.fpu_context_policy) {
+            Ok(())
+        } else {
+            Err(HalError::FpuContextPolicyViolated)
+        }
+    }
+
+    /// Sleep is implemented with WFI, woken up by RB compare of the system timer channel 0. Channel
+    /// counts microseconds and wraps around every 65.536 ms, so longer sleeps end early.
+    fn sleep_until(wakeup_time: Instant) {
+        let Some(remaining) = wakeup_time.checked_duration_since(Self::get_system_time()) else {
+            return;
+        };
+        if remaining.ticks() < MINIMUM_SLEEP_TIME_US {
+            return;
+        }
+
+        // SAFETY: This is safe, because this is a single-core system, and no other references to
+        // system peripherals should exist during this call.
+        let peripherals = unsafe {
+            HAL_SYSTEM_PERIPHERALS
+                .as_mut()
+                .expect("HAL cannot be accessed before initialization")
+        };
+        let ch0 = peripherals
+            .timer_ch0
+            .as_mut()
+            .expect("sleep_until called before HAL initialization");
+
+        let sleep_ticks = remaining.ticks().min(u16::MAX as u64) as u16;
+        ch0.set_rb(ch0.counter_value().wrapping_add(sleep_ticks));
+        // Status is read to clear the flags of compare events that happened before.
+        ch0.status();
+        ch0.enable_interrupts(WAKEUP_INTERRUPTS);
+
+        // If monitor timer is started, TC0 interrupt is always unmasked, and its handler clears
+        // the flags after the sleep.
+        let monitor_started =
+            critical_section::with(|cs| MONITOR_HANDLER.borrow(cs).get().is_some());
+
+        // SAFETY: TC0 interrupt is unmasked only for the duration of the sleep, with interrupts
+        // disabled, so it only wakes the CPU up and its handler is never executed.
+        unsafe { NVIC::unmask(Interrupt::TC0) };
+        asm::dsb();
+        asm::wfi();
+
+        ch0.disable_interrupts(WAKEUP_INTERRUPTS);
+        if !monitor_started {
+            NVIC::mask(Interrupt::TC0);
+            ch0.status();
+            NVIC::unpend(Interrupt::TC0);
+        }
+    }
+
+    fn toggle_heartbeat() {
+        // SAFETY: This is safe, because this is a single-core system, and no other references to
+        // system peripherals should exist during this call.
+        let peripherals = unsafe {
+            HAL_SYSTEM_PERIPHERALS
+                .as_ref()
+                .expect("HAL cannot be accessed before initialization")
+        };
+
+        if let Some(heartbeat) = &peripherals.heartbeat {
+            heartbeat.toggle();
+        }
+    }
+
+    fn reset_system() -> ! {
+        samv71_hal::cortex_m::peripheral::SCB::sys_reset()
+    }
+
+    fn reset_cause() -> ResetCause {
+        // SAFETY: This is safe, because status register is read-only, and reading it doesn't
+        // change the state of RSTC, which is owned by the user.
+        let rstc = unsafe { &*RSTC::PTR };
+
+        match rstc.sr.read().rsttyp().variant() {
+            Some(RSTTYPSELECT_A::GENERAL_RST) => ResetCause::PowerOn,
+            Some(RSTTYPSELECT_A::BACKUP_RST) => ResetCause::Backup,
+            Some(RSTTYPSELECT_A::WDT_RST) => ResetCause::Watchdog,
+            Some(RSTTYPSELECT_A::SOFT_RST) => ResetCause::Software,
+            Some(RSTTYPSELECT_A::USER_RST) => ResetCause::External,
+            None => ResetCause::Unknown,
+        }
+    }
+
+    /// Code is stored in the general purpose backup register 6, and its complement in register 7.
+    fn store_shutdown_reason(code: u32) {
+        // SAFETY: This is safe, because backup registers used by the system are reserved for it,
+        // and writes to them don't change the state of other registers owned by the user.
+        let gpbr = unsafe { &*GPBR::PTR };
+
+        gpbr.sys_gpbr[SHUTDOWN_REASON_GPBR].write(|w| w.gpbr_value().variant(code));
+        gpbr.sys_gpbr[SHUTDOWN_REASON_GPBR + 1].write(|w| w.gpbr_value().variant(!code));
+    }
+
+    fn take_shutdown_reason() -> Option<u32> {
+        // SAFETY: This is safe, because backup registers used by the system are reserved for it,
+        // and writes to them don't change the state of other registers owned by the user.
+        let gpbr = unsafe { &*GPBR::PTR };
+
+        let code = gpbr.sys_gpbr[SHUTDOWN_REASON_GPBR]
+            .read()
+            .gpbr_value()
+            .bits();
+        let complement = gpbr.sys_gpbr[SHUTDOWN_REASON_GPBR + 1]
+            .read()
+            .gpbr_value()
+            .bits();
+        gpbr.sys_gpbr[SHUTDOWN_REASON_GPBR].reset();
+        gpbr.sys_gpbr[SHUTDOWN_REASON_GPBR + 1].reset();
+
+        (code == !complement).then_some(code)
+    }
+
+    /// Painting is inlined, so the stack is painted below the frame of the caller, from which
+    /// the measured code is called.
+    #[inline(always)]
+    fn paint_stack(depth: usize) -> Option<StackPaint> {
+        extern "C" {
+            /// Start of the heap, which is also the bottom of the stack, provided by the
+            /// `cortex-m-rt` linker script.
+            static __sheap: u32;
+        }
+
+        let top = register::msp::read() as usize & !(WORD_SIZE - 1);
+        // SAFETY: Only the address of the symbol is taken, its value is never read.
+        let stack_bottom = unsafe { &__sheap as *const u32 } as usize;
+        let bottom = top
+            .saturating_sub(depth & !(WORD_SIZE - 1))
+            .max(stack_bottom);
+
+        for address in (bottom..top).step_by(WORD_SIZE) {
+            // SAFETY: This is safe, because the region lies between the bottom of the stack and
+            // the stack pointer of this function, so it's not used by anything. Interrupts that
+            // happen during painting push their frames below the stack pointer, which only
+            // overwrites the paint.
+            unsafe { core::ptr::write_volatile(address as *mut u32, StackPaint::PATTERN) };
+        }
+
+        Some(StackPaint { top, bottom })
+    }
+
+    fn stack_usage(paint: &StackPaint) -> usize {
+        let untouched = (paint.bottom..paint.top)
+            .step_by(WORD_SIZE)
+            // SAFETY: This is safe, because the address is aligned and lies within the stack.
+            .take_while(|&address| unsafe {
+                core::ptr::read_volatile(address as *const u32) == StackPaint::PATTERN
+            })
+            .count();
+
+        paint.size() - untouched * WORD_SIZE
+    }
+
+    fn firmware_image_start() -> Option<*const u8> {
+        extern "C" {
+            /// Vector table, which is placed at the start of the image by the `cortex-m-rt` linker
+            /// script.
+            static __vector_table: u32;
+        }
+
+        // SAFETY: Only the address of the symbol is taken, its value is never read.
+        Some(unsafe { &__vector_table as *const u32 } as *const u8)
+    }
+
+    fn wakeup_jitter(_period: Duration) -> Duration {
+        // Timing variation is a test feature, wakeups on the hardware are never delayed.
+        Duration::from_ticks(0)
+    }
+
+    fn in_interrupt() -> bool {
+        samv71_hal::cortex_m::peripheral::SCB::vect_active() != VectActive::ThreadMode
+    }
+
+    /// Handler is called from TC0 interrupt, on RC compare of channel 0 which happens every
+    /// 65.536 ms. Interrupt handler is defined by the HAL only with `rt` feature.
+    fn start_monitor_timer(handler: fn()) -> Result<(), HalError> {
+        if cfg!(not(feature = "rt")) {
+            return Err(HalError::MonitorTimerUnavailable);
+        }
+
+        critical_section::with(|cs| {
+            // SAFETY: This is safe, because we're in critical section of single-core MCU and no
+            // other references to system peripherals should exist at this time.
+            let peripherals =
+                unsafe { HAL_SYSTEM_PERIPHERALS.as_mut() }.ok_or(HalError::HalNotInitialized)?;
+            let ch0 = peripherals
+                .timer_ch0
+                .as_mut()
+                .ok_or(HalError::HalNotInitialized)?;
+
+            MONITOR_HANDLER.borrow(cs).set(Some(handler));
+            ch0.enable_interrupts(MONITOR_INTERRUPTS);
+            // SAFETY: TC0 interrupt handler only clears the status flags and calls the monitor
+            // handler, it doesn't access system peripherals used by other HAL functions.
+            unsafe { NVIC::unmask(Interrupt::TC0) };
+
+            Ok(())
+        })
+    }
+}
+
+/// System timer interrupt handler, calling the monitor handler.
+#[cfg(feature = "rt")]
+#[interrupt]
+fn TC0() {
+    // SAFETY: This is safe, because only status register of channel 0 is read, which clears its
+    // flags. Channel 0 flags are used only by this handler and by the sleep, which runs with
+    // interrupts disabled.
+    let tc0 = unsafe { &*TC0::PTR };
+    tc0.tc_channel0.sr.read();
+
+    if let Some(handler) = critical_section::with(|cs| MONITOR_HANDLER.borrow(cs).get()) {
+        handler();
+    }
 }
 
 /// Type representing all TC0 channels in Waveform mode.
@@ -306,7 +636,15 @@ fn configure_timer(timer: &mut Timer<TC0>, pmc: &mut PMC) -> Tc0Channels {
         ..Default::default()
     };
 
-    let mut ch0 = ch0.into_waveform_channel(waveform_config);
+    // Channel 0 uses RB compare to wake up the CPU from sleep, which requires TIOB0 to be an
+    // output, so XC0 is selected as unused external event instead of TIOB0.
+    let mut ch0 = ch0.into_waveform_channel(WaveformModeConfig {
+        external_event: ExternalEventConfig {
+            signal: ExternalEventSignal::XC0,
+            ..Default::default()
+        },
+        ..waveform_config
+    });
     let mut ch1 = ch1.into_waveform_channel(waveform_config);
     let mut ch2 = ch2.into_waveform_channel(waveform_config);
 
@@ -0,0 +1,80 @@
+//! Heartbeat output pin.
+
+use samv71_hal::pac::{self, pioa::RegisterBlock};
+use samv71_hal::pmc::config::PeripheralId;
+
+use crate::error::HalError;
+
+/// Heartbeat output pin.
+///
+/// Pin is controlled directly through PIO registers, as the port it belongs to is given to the
+/// user in [`UserPeripherals`](crate::UserPeripherals). Only the heartbeat pin's bit is ever
+/// written, so other pins of the port can be safely used by the user.
+pub(crate) struct HeartbeatPin {
+    /// Registers of the pin's port.
+    registers: *const RegisterBlock,
+    /// Bit mask of the pin.
+    mask: u32,
+}
+
+impl HeartbeatPin {
+    /// Creates new heartbeat pin.
+    ///
+    /// # Parameters
+    /// * `port` - ID of the port, `'A'` to `'E'`.
+    /// * `pin` - Number of the pin in the port, `0` to `31`.
+    ///
+    /// # Return
+    /// Heartbeat pin and ID of its port's peripheral clock, or [`HalError::InvalidHeartbeatPin`]
+    /// if port or pin doesn't exist.
+    pub(crate) fn new(port: char, pin: u8) -> Result<(Self, PeripheralId), HalError> {
+        let (registers, peripheral_id) = match port {
+            'A' => (pac::PIOA::PTR, PeripheralId::PIOA),
+            'B' => (pac::PIOB::PTR, PeripheralId::PIOB),
+            'C' => (pac::PIOC::PTR, PeripheralId::PIOC),
+            'D' => (pac::PIOD::PTR, PeripheralId::PIOD),
+            'E' => (pac::PIOE::PTR, PeripheralId::PIOE),
+            _ => return Err(HalError::InvalidHeartbeatPin),
+        };
+
+        if pin > 31 {
+            return Err(HalError::InvalidHeartbeatPin);
+        }
+
+        let heartbeat = HeartbeatPin {
+            registers,
+            mask: 1 << pin,
+        };
+
+        Ok((heartbeat, peripheral_id))
+    }
+
+    /// Configures the pin as PIO-controlled output.
+    ///
+    /// Port's peripheral clock has to be enabled before calling this.
+    pub(crate) fn configure(&self) {
+        let registers = self.registers();
+
+        // SAFETY: Only the heartbeat pin's bit is written.
+        registers.per.write(|w| unsafe { w.bits(self.mask) });
+        registers.oer.write(|w| unsafe { w.bits(self.mask) });
+    }
+
+    /// Toggles the pin state.
+    pub(crate) fn toggle(&self) {
+        let registers = self.registers();
+
+        // SAFETY: Only the heartbeat pin's bit is written.
+        if registers.odsr.read().bits() & self.mask != 0 {
+            registers.codr.write(|w| unsafe { w.bits(self.mask) });
+        } else {
+            registers.sodr.write(|w| unsafe { w.bits(self.mask) });
+        }
+    }
+
+    /// Returns reference to the pin's port registers.
+    fn registers(&self) -> &RegisterBlock {
+        // SAFETY: Pointer is one of PAC's PIO register blocks, which are always valid.
+        unsafe { &*self.registers }
+    }
+}
@@ -6,16 +6,20 @@ SAMV71 implementation of aerugo HAL.
 #![warn(clippy::missing_docs_in_private_items)]
 #![warn(rustdoc::missing_crate_level_docs)]
 
+mod fpu_policy;
+mod heartbeat;
 mod system_peripherals;
 
 pub mod error;
+#[cfg(feature = "pio")]
+pub mod external_watchdog;
 pub mod hal;
 pub mod user_peripherals;
 
 pub use hal::Hal;
 pub use samv71_hal as drivers;
 pub use samv71_hal::cortex_m;
-pub use user_peripherals::UserPeripherals;
+pub use user_peripherals::{PeripheralOwnership, UserPeripherals};
 
 #[cfg(feature = "rt")]
 /// Macro for interrupt handlers.
@@ -1,5 +1,8 @@
 //! Module representing peripherals internally used by Aerugo.
 
+use aerugo_hal::FpuContextPolicy;
+
+use crate::heartbeat::HeartbeatPin;
 use samv71_hal::pac::TC0;
 use samv71_hal::pmc::PMC;
 use samv71_hal::{
@@ -23,4 +26,8 @@ pub struct SystemPeripherals {
     pub timer_ch2: Option<Channel<TC0, Ch2, Waveform>>,
     /// PMC instance. This will be stored only temporarily here, between HAL init and system config
     pub pmc: Option<PMC>,
+    /// Heartbeat output pin, if configured.
+    pub heartbeat: Option<HeartbeatPin>,
+    /// Configured FPU context policy.
+    pub fpu_context_policy: FpuContextPolicy,
 }
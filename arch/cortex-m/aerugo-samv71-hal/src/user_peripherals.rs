@@ -1,8 +1,18 @@
 //! Module representing user-accessible peripherals.
+//!
+//! Peripherals taken with [`take_peripheral`](crate::take_peripheral) macro are recorded together
+//! with the name of the component that took them and the time when it happened. Taking such
+//! peripheral again panics with the name of its owner, instead of a generic message, and ownership
+//! of all recorded peripherals can be inspected with [`UserPeripherals::ownership`].
+
+use aerugo_hal::{AerugoHal, Instant};
+
+use crate::Hal;
 
 pub use samv71_hal::pac::{
-    CHIPID, CPUID, FPU, NVIC, PIOA, PIOB, PIOC, PIOD, PIOE, SCB, SPI0, SPI1, SYST, TC1, TC2, TC3,
-    UART0, UART1, UART2, UART3, UART4, XDMAC,
+    AFEC0, AFEC1, CHIPID, CPUID, DACC, FPU, GMAC, GPBR, MCAN0, MCAN1, MPU, NVIC, PIOA, PIOB, PIOC,
+    PIOD, PIOE, RSTC, SCB, SPI0, SPI1, SYST, TC1, TC2, TC3, UART0, UART1, UART2, UART3, UART4,
+    XDMAC,
 };
 pub use samv71_hal::pmc::PMC;
 
@@ -13,12 +23,29 @@ pub use samv71_hal::pmc::PMC;
 /// PAC instances, as they are core components that most applications will have
 /// to create instances of, and use.
 pub struct UserPeripherals {
+    /// Analog Front-End Controller 0.
+    pub afec_0: Option<AFEC0>,
+    /// Analog Front-End Controller 1.
+    pub afec_1: Option<AFEC1>,
     /// Chip ID.
     pub chip_id: Option<CHIPID>,
     /// CPU ID, required for some SCB-related operations.
     pub cpu_id: Option<CPUID>,
+    /// Digital-to-Analog Converter Controller.
+    pub dacc: Option<DACC>,
     /// FPU
     pub fpu: Option<FPU>,
+    /// Ethernet MAC.
+    pub gmac: Option<GMAC>,
+    /// General purpose backup registers. Registers 6 and 7 are reserved for the shutdown reason
+    /// stored by the system.
+    pub gpbr: Option<GPBR>,
+    /// MCAN 0
+    pub mcan_0: Option<MCAN0>,
+    /// MCAN 1
+    pub mcan_1: Option<MCAN1>,
+    /// MPU
+    pub mpu: Option<MPU>,
     /// NVIC
     pub nvic: Option<NVIC>,
     /// I/O Port A.
@@ -34,6 +61,8 @@ pub struct UserPeripherals {
     /// Clocks controller.
     /// This is HAL driver instance that provides abstraction over PMC.
     pub pmc: Option<PMC>,
+    /// Reset controller.
+    pub rstc: Option<RSTC>,
     /// System Control Block
     pub scb: Option<SCB>,
     /// SPI 0
@@ -60,4 +89,114 @@ pub struct UserPeripherals {
     pub uart_4: Option<UART4>,
     /// XDMAC
     pub xdmac: Option<XDMAC>,
+    /// Records of the peripherals taken with [`take_peripheral`](crate::take_peripheral).
+    pub(crate) ownership: [Option<PeripheralOwnership>; PERIPHERAL_COUNT],
+}
+
+/// Number of the peripherals in [`UserPeripherals`].
+pub(crate) const PERIPHERAL_COUNT: usize = 32;
+
+/// Record of the peripheral taken from [`UserPeripherals`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct PeripheralOwnership {
+    /// Name of the peripheral field.
+    pub peripheral: &'static str,
+    /// Name of the component that took the peripheral.
+    pub owner: &'static str,
+    /// System time when the peripheral was taken.
+    pub taken_at: Instant,
+}
+
+impl UserPeripherals {
+    /// Takes peripheral and records its owner.
+    ///
+    /// This is used by [`take_peripheral`](crate::take_peripheral) macro, which should be used
+    /// instead of calling this function directly.
+    ///
+    /// # Parameters
+    /// * `peripheral` - Name of the peripheral field.
+    /// * `owner` - Name of the component taking the peripheral.
+    /// * `field` - Function returning the peripheral field.
+    ///
+    /// # Return
+    /// Taken peripheral.
+    ///
+    /// # Panics
+    /// When the peripheral was already taken. Panic message contains the owner of the peripheral
+    /// if it was taken with [`take_peripheral`](crate::take_peripheral) macro.
+    pub fn take_tracked<T>(
+        &mut self,
+        peripheral: &'static str,
+        owner: &'static str,
+        field: impl FnOnce(&mut Self) -> &mut Option<T>,
+    ) -> T {
+        if let Some(value) = field(self).take() {
+            let record = PeripheralOwnership {
+                peripheral,
+                owner,
+                taken_at: Hal::get_system_time(),
+            };
+
+            // There is a slot for each peripheral, and each of them can be taken only once.
+            if let Some(slot) = self.ownership.iter_mut().find(|slot| slot.is_none()) {
+                *slot = Some(record);
+            }
+
+            return value;
+        }
+
+        match self.owner_of(peripheral) {
+            Some(record) => panic!(
+                "{} requested by {} was already taken by {} at {} us",
+                peripheral,
+                owner,
+                record.owner,
+                record.taken_at.duration_since_epoch().to_micros()
+            ),
+            None => panic!(
+                "{} requested by {} was already taken by an unknown owner",
+                peripheral, owner
+            ),
+        }
+    }
+
+    /// Returns record of the given peripheral.
+    ///
+    /// # Parameters
+    /// * `peripheral` - Name of the peripheral field (ex. `"timer_counter1"`).
+    ///
+    /// # Return
+    /// Ownership record, `None` if peripheral wasn't taken with
+    /// [`take_peripheral`](crate::take_peripheral) macro.
+    pub fn owner_of(&self, peripheral: &str) -> Option<&PeripheralOwnership> {
+        self.ownership()
+            .find(|record| record.peripheral == peripheral)
+    }
+
+    /// Returns records of all peripherals taken with [`take_peripheral`](crate::take_peripheral)
+    /// macro, in the order in which they were taken.
+    pub fn ownership(&self) -> impl Iterator<Item = &PeripheralOwnership> {
+        self.ownership.iter().flatten()
+    }
+}
+
+/// Takes peripheral from [`UserPeripherals`] and records its owner.
+///
+/// # Parameters
+/// * `peripherals` - User peripherals.
+/// * `field` - Name of the peripheral field.
+/// * `owner` - Name of the component taking the peripheral.
+///
+/// # Panics
+/// When the peripheral was already taken, with the name of its owner.
+///
+/// # Example
+/// ```ignore
+/// let timer = Timer::new(take_peripheral!(peripherals.timer_counter1, "TimerTask"));
+/// ```
+#[macro_export]
+macro_rules! take_peripheral {
+    ($peripherals:ident . $field:ident, $owner:expr) => {
+        $peripherals.take_tracked(stringify!($field), $owner, |p| &mut p.$field)
+    };
 }
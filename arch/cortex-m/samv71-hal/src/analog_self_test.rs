@@ -0,0 +1,269 @@
+//! Analog loopback self-test.
+//!
+//! Self-test drives known values on DACC output and verifies that AFEC reads them back within
+//! tolerance. It requires the DACC output to be wired to the AFEC input (directly on the board, or
+//! with a test jumper), so it can only be used when board wiring permits.
+//!
+//! It's intended to be run once at startup, as a part of power-on self-test, before the analog
+//! peripherals are configured for the application. Both peripherals are reset by the self-test
+//! and left disabled afterwards.
+//!
+//! # Clocks
+//! Peripheral clocks of the AFEC and DACC must be enabled with
+//! [`PMC::enable_peripheral_clock`](crate::pmc::PMC::enable_peripheral_clock) before running the
+//! self-test.
+
+use core::ops::Deref;
+
+use crate::pac::afec0::emr::{RESSELECT_A, SIGNMODESELECT_A};
+use crate::pac::afec0::mr::STARTUPSELECT_A;
+use crate::pac::afec0::RegisterBlock as AfecRegisterBlock;
+use crate::pac::DACC;
+
+/// Number of AFEC channels.
+const AFEC_CHANNEL_COUNT: u8 = 12;
+/// Maximum value of 12-bit conversion.
+const MAX_CONVERSION_VALUE: u16 = 0x0FFF;
+/// AFEC analog offset centering single-ended conversion range.
+const AFEC_SINGLE_ENDED_OFFSET: u16 = 0x200;
+/// AFEC bias current control, recommended by the datasheet.
+const AFEC_BIAS_CURRENT: u8 = 0b01;
+/// DACC bias current control, for conversion rate up to 1MHz.
+const DACC_BIAS_CURRENT: u8 = 0b10;
+/// Number of status register reads after which waiting for the peripheral is abandoned.
+const TIMEOUT_ITERATIONS: u32 = 100_000;
+
+/// DACC output channel.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum DaccChannel {
+    /// DACC channel 0 (DAC0 pin).
+    Channel0,
+    /// DACC channel 1 (DAC1 pin).
+    Channel1,
+}
+
+/// Analog loopback self-test configuration.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct LoopbackConfig {
+    /// DACC channel driving the loopback.
+    pub dacc_channel: DaccChannel,
+    /// AFEC channel connected to the DACC channel (0-11).
+    pub afec_channel: u8,
+    /// Values written to the DACC, 12-bit.
+    pub test_points: &'static [u16],
+    /// Maximum allowed difference between written and read value, in LSB.
+    pub tolerance: u16,
+    /// AFEC clock prescaler. AFEC clock is peripheral clock divided by `afec_prescaler + 1`, and
+    /// must be in the range specified in the datasheet.
+    pub afec_prescaler: u8,
+    /// DACC clock prescaler. DACC clock is peripheral clock divided by `dacc_prescaler + 2`, and
+    /// must be in the range specified in the datasheet.
+    pub dacc_prescaler: u8,
+}
+
+impl LoopbackConfig {
+    /// Default set of test points, spread over the conversion range with margin at both ends.
+    pub const DEFAULT_TEST_POINTS: [u16; 5] = [0x0200, 0x0600, 0x0800, 0x0A00, 0x0E00];
+}
+
+/// Enumeration listing analog self-test errors.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum LoopbackError {
+    /// Specified AFEC channel doesn't exist.
+    InvalidChannel,
+    /// Test point is out of 12-bit range.
+    InvalidTestPoint(u16),
+    /// Peripheral didn't finish the operation in expected time.
+    Timeout,
+    /// Read back value differs from the written one by more than the tolerance.
+    OutOfTolerance {
+        /// Value written to the DACC.
+        expected: u16,
+        /// Value read from the AFEC.
+        measured: u16,
+    },
+}
+
+/// Result of successful analog self-test.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct LoopbackReport {
+    /// Number of test points that were checked.
+    pub test_point_count: usize,
+    /// Largest difference between written and read value, in LSB.
+    pub max_deviation: u16,
+}
+
+/// Runs analog loopback self-test.
+///
+/// Each test point is written to the DACC, converted by the AFEC and compared with the written
+/// value. Test stops at the first failing test point.
+///
+/// # Parameters
+/// * `afec` - PAC AFEC instance connected to the DACC output.
+/// * `dacc` - PAC DACC instance.
+/// * `config` - Self-test configuration.
+///
+/// # Return
+/// [`LoopbackReport`] if all test points were read back within tolerance, [`LoopbackError`]
+/// otherwise.
+pub fn analog_loopback_self_test<Afec: Deref<Target = AfecRegisterBlock>>(
+    afec: &Afec,
+    dacc: &DACC,
+    config: &LoopbackConfig,
+) -> Result<LoopbackReport, LoopbackError> {
+    if config.afec_channel >= AFEC_CHANNEL_COUNT {
+        return Err(LoopbackError::InvalidChannel);
+    }
+    if let Some(&point) = config
+        .test_points
+        .iter()
+        .find(|&&point| point > MAX_CONVERSION_VALUE)
+    {
+        return Err(LoopbackError::InvalidTestPoint(point));
+    }
+
+    configure_dacc(dacc, config)?;
+    configure_afec(afec, config);
+
+    let result = config
+        .test_points
+        .iter()
+        .try_fold(0, |max_deviation, &expected| {
+            write_dacc(dacc, config.dacc_channel, expected)?;
+            let measured = convert(afec, config.afec_channel)?;
+
+            let deviation = expected.abs_diff(measured);
+            if deviation > config.tolerance {
+                return Err(LoopbackError::OutOfTolerance { expected, measured });
+            }
+
+            Ok(max_deviation.max(deviation))
+        });
+
+    afec.cr.write(|w| w.swrst().set_bit());
+    dacc.cr.write(|w| w.swrst().set_bit());
+
+    result.map(|max_deviation| LoopbackReport {
+        test_point_count: config.test_points.len(),
+        max_deviation,
+    })
+}
+
+/// Resets DACC and enables the test channel in free-running mode.
+fn configure_dacc(dacc: &DACC, config: &LoopbackConfig) -> Result<(), LoopbackError> {
+    dacc.cr.write(|w| w.swrst().set_bit());
+    dacc.mr.write(|w| {
+        w.maxs0()
+            .trig_event()
+            .maxs1()
+            .trig_event()
+            .word()
+            .disabled()
+            .diff()
+            .disabled()
+            .prescaler()
+            .variant(config.dacc_prescaler)
+    });
+    dacc.trigr.write(|w| w.trgen0().dis().trgen1().dis());
+    dacc.acr.write(|w| {
+        w.ibctlch0()
+            .variant(DACC_BIAS_CURRENT)
+            .ibctlch1()
+            .variant(DACC_BIAS_CURRENT)
+    });
+
+    match config.dacc_channel {
+        DaccChannel::Channel0 => {
+            dacc.cher.write(|w| w.ch0().set_bit());
+            wait_for(|| dacc.chsr.read().dacrdy0().bit_is_set())
+        }
+        DaccChannel::Channel1 => {
+            dacc.cher.write(|w| w.ch1().set_bit());
+            wait_for(|| dacc.chsr.read().dacrdy1().bit_is_set())
+        }
+    }
+}
+
+/// Resets AFEC and enables the test channel for software triggered single-ended conversions.
+fn configure_afec(afec: &AfecRegisterBlock, config: &LoopbackConfig) {
+    let channel_mask = 1 << config.afec_channel;
+
+    afec.cr.write(|w| w.swrst().set_bit());
+    afec.mr.write(|w| {
+        w.trgen()
+            .dis()
+            .freerun()
+            .off()
+            .prescal()
+            .variant(config.afec_prescaler)
+            .startup()
+            .variant(STARTUPSELECT_A::SUT64)
+            .one()
+            .set_bit() // Must be written to 1
+            .tracktim()
+            .variant(15)
+            .transfer()
+            .variant(2)
+    });
+    afec.emr.write(|w| {
+        w.res()
+            .variant(RESSELECT_A::NO_AVERAGE)
+            .tag()
+            .set_bit()
+            .stm()
+            .set_bit()
+            .signmode()
+            .variant(SIGNMODESELECT_A::ALL_UNSIGNED)
+    });
+    afec.acr.write(|w| {
+        w.pga0en()
+            .set_bit()
+            .pga1en()
+            .set_bit()
+            .ibctl()
+            .variant(AFEC_BIAS_CURRENT)
+    });
+    afec.cselr.write(|w| w.csel().variant(config.afec_channel));
+    afec.cocr
+        .write(|w| w.aoff().variant(AFEC_SINGLE_ENDED_OFFSET));
+    // SAFETY: Only the bit of the valid test channel is set.
+    afec.cher.write(|w| unsafe { w.bits(channel_mask) });
+}
+
+/// Writes value to the DACC and waits until it's converted.
+fn write_dacc(dacc: &DACC, channel: DaccChannel, value: u16) -> Result<(), LoopbackError> {
+    match channel {
+        DaccChannel::Channel0 => {
+            wait_for(|| dacc.isr.read().txrdy0().bit_is_set())?;
+            dacc.cdr[0].write(|w| w.data0().variant(value));
+            wait_for(|| dacc.isr.read().eoc0().bit_is_set())
+        }
+        DaccChannel::Channel1 => {
+            wait_for(|| dacc.isr.read().txrdy1().bit_is_set())?;
+            dacc.cdr[1].write(|w| w.data0().variant(value));
+            wait_for(|| dacc.isr.read().eoc1().bit_is_set())
+        }
+    }
+}
+
+/// Starts AFEC conversion and returns its result.
+fn convert(afec: &AfecRegisterBlock, channel: u8) -> Result<u16, LoopbackError> {
+    let channel_mask = 1 << channel;
+
+    afec.cr.write(|w| w.start().set_bit());
+    wait_for(|| afec.isr.read().bits() & channel_mask != 0)?;
+
+    Ok(afec.cdr.read().data().bits() & MAX_CONVERSION_VALUE)
+}
+
+/// Waits until the condition is met.
+///
+/// # Return
+/// [`LoopbackError::Timeout`] if the condition wasn't met in [`TIMEOUT_ITERATIONS`] checks.
+fn wait_for(mut condition: impl FnMut() -> bool) -> Result<(), LoopbackError> {
+    if (0..TIMEOUT_ITERATIONS).any(|_| condition()) {
+        Ok(())
+    } else {
+        Err(LoopbackError::Timeout)
+    }
+}
@@ -0,0 +1,214 @@
+//! Worst-case durations of blocking driver calls.
+//!
+//! Blocking driver calls busy-wait on peripheral status, with the number of status checks limited
+//! by the timeout passed by the caller. Entry points listed here are annotated with their
+//! worst-case duration in CPU cycles, split into a fixed part and a part per status check, so the
+//! duration of a call can be budgeted from its timeout when composing tasklet steps.
+//!
+//! Bounds are conservative estimates. With `call-bounds` feature enabled, each annotated call is
+//! measured with the DWT cycle counter, and statistics of the calls (with the number of calls that
+//! exceeded their bound) are collected, so the bounds can be verified on the target, with the
+//! application's clock configuration and bus load. Cycle counter has to be
+//! enabled with `enable` first, calls made before that are not measured.
+
+#[cfg(feature = "call-bounds")]
+use core::cell::RefCell;
+
+#[cfg(feature = "call-bounds")]
+use cortex_m::interrupt::Mutex;
+#[cfg(feature = "call-bounds")]
+use cortex_m::peripheral::{DCB, DWT};
+
+/// Worst-case duration of a blocking driver call.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct CallBound {
+    /// Name of the driver entry point.
+    pub name: &'static str,
+    /// Worst-case number of CPU cycles spent outside of the status checks.
+    pub fixed_cycles: u32,
+    /// Worst-case number of CPU cycles of a single status check.
+    pub cycles_per_check: u32,
+    /// Index of the bound in [`BOUNDS`].
+    index: usize,
+}
+
+impl CallBound {
+    /// Returns worst-case duration of the call.
+    ///
+    /// # Parameters
+    /// * `checks` - Maximum number of status checks made by the call.
+    ///
+    /// # Returns
+    /// Worst-case number of CPU cycles.
+    pub const fn worst_case_cycles(&self, checks: u32) -> u32 {
+        self.fixed_cycles
+            .saturating_add(self.cycles_per_check.saturating_mul(checks))
+    }
+}
+
+/// Bound of [`uart::Writer::transmit_byte`](crate::uart::writer::Writer::transmit_byte), checking the
+/// status up to `timeout` times.
+pub const UART_TRANSMIT_BYTE: CallBound = CallBound {
+    name: "uart::Writer::transmit_byte",
+    fixed_cycles: 60,
+    cycles_per_check: 40,
+    index: 0,
+};
+
+/// Bound of [`uart::Writer::transmit_bytes`](crate::uart::writer::Writer::transmit_bytes), checking the
+/// status up to `timeout * (bytes.len() + 2)` times.
+pub const UART_TRANSMIT_BYTES: CallBound = CallBound {
+    name: "uart::Writer::transmit_bytes",
+    fixed_cycles: 100,
+    cycles_per_check: 40,
+    index: 1,
+};
+
+/// Bound of [`uart::Writer::flush`](crate::uart::writer::Writer::flush), checking the status up to
+/// `timeout` times.
+pub const UART_FLUSH: CallBound = CallBound {
+    name: "uart::Writer::flush",
+    fixed_cycles: 40,
+    cycles_per_check: 40,
+    index: 2,
+};
+
+/// Bound of [`uart::Reader::receive_byte`](crate::uart::reader::Reader::receive_byte), checking the status
+/// up to `timeout` times.
+pub const UART_RECEIVE_BYTE: CallBound = CallBound {
+    name: "uart::Reader::receive_byte",
+    fixed_cycles: 60,
+    cycles_per_check: 40,
+    index: 3,
+};
+
+/// Bound of [`Watchdog::feed`](crate::watchdog::Watchdog::feed), which doesn't check the status.
+pub const WATCHDOG_FEED: CallBound = CallBound {
+    name: "watchdog::Watchdog::feed",
+    fixed_cycles: 40,
+    cycles_per_check: 0,
+    index: 4,
+};
+
+/// All annotated driver entry points.
+pub const BOUNDS: [CallBound; 5] = [
+    UART_TRANSMIT_BYTE,
+    UART_TRANSMIT_BYTES,
+    UART_FLUSH,
+    UART_RECEIVE_BYTE,
+    WATCHDOG_FEED,
+];
+
+/// Statistics of the measured calls of a driver entry point.
+#[cfg(feature = "call-bounds")]
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct CallStats {
+    /// Name of the driver entry point.
+    pub name: &'static str,
+    /// Number of measured calls.
+    pub call_count: u32,
+    /// Longest measured call in CPU cycles.
+    pub max_cycles: u32,
+    /// Number of calls that exceeded their worst-case duration.
+    pub violation_count: u32,
+    /// Largest excess over the worst-case duration in CPU cycles.
+    pub max_excess_cycles: u32,
+}
+
+#[cfg(feature = "call-bounds")]
+impl CallStats {
+    /// Creates empty statistics.
+    ///
+    /// # Parameters
+    /// * `name` - Name of the driver entry point.
+    const fn new(name: &'static str) -> Self {
+        CallStats {
+            name,
+            call_count: 0,
+            max_cycles: 0,
+            violation_count: 0,
+            max_excess_cycles: 0,
+        }
+    }
+}
+
+/// Statistics of all annotated driver entry points.
+#[cfg(feature = "call-bounds")]
+static STATS: Mutex<RefCell<[CallStats; BOUNDS.len()]>> = Mutex::new(RefCell::new(empty_stats()));
+
+/// Creates empty statistics of all annotated driver entry points.
+#[cfg(feature = "call-bounds")]
+const fn empty_stats() -> [CallStats; BOUNDS.len()] {
+    let mut stats = [CallStats::new(""); BOUNDS.len()];
+    let mut index = 0;
+    while index < BOUNDS.len() {
+        stats[index] = CallStats::new(BOUNDS[index].name);
+        index += 1;
+    }
+    stats
+}
+
+/// Enables the DWT cycle counter used to measure driver calls.
+///
+/// # Parameters
+/// * `dcb` - Debug Control Block, used to enable the trace unit.
+/// * `dwt` - Data Watchpoint and Trace unit.
+#[cfg(feature = "call-bounds")]
+pub fn enable(dcb: &mut DCB, dwt: &mut DWT) {
+    dcb.enable_trace();
+    dwt.enable_cycle_counter();
+}
+
+/// Returns statistics of all annotated driver entry points, in the order of [`BOUNDS`].
+#[cfg(feature = "call-bounds")]
+pub fn stats() -> [CallStats; BOUNDS.len()] {
+    cortex_m::interrupt::free(|cs| *STATS.borrow(cs).borrow())
+}
+
+/// Clears statistics of all annotated driver entry points.
+#[cfg(feature = "call-bounds")]
+pub fn reset_stats() {
+    cortex_m::interrupt::free(|cs| *STATS.borrow(cs).borrow_mut() = empty_stats())
+}
+
+/// Executes the driver call, measuring its duration against the bound.
+///
+/// # Parameters
+/// * `bound` - Bound of the call.
+/// * `checks` - Maximum number of status checks made by the call.
+/// * `call` - Body of the call.
+#[cfg(feature = "call-bounds")]
+pub(crate) fn measure<R>(bound: &CallBound, checks: u32, call: impl FnOnce() -> R) -> R {
+    if !DWT::cycle_counter_enabled() {
+        return call();
+    }
+
+    let start = DWT::cycle_count();
+    let result = call();
+    let cycles = DWT::cycle_count().wrapping_sub(start);
+
+    let worst_case = bound.worst_case_cycles(checks);
+    cortex_m::interrupt::free(|cs| {
+        let stats = &mut STATS.borrow(cs).borrow_mut()[bound.index];
+        stats.call_count = stats.call_count.saturating_add(1);
+        stats.max_cycles = stats.max_cycles.max(cycles);
+        if cycles > worst_case {
+            stats.violation_count = stats.violation_count.saturating_add(1);
+            stats.max_excess_cycles = stats.max_excess_cycles.max(cycles - worst_case);
+        }
+    });
+
+    result
+}
+
+/// Executes the driver call. Calls are measured only with `call-bounds` feature enabled.
+///
+/// # Parameters
+/// * `bound` - Bound of the call.
+/// * `checks` - Maximum number of status checks made by the call.
+/// * `call` - Body of the call.
+#[cfg(not(feature = "call-bounds"))]
+#[inline(always)]
+pub(crate) fn measure<R>(_bound: &CallBound, _checks: u32, call: impl FnOnce() -> R) -> R {
+    call()
+}
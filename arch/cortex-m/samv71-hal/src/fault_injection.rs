@@ -0,0 +1,63 @@
+//! Fault injection functions.
+//!
+//! These functions deliberately trigger hardware faults, so it's possible to demonstrate on the
+//! target hardware that protective mechanisms (MPU, bus error detection) actually work.
+//! They are intended only for self-test applications and should never be used in flight code.
+//!
+//! # Fault handlers
+//! Triggered faults are handled by `MemoryManagement` and `BusFault` exception handlers, if they
+//! are enabled in SCB (ex. with `SCB::enable`), or escalated to `HardFault` otherwise.
+//! Returning from the handler will re-execute the faulting instruction, so handlers used during
+//! fault injection should never return (ex. they should reset the MCU).
+
+use core::ptr::read_volatile;
+
+use crate::mpu::{Mpu, MpuError, RegionSize};
+
+/// Address that is not mapped to any memory or peripheral.
+///
+/// This is the first address after the end of internal SRAM.
+pub const UNMAPPED_ADDRESS: u32 = 0x2046_0000;
+
+/// Memory area used as a target of MPU fault injection.
+#[repr(C, align(32))]
+struct GuardTarget([u32; 8]);
+
+/// Target of MPU fault injection.
+static GUARD_TARGET: GuardTarget = GuardTarget([0; 8]);
+
+/// Triggers MPU violation.
+///
+/// This function configures no-access guard region over internal memory area, enables the MPU
+/// and then reads from that area, triggering MemManage fault.
+///
+/// # Parameters
+/// * `mpu` - MPU driver.
+/// * `region` - Number of the region used for the guard.
+///
+/// # Return
+/// [`MpuError`] if guard region could not be configured. If the function returns `Ok(())`,
+/// the access was not trapped and MPU protection doesn't work.
+pub fn trigger_mpu_fault(mpu: &mut Mpu, region: u8) -> Result<(), MpuError> {
+    let target_address = &GUARD_TARGET as *const GuardTarget as u32;
+
+    mpu.configure_guard_region(region, target_address, RegionSize::Size32B)?;
+    mpu.enable(true, false);
+
+    // SAFETY: This is safe, because the target is a valid static memory. Access is expected to be
+    // trapped by the MPU.
+    let _ = unsafe { read_volatile(&GUARD_TARGET.0[0]) };
+
+    Ok(())
+}
+
+/// Triggers bus fault.
+///
+/// This function reads from [`UNMAPPED_ADDRESS`], which causes bus error.
+///
+/// # Safety
+/// This function performs invalid memory access on purpose. It's only safe to call if fault
+/// handler is prepared to handle it, and never returns to the faulting code.
+pub unsafe fn trigger_bus_fault() {
+    let _ = read_volatile(UNMAPPED_ADDRESS as *const u32);
+}
@@ -1,6 +1,7 @@
 //! Floating Point Unit HAL driver implementation.
 
 use cortex_m::asm::{dsb, isb};
+use cortex_m::peripheral::scb::FpuAccessMode;
 use cortex_m::register::fpscr::{read as read_fpscr, write as write_fpscr};
 use samv71q21_pac::{FPU, SCB};
 
@@ -49,11 +50,57 @@ impl Fpu {
         isb();
     }
 
+    /// Returns `true` if FPU is enabled.
+    pub fn is_enabled(&self) -> bool {
+        SCB::fpu_access_mode() != FpuAccessMode::Disabled
+    }
+
     /// Returns `true` if lazy stacking feature is enabled.
     pub fn is_lazy_stacking_enabled(&self) -> bool {
         self.fpu.fpccr.read() & FPU_FPCCR_LSPEN_MASK != 0
     }
 
+    /// Enables or disables lazy stacking feature.
+    ///
+    /// With lazy stacking enabled, space for FPU context is reserved on exception entry, but the
+    /// registers are saved only if the exception handler uses the FPU.
+    pub fn set_lazy_stacking(&mut self, enabled: bool) {
+        unsafe {
+            self.fpu.fpccr.modify(|reg| {
+                if enabled {
+                    reg | FPU_FPCCR_LSPEN_MASK
+                } else {
+                    reg & !FPU_FPCCR_LSPEN_MASK
+                }
+            })
+        };
+        dsb();
+        isb();
+    }
+
+    /// Returns `true` if FPU context is preserved on exception entry.
+    pub fn is_context_preserved_on_exception(&self) -> bool {
+        self.fpu.fpccr.read() & FPU_FPCCR_ASPEN_MASK != 0
+    }
+
+    /// Enables or disables automatic FPU context preservation on exception entry.
+    ///
+    /// If disabled, exception handlers must not use the FPU, as they would corrupt the FPU
+    /// context of the interrupted code.
+    pub fn set_context_preservation(&mut self, enabled: bool) {
+        unsafe {
+            self.fpu.fpccr.modify(|reg| {
+                if enabled {
+                    reg | FPU_FPCCR_ASPEN_MASK
+                } else {
+                    reg & !FPU_FPCCR_ASPEN_MASK
+                }
+            })
+        };
+        dsb();
+        isb();
+    }
+
     /// Sets FPU configuration.
     pub fn set_config(&mut self, config: Config) {
         unsafe {
@@ -117,7 +164,7 @@ impl Fpu {
             FlushToZeroMode::try_from((fpdscr & FPU_FPDSCR_FZ_MASK) >> FPU_FPDSCR_FZ_OFFSET)
                 .unwrap();
         let rounding_mode =
-            RoundingMode::try_from((fpdscr * FPU_FPDSCR_RMODE_MASK) >> FPU_FPDSCR_RMODE_OFFSET)
+            RoundingMode::try_from((fpdscr & FPU_FPDSCR_RMODE_MASK) >> FPU_FPDSCR_RMODE_OFFSET)
                 .unwrap();
 
         Config {
@@ -0,0 +1,77 @@
+//! Implementation of HAL GMAC driver.
+//!
+//! GMAC is the Ethernet MAC of SAMV71. Physical layer is handled by an external Ethernet PHY,
+//! which is managed through the MDIO interface of the GMAC.
+//!
+//! This driver currently supports:
+//! * MDIO access to PHY registers (clause 22)
+//! * PHY management, with driver for KSZ8061 PHY used on SAMV71 Xplained Ultra board
+//! * Link state monitoring
+//! * IEEE 1588 PTP clock and event frame timestamps
+//!
+//! Specifically, it currently does **NOT** support:
+//! * Transmission and reception of frames
+//! * Interrupts
+//! * Clause 45 MDIO access
+
+pub use self::mdio::{MdcClockDivider, MdioBus};
+pub use self::phy::{Duplex, LinkMonitor, LinkSpeed, LinkState, Phy};
+pub use self::ptp::{ClockIncrement, PtpError, PtpEventTimestamp, PtpTimestamp};
+use crate::pac::GMAC;
+use crate::register_dump::{write_register, RegisterDump};
+use core::fmt;
+
+pub mod mdio;
+pub mod phy;
+pub mod ptp;
+
+/// Structure representing GMAC.
+pub struct Gmac {
+    /// PAC GMAC instance.
+    gmac: GMAC,
+    /// Nominal increment of PTP clock, `None` if the clock isn't enabled.
+    ptp_increment: Option<ptp::ClockIncrement>,
+}
+
+impl Gmac {
+    /// Creates new GMAC driver instance and enables its management port.
+    ///
+    /// GMAC peripheral clock must be enabled before creating the driver.
+    ///
+    /// # Parameters
+    /// * `gmac` - PAC GMAC instance.
+    /// * `mdc_divider` - Divider of peripheral clock used to generate MDC clock. MDC frequency
+    ///   must not exceed 2.5MHz.
+    pub fn new(gmac: GMAC, mdc_divider: MdcClockDivider) -> Self {
+        gmac.ncfgr
+            .modify(|_, w| w.clk().variant(mdc_divider.into()));
+        gmac.ncr.modify(|_, w| w.mpe().set_bit());
+
+        Self {
+            gmac,
+            ptp_increment: None,
+        }
+    }
+
+    /// Waits until management port finishes current operation.
+    fn wait_for_management_idle(&self) {
+        while self.gmac.nsr.read().idle().bit_is_clear() {}
+    }
+}
+
+impl RegisterDump for Gmac {
+    /// Dumps GMAC network control, status and PTP clock registers. Interrupt status register
+    /// (which is cleared on read) is omitted.
+    fn dump_registers(&self, writer: &mut impl fmt::Write) -> fmt::Result {
+        write_register(writer, "NCR", self.gmac.ncr.read().bits())?;
+        write_register(writer, "NCFGR", self.gmac.ncfgr.read().bits())?;
+        write_register(writer, "NSR", self.gmac.nsr.read().bits())?;
+        write_register(writer, "DCFGR", self.gmac.dcfgr.read().bits())?;
+        write_register(writer, "TSR", self.gmac.tsr.read().bits())?;
+        write_register(writer, "RSR", self.gmac.rsr.read().bits())?;
+        write_register(writer, "IMR", self.gmac.imr.read().bits())?;
+        write_register(writer, "MAN", self.gmac.man.read().bits())?;
+        write_register(writer, "TI", self.gmac.ti.read().bits())?;
+        write_register(writer, "TISUBN", self.gmac.tisubn.read().bits())
+    }
+}
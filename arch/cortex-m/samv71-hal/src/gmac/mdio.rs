@@ -0,0 +1,109 @@
+//! Module with MDIO interface used to manage Ethernet PHYs.
+
+use samv71q21_pac::gmac::ncfgr::CLKSELECT_A;
+
+use super::Gmac;
+
+/// Clause 22 start of frame code.
+const CLAUSE_22_FRAME: bool = true;
+/// Code of read operation.
+const READ_OPERATION: u8 = 0b10;
+/// Code of write operation.
+const WRITE_OPERATION: u8 = 0b01;
+/// Value that must be written to `WTN` field.
+const WRITE_TEN: u8 = 0b10;
+
+/// Trait for buses providing access to PHY registers.
+pub trait MdioBus {
+    /// Reads PHY register.
+    ///
+    /// # Parameters
+    /// * `phy` - Address of the PHY (0-31).
+    /// * `register` - Address of the register (0-31).
+    ///
+    /// # Return
+    /// Value of the register. If there's no PHY with specified address, `0xFFFF` is returned.
+    fn read(&mut self, phy: u8, register: u8) -> u16;
+
+    /// Writes PHY register.
+    ///
+    /// # Parameters
+    /// * `phy` - Address of the PHY (0-31).
+    /// * `register` - Address of the register (0-31).
+    /// * `value` - Value to write.
+    fn write(&mut self, phy: u8, register: u8, value: u16);
+}
+
+/// Divider of peripheral clock used to generate MDC clock.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum MdcClockDivider {
+    /// Peripheral clock divided by 8, for peripheral clock up to 20MHz.
+    Div8,
+    /// Peripheral clock divided by 16, for peripheral clock up to 40MHz.
+    Div16,
+    /// Peripheral clock divided by 32, for peripheral clock up to 80MHz.
+    Div32,
+    /// Peripheral clock divided by 48, for peripheral clock up to 120MHz.
+    Div48,
+    /// Peripheral clock divided by 64, for peripheral clock up to 160MHz.
+    Div64,
+    /// Peripheral clock divided by 96, for peripheral clock up to 240MHz.
+    Div96,
+}
+
+impl From<MdcClockDivider> for CLKSELECT_A {
+    fn from(value: MdcClockDivider) -> Self {
+        match value {
+            MdcClockDivider::Div8 => CLKSELECT_A::MCK_8,
+            MdcClockDivider::Div16 => CLKSELECT_A::MCK_16,
+            MdcClockDivider::Div32 => CLKSELECT_A::MCK_32,
+            MdcClockDivider::Div48 => CLKSELECT_A::MCK_48,
+            MdcClockDivider::Div64 => CLKSELECT_A::MCK_64,
+            MdcClockDivider::Div96 => CLKSELECT_A::MCK_96,
+        }
+    }
+}
+
+impl MdioBus for Gmac {
+    fn read(&mut self, phy: u8, register: u8) -> u16 {
+        self.wait_for_management_idle();
+        self.gmac.man.write(|w| {
+            w.wzo()
+                .clear_bit()
+                .cltto()
+                .bit(CLAUSE_22_FRAME)
+                .op()
+                .variant(READ_OPERATION)
+                .phya()
+                .variant(phy)
+                .rega()
+                .variant(register)
+                .wtn()
+                .variant(WRITE_TEN)
+        });
+        self.wait_for_management_idle();
+
+        self.gmac.man.read().data().bits()
+    }
+
+    fn write(&mut self, phy: u8, register: u8, value: u16) {
+        self.wait_for_management_idle();
+        self.gmac.man.write(|w| {
+            w.wzo()
+                .clear_bit()
+                .cltto()
+                .bit(CLAUSE_22_FRAME)
+                .op()
+                .variant(WRITE_OPERATION)
+                .phya()
+                .variant(phy)
+                .rega()
+                .variant(register)
+                .wtn()
+                .variant(WRITE_TEN)
+                .data()
+                .variant(value)
+        });
+        self.wait_for_management_idle();
+    }
+}
@@ -0,0 +1,162 @@
+//! Module with Ethernet PHY management layer.
+//!
+//! PHY drivers implement [`Phy`] trait, which provides link management independent of the PHY
+//! model. Link state changes are detected by [`LinkMonitor`], either by polling the PHY
+//! periodically, or after PHY's interrupt pin signals a link interrupt.
+
+pub mod ksz8061;
+
+pub use self::ksz8061::Ksz8061;
+use super::mdio::MdioBus;
+
+/// Address of basic control register.
+pub const BASIC_CONTROL: u8 = 0x00;
+/// Address of basic status register.
+pub const BASIC_STATUS: u8 = 0x01;
+/// Address of PHY identifier 1 register.
+pub const PHY_IDENTIFIER_1: u8 = 0x02;
+/// Address of PHY identifier 2 register.
+pub const PHY_IDENTIFIER_2: u8 = 0x03;
+
+/// Software reset bit of basic control register.
+pub const BASIC_CONTROL_RESET: u16 = 1 << 15;
+/// Auto-negotiation enable bit of basic control register.
+pub const BASIC_CONTROL_AUTO_NEGOTIATION_ENABLE: u16 = 1 << 12;
+/// Restart auto-negotiation bit of basic control register.
+pub const BASIC_CONTROL_RESTART_AUTO_NEGOTIATION: u16 = 1 << 9;
+/// Link status bit of basic status register.
+pub const BASIC_STATUS_LINK: u16 = 1 << 2;
+/// Auto-negotiation complete bit of basic status register.
+pub const BASIC_STATUS_AUTO_NEGOTIATION_COMPLETE: u16 = 1 << 5;
+
+/// Link speed.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum LinkSpeed {
+    /// 10Mbps.
+    Mbps10,
+    /// 100Mbps.
+    Mbps100,
+}
+
+/// Link duplex mode.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Duplex {
+    /// Half duplex.
+    Half,
+    /// Full duplex.
+    Full,
+}
+
+/// State of the Ethernet link.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum LinkState {
+    /// Link is down, for example the cable is unplugged.
+    Down,
+    /// Link is up with negotiated parameters.
+    Up {
+        /// Link speed.
+        speed: LinkSpeed,
+        /// Link duplex mode.
+        duplex: Duplex,
+    },
+}
+
+impl LinkState {
+    /// Returns `true` if link is up.
+    pub fn is_up(&self) -> bool {
+        matches!(self, LinkState::Up { .. })
+    }
+}
+
+/// Trait for Ethernet PHY drivers.
+pub trait Phy {
+    /// Returns address of the PHY on MDIO bus.
+    fn address(&self) -> u8;
+
+    /// Triggers software reset of the PHY and waits until it's finished.
+    ///
+    /// # Parameters
+    /// * `bus` - MDIO bus the PHY is connected to.
+    fn reset(&mut self, bus: &mut dyn MdioBus) {
+        bus.write(self.address(), BASIC_CONTROL, BASIC_CONTROL_RESET);
+        while bus.read(self.address(), BASIC_CONTROL) & BASIC_CONTROL_RESET != 0 {}
+    }
+
+    /// Enables and restarts auto-negotiation. Result of the negotiation is reported by
+    /// [`Phy::link_state`] after link goes up.
+    ///
+    /// # Parameters
+    /// * `bus` - MDIO bus the PHY is connected to.
+    fn start_auto_negotiation(&mut self, bus: &mut dyn MdioBus) {
+        let control = bus.read(self.address(), BASIC_CONTROL);
+        bus.write(
+            self.address(),
+            BASIC_CONTROL,
+            control
+                | BASIC_CONTROL_AUTO_NEGOTIATION_ENABLE
+                | BASIC_CONTROL_RESTART_AUTO_NEGOTIATION,
+        );
+    }
+
+    /// Returns `true` if auto-negotiation is complete.
+    ///
+    /// # Parameters
+    /// * `bus` - MDIO bus the PHY is connected to.
+    fn is_auto_negotiation_complete(&mut self, bus: &mut dyn MdioBus) -> bool {
+        bus.read(self.address(), BASIC_STATUS) & BASIC_STATUS_AUTO_NEGOTIATION_COMPLETE != 0
+    }
+
+    /// Returns current link state.
+    ///
+    /// # Parameters
+    /// * `bus` - MDIO bus the PHY is connected to.
+    fn link_state(&mut self, bus: &mut dyn MdioBus) -> LinkState;
+}
+
+/// Structure detecting link state changes.
+///
+/// It's meant to be used from a tasklet, that polls PHY periodically (or is woken up by PHY
+/// interrupt) and emits an event when link state changes, so network service tasklets can react
+/// to cable being plugged or unplugged.
+pub struct LinkMonitor {
+    /// Last known link state.
+    state: LinkState,
+}
+
+impl LinkMonitor {
+    /// Creates new link monitor. Link is initially assumed to be down.
+    pub const fn new() -> Self {
+        Self {
+            state: LinkState::Down,
+        }
+    }
+
+    /// Returns last known link state.
+    pub fn state(&self) -> LinkState {
+        self.state
+    }
+
+    /// Reads link state from the PHY.
+    ///
+    /// # Parameters
+    /// * `phy` - PHY driver.
+    /// * `bus` - MDIO bus the PHY is connected to.
+    ///
+    /// # Return
+    /// New link state if it has changed since previous poll, `None` otherwise.
+    pub fn poll(&mut self, phy: &mut dyn Phy, bus: &mut dyn MdioBus) -> Option<LinkState> {
+        let state = phy.link_state(bus);
+        if state == self.state {
+            return None;
+        }
+
+        self.state = state;
+        Some(state)
+    }
+}
+
+impl Default for LinkMonitor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,144 @@
+//! Driver of Microchip KSZ8061 Ethernet PHY.
+//!
+//! KSZ8061 is a 10BASE-T/100BASE-TX PHY used on SAMV71 Xplained Ultra board.
+
+use super::{
+    Duplex, LinkSpeed, LinkState, Phy, BASIC_STATUS, BASIC_STATUS_LINK, PHY_IDENTIFIER_1,
+    PHY_IDENTIFIER_2,
+};
+use crate::gmac::mdio::MdioBus;
+
+/// Expected value of PHY identifier 1 register.
+const IDENTIFIER_1: u16 = 0x0022;
+/// Expected value of PHY identifier 2 register, without revision number.
+const IDENTIFIER_2: u16 = 0x1570;
+/// Mask of PHY identifier 2 register removing revision number.
+const IDENTIFIER_2_MASK: u16 = 0xFFF0;
+
+/// Address of interrupt control/status register.
+const INTERRUPT_CONTROL_STATUS: u8 = 0x1B;
+/// Address of PHY control 1 register.
+const PHY_CONTROL_1: u8 = 0x1E;
+
+/// Link-down interrupt enable bit.
+const LINK_DOWN_INTERRUPT_ENABLE: u16 = 1 << 10;
+/// Link-up interrupt enable bit.
+const LINK_UP_INTERRUPT_ENABLE: u16 = 1 << 8;
+/// Link-down interrupt status bit.
+const LINK_DOWN_INTERRUPT: u16 = 1 << 2;
+/// Link-up interrupt status bit.
+const LINK_UP_INTERRUPT: u16 = 1 << 0;
+
+/// Mask of operation mode indication field of PHY control 1 register.
+const OPERATION_MODE_MASK: u16 = 0b111;
+/// 10BASE-T half duplex operation mode.
+const OPERATION_MODE_10_HALF: u16 = 0b001;
+/// 100BASE-TX half duplex operation mode.
+const OPERATION_MODE_100_HALF: u16 = 0b010;
+/// 10BASE-T full duplex operation mode.
+const OPERATION_MODE_10_FULL: u16 = 0b101;
+/// 100BASE-TX full duplex operation mode.
+const OPERATION_MODE_100_FULL: u16 = 0b110;
+
+/// Link interrupts pending in the PHY.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct LinkInterrupts {
+    /// `true` if link went up.
+    pub link_up: bool,
+    /// `true` if link went down.
+    pub link_down: bool,
+}
+
+/// Structure representing KSZ8061 PHY.
+pub struct Ksz8061 {
+    /// Address of the PHY on MDIO bus.
+    address: u8,
+}
+
+impl Ksz8061 {
+    /// Creates new driver instance for PHY with known address.
+    ///
+    /// # Parameters
+    /// * `address` - Address of the PHY on MDIO bus.
+    pub const fn new(address: u8) -> Self {
+        Self { address }
+    }
+
+    /// Searches MDIO bus for KSZ8061 PHY.
+    ///
+    /// Address of the PHY is selected by board's strapping pins, so it doesn't have to be known
+    /// upfront.
+    ///
+    /// # Parameters
+    /// * `bus` - MDIO bus to search.
+    ///
+    /// # Return
+    /// Driver instance for the first PHY found, or `None` if there's no KSZ8061 on the bus.
+    pub fn probe(bus: &mut dyn MdioBus) -> Option<Self> {
+        (0..32)
+            .find(|&address| {
+                bus.read(address, PHY_IDENTIFIER_1) == IDENTIFIER_1
+                    && bus.read(address, PHY_IDENTIFIER_2) & IDENTIFIER_2_MASK == IDENTIFIER_2
+            })
+            .map(Self::new)
+    }
+
+    /// Enables link-up and link-down interrupts. PHY's interrupt pin is asserted (low) until
+    /// interrupts are cleared with [`Ksz8061::clear_interrupts`].
+    ///
+    /// # Parameters
+    /// * `bus` - MDIO bus the PHY is connected to.
+    pub fn enable_link_interrupts(&mut self, bus: &mut dyn MdioBus) {
+        bus.write(
+            self.address,
+            INTERRUPT_CONTROL_STATUS,
+            LINK_UP_INTERRUPT_ENABLE | LINK_DOWN_INTERRUPT_ENABLE,
+        );
+    }
+
+    /// Disables link-up and link-down interrupts.
+    ///
+    /// # Parameters
+    /// * `bus` - MDIO bus the PHY is connected to.
+    pub fn disable_link_interrupts(&mut self, bus: &mut dyn MdioBus) {
+        bus.write(self.address, INTERRUPT_CONTROL_STATUS, 0);
+    }
+
+    /// Reads and clears pending link interrupts.
+    ///
+    /// # Parameters
+    /// * `bus` - MDIO bus the PHY is connected to.
+    pub fn clear_interrupts(&mut self, bus: &mut dyn MdioBus) -> LinkInterrupts {
+        let status = bus.read(self.address, INTERRUPT_CONTROL_STATUS);
+
+        LinkInterrupts {
+            link_up: status & LINK_UP_INTERRUPT != 0,
+            link_down: status & LINK_DOWN_INTERRUPT != 0,
+        }
+    }
+}
+
+impl Phy for Ksz8061 {
+    fn address(&self) -> u8 {
+        self.address
+    }
+
+    fn link_state(&mut self, bus: &mut dyn MdioBus) -> LinkState {
+        // Link status bit is latched low, so it's read twice to get the current state.
+        bus.read(self.address, BASIC_STATUS);
+        if bus.read(self.address, BASIC_STATUS) & BASIC_STATUS_LINK == 0 {
+            return LinkState::Down;
+        }
+
+        let (speed, duplex) = match bus.read(self.address, PHY_CONTROL_1) & OPERATION_MODE_MASK {
+            OPERATION_MODE_10_HALF => (LinkSpeed::Mbps10, Duplex::Half),
+            OPERATION_MODE_100_HALF => (LinkSpeed::Mbps100, Duplex::Half),
+            OPERATION_MODE_10_FULL => (LinkSpeed::Mbps10, Duplex::Full),
+            OPERATION_MODE_100_FULL => (LinkSpeed::Mbps100, Duplex::Full),
+            // Auto-negotiation is still in progress.
+            _ => return LinkState::Down,
+        };
+
+        LinkState::Up { speed, duplex }
+    }
+}
@@ -0,0 +1,267 @@
+//! Module with IEEE 1588 Precision Time Protocol timestamping unit of GMAC.
+//!
+//! Timestamping unit is a 48-bit seconds and 30-bit nanoseconds clock incremented with every
+//! peripheral clock cycle. GMAC captures its value when PTP event frames (Sync, Delay_Req,
+//! Pdelay_Req and Pdelay_Resp) are transmitted or received, which allows to measure the time of
+//! flight of the frames independently of software latencies.
+//!
+//! Clock is synchronized to a PTP master by stepping it with [`Gmac::set_ptp_time`], slewing it
+//! with [`Gmac::adjust_ptp_time`], and tuning its rate with [`Gmac::adjust_ptp_frequency`].
+
+use super::Gmac;
+use crate::time::{Duration, NanosDurationU64};
+
+/// Number of nanoseconds in a second.
+const NANOS_PER_SECOND: u32 = 1_000_000_000;
+/// Maximum value of the seconds counter.
+const MAX_SECONDS: u64 = (1 << 48) - 1;
+/// Number of sub-nanosecond increment bits.
+const SUB_NANOSECOND_BITS: u32 = 16;
+
+/// Enumeration listing PTP errors.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum PtpError {
+    /// PTP clock wasn't enabled with [`Gmac::enable_ptp_clock`].
+    ClockNotEnabled,
+    /// Requested adjustment is out of range of the hardware.
+    OutOfRange,
+}
+
+/// Structure representing PTP timestamp.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd)]
+pub struct PtpTimestamp {
+    /// Seconds part of the timestamp, 48-bit.
+    pub seconds: u64,
+    /// Nanoseconds part of the timestamp, always lower than one second.
+    pub nanoseconds: u32,
+}
+
+impl PtpTimestamp {
+    /// Creates new timestamp.
+    ///
+    /// # Parameters
+    /// * `seconds` - Seconds part of the timestamp.
+    /// * `nanoseconds` - Nanoseconds part of the timestamp.
+    ///
+    /// # Return
+    /// Timestamp, or `None` if any of the parts is out of range.
+    pub const fn new(seconds: u64, nanoseconds: u32) -> Option<Self> {
+        if seconds > MAX_SECONDS || nanoseconds >= NANOS_PER_SECOND {
+            return None;
+        }
+
+        Some(Self {
+            seconds,
+            nanoseconds,
+        })
+    }
+
+    /// Returns timestamp in nanoseconds.
+    pub const fn as_nanos(&self) -> u64 {
+        self.seconds * NANOS_PER_SECOND as u64 + self.nanoseconds as u64
+    }
+
+    /// Returns timestamp as a duration since PTP epoch.
+    ///
+    /// This can be used to convert the timestamp to system time duration, for example when
+    /// correcting system time offset.
+    pub fn to_duration<const NOM: u32, const DENOM: u32>(&self) -> Duration<u64, NOM, DENOM> {
+        NanosDurationU64::nanos(self.as_nanos()).convert()
+    }
+}
+
+/// Increment of PTP clock for each peripheral clock cycle.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct ClockIncrement {
+    /// Nanoseconds part of the increment.
+    pub nanoseconds: u8,
+    /// Sub-nanoseconds part of the increment, in units of 2^-16 ns.
+    pub sub_nanoseconds: u16,
+}
+
+impl ClockIncrement {
+    /// Calculates clock increment for given peripheral clock frequency.
+    ///
+    /// # Parameters
+    /// * `frequency_hz` - Frequency of peripheral clock.
+    ///
+    /// # Return
+    /// Clock increment, or `None` if the frequency is too low (period of 256ns or more) or zero.
+    pub const fn from_frequency(frequency_hz: u32) -> Option<Self> {
+        if frequency_hz == 0 {
+            return None;
+        }
+
+        let increment = ((NANOS_PER_SECOND as u64) << SUB_NANOSECOND_BITS) / frequency_hz as u64;
+        Self::from_raw(increment)
+    }
+
+    /// Returns increment in units of 2^-16 ns.
+    const fn raw(&self) -> u64 {
+        ((self.nanoseconds as u64) << SUB_NANOSECOND_BITS) | self.sub_nanoseconds as u64
+    }
+
+    /// Creates increment from a value in units of 2^-16 ns.
+    const fn from_raw(increment: u64) -> Option<Self> {
+        let nanoseconds = increment >> SUB_NANOSECOND_BITS;
+        if nanoseconds == 0 || nanoseconds > u8::MAX as u64 {
+            return None;
+        }
+
+        Some(Self {
+            nanoseconds: nanoseconds as u8,
+            sub_nanoseconds: increment as u16,
+        })
+    }
+}
+
+/// Type of PTP event frame timestamp.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum PtpEventTimestamp {
+    /// Last transmitted Sync or Delay_Req frame.
+    EventTransmitted,
+    /// Last received Sync or Delay_Req frame.
+    EventReceived,
+    /// Last transmitted Pdelay_Req or Pdelay_Resp frame.
+    PeerEventTransmitted,
+    /// Last received Pdelay_Req or Pdelay_Resp frame.
+    PeerEventReceived,
+}
+
+impl Gmac {
+    /// Enables PTP clock with given increment.
+    ///
+    /// # Parameters
+    /// * `increment` - Nominal increment of the clock for each peripheral clock cycle, usually
+    ///   calculated with [`ClockIncrement::from_frequency`].
+    pub fn enable_ptp_clock(&mut self, increment: ClockIncrement) {
+        self.write_ptp_increment(increment);
+        self.ptp_increment = Some(increment);
+    }
+
+    /// Returns nominal increment of PTP clock, or `None` if the clock isn't enabled.
+    pub fn ptp_clock_increment(&self) -> Option<ClockIncrement> {
+        self.ptp_increment
+    }
+
+    /// Returns current time of PTP clock.
+    pub fn ptp_time(&self) -> PtpTimestamp {
+        // Seconds are read before and after nanoseconds, to detect nanoseconds overflow.
+        loop {
+            let seconds = self.read_ptp_seconds();
+            let nanoseconds = self.gmac.tn.read().tns().bits();
+            if self.read_ptp_seconds() == seconds {
+                return PtpTimestamp {
+                    seconds,
+                    nanoseconds,
+                };
+            }
+        }
+    }
+
+    /// Sets PTP clock time. This steps the clock, and should be used only for large corrections.
+    ///
+    /// # Parameters
+    /// * `time` - New time of the clock.
+    pub fn set_ptp_time(&mut self, time: PtpTimestamp) {
+        self.gmac
+            .tsh
+            .write(|w| w.tcs().variant((time.seconds >> 32) as u16));
+        self.gmac
+            .tsl
+            .write(|w| w.tcs().variant(time.seconds as u32));
+        self.gmac.tn.write(|w| w.tns().variant(time.nanoseconds));
+    }
+
+    /// Adjusts PTP clock time by given offset.
+    ///
+    /// # Parameters
+    /// * `offset_ns` - Offset in nanoseconds, added to the clock. Its absolute value must be lower
+    ///   than one second.
+    pub fn adjust_ptp_time(&mut self, offset_ns: i32) -> Result<(), PtpError> {
+        if offset_ns.unsigned_abs() >= NANOS_PER_SECOND {
+            return Err(PtpError::OutOfRange);
+        }
+
+        self.gmac.ta.write(|w| {
+            w.adj()
+                .bit(offset_ns < 0)
+                .itdt()
+                .variant(offset_ns.unsigned_abs())
+        });
+
+        Ok(())
+    }
+
+    /// Adjusts rate of PTP clock relative to its nominal increment.
+    ///
+    /// # Parameters
+    /// * `ppb` - Rate adjustment in parts per billion. Positive value makes the clock run faster.
+    pub fn adjust_ptp_frequency(&mut self, ppb: i32) -> Result<(), PtpError> {
+        let nominal = self.ptp_increment.ok_or(PtpError::ClockNotEnabled)?.raw() as i64;
+        let adjusted = nominal + nominal * ppb as i64 / NANOS_PER_SECOND as i64;
+        let increment = u64::try_from(adjusted)
+            .ok()
+            .and_then(ClockIncrement::from_raw)
+            .ok_or(PtpError::OutOfRange)?;
+
+        self.write_ptp_increment(increment);
+        Ok(())
+    }
+
+    /// Returns timestamp of the last PTP event frame of given type.
+    ///
+    /// # Parameters
+    /// * `event` - Type of the event frame.
+    pub fn ptp_event_timestamp(&self, event: PtpEventTimestamp) -> PtpTimestamp {
+        let (seconds_high, seconds_low, nanoseconds) = match event {
+            PtpEventTimestamp::EventTransmitted => (
+                self.gmac.eftsh.read().rud().bits(),
+                self.gmac.eftsl.read().rud().bits(),
+                self.gmac.eftn.read().rud().bits(),
+            ),
+            PtpEventTimestamp::EventReceived => (
+                self.gmac.efrsh.read().rud().bits(),
+                self.gmac.efrsl.read().rud().bits(),
+                self.gmac.efrn.read().rud().bits(),
+            ),
+            PtpEventTimestamp::PeerEventTransmitted => (
+                self.gmac.peftsh.read().rud().bits(),
+                self.gmac.peftsl.read().rud().bits(),
+                self.gmac.peftn.read().rud().bits(),
+            ),
+            PtpEventTimestamp::PeerEventReceived => (
+                self.gmac.pefrsh.read().rud().bits(),
+                self.gmac.pefrsl.read().rud().bits(),
+                self.gmac.pefrn.read().rud().bits(),
+            ),
+        };
+
+        PtpTimestamp {
+            seconds: ((seconds_high as u64) << 32) | seconds_low as u64,
+            nanoseconds,
+        }
+    }
+
+    /// Reads seconds part of PTP clock.
+    fn read_ptp_seconds(&self) -> u64 {
+        let high = self.gmac.tsh.read().tcs().bits() as u64;
+        let low = self.gmac.tsl.read().tcs().bits() as u64;
+        (high << 32) | low
+    }
+
+    /// Writes PTP clock increment to the hardware.
+    fn write_ptp_increment(&mut self, increment: ClockIncrement) {
+        self.gmac
+            .tisubn
+            .write(|w| w.lsbtir().variant(increment.sub_nanoseconds));
+        self.gmac.ti.write(|w| {
+            w.cns()
+                .variant(increment.nanoseconds)
+                .acns()
+                .variant(0)
+                .nit()
+                .variant(0)
+        });
+    }
+}
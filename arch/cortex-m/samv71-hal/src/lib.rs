@@ -1,4 +1,9 @@
 //! This module contains all the drivers for supported peripherals.
+//!
+//! Drivers of peripherals that are not used by every application (GMAC, MCAN, PIO, SPI, timer,
+//! UART and XDMAC) are gated behind cargo features named after their modules, all of them enabled
+//! by default. Application can disable default features and enable only the drivers it uses, so
+//! the remaining ones are not compiled at all. SPI and UART drivers require the XDMAC driver.
 
 #![no_std]
 #![warn(missing_docs)]
@@ -14,14 +19,29 @@ pub use samv71q21_pac as pac;
 /// Macro for interrupt handlers.
 pub use pac::interrupt;
 
+pub mod analog_self_test;
+pub mod call_bounds;
+pub mod fault_injection;
 #[cfg(has_fpu)]
 pub mod fpu;
+#[cfg(feature = "gmac")]
+pub mod gmac;
+#[cfg(feature = "mcan")]
+pub mod mcan;
+pub mod mpu;
 pub mod nvic;
+#[cfg(feature = "pio")]
 pub mod pio;
 pub mod pmc;
+pub mod recovery;
+pub mod register_dump;
+#[cfg(feature = "spi")]
 pub mod spi;
+#[cfg(feature = "timer")]
 pub mod timer;
+#[cfg(feature = "uart")]
 pub mod uart;
 pub mod utils;
 pub mod watchdog;
+#[cfg(feature = "xdmac")]
 pub mod xdmac;
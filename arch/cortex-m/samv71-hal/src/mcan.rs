@@ -0,0 +1,161 @@
+//! Implementation of HAL MCAN health monitoring.
+//!
+//! MCAN is a Controller Area Network controller. Node that detects too many errors on the bus goes
+//! through error warning and error passive states, and finally enters bus-off state, in which it
+//! stops taking part in bus communication. Transmissions requested in bus-off state silently never
+//! complete, so bus health should be observed explicitly instead.
+//!
+//! [`Mcan`] driver reads error counters and fault confinement state of the MCAN and returns them
+//! as a [`BusHealth`] sample. It's intended to be sampled periodically (for example from a cyclic
+//! tasklet), with [`BusState::level`] or error counters sent to the monitoring subsystem. When
+//! MCAN enters bus-off state, hardware stops the controller by setting the initialization bit.
+//! Depending on [`BusOffRecovery`] policy, the driver starts the recovery sequence (129 occurrences
+//! of 11 consecutive recessive bits on the bus) either on the next sample, or when requested by
+//! the user.
+//!
+//! This driver currently supports:
+//! * Error counters and bus state monitoring
+//! * Bus-off recovery
+//!
+//! Specifically, it currently does **NOT** support:
+//! * Configuration of bit timing and message RAM
+//! * Transmission and reception of messages
+//! * Interrupts
+
+use core::fmt;
+use core::marker::PhantomData;
+
+pub use self::health::{BusHealth, BusOffRecovery, BusState, ErrorCounters, LastError};
+use self::metadata::MCANMetadata;
+use crate::register_dump::{write_register, RegisterDump};
+
+pub mod health;
+pub mod metadata;
+
+/// Structure representing MCAN health monitor.
+///
+/// # Generic parameters
+/// * `Instance` - PAC MCAN instance.
+pub struct Mcan<Instance: MCANMetadata> {
+    /// Bus-off recovery policy.
+    recovery_policy: BusOffRecovery,
+    /// Number of bus-off recovery sequences started by the driver.
+    recovery_count: u32,
+    /// PAC MCAN instance metadata.
+    _meta: PhantomData<Instance>,
+}
+
+impl<Instance: MCANMetadata> Mcan<Instance> {
+    /// Creates new MCAN driver instance, consuming PAC MCAN instance to prevent creation of
+    /// multiple drivers for the same MCAN instance.
+    ///
+    /// MCAN must be configured and started before bus health can be meaningfully sampled.
+    ///
+    /// # Parameters
+    /// * `mcan` - PAC MCANx instance, where `x` is the number of MCAN peripheral.
+    /// * `recovery_policy` - Behavior of the driver when MCAN enters bus-off state.
+    pub fn new(_mcan: Instance, recovery_policy: BusOffRecovery) -> Self {
+        Self {
+            recovery_policy,
+            recovery_count: 0,
+            _meta: PhantomData,
+        }
+    }
+
+    /// Returns current bus-off recovery policy.
+    pub fn recovery_policy(&self) -> BusOffRecovery {
+        self.recovery_policy
+    }
+
+    /// Sets bus-off recovery policy.
+    pub fn set_recovery_policy(&mut self, recovery_policy: BusOffRecovery) {
+        self.recovery_policy = recovery_policy;
+    }
+
+    /// Samples bus health.
+    ///
+    /// If MCAN is in bus-off state, recovery policy is [`BusOffRecovery::Automatic`] and recovery
+    /// sequence wasn't started yet, it's started by this function.
+    ///
+    /// Reading the sample clears error logging counter and last error code.
+    ///
+    /// # Return
+    /// Current bus health.
+    pub fn sample(&mut self) -> BusHealth {
+        let psr = Instance::registers().psr.read();
+        let state = BusState::from(&psr);
+
+        if state == BusState::BusOff
+            && self.recovery_policy == BusOffRecovery::Automatic
+            && !self.is_recovering()
+        {
+            self.start_bus_off_recovery();
+        }
+
+        BusHealth {
+            state,
+            counters: Instance::registers().ecr.read().into(),
+            last_error: (&psr).into(),
+            recovering: self.is_recovering(),
+            recovery_count: self.recovery_count,
+        }
+    }
+
+    /// Returns current fault confinement state, without starting bus-off recovery.
+    pub fn bus_state(&self) -> BusState {
+        (&Instance::registers().psr.read()).into()
+    }
+
+    /// Returns `true` if bus-off recovery sequence is in progress.
+    pub fn is_recovering(&self) -> bool {
+        self.bus_state() == BusState::BusOff
+            && Instance::registers().cccr.read().init().is_disabled()
+    }
+
+    /// Starts bus-off recovery sequence.
+    ///
+    /// Does nothing if MCAN isn't in bus-off state, or recovery is already in progress.
+    ///
+    /// # Return
+    /// `true` if recovery sequence was started, `false` otherwise.
+    pub fn start_bus_off_recovery(&mut self) -> bool {
+        if self.bus_state() != BusState::BusOff || self.is_recovering() {
+            return false;
+        }
+
+        Instance::registers()
+            .cccr
+            .modify(|_, w| w.init().clear_bit());
+        self.recovery_count = self.recovery_count.wrapping_add(1);
+        true
+    }
+
+    /// Returns number of bus-off recovery sequences started by the driver.
+    pub fn recovery_count(&self) -> u32 {
+        self.recovery_count
+    }
+}
+
+impl<Instance: MCANMetadata> RegisterDump for Mcan<Instance> {
+    /// Dumps MCAN configuration and status registers. Error counter register and protocol status
+    /// register (which clear error logging and last error code on read) are omitted, use
+    /// [`Mcan::sample`] to read them.
+    fn dump_registers(&self, writer: &mut impl fmt::Write) -> fmt::Result {
+        let registers = Instance::registers();
+        write_register(writer, "CREL", registers.crel.read().bits())?;
+        write_register(writer, "CCCR", registers.cccr.read().bits())?;
+        write_register(writer, "NBTP", registers.nbtp.read().bits())?;
+        write_register(writer, "DBTP", registers.dbtp.read().bits())?;
+        write_register(writer, "TEST", registers.test.read().bits())?;
+        write_register(writer, "TSCC", registers.tscc.read().bits())?;
+        write_register(writer, "TOCC", registers.tocc.read().bits())?;
+        write_register(writer, "IR", registers.ir.read().bits())?;
+        write_register(writer, "IE", registers.ie.read().bits())?;
+        write_register(writer, "ILS", registers.ils.read().bits())?;
+        write_register(writer, "ILE", registers.ile.read().bits())?;
+        write_register(writer, "RXF0S", registers.rxf0s.read().bits())?;
+        write_register(writer, "RXF1S", registers.rxf1s.read().bits())?;
+        write_register(writer, "TXFQS", registers.txfqs.read().bits())?;
+        write_register(writer, "TXBRP", registers.txbrp.read().bits())
+    }
+}
@@ -0,0 +1,122 @@
+//! Module with structures and enumerations representing MCAN bus health.
+
+use samv71q21_pac::mcan0::{ecr, psr};
+
+/// Fault confinement state of the MCAN node.
+///
+/// States are ordered by severity, so the state can be directly compared against a threshold.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd)]
+pub enum BusState {
+    /// Both error counters are below the warning limit.
+    ErrorActive = 0,
+    /// At least one of the error counters reached the warning limit of 96.
+    ErrorWarning = 1,
+    /// At least one of the error counters exceeded 127. Node can't send active error frames.
+    ErrorPassive = 2,
+    /// Transmit error counter exceeded 255. Node doesn't take part in bus communication.
+    BusOff = 3,
+}
+
+impl BusState {
+    /// Returns numeric level of the state, that can be routed to the monitoring subsystem
+    /// (for example a limit monitor) as a regular sample.
+    pub const fn level(self) -> u8 {
+        self as u8
+    }
+}
+
+impl From<&psr::R> for BusState {
+    fn from(reg: &psr::R) -> Self {
+        if reg.bo().bit_is_set() {
+            BusState::BusOff
+        } else if reg.ep().bit_is_set() {
+            BusState::ErrorPassive
+        } else if reg.ew().bit_is_set() {
+            BusState::ErrorWarning
+        } else {
+            BusState::ErrorActive
+        }
+    }
+}
+
+/// Type of the last error that occurred on the bus.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum LastError {
+    /// No error occurred since the error code was last read.
+    None,
+    /// More than 5 equal bits in a sequence occurred in a part of a received message.
+    Stuff,
+    /// A fixed format part of a received frame had the wrong format.
+    Form,
+    /// Message transmitted by the MCAN wasn't acknowledged by another node.
+    Ack,
+    /// MCAN tried to send a recessive bit, but monitored bus value was dominant.
+    Bit1,
+    /// MCAN tried to send a dominant bit, but monitored bus value was recessive.
+    Bit0,
+    /// CRC of a received message didn't match the calculated one.
+    Crc,
+}
+
+impl From<&psr::R> for LastError {
+    fn from(reg: &psr::R) -> Self {
+        match reg.lec().variant() {
+            psr::LECSELECT_A::STUFF_ERROR => LastError::Stuff,
+            psr::LECSELECT_A::FORM_ERROR => LastError::Form,
+            psr::LECSELECT_A::ACK_ERROR => LastError::Ack,
+            psr::LECSELECT_A::BIT1_ERROR => LastError::Bit1,
+            psr::LECSELECT_A::BIT0_ERROR => LastError::Bit0,
+            psr::LECSELECT_A::CRC_ERROR => LastError::Crc,
+            psr::LECSELECT_A::NO_ERROR | psr::LECSELECT_A::NO_CHANGE => LastError::None,
+        }
+    }
+}
+
+/// Structure representing MCAN error counters.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct ErrorCounters {
+    /// Transmit error counter.
+    pub transmit: u8,
+    /// Receive error counter.
+    pub receive: u8,
+    /// `true` if receive error counter reached the error passive level of 128.
+    pub receive_error_passive: bool,
+    /// Number of errors that occurred since previous sample, saturated at 255.
+    pub error_logging: u8,
+}
+
+impl From<ecr::R> for ErrorCounters {
+    fn from(reg: ecr::R) -> Self {
+        ErrorCounters {
+            transmit: reg.tec().bits(),
+            receive: reg.rec().bits(),
+            receive_error_passive: reg.rp().bit_is_set(),
+            error_logging: reg.cel().bits(),
+        }
+    }
+}
+
+/// Behavior of the driver when the MCAN enters bus-off state.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum BusOffRecovery {
+    /// Bus-off recovery sequence is started by the next health sample after entering bus-off.
+    Automatic,
+    /// Bus-off recovery sequence is started only by explicit
+    /// [`Mcan::start_bus_off_recovery`](super::Mcan::start_bus_off_recovery) call.
+    Manual,
+}
+
+/// Structure representing a single sample of MCAN bus health.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct BusHealth {
+    /// Fault confinement state.
+    pub state: BusState,
+    /// Error counters.
+    pub counters: ErrorCounters,
+    /// Last error that occurred on the bus.
+    pub last_error: LastError,
+    /// `true` if bus-off recovery sequence is in progress.
+    pub recovering: bool,
+    /// Number of bus-off recovery sequences started by the driver.
+    pub recovery_count: u32,
+}
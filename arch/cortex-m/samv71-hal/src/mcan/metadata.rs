@@ -0,0 +1,38 @@
+//! Module containing meta-traits and their implementations for HAL MCAN driver
+use crate::pac::mcan0::RegisterBlock;
+pub use crate::pac::{MCAN0, MCAN1};
+use crate::pmc::config::peripheral::PeripheralId;
+
+/// Trait for PAC MCAN instances.
+///
+/// This trait erases the type of MCAN instance, so it can be used as generic argument for
+/// [`Mcan`](super::Mcan) instead of concrete type.
+pub trait MCANMetadata {
+    /// Pointer to MCAN registers.
+    const REGISTERS: *const RegisterBlock;
+    /// Peripheral ID of MCAN instance, used to control its clock.
+    const PERIPHERAL_ID: PeripheralId;
+
+    /// Returns a reference to MCAN's register block.
+    ///
+    /// # Safety
+    /// This function dereferences a raw pointer.
+    /// It's safe to use, as long as there aren't multiple instances of the same MCAN peripheral.
+    #[inline(always)]
+    fn registers() -> &'static RegisterBlock {
+        unsafe { &*Self::REGISTERS }
+    }
+}
+
+/// Internal macro used to generate MCANMetadata implementations for every available MCAN.
+macro_rules! implement_mcan_metadata_for {
+    ($mcan:ident) => {
+        impl MCANMetadata for $mcan {
+            const REGISTERS: *const RegisterBlock = <$mcan>::PTR;
+            const PERIPHERAL_ID: PeripheralId = PeripheralId::$mcan;
+        }
+    };
+}
+
+implement_mcan_metadata_for!(MCAN0);
+implement_mcan_metadata_for!(MCAN1);
@@ -0,0 +1,172 @@
+//! Implementation of HAL MPU driver.
+//!
+//! Memory Protection Unit (MPU) divides the memory map into regions with their own access
+//! permissions. Any access that violates those permissions triggers MemManage fault (or HardFault,
+//! if MemManage fault is disabled).
+//!
+//! Primary use of this driver in the system is placing no-access guard regions between memory
+//! areas (ex. static storages), so an overflow from one of them is trapped immediately instead of
+//! silently corrupting the neighbouring memory.
+
+pub mod region;
+
+pub use region::{RegionAccess, RegionConfig, RegionSize};
+
+use cortex_m::asm::{dsb, isb};
+use samv71q21_pac as pac;
+
+/// MPU enable bit mask in CTRL register.
+const MPU_CTRL_ENABLE_MASK: u32 = 0x0000_0001;
+/// MPU enabled during faults bit mask in CTRL register.
+const MPU_CTRL_HFNMIENA_MASK: u32 = 0x0000_0002;
+/// Default memory map as background region bit mask in CTRL register.
+const MPU_CTRL_PRIVDEFENA_MASK: u32 = 0x0000_0004;
+/// Number of regions field offset in TYPE register.
+const MPU_TYPE_DREGION_OFFSET: u32 = 8;
+/// Number of regions field mask in TYPE register.
+const MPU_TYPE_DREGION_MASK: u32 = 0x0000_FF00;
+
+/// Structure representing Memory Protection Unit.
+pub struct Mpu {
+    /// PAC MPU instance.
+    mpu: pac::MPU,
+}
+
+impl Mpu {
+    /// Creates new instance of MPU driver and consumes PAC MPU instance.
+    ///
+    /// # Parameters
+    /// * `mpu` - PAC/Cortex-M MPU instance.
+    pub fn new(mpu: pac::MPU) -> Self {
+        Mpu { mpu }
+    }
+
+    /// Returns the number of regions supported by the MPU.
+    pub fn region_count(&self) -> u8 {
+        ((self.mpu._type.read() & MPU_TYPE_DREGION_MASK) >> MPU_TYPE_DREGION_OFFSET) as u8
+    }
+
+    /// Enables the MPU.
+    ///
+    /// # Parameters
+    /// * `use_default_memory_map` - If `true`, default memory map is used as a background region
+    ///                              for privileged accesses outside of configured regions.
+    /// * `enabled_in_fault_handlers` - If `true`, MPU stays enabled during HardFault and NMI handlers.
+    pub fn enable(&mut self, use_default_memory_map: bool, enabled_in_fault_handlers: bool) {
+        let mut ctrl = MPU_CTRL_ENABLE_MASK;
+        if use_default_memory_map {
+            ctrl |= MPU_CTRL_PRIVDEFENA_MASK;
+        }
+        if enabled_in_fault_handlers {
+            ctrl |= MPU_CTRL_HFNMIENA_MASK;
+        }
+
+        dsb();
+        unsafe { self.mpu.ctrl.write(ctrl) };
+        dsb();
+        isb();
+    }
+
+    /// Disables the MPU.
+    pub fn disable(&mut self) {
+        dsb();
+        unsafe { self.mpu.ctrl.write(0) };
+        dsb();
+        isb();
+    }
+
+    /// Returns `true` if MPU is enabled.
+    pub fn is_enabled(&self) -> bool {
+        self.mpu.ctrl.read() & MPU_CTRL_ENABLE_MASK != 0
+    }
+
+    /// Configures and enables region.
+    ///
+    /// # Parameters
+    /// * `region` - Region number.
+    /// * `config` - Region configuration.
+    ///
+    /// # Return
+    /// `()` on success, [`MpuError`] if region number is out of range, or base address is not
+    /// aligned to the region size.
+    pub fn configure_region(&mut self, region: u8, config: RegionConfig) -> Result<(), MpuError> {
+        if region >= self.region_count() {
+            return Err(MpuError::InvalidRegion(region));
+        }
+
+        if (config.base_address as u64) % config.size.bytes() != 0 {
+            return Err(MpuError::UnalignedBaseAddress(config.base_address));
+        }
+
+        dsb();
+        unsafe {
+            self.mpu.rnr.write(region as u32);
+            self.mpu.rbar.write(config.base_address);
+            self.mpu.rasr.write(config.rasr_value());
+        }
+        dsb();
+        isb();
+
+        Ok(())
+    }
+
+    /// Disables region.
+    ///
+    /// # Parameters
+    /// * `region` - Region number.
+    ///
+    /// # Return
+    /// `()` on success, [`MpuError::InvalidRegion`] if region number is out of range.
+    pub fn disable_region(&mut self, region: u8) -> Result<(), MpuError> {
+        if region >= self.region_count() {
+            return Err(MpuError::InvalidRegion(region));
+        }
+
+        dsb();
+        unsafe {
+            self.mpu.rnr.write(region as u32);
+            self.mpu.rasr.write(0);
+        }
+        dsb();
+        isb();
+
+        Ok(())
+    }
+
+    /// Configures no-access guard region at given address.
+    ///
+    /// Any access to the guard region will trigger a MemManage fault.
+    ///
+    /// # Parameters
+    /// * `region` - Region number.
+    /// * `base_address` - Address of the guard, aligned to `size`.
+    /// * `size` - Size of the guard.
+    ///
+    /// # Return
+    /// `()` on success, [`MpuError`] otherwise.
+    pub fn configure_guard_region(
+        &mut self,
+        region: u8,
+        base_address: u32,
+        size: RegionSize,
+    ) -> Result<(), MpuError> {
+        self.configure_region(
+            region,
+            RegionConfig {
+                base_address,
+                size,
+                access: RegionAccess::NoAccess,
+                execute_never: true,
+            },
+        )
+    }
+}
+
+/// MPU errors.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum MpuError {
+    /// Region number is out of supported range.
+    InvalidRegion(u8),
+    /// Region base address is not aligned to the region size.
+    UnalignedBaseAddress(u32),
+}
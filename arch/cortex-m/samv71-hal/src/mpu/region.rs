@@ -0,0 +1,99 @@
+//! Module containing MPU region configuration types.
+
+/// Region enable bit mask in RASR register.
+const MPU_RASR_ENABLE_MASK: u32 = 0x0000_0001;
+/// Region size field offset in RASR register.
+const MPU_RASR_SIZE_OFFSET: u32 = 1;
+/// Access permission field offset in RASR register.
+const MPU_RASR_AP_OFFSET: u32 = 24;
+/// Execute never bit mask in RASR register.
+const MPU_RASR_XN_MASK: u32 = 0x1000_0000;
+
+/// Structure representing MPU region configuration.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct RegionConfig {
+    /// Base address of the region. Has to be aligned to the region size.
+    pub base_address: u32,
+    /// Size of the region.
+    pub size: RegionSize,
+    /// Access permissions.
+    pub access: RegionAccess,
+    /// If true, instruction fetches from this region are forbidden.
+    pub execute_never: bool,
+}
+
+impl RegionConfig {
+    /// Returns value of RASR register for this configuration.
+    pub(super) fn rasr_value(&self) -> u32 {
+        let mut rasr = MPU_RASR_ENABLE_MASK
+            | ((self.size as u32) << MPU_RASR_SIZE_OFFSET)
+            | ((self.access as u32) << MPU_RASR_AP_OFFSET);
+
+        if self.execute_never {
+            rasr |= MPU_RASR_XN_MASK;
+        }
+
+        rasr
+    }
+}
+
+/// Region access permissions.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum RegionAccess {
+    /// Any access generates a permission fault.
+    NoAccess = 0b000,
+    /// Privileged read/write access only.
+    PrivilegedReadWrite = 0b001,
+    /// Privileged read/write, unprivileged read-only access.
+    PrivilegedReadWriteUserReadOnly = 0b010,
+    /// Full access.
+    FullAccess = 0b011,
+    /// Privileged read-only access only.
+    PrivilegedReadOnly = 0b101,
+    /// Read-only access.
+    ReadOnly = 0b110,
+}
+
+/// Region size.
+///
+/// Value of each variant is the value of SIZE field in RASR register, region size in bytes is
+/// equal to 2^(SIZE+1).
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[allow(missing_docs)]
+pub enum RegionSize {
+    Size32B = 4,
+    Size64B = 5,
+    Size128B = 6,
+    Size256B = 7,
+    Size512B = 8,
+    Size1KB = 9,
+    Size2KB = 10,
+    Size4KB = 11,
+    Size8KB = 12,
+    Size16KB = 13,
+    Size32KB = 14,
+    Size64KB = 15,
+    Size128KB = 16,
+    Size256KB = 17,
+    Size512KB = 18,
+    Size1MB = 19,
+    Size2MB = 20,
+    Size4MB = 21,
+    Size8MB = 22,
+    Size16MB = 23,
+    Size32MB = 24,
+    Size64MB = 25,
+    Size128MB = 26,
+    Size256MB = 27,
+    Size512MB = 28,
+    Size1GB = 29,
+    Size2GB = 30,
+    Size4GB = 31,
+}
+
+impl RegionSize {
+    /// Returns size of the region in bytes.
+    pub const fn bytes(&self) -> u64 {
+        1u64 << (*self as u32 + 1)
+    }
+}
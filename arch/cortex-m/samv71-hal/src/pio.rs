@@ -3,6 +3,10 @@
 //! Parallel Input/Output (PIO) driver controls general purpose pins of SAMV71 MCU.
 //! You can use it to configure the mode and state of I/O pins, or give them to other
 //! peripherals for control.
+//!
+//! All I/O ports (PIOA to PIOE) are handled by the same [`Port`] type. Multiple pins of a port
+//! can be configured at once with [`Port::configure_pins`], and [`Port::split`] returns
+//! [`PortStatus`] that reads interrupt status of the whole port.
 
 /*
 Implementation plan/ideas/issues:
@@ -18,11 +22,18 @@ pub mod input_pin;
 pub mod output_pin;
 pub mod peripheral_pin;
 pub mod pin;
+pub mod pin_config;
 pub mod port;
+pub mod port_status;
 pub mod synchronous_port;
 
-pub use pin::Pin;
+pub use input_pin::InterruptTrigger;
+pub use output_pin::DriveMode;
+pub use peripheral_pin::Peripheral;
+pub use pin::{Pin, PinState, PullResistor};
+pub use pin_config::{PinConfig, PinConfigError, PinModeConfig};
 pub use port::Port;
+pub use port_status::{CaptureStatus, PortStatus};
 pub use synchronous_port::SynchronousPort;
 
 mod port_metadata;
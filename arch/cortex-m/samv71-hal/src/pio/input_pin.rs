@@ -4,11 +4,91 @@ use super::{pin::InputMode, Pin};
 
 use embedded_hal::digital::InputPin;
 
+/// Enumeration representing events that can trigger an input pin interrupt.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum InterruptTrigger {
+    /// Interrupt is triggered on both rising and falling edge.
+    AnyEdge,
+    /// Interrupt is triggered on rising edge.
+    RisingEdge,
+    /// Interrupt is triggered on falling edge.
+    FallingEdge,
+}
+
 /// PIO-controlled pin's implementation for pin in input mode.
 ///
-/// There should be input filtering and interrupt configuration functions here.
-/// As of now, it's outside of project's requirements, so it's left for future activity.
-impl Pin<InputMode> {}
+/// Input filtering is not implemented, as it's outside of project's requirements.
+impl Pin<InputMode> {
+    /// Configures the trigger and enables the pin's interrupt.
+    ///
+    /// Port's interrupt has to be also enabled in NVIC for the interrupt handler to be called.
+    ///
+    /// # Parameters
+    /// * `trigger` - Event that triggers the interrupt.
+    pub fn enable_interrupt(&mut self, trigger: InterruptTrigger) {
+        let registers = self.registers_ref();
+
+        // Safety: See `Pin::pin_mask` description.
+        match trigger {
+            InterruptTrigger::AnyEdge => {
+                registers
+                    .aimdr
+                    .write(|w| unsafe { w.bits(self.pin_mask()) });
+            }
+            InterruptTrigger::RisingEdge | InterruptTrigger::FallingEdge => {
+                registers.esr.write(|w| unsafe { w.bits(self.pin_mask()) });
+                if trigger == InterruptTrigger::RisingEdge {
+                    registers
+                        .rehlsr
+                        .write(|w| unsafe { w.bits(self.pin_mask()) });
+                } else {
+                    registers
+                        .fellsr
+                        .write(|w| unsafe { w.bits(self.pin_mask()) });
+                }
+                registers
+                    .aimer
+                    .write(|w| unsafe { w.bits(self.pin_mask()) });
+            }
+        }
+
+        registers.ier.write(|w| unsafe { w.bits(self.pin_mask()) });
+    }
+
+    /// Disables the pin's interrupt.
+    pub fn disable_interrupt(&mut self) {
+        // Safety: See `Pin::pin_mask` description.
+        self.registers_ref()
+            .idr
+            .write(|w| unsafe { w.bits(self.pin_mask()) });
+    }
+
+    /// Returns `true` if the pin's interrupt is enabled.
+    pub fn is_interrupt_enabled(&self) -> bool {
+        self.is_pin_bit_set(self.registers_ref().imr.read().bits())
+    }
+
+    /// Reads and clears interrupt status of the whole pin's port.
+    ///
+    /// Reading the status clears pending interrupts of **all** pins of the port, so if more than
+    /// one pin of the port uses interrupts, returned status should be shared between their handlers.
+    /// Use [`Pin<InputMode>::is_interrupt_pending`] to check if this pin's interrupt is pending.
+    ///
+    /// # Return
+    /// Interrupt status of the port.
+    pub fn read_port_interrupt_status(&self) -> u32 {
+        self.registers_ref().isr.read().bits()
+    }
+
+    /// Returns `true` if the pin's interrupt is pending in given port interrupt status.
+    ///
+    /// # Parameters
+    /// * `port_status` - Status returned by [`Pin<InputMode>::read_port_interrupt_status`].
+    #[inline(always)]
+    pub fn is_interrupt_pending(&self, port_status: u32) -> bool {
+        self.is_pin_bit_set(port_status)
+    }
+}
 
 /// Implementation of InputPin trait from `embedded-hal` crate.
 /// These are just aliases of the functions from generic Pin implementation,
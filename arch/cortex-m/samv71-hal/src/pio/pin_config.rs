@@ -0,0 +1,46 @@
+//! Module containing configuration of multiple pins of a PIO port.
+//!
+//! Board setup code usually configures many pins at once, most of them given to peripherals.
+//! Instead of taking and converting each pin separately, configuration of all pins of a port can
+//! be described with an array of [`PinConfig`] and applied with [`Port::configure_pins`](super::Port::configure_pins).
+
+use super::{DriveMode, InterruptTrigger, Peripheral, PinState, PullResistor};
+
+/// Mode of the pin set by [`PinConfig`].
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum PinModeConfig {
+    /// Pin is a PIO-controlled input, with optional interrupt.
+    Input {
+        /// Event triggering the pin's interrupt, `None` if the interrupt is disabled.
+        interrupt: Option<InterruptTrigger>,
+    },
+    /// Pin is a PIO-controlled output.
+    Output {
+        /// State the pin is driven to when it becomes an output.
+        initial_state: PinState,
+        /// Driving mode of the pin.
+        drive_mode: DriveMode,
+    },
+    /// Pin is controlled by a peripheral.
+    Peripheral(Peripheral),
+}
+
+/// Configuration of a single pin of a port.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct PinConfig {
+    /// Number of the pin, in (0..=31) range.
+    pub id: u8,
+    /// Mode of the pin.
+    pub mode: PinModeConfig,
+    /// Pull resistor configuration of the pin.
+    pub pull_resistor: PullResistor,
+}
+
+/// Enumeration representing errors of port configuration.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum PinConfigError {
+    /// Pin number is out of (0..=31) range.
+    InvalidPinId(u8),
+    /// Pin is configured more than once.
+    DuplicatePin(u8),
+}
@@ -2,11 +2,17 @@
 
 use core::marker::PhantomData;
 
-use super::{pin::ResetMode, port_metadata::IoPortMetadata, Pin};
+use super::{
+    pin::ResetMode,
+    pin_config::{PinConfig, PinConfigError, PinModeConfig},
+    port_metadata::{IoPortMetadata, RegisterBlock},
+    DriveMode, InterruptTrigger, Peripheral, Pin, PinState, PortStatus, PullResistor,
+};
 
 /// Structure representing a generic I/O port.
 ///
-/// Instances of this structure can be consumed and split into pins array.
+/// Instances of this structure can be consumed and split into pins array. Before that, multiple
+/// pins can be configured at once with [`Port::configure_pins`].
 ///
 /// # Generic parameters
 /// * `PortMetadata` - PAC PIO port type. This type indicates which PIO port
@@ -38,6 +44,55 @@ impl<Instance: IoPortMetadata> Port<Instance> {
         Instance::ID
     }
 
+    /// Configures multiple pins of the port at once.
+    ///
+    /// Pins with the same setting are configured with a single register write, so this is
+    /// faster than configuring each pin separately. Configuration is validated before any pin is
+    /// configured. Pins not listed in `configs` are not modified. Output state and selected
+    /// peripheral are set before the control over the pin is changed, so the pin doesn't glitch.
+    ///
+    /// Pins taken from the port afterwards are in [`ResetMode`], and have to be converted into
+    /// their configured mode with `Pin::into_X` functions before being used by the application,
+    /// which doesn't change their configuration.
+    ///
+    /// # Parameters
+    /// * `configs` - Configuration of the pins.
+    ///
+    /// # Returns
+    /// `Ok(())` if the pins were configured, `Err(PinConfigError)` if the configuration is invalid.
+    pub fn configure_pins(&mut self, configs: &[PinConfig]) -> Result<(), PinConfigError> {
+        let mut configured_mask: u32 = 0;
+        for config in configs {
+            if config.id > 31 {
+                return Err(PinConfigError::InvalidPinId(config.id));
+            }
+            let pin_mask = 1u32 << config.id;
+            if configured_mask & pin_mask != 0 {
+                return Err(PinConfigError::DuplicatePin(config.id));
+            }
+            configured_mask |= pin_mask;
+        }
+
+        let mut masks = PinConfigMasks::default();
+        for config in configs {
+            masks.add(config);
+        }
+
+        // Safety: Only bits of the configured pins are set in the masks.
+        unsafe { masks.apply(self.registers_ref(), configured_mask) };
+
+        Ok(())
+    }
+
+    /// Consumes port instance and returns it's pins, together with the status of the port.
+    ///
+    /// # Returns
+    /// Port status, that can be used to read interrupt status of the whole port, and pins of
+    /// the port.
+    pub const fn split(self) -> (PortStatus<Instance>, Pins) {
+        (PortStatus::new(), self.into_pins())
+    }
+
     /// Consumes port instance and returns it's pins.
     pub const fn into_pins(self) -> Pins {
         [
@@ -75,4 +130,145 @@ impl<Instance: IoPortMetadata> Port<Instance> {
             Some(Pin::new(&self, 31)),
         ]
     }
+    /// Returns a reference to the registers of the port.
+    ///
+    /// # Safety
+    /// This function is safe to use, because there can be only one instance of the port, and
+    /// pins of the port can't be used until the port is consumed.
+    const fn registers_ref(&self) -> &RegisterBlock {
+        unsafe { &*Instance::REGISTERS }
+    }
+}
+
+/// Bitmasks of the pins, grouped by the registers written to configure them.
+#[derive(Default)]
+struct PinConfigMasks {
+    /// Pins controlled by PIO controller.
+    pio: u32,
+    /// Pins controlled by a peripheral.
+    peripheral: u32,
+    /// Pins with peripheral select bit set in ABCDSR\[0\] register.
+    select_0: u32,
+    /// Pins with peripheral select bit set in ABCDSR\[1\] register.
+    select_1: u32,
+    /// Pins in input mode.
+    input: u32,
+    /// Pins in output mode.
+    output: u32,
+    /// Outputs driven high.
+    output_high: u32,
+    /// Outputs driven low.
+    output_low: u32,
+    /// Outputs in open-drain mode.
+    open_drain: u32,
+    /// Outputs in push-pull mode.
+    push_pull: u32,
+    /// Pins with pull-up resistor.
+    pull_up: u32,
+    /// Pins with pull-down resistor.
+    pull_down: u32,
+    /// Inputs with interrupt triggered on any edge.
+    any_edge: u32,
+    /// Inputs with interrupt triggered on rising edge.
+    rising_edge: u32,
+    /// Inputs with interrupt triggered on falling edge.
+    falling_edge: u32,
+}
+
+impl PinConfigMasks {
+    /// Adds pin configuration to the masks.
+    ///
+    /// # Parameters
+    /// * `config` - Configuration of the pin. Pin ID must be in (0..=31) range.
+    fn add(&mut self, config: &PinConfig) {
+        let pin_mask = 1u32 << config.id;
+
+        match config.mode {
+            PinModeConfig::Input { interrupt } => {
+                self.pio |= pin_mask;
+                self.input |= pin_mask;
+                match interrupt {
+                    Some(InterruptTrigger::AnyEdge) => self.any_edge |= pin_mask,
+                    Some(InterruptTrigger::RisingEdge) => self.rising_edge |= pin_mask,
+                    Some(InterruptTrigger::FallingEdge) => self.falling_edge |= pin_mask,
+                    None => {}
+                }
+            }
+            PinModeConfig::Output {
+                initial_state,
+                drive_mode,
+            } => {
+                self.pio |= pin_mask;
+                self.output |= pin_mask;
+                match initial_state {
+                    PinState::High => self.output_high |= pin_mask,
+                    PinState::Low => self.output_low |= pin_mask,
+                }
+                match drive_mode {
+                    DriveMode::OpenDrain => self.open_drain |= pin_mask,
+                    DriveMode::PushPull => self.push_pull |= pin_mask,
+                }
+            }
+            PinModeConfig::Peripheral(peripheral) => {
+                self.peripheral |= pin_mask;
+                if matches!(peripheral, Peripheral::B | Peripheral::D) {
+                    self.select_0 |= pin_mask;
+                }
+                if matches!(peripheral, Peripheral::C | Peripheral::D) {
+                    self.select_1 |= pin_mask;
+                }
+            }
+        }
+
+        match config.pull_resistor {
+            PullResistor::Up => self.pull_up |= pin_mask,
+            PullResistor::Down => self.pull_down |= pin_mask,
+            PullResistor::None => {}
+        }
+    }
+
+    /// Writes the configuration to the port registers.
+    ///
+    /// # Parameters
+    /// * `registers` - Registers of the port.
+    /// * `configured_mask` - Bitmask of all configured pins.
+    ///
+    /// # Safety
+    /// Only bits of the pins owned by the caller can be set in the masks and `configured_mask`.
+    unsafe fn apply(&self, registers: &RegisterBlock, configured_mask: u32) {
+        // Interrupts are disabled while the pins are reconfigured.
+        registers.idr.write(|w| w.bits(configured_mask));
+
+        registers
+            .pudr
+            .write(|w| w.bits(configured_mask & !self.pull_up));
+        registers
+            .ppddr
+            .write(|w| w.bits(configured_mask & !self.pull_down));
+        registers.puer.write(|w| w.bits(self.pull_up));
+        registers.ppder.write(|w| w.bits(self.pull_down));
+
+        let peripheral_pins = self.peripheral;
+        registers.abcdsr[0].modify(|r, w| w.bits((r.bits() & !peripheral_pins) | self.select_0));
+        registers.abcdsr[1].modify(|r, w| w.bits((r.bits() & !peripheral_pins) | self.select_1));
+        registers.pdr.write(|w| w.bits(self.peripheral));
+
+        registers.sodr.write(|w| w.bits(self.output_high));
+        registers.codr.write(|w| w.bits(self.output_low));
+        registers.mder.write(|w| w.bits(self.open_drain));
+        registers.mddr.write(|w| w.bits(self.push_pull));
+        registers.oer.write(|w| w.bits(self.output));
+        registers.odr.write(|w| w.bits(self.input));
+        registers.per.write(|w| w.bits(self.pio));
+
+        registers.aimdr.write(|w| w.bits(self.any_edge));
+        let edge_triggered = self.rising_edge | self.falling_edge;
+        registers.esr.write(|w| w.bits(edge_triggered));
+        registers.rehlsr.write(|w| w.bits(self.rising_edge));
+        registers.fellsr.write(|w| w.bits(self.falling_edge));
+        registers.aimer.write(|w| w.bits(edge_triggered));
+        registers
+            .ier
+            .write(|w| w.bits(self.any_edge | edge_triggered));
+    }
 }
@@ -0,0 +1,83 @@
+//! Module containing port-level status of a PIO port.
+
+use core::marker::PhantomData;
+
+use super::port_metadata::{IoPortMetadata, RegisterBlock};
+
+/// Structure giving access to status registers shared by all pins of a port.
+///
+/// Interrupt status of the port is cleared when it's read, so there can be only one instance of
+/// this structure per port, created with [`Port::split`](super::Port::split). It's usually moved
+/// to the port's interrupt handler, which dispatches the pending interrupts to the pins.
+///
+/// # Generic parameters
+/// * `PortMetadata` - PAC PIO port type.
+pub struct PortStatus<PortMetadata: IoPortMetadata> {
+    /// Port marker.
+    _port_meta: PhantomData<PortMetadata>,
+}
+
+/// Parallel capture status of the port.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct CaptureStatus {
+    /// New data was captured and is ready to be read.
+    pub data_ready: bool,
+    /// Captured data was overwritten before it was read.
+    pub overrun: bool,
+}
+
+impl<Instance: IoPortMetadata> PortStatus<Instance> {
+    /// Creates new port status instance.
+    pub(super) const fn new() -> Self {
+        Self {
+            _port_meta: PhantomData,
+        }
+    }
+
+    /// Returns ID (uppercase letter) of the port.
+    #[inline(always)]
+    pub const fn id(&self) -> char {
+        Instance::ID
+    }
+
+    /// Reads and clears interrupt status of the port.
+    ///
+    /// # Return
+    /// Bitmask of the pins with pending interrupts.
+    pub fn read_interrupt_status(&mut self) -> u32 {
+        self.registers_ref().isr.read().bits()
+    }
+
+    /// Returns bitmask of the pins with enabled interrupts.
+    pub fn interrupt_mask(&self) -> u32 {
+        self.registers_ref().imr.read().bits()
+    }
+
+    /// Returns logic states of all pins of the port, as a bitmask of the pins in high state.
+    pub fn pin_states(&self) -> u32 {
+        self.registers_ref().pdsr.read().bits()
+    }
+
+    /// Reads parallel capture status of the port.
+    ///
+    /// Overrun flag is cleared when the status is read, data ready flag is cleared when the
+    /// captured data is read.
+    pub fn read_capture_status(&mut self) -> CaptureStatus {
+        let status = self.registers_ref().pcisr.read();
+
+        CaptureStatus {
+            data_ready: status.drdy().bit_is_set(),
+            overrun: status.ovre().bit_is_set(),
+        }
+    }
+
+    /// Returns a reference to the registers of the port.
+    ///
+    /// # Safety
+    /// This function is safe to use, because only status registers are accessed, and registers
+    /// that are cleared on read are accessed only with a mutable reference to the only instance of
+    /// this structure.
+    const fn registers_ref(&self) -> &RegisterBlock {
+        unsafe { &*Instance::REGISTERS }
+    }
+}
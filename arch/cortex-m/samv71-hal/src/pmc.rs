@@ -16,7 +16,9 @@ use self::config::master_clock::*;
 use self::config::pck::*;
 use self::config::peripheral::*;
 use crate::pac;
+use crate::register_dump::{write_register, RegisterDump};
 use crate::time;
+use core::fmt;
 use cortex_m::asm;
 
 /// Structure representing Power Management Controller (PMC).
@@ -590,3 +592,24 @@ impl PMC {
         }
     }
 }
+
+impl RegisterDump for PMC {
+    /// Dumps PMC clock configuration and clock status registers. Status register and write
+    /// protection status register (which are cleared on read) are omitted.
+    fn dump_registers(&self, writer: &mut impl fmt::Write) -> fmt::Result {
+        write_register(writer, "SCSR", self.pmc.scsr.read().bits())?;
+        write_register(writer, "PCSR0", self.pmc.pcsr0.read().bits())?;
+        write_register(writer, "PCSR1", self.pmc.pcsr1.read().bits())?;
+        write_register(writer, "CKGR_UCKR", self.pmc.ckgr_uckr.read().bits())?;
+        write_register(writer, "CKGR_MOR", self.pmc.ckgr_mor.read().bits())?;
+        write_register(writer, "CKGR_MCFR", self.pmc.ckgr_mcfr.read().bits())?;
+        write_register(writer, "CKGR_PLLAR", self.pmc.ckgr_pllar.read().bits())?;
+        write_register(writer, "MCKR", self.pmc.mckr.read().bits())?;
+        write_register(writer, "USB", self.pmc.usb.read().bits())?;
+        for (index, pck) in self.pmc.pck.iter().enumerate() {
+            writeln!(writer, "PCK{:<7} 0x{:08X}", index, pck.read().bits())?;
+        }
+        write_register(writer, "IMR", self.pmc.imr.read().bits())?;
+        write_register(writer, "WPMR", self.pmc.wpmr.read().bits())
+    }
+}
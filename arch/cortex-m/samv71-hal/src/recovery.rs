@@ -0,0 +1,22 @@
+//! Recovery of peripherals without resetting the whole MCU.
+//!
+//! A peripheral can get wedged (for example by a single event upset in its internal logic, or a
+//! glitch on the bus) in a way that can't be fixed by its regular API. Instead of rebooting the
+//! whole MCU, drivers implementing [`Recoverable`] trait can power-cycle their peripheral by
+//! gating its clock off and on, reset it with its software reset bit, and reapply configuration
+//! stored in the driver. This can be called for example from FDIR recovery actions.
+
+use crate::pmc::PMC;
+
+/// Trait for drivers of peripherals that can be recovered by a reset.
+pub trait Recoverable {
+    /// Resets the peripheral and reconfigures it.
+    ///
+    /// This gates peripheral's clock off and on, triggers software reset of the peripheral and
+    /// reapplies configuration stored in the driver. Enabled interrupts are preserved. Data that
+    /// was being transferred when this function was called is lost.
+    ///
+    /// # Parameters
+    /// * `pmc` - PMC driver, used to gate peripheral's clock.
+    fn reset_and_reconfigure(&mut self, pmc: &mut PMC);
+}
@@ -0,0 +1,30 @@
+//! Dumping of peripheral registers.
+//!
+//! Drivers implementing [`RegisterDump`] trait can write the state of their peripheral's registers
+//! in human-readable form, so it can be attached to bug reports or logged together with fault
+//! records without attaching a debugger and reading the registers manually.
+//!
+//! Only registers that can be read without side effects are dumped. Registers whose flags are
+//! cleared on read (for example status registers of some peripherals), and data registers, are
+//! omitted, so dumping the registers never changes the state of the peripheral.
+
+use core::fmt;
+
+/// Trait for drivers that can dump registers of their peripheral.
+pub trait RegisterDump {
+    /// Writes current values of peripheral's registers, one register per line.
+    ///
+    /// # Parameters
+    /// * `writer` - Destination of the dump, for example a log or a buffer.
+    fn dump_registers(&self, writer: &mut impl fmt::Write) -> fmt::Result;
+}
+
+/// Writes a single register line of the dump.
+///
+/// # Parameters
+/// * `writer` - Destination of the dump.
+/// * `name` - Name of the register.
+/// * `value` - Value of the register.
+pub(crate) fn write_register(writer: &mut impl fmt::Write, name: &str, value: u32) -> fmt::Result {
+    writeln!(writer, "{:<10} 0x{:08X}", name, value)
+}
@@ -23,21 +23,28 @@
 //! * Interrupt configuration and status management
 //! * Mode Fault Detection
 //! * DMA transfers
+//! * Sharing the bus between multiple devices, with hardware or GPIO chip select, via
+//!   [`SpiDevice`](device::SpiDevice) implementing `embedded_hal::spi::SpiDevice`
+//! * [Timeouts](timeout) of blocking transactions
 //!
 //! Specifically, it currently does **NOT** support:
 //! * SPI Client mode
 //! * Variable Chip Select signal management
 //! * Register Write Protection
 
+use core::fmt;
 use core::marker::PhantomData;
 
+use crate::register_dump::{write_register, RegisterDump};
+
 use self::{
-    config::MasterConfig, metadata::SPIMetadata, reader::Reader, status_reader::StatusReader,
-    writer::Writer,
+    chip_config::ChipConfig, config::MasterConfig, metadata::SPIMetadata, reader::Reader,
+    status_reader::StatusReader, timeout::TransferTimeout, writer::Writer,
 };
 
 pub mod chip_config;
 pub mod config;
+pub mod device;
 pub mod embedded_hal;
 pub mod interrupts;
 pub mod master;
@@ -45,6 +52,7 @@ pub mod metadata;
 pub mod reader;
 pub mod status;
 pub mod status_reader;
+pub mod timeout;
 pub mod writer;
 
 /// Amount of supported chip select signals and configurations.
@@ -60,10 +68,15 @@ pub struct NotConfigured;
 
 /// Typestate struct representing SPI in configured Master state.
 pub struct Master {
-    /// If this flag is set, then the chip with ID equal to the index is configured and can be used
-    /// for transactions. Trying to start a transaction with unconfigured chip should always result
-    /// in a runtime error.
-    is_chip_select_configured: [bool; SUPPORTED_CHIP_AMOUNT],
+    /// Master mode configuration, reapplied when SPI is reset with
+    /// [`Recoverable::reset_and_reconfigure`](crate::recovery::Recoverable::reset_and_reconfigure).
+    config: MasterConfig,
+    /// Configuration of the chip with ID equal to the index, or `None` if the chip isn't configured
+    /// and can't be used for transactions. Trying to start a transaction with unconfigured chip
+    /// should always result in a runtime error.
+    chip_configs: [Option<ChipConfig>; SUPPORTED_CHIP_AMOUNT],
+    /// Maximum duration of blocking transactions, or `None` if they can block forever.
+    transfer_timeout: Option<TransferTimeout>,
 }
 
 impl State for NotConfigured {}
@@ -112,6 +125,31 @@ impl<Instance: SPIMetadata> Spi<Instance, NotConfigured> {
     /// transaction. Trying to perform transaction with unconfigured chip will result in runtime
     /// error.
     pub fn into_master(mut self, config: MasterConfig) -> Spi<Instance, Master> {
+        self.write_master_config(config);
+        self.enable_hardware();
+
+        Spi::transform(
+            self,
+            Master {
+                config,
+                chip_configs: [None; SUPPORTED_CHIP_AMOUNT],
+                transfer_timeout: None,
+            },
+        )
+    }
+}
+
+impl<Instance: SPIMetadata, CurrentState: State> Spi<Instance, CurrentState> {
+    /// Disables SPI and restores the configuration to defaults.
+    pub fn reset(mut self) -> Spi<Instance, NotConfigured> {
+        self.disable_hardware();
+        self.disable_all_irqs();
+        self.reset_hardware();
+        Spi::transform(self, NotConfigured)
+    }
+
+    /// Writes Master mode configuration to the hardware.
+    fn write_master_config(&mut self, config: MasterConfig) {
         Instance::registers().mr.write(|w| {
             w.mstr()
                 .master() // Master mode
@@ -130,24 +168,6 @@ impl<Instance: SPIMetadata> Spi<Instance, NotConfigured> {
                 .dlybcs()
                 .variant(config.chip_selection_delay.get())
         });
-        self.enable_hardware();
-
-        Spi::transform(
-            self,
-            Master {
-                is_chip_select_configured: [false, false, false, false],
-            },
-        )
-    }
-}
-
-impl<Instance: SPIMetadata, CurrentState: State> Spi<Instance, CurrentState> {
-    /// Disables SPI and restores the configuration to defaults.
-    pub fn reset(mut self) -> Spi<Instance, NotConfigured> {
-        self.disable_hardware();
-        self.disable_all_irqs();
-        self.reset_hardware();
-        Spi::transform(self, NotConfigured)
     }
 
     /// Returns status reader (or None if it's already been taken)
@@ -212,3 +232,18 @@ impl<Instance: SPIMetadata, CurrentState: State> Spi<Instance, CurrentState> {
         }
     }
 }
+
+impl<Instance: SPIMetadata, CurrentState: State> RegisterDump for Spi<Instance, CurrentState> {
+    /// Dumps SPI registers. Status register (which clears error flags on read), receive data
+    /// register and write protection status register are omitted.
+    fn dump_registers(&self, writer: &mut impl fmt::Write) -> fmt::Result {
+        let registers = Instance::registers();
+        write_register(writer, "MR", registers.mr.read().bits())?;
+        write_register(writer, "IMR", registers.imr.read().bits())?;
+        write_register(writer, "CSR0", registers.csr[0].read().bits())?;
+        write_register(writer, "CSR1", registers.csr[1].read().bits())?;
+        write_register(writer, "CSR2", registers.csr[2].read().bits())?;
+        write_register(writer, "CSR3", registers.csr[3].read().bits())?;
+        write_register(writer, "WPMR", registers.wpmr.read().bits())
+    }
+}
@@ -0,0 +1,235 @@
+//! Implementation of `embedded-hal` SPI device, sharing SPI bus between multiple chips.
+//!
+//! [`SpiDevice`] represents a single chip connected to the SPI bus. It owns chip's configuration
+//! (mode, word size, clock) and chip select, and borrows the bus for the duration of each
+//! transaction, so multiple devices can be created for the same bus and passed to drivers using
+//! `embedded_hal::spi::SpiDevice` trait.
+//!
+//! Chip select can be either driven by SPI hardware (NPCS line), or by a GPIO pin. The latter
+//! allows connecting more than [`SUPPORTED_CHIP_AMOUNT`](super::SUPPORTED_CHIP_AMOUNT) devices
+//! to a single bus - in that case, chip configuration is still stored in one of the SPI chip
+//! configuration slots, but NPCS line of that slot should not be connected to anything.
+//!
+//! Same restrictions as for `SpiBus` implementation apply, see
+//! [`embedded_hal`](super::embedded_hal) module.
+
+use core::cell::RefCell;
+
+use embedded_hal::delay::DelayUs;
+use embedded_hal::digital::OutputPin;
+use embedded_hal::spi::{Error, ErrorKind, ErrorType, Operation};
+
+use super::{
+    chip_config::{ChipConfig, ChipSelectBehavior},
+    config::SelectedChip,
+    embedded_hal::SpiError,
+    metadata::SPIMetadata,
+    Master, Spi,
+};
+
+/// Chip select of SPI device.
+///
+/// # Generic parameters
+/// * `Pin` - Type of GPIO pin used as chip select.
+#[derive(Debug)]
+pub enum ChipSelect<Pin: OutputPin> {
+    /// Chip select is driven by SPI hardware, using NPCS line of device's chip.
+    Hardware,
+    /// Chip select is driven by GPIO pin. Pin is active low.
+    Gpio(Pin),
+}
+
+/// SPI device configuration.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct SpiDeviceConfig {
+    /// Chip configuration slot used by the device. With hardware chip select, this is also the
+    /// NPCS line of the device.
+    pub chip: SelectedChip,
+    /// Chip configuration, applied to the chip slot before transaction if it differs from the
+    /// current one. Chip select behavior is ignored, as chip select is always kept active for the
+    /// whole transaction.
+    pub chip_config: ChipConfig,
+    /// Delay between chip select activation and the first operation, in microseconds. Applies
+    /// only to GPIO chip select, hardware chip select is activated by the first transfer, so
+    /// `delay_before_first_clock` of the chip configuration should be used instead.
+    pub pre_delay_us: u32,
+    /// Delay between the last operation and chip select deactivation, in microseconds.
+    pub post_delay_us: u32,
+}
+
+/// Errors that may happen during SPI device transaction.
+///
+/// # Generic parameters
+/// * `PinError` - Error type of chip select pin.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum SpiDeviceError<PinError> {
+    /// SPI bus is already used by another device. This can happen only if transaction is started
+    /// from the interrupt that preempted other transaction on the same bus.
+    BusBusy,
+    /// SPI bus error.
+    Bus(SpiError),
+    /// Chip select pin error.
+    ChipSelect(PinError),
+}
+
+impl<PinError: core::fmt::Debug> Error for SpiDeviceError<PinError> {
+    fn kind(&self) -> ErrorKind {
+        match self {
+            SpiDeviceError::BusBusy => ErrorKind::Other,
+            SpiDeviceError::Bus(error) => error.kind(),
+            SpiDeviceError::ChipSelect(_) => ErrorKind::ChipSelectFault,
+        }
+    }
+}
+
+/// Single device on the shared SPI bus.
+///
+/// # Generic parameters
+/// * `Instance` - PAC SPI instance.
+/// * `Pin` - Type of GPIO pin used as chip select.
+/// * `Delay` - Delay provider used for pre-, post- and operation delays.
+pub struct SpiDevice<'a, Instance: SPIMetadata, Pin: OutputPin, Delay: DelayUs> {
+    /// Shared SPI bus.
+    bus: &'a RefCell<Spi<Instance, Master>>,
+    /// Device's chip select.
+    chip_select: ChipSelect<Pin>,
+    /// Device's configuration.
+    config: SpiDeviceConfig,
+    /// Delay provider.
+    delay: Delay,
+}
+
+impl<'a, Instance: SPIMetadata, Pin: OutputPin, Delay: DelayUs>
+    SpiDevice<'a, Instance, Pin, Delay>
+{
+    /// Creates new SPI device on the shared bus.
+    ///
+    /// GPIO chip select pin is deactivated (driven high) during creation.
+    ///
+    /// # Parameters
+    /// * `bus` - Shared SPI bus, in Master mode.
+    /// * `chip_select` - Device's chip select.
+    /// * `config` - Device's configuration.
+    /// * `delay` - Delay provider.
+    ///
+    /// # Returns
+    /// SPI device, or [`SpiDeviceError::ChipSelect`] if chip select pin couldn't be deactivated.
+    pub fn new(
+        bus: &'a RefCell<Spi<Instance, Master>>,
+        mut chip_select: ChipSelect<Pin>,
+        config: SpiDeviceConfig,
+        delay: Delay,
+    ) -> Result<Self, SpiDeviceError<Pin::Error>> {
+        if let ChipSelect::Gpio(pin) = &mut chip_select {
+            pin.set_high().map_err(SpiDeviceError::ChipSelect)?;
+        }
+
+        Ok(Self {
+            bus,
+            chip_select,
+            config,
+            delay,
+        })
+    }
+
+    /// Returns device's configuration.
+    pub fn config(&self) -> SpiDeviceConfig {
+        self.config
+    }
+
+    /// Releases the device, returning its chip select and delay provider.
+    pub fn release(self) -> (ChipSelect<Pin>, Delay) {
+        (self.chip_select, self.delay)
+    }
+
+    /// Selects device's chip on the bus, and applies its configuration if it differs from the
+    /// current one. Chip select is configured to be kept active until the end of transaction.
+    fn prepare_bus(&self, bus: &mut Spi<Instance, Master>) {
+        let chip_config = ChipConfig {
+            chip_select_behavior: ChipSelectBehavior::KeepActive,
+            ..self.config.chip_config
+        };
+
+        if bus.state.chip_configs[self.config.chip as usize] != Some(chip_config) {
+            bus.configure_chip(self.config.chip, chip_config);
+        }
+        if bus.state.config.selected_chip != self.config.chip {
+            bus.change_chip(self.config.chip);
+        }
+    }
+
+    /// Performs transaction operations on the bus.
+    ///
+    /// Transfer timeout of the bus, if it's set, limits duration of the whole transaction.
+    fn perform_operations(
+        &mut self,
+        bus: &mut Spi<Instance, Master>,
+        operations: &mut [Operation<'_, u8>],
+    ) -> Result<(), SpiError> {
+        let deadline = bus.start_deadline();
+        let deadline = deadline.as_ref();
+
+        for operation in operations {
+            match operation {
+                Operation::Read(words) => bus.read_until(words, deadline)?,
+                Operation::Write(words) => bus.write_until(words, deadline)?,
+                Operation::Transfer(read, write) => bus.transfer_until(read, write, deadline)?,
+                Operation::TransferInPlace(words) => {
+                    bus.transfer_in_place_until(words, deadline)?
+                }
+                Operation::DelayUs(us) => {
+                    bus.flush_until(deadline)?;
+                    self.delay.delay_us(*us);
+                }
+            }
+        }
+
+        bus.flush_until(deadline)
+    }
+}
+
+impl<'a, Instance: SPIMetadata, Pin: OutputPin, Delay: DelayUs> ErrorType
+    for SpiDevice<'a, Instance, Pin, Delay>
+{
+    type Error = SpiDeviceError<Pin::Error>;
+}
+
+impl<'a, Instance: SPIMetadata, Pin: OutputPin, Delay: DelayUs> embedded_hal::spi::SpiDevice<u8>
+    for SpiDevice<'a, Instance, Pin, Delay>
+{
+    /// Performs a transaction on the device.
+    ///
+    /// Bus is locked for the whole transaction. Chip select is activated, pre-delay is waited
+    /// (GPIO chip select only), operations are performed, bus is flushed, post-delay is waited and
+    /// chip select is deactivated. Chip select is deactivated even if any of the operations fails.
+    ///
+    /// # Parameters
+    /// * `operations` - Operations to perform.
+    ///
+    /// # Returns
+    /// Ok(()) on success, [`SpiDeviceError`] on error.
+    fn transaction(&mut self, operations: &mut [Operation<'_, u8>]) -> Result<(), Self::Error> {
+        let bus = self.bus;
+        let mut bus = bus.try_borrow_mut().map_err(|_| SpiDeviceError::BusBusy)?;
+        self.prepare_bus(&mut bus);
+
+        if let ChipSelect::Gpio(pin) = &mut self.chip_select {
+            pin.set_low().map_err(SpiDeviceError::ChipSelect)?;
+            self.delay.delay_us(self.config.pre_delay_us);
+        }
+
+        let result = self.perform_operations(&mut bus, operations);
+
+        self.delay.delay_us(self.config.post_delay_us);
+        let chip_select_result = match &mut self.chip_select {
+            ChipSelect::Hardware => {
+                Instance::registers().cr.write(|w| w.lastxfer().set_bit());
+                Ok(())
+            }
+            ChipSelect::Gpio(pin) => pin.set_high(),
+        };
+
+        result.map_err(SpiDeviceError::Bus)?;
+        chip_select_result.map_err(SpiDeviceError::ChipSelect)
+    }
+}
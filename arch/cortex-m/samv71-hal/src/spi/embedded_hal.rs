@@ -10,8 +10,16 @@
 //! Reader, Writer and StatusReader **must be present in driver's instance**, every operation checks
 //! their presence and will fail if those are missing. Flush requires only StatusReader - missing
 //! Reader or Writer will not fail it, but all I/O operations require all three of them to be present.
-
-use super::{metadata::SPIMetadata, status_reader::StatusReader, Master, Spi};
+//!
+//! Operations wait for SPI status without limit, unless
+//! [transfer timeout](super::Spi::set_transfer_timeout) is set. With the timeout, each operation
+//! (or each transaction of [`SpiDevice`](super::device::SpiDevice)) fails with
+//! [`SpiError::Timeout`] when it doesn't complete in time.
+
+use super::{
+    metadata::SPIMetadata, status::SpiStatus, status_reader::StatusReader, timeout::Deadline,
+    Master, Spi,
+};
 pub use embedded_hal::spi::SpiBus;
 use embedded_hal::spi::{Error, ErrorKind, ErrorType};
 
@@ -31,6 +39,9 @@ pub enum SpiError {
     ModeFault,
     /// Overrun error.
     Overrun,
+    /// Operation didn't complete before transfer timeout. Received data was discarded and
+    /// hardware chip select was released.
+    Timeout,
 }
 
 impl Error for SpiError {
@@ -41,6 +52,7 @@ impl Error for SpiError {
             SpiError::StatusReaderNotAvailable => ErrorKind::Other,
             SpiError::ModeFault => ErrorKind::ModeFault,
             SpiError::Overrun => ErrorKind::Overrun,
+            SpiError::Timeout => ErrorKind::Other,
         }
     }
 }
@@ -61,17 +73,7 @@ impl<Instance: SPIMetadata> SpiBus<u8> for Spi<Instance, Master> {
     /// # Returns
     /// Ok(()) on success, [`SpiError`] on SPI error.
     fn read(&mut self, words: &mut [u8]) -> Result<(), Self::Error> {
-        let status_reader = self.get_status_reader_ref()?;
-        self.check_io_presence()?;
-
-        for word in words {
-            self.transmit_value(0);
-            status_reader
-                .wait_for_status(|status| status.interrupts.rx_data_register_full, usize::MAX);
-            *word = self.get_received_data() as u8;
-        }
-
-        Ok(())
+        self.read_until(words, self.start_deadline().as_ref())
     }
 
     /// Writes provided `words` to currently selected SPI device.
@@ -95,6 +97,86 @@ impl<Instance: SPIMetadata> SpiBus<u8> for Spi<Instance, Master> {
     /// # Returns
     /// Ok(()) on success, [`SpiError`] on SPI error.
     fn write(&mut self, words: &[u8]) -> Result<(), Self::Error> {
+        self.write_until(words, self.start_deadline().as_ref())
+    }
+
+    /// Performs an SPI transfer to currently selected device.
+    /// Blocks until the transaction is finished (last word is received, which also implies that
+    /// last word is transmitted).
+    /// Flushing is not necessary after this operation.
+    ///
+    /// # Parameters
+    /// * `read` - Slice for incoming data.
+    /// * `write` - Slice of words to be transmitted.
+    ///
+    /// # Returns
+    /// Ok(()) on success, [`SpiError`] on SPI error.
+    fn transfer(&mut self, read: &mut [u8], write: &[u8]) -> Result<(), Self::Error> {
+        self.transfer_until(read, write, self.start_deadline().as_ref())
+    }
+
+    /// Performs an SPI transfer to currently selected device.
+    /// Blocks until the transaction is finished (last word is received, which also implies that
+    /// last word is transmitted).
+    /// Flushing is not necessary after this operation.
+    ///
+    /// # Parameters
+    /// * `words` - Slice used for transfer, should contain data to be transmitted and will be
+    ///             filled with received data.
+    ///
+    /// # Returns
+    /// Ok(()) on success, [`SpiError`] on SPI error.
+    fn transfer_in_place(&mut self, words: &mut [u8]) -> Result<(), Self::Error> {
+        self.transfer_in_place_until(words, self.start_deadline().as_ref())
+    }
+
+    fn flush(&mut self) -> Result<(), Self::Error> {
+        self.flush_until(self.start_deadline().as_ref())
+    }
+}
+
+/// Additional functions used by SpiBus
+impl<Instance: SPIMetadata> Spi<Instance, Master> {
+    /// Reads data from currently selected SPI device, until the deadline.
+    ///
+    /// See [`SpiBus::read`].
+    ///
+    /// # Parameters
+    /// * `words` - Slice for incoming data. It's length determines transfer length.
+    /// * `deadline` - Deadline of the operation, or `None` if it's not limited.
+    pub(super) fn read_until(
+        &self,
+        words: &mut [u8],
+        deadline: Option<&Deadline>,
+    ) -> Result<(), SpiError> {
+        let status_reader = self.get_status_reader_ref()?;
+        self.check_io_presence()?;
+
+        for word in words {
+            self.transmit_value(0);
+            self.wait_for_status_until(
+                status_reader,
+                |status| status.interrupts.rx_data_register_full,
+                deadline,
+            )?;
+            *word = self.get_received_data() as u8;
+        }
+
+        Ok(())
+    }
+
+    /// Writes provided `words` to currently selected SPI device, until the deadline.
+    ///
+    /// See [`SpiBus::write`].
+    ///
+    /// # Parameters
+    /// * `words` - Slice of words to be transmitted.
+    /// * `deadline` - Deadline of the operation, or `None` if it's not limited.
+    pub(super) fn write_until(
+        &self,
+        words: &[u8],
+        deadline: Option<&Deadline>,
+    ) -> Result<(), SpiError> {
         let status_reader = self.get_status_reader_ref()?;
         self.check_io_presence()?;
 
@@ -105,8 +187,11 @@ impl<Instance: SPIMetadata> SpiBus<u8> for Spi<Instance, Master> {
             // is to wait for RX data flag, as it'll be risen after the word is transmitted, but it's
             // not guaranteed to work in every case (as it doesn't guarantee that the transfer will
             // be a single SPI transaction, which may sometimes be necessary).
-            status_reader
-                .wait_for_status(|status| status.interrupts.rx_data_register_full, usize::MAX);
+            self.wait_for_status_until(
+                status_reader,
+                |status| status.interrupts.rx_data_register_full,
+                deadline,
+            )?;
             // Dummy read to prevent overrun error
             self.get_received_data();
         }
@@ -114,18 +199,20 @@ impl<Instance: SPIMetadata> SpiBus<u8> for Spi<Instance, Master> {
         Ok(())
     }
 
-    /// Performs an SPI transfer to currently selected device.
-    /// Blocks until the transaction is finished (last word is received, which also implies that
-    /// last word is transmitted).
-    /// Flushing is not necessary after this operation.
+    /// Performs an SPI transfer to currently selected device, until the deadline.
+    ///
+    /// See [`SpiBus::transfer`].
     ///
     /// # Parameters
     /// * `read` - Slice for incoming data.
     /// * `write` - Slice of words to be transmitted.
-    ///
-    /// # Returns
-    /// Ok(()) on success, [`SpiError`] on SPI error.
-    fn transfer(&mut self, read: &mut [u8], write: &[u8]) -> Result<(), Self::Error> {
+    /// * `deadline` - Deadline of the operation, or `None` if it's not limited.
+    pub(super) fn transfer_until(
+        &self,
+        read: &mut [u8],
+        write: &[u8],
+        deadline: Option<&Deadline>,
+    ) -> Result<(), SpiError> {
         let status_reader = self.get_status_reader_ref()?;
         self.check_io_presence()?;
 
@@ -175,23 +262,26 @@ impl<Instance: SPIMetadata> SpiBus<u8> for Spi<Instance, Master> {
             if status.interrupts.mode_fault_error {
                 return Err(SpiError::ModeFault);
             }
+
+            self.check_deadline(deadline)?;
         }
 
         Ok(())
     }
 
-    /// Performs an SPI transfer to currently selected device.
-    /// Blocks until the transaction is finished (last word is received, which also implies that
-    /// last word is transmitted).
-    /// Flushing is not necessary after this operation.
+    /// Performs an SPI transfer to currently selected device, until the deadline.
+    ///
+    /// See [`SpiBus::transfer_in_place`].
     ///
     /// # Parameters
     /// * `words` - Slice used for transfer, should contain data to be transmitted and will be
-    ///             filled with received data.
-    ///
-    /// # Returns
-    /// Ok(()) on success, [`SpiError`] on SPI error.
-    fn transfer_in_place(&mut self, words: &mut [u8]) -> Result<(), Self::Error> {
+    ///   filled with received data.
+    /// * `deadline` - Deadline of the operation, or `None` if it's not limited.
+    pub(super) fn transfer_in_place_until(
+        &self,
+        words: &mut [u8],
+        deadline: Option<&Deadline>,
+    ) -> Result<(), SpiError> {
         let status_reader = self.get_status_reader_ref()?;
         self.check_io_presence()?;
 
@@ -237,20 +327,77 @@ impl<Instance: SPIMetadata> SpiBus<u8> for Spi<Instance, Master> {
             if status.interrupts.mode_fault_error {
                 return Err(SpiError::ModeFault);
             }
+
+            self.check_deadline(deadline)?;
         }
 
         Ok(())
     }
 
-    fn flush(&mut self) -> Result<(), Self::Error> {
-        self.get_status_reader_ref()?
-            .wait_for_status(|status| status.interrupts.tx_registers_empty, usize::MAX);
+    /// Waits until the last word is transmitted, or the deadline.
+    ///
+    /// # Parameters
+    /// * `deadline` - Deadline of the operation, or `None` if it's not limited.
+    pub(super) fn flush_until(&self, deadline: Option<&Deadline>) -> Result<(), SpiError> {
+        let status_reader = self.get_status_reader_ref()?;
+        self.wait_for_status_until(
+            status_reader,
+            |status| status.interrupts.tx_registers_empty,
+            deadline,
+        )
+    }
+
+    /// Starts transfer timeout of the driver.
+    ///
+    /// # Returns
+    /// Deadline of the operation started now, or `None` if transfer timeout is not set.
+    pub(super) fn start_deadline(&self) -> Option<Deadline> {
+        self.state.transfer_timeout.map(|timeout| timeout.start())
+    }
+
+    /// Blocks until provided functor returns `true`, or the deadline.
+    ///
+    /// # Parameters
+    /// * `status_reader` - SPI status reader.
+    /// * `f` - Function checking the status.
+    /// * `deadline` - Deadline of the operation, or `None` if it's not limited.
+    ///
+    /// # Returns
+    /// Ok(()) when `f` returns `true`, [`SpiError::Timeout`] if the deadline expired.
+    fn wait_for_status_until<F: Fn(SpiStatus) -> bool>(
+        &self,
+        status_reader: &StatusReader<Instance>,
+        f: F,
+        deadline: Option<&Deadline>,
+    ) -> Result<(), SpiError> {
+        while !f(status_reader.status()) {
+            self.check_deadline(deadline)?;
+        }
+
         Ok(())
     }
-}
 
-/// Additional functions used by SpiBus
-impl<Instance: SPIMetadata> Spi<Instance, Master> {
+    /// Checks the deadline of the operation, and aborts the transfer if it expired.
+    ///
+    /// Received data is discarded and hardware chip select is released, so next transaction can
+    /// be started.
+    ///
+    /// # Parameters
+    /// * `deadline` - Deadline of the operation, or `None` if it's not limited.
+    ///
+    /// # Returns
+    /// Ok(()) if the deadline hasn't expired, [`SpiError::Timeout`] otherwise.
+    fn check_deadline(&self, deadline: Option<&Deadline>) -> Result<(), SpiError> {
+        if !deadline.is_some_and(|deadline| deadline.is_expired()) {
+            return Ok(());
+        }
+
+        self.get_received_data();
+        Instance::registers().cr.write(|w| w.lastxfer().set_bit());
+
+        Err(SpiError::Timeout)
+    }
+
     /// Returns a reference to status reader, or `SpiError` if it's missing.
     ///
     /// This function is to be used in `embedded-hal` implementation.
@@ -6,9 +6,12 @@ use super::{
     interrupts::Interrupts,
     metadata::SPIMetadata,
     reader::Reader,
+    timeout::TransferTimeout,
     writer::Writer,
     Master, Spi,
 };
+use crate::pmc::PMC;
+use crate::recovery::Recoverable;
 
 /// Enumeration listing SPI errors
 pub enum SpiError {
@@ -93,12 +96,12 @@ impl<Instance: SPIMetadata> Spi<Instance, Master> {
                 .variant(config.delay_between_consecutive_transfers)
         });
 
-        self.state.is_chip_select_configured[chip as usize] = true;
+        self.state.chip_configs[chip as usize] = Some(config);
     }
 
     /// Returns `true` if specified chip is configured, `false` otherwise.
     pub fn is_chip_configured(&self, chip: SelectedChip) -> bool {
-        self.state.is_chip_select_configured[chip as usize]
+        self.state.chip_configs[chip as usize].is_some()
     }
 
     /// Returns specified chip's configuration, or None if the chip wasn't configured yet.
@@ -133,6 +136,7 @@ impl<Instance: SPIMetadata> Spi<Instance, Master> {
         Instance::registers()
             .mr
             .modify(|_, w| w.pcs().variant(new_chip.into()));
+        self.state.config.selected_chip = new_chip;
     }
 
     /// Returns currently selected chip.
@@ -148,7 +152,7 @@ impl<Instance: SPIMetadata> Spi<Instance, Master> {
 
     /// Returns `true` if currently selected chip has been configured and is ready for transaction.
     pub fn is_current_chip_configured(&self) -> bool {
-        self.state.is_chip_select_configured[self.selected_chip() as usize]
+        self.is_chip_configured(self.selected_chip())
     }
 
     /// Returns current master mode configuration.
@@ -162,6 +166,23 @@ impl<Instance: SPIMetadata> Spi<Instance, Master> {
         }
     }
 
+    /// Sets maximum duration of blocking transactions performed via `embedded-hal` traits.
+    ///
+    /// Transaction that doesn't complete in time fails with
+    /// [`SpiError::Timeout`](super::embedded_hal::SpiError::Timeout). See [timeout](super::timeout)
+    /// module for details.
+    ///
+    /// # Parameters
+    /// * `timeout` - Maximum duration of the transaction, or `None` to wait without limit.
+    pub fn set_transfer_timeout(&mut self, timeout: Option<TransferTimeout>) {
+        self.state.transfer_timeout = timeout;
+    }
+
+    /// Returns maximum duration of blocking transactions, or `None` if it's not limited.
+    pub fn transfer_timeout(&self) -> Option<TransferTimeout> {
+        self.state.transfer_timeout
+    }
+
     /// Enables loopback mode.
     pub fn enable_loopback(&mut self) {
         Instance::registers().mr.modify(|_, w| w.llb().set_bit());
@@ -225,3 +246,41 @@ impl<Instance: SPIMetadata> Spi<Instance, Master> {
         self.writer.is_some()
     }
 }
+
+impl<Instance: SPIMetadata> Recoverable for Spi<Instance, Master> {
+    /// Resets SPI and reconfigures it.
+    ///
+    /// Master mode configuration, configuration of all configured chips and loopback mode are
+    /// reapplied.
+    fn reset_and_reconfigure(&mut self, pmc: &mut PMC) {
+        let interrupts = self.interrupts_state();
+        let loopback_enabled = self.is_loopback_enabled();
+
+        self.disable_hardware();
+        self.disable_all_irqs();
+
+        pmc.disable_peripheral_clock(Instance::PERIPHERAL_ID);
+        pmc.enable_peripheral_clock(Instance::PERIPHERAL_ID);
+
+        self.reset_hardware();
+
+        self.write_master_config(self.state.config);
+        let chips = [
+            SelectedChip::Chip0,
+            SelectedChip::Chip1,
+            SelectedChip::Chip2,
+            SelectedChip::Chip3,
+        ];
+        for (chip, config) in chips.into_iter().zip(self.state.chip_configs) {
+            if let Some(config) = config {
+                self.configure_chip(chip, config);
+            }
+        }
+        if loopback_enabled {
+            self.enable_loopback();
+        }
+
+        self.set_interrupts_state(interrupts);
+        self.enable_hardware();
+    }
+}
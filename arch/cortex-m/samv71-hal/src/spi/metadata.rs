@@ -1,6 +1,7 @@
 //! Module containing meta-traits and their implementation for HAL SPI driver
 use crate::pac::spi0::RegisterBlock;
 pub use crate::pac::{SPI0, SPI1};
+use crate::pmc::config::peripheral::PeripheralId;
 use crate::xdmac::transfer::Peripheral;
 
 /// Trait for PAC SPI instances.
@@ -10,6 +11,8 @@ use crate::xdmac::transfer::Peripheral;
 pub trait SPIMetadata {
     /// Pointer to SPI registers.
     const REGISTERS: *const RegisterBlock;
+    /// Peripheral ID of SPI instance, used to control its clock.
+    const PERIPHERAL_ID: PeripheralId;
     /// Peripheral ID for XDMAC RX transfer from this SPI instance.
     const DMA_RX_PERIPHERAL: Peripheral;
     /// Peripheral ID for XDMAC TX transfer from this SPI instance.
@@ -28,12 +31,14 @@ pub trait SPIMetadata {
 
 impl SPIMetadata for SPI0 {
     const REGISTERS: *const RegisterBlock = SPI0::PTR;
+    const PERIPHERAL_ID: PeripheralId = PeripheralId::SPI0;
     const DMA_RX_PERIPHERAL: Peripheral = Peripheral::SPI0_RX;
     const DMA_TX_PERIPHERAL: Peripheral = Peripheral::SPI0_TX;
 }
 
 impl SPIMetadata for SPI1 {
     const REGISTERS: *const RegisterBlock = SPI1::PTR;
+    const PERIPHERAL_ID: PeripheralId = PeripheralId::SPI1;
     const DMA_RX_PERIPHERAL: Peripheral = Peripheral::SPI1_RX;
     const DMA_TX_PERIPHERAL: Peripheral = Peripheral::SPI1_TX;
 }
@@ -0,0 +1,59 @@
+//! Timeouts of SPI transactions.
+//!
+//! Blocking SPI operations wait for the status of the peripheral, so a device that never
+//! completes the transfer would block the caller forever. With [`TransferTimeout`] set in the
+//! driver, each transaction is limited in time measured with the timebase provided by the
+//! application (for example system time of the runtime). Expired transaction fails with
+//! [`SpiError::Timeout`](super::embedded_hal::SpiError::Timeout), after received data is discarded
+//! and hardware chip select is released, so the next transaction can be started. If the
+//! peripheral itself is wedged, it can be reset with
+//! [`Recoverable::reset_and_reconfigure`](crate::recovery::Recoverable::reset_and_reconfigure).
+
+use fugit::{TimerDurationU64, TimerInstantU64};
+
+/// Instant of the timebase, in microseconds.
+pub type TimeoutInstant = TimerInstantU64<1_000_000>;
+
+/// Duration of the timeout, in microseconds.
+pub type TimeoutDuration = TimerDurationU64<1_000_000>;
+
+/// Function returning current time of the timebase. Timebase has to be monotonic.
+pub type Timebase = fn() -> TimeoutInstant;
+
+/// Maximum duration of SPI transaction.
+#[derive(Debug, Copy, Clone)]
+pub struct TransferTimeout {
+    /// Timebase measuring duration of the transaction.
+    pub timebase: Timebase,
+    /// Maximum duration of the transaction.
+    pub duration: TimeoutDuration,
+}
+
+impl TransferTimeout {
+    /// Starts the timeout.
+    ///
+    /// # Returns
+    /// Deadline of the transaction started now.
+    pub(super) fn start(&self) -> Deadline {
+        Deadline {
+            timebase: self.timebase,
+            end: (self.timebase)() + self.duration,
+        }
+    }
+}
+
+/// Deadline of the started transaction.
+#[derive(Copy, Clone)]
+pub(super) struct Deadline {
+    /// Timebase measuring duration of the transaction.
+    timebase: Timebase,
+    /// Time after which the transaction is timed out.
+    end: TimeoutInstant,
+}
+
+impl Deadline {
+    /// Returns `true` if the deadline has expired.
+    pub(super) fn is_expired(&self) -> bool {
+        (self.timebase)() >= self.end
+    }
+}
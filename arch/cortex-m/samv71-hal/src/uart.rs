@@ -15,6 +15,8 @@
 //! - Parity/overrun/framing error detection
 //! - Interrupt configuration
 //! - Digital filter configuration
+//! - Reception of variable-length frames into a ring buffer with circular XDMAC transfer, see
+//!   [`DmaRingReceiver`](dma_receiver::DmaRingReceiver)
 //!
 //! Currently, it does NOT support:
 //! - Comparison configuration
@@ -47,6 +49,7 @@
 
 extern crate embedded_io;
 
+use core::fmt;
 use core::marker::PhantomData;
 
 use self::config::{bool_to_rx_filter_config, calculate_baudrate};
@@ -59,6 +62,7 @@ pub use embedded_io::{Read, ReadReady, Write, WriteReady};
 pub use super::time::HertzU32 as Frequency;
 
 pub mod config;
+pub mod dma_receiver;
 pub mod interrupt;
 pub mod metadata;
 pub mod reader;
@@ -66,11 +70,15 @@ pub mod states;
 pub mod status;
 pub mod writer;
 
-pub use self::config::{ClockSource, Config, ParityBit, ReceiverConfig};
+pub use self::config::{ClockSource, Config, LoopbackMode, ParityBit, ReceiverConfig};
 pub use self::interrupt::Interrupt;
 pub use self::metadata::UARTMetadata;
 pub use self::status::Status;
 
+use crate::pmc::PMC;
+use crate::recovery::Recoverable;
+use crate::register_dump::{write_register, RegisterDump};
+
 /// Constant representing oversampling ratio, which is used in baudrate and
 /// clock divider calculations.
 pub(super) const OVERSAMPLING_RATIO: u32 = 16;
@@ -86,7 +94,12 @@ pub trait State {}
 /// independent from concrete state, for example baudrate, or clock source.
 ///
 /// State-dependent configuration should be kept in more specific implementations.
-pub trait Configured: State {}
+pub trait Configured: State {
+    /// `true` if receiver is enabled in this state.
+    const RECEIVER_ENABLED: bool;
+    /// `true` if transmitter is enabled in this state.
+    const TRANSMITTER_ENABLED: bool;
+}
 
 /// Typestate trait representing UART with enabled receiver.
 ///
@@ -121,9 +134,20 @@ impl State for Receiver {}
 impl State for Transmitter {}
 impl State for Bidirectional {}
 
-impl Configured for Receiver {}
-impl Configured for Transmitter {}
-impl Configured for Bidirectional {}
+impl Configured for Receiver {
+    const RECEIVER_ENABLED: bool = true;
+    const TRANSMITTER_ENABLED: bool = false;
+}
+
+impl Configured for Transmitter {
+    const RECEIVER_ENABLED: bool = false;
+    const TRANSMITTER_ENABLED: bool = true;
+}
+
+impl Configured for Bidirectional {
+    const RECEIVER_ENABLED: bool = true;
+    const TRANSMITTER_ENABLED: bool = true;
+}
 
 impl Receive for Receiver {}
 impl Receive for Bidirectional {}
@@ -171,6 +195,12 @@ pub struct Uart<Instance: UARTMetadata, CurrentState: State> {
     /// the clock source or it's frequency, otherwise
     /// UART will not work correctly.
     clock_source_frequency: Option<Frequency>,
+    /// Generic configuration applied in the last state transition.
+    /// Reapplied when UART is reset with [`Recoverable::reset_and_reconfigure`].
+    config: Option<Config>,
+    /// Receiver configuration applied in the last state transition.
+    /// Reapplied when UART is reset with [`Recoverable::reset_and_reconfigure`].
+    receiver_config: Option<ReceiverConfig>,
     /// UART Reader instance.
     /// Can be taken using [`Uart::take_reader`] in Receiver mode.
     /// Can be put here after taking it using [`Uart::put_reader`] in Receiver mode.
@@ -205,6 +235,8 @@ impl<Instance: UARTMetadata> Uart<Instance, NotConfigured> {
     pub fn new(_uart: Instance) -> Self {
         Self {
             clock_source_frequency: None,
+            config: None,
+            receiver_config: None,
             reader: Some(Reader::new()),
             writer: Some(Writer::new()),
             _meta: PhantomData,
@@ -228,6 +260,7 @@ impl<Instance: UARTMetadata, AnyState: State> Uart<Instance, AnyState> {
 
         self.internal_reset_status();
         self.internal_set_config(config);
+        self.receiver_config = None;
         self.enable_transmitter();
 
         Uart::transform(self)
@@ -253,6 +286,7 @@ impl<Instance: UARTMetadata, AnyState: State> Uart<Instance, AnyState> {
         self.internal_reset_status();
         self.internal_set_config(config);
         self.internal_set_rx_filter_state(receiver_config.rx_filter_enabled);
+        self.receiver_config.replace(receiver_config);
         self.enable_receiver();
 
         Uart::transform(self)
@@ -279,6 +313,7 @@ impl<Instance: UARTMetadata, AnyState: State> Uart<Instance, AnyState> {
         self.internal_reset_status();
         self.internal_set_config(config);
         self.internal_set_rx_filter_state(receiver_config.rx_filter_enabled);
+        self.receiver_config.replace(receiver_config);
         self.enable_transmitter();
         self.enable_receiver();
 
@@ -300,6 +335,8 @@ impl<Instance: UARTMetadata, AnyState: State> Uart<Instance, AnyState> {
         }
         self.internal_switch_to_normal_mode();
         self.internal_reset_status();
+        self.config = None;
+        self.receiver_config = None;
 
         Uart::transform(self)
     }
@@ -356,6 +393,8 @@ impl<Instance: UARTMetadata, AnyState: State> Uart<Instance, AnyState> {
     const fn transform<NewState: State>(uart: Uart<Instance, NewState>) -> Self {
         Self {
             clock_source_frequency: uart.clock_source_frequency,
+            config: uart.config,
+            receiver_config: uart.receiver_config,
             reader: uart.reader,
             writer: uart.writer,
             _meta: PhantomData,
@@ -512,6 +551,7 @@ impl<Instance: UARTMetadata, AnyState: State> Uart<Instance, AnyState> {
     fn internal_set_config(&mut self, config: Config) {
         self.clock_source_frequency
             .replace(config.clock_source_frequency());
+        self.config.replace(config);
         // Disable baudrate clock before changing the configuration.
         // Safety: This is intentional. Setting divider to 0 disabled baudrate clock.
         unsafe {
@@ -537,3 +577,59 @@ impl<Instance: UARTMetadata, AnyState: State> Uart<Instance, AnyState> {
         }
     }
 }
+
+impl<Instance: UARTMetadata, CurrentState: Configured> Recoverable
+    for Uart<Instance, CurrentState>
+{
+    /// Resets UART and reconfigures it.
+    ///
+    /// Configuration applied in the last state transition is reapplied, loopback mode is disabled.
+    /// Reception errors are cleared.
+    fn reset_and_reconfigure(&mut self, pmc: &mut PMC) {
+        let interrupts_mask = Instance::registers().imr.read().bits();
+
+        self.disable_all_interrupts();
+        self.disable_receiver();
+        self.disable_transmitter();
+
+        pmc.disable_peripheral_clock(Instance::PERIPHERAL_ID);
+        pmc.enable_peripheral_clock(Instance::PERIPHERAL_ID);
+
+        Instance::registers()
+            .cr
+            .write(|w| w.rstrx().set_bit().rsttx().set_bit().rststa().set_bit());
+
+        if let Some(config) = self.config {
+            self.internal_set_config(config);
+        }
+        if let Some(receiver_config) = self.receiver_config {
+            self.internal_set_rx_filter_state(receiver_config.rx_filter_enabled);
+        }
+
+        // Safety: Interrupt enable and mask registers have the same layout, and the mask was just
+        // read from the hardware.
+        Instance::registers()
+            .ier
+            .write(|w| unsafe { w.bits(interrupts_mask) });
+
+        if CurrentState::TRANSMITTER_ENABLED {
+            self.enable_transmitter();
+        }
+        if CurrentState::RECEIVER_ENABLED {
+            self.enable_receiver();
+        }
+    }
+}
+
+impl<Instance: UARTMetadata, CurrentState: State> RegisterDump for Uart<Instance, CurrentState> {
+    /// Dumps UART registers. Receive holding register is omitted.
+    fn dump_registers(&self, writer: &mut impl fmt::Write) -> fmt::Result {
+        let registers = Instance::registers();
+        write_register(writer, "MR", registers.mr.read().bits())?;
+        write_register(writer, "IMR", registers.imr.read().bits())?;
+        write_register(writer, "SR", registers.sr.read().bits())?;
+        write_register(writer, "BRGR", registers.brgr.read().bits())?;
+        write_register(writer, "CMPR", registers.cmpr.read().bits())?;
+        write_register(writer, "WPMR", registers.wpmr.read().bits())
+    }
+}
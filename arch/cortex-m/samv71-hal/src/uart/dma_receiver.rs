@@ -0,0 +1,187 @@
+//! UART receiver using circular XDMAC transfer.
+//!
+//! [`DmaRingReceiver`] continuously receives data into a ring buffer with a circular XDMAC
+//! transfer, without CPU intervention for each byte. Data is split into frames on idle line - a
+//! frame is complete when no new data arrived for a period of time. UART doesn't have a receiver
+//! timeout, so idle line is detected by calling [`DmaRingReceiver::poll_frame`] periodically,
+//! ex. from a timer interrupt. Poll period should be longer than transmission time of a few
+//! characters at the used baudrate, so gaps between characters are not treated as the end of
+//! the frame.
+//!
+//! Ring buffer must be large enough to hold all data received between two polls, as data that is
+//! not yet read is overwritten when XDMAC wraps around the buffer.
+
+use heapless::Vec;
+
+use crate::xdmac::channel::{Channel, Configured, NotConfigured};
+use crate::xdmac::descriptor::CircularDescriptor;
+use crate::xdmac::transfer::{
+    AddressingMode, DataWidth, MicroblockLength, SystemBus, TransferBlock, TransferLocation,
+    TransferType, TriggerSource,
+};
+
+use super::metadata::UARTMetadata;
+use super::reader::Reader;
+
+/// UART receiver delivering idle-line separated frames from a ring buffer filled by XDMAC.
+///
+/// # Generic parameters
+/// * `Instance` - PAC UART instance.
+/// * `N` - Size of the ring buffer in bytes.
+pub struct DmaRingReceiver<Instance: UARTMetadata, const N: usize> {
+    /// Ring buffer written by XDMAC.
+    buffer: *const [u8; N],
+    /// XDMAC channel performing the circular transfer.
+    channel: Channel<Configured>,
+    /// UART reader, held to prevent reading UART data register while XDMAC uses it.
+    reader: Reader<Instance>,
+    /// Position in the buffer of the next byte to be read.
+    read_position: usize,
+    /// Position in the buffer of the next byte to be written by XDMAC, at the time of the last
+    /// poll.
+    last_write_position: usize,
+}
+
+impl<Instance: UARTMetadata, const N: usize> DmaRingReceiver<Instance, N> {
+    /// Creates new receiver and starts the reception.
+    ///
+    /// UART receiver must be enabled, and its reader is held by the receiver until it's
+    /// [released](DmaRingReceiver::release). XDMAC channel events and interrupts are not modified.
+    ///
+    /// # Parameters
+    /// * `reader` - UART reader.
+    /// * `channel` - Free XDMAC channel.
+    /// * `buffer` - Ring buffer. If data cache is enabled, it must be placed in non-cacheable memory.
+    /// * `descriptor` - Descriptor of the circular transfer.
+    ///
+    /// # Returns
+    /// Receiver, or `Err` with the reader and the channel if buffer size is not a valid microblock
+    /// length or the channel couldn't be enabled.
+    #[allow(clippy::type_complexity)]
+    pub fn new(
+        reader: Reader<Instance>,
+        channel: Channel<NotConfigured>,
+        buffer: &'static mut [u8; N],
+        descriptor: &'static mut CircularDescriptor,
+    ) -> Result<Self, (Reader<Instance>, Channel<NotConfigured>)> {
+        let Some(microblock_length) = MicroblockLength::new(N as u32) else {
+            return Err((reader, channel));
+        };
+
+        let source = TransferLocation {
+            address: Instance::registers().rhr.as_ptr() as *const (),
+            interface: SystemBus::Interface1,
+            addressing_mode: AddressingMode::Fixed,
+        };
+        let destination = TransferLocation {
+            address: buffer.as_ptr() as *const (),
+            interface: SystemBus::Interface1,
+            addressing_mode: AddressingMode::Incremented,
+        };
+        let transfer = TransferBlock::new(
+            source,
+            destination,
+            TransferType::PeripheralToMemory(
+                Instance::XDMAC_RX_PERIPHERAL,
+                TriggerSource::Hardware,
+            ),
+            DataWidth::Byte,
+        )
+        // Byte transfers are always aligned.
+        .unwrap()
+        .with_microblock_length(microblock_length);
+
+        let mut channel = match channel.configure_circular_transfer(transfer, descriptor) {
+            Ok(channel) => channel,
+            Err(channel) => return Err((reader, channel)),
+        };
+        if !channel.enable() {
+            // Channel was just configured, so it's not busy and can be reset.
+            return Err((reader, channel.reset_state().unwrap()));
+        }
+
+        Ok(Self {
+            buffer,
+            channel,
+            reader,
+            read_position: 0,
+            last_write_position: 0,
+        })
+    }
+
+    /// Returns number of received bytes that were not read yet.
+    pub fn available(&self) -> usize {
+        (self.write_position() + N - self.read_position) % N
+    }
+
+    /// Reads received bytes, regardless of the frame boundaries.
+    ///
+    /// # Parameters
+    /// * `data` - Buffer for the received bytes.
+    ///
+    /// # Returns
+    /// Number of bytes read.
+    pub fn read(&mut self, data: &mut [u8]) -> usize {
+        let count = core::cmp::min(self.available(), data.len());
+
+        for byte in data.iter_mut().take(count) {
+            *byte = self.read_byte();
+        }
+
+        count
+    }
+
+    /// Checks for a complete frame.
+    ///
+    /// Frame is complete when there is received data, and no new data arrived since the previous
+    /// call. This should be called periodically, see [module documentation](self).
+    ///
+    /// # Generic parameters
+    /// * `F` - Maximum size of the frame. If more data was received, the rest of it is returned by
+    ///   the next call.
+    ///
+    /// # Returns
+    /// Received frame, or `None` if there is no data or the data is still being received.
+    pub fn poll_frame<const F: usize>(&mut self) -> Option<Vec<u8, F>> {
+        let write_position = self.write_position();
+        let is_idle = write_position == self.last_write_position;
+        self.last_write_position = write_position;
+
+        if !is_idle || self.available() == 0 {
+            return None;
+        }
+
+        let mut frame = Vec::new();
+        while self.available() > 0 && !frame.is_full() {
+            // This cannot fail, as the frame is not full.
+            frame.push(self.read_byte()).ok();
+        }
+
+        Some(frame)
+    }
+
+    /// Stops the reception and releases the receiver.
+    ///
+    /// # Returns
+    /// UART reader and XDMAC channel.
+    pub fn release(mut self) -> (Reader<Instance>, Channel<Configured>) {
+        self.channel.disable();
+        (self.reader, self.channel)
+    }
+
+    /// Returns position in the buffer of the next byte to be written by XDMAC.
+    fn write_position(&self) -> usize {
+        (N - self.channel.remaining_microblock_length() as usize) % N
+    }
+
+    /// Reads a byte at the read position and advances it. Caller must check that the byte is
+    /// available.
+    fn read_byte(&mut self) -> u8 {
+        // SAFETY: Buffer is valid for the whole program lifetime and the read position is always
+        // within it. Volatile read is used, as the buffer is written by XDMAC.
+        let byte =
+            unsafe { core::ptr::read_volatile((self.buffer as *const u8).add(self.read_position)) };
+        self.read_position = (self.read_position + 1) % N;
+        byte
+    }
+}
@@ -1,6 +1,8 @@
 //! Module containing meta-traits and their implementations for HAL UART driver
 use crate::pac::uart0::RegisterBlock;
 pub use crate::pac::{UART0, UART1, UART2, UART3, UART4};
+use crate::pmc::config::peripheral::PeripheralId;
+use crate::xdmac::transfer::Peripheral as XdmacPeripheral;
 
 /// Trait for PAC UART instances.
 ///
@@ -9,6 +11,10 @@ pub use crate::pac::{UART0, UART1, UART2, UART3, UART4};
 pub trait UARTMetadata {
     /// Pointer to UART registers.
     const REGISTERS: *const RegisterBlock;
+    /// Peripheral ID of UART instance, used to control its clock.
+    const PERIPHERAL_ID: PeripheralId;
+    /// XDMAC peripheral ID of UART receiver, used for peripheral-synchronized transfers.
+    const XDMAC_RX_PERIPHERAL: XdmacPeripheral;
 
     /// Returns a reference to UART's register block.
     ///
@@ -23,15 +29,17 @@ pub trait UARTMetadata {
 
 /// Internal macro used to generate UartMetadata implementations for every available UART.
 macro_rules! implement_uart_metadata_for {
-    ($uart:ty) => {
+    ($uart:ident, $rx:ident) => {
         impl UARTMetadata for $uart {
             const REGISTERS: *const RegisterBlock = <$uart>::PTR;
+            const PERIPHERAL_ID: PeripheralId = PeripheralId::$uart;
+            const XDMAC_RX_PERIPHERAL: XdmacPeripheral = XdmacPeripheral::$rx;
         }
     };
 }
 
-implement_uart_metadata_for!(UART0);
-implement_uart_metadata_for!(UART1);
-implement_uart_metadata_for!(UART2);
-implement_uart_metadata_for!(UART3);
-implement_uart_metadata_for!(UART4);
+implement_uart_metadata_for!(UART0, UART0_RX);
+implement_uart_metadata_for!(UART1, UART1_RX);
+implement_uart_metadata_for!(UART2, UART2_RX);
+implement_uart_metadata_for!(UART3, UART3_RX);
+implement_uart_metadata_for!(UART4, UART4_RX);
@@ -6,6 +6,7 @@ pub use embedded_io::{ErrorKind, ErrorType, Read, ReadReady};
 
 use core::marker::PhantomData;
 
+use crate::call_bounds::{self, UART_RECEIVE_BYTE};
 use crate::utils::wait_until;
 
 use super::Error;
@@ -51,13 +52,18 @@ impl<Instance: UARTMetadata> Reader<Instance> {
     /// # Returns
     /// `Ok(u8)` if reception was successful, with the value of received byte.
     /// `Err(())` on timeout.
+    ///
+    /// # Worst-case duration
+    /// [`UART_RECEIVE_BYTE`], with `timeout` status checks.
     pub fn receive_byte(&mut self, timeout: u32) -> Result<u8, Error> {
-        self.wait_for_byte_reception(timeout)
-            // This is safe, as we just verified that receiver is ready and RX holding register
-            // contains a received byte.
-            .map_or(Err(Error::TimedOut), |_| unsafe {
-                Ok(self.get_received_byte())
-            })
+        call_bounds::measure(&UART_RECEIVE_BYTE, timeout, || {
+            self.wait_for_byte_reception(timeout)
+                // This is safe, as we just verified that receiver is ready and RX holding register
+                // contains a received byte.
+                .map_or(Err(Error::TimedOut), |_| unsafe {
+                    Ok(self.get_received_byte())
+                })
+        })
     }
 
     /// Returns the byte currently stored in received character register.
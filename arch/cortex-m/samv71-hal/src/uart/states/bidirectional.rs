@@ -1,8 +1,22 @@
 //! Module with implementation of UART in bidirectional mode.
 //!
-use crate::uart::{metadata::UARTMetadata, Bidirectional, Uart};
+use crate::uart::{config::LoopbackMode, metadata::UARTMetadata, Bidirectional, Uart};
 
 impl<Instance: UARTMetadata> Uart<Instance, Bidirectional> {
+    /// Switches UART into specified loopback mode.
+    ///
+    /// All loopback modes are available in bidirectional mode, so this can be used
+    /// to select the mode at runtime, for example by a test command. Mode can be read
+    /// back with [`Uart::loopback_mode`].
+    ///
+    /// # Parameters
+    /// * `mode` - New loopback mode.
+    pub fn set_loopback_mode(&mut self, mode: LoopbackMode) {
+        Instance::registers()
+            .mr
+            .modify(|_, w| w.chmode().variant(mode.into()));
+    }
+
     /// Switches UART into local loopback mode.
     ///
     /// In this mode, transmitter is internally connected to receiver.
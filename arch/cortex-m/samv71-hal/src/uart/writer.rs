@@ -6,6 +6,7 @@ pub use embedded_io::{ErrorKind, ErrorType, Write, WriteReady};
 
 use core::marker::PhantomData;
 
+use crate::call_bounds::{self, UART_FLUSH, UART_TRANSMIT_BYTE, UART_TRANSMIT_BYTES};
 use crate::utils::wait_until;
 
 use super::Error;
@@ -53,13 +54,18 @@ impl<Instance: UARTMetadata> Writer<Instance> {
     ///
     /// # Returns
     /// `Ok(())` on successful transmission, `Err(Error::TimedOut)` if timeout has been reached.                         
+    ///
+    /// # Worst-case duration
+    /// [`UART_TRANSMIT_BYTE`], with `timeout` status checks.
     pub fn transmit_byte(&mut self, byte: u8, timeout: u32) -> Result<(), Error> {
-        self.wait_for_transmitter_ready(timeout)
-            // Safety: this is safe, as we just verified that transmitter is ready.
-            .map_or(Err(Error::TimedOut), |_| unsafe {
-                self.set_transmitted_byte(byte);
-                Ok(())
-            })
+        call_bounds::measure(&UART_TRANSMIT_BYTE, timeout, || {
+            self.wait_for_transmitter_ready(timeout)
+                // Safety: this is safe, as we just verified that transmitter is ready.
+                .map_or(Err(Error::TimedOut), |_| unsafe {
+                    self.set_transmitted_byte(byte);
+                    Ok(())
+                })
+        })
     }
 
     /// Transmits multiple bytes. Blocks until the transmission is completed, or timeout
@@ -72,21 +78,30 @@ impl<Instance: UARTMetadata> Writer<Instance> {
     ///
     /// # Returns
     /// `Ok(())` on successful transmission, `Err(Error::TimedOut)` if timeout has been reached.
+    ///
+    /// # Worst-case duration
+    /// [`UART_TRANSMIT_BYTES`], with `timeout * (bytes.len() + 2)` status checks.
     pub fn transmit_bytes(&mut self, bytes: &[u8], timeout: u32) -> Result<(), Error> {
-        if self.wait_for_transmitter_ready(timeout).is_some() {
-            for &byte in bytes {
-                // Safety: this is safe, as we just verified that transmitter is ready.
-                unsafe { self.set_transmitted_byte(byte) };
+        let checks = timeout.saturating_mul((bytes.len() as u32).saturating_add(2));
+
+        call_bounds::measure(&UART_TRANSMIT_BYTES, checks, || {
+            if self.wait_for_transmitter_ready(timeout).is_some() {
+                for &byte in bytes {
+                    // Safety: this is safe, as we just verified that transmitter is ready.
+                    unsafe { self.set_transmitted_byte(byte) };
 
-                if self.wait_for_transmitter_ready(timeout).is_none() {
-                    return Err(Error::TimedOut);
+                    if self.wait_for_transmitter_ready(timeout).is_none() {
+                        return Err(Error::TimedOut);
+                    }
                 }
-            }
 
-            return self.flush(timeout);
-        }
+                return self
+                    .wait_for_transmission_to_complete(timeout)
+                    .map_or(Err(Error::TimedOut), |_| Ok(()));
+            }
 
-        Err(Error::TimedOut)
+            Err(Error::TimedOut)
+        })
     }
 
     /// Flushes the UART by waiting until currently transmitted character is processed.
@@ -96,9 +111,14 @@ impl<Instance: UARTMetadata> Writer<Instance> {
     ///
     /// # Returns
     /// `Ok(())` on successful flush, `Err(Error::TimedOut)` if timeout has been reached.
+    ///
+    /// # Worst-case duration
+    /// [`UART_FLUSH`], with `timeout` status checks.
     pub fn flush(&mut self, timeout: u32) -> Result<(), Error> {
-        self.wait_for_transmission_to_complete(timeout)
-            .map_or(Err(Error::TimedOut), |_| Ok(()))
+        call_bounds::measure(&UART_FLUSH, timeout, || {
+            self.wait_for_transmission_to_complete(timeout)
+                .map_or(Err(Error::TimedOut), |_| Ok(()))
+        })
     }
 
     /// Writes a byte to be transmitted next into TX holding register.
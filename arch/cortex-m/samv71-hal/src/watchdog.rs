@@ -17,6 +17,7 @@ pub use watchdog_config::WatchdogConfig;
 pub use watchdog_error::WatchdogError;
 
 use self::watchdog_config::MAXIMUM_WATCHDOG_DURATION;
+use crate::call_bounds::{self, WATCHDOG_FEED};
 use crate::pac::WDT;
 use crate::time::MillisDurationU32 as Milliseconds;
 
@@ -103,8 +104,13 @@ impl Watchdog {
     }
 
     /// Refresh the watchdog counter.
+    ///
+    /// # Worst-case duration
+    /// [`WATCHDOG_FEED`](crate::call_bounds::WATCHDOG_FEED).
     pub fn feed(&mut self) {
-        self.wdt.cr.write(|w| w.key().passwd().wdrstt().set_bit());
+        call_bounds::measure(&WATCHDOG_FEED, 0, || {
+            self.wdt.cr.write(|w| w.key().passwd().wdrstt().set_bit());
+        })
     }
 
     /// Disable the watchdog.
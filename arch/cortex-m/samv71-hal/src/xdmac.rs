@@ -31,7 +31,9 @@
 //! After receiving instance of [`Channel`] from [`Xdmac`], you can use it to configure the transfer,
 //! and manage channel's state.
 //!
-//! Only a single-block operation mode is supported. Linked list transfers are not implemented.
+//! Only a single-block operation mode is supported. Linked list transfers are not implemented,
+//! except for [circular transfers](channel::Channel::configure_circular_transfer), which use a
+//! single descriptor pointing to itself to continuously repeat a microblock.
 //! Data striding and memset operation is also not supported.
 //!
 //! Both [`Xdmac`] and [`Channel`] provide status reader objects -
@@ -98,6 +100,7 @@ use self::status::StatusReader;
 
 pub mod channel;
 pub mod channel_status;
+pub mod descriptor;
 pub mod events;
 pub mod status;
 pub mod transfer;
@@ -6,8 +6,9 @@ use samv71q21_pac::{
 };
 
 pub use super::channel_status::ChannelStatusReader;
+use super::descriptor::CircularDescriptor;
 pub use super::events::ChannelEvents;
-use super::transfer::{ErrataTransferBlockConfig, TransferBlock};
+use super::transfer::{ErrataTransferBlockConfig, SystemBus, TransferBlock, TransferType};
 
 /// Typestate trait representing generic XDMAC channel's state.
 pub trait State {}
@@ -318,6 +319,67 @@ impl Channel<NotConfigured> {
         )
     }
 
+    /// Configures a circular XDMAC transfer on this channel.
+    /// Consumes channel's instance, and returns one with new state.
+    ///
+    /// Circular transfer repeats the microblock continuously, restarting from the beginning of the
+    /// memory buffer after it's filled (or emptied), until the channel is disabled. End of block
+    /// event is signaled after every microblock. Descriptor is fetched by XDMAC using the memory
+    /// interface of the transfer.
+    ///
+    /// # Parameters
+    /// * `block` - Transfer block. It must be a peripheral transfer with block length of 1.
+    /// * `descriptor` - Descriptor used by XDMAC to restart the transfer.
+    ///
+    /// # Returns
+    /// Configured channel, or `Err` with unchanged channel if the transfer block is not valid for a
+    /// circular transfer.
+    pub fn configure_circular_transfer(
+        mut self,
+        block: TransferBlock,
+        descriptor: &'static mut CircularDescriptor,
+    ) -> Result<Channel<Configured>, Self> {
+        let (memory, update_destination) = match block.transfer_type() {
+            TransferType::PeripheralToMemory(_, _) => (block.destination(), true),
+            TransferType::MemoryToPeripheral(_, _) => (block.source(), false),
+            TransferType::MemoryToMemory => return Err(self),
+        };
+        if block.block_length().get() != 1 {
+            return Err(self);
+        }
+
+        self.internal_configure_transfer(block);
+        descriptor.fill(
+            block.microblock_length().get(),
+            memory.address,
+            update_destination,
+        );
+
+        self.channel_registers_ref().cnda.write(|w| {
+            w.ndaif()
+                .bit(memory.interface == SystemBus::Interface1)
+                .nda()
+                .variant(descriptor.address() >> 2)
+        });
+        self.channel_registers_ref().cndc.write(|w| {
+            w.nde()
+                .dscr_fetch_en()
+                .ndsup()
+                .bit(!update_destination)
+                .nddup()
+                .bit(update_destination)
+                .ndview()
+                .ndv1()
+        });
+
+        Ok(Channel::transform(
+            self,
+            Configured {
+                configured_transfer: block,
+            },
+        ))
+    }
+
     /// Enables channel's global interrupt.
     ///
     /// While channel's global interrupt is enabled, IRQ will be triggered when one of the enabled
@@ -450,6 +512,13 @@ impl Channel<Configured> {
         self.internal_configure_transfer(self.state.configured_transfer);
     }
 
+    /// Returns number of data units remaining in the current microblock.
+    ///
+    /// For circular transfers, it can be used to calculate current position in the buffer.
+    pub fn remaining_microblock_length(&self) -> u32 {
+        self.channel_registers_ref().cubc.read().ublen().bits()
+    }
+
     /// Returns `true` if Channel is currently enabled and XDMAC transfer is in progress.
     pub fn is_busy(&self) -> bool {
         self.is_channels_bit_set(self.xdmac_registers_ref().gs.read().bits())
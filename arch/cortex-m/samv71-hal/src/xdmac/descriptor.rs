@@ -0,0 +1,83 @@
+//! XDMAC linked list descriptors.
+
+/// Descriptor of a circular transfer.
+///
+/// This is a view 1 linked list descriptor pointing to itself. After each microblock, XDMAC
+/// fetches it and restarts the transfer from the beginning of the buffer, so the channel runs
+/// continuously until it's disabled.
+///
+/// Descriptor is read by XDMAC, so it must be placed in memory accessible by the interface used
+/// by the transfer, and must live as long as the transfer. If data cache is enabled, it must be
+/// placed in non-cacheable memory.
+#[repr(C, align(4))]
+pub struct CircularDescriptor {
+    /// Address of the next descriptor (this one).
+    next_descriptor_address: u32,
+    /// Microblock length and next descriptor control.
+    microblock_control: u32,
+    /// Address of the memory side of the transfer.
+    transfer_address: u32,
+}
+
+impl CircularDescriptor {
+    /// Next descriptor enable bit of microblock control.
+    const NEXT_DESCRIPTOR_ENABLE: u32 = 1 << 24;
+    /// Source parameters update bit of microblock control.
+    const NEXT_SOURCE_UPDATE: u32 = 1 << 25;
+    /// Destination parameters update bit of microblock control.
+    const NEXT_DESTINATION_UPDATE: u32 = 1 << 26;
+    /// View 1 value of next descriptor view field of microblock control.
+    const NEXT_VIEW_1: u32 = 1 << 27;
+
+    /// Creates new, empty descriptor.
+    pub const fn new() -> Self {
+        Self {
+            next_descriptor_address: 0,
+            microblock_control: 0,
+            transfer_address: 0,
+        }
+    }
+
+    /// Fills the descriptor.
+    ///
+    /// # Parameters
+    /// * `microblock_length` - Length of the transfer in data units.
+    /// * `transfer_address` - Address of the memory side of the transfer.
+    /// * `update_destination` - `true` if memory is the destination of the transfer, `false` if
+    ///   it's the source.
+    pub(super) fn fill(
+        &mut self,
+        microblock_length: u32,
+        transfer_address: *const (),
+        update_destination: bool,
+    ) {
+        let update = if update_destination {
+            Self::NEXT_DESTINATION_UPDATE
+        } else {
+            Self::NEXT_SOURCE_UPDATE
+        };
+        let microblock_control =
+            microblock_length | Self::NEXT_DESCRIPTOR_ENABLE | update | Self::NEXT_VIEW_1;
+
+        // SAFETY: Fields are valid and aligned. Volatile writes are used, as the descriptor is
+        // read by XDMAC, not the program.
+        unsafe {
+            core::ptr::write_volatile(&mut self.next_descriptor_address, self.address());
+            core::ptr::write_volatile(&mut self.microblock_control, microblock_control);
+            core::ptr::write_volatile(&mut self.transfer_address, transfer_address as u32);
+        }
+        // Make sure that the descriptor is in memory before XDMAC can fetch it.
+        cortex_m::asm::dsb();
+    }
+
+    /// Returns address of the descriptor.
+    pub(super) fn address(&self) -> u32 {
+        self as *const Self as u32
+    }
+}
+
+impl Default for CircularDescriptor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
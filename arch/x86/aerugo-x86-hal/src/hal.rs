@@ -1,24 +1,53 @@
 //! System HAL implementation for x86 target.
 
+use std::cell::Cell;
 use std::convert::TryInto;
 use std::time::SystemTime;
 
-use aerugo_hal::{AerugoHal, Duration, Instant, SystemHardwareConfig};
+use aerugo_hal::{AerugoHal, Duration, Instant, ResetCause, StackPaint, SystemHardwareConfig};
 use once_cell::sync::Lazy;
 
 use crate::error::HalError;
+use crate::timing_variation;
 use crate::user_peripherals::UserPeripherals;
 
+/// Period of the simulated monitor timer interrupt.
+const MONITOR_TIMER_PERIOD: std::time::Duration = std::time::Duration::from_millis(1);
+
 /// Time when system was started
 static TIME_START: Lazy<SystemTime> = Lazy::new(SystemTime::now);
 
+thread_local! {
+    /// `true` if the thread is executing a simulated interrupt handler.
+    static IN_INTERRUPT: Cell<bool> = const { Cell::new(false) };
+}
+
 /// HAL implementation for x86.
 pub struct Hal {}
 
 impl Hal {
-    /// Returns empty UserPeripherals struct, as x86 does not provide any.
+    /// Returns UserPeripherals struct with simulated peripherals.
     pub fn create_user_peripherals() -> Option<UserPeripherals> {
-        Some(UserPeripherals {})
+        Some(UserPeripherals::default())
+    }
+
+    /// Simulates delivery of an interrupt.
+    ///
+    /// Should be called from a thread emulating a peripheral. If timing variation is enabled with
+    /// `AERUGO_TIMING_JITTER_US` environmental variable, handler is called after a random delay,
+    /// as the interrupt could be delayed by the hardware.
+    ///
+    /// # Parameters
+    /// * `handler` - Interrupt handler.
+    pub fn deliver_interrupt(handler: impl FnOnce()) {
+        let delay = timing_variation::interrupt_delay();
+        if !delay.is_zero() {
+            std::thread::sleep(delay);
+        }
+
+        IN_INTERRUPT.with(|in_interrupt| in_interrupt.set(true));
+        handler();
+        IN_INTERRUPT.with(|in_interrupt| in_interrupt.set(false));
     }
 }
 
@@ -46,4 +75,71 @@ impl AerugoHal for Hal {
     fn feed_watchdog() {
         // There is no watchdog for x86 target.
     }
+
+    fn verify_fpu_context_policy() -> Result<(), HalError> {
+        // FPU context is handled by the operating system on x86 target.
+        Ok(())
+    }
+
+    fn toggle_heartbeat() {
+        // There are no I/O pins on x86 target.
+    }
+
+    fn reset_system() -> ! {
+        // There is no MCU to reset on x86 target, so the process is terminated instead.
+        std::process::exit(1)
+    }
+
+    fn sleep_until(_wakeup_time: Instant) {
+        // There are no interrupts that could wake the process on x86 target, so it doesn't sleep.
+    }
+
+    fn reset_cause() -> ResetCause {
+        // Process is always started from scratch on x86 target.
+        ResetCause::PowerOn
+    }
+
+    fn store_shutdown_reason(_code: u32) {
+        // Process is always started from scratch on x86 target, nothing is preserved over reset.
+    }
+
+    fn take_shutdown_reason() -> Option<u32> {
+        None
+    }
+
+    fn paint_stack(_depth: usize) -> Option<StackPaint> {
+        // Stack of the process is managed by the operating system on x86 target.
+        None
+    }
+
+    fn stack_usage(_paint: &StackPaint) -> usize {
+        0
+    }
+
+    fn firmware_image_start() -> Option<*const u8> {
+        // Program is loaded by the operating system on x86 target, there is no firmware image.
+        None
+    }
+
+    fn wakeup_jitter(period: Duration) -> Duration {
+        timing_variation::wakeup_jitter(period)
+    }
+
+    fn in_interrupt() -> bool {
+        IN_INTERRUPT.with(|in_interrupt| in_interrupt.get())
+    }
+
+    /// Timer interrupt is simulated with a separate thread, calling the handler every millisecond.
+    fn start_monitor_timer(handler: fn()) -> Result<(), HalError> {
+        std::thread::spawn(move || {
+            IN_INTERRUPT.with(|in_interrupt| in_interrupt.set(true));
+
+            loop {
+                std::thread::sleep(MONITOR_TIMER_PERIOD);
+                handler();
+            }
+        });
+
+        Ok(())
+    }
 }
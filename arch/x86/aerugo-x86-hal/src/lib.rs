@@ -8,6 +8,8 @@ x86 implementation of aerugo HAL.
 pub mod error;
 pub mod hal;
 mod system_peripherals;
+mod timing_variation;
+pub mod uart;
 pub mod user_peripherals;
 
 pub use self::hal::Hal;
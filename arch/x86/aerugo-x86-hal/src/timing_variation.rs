@@ -0,0 +1,158 @@
+//! Monte Carlo timing variation.
+//!
+//! On x86 target cyclic wakeups and simulated interrupts can be delayed by a random, bounded
+//! jitter, so test campaigns can check robustness of the application timing assumptions before
+//! they are revealed by the hardware. Variation is enabled by setting `AERUGO_TIMING_JITTER_US`
+//! environmental variable to the maximum jitter in microseconds. Jitter sequence is selected with
+//! `AERUGO_TIMING_JITTER_SEED`, so failing run can be reproduced with the same seed. If seed is
+//! not set, new one is selected on each start and printed to the standard error output.
+
+use std::sync::Mutex;
+use std::time::SystemTime;
+
+use aerugo_hal::Duration;
+use once_cell::sync::Lazy;
+
+/// Timing variation configuration and generator state.
+static TIMING_VARIATION: Lazy<TimingVariation> = Lazy::new(TimingVariation::from_env);
+
+/// Generator of the random timing jitter.
+struct TimingVariation {
+    /// Maximum jitter in microseconds, `0` if variation is disabled.
+    max_jitter_us: u64,
+    /// State of the pseudo-random generator.
+    state: Mutex<u64>,
+}
+
+impl TimingVariation {
+    /// Creates generator with given parameters.
+    ///
+    /// # Parameters
+    /// * `max_jitter_us` - Maximum jitter in microseconds.
+    /// * `seed` - Seed of the jitter sequence.
+    fn new(max_jitter_us: u64, seed: u64) -> Self {
+        TimingVariation {
+            max_jitter_us,
+            // Zero state would make the generator return only zeros.
+            state: Mutex::new(if seed != 0 { seed } else { 1 }),
+        }
+    }
+
+    /// Creates generator configured with environmental variables.
+    fn from_env() -> Self {
+        let max_jitter_us = read_env_u64("AERUGO_TIMING_JITTER_US").unwrap_or(0);
+
+        let seed = match read_env_u64("AERUGO_TIMING_JITTER_SEED") {
+            Some(seed) => seed,
+            None => {
+                let seed = SystemTime::now()
+                    .duration_since(SystemTime::UNIX_EPOCH)
+                    .map(|time| time.as_nanos() as u64)
+                    .unwrap_or(1);
+
+                if max_jitter_us != 0 {
+                    eprintln!("Timing jitter seed: {seed}");
+                }
+                seed
+            }
+        };
+
+        TimingVariation::new(max_jitter_us, seed)
+    }
+
+    /// Returns random jitter.
+    ///
+    /// # Parameters
+    /// * `bound` - Bound of the jitter in microseconds, returned value is lower than it.
+    ///
+    /// # Return
+    /// Jitter in microseconds, not greater than the maximum jitter and lower than `bound`.
+    fn next_jitter_us(&self, bound: u64) -> u64 {
+        let bound = bound.min(self.max_jitter_us.saturating_add(1));
+        if bound <= 1 {
+            return 0;
+        }
+
+        let mut state = self.state.lock().unwrap();
+        // Xorshift generator, which never leaves `0` state.
+        *state ^= *state << 13;
+        *state ^= *state >> 7;
+        *state ^= *state << 17;
+
+        *state % bound
+    }
+}
+
+/// Reads numeric value of the environmental variable.
+///
+/// # Parameters
+/// * `name` - Name of the variable.
+///
+/// # Return
+/// Value of the variable, `None` if it's not set or isn't a number.
+fn read_env_u64(name: &str) -> Option<u64> {
+    std::env::var(name).ok()?.trim().parse().ok()
+}
+
+/// Returns random jitter of the cyclic wakeup.
+///
+/// # Parameters
+/// * `period` - Period of the cyclic execution, returned jitter is lower than it.
+pub(crate) fn wakeup_jitter(period: Duration) -> Duration {
+    Duration::micros(TIMING_VARIATION.next_jitter_us(period.to_micros()))
+}
+
+/// Returns random delay of the simulated interrupt.
+pub(crate) fn interrupt_delay() -> std::time::Duration {
+    std::time::Duration::from_micros(TIMING_VARIATION.next_jitter_us(u64::MAX))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn disabled_variation() {
+        let variation = TimingVariation::new(0, 1234);
+
+        assert_eq!(variation.next_jitter_us(u64::MAX), 0);
+        assert_eq!(variation.next_jitter_us(1000), 0);
+    }
+
+    #[test]
+    fn seeded_jitter_sequence() {
+        let variation = TimingVariation::new(1000, 1);
+        let jitter: Vec<u64> = (0..3).map(|_| variation.next_jitter_us(u64::MAX)).collect();
+
+        assert_eq!(jitter, [573, 328, 812]);
+
+        let replay = TimingVariation::new(1000, 1);
+        let replayed: Vec<u64> = (0..3).map(|_| replay.next_jitter_us(u64::MAX)).collect();
+
+        assert_eq!(replayed, jitter);
+    }
+
+    #[test]
+    fn zero_seed() {
+        let variation = TimingVariation::new(1000, 0);
+
+        assert_eq!(variation.next_jitter_us(u64::MAX), 573);
+    }
+
+    #[test]
+    fn wakeup_jitter_bounded_by_period() {
+        let period_us = 250;
+        let variation = TimingVariation::new(1000, 1);
+
+        let releases = [0, 250, 500];
+        let wakeups: Vec<u64> = releases
+            .iter()
+            .map(|release| release + variation.next_jitter_us(period_us))
+            .collect();
+
+        assert_eq!(wakeups, [11, 255, 707]);
+        for (release, wakeup) in releases.iter().zip(&wakeups) {
+            assert!((*release..release + period_us).contains(wakeup));
+        }
+    }
+}
@@ -0,0 +1,263 @@
+//! Simulated UART.
+//!
+//! x86 target has no serial hardware, so UART is simulated in memory to let serial protocol
+//! handling be tested on the host. By default, bytes written by the application are received by
+//! the same UART, like in SAMV71 UART local loopback mode. Bytes sent by the other side of the
+//! link can be delivered with [`SimulatedUart::deliver`].
+//!
+//! Reception errors can be injected with [`SimulatedUart::inject_error`]. Parity, framing and
+//! overrun errors are reported with the same status flags as on SAMV71 UART, so the same error
+//! handling code can be tested on both platforms. Corrupted and lost bytes are not reported by
+//! the UART, and have to be detected by the protocol (ex. with CRC or frame length).
+
+use std::collections::VecDeque;
+
+/// Size of the simulated receiver buffer.
+pub const RECEIVER_BUFFER_SIZE: usize = 64;
+
+/// Error injected into reception of a byte.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum InjectedError {
+    /// Byte is received with invalid parity bit.
+    Parity,
+    /// Byte is received without valid stop bit.
+    Framing,
+    /// Byte is lost, as if it was received while the receiver buffer was full.
+    Overrun,
+    /// Byte is received with bits flipped by the mask, without reception error.
+    Corruption(u8),
+    /// Byte is lost without reception error, leaving a partial frame.
+    Loss,
+}
+
+/// Structure representing simulated UART status.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq)]
+pub struct Status {
+    /// `true` if invalid parity bit(s) was/were detected.
+    pub parity_error: bool,
+    /// `true` if stop bit was not successfully detected.
+    pub framing_error: bool,
+    /// `true` if a byte was received while receiver buffer was full.
+    pub overrun_error: bool,
+    /// `true` if receiver buffer contains a byte, ready to be read.
+    pub receiver_ready: bool,
+}
+
+/// Simulated UART.
+pub struct SimulatedUart {
+    /// `true` if transmitted bytes are received by this UART.
+    loopback: bool,
+    /// Received bytes, waiting to be read.
+    received: VecDeque<u8>,
+    /// Transmitted bytes, waiting to be taken.
+    transmitted: Vec<u8>,
+    /// Errors injected into reception of the next bytes.
+    injected_errors: VecDeque<InjectedError>,
+    /// Error flags of the receiver.
+    status: Status,
+}
+
+impl SimulatedUart {
+    /// Creates new simulated UART in loopback mode.
+    pub fn new() -> Self {
+        SimulatedUart {
+            loopback: true,
+            received: VecDeque::with_capacity(RECEIVER_BUFFER_SIZE),
+            transmitted: Vec::new(),
+            injected_errors: VecDeque::new(),
+            status: Status::default(),
+        }
+    }
+
+    /// Enables or disables loopback mode.
+    ///
+    /// # Parameters
+    /// * `enabled` - `true` if transmitted bytes should be received by this UART.
+    pub fn set_loopback(&mut self, enabled: bool) {
+        self.loopback = enabled;
+    }
+
+    /// Transmits a byte.
+    ///
+    /// # Parameters
+    /// * `byte` - Byte to transmit.
+    pub fn write_byte(&mut self, byte: u8) {
+        self.transmitted.push(byte);
+        if self.loopback {
+            self.receive(byte);
+        }
+    }
+
+    /// Transmits bytes.
+    ///
+    /// # Parameters
+    /// * `bytes` - Bytes to transmit.
+    pub fn write(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.write_byte(*byte);
+        }
+    }
+
+    /// Returns bytes transmitted since the last call, as received by the other side of the link.
+    pub fn take_transmitted(&mut self) -> Vec<u8> {
+        core::mem::take(&mut self.transmitted)
+    }
+
+    /// Delivers bytes sent by the other side of the link to the receiver.
+    ///
+    /// # Parameters
+    /// * `bytes` - Received bytes.
+    pub fn deliver(&mut self, bytes: &[u8]) {
+        for byte in bytes {
+            self.receive(*byte);
+        }
+    }
+
+    /// Injects error into reception of the next byte.
+    ///
+    /// Errors are applied in the order of injection, one per received byte, so injecting `N`
+    /// errors affects `N` next bytes.
+    ///
+    /// # Parameters
+    /// * `error` - Injected error.
+    pub fn inject_error(&mut self, error: InjectedError) {
+        self.injected_errors.push_back(error);
+    }
+
+    /// Reads a received byte.
+    ///
+    /// # Returns
+    /// `Some(byte)` if there was a byte in the receiver buffer, `None` otherwise.
+    pub fn read_byte(&mut self) -> Option<u8> {
+        self.received.pop_front()
+    }
+
+    /// Returns current UART status.
+    ///
+    /// Error flags **must** be cleared manually by calling [`SimulatedUart::reset_status`].
+    pub fn status(&self) -> Status {
+        Status {
+            receiver_ready: !self.received.is_empty(),
+            ..self.status
+        }
+    }
+
+    /// Resets error flags of the UART status.
+    pub fn reset_status(&mut self) {
+        self.status = Status::default();
+    }
+
+    /// Receives a byte, applying next injected error to it.
+    ///
+    /// # Parameters
+    /// * `byte` - Received byte.
+    fn receive(&mut self, byte: u8) {
+        let byte = match self.injected_errors.pop_front() {
+            Some(InjectedError::Parity) => {
+                self.status.parity_error = true;
+                byte
+            }
+            Some(InjectedError::Framing) => {
+                self.status.framing_error = true;
+                byte
+            }
+            Some(InjectedError::Overrun) => {
+                self.status.overrun_error = true;
+                return;
+            }
+            Some(InjectedError::Corruption(mask)) => byte ^ mask,
+            Some(InjectedError::Loss) => return,
+            None => byte,
+        };
+
+        if self.received.len() == RECEIVER_BUFFER_SIZE {
+            self.status.overrun_error = true;
+            return;
+        }
+
+        self.received.push_back(byte);
+    }
+}
+
+impl Default for SimulatedUart {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn read_all(uart: &mut SimulatedUart) -> Vec<u8> {
+        core::iter::from_fn(|| uart.read_byte()).collect()
+    }
+
+    #[test]
+    fn loopback() {
+        let mut uart = SimulatedUart::new();
+
+        uart.write(&[1, 2, 3]);
+
+        assert!(uart.status().receiver_ready);
+        assert_eq!(read_all(&mut uart), [1, 2, 3]);
+        assert_eq!(uart.take_transmitted(), [1, 2, 3]);
+        assert_eq!(uart.status(), Status::default());
+
+        uart.set_loopback(false);
+        uart.write(&[4]);
+        uart.deliver(&[5]);
+
+        assert_eq!(read_all(&mut uart), [5]);
+        assert_eq!(uart.take_transmitted(), [4]);
+    }
+
+    #[test]
+    fn reception_error_flags() {
+        let mut uart = SimulatedUart::new();
+
+        uart.inject_error(InjectedError::Parity);
+        uart.inject_error(InjectedError::Framing);
+        uart.write(&[1, 2, 3]);
+
+        let status = uart.status();
+        assert!(status.parity_error);
+        assert!(status.framing_error);
+        assert!(!status.overrun_error);
+        assert_eq!(read_all(&mut uart), [1, 2, 3]);
+
+        uart.reset_status();
+        uart.inject_error(InjectedError::Overrun);
+        uart.write(&[4, 5]);
+
+        assert!(uart.status().overrun_error);
+        assert!(!uart.status().parity_error);
+        assert_eq!(read_all(&mut uart), [5]);
+    }
+
+    #[test]
+    fn silent_errors() {
+        let mut uart = SimulatedUart::new();
+
+        uart.write(&[0x10]);
+        uart.inject_error(InjectedError::Corruption(0x01));
+        uart.inject_error(InjectedError::Loss);
+        uart.write(&[0x20, 0x30, 0x40]);
+
+        assert_eq!(read_all(&mut uart), [0x10, 0x21, 0x40]);
+        assert_eq!(uart.status(), Status::default());
+    }
+
+    #[test]
+    fn receiver_buffer_overrun() {
+        let mut uart = SimulatedUart::new();
+        uart.set_loopback(false);
+
+        uart.deliver(&[0; RECEIVER_BUFFER_SIZE]);
+        assert!(!uart.status().overrun_error);
+
+        uart.deliver(&[1]);
+        assert!(uart.status().overrun_error);
+        assert_eq!(read_all(&mut uart), [0; RECEIVER_BUFFER_SIZE]);
+    }
+}
@@ -1,6 +1,11 @@
 //! Module representing user-accessible peripherals.
 
+use crate::uart::SimulatedUart;
+
 /// Peripherals structure.
 /// These peripherals can be used to create HAL drivers in user code.
 #[derive(Default)]
-pub struct UserPeripherals {}
+pub struct UserPeripherals {
+    /// Simulated UART.
+    pub uart: SimulatedUart,
+}
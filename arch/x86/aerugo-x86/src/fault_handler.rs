@@ -0,0 +1,9 @@
+//! Fault handlers for the x86 target.
+
+/// Sets name of the currently executed tasklet, stored in the fault report.
+///
+/// # Parameters
+/// * `name` - Name of the tasklet, `None` if no tasklet is executed.
+pub fn set_current_tasklet(_name: Option<&'static str>) {
+    // No-op on x86
+}
@@ -0,0 +1,17 @@
+//! Scheduler instrumentation for the x86 target.
+
+/// Function used to initialize instrumentation facilities. Should be called once, on init.
+pub fn init_instrumentation() {
+    // No-op on x86
+}
+
+/// Writes tasklet execution record to the trace channel.
+///
+/// # Parameters
+/// * `tasklet_id` - ID of the executed tasklet.
+/// * `enqueue_time` - Time when the tasklet was put into the execution queue, in microseconds.
+/// * `start_time` - Time when the execution started, in microseconds.
+/// * `end_time` - Time when the execution ended, in microseconds.
+pub fn trace_execution(_tasklet_id: u32, _enqueue_time: u64, _start_time: u64, _end_time: u64) {
+    // No-op on x86
+}
@@ -5,8 +5,14 @@ x86 specific implementation for Aerugo.
 #![warn(clippy::missing_docs_in_private_items)]
 #![warn(rustdoc::missing_crate_level_docs)]
 
+#[cfg(feature = "fault-handler")]
+pub mod fault_handler;
+#[cfg(feature = "instrumented")]
+mod instrumentation;
 #[cfg(feature = "log")]
 mod logger;
 
+#[cfg(feature = "instrumented")]
+pub use self::instrumentation::{init_instrumentation, trace_execution};
 #[cfg(feature = "log")]
 pub use self::logger::{init_log, log, logln};
@@ -1,17 +1,8 @@
-use aerugo::EventId;
+use aerugo::AerugoEvents;
 
+#[derive(AerugoEvents)]
 pub enum CommandEvent {
-    Start,
-    Stop,
-    GetExecutionStats,
-}
-
-impl From<CommandEvent> for EventId {
-    fn from(value: CommandEvent) -> Self {
-        match value {
-            CommandEvent::Start => 0x10,
-            CommandEvent::Stop => 0x20,
-            CommandEvent::GetExecutionStats => 0x60,
-        }
-    }
+    Start = 0x10,
+    Stop = 0x20,
+    GetExecutionStats = 0x60,
 }
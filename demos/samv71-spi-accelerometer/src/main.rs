@@ -70,7 +70,7 @@ use aerugo::{
     },
     logln,
     time::RateExtU32,
-    Aerugo, EventId, EventStorage, InitApi, MessageQueueHandle, MessageQueueStorage,
+    Aerugo, EventId, InitApi, IrqCell, MessageQueueHandle, MessageQueueStorage, StaticDmaCell,
     SystemHardwareConfig, TaskletConfig, TaskletStorage,
 };
 use lsm6dso::{
@@ -106,27 +106,27 @@ type IMU = LSM6DSO<Spi<SPI0, Master>, 32>;
 
 const TELECOMMAND_LENGTH: usize = 7;
 type TelecommandBuffer = [u8; TELECOMMAND_LENGTH];
-static mut TELECOMMAND_BUFFER: TelecommandBuffer = [0; TELECOMMAND_LENGTH];
+/// Buffer to which XDMAC writes telecommands received by UART.
+static TELECOMMAND_BUFFER: StaticDmaCell<TelecommandBuffer> =
+    StaticDmaCell::new([0; TELECOMMAND_LENGTH]);
 
 /// This is used for passing XDMAC's status reader to IRQ.
 /// It must be initialized before starting an IRQ-synchronized XDMAC transaction, otherwise the
 /// program may panic.
-/// This can be safely accessed outside of XDMAC IRQ only when no XDMAC transactions are in progress.
-static mut XDMAC_STATUS_READER: Option<StatusReader> = None;
+static XDMAC_STATUS_READER: IrqCell<StatusReader> = IrqCell::new();
 /// This is used for passing XDMAC's channel status reader to IRQ.
 /// It must be initialized before starting an IRQ-synchronized XDMAC transaction, otherwise the
 /// program may panic.
-/// This can be safely accessed outside of XDMAC IRQ only when no XDMAC transactions are in progress.
-static mut XDMAC_CHANNEL_STATUS_READER: Option<ChannelStatusReader> = None;
+static XDMAC_CHANNEL_STATUS_READER: IrqCell<ChannelStatusReader> = IrqCell::new();
 /// This is used for passing XDMAC's channel to IRQ.
 /// It must be initialized before starting an IRQ-synchronized XDMAC transaction, otherwise the
 /// program may panic.
-/// This can be safely accessed outside of XDMAC IRQ only when no XDMAC transactions are in progress.
-static mut XDMAC_RX_CHANNEL: Option<Channel<Configured>> = None;
+static XDMAC_RX_CHANNEL: IrqCell<Channel<Configured>> = IrqCell::new();
 /// This is used for passing command queue handle to IRQ.
 /// It must be initialized before starting an IRQ-synchronized XDMAC transaction, otherwise the
 /// program may panic.
-static mut XDMAC_COMMAND_QUEUE_HANDLE: Option<MessageQueueHandle<TelecommandBuffer, 10>> = None;
+static XDMAC_COMMAND_QUEUE_HANDLE: IrqCell<MessageQueueHandle<TelecommandBuffer, 10>> =
+    IrqCell::new();
 
 static TASK_UART_READER_STORAGE: TaskletStorage<TelecommandBuffer, TaskUartReaderContext, 0> =
     TaskletStorage::new();
@@ -163,10 +163,6 @@ static QUEUE_SET_ACCELEROMETER_SCALE_STORAGE: MessageQueueStorage<AccelerometerS
 static QUEUE_SET_GYROSCOPE_SCALE_STORAGE: MessageQueueStorage<GyroscopeScale, 2> =
     MessageQueueStorage::new();
 
-static EVENT_START_STORAGE: EventStorage = EventStorage::new();
-static EVENT_STOP_STORAGE: EventStorage = EventStorage::new();
-static EVENT_STATS_STORAGE: EventStorage = EventStorage::new();
-
 /// IMU will never be accessed from an interrupt, so it's safe to access from tasklets.
 /// This is an "unsafe" alternative to `Mutex<RefCell<Option<T>>>` that's 100% safe in this specific
 /// scenario. Wrapping IMU in a mutex would require doing all IMU operations in critical sections,
@@ -239,10 +235,7 @@ fn main() -> ! {
     init_system(aerugo);
     logln!("System initialized!");
 
-    // This is safe, because this channel is currently idle.
-    unsafe {
-        XDMAC_RX_CHANNEL.as_mut().unwrap().enable();
-    }
+    XDMAC_RX_CHANNEL.borrow_mut().unwrap().enable();
 
     logln!("System is starting!");
     aerugo.start();
@@ -353,8 +346,10 @@ fn init_imu(spi: Spi<SPI0, Master>) -> IMU {
 
 fn init_xdmac(mut xdmac: Xdmac, uart: &mut Uart<UART4, Bidirectional>) {
     // Place XDMAC status reader in IRQ storage.
-    // This is safe as long as XDMAC IRQ is disabled.
-    unsafe { XDMAC_STATUS_READER.replace(xdmac.take_status_reader().unwrap()) };
+    XDMAC_STATUS_READER
+        .init(xdmac.take_status_reader().unwrap())
+        .ok()
+        .unwrap();
 
     let rx_source_location = TransferLocation {
         address: uart.xdmac_rx_address(),
@@ -363,7 +358,7 @@ fn init_xdmac(mut xdmac: Xdmac, uart: &mut Uart<UART4, Bidirectional>) {
     };
 
     let rx_destination_location = TransferLocation {
-        address: unsafe { TELECOMMAND_BUFFER.as_mut_ptr() as *const () },
+        address: TELECOMMAND_BUFFER.start_transfer().unwrap() as *const (),
         interface: SystemBus::Interface1,
         addressing_mode: AddressingMode::Incremented,
     };
@@ -394,11 +389,11 @@ fn init_xdmac(mut xdmac: Xdmac, uart: &mut Uart<UART4, Bidirectional>) {
     let mut rx_channel = rx_channel.configure_transfer(rx_transfer);
 
     // Place RX channel and it's status reader in IRQ storage.
-    // This is safe as long as XDMAC IRQ is disabled.
-    unsafe {
-        XDMAC_CHANNEL_STATUS_READER.replace(rx_channel.take_status_reader().unwrap());
-        XDMAC_RX_CHANNEL.replace(rx_channel);
-    };
+    XDMAC_CHANNEL_STATUS_READER
+        .init(rx_channel.take_status_reader().unwrap())
+        .ok()
+        .unwrap();
+    XDMAC_RX_CHANNEL.init(rx_channel).ok().unwrap();
 }
 
 fn init_system(aerugo: &'static impl InitApi) {
@@ -422,9 +417,7 @@ fn init_system(aerugo: &'static impl InitApi) {
 
     // Events
 
-    aerugo.create_event(CommandEvent::Start.into(), &EVENT_START_STORAGE);
-    aerugo.create_event(CommandEvent::Stop.into(), &EVENT_STOP_STORAGE);
-    aerugo.create_event(CommandEvent::GetExecutionStats.into(), &EVENT_STATS_STORAGE);
+    CommandEvent::create_events(aerugo);
 
     // UART reader
 
@@ -609,18 +602,22 @@ fn init_system(aerugo: &'static impl InitApi) {
 
     // Post-init
 
-    unsafe {
-        XDMAC_COMMAND_QUEUE_HANDLE.replace(queue_command_handle);
-    }
+    XDMAC_COMMAND_QUEUE_HANDLE
+        .init(queue_command_handle)
+        .ok()
+        .unwrap();
 }
 
 #[interrupt]
 fn XDMAC() {
-    let rx_channel = unsafe { XDMAC_RX_CHANNEL.as_mut().unwrap() };
+    let mut rx_channel = XDMAC_RX_CHANNEL.borrow_mut().unwrap();
 
-    let channel_status_reader = unsafe { XDMAC_CHANNEL_STATUS_READER.as_mut().unwrap() };
+    let channel_status_reader = XDMAC_CHANNEL_STATUS_READER.borrow_mut().unwrap();
 
-    let status = unsafe { XDMAC_STATUS_READER.as_mut().unwrap().get_pending_channels() };
+    let status = XDMAC_STATUS_READER
+        .borrow_mut()
+        .unwrap()
+        .get_pending_channels();
 
     if status[channel_status_reader.id()] {
         let events = channel_status_reader.get_pending_events();
@@ -636,16 +633,19 @@ fn XDMAC() {
         }
     }
 
-    unsafe {
-        let result = XDMAC_COMMAND_QUEUE_HANDLE
-            .unwrap()
-            .send_data(TELECOMMAND_BUFFER);
+    // Transfer has ended, so the buffer can be read before it's passed to the next one.
+    TELECOMMAND_BUFFER.finish_transfer();
+    let telecommand = TELECOMMAND_BUFFER.read().unwrap();
+    let result = XDMAC_COMMAND_QUEUE_HANDLE
+        .borrow_mut()
+        .unwrap()
+        .send_data(telecommand);
 
-        if result.is_err() {
-            logln!("Failed to send command to the queue");
-        }
+    if result.is_err() {
+        logln!("Failed to send command to the queue");
     }
 
+    TELECOMMAND_BUFFER.start_transfer().unwrap();
     rx_channel.repeat_transfer();
     rx_channel.enable();
 }
@@ -155,6 +155,9 @@ fn main() -> ! {
     let distributor_config = TaskletConfig {
         name: "Distributor",
         priority: 1,
+        deadline: None,
+        yield_budget: None,
+        finalize: None,
     };
 
     aerugo.create_tasklet(distributor_config, distributor, &DISTRIBUTOR_STORAGE);
@@ -162,6 +165,9 @@ fn main() -> ! {
     let fizz_config = TaskletConfig {
         name: "Fizz",
         priority: 3,
+        deadline: None,
+        yield_budget: None,
+        finalize: None,
     };
 
     aerugo.create_tasklet(fizz_config, fizz, &FIZZ_STORAGE);
@@ -169,6 +175,9 @@ fn main() -> ! {
     let buzz_config = TaskletConfig {
         name: "Buzz",
         priority: 2,
+        deadline: None,
+        yield_budget: None,
+        finalize: None,
     };
 
     aerugo.create_tasklet(buzz_config, buzz, &BUZZ_STORAGE);
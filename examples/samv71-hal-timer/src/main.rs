@@ -12,6 +12,7 @@ use aerugo::hal::drivers::pmc::PMC;
 use aerugo::hal::drivers::timer::{
     channel_config::ChannelClock, waveform_config::WaveformModeConfig, Ch0, Channel, Waveform, TC1,
 };
+use aerugo::hal::take_peripheral;
 use aerugo::Mutex;
 
 use aerugo::{
@@ -81,13 +82,13 @@ fn init_tasks(aerugo: &'static impl InitApi) {
 
 #[entry]
 fn main() -> ! {
-    let (aerugo, peripherals) = Aerugo::initialize(SystemHardwareConfig::default());
+    let (aerugo, mut peripherals) = Aerugo::initialize(SystemHardwareConfig::default());
 
     logln!("Hello, world! Aerugo initialized!");
 
     logln!("Doing stuff with timers...");
-    let timer = Timer::new(peripherals.timer_counter1.expect("Timer 1 already used"));
-    let pmc = peripherals.pmc.expect("PMC already taken");
+    let timer = Timer::new(take_peripheral!(peripherals.timer_counter1, "main"));
+    let pmc = take_peripheral!(peripherals.pmc, "main");
     init_clocks(pmc);
     init_timer(timer);
 
@@ -0,0 +1,111 @@
+#![no_std]
+#![no_main]
+
+extern crate cortex_m;
+extern crate cortex_m_rt as rt;
+extern crate panic_rtt_target;
+
+use aerugo::hal::drivers::fault_injection;
+use aerugo::hal::drivers::mpu::Mpu;
+use aerugo::hal::drivers::pac::GPBR;
+use aerugo::hal::Hal;
+use aerugo::{logln, Aerugo, InitApi, SystemHardwareConfig};
+use cortex_m::peripheral::scb::Exception;
+use cortex_m::peripheral::SCB;
+use rt::{entry, exception};
+
+/// Self-test stages. Current stage is kept in general purpose backup register, so it survives
+/// MCU resets caused by the tests.
+const STAGE_WATCHDOG: u32 = 0;
+const STAGE_WATCHDOG_CHECK: u32 = 1;
+const STAGE_MPU: u32 = 2;
+const STAGE_BUS_FAULT: u32 = 3;
+const STAGE_FINISHED: u32 = 4;
+
+/// MPU region used for the guard in MPU test.
+const GUARD_REGION: u8 = 0;
+
+fn stage() -> u32 {
+    // SAFETY: GPBR is only used by this application, single register read is atomic.
+    unsafe { (*GPBR::ptr()).sys_gpbr[0].read().gpbr_value().bits() }
+}
+
+fn set_stage(stage: u32) {
+    // SAFETY: GPBR is only used by this application, single register write is atomic.
+    unsafe { (*GPBR::ptr()).sys_gpbr[0].write(|w| w.gpbr_value().bits(stage)) };
+}
+
+#[entry]
+fn main() -> ! {
+    let (aerugo, mut peripherals) = Aerugo::initialize(SystemHardwareConfig::default());
+
+    let rstc = peripherals.rstc.take().unwrap();
+    let reset_type = rstc.sr.read().rsttyp();
+
+    // Backup registers are not reliable after power-up, so the test always starts from the
+    // beginning in that case.
+    if reset_type.is_general_rst() {
+        set_stage(STAGE_WATCHDOG);
+    }
+
+    let mut scb = peripherals.scb.take().unwrap();
+
+    match stage() {
+        STAGE_WATCHDOG => {
+            logln!("Watchdog test: waiting for the watchdog reset...");
+            set_stage(STAGE_WATCHDOG_CHECK);
+            Hal::trigger_watchdog_reset();
+        }
+        STAGE_WATCHDOG_CHECK => {
+            if reset_type.is_wdt_rst() {
+                logln!("Watchdog test: PASSED");
+            } else {
+                logln!("Watchdog test: FAILED, MCU was not reset by the watchdog");
+            }
+
+            logln!("MPU test: accessing guard region...");
+            set_stage(STAGE_MPU);
+            scb.enable(Exception::MemoryManagement);
+
+            let mut mpu = Mpu::new(peripherals.mpu.take().unwrap());
+            match fault_injection::trigger_mpu_fault(&mut mpu, GUARD_REGION) {
+                Ok(()) => logln!("MPU test: FAILED, access was not trapped"),
+                Err(error) => logln!("MPU test: FAILED, cannot configure guard: {:?}", error),
+            }
+
+            set_stage(STAGE_BUS_FAULT);
+            SCB::sys_reset();
+        }
+        STAGE_BUS_FAULT => {
+            logln!("Bus fault test: accessing unmapped memory...");
+            scb.enable(Exception::BusFault);
+
+            // SAFETY: BusFault handler resets the MCU and never returns to this code.
+            unsafe { fault_injection::trigger_bus_fault() };
+
+            logln!("Bus fault test: FAILED, access was not trapped");
+            set_stage(STAGE_FINISHED);
+            SCB::sys_reset();
+        }
+        _ => {
+            logln!("Self-test finished");
+            set_stage(STAGE_WATCHDOG);
+        }
+    }
+
+    aerugo.start();
+}
+
+#[exception]
+fn MemoryManagement() -> ! {
+    logln!("MPU test: PASSED");
+    set_stage(STAGE_BUS_FAULT);
+    SCB::sys_reset();
+}
+
+#[exception]
+fn BusFault() -> ! {
+    logln!("Bus fault test: PASSED");
+    set_stage(STAGE_FINISHED);
+    SCB::sys_reset();
+}
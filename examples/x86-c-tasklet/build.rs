@@ -0,0 +1,11 @@
+fn main() {
+    println!("cargo:rerun-if-changed=c");
+
+    cc::Build::new()
+        .file("c/pid.c")
+        .file("c/pid_tasklet.c")
+        .include("c")
+        .include("../../aerugo-capi/include")
+        .warnings(true)
+        .compile("pid_tasklet");
+}
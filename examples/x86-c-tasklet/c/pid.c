@@ -0,0 +1,23 @@
+/* Legacy PID controller library, used without modifications. */
+
+#include "pid.h"
+
+void pid_init(PidController *pid, float kp, float ki, float kd)
+{
+    pid->kp = kp;
+    pid->ki = ki;
+    pid->kd = kd;
+    pid->integral = 0.0f;
+    pid->previous_error = 0.0f;
+}
+
+float pid_update(PidController *pid, float setpoint, float measurement, float dt)
+{
+    float error = setpoint - measurement;
+    float derivative = (error - pid->previous_error) / dt;
+
+    pid->integral += error * dt;
+    pid->previous_error = error;
+
+    return (pid->kp * error) + (pid->ki * pid->integral) + (pid->kd * derivative);
+}
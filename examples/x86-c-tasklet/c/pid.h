@@ -0,0 +1,17 @@
+/* Legacy PID controller library, used without modifications. */
+
+#ifndef PID_H
+#define PID_H
+
+typedef struct PidController {
+    float kp;
+    float ki;
+    float kd;
+    float integral;
+    float previous_error;
+} PidController;
+
+void pid_init(PidController *pid, float kp, float ki, float kd);
+float pid_update(PidController *pid, float setpoint, float measurement, float dt);
+
+#endif /* PID_H */
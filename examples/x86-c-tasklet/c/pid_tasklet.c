@@ -0,0 +1,63 @@
+/*
+ * Shim running the legacy PID controller as an aerugo tasklet.
+ *
+ * The shim is written with MISRA-C in mind: all memory is allocated statically, fixed-width types
+ * are used, every status returned by the C API is checked, and each function has a single exit
+ * point. The PID library itself is not modified.
+ */
+
+#include <stdint.h>
+#include <stdio.h>
+
+#include "aerugo.h"
+#include "pid.h"
+
+#define PID_PERIOD_US ((uint64_t)100000U)
+#define PID_PERIOD_S (0.1f)
+#define PID_SETPOINT (10.0f)
+
+typedef struct PidTaskletContext {
+    PidController pid;
+    float plant_output;
+} PidTaskletContext;
+
+static PidTaskletContext pid_tasklet_context;
+
+static void pid_tasklet_step(void *context, const AerugoRuntime *runtime)
+{
+    PidTaskletContext *const ctx = (PidTaskletContext *)context;
+    const float control = pid_update(&ctx->pid, PID_SETPOINT, ctx->plant_output, PID_PERIOD_S);
+
+    /* Simple first-order plant model. */
+    ctx->plant_output += (control - ctx->plant_output) * PID_PERIOD_S;
+
+    (void)printf("[%llu us] PID output: %f\n",
+                 (unsigned long long)aerugo_get_system_time_us(runtime),
+                 (double)ctx->plant_output);
+}
+
+AerugoStatus pid_tasklet_init(void)
+{
+    AerugoStatus status = AERUGO_STATUS_OK;
+    const AerugoTasklet *tasklet = NULL;
+    const AerugoVersion version = aerugo_capi_version();
+
+    /* Minor version changes are backwards compatible, so only major version has to match. */
+    if (version.major != (uint16_t)AERUGO_CAPI_VERSION_MAJOR) {
+        status = AERUGO_STATUS_INVALID_PARAMETER;
+    }
+
+    if (status == AERUGO_STATUS_OK) {
+        pid_init(&pid_tasklet_context.pid, 1.5f, 0.5f, 0.05f);
+        pid_tasklet_context.plant_output = 0.0f;
+
+        status = aerugo_create_tasklet("PidTasklet", (uint8_t)0U, &pid_tasklet_step,
+                                       &pid_tasklet_context, &tasklet);
+    }
+
+    if (status == AERUGO_STATUS_OK) {
+        status = aerugo_subscribe_tasklet_to_cyclic(tasklet, PID_PERIOD_US, (uint64_t)0U);
+    }
+
+    return status;
+}
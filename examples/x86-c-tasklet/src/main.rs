@@ -0,0 +1,16 @@
+use aerugo_capi::{aerugo_initialize, aerugo_start, AerugoStatus};
+
+extern "C" {
+    /// Initializes PID controller tasklet, implemented in C.
+    fn pid_tasklet_init() -> AerugoStatus;
+}
+
+fn main() {
+    assert_eq!(aerugo_initialize(), AerugoStatus::Ok);
+
+    // SAFETY: This is safe, because it's called once, during system initialization.
+    let status = unsafe { pid_tasklet_init() };
+    assert_eq!(status, AerugoStatus::Ok, "Failed to initialize PID tasklet");
+
+    aerugo_start();
+}
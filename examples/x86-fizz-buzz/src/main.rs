@@ -152,6 +152,9 @@ fn main() -> ! {
     let fizz_config = TaskletConfig {
         name: "Fizz",
         priority: 2,
+        deadline: None,
+        yield_budget: None,
+        finalize: None,
     };
 
     aerugo.create_tasklet(fizz_config, fizz, &FIZZ_STORAGE);
@@ -159,6 +162,9 @@ fn main() -> ! {
     let buzz_config = TaskletConfig {
         name: "Buzz",
         priority: 1,
+        deadline: None,
+        yield_budget: None,
+        finalize: None,
     };
 
     aerugo.create_tasklet(buzz_config, buzz, &BUZZ_STORAGE);
@@ -166,6 +172,9 @@ fn main() -> ! {
     let done_config = TaskletConfig {
         name: "Done",
         priority: 3,
+        deadline: None,
+        yield_budget: None,
+        finalize: None,
     };
 
     aerugo.create_tasklet(done_config, done, &DONE_STORAGE);
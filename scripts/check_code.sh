@@ -3,6 +3,8 @@
 set -euo pipefail
 
 cargo clippy --workspace --tests -F use-aerugo-cortex-m -- -D warnings
+cargo clippy --package samv71-hal --no-default-features -- -D warnings
+cargo clippy --package aerugo --no-default-features -F use-aerugo-cortex-m -- -D warnings
 
 for d in examples/*/; do
     pushd $d >/dev/null
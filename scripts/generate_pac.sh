@@ -0,0 +1,40 @@
+#!/bin/bash
+
+# Run this from main project's directory.
+# This script regenerates SAMV71Q21 Peripheral Access Crate from the SVD file stored in the crate.
+# Registers with atomic access get `set_bits`, `clear_bits` and `toggle_bits` accessors (enabled with
+# `atomics` feature of the PAC), and fields described with enumerated values get typed variants, so HAL
+# drivers don't have to write raw bits.
+
+# Pinned version is the one the PAC is currently generated with. After changing it, HAL drivers have to be
+# adapted to the changed API and checked with `./scripts/check_code.sh` and `./scripts/build_hal_tests.sh`.
+
+# Dependencies:
+# * `svd2rust` (version below, installed with `cargo install svd2rust --version <version> --locked`)
+# * `form` (installed with `cargo install form --locked`)
+# * `rustfmt`
+
+set -euo pipefail
+
+SVD2RUST_VERSION="0.30.1"
+PAC_DIR="arch/cortex-m/samv71q21-pac"
+SVD_FILE="${PAC_DIR}/ATSAMV71Q21B.svd"
+
+if ! svd2rust --version | grep -q "${SVD2RUST_VERSION}"; then
+    echo "svd2rust ${SVD2RUST_VERSION} is required, found: $(svd2rust --version)"
+    exit 1
+fi
+
+GENERATED_DIR=$(mktemp -d)
+trap 'rm -rf "${GENERATED_DIR}"' EXIT
+
+echo "Generating PAC from ${SVD_FILE}"
+svd2rust --target cortex-m --atomics --atomics_feature atomics -i "${SVD_FILE}" -o "${GENERATED_DIR}"
+
+echo "Splitting generated code into modules"
+rm -rf "${PAC_DIR}/src"
+form -i "${GENERATED_DIR}/lib.rs" -o "${PAC_DIR}/src"
+cp "${GENERATED_DIR}/build.rs" "${GENERATED_DIR}/device.x" "${PAC_DIR}"
+
+echo "Formatting generated code"
+rustfmt --edition 2021 "${PAC_DIR}/src/lib.rs"
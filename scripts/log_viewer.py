@@ -0,0 +1,272 @@
+"""Script that displays and filters the system log, and exports it to CSV.
+
+System log is parsed into records. With `log-prefix` feature, each line logged with `logln!` is
+prefixed with time in microseconds and name of the executed tasklet, for example
+`[1520] [TaskB] value: 3`. Records forwarded from the `log` crate facade (`log-facade` feature)
+additionally carry level and target, for example `[1520] [TaskB] WARN sensor: no data`. Lines
+without these prefixes are kept as records with message only.
+
+Log can be read from a file, from RTT server (in `hostname:port` format, for example
+192.168.1.1:1234) or from UART of the target board, connected to a remote debugging setup.
+Records are printed as they arrive, until the source ends or the script is interrupted with
+Ctrl+C. Call with `--help` to show usage.
+"""
+
+from __future__ import annotations
+
+import argparse
+import csv
+import re
+import sys
+from dataclasses import dataclass
+from pathlib import Path
+from typing import TYPE_CHECKING, TextIO
+
+from calldwell.rtt_client import RTTClient
+from calldwell.ssh_client import SSHClient
+from calldwell.uart import RemoteUARTConfig, RemoteUARTConnection
+
+if TYPE_CHECKING:
+    from collections.abc import Iterator
+
+LOG_LEVELS = ["ERROR", "WARN", "INFO", "DEBUG", "TRACE"]
+"""Levels of the `log` crate facade, from the most severe."""
+LOG_LINE_REGEX = re.compile(
+    r"^(?:\[(?P<time>\d+)\] )?"
+    r"(?:\[(?P<tasklet>[^\]]+)\] )?"
+    rf"(?:(?P<level>{'|'.join(LOG_LEVELS)}) (?P<target>\S+): )?"
+    r"(?P<message>.*)$",
+)
+"""Regular expression matching a single log line."""
+CSV_COLUMNS = ["time_us", "tasklet", "level", "target", "message"]
+"""Columns of the exported CSV file."""
+
+
+@dataclass
+class LogRecord:
+    """Single log record. Fields missing from the log line are `None`."""
+
+    time_us: int | None
+    tasklet: str | None
+    level: str | None
+    target: str | None
+    message: str
+
+    def __str__(self: LogRecord) -> str:
+        prefix = "".join(
+            f"{field:<{width}} "
+            for field, width in [
+                ("" if self.time_us is None else str(self.time_us), 12),
+                (self.tasklet or "", 16),
+                (self.level or "", 5),
+            ]
+        )
+        target = f"{self.target}: " if self.target is not None else ""
+        return f"{prefix}{target}{self.message}"
+
+
+@dataclass
+class LogFilter:
+    """Filter of log records. Empty criteria match all records."""
+
+    tasklets: list[str]
+    max_level: str | None
+    pattern: re.Pattern[str] | None
+
+    def matches(self: LogFilter, record: LogRecord) -> bool:
+        """Returns `True` if record matches all criteria of the filter. Records without level
+        are matched by any level criteria."""
+        if self.tasklets and record.tasklet not in self.tasklets:
+            return False
+
+        if (
+            self.max_level is not None
+            and record.level is not None
+            and LOG_LEVELS.index(record.level) > LOG_LEVELS.index(self.max_level)
+        ):
+            return False
+
+        return self.pattern is None or self.pattern.search(record.message) is not None
+
+
+def get_user_args() -> argparse.Namespace:
+    """Fetch user arguments via `argparse`."""
+    parser = argparse.ArgumentParser(
+        description="Helper script that displays and filters the system log, and exports it to "
+        "CSV",
+        exit_on_error=True,
+    )
+
+    parser.add_argument(
+        "--tasklet",
+        type=str,
+        action="append",
+        default=[],
+        help="Show only records logged by the tasklet with given name, can be repeated",
+    )
+    parser.add_argument(
+        "--level",
+        type=str.upper,
+        choices=LOG_LEVELS,
+        help="Show only records with given or more severe level, records without level are "
+        "always shown",
+    )
+    parser.add_argument(
+        "--grep",
+        type=re.compile,
+        help="Show only records with message matching given regular expression",
+    )
+    parser.add_argument("--csv", type=Path, help="Export shown records to given CSV file")
+
+    sources = parser.add_subparsers(dest="source", required=True, help="Source of the log")
+
+    file_source = sources.add_parser("file", help="Read log from a file")
+    file_source.add_argument("path", type=Path, help="Path to the log file")
+
+    rtt_source = sources.add_parser("rtt", help="Receive log from RTT server")
+    rtt_source.add_argument(
+        "address",
+        type=str,
+        help="Hostname and port of RTT server, for example 192.168.1.1:1234",
+    )
+
+    uart_source = sources.add_parser("uart", help="Receive log from UART of the target board")
+    uart_source.add_argument(
+        "hostname",
+        type=str,
+        help="Target's hostname, for example 192.168.1.1 or localhost",
+    )
+    uart_source.add_argument("login", type=str, help="SSH login of target machine")
+    uart_source.add_argument("password", type=str, help="SSH password of target machine")
+    uart_source.add_argument(
+        "device_path",
+        type=str,
+        help="Path to UART device on target machine, for example /dev/ttyUSB0",
+    )
+    uart_source.add_argument("baudrate", type=int, help="Target's baudrate, in bits per second")
+    uart_source.add_argument(
+        "--port",
+        type=int,
+        default=19876,
+        help="TCP port for UART connection",
+    )
+
+    return parser.parse_args()
+
+
+def receive_from_file(path: Path) -> Iterator[str]:
+    """Yields content of the log file."""
+    yield path.read_text(encoding="utf-8", errors="replace")
+
+
+def receive_from_rtt(address: str) -> Iterator[str]:
+    """Yields data received from RTT server, until the connection is closed."""
+    host, port = address.rsplit(":", 1)
+    rtt = RTTClient(host, int(port))
+
+    try:
+        while data := rtt.receive():
+            yield data.decode("utf-8", errors="replace")
+    finally:
+        rtt.close()
+
+
+def receive_from_uart(args: argparse.Namespace) -> Iterator[str]:
+    """Yields data received from UART of the target board."""
+    ssh = SSHClient(args.hostname, args.login, args.password)
+    uart_config = RemoteUARTConfig(
+        device_path=args.device_path,
+        port=args.port,
+        baudrate=args.baudrate,
+    )
+    uart = RemoteUARTConnection(ssh, uart_config)
+
+    if not uart.open_uart():
+        print("UART connection couldn't be established, quitting...")
+        sys.exit(1)
+
+    try:
+        while True:
+            if (data := uart.read_bytes()).is_ok:
+                yield data.unwrap().decode("utf-8", errors="replace")
+    finally:
+        uart.close_uart()
+
+
+def split_lines(chunks: Iterator[str]) -> Iterator[str]:
+    """Yields complete lines from received chunks of data. Incomplete line at the end of the data
+    is yielded when the source ends."""
+    pending = ""
+    for chunk in chunks:
+        *lines, pending = (pending + chunk).split("\n")
+        yield from (line.rstrip("\r") for line in lines)
+
+    if pending:
+        yield pending.rstrip("\r")
+
+
+def parse_line(line: str) -> LogRecord:
+    """Parses single log line into a record."""
+    match = LOG_LINE_REGEX.match(line)
+    # Regular expression matches any line, as all prefixes are optional.
+    assert match is not None  # noqa: S101
+    time = match.group("time")
+    return LogRecord(
+        time_us=int(time) if time is not None else None,
+        tasklet=match.group("tasklet"),
+        level=match.group("level"),
+        target=match.group("target"),
+        message=match.group("message"),
+    )
+
+
+def show_log(lines: Iterator[str], log_filter: LogFilter, csv_file: TextIO | None) -> int:
+    """Prints records matching the filter and writes them to CSV file, if given.
+    Returns number of shown records."""
+    writer = csv.writer(csv_file) if csv_file is not None else None
+    if writer is not None:
+        writer.writerow(CSV_COLUMNS)
+
+    shown = 0
+    try:
+        for line in lines:
+            record = parse_line(line)
+            if not log_filter.matches(record):
+                continue
+
+            print(record, flush=True)
+            if writer is not None:
+                writer.writerow(
+                    [record.time_us, record.tasklet, record.level, record.target, record.message],
+                )
+            shown += 1
+    except KeyboardInterrupt:
+        pass
+
+    return shown
+
+
+def main() -> None:
+    """Main function of this script."""
+    args = get_user_args()
+
+    if args.source == "file":
+        chunks = receive_from_file(args.path)
+    elif args.source == "rtt":
+        chunks = receive_from_rtt(args.address)
+    else:
+        chunks = receive_from_uart(args)
+
+    log_filter = LogFilter(tasklets=args.tasklet, max_level=args.level, pattern=args.grep)
+
+    if args.csv is None:
+        show_log(split_lines(chunks), log_filter, None)
+        return
+
+    with args.csv.open("w", newline="", encoding="utf-8") as csv_file:
+        shown = show_log(split_lines(chunks), log_filter, csv_file)
+    print(f"{shown} records written to {args.csv}")
+
+
+if __name__ == "__main__":
+    main()
@@ -0,0 +1,214 @@
+"""Script that generates dataflow diagram of the system from its configuration manifest.
+
+Manifest is written by `InitApi::write_manifest` in JSON Lines format. Log with the manifest can be
+passed directly, lines that don't contain a JSON object are skipped, as is any prefix before the
+object (for example time and tasklet name added by `log-prefix` feature).
+
+Tasklets are the nodes of the diagram. Message queues, boolean conditions, cyclic executions and
+events are drawn as separate nodes connected to the tasklets they wake up, conditions from
+condition sets are connected with dashed edges, and chained tasklets are connected directly.
+Diagram is generated in Graphviz (DOT) or Mermaid format.
+
+Usage:
+Pass the manifest file as argument to this script, call with `--help` to show all options.
+"""
+
+from __future__ import annotations
+
+import argparse
+import json
+import sys
+from dataclasses import dataclass, field
+from pathlib import Path
+from typing import Any
+
+
+@dataclass
+class Node:
+    """Node of the diagram."""
+
+    label: str
+    shape: str
+    """Shape of the node: `box`, `cylinder`, `diamond` or `ellipse`."""
+
+
+@dataclass
+class Edge:
+    """Edge of the diagram."""
+
+    source: str
+    target: str
+    label: str = ""
+    dashed: bool = False
+
+
+@dataclass
+class Diagram:
+    """Dataflow diagram, with nodes identified by unique names."""
+
+    nodes: dict[str, Node] = field(default_factory=dict)
+    edges: list[Edge] = field(default_factory=list)
+
+
+def get_user_args() -> argparse.Namespace:
+    """Fetch user arguments via `argparse`."""
+    parser = argparse.ArgumentParser(
+        description="Generates dataflow diagram of the system from its configuration manifest",
+    )
+    parser.add_argument("manifest", type=Path, help="Path to the manifest (or log containing it)")
+    parser.add_argument(
+        "--format",
+        choices=["dot", "mermaid"],
+        default="dot",
+        help="Format of the diagram (default: dot)",
+    )
+    parser.add_argument(
+        "-o",
+        "--output",
+        type=Path,
+        help="Path to the output file, diagram is printed if not set",
+    )
+    return parser.parse_args()
+
+
+def read_manifest(path: Path) -> list[dict[str, Any]]:
+    """Returns manifest records read from given file."""
+    records = []
+    for line in path.read_text(encoding="utf-8").splitlines():
+        start = line.find("{")
+        if start < 0:
+            continue
+        try:
+            record = json.loads(line[start:])
+        except json.JSONDecodeError:
+            continue
+        if isinstance(record, dict) and "type" in record:
+            records.append(record)
+    return records
+
+
+def format_period(period_us: int | None) -> str:
+    """Returns human-readable period of the cyclic execution."""
+    if period_us is None:
+        return "always"
+    if period_us >= 1_000_000 and period_us % 1_000_000 == 0:
+        return f"every {period_us // 1_000_000} s"
+    if period_us % 1000 == 0:
+        return f"every {period_us // 1000} ms"
+    return f"every {period_us} us"
+
+
+def source_node(source: dict[str, Any]) -> Node:
+    """Returns node of the tasklet data source."""
+    kind = source["kind"]
+    if kind == "message_queue":
+        return Node(f"queue ({source['capacity']})", "cylinder")
+    if kind == "data_event":
+        return Node(f"data event ({source['capacity']})", "cylinder")
+    if kind == "boolean_condition":
+        return Node("condition", "diamond")
+    if kind == "cyclic_execution":
+        return Node(format_period(source["period_us"]), "ellipse")
+    if kind == "schedule_table":
+        return Node(f"schedule table ({source['slot_count']} slots)", "ellipse")
+    return Node(kind.replace("_", " "), "ellipse")
+
+
+def build_diagram(records: list[dict[str, Any]]) -> Diagram:
+    """Builds dataflow diagram from the manifest records."""
+    diagram = Diagram()
+    tasklets = [record for record in records if record["type"] == "tasklet"]
+    predecessors = {
+        tasklet["successor_id"]: f"tasklet_{tasklet['id']}"
+        for tasklet in tasklets
+        if tasklet.get("successor_id") is not None
+    }
+
+    for tasklet in tasklets:
+        name = f"tasklet_{tasklet['id']}"
+        diagram.nodes[name] = Node(f"{tasklet['name']}\\nprio {tasklet['priority']}", "box")
+
+        source = tasklet.get("source")
+        source_id = tasklet.get("source_id")
+        if source is None or source["kind"] == "events":
+            # Events are connected to their subscribers from event records.
+            pass
+        elif source["kind"] == "tasklet_chain" and source_id in predecessors:
+            diagram.edges.append(Edge(predecessors[source_id], name, "completion"))
+        else:
+            prefix = "condition" if source["kind"] == "boolean_condition" else "source"
+            source_name = f"{prefix}_{source_id}"
+            diagram.nodes.setdefault(source_name, source_node(source))
+            diagram.edges.append(Edge(source_name, name))
+
+        conditions = tasklet.get("conditions")
+        if conditions is not None:
+            for condition_id in conditions["ids"]:
+                condition_name = f"condition_{condition_id}"
+                diagram.nodes.setdefault(condition_name, Node("condition", "diamond"))
+                diagram.edges.append(Edge(condition_name, name, conditions["type"], dashed=True))
+
+    for event in (record for record in records if record["type"] == "event"):
+        name = f"event_{event['id']}"
+        diagram.nodes[name] = Node(f"event {event['id']}", "ellipse")
+        for subscriber in event["subscribers"]:
+            diagram.edges.append(Edge(name, f"tasklet_{subscriber}"))
+
+    return diagram
+
+
+def render_dot(diagram: Diagram) -> str:
+    """Renders diagram in Graphviz (DOT) format."""
+    lines = ["digraph aerugo {", "    rankdir=LR;"]
+    for name, node in diagram.nodes.items():
+        label = node.label.replace('"', '\\"')
+        lines.append(f'    {name} [label="{label}", shape={node.shape}];')
+    for edge in diagram.edges:
+        attributes = [f'label="{edge.label}"'] if edge.label else []
+        if edge.dashed:
+            attributes.append("style=dashed")
+        suffix = f" [{', '.join(attributes)}]" if attributes else ""
+        lines.append(f"    {edge.source} -> {edge.target}{suffix};")
+    lines.append("}")
+    return "\n".join(lines) + "\n"
+
+
+def render_mermaid(diagram: Diagram) -> str:
+    """Renders diagram in Mermaid format."""
+    brackets = {
+        "box": ('["', '"]'),
+        "cylinder": ('[("', '")]'),
+        "diamond": ('{"', '"}'),
+        "ellipse": ('(["', '"])'),
+    }
+    lines = ["flowchart LR"]
+    for name, node in diagram.nodes.items():
+        opening, closing = brackets[node.shape]
+        label = node.label.replace('"', "#quot;").replace("\\n", "<br>")
+        lines.append(f"    {name}{opening}{label}{closing}")
+    for edge in diagram.edges:
+        arrow = "-.->" if edge.dashed else "-->"
+        label = f"|{edge.label}|" if edge.label else ""
+        lines.append(f"    {edge.source} {arrow}{label} {edge.target}")
+    return "\n".join(lines) + "\n"
+
+
+def main() -> None:
+    """Main function of the script."""
+    args = get_user_args()
+    records = read_manifest(args.manifest)
+    if not records:
+        print(f"No manifest records found in {args.manifest}", file=sys.stderr)
+        sys.exit(1)
+
+    diagram = build_diagram(records)
+    output = render_dot(diagram) if args.format == "dot" else render_mermaid(diagram)
+
+    if args.output is None:
+        print(output, end="")
+    else:
+        args.output.write_text(output, encoding="utf-8")
+
+
+if __name__ == "__main__":
+    main()
@@ -5,14 +5,47 @@ set -euo pipefail
 aerugo_x86() {
     export AERUGO_TASKLET_COUNT=5
     export AERUGO_EVENT_COUNT=5
+    export AERUGO_PARTITION_WINDOW_COUNT=2
+    export AERUGO_EXTERNAL_WATCHDOG_COUNT=1
+    export AERUGO_TIMER_COUNT=2
+    export AERUGO_SOFTWARE_WATCHDOG_COUNT=1
+    export AERUGO_CAPI_TASKLET_COUNT=1
 
     echo "Running x86 tests"
-    cargo test --features=use-aerugo-x86 --target=x86_64-unknown-linux-gnu --package aerugo
+    cargo test --features=use-aerugo-x86,stimulus --target=x86_64-unknown-linux-gnu --package aerugo
+    cargo test --features=use-aerugo-x86,edf-scheduling --target=x86_64-unknown-linux-gnu --package aerugo --lib
+    cargo test --features=use-aerugo-x86,wake-latency --target=x86_64-unknown-linux-gnu --package aerugo --lib
+    cargo test --target=x86_64-unknown-linux-gnu --package aerugo-x86-hal
 
+    export -n AERUGO_CAPI_TASKLET_COUNT
+    export -n AERUGO_SOFTWARE_WATCHDOG_COUNT
+    export -n AERUGO_TIMER_COUNT
+    export -n AERUGO_EXTERNAL_WATCHDOG_COUNT
+    export -n AERUGO_PARTITION_WINDOW_COUNT
     export -n AERUGO_EVENT_COUNT
     export -n AERUGO_TASKLET_COUNT
 }
 
+tasklet_count() {
+    export AERUGO_EVENT_COUNT=5
+    export AERUGO_PARTITION_WINDOW_COUNT=2
+    export AERUGO_EXTERNAL_WATCHDOG_COUNT=1
+    export AERUGO_TIMER_COUNT=2
+    export AERUGO_SOFTWARE_WATCHDOG_COUNT=1
+
+    # Tasklet count is selected by the feature only when AERUGO_TASKLET_COUNT is not set.
+    for count in 8 32 128; do
+        echo "Running x86 tests with tasklet-count-${count} feature"
+        cargo test --features=use-aerugo-x86,tasklet-count-${count} --target=x86_64-unknown-linux-gnu --package aerugo --lib
+    done
+
+    export -n AERUGO_SOFTWARE_WATCHDOG_COUNT
+    export -n AERUGO_TIMER_COUNT
+    export -n AERUGO_EXTERNAL_WATCHDOG_COUNT
+    export -n AERUGO_PARTITION_WINDOW_COUNT
+    export -n AERUGO_EVENT_COUNT
+}
+
 aerugo_v71() {
     export AERUGO_TASKLET_COUNT=5
     export AERUGO_EVENT_COUNT=5
@@ -35,6 +68,11 @@ env_parser() {
     export -n ENV_PARSER_TEST_INTEGER_DIFFERENT_NAME
 }
 
+event_derive() {
+    echo "Running event-derive tests"
+    cargo test --features=use-aerugo-x86 --target=x86_64-unknown-linux-gnu --package event-derive-tests
+}
+
 all() {
     export ENV_PARSER_TEST_INTEGER_OVERRIDE=42
     export ENV_PARSER_TEST_INTEGER_DIFFERENT_NAME=314
@@ -56,7 +94,9 @@ run_ci_tests=$#
 
 if [ $run_ci_tests == 0 ]; then
     env_parser
+    event_derive
     aerugo_x86
+    tasklet_count
 else
     "$@"
 fi
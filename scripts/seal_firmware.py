@@ -0,0 +1,108 @@
+"""Script that seals firmware image metadata in the binary image.
+
+Firmware image metadata is declared in the application with `aerugo::firmware_info!` macro.
+Build ID, size and CRC of the image are not known at compile time, so this script writes them into
+the metadata after the build. Image must be a raw binary starting at the vector table, for example
+created with `cargo objcopy --release -- -O binary firmware.bin`. The image is modified in place.
+
+Usage:
+Pass path to the binary image and, optionally, build ID (for example git commit hash) as
+arguments to this script. Build ID longer than 20 bytes is truncated.
+"""
+
+from __future__ import annotations
+
+import struct
+import sys
+import zlib
+from pathlib import Path
+
+MAGIC = b"AERUGOFW"
+"""Magic value identifying the metadata in the image."""
+BUILD_ID_OFFSET = 24
+"""Offset of the build ID from the start of the metadata."""
+BUILD_ID_SIZE = 20
+"""Maximum length of the build ID."""
+IMAGE_SIZE_OFFSET = 44
+"""Offset of the image size from the start of the metadata."""
+CRC_OFFSET = 48
+"""Offset of the image CRC from the start of the metadata."""
+VERSION_OFFSET = 8
+"""Offset of the version from the start of the metadata."""
+VERSION_SIZE = 16
+"""Maximum length of the version."""
+
+
+def get_args() -> tuple[Path, str]:
+    """Parses and returns script's arguments, exits the program with non-zero exit code
+    if arguments are missing."""
+    if len(sys.argv) not in (2, 3):
+        print("Firmware image sealer")
+        print("Writes build ID, size and CRC into the firmware image metadata")
+        print(f"Usage: {sys.argv[0]} firmware.bin [build_id]")
+        sys.exit(1)
+
+    build_id = sys.argv[2] if len(sys.argv) == 3 else ""  # noqa: PLR2004 (self-explanatory)
+    return Path(sys.argv[1]), build_id
+
+
+def is_padded_string(data: bytes) -> bool:
+    """Checks if data is a valid UTF-8 string padded with zeros."""
+    text, _, padding = data.partition(b"\0")
+    try:
+        text.decode()
+    except UnicodeDecodeError:
+        return False
+    return not padding.strip(b"\0")
+
+
+def find_metadata(image: bytearray) -> int:
+    """Returns offset of the metadata in the image. Magic value can also be present in the code,
+    as a constant metadata is compared with, so the rest of the metadata is checked too."""
+    candidates = []
+    offset = image.find(MAGIC)
+    while offset >= 0:
+        version = image[offset + VERSION_OFFSET : offset + VERSION_OFFSET + VERSION_SIZE]
+        build_id = image[offset + BUILD_ID_OFFSET : offset + BUILD_ID_OFFSET + BUILD_ID_SIZE]
+        (image_size,) = struct.unpack_from("<I", image, offset + IMAGE_SIZE_OFFSET)
+        if (
+            offset % 4 == 0
+            and is_padded_string(version)
+            and is_padded_string(build_id)
+            and image_size in (0, len(image))
+        ):
+            candidates.append(offset)
+        offset = image.find(MAGIC, offset + 1)
+
+    if len(candidates) != 1:
+        print(f"Expected one firmware metadata in the image, found {len(candidates)}")
+        sys.exit(1)
+    return candidates[0]
+
+
+def seal_image(image: bytearray, build_id: str) -> int:
+    """Writes build ID, size and CRC into the metadata in the image, and returns the CRC.
+    CRC is calculated over the whole image, excluding the CRC field."""
+    offset = find_metadata(image)
+
+    build_id_bytes = build_id.encode()[:BUILD_ID_SIZE].ljust(BUILD_ID_SIZE, b"\0")
+    image[offset + BUILD_ID_OFFSET : offset + BUILD_ID_OFFSET + BUILD_ID_SIZE] = build_id_bytes
+    struct.pack_into("<I", image, offset + IMAGE_SIZE_OFFSET, len(image))
+
+    crc_offset = offset + CRC_OFFSET
+    crc = zlib.crc32(image[crc_offset + 4 :], zlib.crc32(image[:crc_offset]))
+    struct.pack_into("<I", image, crc_offset, crc)
+    return crc
+
+
+def main() -> None:
+    """Main function of this script."""
+    path, build_id = get_args()
+    image = bytearray(path.read_bytes())
+    crc = seal_image(image, build_id)
+    path.write_bytes(image)
+    print(f"Sealed {path}: {len(image)} bytes, CRC 0x{crc:08X}")
+
+
+if __name__ == "__main__":
+    main()
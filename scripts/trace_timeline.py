@@ -0,0 +1,157 @@
+"""Script that generates tasklet execution timeline from the scheduler trace.
+
+Scheduler trace is emitted by Aerugo built with `instrumented` feature, over the RTT channel
+named "Trace". Each trace record describes a single tasklet execution.
+
+Usage:
+Pass the source of the trace and path to the output HTML file as arguments to this script.
+Source can be either a file with raw trace data captured from the trace channel, or hostname and
+port (in `hostname:port` format, for example 192.168.1.1:1235) of the RTT server serving the trace
+channel. In the latter case, trace is collected until the script is interrupted with Ctrl+C.
+"""
+
+from __future__ import annotations
+
+import html
+import struct
+import sys
+from dataclasses import dataclass
+from pathlib import Path
+
+from calldwell.rtt_client import RTTClient
+
+TRACE_RECORD_FORMAT = "<IQQQ"
+"""Format of a single trace record: tasklet ID, enqueue time, start time, end time."""
+TRACE_RECORD_SIZE = struct.calcsize(TRACE_RECORD_FORMAT)
+"""Size of a single trace record in bytes."""
+PIXELS_PER_MICROSECOND = 0.05
+"""Horizontal scale of the timeline."""
+ROW_HEIGHT = 24
+"""Height of a single tasklet row in pixels."""
+
+
+@dataclass
+class TaskletExecution:
+    """Single tasklet execution, with all times in microseconds."""
+
+    tasklet_id: int
+    enqueue_time: int
+    start_time: int
+    end_time: int
+
+
+def get_args() -> tuple[str, Path]:
+    """Parses and returns script's arguments, exits the program with non-zero exit code
+    if arguments are missing."""
+    if len(sys.argv) != 3:  # noqa: PLR2004 (magic number self-explanatory)
+        print("Scheduler trace timeline generator")
+        print("Generates HTML timeline of tasklet executions from the scheduler trace")
+        print(f"Usage: {sys.argv[0]} (trace_file | hostname:port) output.html")
+        sys.exit(1)
+
+    return sys.argv[1], Path(sys.argv[2])
+
+
+def collect_trace(source: str) -> bytes:
+    """Returns raw trace data, read from file or received from RTT server."""
+    source_path = Path(source)
+    if source_path.is_file():
+        return source_path.read_bytes()
+
+    host, port = source.rsplit(":", 1)
+    rtt = RTTClient(host, int(port))
+    data = b""
+
+    print("Collecting trace, press Ctrl+C to stop...")
+    while True:
+        try:
+            data += rtt.receive()
+        except KeyboardInterrupt:  # noqa: PERF203 (we don't care about performance here)
+            rtt.close()
+            return data
+
+
+def parse_trace(data: bytes) -> list[TaskletExecution]:
+    """Parses raw trace data. Incomplete record at the end of the data is ignored."""
+    complete_size = len(data) - len(data) % TRACE_RECORD_SIZE
+    return [
+        TaskletExecution(*record)
+        for record in struct.iter_unpack(TRACE_RECORD_FORMAT, data[:complete_size])
+    ]
+
+
+def render_timeline(executions: list[TaskletExecution]) -> str:
+    """Renders executions as a Gantt-style HTML timeline, with one row per tasklet.
+    Time spent waiting in the execution queue is drawn in light color, and the execution itself
+    in dark color."""
+    if not executions:
+        return "<html><body><p>Trace is empty.</p></body></html>"
+
+    origin = min(execution.enqueue_time for execution in executions)
+    end = max(execution.end_time for execution in executions)
+    tasklet_ids = sorted({execution.tasklet_id for execution in executions})
+    rows = {tasklet_id: row for row, tasklet_id in enumerate(tasklet_ids)}
+
+    def x(time: int) -> float:
+        return (time - origin) * PIXELS_PER_MICROSECOND
+
+    bars = []
+    for execution in executions:
+        top = rows[execution.tasklet_id] * ROW_HEIGHT
+        title = html.escape(
+            f"tasklet {execution.tasklet_id}: enqueued {execution.enqueue_time} us, "
+            f"started {execution.start_time} us, ended {execution.end_time} us, "
+            f"waited {execution.start_time - execution.enqueue_time} us, "
+            f"ran {execution.end_time - execution.start_time} us",
+        )
+        bars.append(
+            f'<div class="wait" title="{title}" style="top:{top}px;'
+            f'left:{x(execution.enqueue_time)}px;'
+            f'width:{x(execution.start_time) - x(execution.enqueue_time)}px"></div>',
+        )
+        bars.append(
+            f'<div class="run" title="{title}" style="top:{top}px;'
+            f'left:{x(execution.start_time)}px;'
+            f'width:{max(x(execution.end_time) - x(execution.start_time), 1)}px"></div>',
+        )
+
+    labels = "".join(
+        f'<div class="label" style="top:{row * ROW_HEIGHT}px">tasklet {tasklet_id}</div>'
+        for tasklet_id, row in rows.items()
+    )
+
+    return f"""<html>
+<head>
+<title>Aerugo scheduler timeline</title>
+<style>
+body {{ font-family: sans-serif; }}
+.labels, .timeline {{ position: relative; display: inline-block; vertical-align: top;
+    height: {len(rows) * ROW_HEIGHT}px; }}
+.labels {{ width: 100px; }}
+.timeline {{ width: {x(end) + 1}px; }}
+.label {{ position: absolute; height: {ROW_HEIGHT}px; line-height: {ROW_HEIGHT}px; }}
+.wait, .run {{ position: absolute; height: {ROW_HEIGHT - 4}px; margin-top: 2px; }}
+.wait {{ background: #c6dbef; }}
+.run {{ background: #08519c; }}
+</style>
+</head>
+<body>
+<p>{len(executions)} executions, {end - origin} us, scale: {PIXELS_PER_MICROSECOND} px/us</p>
+<div style="overflow-x: scroll; white-space: nowrap">
+<div class="labels">{labels}</div><div class="timeline">{"".join(bars)}</div>
+</div>
+</body>
+</html>
+"""
+
+
+def main() -> None:
+    """Main function of this script."""
+    source, output = get_args()
+    executions = parse_trace(collect_trace(source))
+    output.write_text(render_timeline(executions))
+    print(f"Timeline of {len(executions)} executions written to {output}")
+
+
+if __name__ == "__main__":
+    main()
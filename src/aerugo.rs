@@ -5,30 +5,82 @@
 //!
 //! This module also contains singleton instances of all system parts.
 
-use aerugo_hal::{AerugoHal, SystemHardwareConfig};
+use core::cell::OnceCell;
+use core::fmt;
+use core::ops::{Add, Sub};
+
+use aerugo_hal::{AerugoHal, ExternalWatchdog, SystemHardwareConfig};
 use critical_section::CriticalSection;
 use env_parser::read_env;
 
+use crate::aggregator::{
+    aggregator_step, Aggregate, Aggregator, AggregatorConfig, AggregatorStorage,
+};
 use crate::api::{InitApi, RuntimeApi};
+#[cfg(feature = "instrumented")]
+use crate::arch::init_instrumentation;
 #[cfg(feature = "log")]
 use crate::arch::init_log;
+use crate::barrier::{BarrierHandle, BarrierStorage};
 use crate::boolean_condition::{
     BooleanConditionHandle, BooleanConditionSet, BooleanConditionStorage,
 };
+use crate::bus_manager::{
+    bus_step, BusDriver, BusManager, BusManagerConfig, BusManagerStorage, BusTransaction,
+};
+use crate::cancellation_token::CancellationToken;
+use crate::configuration_seal::{configuration_crc, ConfigurationSeal};
 use crate::cyclic_execution_manager::CyclicExecutionManager;
+use crate::data_provider::{DataProvider, DataReceiver};
+use crate::equal_priority_policy::EqualPriorityPolicy;
 use crate::error::{RuntimeError, SystemError};
-use crate::event::{EventHandle, EventId, EventStorage};
+use crate::event::{
+    DataEventHandle, DataEventStorage, EventGroup, EventHandle, EventId, EventStorage,
+};
 use crate::event_manager::EventManager;
-use crate::execution_monitor::{ExecutionMonitor, ExecutionStats};
-use crate::executor::Executor;
+use crate::execution_monitor::{DeadlineMissHandler, ExecutionMonitor, ExecutionStats};
+use crate::executor::{ErrorHook, Executor, IdleHook};
+use crate::fault::FaultRecord;
+use crate::fault_manager::FaultManager;
+use crate::fdir::{fdir_step, FdirConfig, FdirEngine, FdirStorage};
+use crate::firmware_info::FirmwareInfo;
 use crate::hal::{Hal, UserPeripherals};
+use crate::health_monitor::HealthMonitor;
+use crate::heartbeat::Heartbeat;
+use crate::idle_strategy::{self, IdleStrategy};
 use crate::internal_list::InternalList;
-use crate::message_queue::{MessageQueueHandle, MessageQueueStorage};
+use crate::limit_monitor::{
+    limit_monitor_step, LimitMonitor, LimitMonitorConfig, LimitMonitorStorage,
+};
+use crate::manifest::{EventManifest, ManifestWriter};
+use crate::message_queue::{MessageQueueHandle, MessageQueueStorage, OverflowPolicy};
+use crate::missed_activation_policy::MissedActivationPolicy;
+use crate::mutex::Mutex;
+use crate::partition::{PartitionId, PartitionWindow};
+use crate::partition_manager::PartitionManager;
+use crate::queue_bridge::{bridge_step, QueueBridge, QueueBridgeConfig, QueueBridgeStorage};
+use crate::queue_growth_monitor::QueueGrowthMonitor;
+use crate::schedule_table::{ScheduleSlot, ScheduleTableConfig};
+use crate::schedule_table_manager::ScheduleTableManager;
+use crate::scheduler_watchdog::{SchedulerWatchdog, StallAction};
+use crate::shutdown_reason::ShutdownReason;
+use crate::software_watchdog::{SoftwareWatchdogConfig, SoftwareWatchdogStorage};
+use crate::software_watchdog_manager::SoftwareWatchdogManager;
+use crate::startup::{ComponentStatus, StartupConfig, StartupInfo, StartupReport};
+use crate::startup_manager::StartupManager;
+use crate::system_state::EventState;
 use crate::tasklet::{
-    StepFn, Tasklet, TaskletConfig, TaskletHandle, TaskletId, TaskletPtr, TaskletStorage,
+    IdentifiableTasklet, RefStepFn, ResumableStepFn, SpawnableTasklet, StepFn, SuspendableTasklet,
+    Tasklet, TaskletChain, TaskletConfig, TaskletDataSource, TaskletHandle, TaskletId, TaskletPtr,
+    TaskletSpawner, TaskletStep, TaskletStorage,
 };
+use crate::tasklet_containment::TaskletContainment;
+use crate::telemetry::{ContextTelemetry, Telemetry, TelemetrySample, TelemetryStorage};
+use crate::tie_breaker::TieBreaker;
 use crate::time::{Duration, Instant};
 use crate::time_source::TimeSource;
+use crate::timer::{TimerAction, TimerStorage};
+use crate::timer_manager::TimerManager;
 
 /// Core system.
 ///
@@ -38,7 +90,7 @@ static AERUGO: Aerugo = Aerugo::new();
 /// System scheduler.
 ///
 /// Singleton instance of the scheduler. Used directly only by the [Aerugo] structure.
-static EXECUTOR: Executor = Executor::new(AERUGO.time_source());
+static EXECUTOR: Executor = Executor::new(AERUGO.time_source(), &PARTITION_MANAGER, &TIE_BREAKER);
 /// Event manager.
 ///
 /// Singleton instance of the event manager. Used directly only by the [Aerugo] structure.
@@ -48,10 +100,59 @@ static EVENT_MANAGER: EventManager = EventManager::new(AERUGO.time_source());
 /// Singleton instance of the time manager. Used directly only by the [Aerugo] structure.
 static CYCLIC_EXECUTION_MANAGER: CyclicExecutionManager =
     CyclicExecutionManager::new(AERUGO.time_source());
+/// Timer manager.
+///
+/// Singleton instance of the timer manager. Used directly only by the [Aerugo] structure.
+static TIMER_MANAGER: TimerManager = TimerManager::new();
 /// Execution monitor.
 ///
 /// Singleton instance of the execution monitor. Used directly only by the [Aerugo] structure.
 static EXECUTION_MONITOR: ExecutionMonitor = ExecutionMonitor::new();
+/// Partition manager.
+///
+/// Singleton instance of the partition manager. Used directly only by the [Aerugo] structure.
+static PARTITION_MANAGER: PartitionManager = PartitionManager::new(AERUGO.time_source());
+/// Schedule table manager.
+///
+/// Singleton instance of the schedule table manager. Used directly only by the [Aerugo] structure.
+static SCHEDULE_TABLE_MANAGER: ScheduleTableManager =
+    ScheduleTableManager::new(AERUGO.time_source());
+/// Fault manager.
+///
+/// Singleton instance of the fault manager. Used directly only by the [Aerugo] structure.
+static FAULT_MANAGER: FaultManager = FaultManager::new();
+/// System heartbeat.
+///
+/// Singleton instance of the heartbeat. Used directly only by the [Aerugo] structure.
+static HEARTBEAT: Heartbeat = Heartbeat::new();
+/// Software watchdog manager.
+///
+/// Singleton instance of the software watchdog manager. Used directly only by the [Aerugo] structure.
+static SOFTWARE_WATCHDOG_MANAGER: SoftwareWatchdogManager = SoftwareWatchdogManager::new();
+/// Health monitor.
+///
+/// Singleton instance of the health monitor. Used directly only by the [Aerugo] structure.
+static HEALTH_MONITOR: HealthMonitor = HealthMonitor::new();
+/// Queue growth monitor.
+///
+/// Singleton instance of the queue growth monitor. Used directly only by the [Aerugo] structure.
+static QUEUE_GROWTH_MONITOR: QueueGrowthMonitor = QueueGrowthMonitor::new();
+/// Scheduler watchdog.
+///
+/// Singleton instance of the scheduler watchdog. Used directly only by the [Aerugo] structure.
+static SCHEDULER_WATCHDOG: SchedulerWatchdog = SchedulerWatchdog::new();
+/// Tasklet containment.
+///
+/// Singleton instance of the tasklet containment. Used directly only by the [Aerugo] structure.
+static TASKLET_CONTAINMENT: TaskletContainment = TaskletContainment::new();
+/// Startup manager.
+///
+/// Singleton instance of the startup manager. Used directly only by the [Aerugo] structure.
+static STARTUP_MANAGER: StartupManager = StartupManager::new();
+/// Scheduling tie breaker.
+///
+/// Singleton instance of the tie breaker. Used directly only by the [Aerugo] and [Executor] structures.
+static TIE_BREAKER: TieBreaker = TieBreaker::new();
 
 /// System structure.
 ///
@@ -63,6 +164,19 @@ pub struct Aerugo {
     tasklet_ids: InternalList<TaskletId, { Aerugo::TASKLET_COUNT }>,
     /// Time source, responsible for creating timestamps.
     time_source: TimeSource,
+    /// Seal of the system configuration, created when the system is started.
+    configuration_seal: ConfigurationSeal,
+    /// External watchdogs fed by the system.
+    external_watchdogs:
+        InternalList<Mutex<&'static mut dyn ExternalWatchdog>, { Aerugo::EXTERNAL_WATCHDOG_COUNT }>,
+    /// Firmware image metadata, with the result of the image verification.
+    firmware_info: OnceCell<Result<FirmwareInfo, RuntimeError>>,
+    /// Number of places in the tasklet list reserved for tasklets spawned at runtime.
+    reserved_tasklet_count: Mutex<usize>,
+    /// Behavior of the system when there is nothing to execute.
+    idle_strategy: Mutex<IdleStrategy>,
+    /// Reason of the requested controlled shutdown, `None` if shutdown wasn't requested.
+    shutdown_requested: Mutex<Option<ShutdownReason>>,
 }
 
 /// This structure stores a list of tasklets that were created in a system. Adding new elements to
@@ -72,9 +186,39 @@ unsafe impl Sync for Aerugo {}
 
 impl Aerugo {
     /// Maximum number of tasklets registered in the system.
+    ///
+    /// Count is set with `AERUGO_TASKLET_COUNT` environmental variable. If it's not set, count is
+    /// selected by the largest enabled `tasklet-count-*` feature.
+    #[cfg(not(any(
+        feature = "tasklet-count-8",
+        feature = "tasklet-count-32",
+        feature = "tasklet-count-128"
+    )))]
     #[read_env("AERUGO_TASKLET_COUNT")]
     pub(crate) const TASKLET_COUNT: usize = 0;
 
+    /// Maximum number of tasklets registered in the system.
+    #[cfg(all(
+        feature = "tasklet-count-8",
+        not(any(feature = "tasklet-count-32", feature = "tasklet-count-128"))
+    ))]
+    #[read_env("AERUGO_TASKLET_COUNT")]
+    pub(crate) const TASKLET_COUNT: usize = 8;
+
+    /// Maximum number of tasklets registered in the system.
+    #[cfg(all(feature = "tasklet-count-32", not(feature = "tasklet-count-128")))]
+    #[read_env("AERUGO_TASKLET_COUNT")]
+    pub(crate) const TASKLET_COUNT: usize = 32;
+
+    /// Maximum number of tasklets registered in the system.
+    #[cfg(feature = "tasklet-count-128")]
+    #[read_env("AERUGO_TASKLET_COUNT")]
+    pub(crate) const TASKLET_COUNT: usize = 128;
+
+    /// Maximum number of external watchdogs registered in the system.
+    #[read_env("AERUGO_EXTERNAL_WATCHDOG_COUNT")]
+    pub(crate) const EXTERNAL_WATCHDOG_COUNT: usize = 0;
+
     /// Creates new system instance.
     ///
     /// # Safety
@@ -84,22 +228,119 @@ impl Aerugo {
             tasklets: InternalList::new(),
             tasklet_ids: InternalList::new(),
             time_source: TimeSource::new(),
+            configuration_seal: ConfigurationSeal::new(),
+            external_watchdogs: InternalList::new(),
+            firmware_info: OnceCell::new(),
+            reserved_tasklet_count: Mutex::new(0),
+            idle_strategy: Mutex::new(IdleStrategy::BusyWait),
+            shutdown_requested: Mutex::new(None),
         }
     }
 
     /// Initialize the system runtime and hardware.
     pub fn initialize(config: SystemHardwareConfig) -> (&'static impl InitApi, UserPeripherals) {
+        Aerugo::initialize_with_startup(config, StartupConfig::default())
+    }
+
+    /// Initialize the system runtime and hardware, and execute application's initialization path.
+    ///
+    /// After the hardware is configured, system waits for the configured startup delay, and then
+    /// executes warm-start path if the system was reset by itself (see
+    /// [ResetCause::is_warm](crate::ResetCause::is_warm)) and the path is given, or cold-start path
+    /// otherwise. Watchdog is fed during the delay, and before and after the initialization path.
+    /// Path that takes longer than its budget stores a fault in the fault recorder. Information
+    /// about the startup can be read with
+    /// [get_startup_info](crate::api::RuntimeApi::get_startup_info).
+    ///
+    /// # Parameters
+    /// * `config` - System hardware configuration.
+    /// * `startup` - Startup configuration.
+    ///
+    /// # Example
+    /// ```
+    /// # use aerugo::{Aerugo, StartupConfig, StartupPath, SystemHardwareConfig};
+    /// # use aerugo::hal::UserPeripherals;
+    /// # use aerugo::Duration;
+    /// #
+    /// fn cold_start(_: &mut UserPeripherals) {
+    ///     // Power up and calibrate external sensors.
+    /// }
+    ///
+    /// fn warm_start(_: &mut UserPeripherals) {
+    ///     // Sensors kept their configuration, only MCU peripherals are configured.
+    /// }
+    ///
+    /// fn main() {
+    ///     let startup = StartupConfig {
+    ///         delay: Duration::millis(10),
+    ///         cold_start: Some(StartupPath {
+    ///             init: cold_start,
+    ///             budget: Duration::millis(2000),
+    ///             fault_code: 0x20,
+    ///         }),
+    ///         warm_start: Some(StartupPath {
+    ///             init: warm_start,
+    ///             budget: Duration::millis(100),
+    ///             fault_code: 0x21,
+    ///         }),
+    ///         ..Default::default()
+    ///     };
+    ///
+    ///     let (aerugo, peripherals) =
+    ///         Aerugo::initialize_with_startup(SystemHardwareConfig::default(), startup);
+    /// }
+    /// ```
+    pub fn initialize_with_startup(
+        config: SystemHardwareConfig,
+        startup: StartupConfig,
+    ) -> (&'static impl InitApi, UserPeripherals) {
+        #[cfg(feature = "instrumented")]
+        init_instrumentation();
         #[cfg(feature = "log")]
         init_log();
 
+        let heartbeat_period = config
+            .heartbeat
+            .as_ref()
+            .map(|heartbeat| Duration::millis(heartbeat.period.to_millis() as u64));
+        HEARTBEAT.set_period(heartbeat_period);
+
         Hal::configure_hardware(config)
             .expect("HAL initialization or hardware configuration failed");
-        let user_peripherals =
+        let mut user_peripherals =
             Hal::create_user_peripherals().expect("Cannot create user peripherals instance");
+        STARTUP_MANAGER.record_step("HardwareConfiguration");
+
+        STARTUP_MANAGER.execute(&startup, &mut user_peripherals);
 
         (&AERUGO, user_peripherals)
     }
 
+    /// Reports a fault detected by the application.
+    ///
+    /// Fault is stored in the fault recorder and handled according to the fault policy (see
+    /// [`FaultPolicy`](crate::FaultPolicy)). In debug builds this function panics.
+    ///
+    /// This is usually called by [`kernel_assert`](crate::kernel_assert) and
+    /// [`require`](crate::require) macros.
+    ///
+    /// # Parameters
+    /// * `fault` - Detected fault.
+    pub fn report_fault(fault: FaultRecord) {
+        FAULT_MANAGER.report(fault);
+    }
+
+    /// Stores a fault detected by a system component in the fault recorder.
+    ///
+    /// Unlike [report_fault](Aerugo::report_fault), fault is not latched and is not handled
+    /// according to the fault policy.
+    ///
+    /// # Parameters
+    /// * `fault` - Detected fault.
+    pub(crate) fn store_fault(fault: FaultRecord) {
+        FAULT_MANAGER.store(fault);
+    }
+
     /// Returns reference to the system time source.
     pub(crate) const fn time_source(&'static self) -> &'static TimeSource {
         &self.time_source
@@ -107,12 +348,133 @@ impl Aerugo {
 
     /// Wakes given tasklet by scheduling it for execution.
     ///
+    /// With `wake-latency` feature, time of the wake from an interrupt handler is recorded, to
+    /// measure latency of the tasklet execution.
+    ///
     /// # Parameters
     /// * `tasklet` - Tasklet to wake
     pub(crate) fn wake_tasklet(tasklet: &TaskletPtr) {
-        EXECUTOR.schedule_tasklet(tasklet).unwrap_or_else(|err| {
-            panic!("Failed to wake tasklet '{}': {:?}", tasklet.get_name(), err)
-        });
+        if let Err(err) = Aerugo::try_wake_tasklet(tasklet) {
+            if !EXECUTOR.report_error(err) {
+                panic!("Failed to wake tasklet '{}': {:?}", tasklet.get_name(), err)
+            }
+        }
+    }
+
+    /// Wakes given tasklet by scheduling it for execution, returning error instead of reporting it.
+    ///
+    /// # Parameters
+    /// * `tasklet` - Tasklet to wake
+    ///
+    /// # Return
+    /// `()` if successful, `SystemError` if the tasklet couldn't be scheduled.
+    pub(crate) fn try_wake_tasklet(tasklet: &TaskletPtr) -> Result<(), SystemError> {
+        #[cfg(feature = "wake-latency")]
+        if Hal::in_interrupt() && tasklet.is_active() {
+            tasklet.set_interrupt_wake_time(AERUGO.time_source().system_time());
+        }
+
+        EXECUTOR.schedule_tasklet(tasklet).map(|_| ())
+    }
+
+    /// Spawns tasklet reserved during system initialization.
+    ///
+    /// Tasklet is created in the given storage, added to the tasklet list in the place reserved for
+    /// it, and subscribed to its data provider. Configuration is sealed again after modification.
+    /// If spawning fails, tasklet reservation and configuration seal are left unchanged.
+    ///
+    /// # Parameters
+    /// * `config` - Tasklet creation configuration.
+    /// * `step_fn` - Tasklet step function.
+    /// * `provider` - Data provider the tasklet is subscribed to.
+    /// * `storage` - Static memory storage where the tasklet should be allocated.
+    ///
+    /// # Return
+    /// Receiver of the data provider if successful, `RuntimeError` otherwise.
+    pub(crate) fn spawn_reserved_tasklet<T, C: Default, const COND_COUNT: usize>(
+        config: TaskletConfig,
+        step_fn: StepFn<T, C>,
+        provider: &'static (dyn DataProvider<T> + Sync),
+        storage: &'static TaskletStorage<T, C, COND_COUNT>,
+    ) -> Result<DataReceiver, RuntimeError> {
+        critical_section::with(|_| {
+            if AERUGO.reserved_tasklet_count.lock(|count| *count) == 0 {
+                return Err(RuntimeError::TaskletNotReserved);
+            }
+
+            if storage.is_initialized() {
+                return Err(RuntimeError::TaskletAlreadySpawned);
+            }
+
+            // Configuration is verified before the modification, so corrupted configuration is
+            // never sealed again.
+            let previous_crc = AERUGO.configuration_crc();
+            AERUGO
+                .configuration_seal
+                .verify(previous_crc)
+                .map_err(|_| RuntimeError::ConfigurationCorrupted)?;
+
+            // SAFETY: This is safe, because tasklets are spawned only from the tasklet context, when
+            // the system lists are not accessed by the scheduler, and the modification can't be
+            // interrupted.
+            unsafe {
+                let tasklet = storage
+                    .init(config, TaskletStep::ByValue(step_fn), C::default(), &AERUGO)
+                    .map_err(|_| RuntimeError::TaskletAlreadySpawned)?;
+                tasklet
+                    .subscribe(provider)
+                    .map_err(|_| RuntimeError::TaskletAlreadySubscribed)?;
+
+                AERUGO.reserved_tasklet_count.lock(|count| *count -= 1);
+                if AERUGO.add_tasklet(tasklet).is_err() {
+                    AERUGO.reserved_tasklet_count.lock(|count| *count += 1);
+                    return Err(RuntimeError::TaskletListFull);
+                }
+
+                AERUGO
+                    .configuration_seal
+                    .reseal(previous_crc, AERUGO.configuration_crc())
+                    .map_err(|_| RuntimeError::ConfigurationCorrupted)?;
+
+                if tasklet.has_work() {
+                    Aerugo::wake_tasklet(&tasklet.ptr());
+                }
+
+                Ok(DataReceiver::new(tasklet.ptr()))
+            }
+        })
+    }
+
+    /// Returns currently executed tasklet.
+    ///
+    /// # Return
+    /// Tasklet, or `None` if no tasklet is executed.
+    pub(crate) fn current_tasklet() -> Option<TaskletPtr> {
+        EXECUTOR.current_tasklet()
+    }
+
+    /// Sets priority inherited by the given tasklet, and reorders the execution queue accordingly.
+    ///
+    /// # Parameters
+    /// * `tasklet` - Tasklet to set priority of.
+    /// * `priority` - Inherited priority, or `None` to restore the configured priority.
+    pub(crate) fn set_inherited_priority(tasklet: &TaskletPtr, priority: Option<u8>) {
+        tasklet.set_inherited_priority(priority);
+        EXECUTOR.reorder_queue();
+    }
+
+    /// Returns partition of the currently executed tasklet.
+    ///
+    /// # Return
+    /// Partition ID, or `None` if no tasklet is executed or executed tasklet is not assigned to any
+    /// partition.
+    pub(crate) fn current_partition() -> Option<PartitionId> {
+        PARTITION_MANAGER.current_partition()
+    }
+
+    /// Returns current system time.
+    pub(crate) fn system_time() -> Instant {
+        AERUGO.time_source.system_time()
     }
 
     /// Runs the system.
@@ -121,19 +483,136 @@ impl Aerugo {
     /// its internal components and hardware.
     fn run(&'static self) -> ! {
         loop {
-            let execution_data = EXECUTOR
-                .execute_next_tasklet()
-                .expect("Failure in tasklet execution");
+            SCHEDULER_WATCHDOG.progress();
+
+            let iteration_start = self.time_source.system_time();
+
+            let execution_data = match EXECUTOR.execute_next_tasklet() {
+                Ok(execution_data) => execution_data,
+                Err(err) if EXECUTOR.report_error(err) => None,
+                Err(err) => panic!("Failure in tasklet execution: {:?}", err),
+            };
+            let busy = execution_data
+                .as_ref()
+                .is_some_and(|data| data.was_executed());
 
             if let Some(data) = execution_data {
+                PARTITION_MANAGER.update(&data);
+                SCHEDULE_TABLE_MANAGER.update(&data);
+                SOFTWARE_WATCHDOG_MANAGER.update(&data, self.time_source.elapsed_time());
+
                 // SAFETY: This is safe, as `EXECUTION_MONITOR` is not available from the IRQ context.
                 unsafe { EXECUTION_MONITOR.update(data) };
             }
 
+            if let Some(reason) = self.shutdown_requested.lock(|requested| *requested) {
+                self.shutdown_system(reason);
+            }
+
             EVENT_MANAGER.activate_scheduled_events();
             CYCLIC_EXECUTION_MANAGER.wake_tasklets();
+            SCHEDULE_TABLE_MANAGER.wake_tasklets();
+            TIMER_MANAGER.fire_timers(self);
+            QUEUE_GROWTH_MONITOR.check(self.time_source.elapsed_time());
+
+            if self
+                .configuration_seal
+                .is_check_due(self.time_source.elapsed_time())
+            {
+                if let Err(err) = self.verify_configuration() {
+                    if !EXECUTOR.report_error(err) {
+                        panic!("System configuration corrupted: {:?}", err);
+                    }
+                }
+            }
+
+            self.feed_watchdogs();
+
+            let is_healthy = FAULT_MANAGER.latched_fault().is_none();
+            if HEARTBEAT.update(self.time_source.elapsed_time(), is_healthy) {
+                Hal::toggle_heartbeat();
+            }
+
+            if !busy {
+                EXECUTOR.run_idle_hook(self);
+                self.idle();
+            }
+
+            let iteration_end = self.time_source.system_time();
+            // SAFETY: This is safe, as `EXECUTION_MONITOR` is not available from the IRQ context.
+            unsafe { EXECUTION_MONITOR.update_cpu_load(iteration_start, iteration_end, busy) };
+        }
+    }
+
+    /// Calls finalizers of all tasklets, stores the shutdown reason and resets the system.
+    ///
+    /// # Parameters
+    /// * `reason` - Reason of the shutdown.
+    fn shutdown_system(&'static self, reason: ShutdownReason) -> ! {
+        crate::logln!("Aerugo: shutting down the system, reason: {:?}", reason);
+
+        for tasklet in &self.tasklets {
+            self.feed_watchdogs();
+            tasklet.finalize();
+        }
+
+        Hal::store_shutdown_reason(reason.code());
+        Hal::reset_system();
+    }
+
+    /// Performs the checks of the system timer interrupt.
+    fn on_monitor_timer() {
+        SCHEDULER_WATCHDOG.supervise(&EXECUTOR, &FAULT_MANAGER);
+        TASKLET_CONTAINMENT.contain_overrun(&EXECUTOR, &FAULT_MANAGER);
+    }
+
+    /// Puts the CPU to sleep until the nearest system activity, if the idle strategy allows it and
+    /// there are no tasklets waiting for execution.
+    fn idle(&'static self) {
+        let max_sleep = match self.idle_strategy.lock(|strategy| *strategy) {
+            IdleStrategy::BusyWait => return,
+            IdleStrategy::Sleep { max_sleep } => max_sleep,
+        };
+
+        let sleep_time = idle_strategy::sleep_time(
+            max_sleep,
+            [
+                CYCLIC_EXECUTION_MANAGER.time_to_next_execution(),
+                SCHEDULE_TABLE_MANAGER.time_to_next_slot(),
+                EVENT_MANAGER.time_to_next_event(),
+                TIMER_MANAGER.time_to_next_expiry(),
+                HEARTBEAT.time_to_toggle(self.time_source.elapsed_time()),
+            ],
+        );
+        let wakeup_time = Hal::get_system_time() + sleep_time;
+
+        // Queue is checked with interrupts disabled, so a tasklet woken from interrupt after the
+        // check wakes the CPU up instead of waiting for the wakeup time.
+        critical_section::with(|_| {
+            if EXECUTOR.is_queue_empty() {
+                Hal::sleep_until(wakeup_time);
+                // Sleep is not a stall, and the watchdog can't be checked until interrupts are
+                // enabled again.
+                SCHEDULER_WATCHDOG.progress();
+            }
+        });
+    }
+
+    /// Feeds internal watchdog and all external watchdogs.
+    ///
+    /// Watchdogs are not fed when any of the active software watchdogs expired.
+    fn feed_watchdogs(&'static self) {
+        let time = self.time_source.elapsed_time();
+        // Both are checked, so each of them stores its faults.
+        let healthy = SOFTWARE_WATCHDOG_MANAGER.check(time) & HEALTH_MONITOR.check(time);
+        if !healthy {
+            return;
+        }
 
-            Hal::feed_watchdog();
+        Hal::feed_watchdog();
+
+        for watchdog in self.external_watchdogs.iter() {
+            watchdog.lock(|watchdog| watchdog.feed());
         }
     }
 
@@ -152,6 +631,10 @@ impl Aerugo {
         &'static self,
         tasklet: &'static Tasklet<T, C, COND_COUNT>,
     ) -> Result<(), SystemError> {
+        if !self.has_tasklet_place() {
+            return Err(SystemError::TaskletListFull);
+        }
+
         match self.tasklets.add(tasklet.ptr()) {
             Ok(_) => (),
             Err(_) => return Err(SystemError::TaskletListFull),
@@ -165,6 +648,12 @@ impl Aerugo {
         Ok(())
     }
 
+    /// Checks if there is a place in the tasklet list that is not reserved.
+    fn has_tasklet_place(&'static self) -> bool {
+        let reserved = self.reserved_tasklet_count.lock(|count| *count);
+        Self::TASKLET_COUNT.saturating_sub(reserved) > self.tasklets.len()
+    }
+
     /// Check if system is valid and ready to start.
     fn validate(&'static self) -> Result<(), SystemError> {
         for tasklet_ptr in &self.tasklets {
@@ -174,8 +663,46 @@ impl Aerugo {
             }
         }
 
+        PARTITION_MANAGER.validate()?;
+        SCHEDULE_TABLE_MANAGER.validate()?;
+
         Ok(())
     }
+
+    /// Calculates CRC of the system configuration.
+    fn configuration_crc(&'static self) -> u32 {
+        configuration_crc(&self.tasklets, &self.tasklet_ids)
+    }
+
+    /// Seals the system configuration.
+    ///
+    /// # Safety
+    /// This is safe to call only once, when the system is started.
+    unsafe fn seal_configuration(&'static self) -> Result<(), SystemError> {
+        self.configuration_seal.seal(self.configuration_crc())
+    }
+
+    /// Verifies that the system configuration wasn't modified since it was sealed.
+    fn verify_configuration(&'static self) -> Result<(), SystemError> {
+        self.configuration_seal.verify(self.configuration_crc())
+    }
+
+    /// Verifies the system configuration before it's read by the introspection functions.
+    ///
+    /// # Return
+    /// `true` if the configuration is valid. Otherwise, error is passed to the error hook (or
+    /// logged, if the hook wasn't set) and `false` is returned.
+    fn is_configuration_intact(&'static self) -> bool {
+        match self.verify_configuration() {
+            Ok(()) => true,
+            Err(err) => {
+                if !EXECUTOR.report_error(err) {
+                    crate::logln!("Aerugo: system configuration corrupted: {:?}", err);
+                }
+                false
+            }
+        }
+    }
 }
 
 impl InitApi for Aerugo {
@@ -276,7 +803,7 @@ impl InitApi for Aerugo {
         // and can't be interrupted.
         critical_section::with(|_| unsafe {
             let tasklet = storage
-                .init(config, step_fn, C::default(), self)
+                .init(config, TaskletStep::ByValue(step_fn), C::default(), self)
                 .expect("Failed to initialize storage for tasklet");
 
             self.add_tasklet(tasklet)
@@ -389,7 +916,7 @@ impl InitApi for Aerugo {
         // and can't be interrupted.
         critical_section::with(|_| unsafe {
             let tasklet = storage
-                .init(config, step_fn, context, self)
+                .init(config, TaskletStep::ByValue(step_fn), context, self)
                 .expect("Failed to initialize storage for tasklet");
 
             self.add_tasklet(tasklet)
@@ -397,17 +924,25 @@ impl InitApi for Aerugo {
         });
     }
 
-    /// Creates new message queue in the system.
+    /// Creates new tasklet in the system, which receives the data by reference.
     ///
-    /// Queue is created in the passed `storage` memory. Storage has to be static to keep the
-    /// stored tasklet for the whole duration of system life.
+    /// Step function of this tasklet receives reference to the data, instead of the data itself.
+    /// Data providers which store the data, like message queues, pass reference to the stored
+    /// data, so it isn't copied on every step. Data is removed from the provider after the step
+    /// function returns. Other data providers pass reference to the copy of the data.
+    ///
+    /// Tasklet is created in the passed `storage` memory. Storage has to be static to keep the stored
+    /// tasklet for the whole duration of systems' life.
     ///
     /// # Generic Parameters
-    /// * `T` - Type of the data stored in the queue.
-    /// * `QUEUE_SIZE` - Size of the queue.
+    /// * `T` - Type of the data processed by the tasklet.
+    /// * `C` - Type of the structure with tasklet context data.
+    /// * `COND_COUNT` - Number of tasklet conditions.
     ///
     /// # Parameters
-    /// * `storage` - Static memory storage where the queue should be allocated.
+    /// * `config` - Tasklet creation configuration.
+    /// * `step_fn` - Tasklet step function.
+    /// * `storage` - Static memory storage where the tasklet should be allocated.
     ///
     /// # Return
     /// `()` if successful, `InitError` otherwise.
@@ -418,166 +953,282 @@ impl InitApi for Aerugo {
     ///
     /// # Example
     /// ```
-    /// # use aerugo::{Aerugo, InitApi, MessageQueueStorage, SystemHardwareConfig};
-    /// #
-    /// static QUEUE_STORAGE: MessageQueueStorage<u8, 10> = MessageQueueStorage::new();
+    /// # use aerugo::{Aerugo, InitApi, MessageQueueStorage, RuntimeApi, SystemHardwareConfig, TaskletConfig, TaskletStorage};
+    /// struct Frame([u8; 512]);
     ///
-    /// fn main() {
-    ///     let (aerugo, _) = Aerugo::initialize(SystemHardwareConfig::default());
-    ///     #
-    ///     # assert!(!QUEUE_STORAGE.is_initialized());
-    ///     #
-    ///     aerugo.create_message_queue(&QUEUE_STORAGE);
-    ///     #
-    ///     # assert!(QUEUE_STORAGE.is_initialized());
+    /// #[derive(Default)]
+    /// struct TaskCtx;
     ///
-    ///     // Do something with the queue via handle.
-    ///     let queue_handle = QUEUE_STORAGE.create_handle();
-    ///     #
-    ///     # assert!(queue_handle.is_some())
+    /// fn task(frame: &Frame, _: &mut TaskCtx, _: &dyn RuntimeApi) {
+    ///     let _checksum = frame.0.iter().fold(0u8, |sum, byte| sum.wrapping_add(*byte));
     /// }
-    /// ```
-    ///
-    /// # Notes
-    /// ## Given storage cannot be used to create more than one queue.
     ///
-    /// ```should_panic
-    /// # use aerugo::{Aerugo, InitApi, MessageQueueStorage, SystemHardwareConfig};
-    /// #
-    /// static QUEUE_STORAGE: MessageQueueStorage<u8, 5> = MessageQueueStorage::new();
+    /// static TASK_STORAGE: TaskletStorage<Frame, TaskCtx, 0> = TaskletStorage::new();
+    /// static QUEUE_STORAGE: MessageQueueStorage<Frame, 4> = MessageQueueStorage::new();
     ///
     /// fn main() {
-    ///     # let (aerugo, _) = Aerugo::initialize(SystemHardwareConfig::default());
-    ///     #
-    ///     // First call is successful.
-    ///     aerugo.create_message_queue(&QUEUE_STORAGE);
+    ///     let (aerugo, _) = Aerugo::initialize(SystemHardwareConfig::default());
     ///
-    ///     // But second is not.
+    ///     let task_config = TaskletConfig::default();
+    ///     aerugo.create_tasklet_by_reference(task_config, task, &TASK_STORAGE);
     ///     aerugo.create_message_queue(&QUEUE_STORAGE);
-    /// }
-    /// ```
-    ///
-    /// ## Storage has to be initialized to create a handle to the queue.
-    /// ```
-    /// # use aerugo::{InitApi, MessageQueueStorage};
-    /// #
-    /// static QUEUE_STORAGE: MessageQueueStorage<u8, 5> = MessageQueueStorage::new();
     ///
-    /// fn main() {
-    ///     assert!(QUEUE_STORAGE.create_handle().is_none());
+    ///     let task_handle = TASK_STORAGE.create_handle().unwrap();
+    ///     let queue_handle = QUEUE_STORAGE.create_handle().unwrap();
+    ///     aerugo.subscribe_tasklet_to_queue(&task_handle, &queue_handle);
     /// }
     /// ```
-    fn create_message_queue<T, const QUEUE_SIZE: usize>(
+    fn create_tasklet_by_reference<T, C: Default, const COND_COUNT: usize>(
         &'static self,
-        storage: &'static MessageQueueStorage<T, QUEUE_SIZE>,
+        config: TaskletConfig,
+        step_fn: RefStepFn<T, C>,
+        storage: &'static TaskletStorage<T, C, COND_COUNT>,
     ) {
         // SAFETY: This is safe because this function can be called only during system initialization
         // and can't be interrupted.
         critical_section::with(|_| unsafe {
-            storage
-                .init()
-                .expect("Failed to initialize storage for message queue");
+            let tasklet = storage
+                .init(
+                    config,
+                    TaskletStep::ByReference(step_fn),
+                    C::default(),
+                    self,
+                )
+                .expect("Failed to initialize storage for tasklet");
+
+            self.add_tasklet(tasklet)
+                .expect("Failed to add tasklet to a list");
         });
     }
 
-    /// Creates new event in the system.
+    /// Creates new tasklet in the system with initialized context data, which receives the data
+    /// by reference.
     ///
-    /// Events are created in EventManager and are identifier by the user-provided ID value. ID has
-    /// to be unique across the events in the system.
+    /// See [create_tasklet_by_reference](InitApi::create_tasklet_by_reference) for details.
+    ///
+    /// # Generic Parameters
+    /// * `T` - Type of the data processed by the tasklet.
+    /// * `C` - Type of the structure with tasklet context data.
+    /// * `COND_COUNT` - Number of tasklet conditions.
     ///
     /// # Parameters
-    /// * `event_id` - ID of the new event.
+    /// * `config` - Tasklet creation configuration.
+    /// * `step_fn` - Tasklet step function.
+    /// * `context` - Tasklet context data.
+    /// * `storage` - Static memory storage where the tasklet should be allocated.
     ///
     /// # Return
     /// `()` if successful, `InitError` otherwise.
     ///
     /// # Safety
-    /// This function shouldn't be called after the system was started, because it modifies event
-    /// list which is safe only before that.
-    ///
-    /// # Example
-    /// ```
-    /// # use aerugo::{Aerugo, EventId, EventStorage, InitApi, SystemHardwareConfig};
-    ///
-    /// enum Events {
-    ///     MyEvent,
-    /// }
-    ///
-    /// impl From<Events> for EventId {
-    ///     fn from(value: Events) -> Self {
-    ///         match value {
-    ///             Events::MyEvent => 42,
-    ///         }
-    ///     }
-    /// }
+    /// This function shouldn't be called after the system was started, because it initializes the
+    /// passed storage which is safe only before that.
+    fn create_tasklet_by_reference_with_context<T, C, const COND_COUNT: usize>(
+        &'static self,
+        config: TaskletConfig,
+        step_fn: RefStepFn<T, C>,
+        context: C,
+        storage: &'static TaskletStorage<T, C, COND_COUNT>,
+    ) {
+        // SAFETY: This is safe because this function can be called only during system initialization
+        // and can't be interrupted.
+        critical_section::with(|_| unsafe {
+            let tasklet = storage
+                .init(config, TaskletStep::ByReference(step_fn), context, self)
+                .expect("Failed to initialize storage for tasklet");
+
+            self.add_tasklet(tasklet)
+                .expect("Failed to add tasklet to a list");
+        });
+    }
+
+    /// Creates new resumable tasklet in the system.
     ///
-    /// static MY_EVENT_STORAGE: EventStorage = EventStorage::new();
+    /// Step function of this tasklet can split processing of the data into many steps, so long
+    /// computations don't block other tasklets. Step returns [`StepResult::Incomplete`](crate::StepResult::Incomplete) when
+    /// processing is not finished, usually with the [`yield_point`](crate::yield_point) macro. Tasklet
+    /// is then rescheduled, and its next step is called with `None` instead of new data, to
+    /// continue the processing with progress stored in the context. Next data is received after
+    /// the step returns [`StepResult::Complete`](crate::StepResult::Complete), and only then the successor of the tasklet is
+    /// notified.
     ///
-    /// fn main() {
-    ///     let (aerugo, _) = Aerugo::initialize(SystemHardwareConfig::default());
+    /// Tasklet is created in the passed `storage` memory. Storage has to be static to keep the stored
+    /// tasklet for the whole duration of systems' life.
     ///
-    ///     aerugo.create_event(Events::MyEvent.into(), &MY_EVENT_STORAGE);
-    /// }
+    /// # Generic Parameters
+    /// * `T` - Type of the data processed by the tasklet.
+    /// * `C` - Type of the structure with tasklet context data.
+    /// * `COND_COUNT` - Number of tasklet conditions.
+    ///
+    /// # Parameters
+    /// * `config` - Tasklet creation configuration.
+    /// * `step_fn` - Tasklet step function.
+    /// * `storage` - Static memory storage where the tasklet should be allocated.
+    ///
+    /// # Safety
+    /// This function shouldn't be called after the system was started, because it initializes the
+    /// passed storage which is safe only before that.
+    ///
+    /// # Example
     /// ```
+    /// # use aerugo::{yield_point, Aerugo, Duration, InitApi, MessageQueueStorage, RuntimeApi, StepResult, SystemHardwareConfig, TaskletConfig, TaskletStorage};
+    /// #[derive(Default)]
+    /// struct SumCtx {
+    ///     remaining: u32,
+    ///     sum: u64,
+    /// }
     ///
-    /// # Notes
-    /// ## Given storage cannot be used to create more than one event.
+    /// fn sum_up_to(limit: Option<u32>, ctx: &mut SumCtx, api: &'static dyn RuntimeApi) -> StepResult {
+    ///     if let Some(limit) = limit {
+    ///         ctx.remaining = limit;
+    ///         ctx.sum = 0;
+    ///     }
     ///
-    /// ```should_panic
-    /// # use aerugo::{Aerugo, EventStorage, InitApi, SystemHardwareConfig};
-    /// #
-    /// static EVENT_STORAGE: EventStorage = EventStorage::new();
+    ///     while ctx.remaining > 0 {
+    ///         ctx.sum += ctx.remaining as u64;
+    ///         ctx.remaining -= 1;
+    ///
+    ///         yield_point!(api);
+    ///     }
+    ///
+    ///     StepResult::Complete
+    /// }
+    ///
+    /// static SUM_STORAGE: TaskletStorage<u32, SumCtx, 0> = TaskletStorage::new();
+    /// static QUEUE_STORAGE: MessageQueueStorage<u32, 4> = MessageQueueStorage::new();
     ///
     /// fn main() {
     ///     # let (aerugo, _) = Aerugo::initialize(SystemHardwareConfig::default());
-    ///     #
-    ///     // First call is successful.
-    ///     aerugo.create_event(0, &EVENT_STORAGE);
+    ///     let sum_config = TaskletConfig {
+    ///         name: "Sum",
+    ///         yield_budget: Some(Duration::micros(500)),
+    ///         ..Default::default()
+    ///     };
+    ///     aerugo.create_resumable_tasklet(sum_config, sum_up_to, &SUM_STORAGE);
+    ///     aerugo.create_message_queue(&QUEUE_STORAGE);
     ///
-    ///     // But second is not.
-    ///     aerugo.create_event(1, &EVENT_STORAGE);
+    ///     let sum_handle = SUM_STORAGE.create_handle().unwrap();
+    ///     let queue_handle = QUEUE_STORAGE.create_handle().unwrap();
+    ///     aerugo.subscribe_tasklet_to_queue(&sum_handle, &queue_handle);
     /// }
     /// ```
+    fn create_resumable_tasklet<T, C: Default, const COND_COUNT: usize>(
+        &'static self,
+        config: TaskletConfig,
+        step_fn: ResumableStepFn<T, C>,
+        storage: &'static TaskletStorage<T, C, COND_COUNT>,
+    ) {
+        // SAFETY: This is safe because this function can be called only during system initialization
+        // and can't be interrupted.
+        critical_section::with(|_| unsafe {
+            let tasklet = storage
+                .init(config, TaskletStep::Resumable(step_fn), C::default(), self)
+                .expect("Failed to initialize storage for tasklet");
+
+            self.add_tasklet(tasklet)
+                .expect("Failed to add tasklet to a list");
+        });
+    }
+
+    /// Reserves tasklet that can be spawned after the system is started.
     ///
-    /// ## Storage has to be initialized to create a handle to the event.
+    /// Reserved tasklet takes a place in the tasklet list, but it's not created until it's
+    /// [spawned](crate::api::RuntimeApi::spawn_tasklet). When spawned, tasklet is created in the
+    /// storage passed to the `spawner`, with default context data, and subscribed to the given data
+    /// provider. Other subscriptions and tasklet conditions are not available for spawned tasklets,
+    /// as they can be created only during system initialization.
+    ///
+    /// # Generic Parameters
+    /// * `T` - Type of the data processed by the tasklet.
+    /// * `C` - Type of the structure with tasklet context data.
+    /// * `COND_COUNT` - Number of tasklet conditions.
+    ///
+    /// # Parameters
+    /// * `config` - Tasklet creation configuration.
+    /// * `step_fn` - Tasklet step function.
+    /// * `provider` - Data provider the tasklet is subscribed to when spawned.
+    /// * `spawner` - Static spawner of the tasklet.
+    ///
+    /// # Safety
+    /// This function shouldn't be called after the system was started, because it modifies the
+    /// tasklet list which is safe only before that.
+    ///
+    /// # Example
     /// ```
-    /// # use aerugo::{EventStorage, InitApi};
+    /// # use aerugo::{Aerugo, DataProvider, InitApi, Mutex, RuntimeApi, SpawnableTasklet,
+    /// #     SystemHardwareConfig, TaskletConfig, TaskletSpawner, TaskletStorage};
     /// #
-    /// static EVENT_STORAGE: EventStorage = EventStorage::new();
+    /// struct FaultReports(Mutex<Option<u32>>);
+    ///
+    /// impl DataProvider<u32> for FaultReports {
+    ///     fn get_data(&self) -> Option<u32> {
+    ///         self.0.lock(|report| report.take())
+    ///     }
+    ///
+    ///     fn data_waiting(&self) -> bool {
+    ///         self.0.lock(|report| report.is_some())
+    ///     }
+    ///
+    ///     fn flush(&self) -> usize {
+    ///         self.0.lock(|report| report.take().map_or(0, |_| 1))
+    ///     }
+    /// }
+    ///
+    /// fn diagnostics(_: u32, _: &mut (), _: &'static dyn RuntimeApi) {}
+    ///
+    /// static FAULT_REPORTS: FaultReports = FaultReports(Mutex::new(None));
+    /// static DIAGNOSTICS_STORAGE: TaskletStorage<u32, (), 0> = TaskletStorage::new();
+    /// static DIAGNOSTICS: TaskletSpawner<u32, (), 0> = TaskletSpawner::new(&DIAGNOSTICS_STORAGE);
     ///
     /// fn main() {
-    ///     assert!(EVENT_STORAGE.create_handle().is_none());
+    ///     # let (aerugo, _) = Aerugo::initialize(SystemHardwareConfig::default());
+    ///     #
+    ///     let diagnostics_config = TaskletConfig {
+    ///         name: "Diagnostics",
+    ///         ..Default::default()
+    ///     };
+    ///     aerugo.reserve_tasklet(diagnostics_config, diagnostics, &FAULT_REPORTS, &DIAGNOSTICS);
+    ///     #
+    ///     # assert!(DIAGNOSTICS.is_reserved());
+    ///     # assert!(!DIAGNOSTICS.is_spawned());
     /// }
     /// ```
-    fn create_event(&'static self, event_id: EventId, storage: &'static EventStorage) {
+    fn reserve_tasklet<T, C: Default, const COND_COUNT: usize>(
+        &'static self,
+        config: TaskletConfig,
+        step_fn: StepFn<T, C>,
+        provider: &'static (dyn DataProvider<T> + Sync),
+        spawner: &'static TaskletSpawner<T, C, COND_COUNT>,
+    ) {
         // SAFETY: This is safe because this function can be called only during system initialization
         // and can't be interrupted.
         critical_section::with(|_| unsafe {
-            storage
-                .init(event_id, &EVENT_MANAGER)
-                .expect("Failed to initialize storage for event");
-        });
+            if !self.has_tasklet_place() {
+                panic!(
+                    "Failed to reserve tasklet: {:?}",
+                    SystemError::TaskletListFull
+                );
+            }
 
-        let event = storage
-            .event()
-            .expect("Failed to get reference to the stored event");
+            spawner
+                .reserve(config, step_fn, provider)
+                .expect("Failed to reserve tasklet");
 
-        // SAFETY: This is safe because this function can be called only during system initialization
-        // and can't be interrupted.
-        critical_section::with(|_| unsafe {
-            EVENT_MANAGER
-                .add_event(event)
-                .expect("Failed to add event to the manager");
+            self.reserved_tasklet_count.lock(|count| *count += 1);
         });
     }
 
-    /// Creates new boolean condition in the system.
+    /// Creates new message queue in the system.
     ///
-    /// Condition is created in the passed `storage` memory. Storage has to be static to keep the
-    /// stored condition for the whole duration of system life
+    /// Queue is created in the passed `storage` memory. Storage has to be static to keep the
+    /// stored tasklet for the whole duration of system life.
+    ///
+    /// # Generic Parameters
+    /// * `T` - Type of the data stored in the queue.
+    /// * `QUEUE_SIZE` - Size of the queue.
     ///
     /// # Parameters
-    /// * `storage` - Static memory storage where the condition should be allocated.
+    /// * `storage` - Static memory storage where the queue should be allocated.
     ///
     /// # Return
     /// `()` if successful, `InitError` otherwise.
@@ -588,178 +1239,134 @@ impl InitApi for Aerugo {
     ///
     /// # Example
     /// ```
-    /// # use aerugo::{Aerugo, InitApi, BooleanConditionStorage, SystemHardwareConfig};
-    /// static CONDITION_STORAGE: BooleanConditionStorage = BooleanConditionStorage::new();
+    /// # use aerugo::{Aerugo, InitApi, MessageQueueStorage, SystemHardwareConfig};
+    /// #
+    /// static QUEUE_STORAGE: MessageQueueStorage<u8, 10> = MessageQueueStorage::new();
     ///
     /// fn main() {
     ///     let (aerugo, _) = Aerugo::initialize(SystemHardwareConfig::default());
     ///     #
-    ///     # assert!(!CONDITION_STORAGE.is_initialized());
+    ///     # assert!(!QUEUE_STORAGE.is_initialized());
     ///     #
-    ///     aerugo.create_boolean_condition(true, &CONDITION_STORAGE);
+    ///     aerugo.create_message_queue(&QUEUE_STORAGE);
     ///     #
-    ///     # assert!(CONDITION_STORAGE.is_initialized());
+    ///     # assert!(QUEUE_STORAGE.is_initialized());
     ///
-    ///     // Do something with the condition via handle.
-    ///     let condition_handle = CONDITION_STORAGE.create_handle();
+    ///     // Do something with the queue via handle.
+    ///     let queue_handle = QUEUE_STORAGE.create_handle();
     ///     #
-    ///     # assert!(condition_handle.is_some())
+    ///     # assert!(queue_handle.is_some())
     /// }
     /// ```
     ///
     /// # Notes
-    /// ## Given storage cannot be used to create more than one boolean condition.
+    /// ## Given storage cannot be used to create more than one queue.
     ///
     /// ```should_panic
-    /// # use aerugo::{Aerugo, BooleanConditionStorage, InitApi, SystemHardwareConfig};
+    /// # use aerugo::{Aerugo, InitApi, MessageQueueStorage, SystemHardwareConfig};
     /// #
-    /// static CONDITION_STORAGE: BooleanConditionStorage = BooleanConditionStorage::new();
+    /// static QUEUE_STORAGE: MessageQueueStorage<u8, 5> = MessageQueueStorage::new();
     ///
     /// fn main() {
     ///     # let (aerugo, _) = Aerugo::initialize(SystemHardwareConfig::default());
     ///     #
     ///     // First call is successful.
-    ///     aerugo.create_boolean_condition(true, &CONDITION_STORAGE);
+    ///     aerugo.create_message_queue(&QUEUE_STORAGE);
     ///
     ///     // But second is not.
-    ///     aerugo.create_boolean_condition(false, &CONDITION_STORAGE);
+    ///     aerugo.create_message_queue(&QUEUE_STORAGE);
     /// }
     /// ```
     ///
-    /// ## Storage has to be initialized to create a handle to the condition.
+    /// ## Storage has to be initialized to create a handle to the queue.
     /// ```
-    /// # use aerugo::{BooleanConditionStorage, InitApi};
+    /// # use aerugo::{InitApi, MessageQueueStorage};
     /// #
-    /// static CONDITION_STORAGE: BooleanConditionStorage = BooleanConditionStorage::new();
+    /// static QUEUE_STORAGE: MessageQueueStorage<u8, 5> = MessageQueueStorage::new();
     ///
     /// fn main() {
-    ///     assert!(CONDITION_STORAGE.create_handle().is_none());
+    ///     assert!(QUEUE_STORAGE.create_handle().is_none());
     /// }
     /// ```
-    fn create_boolean_condition(
+    fn create_message_queue<T, const QUEUE_SIZE: usize>(
         &'static self,
-        value: bool,
-        storage: &'static BooleanConditionStorage,
+        storage: &'static MessageQueueStorage<T, QUEUE_SIZE>,
     ) {
-        // SAFETY: This is safe because this function can be called only during system initialization
-        // and can't be interrupted.
-        critical_section::with(|_| unsafe {
-            storage
-                .init(value)
-                .expect("Failed to initialize storage for boolean condition");
-        });
+        self.create_message_queue_with_policy(storage, OverflowPolicy::Reject)
     }
 
-    /// Subscribes a tasklet to a queue.
-    ///
-    /// Tasklet subscribes for a new data in this queue. Adding new data to the queue will wake up all
-    /// subscribed tasklets and make them ready to be executed. Tasklet is ready for an execution for as
-    /// long as there is some data in the queue. On each execution tasklet takes one element from
-    /// the queue and performs user-specified processing on it.
-    ///
-    /// Each tasklet can be subscribed to at maximum one data provider. Queue can have multiple
-    /// tasklets registered.
+    /// Creates new message queue with given overflow policy in the system.
     ///
-    /// Strong typing is enforced, tasklet can only be subscribed to a queue that stores the same
-    /// type of data, that is processed by the tasklet.
+    /// This works like [create_message_queue](crate::api::InitApi::create_message_queue), but
+    /// allows selecting how data sent to the full queue is handled. With
+    /// [OverwriteOldest](OverflowPolicy::OverwriteOldest) policy sending data never fails, which is
+    /// useful for telemetry-style producers, especially in the IRQ context.
     ///
     /// # Generic Parameters
-    /// * `T` - Type of the data.
-    /// * `C` - Type of the structure with tasklet context data.
-    /// * `COND_COUNT` - Number of tasklet conditions.
+    /// * `T` - Type of the data stored in the queue.
     /// * `QUEUE_SIZE` - Size of the queue.
     ///
     /// # Parameters
-    /// * `tasklet` - Handle to the target tasklet.
-    /// * `queue` - Handle to the target queue.
+    /// * `storage` - Static memory storage where the queue should be allocated.
+    /// * `overflow_policy` - Policy of handling data sent to the full queue.
     ///
     /// # Return
     /// `()` if successful, `InitError` otherwise.
     ///
     /// # Safety
-    /// This function shouldn't be called after the system was started, because subscription is safe
-    /// only before that.
+    /// This function shouldn't be called after the system was started, because initialization is
+    /// safe only before that.
     ///
     /// # Example
     /// ```
-    /// # use aerugo::{Aerugo, InitApi, MessageQueueStorage, RuntimeApi, SystemHardwareConfig, TaskletConfig,
-    /// #   TaskletStorage};
-    /// #
-    /// # fn task(_: u8, _: &mut (), _: &dyn RuntimeApi) {}
-    /// #
-    /// # static TASK_STORAGE: TaskletStorage<u8, (), 0> = TaskletStorage::new();
-    /// # static QUEUE_STORAGE: MessageQueueStorage<u8, 10> = MessageQueueStorage::new();
+    /// # use aerugo::{Aerugo, InitApi, MessageQueueStorage, OverflowPolicy, SystemHardwareConfig};
     /// #
+    /// static QUEUE_STORAGE: MessageQueueStorage<u8, 3> = MessageQueueStorage::new();
+    ///
     /// fn main() {
-    ///     # let (aerugo, _) = Aerugo::initialize(SystemHardwareConfig::default());
-    ///     # let task_config = TaskletConfig::default();
-    ///     #
-    ///     # aerugo.create_tasklet(TaskletConfig::default(), task, &TASK_STORAGE);
-    ///     # aerugo.create_message_queue(&QUEUE_STORAGE);
-    ///     #
-    ///     let task_handle = TASK_STORAGE.create_handle().unwrap();
-    ///     let queue_handle = QUEUE_STORAGE.create_handle().unwrap();
+    ///     let (aerugo, _) = Aerugo::initialize(SystemHardwareConfig::default());
     ///
-    ///     aerugo.subscribe_tasklet_to_queue(&task_handle, &queue_handle)
+    ///     aerugo.create_message_queue_with_policy(&QUEUE_STORAGE, OverflowPolicy::OverwriteOldest);
+    ///
+    ///     let queue_handle = QUEUE_STORAGE.create_handle().unwrap();
+    ///     for sample in 0..10 {
+    ///         assert!(queue_handle.send_data(sample).is_ok());
+    ///     }
     /// }
     /// ```
-    fn subscribe_tasklet_to_queue<T, C, const COND_COUNT: usize, const QUEUE_SIZE: usize>(
+    fn create_message_queue_with_policy<T, const QUEUE_SIZE: usize>(
         &'static self,
-        tasklet_handle: &TaskletHandle<T, C, COND_COUNT>,
-        queue_handle: &MessageQueueHandle<T, QUEUE_SIZE>,
+        storage: &'static MessageQueueStorage<T, QUEUE_SIZE>,
+        overflow_policy: OverflowPolicy,
     ) {
-        let tasklet = tasklet_handle.tasklet();
-        let queue = queue_handle.queue();
-
         // SAFETY: This is safe because this function can be called only during system initialization
         // and can't be interrupted.
         critical_section::with(|_| unsafe {
-            queue
-                .register_tasklet(tasklet.ptr())
-                .expect("Failed to register tasklet in a queue");
-
-            tasklet
-                .subscribe(queue)
-                .expect("Failed to subscribe tasklet to a queue");
+            storage
+                .init(overflow_policy)
+                .expect("Failed to initialize storage for message queue");
         });
     }
 
-    /// Subscribes a tasklet to events.
-    ///
-    /// Tasklet subscribes for emitted events. Emitting an event will wake up all tasklet for which it is enabled
-    /// and make them ready to be executed. Tasklet is ready for an execution for as long as there is unhandled
-    /// event. On each execution tasklet will handle one event, receiving it's ID in step function.
+    /// Creates new event in the system.
     ///
-    /// Each tasklet can be subscribed to at maximum one data provider. Each event can be active
-    /// for multiple tasklets.
-    ///
-    /// Strong typing is enforced, tasklet can only be subscribed to events if it processes
-    /// [EventId] type.
-    ///
-    /// # Generic Parameters
-    /// * `C` - Type of the structure with tasklet context data.
-    /// * `COND_COUNT` - Number of tasklet conditions.
+    /// Events are created in EventManager and are identifier by the user-provided ID value. ID has
+    /// to be unique across the events in the system.
     ///
     /// # Parameters
-    /// * `tasklet` - Handle to the target tasklet.
-    /// * `queue` - Handle to the target queue.
+    /// * `event_id` - ID of the new event.
     ///
     /// # Return
-    /// `EventEnabler` if successful, `InitError` otherwise.
+    /// `()` if successful, `InitError` otherwise.
     ///
     /// # Safety
-    /// This function shouldn't be called after the system was started, because subscription is safe
-    /// only before that.
+    /// This function shouldn't be called after the system was started, because it modifies event
+    /// list which is safe only before that.
     ///
     /// # Example
     /// ```
-    /// # use aerugo::{Aerugo, EventId, EventStorage, InitApi, RuntimeApi, SystemHardwareConfig, TaskletConfig,
-    ///     TaskletStorage};
-    /// #
-    /// # fn task(_: EventId, _: &mut (), _: &dyn RuntimeApi) {}
-    /// #
-    /// # static TASK_STORAGE: TaskletStorage<EventId, (), 0> = TaskletStorage::new();
-    /// #
+    /// # use aerugo::{Aerugo, EventId, EventStorage, InitApi, SystemHardwareConfig};
+    ///
     /// enum Events {
     ///     MyEvent,
     /// }
@@ -767,7 +1374,7 @@ impl InitApi for Aerugo {
     /// impl From<Events> for EventId {
     ///     fn from(value: Events) -> Self {
     ///         match value {
-    ///             Events::MyEvent => 1,
+    ///             Events::MyEvent => 42,
     ///         }
     ///     }
     /// }
@@ -775,270 +1382,2686 @@ impl InitApi for Aerugo {
     /// static MY_EVENT_STORAGE: EventStorage = EventStorage::new();
     ///
     /// fn main() {
+    ///     let (aerugo, _) = Aerugo::initialize(SystemHardwareConfig::default());
+    ///
+    ///     aerugo.create_event(Events::MyEvent.into(), &MY_EVENT_STORAGE);
+    /// }
+    /// ```
+    ///
+    /// # Notes
+    /// ## Given storage cannot be used to create more than one event.
+    ///
+    /// ```should_panic
+    /// # use aerugo::{Aerugo, EventStorage, InitApi, SystemHardwareConfig};
+    /// #
+    /// static EVENT_STORAGE: EventStorage = EventStorage::new();
+    ///
+    /// fn main() {
     ///     # let (aerugo, _) = Aerugo::initialize(SystemHardwareConfig::default());
-    ///     # let task_config = TaskletConfig::default();
-    ///     #
-    ///     # aerugo.create_tasklet(TaskletConfig::default(), task, &TASK_STORAGE);
-    ///     # aerugo.create_event(Events::MyEvent.into(), &MY_EVENT_STORAGE);
     ///     #
-    ///     let task_handle = TASK_STORAGE.create_handle().unwrap();
-    ///     let task_events = [Events::MyEvent.into()];
+    ///     // First call is successful.
+    ///     aerugo.create_event(0, &EVENT_STORAGE);
     ///
-    ///     aerugo.subscribe_tasklet_to_events(&task_handle, task_events);
+    ///     // But second is not.
+    ///     aerugo.create_event(1, &EVENT_STORAGE);
     /// }
     /// ```
-    fn subscribe_tasklet_to_events<C, const COND_COUNT: usize, const EVENT_COUNT: usize>(
-        &'static self,
-        tasklet_handle: &TaskletHandle<EventId, C, COND_COUNT>,
-        events: [EventId; EVENT_COUNT],
-    ) {
-        let tasklet = tasklet_handle.tasklet();
+    ///
+    /// ## Storage has to be initialized to create a handle to the event.
+    /// ```
+    /// # use aerugo::{EventStorage, InitApi};
+    /// #
+    /// static EVENT_STORAGE: EventStorage = EventStorage::new();
+    ///
+    /// fn main() {
+    ///     assert!(EVENT_STORAGE.create_handle().is_none());
+    /// }
+    /// ```
+    fn create_event(&'static self, event_id: EventId, storage: &'static EventStorage) {
+        // SAFETY: This is safe because this function can be called only during system initialization
+        // and can't be interrupted.
+        critical_section::with(|_| unsafe {
+            storage
+                .init(event_id, &EVENT_MANAGER)
+                .expect("Failed to initialize storage for event");
+        });
 
-        // SAFETY: This is safe because this function can be called only during system initialization.
-        let event_set = unsafe {
-            EVENT_MANAGER
-                .create_event_set(tasklet.ptr())
-                .expect("Failed to create event set")
-        };
+        let event = storage
+            .event()
+            .expect("Failed to get reference to the stored event");
 
         // SAFETY: This is safe because this function can be called only during system initialization
         // and can't be interrupted.
         critical_section::with(|_| unsafe {
-            events
-                .iter()
-                .map(|&event_id| {
-                    EVENT_MANAGER
-                        .get_event(event_id)
-                        .unwrap_or_else(|| panic!("Failed to get event with ID '{}'", event_id))
-                })
-                .for_each(|event| {
-                    event
-                        .add_set(event_set)
-                        .expect("Failed to add set to an event");
-                });
-
-            tasklet
-                .subscribe(event_set)
-                .expect("Failed to subscribe tasklet to events");
+            EVENT_MANAGER
+                .add_event(event)
+                .expect("Failed to add event to the manager");
         });
     }
 
-    /// Subscribes tasklet to the boolean condition.
+    /// Creates new event carrying typed data in the system.
     ///
-    /// Tasklet subscribes for a state changes in this condition. Changing the value of the
-    /// condition will wake up all subscribed tasklets and make them ready to be executed.
+    /// Data event delivers the payload emitted with
+    /// [emit_event_with_data](crate::DataEventHandle::emit_event_with_data) to all subscribed
+    /// tasklets. Each subscribed tasklet has its own payload queue of size `N`.
     ///
-    /// Each tasklet can be subscribed to at maximum one data provider. Condition can have multiple
-    /// tasklet registered.
+    /// # Generic Parameters
+    /// * `T` - Type of the payload.
+    /// * `N` - Size of the payload queue of each subscribed tasklet.
     ///
-    /// Strong typing is enforced, tasklet can only be subscribed to events if it processes bool type.
+    /// # Parameters
+    /// * `event_id` - Identifier of this event.
+    /// * `storage` - Static memory storage where the event should be allocated.
     ///
-    /// # Generic Parameters
-    /// * `C` - Type of the structure with tasklet context data.
-    /// * `COND_COUNT` - Number of tasklet conditions.
+    /// # Safety
+    /// This function shouldn't be called after the system was started, because initialization is
+    /// safe only before that.
+    ///
+    /// # Example
+    /// ```
+    /// # use aerugo::{Aerugo, DataEventStorage, InitApi, SystemHardwareConfig};
+    /// #
+    /// static EVENT_STORAGE: DataEventStorage<u32, 4> = DataEventStorage::new();
+    ///
+    /// fn main() {
+    ///     # let (aerugo, _) = Aerugo::initialize(SystemHardwareConfig::default());
+    ///     #
+    ///     aerugo.create_data_event(42, &EVENT_STORAGE);
+    ///
+    ///     let event_handle = EVENT_STORAGE.create_handle();
+    ///     assert!(event_handle.is_some());
+    /// }
+    /// ```
+    fn create_data_event<T: Clone, const N: usize>(
+        &'static self,
+        event_id: EventId,
+        storage: &'static DataEventStorage<T, N>,
+    ) {
+        // SAFETY: This is safe because this function can be called only during system initialization
+        // and can't be interrupted.
+        critical_section::with(|_| unsafe {
+            storage
+                .init(event_id)
+                .expect("Failed to initialize storage for data event");
+        });
+    }
+
+    /// Creates new boolean condition in the system.
+    ///
+    /// Condition is created in the passed `storage` memory. Storage has to be static to keep the
+    /// stored condition for the whole duration of system life
     ///
     /// # Parameters
-    /// * `tasklet` - Handle to the target tasklet.
-    /// * `condition` - Handle to the target condition.
+    /// * `storage` - Static memory storage where the condition should be allocated.
     ///
     /// # Return
     /// `()` if successful, `InitError` otherwise.
     ///
     /// # Safety
-    /// This function shouldn't be called after the system was started, because subscription is safe
-    /// only before that.
+    /// This function shouldn't be called after the system was started, because it initializes the
+    /// passed storage which is safe only before that.
     ///
     /// # Example
     /// ```
-    /// # use aerugo::{Aerugo, InitApi, BooleanConditionStorage, RuntimeApi, SystemHardwareConfig, TaskletConfig,
-    ///     TaskletStorage};
+    /// # use aerugo::{Aerugo, InitApi, BooleanConditionStorage, SystemHardwareConfig};
+    /// static CONDITION_STORAGE: BooleanConditionStorage = BooleanConditionStorage::new();
+    ///
+    /// fn main() {
+    ///     let (aerugo, _) = Aerugo::initialize(SystemHardwareConfig::default());
+    ///     #
+    ///     # assert!(!CONDITION_STORAGE.is_initialized());
+    ///     #
+    ///     aerugo.create_boolean_condition(true, &CONDITION_STORAGE);
+    ///     #
+    ///     # assert!(CONDITION_STORAGE.is_initialized());
+    ///
+    ///     // Do something with the condition via handle.
+    ///     let condition_handle = CONDITION_STORAGE.create_handle();
+    ///     #
+    ///     # assert!(condition_handle.is_some())
+    /// }
+    /// ```
+    ///
+    /// # Notes
+    /// ## Given storage cannot be used to create more than one boolean condition.
+    ///
+    /// ```should_panic
+    /// # use aerugo::{Aerugo, BooleanConditionStorage, InitApi, SystemHardwareConfig};
     /// #
-    /// # fn task(_: bool, _: &mut (), _: &dyn RuntimeApi) {}
+    /// static CONDITION_STORAGE: BooleanConditionStorage = BooleanConditionStorage::new();
+    ///
+    /// fn main() {
+    ///     # let (aerugo, _) = Aerugo::initialize(SystemHardwareConfig::default());
+    ///     #
+    ///     // First call is successful.
+    ///     aerugo.create_boolean_condition(true, &CONDITION_STORAGE);
+    ///
+    ///     // But second is not.
+    ///     aerugo.create_boolean_condition(false, &CONDITION_STORAGE);
+    /// }
+    /// ```
+    ///
+    /// ## Storage has to be initialized to create a handle to the condition.
+    /// ```
+    /// # use aerugo::{BooleanConditionStorage, InitApi};
     /// #
-    /// # static TASK_STORAGE: TaskletStorage<bool, (), 0> = TaskletStorage::new();
-    /// # static CONDITION_STORAGE: BooleanConditionStorage = BooleanConditionStorage::new();
+    /// static CONDITION_STORAGE: BooleanConditionStorage = BooleanConditionStorage::new();
+    ///
+    /// fn main() {
+    ///     assert!(CONDITION_STORAGE.create_handle().is_none());
+    /// }
+    /// ```
+    fn create_boolean_condition(
+        &'static self,
+        value: bool,
+        storage: &'static BooleanConditionStorage,
+    ) {
+        // SAFETY: This is safe because this function can be called only during system initialization
+        // and can't be interrupted.
+        critical_section::with(|_| unsafe {
+            storage
+                .init(value)
+                .expect("Failed to initialize storage for boolean condition");
+        });
+    }
+
+    /// Creates new barrier in the system.
+    ///
+    /// Barrier synchronizes phases of the tasklets [subscribed](InitApi::subscribe_tasklet_to_barrier)
+    /// to it. Next phase starts when all of them have [arrived](crate::BarrierHandle::arrive) at the
+    /// barrier in the current one.
+    ///
+    /// # Generic Parameters
+    /// * `N` - Maximum number of participants.
+    ///
+    /// # Parameters
+    /// * `storage` - Static memory storage where the barrier should be allocated.
+    ///
+    /// # Safety
+    /// This function shouldn't be called after the system was started, because initialization is
+    /// safe only before that.
+    ///
+    /// # Example
+    /// ```
+    /// # use aerugo::{Aerugo, BarrierStorage, InitApi, SystemHardwareConfig};
     /// #
+    /// static BARRIER_STORAGE: BarrierStorage<3> = BarrierStorage::new();
+    ///
     /// fn main() {
     ///     # let (aerugo, _) = Aerugo::initialize(SystemHardwareConfig::default());
-    ///     # let task_config = TaskletConfig::default();
-    ///     #
-    ///     # aerugo.create_tasklet(TaskletConfig::default(), task, &TASK_STORAGE);
-    ///     # aerugo.create_boolean_condition(true, &CONDITION_STORAGE);
     ///     #
-    ///     let task_handle = TASK_STORAGE.create_handle().unwrap();
-    ///     let condition_handle = CONDITION_STORAGE.create_handle().unwrap();
+    ///     aerugo.create_barrier(&BARRIER_STORAGE);
     ///
-    ///     aerugo.subscribe_tasklet_to_condition(&task_handle, &condition_handle);
+    ///     let barrier_handle = BARRIER_STORAGE.create_handle();
+    ///     assert!(barrier_handle.is_some());
+    /// }
+    /// ```
+    fn create_barrier<const N: usize>(&'static self, storage: &'static BarrierStorage<N>) {
+        // SAFETY: This is safe because this function can be called only during system initialization
+        // and can't be interrupted.
+        critical_section::with(|_| unsafe {
+            storage
+                .init()
+                .expect("Failed to initialize storage for barrier");
+        });
+    }
+
+    /// Creates new timer in the system.
+    ///
+    /// Timer is created stopped, and can be started with its handle, also from the IRQ context.
+    /// When the timer fires, its action is performed from the system loop, between tasklet steps.
+    /// Timers don't need a tasklet, so they are a lightweight way to implement short timeouts.
+    ///
+    /// Maximum number of timers is configured with `AERUGO_TIMER_COUNT` environmental variable.
+    ///
+    /// # Parameters
+    /// * `action` - Action performed when the timer fires.
+    /// * `storage` - Static memory storage where the timer should be allocated.
+    ///
+    /// # Safety
+    /// This function shouldn't be called after the system was started, because it initializes the
+    /// passed storage which is safe only before that.
+    ///
+    /// # Example
+    /// ```
+    /// # use aerugo::{Aerugo, EventStorage, InitApi, RuntimeApi, SystemHardwareConfig, TimerAction, TimerStorage};
+    /// # use aerugo::Duration;
+    /// #
+    /// const TIMEOUT_EVENT: u32 = 1;
+    ///
+    /// static TIMEOUT_EVENT_STORAGE: EventStorage = EventStorage::new();
+    /// static RESPONSE_TIMEOUT: TimerStorage = TimerStorage::new();
+    /// static WATCHDOG_KICK: TimerStorage = TimerStorage::new();
+    ///
+    /// fn kick_watchdog(_: &'static dyn RuntimeApi) {}
+    ///
+    /// fn main() {
+    ///     let (aerugo, _) = Aerugo::initialize(SystemHardwareConfig::default());
+    ///
+    ///     aerugo.create_event(TIMEOUT_EVENT, &TIMEOUT_EVENT_STORAGE);
+    ///     aerugo.create_timer(TimerAction::Event(TIMEOUT_EVENT), &RESPONSE_TIMEOUT);
+    ///     aerugo.create_timer(TimerAction::Callback(kick_watchdog), &WATCHDOG_KICK);
+    ///
+    ///     let timeout = RESPONSE_TIMEOUT.create_handle().unwrap();
+    ///     timeout.start_once(Duration::millis(20));
+    ///     # assert!(timeout.is_running());
+    ///
+    ///     let kick = WATCHDOG_KICK.create_handle().unwrap();
+    ///     kick.start_periodic(Duration::millis(100));
+    /// }
+    /// ```
+    fn create_timer(&'static self, action: TimerAction, storage: &'static TimerStorage) {
+        // SAFETY: This is safe because this function can be called only during system initialization
+        // and can't be interrupted.
+        critical_section::with(|_| unsafe {
+            let timer = storage
+                .init(action)
+                .expect("Failed to initialize storage for timer");
+
+            TIMER_MANAGER
+                .register_timer(timer)
+                .expect("Failed to register timer");
+        });
+    }
+
+    /// Subscribes a tasklet to the source of its data.
+    ///
+    /// This is a generic version of `subscribe_tasklet_to_*` functions. Source can be a handle to
+    /// a queue, data event or boolean condition, or a list of event IDs. Source has to provide
+    /// data of the type processed by the tasklet, so a mismatched subscription is a compile error
+    /// which names both types.
+    ///
+    /// # Generic Parameters
+    /// * `T` - Type of the data.
+    /// * `C` - Type of the structure with tasklet context data.
+    /// * `COND_COUNT` - Number of tasklet conditions.
+    ///
+    /// # Parameters
+    /// * `tasklet_handle` - Handle to the target tasklet.
+    /// * `source` - Source of the data.
+    ///
+    /// # Safety
+    /// This function shouldn't be called after the system was started, because subscription is safe
+    /// only before that.
+    ///
+    /// # Example
+    /// ```
+    /// # use aerugo::{Aerugo, InitApi, MessageQueueStorage, RuntimeApi, SystemHardwareConfig, TaskletConfig,
+    /// #   TaskletStorage};
+    /// #
+    /// # fn task(_: u8, _: &mut (), _: &dyn RuntimeApi) {}
+    /// #
+    /// # static TASK_STORAGE: TaskletStorage<u8, ()> = TaskletStorage::new();
+    /// # static QUEUE_STORAGE: MessageQueueStorage<u8, 10> = MessageQueueStorage::new();
+    /// #
+    /// fn main() {
+    ///     # let (aerugo, _) = Aerugo::initialize(SystemHardwareConfig::default());
+    ///     #
+    ///     # aerugo.create_tasklet(TaskletConfig::default(), task, &TASK_STORAGE);
+    ///     # aerugo.create_message_queue(&QUEUE_STORAGE);
+    ///     #
+    ///     let task_handle = TASK_STORAGE.create_handle().unwrap();
+    ///     let queue_handle = QUEUE_STORAGE.create_handle().unwrap();
+    ///
+    ///     aerugo.subscribe_tasklet(&task_handle, &queue_handle)
+    /// }
+    /// ```
+    ///
+    /// Subscribing tasklet to a source of different type fails to compile.
+    /// ```compile_fail
+    /// # use aerugo::{Aerugo, InitApi, MessageQueueStorage, RuntimeApi, SystemHardwareConfig, TaskletConfig,
+    /// #   TaskletStorage};
+    /// #
+    /// # fn task(_: u8, _: &mut (), _: &dyn RuntimeApi) {}
+    /// #
+    /// # static TASK_STORAGE: TaskletStorage<u8, (), 0> = TaskletStorage::new();
+    /// # static QUEUE_STORAGE: MessageQueueStorage<u16, 10> = MessageQueueStorage::new();
+    /// #
+    /// fn main() {
+    ///     # let (aerugo, _) = Aerugo::initialize(SystemHardwareConfig::default());
+    ///     #
+    ///     # aerugo.create_tasklet(TaskletConfig::default(), task, &TASK_STORAGE);
+    ///     # aerugo.create_message_queue(&QUEUE_STORAGE);
+    ///     #
+    ///     let task_handle = TASK_STORAGE.create_handle().unwrap();
+    ///     let queue_handle = QUEUE_STORAGE.create_handle().unwrap();
+    ///
+    ///     // Error: `MessageQueueHandle<u16, 10>` doesn't provide data of type `u8`
+    ///     aerugo.subscribe_tasklet(&task_handle, &queue_handle)
+    /// }
+    /// ```
+    fn subscribe_tasklet<T, C, const COND_COUNT: usize>(
+        &'static self,
+        tasklet_handle: &TaskletHandle<T, C, COND_COUNT>,
+        source: &impl TaskletDataSource<T>,
+    ) {
+        source.subscribe(self, tasklet_handle)
+    }
+
+    /// Subscribes a tasklet to a queue.
+    ///
+    /// Tasklet subscribes for a new data in this queue. Adding new data to the queue will wake up all
+    /// subscribed tasklets and make them ready to be executed. Tasklet is ready for an execution for as
+    /// long as there is some data in the queue. On each execution tasklet takes one element from
+    /// the queue and performs user-specified processing on it.
+    ///
+    /// Each tasklet can be subscribed to at maximum one data provider. Queue can have multiple
+    /// tasklets registered.
+    ///
+    /// Strong typing is enforced, tasklet can only be subscribed to a queue that stores the same
+    /// type of data, that is processed by the tasklet.
+    ///
+    /// # Generic Parameters
+    /// * `T` - Type of the data.
+    /// * `C` - Type of the structure with tasklet context data.
+    /// * `COND_COUNT` - Number of tasklet conditions.
+    /// * `QUEUE_SIZE` - Size of the queue.
+    ///
+    /// # Parameters
+    /// * `tasklet` - Handle to the target tasklet.
+    /// * `queue` - Handle to the target queue.
+    ///
+    /// # Return
+    /// `()` if successful, `InitError` otherwise.
+    ///
+    /// # Safety
+    /// This function shouldn't be called after the system was started, because subscription is safe
+    /// only before that.
+    ///
+    /// # Example
+    /// ```
+    /// # use aerugo::{Aerugo, InitApi, MessageQueueStorage, RuntimeApi, SystemHardwareConfig, TaskletConfig,
+    /// #   TaskletStorage};
+    /// #
+    /// # fn task(_: u8, _: &mut (), _: &dyn RuntimeApi) {}
+    /// #
+    /// # static TASK_STORAGE: TaskletStorage<u8, (), 0> = TaskletStorage::new();
+    /// # static QUEUE_STORAGE: MessageQueueStorage<u8, 10> = MessageQueueStorage::new();
+    /// #
+    /// fn main() {
+    ///     # let (aerugo, _) = Aerugo::initialize(SystemHardwareConfig::default());
+    ///     # let task_config = TaskletConfig::default();
+    ///     #
+    ///     # aerugo.create_tasklet(TaskletConfig::default(), task, &TASK_STORAGE);
+    ///     # aerugo.create_message_queue(&QUEUE_STORAGE);
+    ///     #
+    ///     let task_handle = TASK_STORAGE.create_handle().unwrap();
+    ///     let queue_handle = QUEUE_STORAGE.create_handle().unwrap();
+    ///
+    ///     aerugo.subscribe_tasklet_to_queue(&task_handle, &queue_handle)
+    /// }
+    /// ```
+    fn subscribe_tasklet_to_queue<T, C, const COND_COUNT: usize, const QUEUE_SIZE: usize>(
+        &'static self,
+        tasklet_handle: &TaskletHandle<T, C, COND_COUNT>,
+        queue_handle: &MessageQueueHandle<T, QUEUE_SIZE>,
+    ) {
+        let tasklet = tasklet_handle.tasklet();
+        let queue = queue_handle.queue();
+
+        // SAFETY: This is safe because this function can be called only during system initialization
+        // and can't be interrupted.
+        critical_section::with(|_| unsafe {
+            queue
+                .register_tasklet(tasklet.ptr())
+                .expect("Failed to register tasklet in a queue");
+
+            tasklet
+                .subscribe(queue)
+                .expect("Failed to subscribe tasklet to a queue");
+        });
+    }
+
+    /// Subscribes a tasklet to events.
+    ///
+    /// Tasklet subscribes for emitted events. Emitting an event will wake up all tasklet for which it is enabled
+    /// and make them ready to be executed. Tasklet is ready for an execution for as long as there is unhandled
+    /// event. On each execution tasklet will handle one event, receiving it's ID in step function.
+    ///
+    /// Each tasklet can be subscribed to at maximum one data provider. Each event can be active
+    /// for multiple tasklets.
+    ///
+    /// Strong typing is enforced, tasklet can only be subscribed to events if it processes
+    /// [EventId] type.
+    ///
+    /// # Generic Parameters
+    /// * `C` - Type of the structure with tasklet context data.
+    /// * `COND_COUNT` - Number of tasklet conditions.
+    ///
+    /// # Parameters
+    /// * `tasklet` - Handle to the target tasklet.
+    /// * `queue` - Handle to the target queue.
+    ///
+    /// # Return
+    /// `EventEnabler` if successful, `InitError` otherwise.
+    ///
+    /// # Safety
+    /// This function shouldn't be called after the system was started, because subscription is safe
+    /// only before that.
+    ///
+    /// # Example
+    /// ```
+    /// # use aerugo::{Aerugo, EventId, EventStorage, InitApi, RuntimeApi, SystemHardwareConfig, TaskletConfig,
+    ///     TaskletStorage};
+    /// #
+    /// # fn task(_: EventId, _: &mut (), _: &dyn RuntimeApi) {}
+    /// #
+    /// # static TASK_STORAGE: TaskletStorage<EventId, (), 0> = TaskletStorage::new();
+    /// #
+    /// enum Events {
+    ///     MyEvent,
+    /// }
+    ///
+    /// impl From<Events> for EventId {
+    ///     fn from(value: Events) -> Self {
+    ///         match value {
+    ///             Events::MyEvent => 1,
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// static MY_EVENT_STORAGE: EventStorage = EventStorage::new();
+    ///
+    /// fn main() {
+    ///     # let (aerugo, _) = Aerugo::initialize(SystemHardwareConfig::default());
+    ///     # let task_config = TaskletConfig::default();
+    ///     #
+    ///     # aerugo.create_tasklet(TaskletConfig::default(), task, &TASK_STORAGE);
+    ///     # aerugo.create_event(Events::MyEvent.into(), &MY_EVENT_STORAGE);
+    ///     #
+    ///     let task_handle = TASK_STORAGE.create_handle().unwrap();
+    ///     let task_events = [Events::MyEvent.into()];
+    ///
+    ///     aerugo.subscribe_tasklet_to_events(&task_handle, task_events);
+    /// }
+    /// ```
+    fn subscribe_tasklet_to_events<C, const COND_COUNT: usize, const EVENT_COUNT: usize>(
+        &'static self,
+        tasklet_handle: &TaskletHandle<EventId, C, COND_COUNT>,
+        events: [EventId; EVENT_COUNT],
+    ) {
+        self.subscribe_tasklet_to_event_group(tasklet_handle, EventGroup::any(events));
+    }
+
+    /// Subscribes tasklet to the group of events.
+    ///
+    /// Tasklet subscribed to the group in [Any](crate::EventGroupMode::Any) mode is triggered by
+    /// each emitted event, same as with
+    /// [subscribe_tasklet_to_events](crate::api::InitApi::subscribe_tasklet_to_events). In
+    /// [All](crate::EventGroupMode::All) mode tasklet is triggered only when all events of the group
+    /// were emitted since the last trigger, and receives ID of the event that completed the group.
+    ///
+    /// Tasklet can be subscribed only to one data provider, so it can't be subscribed to more than
+    /// one event group.
+    ///
+    /// # Generic Parameters
+    /// * `C` - Type of the structure with tasklet context data.
+    /// * `COND_COUNT` - Number of tasklet conditions.
+    /// * `EVENT_COUNT` - Number of events in the group.
+    ///
+    /// # Parameters
+    /// * `tasklet_handle` - Handle to the target tasklet.
+    /// * `group` - Group of events.
+    ///
+    /// # Safety
+    /// This function shouldn't be called after the system was started, because subscription is safe
+    /// only before that.
+    ///
+    /// # Example
+    /// ```
+    /// # use aerugo::{Aerugo, EventGroup, EventId, EventStorage, InitApi, RuntimeApi,
+    /// #     SystemHardwareConfig, TaskletConfig, TaskletStorage};
+    /// #
+    /// const CALIBRATION_DONE: EventId = 1;
+    /// const SENSOR_READY: EventId = 2;
+    ///
+    /// fn start_measurements(_: EventId, _: &mut (), _: &'static dyn RuntimeApi) {}
+    ///
+    /// static CALIBRATION_DONE_STORAGE: EventStorage = EventStorage::new();
+    /// static SENSOR_READY_STORAGE: EventStorage = EventStorage::new();
+    /// static MEASUREMENTS_STORAGE: TaskletStorage<EventId, (), 0> = TaskletStorage::new();
+    ///
+    /// fn main() {
+    ///     # let (aerugo, _) = Aerugo::initialize(SystemHardwareConfig::default());
+    ///     #
+    ///     aerugo.create_event(CALIBRATION_DONE, &CALIBRATION_DONE_STORAGE);
+    ///     aerugo.create_event(SENSOR_READY, &SENSOR_READY_STORAGE);
+    ///     aerugo.create_tasklet(TaskletConfig::default(), start_measurements, &MEASUREMENTS_STORAGE);
+    ///
+    ///     let measurements = MEASUREMENTS_STORAGE.create_handle().unwrap();
+    ///     let group = EventGroup::all([CALIBRATION_DONE, SENSOR_READY]);
+    ///
+    ///     aerugo.subscribe_tasklet_to_event_group(&measurements, group);
+    /// }
+    /// ```
+    fn subscribe_tasklet_to_event_group<C, const COND_COUNT: usize, const EVENT_COUNT: usize>(
+        &'static self,
+        tasklet_handle: &TaskletHandle<EventId, C, COND_COUNT>,
+        group: EventGroup<EVENT_COUNT>,
+    ) {
+        let tasklet = tasklet_handle.tasklet();
+
+        // SAFETY: This is safe because this function can be called only during system initialization.
+        let event_set = unsafe {
+            EVENT_MANAGER
+                .create_event_set(tasklet.ptr(), group.mode, group.distinct_count())
+                .expect("Failed to create event set")
+        };
+
+        // SAFETY: This is safe because this function can be called only during system initialization
+        // and can't be interrupted.
+        critical_section::with(|_| unsafe {
+            group
+                .events
+                .iter()
+                .map(|&event_id| {
+                    EVENT_MANAGER
+                        .get_event(event_id)
+                        .unwrap_or_else(|| panic!("Failed to get event with ID '{}'", event_id))
+                })
+                .for_each(|event| {
+                    event
+                        .add_set(event_set)
+                        .expect("Failed to add set to an event");
+                });
+
+            tasklet
+                .subscribe(event_set)
+                .expect("Failed to subscribe tasklet to events");
+        });
+    }
+
+    /// Subscribes a tasklet to the event carrying typed data.
+    ///
+    /// Emitting the event delivers its payload to all subscribed tasklets and wakes them. On each
+    /// execution tasklet handles one payload, receiving it in the step function.
+    ///
+    /// Each tasklet can be subscribed to at maximum one data provider.
+    ///
+    /// # Generic Parameters
+    /// * `T` - Type of the payload.
+    /// * `C` - Type of the structure with tasklet context data.
+    /// * `COND_COUNT` - Number of tasklet conditions.
+    /// * `N` - Size of the payload queue.
+    ///
+    /// # Parameters
+    /// * `tasklet_handle` - Handle to the target tasklet.
+    /// * `event_handle` - Handle to the target event.
+    ///
+    /// # Safety
+    /// This function shouldn't be called after the system was started, because subscription is safe
+    /// only before that.
+    ///
+    /// # Example
+    /// ```
+    /// # use aerugo::{
+    /// #     Aerugo, DataEventStorage, InitApi, RuntimeApi, SystemHardwareConfig, TaskletConfig,
+    /// #     TaskletStorage,
+    /// # };
+    /// #
+    /// # fn task(_: u32, _: &mut (), _: &dyn RuntimeApi) {}
+    /// #
+    /// # static TASK_STORAGE: TaskletStorage<u32, (), 0> = TaskletStorage::new();
+    /// static EVENT_STORAGE: DataEventStorage<u32, 4> = DataEventStorage::new();
+    ///
+    /// fn main() {
+    ///     # let (aerugo, _) = Aerugo::initialize(SystemHardwareConfig::default());
+    ///     #
+    ///     # aerugo.create_tasklet(TaskletConfig::default(), task, &TASK_STORAGE);
+    ///     # aerugo.create_data_event(0, &EVENT_STORAGE);
+    ///     #
+    ///     let task_handle = TASK_STORAGE.create_handle().unwrap();
+    ///     let event_handle = EVENT_STORAGE.create_handle().unwrap();
+    ///
+    ///     aerugo.subscribe_tasklet_to_data_event(&task_handle, &event_handle);
+    ///
+    ///     event_handle.emit_event_with_data(1000).unwrap();
+    /// }
+    /// ```
+    fn subscribe_tasklet_to_data_event<T: Clone, C, const COND_COUNT: usize, const N: usize>(
+        &'static self,
+        tasklet_handle: &TaskletHandle<T, C, COND_COUNT>,
+        event_handle: &DataEventHandle<T, N>,
+    ) {
+        let tasklet = tasklet_handle.tasklet();
+        let event = event_handle.event();
+
+        // SAFETY: This is safe because this function can be called only during system initialization
+        // and can't be interrupted.
+        critical_section::with(|_| unsafe {
+            let subscription = event
+                .subscribe(tasklet.ptr())
+                .expect("Failed to subscribe tasklet to a data event");
+
+            tasklet
+                .subscribe(subscription)
+                .expect("Failed to subscribe tasklet to a data event");
+        });
+    }
+
+    /// Subscribes tasklet to the boolean condition.
+    ///
+    /// Tasklet subscribes for a state changes in this condition. Changing the value of the
+    /// condition will wake up all subscribed tasklets and make them ready to be executed.
+    ///
+    /// Each tasklet can be subscribed to at maximum one data provider. Condition can have multiple
+    /// tasklet registered.
+    ///
+    /// Strong typing is enforced, tasklet can only be subscribed to events if it processes bool type.
+    ///
+    /// # Generic Parameters
+    /// * `C` - Type of the structure with tasklet context data.
+    /// * `COND_COUNT` - Number of tasklet conditions.
+    ///
+    /// # Parameters
+    /// * `tasklet` - Handle to the target tasklet.
+    /// * `condition` - Handle to the target condition.
+    ///
+    /// # Return
+    /// `()` if successful, `InitError` otherwise.
+    ///
+    /// # Safety
+    /// This function shouldn't be called after the system was started, because subscription is safe
+    /// only before that.
+    ///
+    /// # Example
+    /// ```
+    /// # use aerugo::{Aerugo, InitApi, BooleanConditionStorage, RuntimeApi, SystemHardwareConfig, TaskletConfig,
+    ///     TaskletStorage};
+    /// #
+    /// # fn task(_: bool, _: &mut (), _: &dyn RuntimeApi) {}
+    /// #
+    /// # static TASK_STORAGE: TaskletStorage<bool, (), 0> = TaskletStorage::new();
+    /// # static CONDITION_STORAGE: BooleanConditionStorage = BooleanConditionStorage::new();
+    /// #
+    /// fn main() {
+    ///     # let (aerugo, _) = Aerugo::initialize(SystemHardwareConfig::default());
+    ///     # let task_config = TaskletConfig::default();
+    ///     #
+    ///     # aerugo.create_tasklet(TaskletConfig::default(), task, &TASK_STORAGE);
+    ///     # aerugo.create_boolean_condition(true, &CONDITION_STORAGE);
+    ///     #
+    ///     let task_handle = TASK_STORAGE.create_handle().unwrap();
+    ///     let condition_handle = CONDITION_STORAGE.create_handle().unwrap();
+    ///
+    ///     aerugo.subscribe_tasklet_to_condition(&task_handle, &condition_handle);
+    /// }
+    /// ```
+    fn subscribe_tasklet_to_condition<C, const COND_COUNT: usize>(
+        &'static self,
+        tasklet_handle: &TaskletHandle<bool, C, COND_COUNT>,
+        condition_handle: &BooleanConditionHandle,
+    ) {
+        let tasklet = tasklet_handle.tasklet();
+        let condition = condition_handle.condition();
+
+        // SAFETY: This is safe because this function can be called only during system initialization
+        // and can't be interrupted.
+        critical_section::with(|_| unsafe {
+            condition
+                .register_tasklet(tasklet.ptr())
+                .expect("Failed to register tasklet in a condition");
+
+            tasklet
+                .subscribe(condition)
+                .expect("Failed to subscribe tasklet to a condition");
+        });
+    }
+
+    /// Subscribes tasklet to the barrier, as one of its participants.
+    ///
+    /// Participant is released to the current phase of the barrier, and receives its number in
+    /// the step function. It's executed once per phase, and should
+    /// [arrive](crate::BarrierHandle::arrive) at the barrier when its part of the phase is done.
+    /// When all participants have arrived, next phase starts and all of them are woken.
+    ///
+    /// Each tasklet can be subscribed to at maximum one data provider.
+    ///
+    /// # Generic Parameters
+    /// * `C` - Type of the structure with tasklet context data.
+    /// * `COND_COUNT` - Number of tasklet conditions.
+    /// * `N` - Maximum number of participants.
+    ///
+    /// # Parameters
+    /// * `tasklet_handle` - Handle to the target tasklet.
+    /// * `barrier_handle` - Handle to the target barrier.
+    ///
+    /// # Safety
+    /// This function shouldn't be called after the system was started, because subscription is safe
+    /// only before that.
+    ///
+    /// # Example
+    /// ```
+    /// # use aerugo::{
+    /// #     Aerugo, BarrierHandle, BarrierStorage, InitApi, RuntimeApi, SystemHardwareConfig,
+    /// #     TaskletConfig, TaskletStorage,
+    /// # };
+    /// #
+    /// struct FilterCtx {
+    ///     barrier: Option<BarrierHandle<2>>,
+    /// }
+    ///
+    /// fn filter(_phase: u32, ctx: &mut FilterCtx, _: &'static dyn RuntimeApi) {
+    ///     // Filter sensor samples of this phase.
+    ///     if let Some(barrier) = &ctx.barrier {
+    ///         barrier.arrive().unwrap();
+    ///     }
+    /// }
+    ///
+    /// # static GYRO_STORAGE: TaskletStorage<u32, FilterCtx, 0> = TaskletStorage::new();
+    /// # static ACCEL_STORAGE: TaskletStorage<u32, FilterCtx, 0> = TaskletStorage::new();
+    /// static BARRIER_STORAGE: BarrierStorage<2> = BarrierStorage::new();
+    ///
+    /// fn main() {
+    ///     # let (aerugo, _) = Aerugo::initialize(SystemHardwareConfig::default());
+    ///     #
+    ///     aerugo.create_barrier(&BARRIER_STORAGE);
+    ///     let barrier_handle = BARRIER_STORAGE.create_handle().unwrap();
+    ///
+    ///     let gyro_context = FilterCtx { barrier: Some(barrier_handle) };
+    ///     aerugo.create_tasklet_with_context(TaskletConfig::default(), filter, gyro_context, &GYRO_STORAGE);
+    ///     let accel_context = FilterCtx { barrier: Some(barrier_handle) };
+    ///     aerugo.create_tasklet_with_context(TaskletConfig::default(), filter, accel_context, &ACCEL_STORAGE);
+    ///
+    ///     let gyro_handle = GYRO_STORAGE.create_handle().unwrap();
+    ///     let accel_handle = ACCEL_STORAGE.create_handle().unwrap();
+    ///     aerugo.subscribe_tasklet_to_barrier(&gyro_handle, &barrier_handle);
+    ///     aerugo.subscribe_tasklet_to_barrier(&accel_handle, &barrier_handle);
+    /// }
+    /// ```
+    fn subscribe_tasklet_to_barrier<C, const COND_COUNT: usize, const N: usize>(
+        &'static self,
+        tasklet_handle: &TaskletHandle<u32, C, COND_COUNT>,
+        barrier_handle: &BarrierHandle<N>,
+    ) {
+        let tasklet = tasklet_handle.tasklet();
+        let barrier = barrier_handle.barrier();
+
+        // SAFETY: This is safe because this function can be called only during system initialization
+        // and can't be interrupted.
+        critical_section::with(|_| unsafe {
+            let participant = barrier
+                .subscribe(tasklet.ptr())
+                .expect("Failed to subscribe tasklet to a barrier");
+
+            tasklet
+                .subscribe(participant)
+                .expect("Failed to subscribe tasklet to a barrier");
+        });
+    }
+
+    /// Subscribes tasklet to the cyclic execution.
+    ///
+    /// Tasklet subscribes for cyclic execution. Tasklet will be executed in specified period,
+    /// or will be always ready for execution if such period was not specified. On execution
+    /// tasklet won't receive any data, so cycling tasklets are useful mostly as ex. producers or
+    /// some periodic housekeeping operations.
+    ///
+    /// Each tasklet can be subscribed to at maximum on data provider.
+    ///
+    /// # Generic Parameters
+    /// * `C` - Type of the structure with tasklet context data.
+    /// * `COND_COUNT` - Number of tasklet conditions.
+    ///
+    /// # Parameters
+    /// * `tasklet` - Handle to the target tasklet.
+    /// * `period` - Period of execution, `None` if should be woke whenever possible.
+    /// * `offset` - Offset of first execution after scheduled start, `None` if should be executed instantly.
+    ///
+    /// # Return
+    /// `()` if successful, `InitError` otherwise.
+    ///
+    /// # Safety
+    /// This function shouldn't be called after the system was started, because subscription is safe
+    /// only before that.
+    ///
+    /// # Example
+    /// ```
+    /// # use aerugo::{Aerugo, InitApi, RuntimeApi, SystemHardwareConfig, TaskletConfig, TaskletStorage};
+    /// #
+    /// # fn task(_: (), _: &mut (), _: &dyn RuntimeApi) {}
+    /// #
+    /// # static TASK_STORAGE: TaskletStorage<(), (), 0> = TaskletStorage::new();
+    /// #
+    /// fn main() {
+    ///     # let (aerugo, _) = Aerugo::initialize(SystemHardwareConfig::default());
+    ///     # let task_config = TaskletConfig::default();
+    ///     #
+    ///     # aerugo.create_tasklet(TaskletConfig::default(), task, &TASK_STORAGE);
+    ///     #
+    ///     let task_handle = TASK_STORAGE.create_handle().unwrap();
+    ///
+    ///     aerugo.subscribe_tasklet_to_cyclic(&task_handle, None, None);
+    /// }
+    /// ```
+    fn subscribe_tasklet_to_cyclic<C, const COND_COUNT: usize>(
+        &'static self,
+        tasklet_handle: &TaskletHandle<(), C, COND_COUNT>,
+        period: Option<Duration>,
+        offset: Option<Duration>,
+    ) {
+        let tasklet = tasklet_handle.tasklet();
+
+        // SAFETY: This is safe because this function can be called only during system initialization
+        // and can't be interrupted.
+        critical_section::with(|_| unsafe {
+            let cyclic_execution = CYCLIC_EXECUTION_MANAGER
+                .create_cyclic_execution(tasklet.ptr(), period, offset)
+                .expect("Failed to create a cyclic execution");
+
+            tasklet
+                .subscribe(cyclic_execution)
+                .expect("Failed to subscribe tasklet to a cyclic exection");
+        });
+    }
+
+    /// Sets handling of missed activations of the cyclically executed tasklet.
+    ///
+    /// When the system is overloaded, whole periods of the cyclic tasklet can pass before it's
+    /// woken again. By default those missed activations are skipped. With
+    /// [catch-up policy](MissedActivationPolicy::CatchUp), tasklet is executed again for the
+    /// missed activations, up to the given limit. Missed activations are always counted, and given
+    /// event is emitted whenever they occur.
+    ///
+    /// # Generic Parameters
+    /// * `C` - Type of the structure with tasklet context data.
+    /// * `COND_COUNT` - Number of tasklet conditions.
+    ///
+    /// # Parameters
+    /// * `tasklet_handle` - Handle to the target tasklet.
+    /// * `policy` - Handling of missed activations.
+    /// * `event_handle` - Event emitted when activations are missed, `None` if shouldn't be emitted.
+    ///
+    /// # Example
+    /// ```
+    /// # use aerugo::{
+    /// #     Aerugo, Duration, EventStorage, InitApi, MissedActivationPolicy, RuntimeApi,
+    /// #     SystemHardwareConfig, TaskletConfig, TaskletStorage,
+    /// # };
+    /// # fn task(_: (), _: &mut (), _: &'static dyn RuntimeApi) {}
+    /// static TASK_STORAGE: TaskletStorage<(), (), 0> = TaskletStorage::new();
+    /// static MISSED_EVENT_STORAGE: EventStorage = EventStorage::new();
+    ///
+    /// fn main() {
+    /// #   let (aerugo, _) = Aerugo::initialize(SystemHardwareConfig::default());
+    ///     aerugo.create_tasklet(TaskletConfig::default(), task, &TASK_STORAGE);
+    ///     aerugo.create_event(1, &MISSED_EVENT_STORAGE);
+    ///
+    ///     let task_handle = TASK_STORAGE.create_handle().unwrap();
+    ///     let missed_event_handle = MISSED_EVENT_STORAGE.create_handle().unwrap();
+    ///
+    ///     aerugo.subscribe_tasklet_to_cyclic(&task_handle, Some(Duration::millis(10)), None);
+    ///     aerugo.set_missed_activation_policy(
+    ///         &task_handle,
+    ///         MissedActivationPolicy::CatchUp(3),
+    ///         Some(&missed_event_handle),
+    ///     );
+    /// }
+    /// ```
+    fn set_missed_activation_policy<C, const COND_COUNT: usize>(
+        &'static self,
+        tasklet_handle: &TaskletHandle<(), C, COND_COUNT>,
+        policy: MissedActivationPolicy,
+        event_handle: Option<&EventHandle>,
+    ) {
+        let tasklet = tasklet_handle.tasklet();
+        let event = event_handle.map(|handle| handle.event());
+
+        CYCLIC_EXECUTION_MANAGER
+            .set_missed_activation_policy(&tasklet.ptr(), policy, event)
+            .expect("Failed to set missed activation policy of a tasklet");
+    }
+
+    /// Locks phase of the cyclically executed tasklet to the external timebase.
+    ///
+    /// Activations of a cyclic tasklet are aligned to the system start. Devices sampling
+    /// synchronously need them aligned to an external sync signal instead, ex. CAN sync message or
+    /// GPS pulse-per-second. Interrupt handler receiving that signal calls
+    /// [sync_cyclic_execution](crate::api::RuntimeApi::sync_cyclic_execution) with its timestamp,
+    /// and next activation is shifted so the activations occur `phase` after the sync signal. Single
+    /// sync shifts activations by no more than `max_correction`, so a spurious sync signal can't
+    /// disturb them much. Shift backwards can make the next activation due immediately.
+    ///
+    /// # Generic Parameters
+    /// * `C` - Type of the structure with tasklet context data.
+    /// * `COND_COUNT` - Number of tasklet conditions.
+    ///
+    /// # Parameters
+    /// * `tasklet_handle` - Handle to the target tasklet.
+    /// * `phase` - Time from the sync signal to the activation.
+    /// * `max_correction` - Maximum shift of the activations on a single sync.
+    ///
+    /// # Example
+    /// ```
+    /// # use aerugo::{
+    /// #     Aerugo, Duration, InitApi, RuntimeApi, SystemHardwareConfig, TaskletConfig,
+    /// #     TaskletStorage,
+    /// # };
+    /// # fn sample(_: (), _: &mut (), _: &'static dyn RuntimeApi) {}
+    /// static SAMPLE_STORAGE: TaskletStorage<(), (), 0> = TaskletStorage::new();
+    ///
+    /// fn pps_interrupt_handler(aerugo: &'static Aerugo) {
+    ///     let sync_time = aerugo.get_system_time();
+    ///     let sample_handle = SAMPLE_STORAGE.create_handle().unwrap();
+    ///     let _locked = aerugo.sync_cyclic_execution(&sample_handle, sync_time);
+    /// }
+    ///
+    /// fn main() {
+    /// #   let (aerugo, _) = Aerugo::initialize(SystemHardwareConfig::default());
+    ///     aerugo.create_tasklet(TaskletConfig::default(), sample, &SAMPLE_STORAGE);
+    ///
+    ///     let sample_handle = SAMPLE_STORAGE.create_handle().unwrap();
+    ///
+    ///     aerugo.subscribe_tasklet_to_cyclic(&sample_handle, Some(Duration::millis(100)), None);
+    ///     aerugo.set_cyclic_phase_lock(
+    ///         &sample_handle,
+    ///         Duration::millis(10),
+    ///         Duration::micros(500),
+    ///     );
+    /// }
+    /// ```
+    fn set_cyclic_phase_lock<C, const COND_COUNT: usize>(
+        &'static self,
+        tasklet_handle: &TaskletHandle<(), C, COND_COUNT>,
+        phase: Duration,
+        max_correction: Duration,
+    ) {
+        let tasklet = tasklet_handle.tasklet();
+
+        CYCLIC_EXECUTION_MANAGER
+            .set_phase_lock(&tasklet.ptr(), phase, max_correction)
+            .expect("Failed to set phase lock of a tasklet");
+    }
+
+    /// Subscribes tasklet to the custom data provider.
+    ///
+    /// Custom providers allow implementing data sources outside of the system, like DMA ring
+    /// buffers or memory shared with another core. Provider has to follow the
+    /// [implementation contract](DataProvider#implementation-contract), and notify returned
+    /// receiver whenever new data becomes available.
+    ///
+    /// Each tasklet can be subscribed to at maximum one data provider.
+    ///
+    /// # Generic Parameters
+    /// * `T` - Type of the data.
+    /// * `C` - Type of the structure with tasklet context data.
+    /// * `COND_COUNT` - Number of tasklet conditions.
+    ///
+    /// # Parameters
+    /// * `tasklet_handle` - Handle to the target tasklet.
+    /// * `provider` - Custom data provider.
+    ///
+    /// # Return
+    /// Receiver which should be notified about new data.
+    ///
+    /// # Safety
+    /// This function shouldn't be called after the system was started, because subscription is safe
+    /// only before that.
+    ///
+    /// # Example
+    /// ```
+    /// # use aerugo::{
+    /// #     Aerugo, DataProvider, InitApi, Mutex, RuntimeApi, SystemHardwareConfig, TaskletConfig,
+    /// #     TaskletStorage,
+    /// # };
+    /// #
+    /// # fn task(_: u32, _: &mut (), _: &dyn RuntimeApi) {}
+    /// #
+    /// # static TASK_STORAGE: TaskletStorage<u32, (), 0> = TaskletStorage::new();
+    /// #
+    /// struct Sample(Mutex<Option<u32>>);
+    ///
+    /// impl DataProvider<u32> for Sample {
+    ///     fn get_data(&self) -> Option<u32> {
+    ///         self.0.lock(|sample| sample.take())
+    ///     }
+    ///
+    ///     fn data_waiting(&self) -> bool {
+    ///         self.0.lock(|sample| sample.is_some())
+    ///     }
+    ///
+    ///     fn flush(&self) -> usize {
+    ///         self.0.lock(|sample| sample.take().map_or(0, |_| 1))
+    ///     }
+    /// }
+    ///
+    /// static SAMPLE: Sample = Sample(Mutex::new(None));
+    ///
+    /// fn main() {
+    ///     # let (aerugo, _) = Aerugo::initialize(SystemHardwareConfig::default());
+    ///     #
+    ///     # aerugo.create_tasklet(TaskletConfig::default(), task, &TASK_STORAGE);
+    ///     #
+    ///     let task_handle = TASK_STORAGE.create_handle().unwrap();
+    ///
+    ///     let receiver = aerugo.subscribe_tasklet_to_provider(&task_handle, &SAMPLE);
+    ///
+    ///     SAMPLE.0.lock(|sample| sample.replace(42));
+    ///     receiver.notify();
+    /// }
+    /// ```
+    fn subscribe_tasklet_to_provider<T, C, const COND_COUNT: usize>(
+        &'static self,
+        tasklet_handle: &TaskletHandle<T, C, COND_COUNT>,
+        provider: &'static (dyn DataProvider<T> + Sync),
+    ) -> DataReceiver {
+        let tasklet = tasklet_handle.tasklet();
+
+        // SAFETY: This is safe because this function can be called only during system initialization
+        // and can't be interrupted.
+        critical_section::with(|_| unsafe {
+            tasklet
+                .subscribe(provider)
+                .expect("Failed to subscribe tasklet to a custom data provider");
+        });
+
+        DataReceiver::new(tasklet.ptr())
+    }
+
+    /// Chains consumer tasklet to the producer tasklet.
+    ///
+    /// Each executed step of the producer schedules the consumer. Completions are coalesced, so
+    /// the consumer is executed once even if the producer was executed multiple times before it.
+    /// Producer can have only one tasklet chained to it, and consumer has the chain as its data
+    /// provider, so it can't be subscribed to anything else.
+    ///
+    /// # Generic Parameters
+    /// * `T` - Type of the data processed by the producer.
+    /// * `C` - Type of the structure with producer context data.
+    /// * `D` - Type of the structure with consumer context data.
+    /// * `P_COND_COUNT` - Number of producer conditions.
+    /// * `C_COND_COUNT` - Number of consumer conditions.
+    ///
+    /// # Parameters
+    /// * `producer_handle` - Handle to the producer tasklet.
+    /// * `consumer_handle` - Handle to the consumer tasklet.
+    ///
+    /// # Safety
+    /// This function shouldn't be called after the system was started, because chaining is safe
+    /// only before that.
+    ///
+    /// # Example
+    /// ```
+    /// # use aerugo::{Aerugo, InitApi, RuntimeApi, SystemHardwareConfig, TaskletConfig, TaskletStorage};
+    /// #
+    /// fn acquire(_: (), _: &mut (), _: &dyn RuntimeApi) {}
+    /// fn process(_: (), _: &mut (), _: &dyn RuntimeApi) {}
+    ///
+    /// static ACQUIRE_STORAGE: TaskletStorage<(), (), 0> = TaskletStorage::new();
+    /// static PROCESS_STORAGE: TaskletStorage<(), (), 0> = TaskletStorage::new();
+    ///
+    /// fn main() {
+    ///     # let (aerugo, _) = Aerugo::initialize(SystemHardwareConfig::default());
+    ///     #
+    ///     # aerugo.create_tasklet(TaskletConfig::default(), acquire, &ACQUIRE_STORAGE);
+    ///     # aerugo.create_tasklet(TaskletConfig::default(), process, &PROCESS_STORAGE);
+    ///     #
+    ///     let acquire_handle = ACQUIRE_STORAGE.create_handle().unwrap();
+    ///     let process_handle = PROCESS_STORAGE.create_handle().unwrap();
+    ///
+    ///     aerugo.chain_tasklets(&acquire_handle, &process_handle);
+    /// }
+    /// ```
+    fn chain_tasklets<T, C, D, const P_COND_COUNT: usize, const C_COND_COUNT: usize>(
+        &'static self,
+        producer_handle: &TaskletHandle<T, C, P_COND_COUNT>,
+        consumer_handle: &TaskletHandle<(), D, C_COND_COUNT>,
+    ) {
+        let producer = producer_handle.tasklet();
+
+        self.chain_tasklets_with_output(producer_handle, consumer_handle, producer.completion());
+    }
+
+    /// Chains consumer tasklet to the producer tasklet, passing output of the producer to the
+    /// consumer.
+    ///
+    /// After each executed step of the producer, its output is derived from its context by the
+    /// chain, and the consumer is scheduled to process it. If the producer is executed multiple
+    /// times before the consumer, the consumer receives only the latest output. Producer can have
+    /// only one tasklet chained to it, and consumer has the chain as its data provider, so it can't
+    /// be subscribed to anything else.
+    ///
+    /// # Generic Parameters
+    /// * `T` - Type of the data processed by the producer.
+    /// * `C` - Type of the structure with producer context data.
+    /// * `U` - Type of the output, processed by the consumer.
+    /// * `D` - Type of the structure with consumer context data.
+    /// * `P_COND_COUNT` - Number of producer conditions.
+    /// * `C_COND_COUNT` - Number of consumer conditions.
+    ///
+    /// # Parameters
+    /// * `producer_handle` - Handle to the producer tasklet.
+    /// * `consumer_handle` - Handle to the consumer tasklet.
+    /// * `chain` - Chain deriving the output.
+    ///
+    /// # Safety
+    /// This function shouldn't be called after the system was started, because chaining is safe
+    /// only before that.
+    ///
+    /// # Example
+    /// ```
+    /// # use aerugo::{
+    /// #     Aerugo, InitApi, RuntimeApi, SystemHardwareConfig, TaskletChain, TaskletConfig,
+    /// #     TaskletStorage,
+    /// # };
+    /// #
+    /// #[derive(Default)]
+    /// struct FilterCtx {
+    ///     average: u32,
+    /// }
+    ///
+    /// fn filter(sample: u32, context: &mut FilterCtx, _: &dyn RuntimeApi) {
+    ///     context.average = (context.average + sample) / 2;
+    /// }
+    /// fn report(average: u32, _: &mut (), _: &dyn RuntimeApi) {}
+    ///
+    /// static FILTER_STORAGE: TaskletStorage<u32, FilterCtx, 0> = TaskletStorage::new();
+    /// static REPORT_STORAGE: TaskletStorage<u32, (), 0> = TaskletStorage::new();
+    /// static AVERAGE: TaskletChain<FilterCtx, u32> = TaskletChain::new(|context| context.average);
+    ///
+    /// fn main() {
+    ///     # let (aerugo, _) = Aerugo::initialize(SystemHardwareConfig::default());
+    ///     #
+    ///     # aerugo.create_tasklet(TaskletConfig::default(), filter, &FILTER_STORAGE);
+    ///     # aerugo.create_tasklet(TaskletConfig::default(), report, &REPORT_STORAGE);
+    ///     #
+    ///     let filter_handle = FILTER_STORAGE.create_handle().unwrap();
+    ///     let report_handle = REPORT_STORAGE.create_handle().unwrap();
+    ///
+    ///     aerugo.chain_tasklets_with_output(&filter_handle, &report_handle, &AVERAGE);
+    /// }
+    /// ```
+    fn chain_tasklets_with_output<
+        T,
+        C,
+        U: Send,
+        D,
+        const P_COND_COUNT: usize,
+        const C_COND_COUNT: usize,
+    >(
+        &'static self,
+        producer_handle: &TaskletHandle<T, C, P_COND_COUNT>,
+        consumer_handle: &TaskletHandle<U, D, C_COND_COUNT>,
+        chain: &'static TaskletChain<C, U>,
+    ) {
+        let producer = producer_handle.tasklet();
+        let consumer = consumer_handle.tasklet();
+
+        // SAFETY: This is safe because this function can be called only during system initialization
+        // and can't be interrupted.
+        critical_section::with(|_| unsafe {
+            consumer
+                .subscribe(chain)
+                .expect("Failed to subscribe tasklet to a tasklet chain");
+
+            chain
+                .connect(DataReceiver::new(consumer.ptr()))
+                .expect("Failed to connect tasklet to a tasklet chain");
+
+            producer
+                .set_successor(chain)
+                .expect("Failed to chain tasklets");
+        });
+    }
+
+    /// Sets tasklet condition set.
+    ///
+    /// Tasklet can use a set of BooleanConditions as a execution condition. Before tasklet is
+    /// scheduled and then executed it's condition is checked to verify whether this tasklet is
+    /// active. Tasklet will be woken when value of any of the condition in the set changes.
+    ///
+    /// # Generic Parameters
+    /// * `T` - Type of the data.
+    /// * `C` - Type of the structure with tasklet context data.
+    /// * `COND_COUNT` - Number of conditions.
+    ///
+    /// # Parameters
+    /// * `tasklet` - Handle to the target tasklet.
+    /// * `condition` - Set of conditions.
+    ///
+    /// # Return
+    /// `()` if successful, `InitError` otherwise.
+    ///
+    ///
+    /// # Safety
+    /// This function shouldn't be called after the system was started, because subscription is safe
+    /// only before that.
+    ///
+    /// # Example
+    /// ```
+    /// # use aerugo::{Aerugo, BooleanConditionSet, BooleanConditionSetType, BooleanConditionStorage, InitApi,
+    ///     RuntimeApi, SystemHardwareConfig, TaskletConfig, TaskletStorage};
+    /// #
+    /// # fn task(_: (), _: &mut (), _: &dyn RuntimeApi) {}
+    /// #
+    /// # static TASK_STORAGE: TaskletStorage<(), (), 2> = TaskletStorage::new();
+    /// # static CONDITION_X_STORAGE: BooleanConditionStorage = BooleanConditionStorage::new();
+    /// # static CONDITION_Y_STORAGE: BooleanConditionStorage = BooleanConditionStorage::new();
+    /// #
+    /// fn main() {
+    ///     # let (aerugo, _) = Aerugo::initialize(SystemHardwareConfig::default());
+    ///     # let task_config = TaskletConfig::default();
+    ///     #
+    ///     # aerugo.create_tasklet(TaskletConfig::default(), task, &TASK_STORAGE);
+    ///     # aerugo.create_boolean_condition(true, &CONDITION_X_STORAGE);
+    ///     # aerugo.create_boolean_condition(true, &CONDITION_Y_STORAGE);
+    ///     #
+    ///     let task_handle = TASK_STORAGE.create_handle().unwrap();
+    ///     let condition_x_handle = CONDITION_X_STORAGE.create_handle().unwrap();
+    ///     let condition_y_handle = CONDITION_Y_STORAGE.create_handle().unwrap();
+    ///
+    ///     let mut condition_set = BooleanConditionSet::<2>::new(BooleanConditionSetType::And);
+    ///     condition_set.add(&condition_x_handle);
+    ///     condition_set.add(&condition_y_handle);
+    ///
+    ///     aerugo.set_tasklet_conditions(&task_handle, condition_set);
+    /// }
+    fn set_tasklet_conditions<T, C, const COND_COUNT: usize>(
+        &'static self,
+        tasklet_handle: &TaskletHandle<T, C, COND_COUNT>,
+        condition_set: BooleanConditionSet<COND_COUNT>,
+    ) {
+        let tasklet = tasklet_handle.tasklet();
+
+        // SAFETY: This is safe because this function can be called only during system initialization
+        // and can't be interrupted.
+        critical_section::with(|_| unsafe {
+            condition_set
+                .register_tasklet(tasklet.ptr())
+                .expect("Failed to register a tasklet in a condition set");
+
+            tasklet
+                .set_condition_set(condition_set)
+                .expect("Failed to set a condition set for tasklet");
+        });
+    }
+
+    fn set_execution_time_exceeded_maximum_event(
+        &'static self,
+        event_handle: &EventHandle,
+        time: Duration,
+    ) {
+        unsafe {
+            EXECUTION_MONITOR
+                .set_time_exceeded_event(event_handle.event(), time)
+                .expect("Failed to set event for exceeding maximum tasklet execution time.")
+        };
+    }
+
+    fn set_deadline_miss_handler(&'static self, handler: DeadlineMissHandler) {
+        unsafe {
+            EXECUTION_MONITOR
+                .set_deadline_miss_handler(handler)
+                .expect("Failed to set tasklet deadline miss handler.")
+        };
+    }
+
+    fn set_overload_handler(&'static self, handler: DeadlineMissHandler) {
+        unsafe {
+            EXECUTION_MONITOR
+                .set_overload_handler(handler)
+                .expect("Failed to set system overload handler.")
+        };
+    }
+
+    fn set_cpu_load_window(&'static self, window: Duration) {
+        // SAFETY: This is safe because this function can be called only during system initialization
+        // and can't be interrupted.
+        critical_section::with(|_| unsafe { EXECUTION_MONITOR.set_cpu_load_window(window) });
+    }
+
+    fn set_firmware_info(&'static self, info: &'static FirmwareInfo) {
+        let verification = FirmwareInfo::verify(info);
+
+        critical_section::with(|_| {
+            self.firmware_info
+                .set(verification)
+                .map_err(|_| SystemError::FirmwareInfoAlreadySet)
+                .expect("Failed to set firmware info")
+        });
+    }
+
+    /// Adds partition window at the end of the major frame.
+    ///
+    /// Windows are executed in the order they were added. After the last window, the major frame
+    /// starts again from the first one. In each window only tasklets assigned to the window's
+    /// partition (and tasklets not assigned to any partition) can be executed.
+    ///
+    /// Maximum number of windows is configured with `AERUGO_PARTITION_WINDOW_COUNT` environmental
+    /// variable.
+    ///
+    /// # Parameters
+    /// * `window` - Window to add.
+    ///
+    /// # Safety
+    /// This function shouldn't be called after the system was started, because windows list is
+    /// safe to modify only before that.
+    ///
+    /// # Example
+    /// ```
+    /// # use aerugo::{Aerugo, Duration, InitApi, PartitionId, PartitionWindow, SystemHardwareConfig};
+    /// #
+    /// fn main() {
+    ///     # let (aerugo, _) = Aerugo::initialize(SystemHardwareConfig::default());
+    ///     #
+    ///     aerugo.add_partition_window(PartitionWindow {
+    ///         partition: PartitionId(0),
+    ///         duration: Duration::millis(20),
+    ///     });
+    ///     aerugo.add_partition_window(PartitionWindow {
+    ///         partition: PartitionId(1),
+    ///         duration: Duration::millis(30),
+    ///     });
+    /// }
+    /// ```
+    fn add_partition_window(&'static self, window: PartitionWindow) {
+        // SAFETY: This is safe because this function can be called only during system initialization
+        // and can't be interrupted.
+        critical_section::with(|_| unsafe {
+            PARTITION_MANAGER
+                .add_window(window)
+                .expect("Failed to add a partition window")
+        });
+    }
+
+    /// Assigns tasklet to a partition.
+    ///
+    /// Assigned tasklet will be executed only in its partition windows. Tasklet is not started if
+    /// its longest observed execution time doesn't fit in the time left in the window.
+    ///
+    /// Each tasklet can be assigned to at maximum one partition.
+    ///
+    /// # Generic Parameters
+    /// * `T` - Type of the data.
+    /// * `C` - Type of the structure with tasklet context data.
+    /// * `COND_COUNT` - Number of conditions.
+    ///
+    /// # Parameters
+    /// * `tasklet` - Handle to the target tasklet.
+    /// * `partition` - Partition to assign tasklet to.
+    ///
+    /// # Safety
+    /// This function shouldn't be called after the system was started, because assignment is safe
+    /// only before that.
+    fn assign_tasklet_to_partition<T, C, const COND_COUNT: usize>(
+        &'static self,
+        tasklet_handle: &TaskletHandle<T, C, COND_COUNT>,
+        partition: PartitionId,
+    ) {
+        let tasklet = tasklet_handle.tasklet();
+
+        // SAFETY: This is safe because this function can be called only during system initialization
+        // and can't be interrupted.
+        critical_section::with(|_| unsafe {
+            PARTITION_MANAGER
+                .assign_tasklet(tasklet.ptr(), partition)
+                .expect("Failed to assign tasklet to a partition")
+        });
+    }
+
+    /// Configures time-triggered schedule table.
+    ///
+    /// Major frame of the schedule table consists of `minor_frame_count` minor frames of equal
+    /// duration, and is repeated for the whole system lifetime. Tasklets are assigned to slots in
+    /// minor frames with [subscribe_tasklet_to_schedule](InitApi::subscribe_tasklet_to_schedule).
+    ///
+    /// # Parameters
+    /// * `config` - Schedule table configuration.
+    ///
+    /// # Safety
+    /// This function shouldn't be called after the system was started, because configuration is
+    /// safe to modify only before that.
+    ///
+    /// # Example
+    /// ```
+    /// # use aerugo::{Aerugo, Duration, InitApi, ScheduleTableConfig, SystemHardwareConfig};
+    /// #
+    /// fn main() {
+    ///     # let (aerugo, _) = Aerugo::initialize(SystemHardwareConfig::default());
+    ///     #
+    ///     aerugo.configure_schedule_table(ScheduleTableConfig {
+    ///         minor_frame: Duration::millis(25),
+    ///         minor_frame_count: 4,
+    ///     });
+    /// }
+    /// ```
+    fn configure_schedule_table(&'static self, config: ScheduleTableConfig) {
+        // SAFETY: This is safe because this function can be called only during system initialization
+        // and can't be interrupted.
+        critical_section::with(|_| unsafe {
+            SCHEDULE_TABLE_MANAGER
+                .configure(config)
+                .expect("Failed to configure the schedule table")
+        });
+    }
+
+    /// Subscribes tasklet to the schedule table.
+    ///
+    /// Tasklet is woken up once at the start of each of its slots, so its executions are fully
+    /// determined by the schedule table. Slots have to fit in their minor frames and can't overlap
+    /// with other slots, which is checked when the system is started. As the executor is
+    /// cooperative, tasklet isn't stopped at the end of its slot. Executions that end after the
+    /// slot window are counted, and can be read with
+    /// [get_schedule_overrun_count](crate::api::RuntimeApi::get_schedule_overrun_count).
+    ///
+    /// # Generic Parameters
+    /// * `C` - Type of the structure with tasklet context data.
+    /// * `COND_COUNT` - Number of conditions.
+    ///
+    /// # Parameters
+    /// * `tasklet` - Handle to the target tasklet.
+    /// * `slots` - Slots in which tasklet is executed.
+    ///
+    /// # Safety
+    /// This function shouldn't be called after the system was started, because subscription is
+    /// safe only before that.
+    ///
+    /// # Example
+    /// ```
+    /// # use aerugo::{
+    /// #     Aerugo, Duration, InitApi, RuntimeApi, ScheduleSlot, ScheduleTableConfig,
+    /// #     SystemHardwareConfig, TaskletConfig, TaskletStorage,
+    /// # };
+    /// #
+    /// # fn task(_: (), _: &mut (), _: &'static dyn RuntimeApi) {}
+    /// #
+    /// static TASK_STORAGE: TaskletStorage<(), (), 0> = TaskletStorage::new();
+    /// static TASK_SLOTS: [ScheduleSlot; 2] = [
+    ///     ScheduleSlot {
+    ///         minor_frame: 0,
+    ///         offset: Duration::millis(5),
+    ///         window: Duration::millis(10),
+    ///     },
+    ///     ScheduleSlot {
+    ///         minor_frame: 2,
+    ///         offset: Duration::millis(5),
+    ///         window: Duration::millis(10),
+    ///     },
+    /// ];
+    ///
+    /// fn main() {
+    ///     # let (aerugo, _) = Aerugo::initialize(SystemHardwareConfig::default());
+    ///     #
+    ///     aerugo.configure_schedule_table(ScheduleTableConfig {
+    ///         minor_frame: Duration::millis(25),
+    ///         minor_frame_count: 4,
+    ///     });
+    ///
+    ///     aerugo.create_tasklet(TaskletConfig::default(), task, &TASK_STORAGE);
+    ///     let task_handle = TASK_STORAGE.create_handle().unwrap();
+    ///
+    ///     aerugo.subscribe_tasklet_to_schedule(&task_handle, &TASK_SLOTS);
+    /// }
+    /// ```
+    fn subscribe_tasklet_to_schedule<C, const COND_COUNT: usize>(
+        &'static self,
+        tasklet_handle: &TaskletHandle<(), C, COND_COUNT>,
+        slots: &'static [ScheduleSlot],
+    ) {
+        let tasklet = tasklet_handle.tasklet();
+
+        // SAFETY: This is safe because this function can be called only during system initialization
+        // and can't be interrupted.
+        critical_section::with(|_| unsafe {
+            let scheduled_tasklet = SCHEDULE_TABLE_MANAGER
+                .create_scheduled_tasklet(tasklet.ptr(), slots)
+                .expect("Failed to subscribe tasklet to the schedule table");
+
+            tasklet
+                .subscribe(scheduled_tasklet)
+                .expect("Failed to subscribe tasklet to the schedule table");
+        });
+    }
+
+    /// Creates new bridge forwarding messages from one queue to another.
+    ///
+    /// Bridge is a tasklet subscribed to the source queue, created with the name and priority
+    /// given in the configuration. Each message taken from the source queue is converted with the
+    /// transform function and sent to the destination queue. Messages are dropped if transform
+    /// function discards them, if they exceed the rate limit or if the destination queue is full.
+    ///
+    /// Bridge tasklet counts towards `AERUGO_TASKLET_COUNT` limit.
+    ///
+    /// # Generic Parameters
+    /// * `T` - Type of the messages in the source queue.
+    /// * `U` - Type of the messages in the destination queue.
+    /// * `N` - Size of the source queue.
+    /// * `M` - Size of the destination queue.
+    ///
+    /// # Parameters
+    /// * `config` - Bridge configuration.
+    /// * `source` - Handle to the source queue.
+    /// * `destination` - Handle to the destination queue.
+    /// * `storage` - Static memory storage where the bridge should be allocated.
+    ///
+    /// # Safety
+    /// This function shouldn't be called after the system was started, because bridge tasklet is
+    /// safe to create only before that.
+    ///
+    /// # Example
+    /// ```
+    /// # use aerugo::{
+    /// #     Aerugo, InitApi, MessageQueueStorage, QueueBridgeConfig, QueueBridgeStorage, RateLimit,
+    /// #     SystemHardwareConfig,
+    /// # };
+    /// # use aerugo::Duration;
+    /// #
+    /// static RAW_QUEUE: MessageQueueStorage<u16, 8> = MessageQueueStorage::new();
+    /// static SCALED_QUEUE: MessageQueueStorage<f32, 8> = MessageQueueStorage::new();
+    /// static SCALING_BRIDGE: QueueBridgeStorage<u16, f32, 8> = QueueBridgeStorage::new();
+    ///
+    /// fn scale(raw: u16) -> Option<f32> {
+    ///     (raw != u16::MAX).then(|| raw as f32 * 0.01)
+    /// }
+    ///
+    /// fn main() {
+    ///     # let (aerugo, _) = Aerugo::initialize(SystemHardwareConfig::default());
+    ///     aerugo.create_message_queue(&RAW_QUEUE);
+    ///     aerugo.create_message_queue(&SCALED_QUEUE);
+    ///
+    ///     let config = QueueBridgeConfig {
+    ///         rate_limit: Some(RateLimit {
+    ///             max_count: 10,
+    ///             period: Duration::millis(100),
+    ///         }),
+    ///         ..QueueBridgeConfig::with_transform("ScalingBridge", scale)
+    ///     };
+    ///     aerugo.create_queue_bridge(
+    ///         config,
+    ///         &RAW_QUEUE.create_handle().unwrap(),
+    ///         &SCALED_QUEUE.create_handle().unwrap(),
+    ///         &SCALING_BRIDGE,
+    ///     );
+    ///     #
+    ///     # assert!(SCALING_BRIDGE.is_initialized());
+    /// }
+    /// ```
+    fn create_queue_bridge<T, U, const N: usize, const M: usize>(
+        &'static self,
+        config: QueueBridgeConfig<T, U>,
+        source: &MessageQueueHandle<T, N>,
+        destination: &MessageQueueHandle<U, M>,
+        storage: &'static QueueBridgeStorage<T, U, M>,
+    ) {
+        let tasklet_config = TaskletConfig {
+            name: config.name,
+            priority: config.priority,
+            deadline: None,
+            yield_budget: None,
+            finalize: None,
+        };
+        let destination = MessageQueueHandle::new(destination.queue());
+        let bridge = QueueBridge::new(&config, destination, storage.stats());
+        let tasklet_storage = storage.tasklet_storage();
+
+        self.create_tasklet_with_context(tasklet_config, bridge_step, bridge, tasklet_storage);
+
+        let tasklet_handle = tasklet_storage
+            .create_handle()
+            .expect("Failed to create handle to the bridge tasklet");
+        self.subscribe_tasklet_to_queue(&tasklet_handle, source);
+    }
+
+    /// Creates new aggregator sending periodic aggregates of the samples from one queue to another.
+    ///
+    /// Aggregator is a tasklet subscribed to the source queue, created with the name and priority
+    /// given in the configuration. Samples received within each window are aggregated, and the
+    /// [aggregate](crate::Aggregate) is sent to the destination queue when the first sample from
+    /// one of the following windows is received. Aggregates that don't fit in the destination
+    /// queue are dropped.
+    ///
+    /// Aggregator tasklet counts towards `AERUGO_TASKLET_COUNT` limit.
+    ///
+    /// # Generic Parameters
+    /// * `T` - Type of the samples.
+    /// * `N` - Size of the source queue.
+    /// * `M` - Size of the destination queue.
+    ///
+    /// # Parameters
+    /// * `config` - Aggregator configuration.
+    /// * `source` - Handle to the queue with samples.
+    /// * `destination` - Handle to the queue for aggregates.
+    /// * `storage` - Static memory storage where the aggregator should be allocated.
+    ///
+    /// # Safety
+    /// This function shouldn't be called after the system was started, because aggregator tasklet
+    /// is safe to create only before that.
+    ///
+    /// # Example
+    /// ```
+    /// # use aerugo::{
+    /// #     Aerugo, Aggregate, AggregatorConfig, AggregatorStorage, InitApi, MessageQueueStorage,
+    /// #     SystemHardwareConfig,
+    /// # };
+    /// # use aerugo::Duration;
+    /// #
+    /// static GYRO_QUEUE: MessageQueueStorage<i16, 16> = MessageQueueStorage::new();
+    /// static GYRO_TELEMETRY_QUEUE: MessageQueueStorage<Aggregate<i16>, 2> = MessageQueueStorage::new();
+    /// static GYRO_AGGREGATOR: AggregatorStorage<i16, 2> = AggregatorStorage::new();
+    ///
+    /// fn main() {
+    ///     # let (aerugo, _) = Aerugo::initialize(SystemHardwareConfig::default());
+    ///     aerugo.create_message_queue(&GYRO_QUEUE);
+    ///     aerugo.create_message_queue(&GYRO_TELEMETRY_QUEUE);
+    ///
+    ///     let config = AggregatorConfig {
+    ///         name: "GyroAggregator",
+    ///         window: Duration::secs(1),
+    ///         ..Default::default()
+    ///     };
+    ///     aerugo.create_aggregator(
+    ///         config,
+    ///         &GYRO_QUEUE.create_handle().unwrap(),
+    ///         &GYRO_TELEMETRY_QUEUE.create_handle().unwrap(),
+    ///         &GYRO_AGGREGATOR,
+    ///     );
+    ///     #
+    ///     # assert!(GYRO_AGGREGATOR.is_initialized());
+    /// }
+    /// ```
+    fn create_aggregator<T: Copy + PartialOrd + Into<f64>, const N: usize, const M: usize>(
+        &'static self,
+        config: AggregatorConfig,
+        source: &MessageQueueHandle<T, N>,
+        destination: &MessageQueueHandle<Aggregate<T>, M>,
+        storage: &'static AggregatorStorage<T, M>,
+    ) {
+        let tasklet_config = TaskletConfig {
+            name: config.name,
+            priority: config.priority,
+            deadline: None,
+            yield_budget: None,
+            finalize: None,
+        };
+        let destination = MessageQueueHandle::new(destination.queue());
+        let aggregator = Aggregator::new(&config, destination, storage.dropped());
+        let tasklet_storage = storage.tasklet_storage();
+
+        self.create_tasklet_with_context(
+            tasklet_config,
+            aggregator_step,
+            aggregator,
+            tasklet_storage,
+        );
+
+        let tasklet_handle = tasklet_storage
+            .create_handle()
+            .expect("Failed to create handle to the aggregator tasklet");
+        self.subscribe_tasklet_to_queue(&tasklet_handle, source);
+    }
+
+    /// Publishes fields of the tasklet context in telemetry.
+    ///
+    /// Context is sampled right after the step of the tasklet, at most once per given period, and
+    /// a [TelemetrySample](crate::TelemetrySample) with the current value of each field reported by
+    /// [Telemetry](crate::Telemetry) implementation of the context is sent to the destination
+    /// queue. Samples that don't fit in the queue are dropped and counted in the storage. Context
+    /// changes only during tasklet steps, so no samples are sent while the tasklet isn't executed.
+    ///
+    /// # Generic Parameters
+    /// * `T` - Type of the data.
+    /// * `C` - Type of the structure with tasklet context data.
+    /// * `COND_COUNT` - Number of conditions.
+    /// * `N` - Size of the destination queue.
+    ///
+    /// # Parameters
+    /// * `tasklet_handle` - Handle to the sampled tasklet.
+    /// * `period` - Minimum time between samples.
+    /// * `destination` - Handle to the queue for samples.
+    /// * `storage` - Static memory storage where the telemetry publisher should be allocated.
+    ///
+    /// # Safety
+    /// This is unsafe, because it mutably borrows the storage and the tasklet. This is safe to
+    /// call before the system initialization.
+    ///
+    /// # Example
+    /// ```
+    /// # use aerugo::{
+    /// #     Aerugo, AerugoTelemetry, Duration, InitApi, MessageQueueStorage, RuntimeApi,
+    /// #     SystemHardwareConfig, TaskletConfig, TaskletStorage, TelemetrySample, TelemetryStorage,
+    /// # };
+    /// #[derive(Default, AerugoTelemetry)]
+    /// struct ThermalControlContext {
+    ///     #[telemetry]
+    ///     temperature: f32,
+    ///     #[telemetry]
+    ///     heater_on: bool,
+    /// }
+    ///
+    /// fn thermal_control(_: (), context: &mut ThermalControlContext, _: &'static dyn RuntimeApi) {
+    ///     context.heater_on = context.temperature < 20.0;
+    /// }
+    ///
+    /// static THERMAL_CONTROL_STORAGE: TaskletStorage<(), ThermalControlContext, 0> =
+    ///     TaskletStorage::new();
+    /// static TELEMETRY_QUEUE: MessageQueueStorage<TelemetrySample, 16> = MessageQueueStorage::new();
+    /// static THERMAL_CONTROL_TELEMETRY: TelemetryStorage<16> = TelemetryStorage::new();
+    ///
+    /// fn main() {
+    ///     # let (aerugo, _) = Aerugo::initialize(SystemHardwareConfig::default());
+    ///     aerugo.create_tasklet(
+    ///         TaskletConfig::default(),
+    ///         thermal_control,
+    ///         &THERMAL_CONTROL_STORAGE,
+    ///     );
+    ///     aerugo.create_message_queue(&TELEMETRY_QUEUE);
+    ///
+    ///     let thermal_control = THERMAL_CONTROL_STORAGE.create_handle().unwrap();
+    ///     let telemetry_queue = TELEMETRY_QUEUE.create_handle().unwrap();
+    ///
+    ///     aerugo.publish_context_telemetry(
+    ///         &thermal_control,
+    ///         Duration::secs(1),
+    ///         &telemetry_queue,
+    ///         &THERMAL_CONTROL_TELEMETRY,
+    ///     );
+    /// }
+    /// ```
+    fn publish_context_telemetry<T, C: Telemetry, const COND_COUNT: usize, const N: usize>(
+        &'static self,
+        tasklet_handle: &TaskletHandle<T, C, COND_COUNT>,
+        period: Duration,
+        destination: &MessageQueueHandle<TelemetrySample, N>,
+        storage: &'static TelemetryStorage<N>,
+    ) {
+        let telemetry = ContextTelemetry::new(
+            tasklet_handle.get_id(),
+            period,
+            MessageQueueHandle::new(destination.queue()),
+            storage.dropped(),
+        );
+        let tasklet = tasklet_handle.tasklet();
+
+        // SAFETY: This is safe because this function can be called only during system initialization
+        // and can't be interrupted.
+        critical_section::with(|_| unsafe {
+            let telemetry = storage
+                .init(telemetry)
+                .expect("Failed to initialize context telemetry storage");
+            tasklet
+                .set_telemetry(telemetry)
+                .expect("Failed to set context telemetry publisher");
+        });
+    }
+
+    /// Creates new bus manager executing transactions from the queue on the shared bus.
+    ///
+    /// Bus manager is a tasklet subscribed to the request queue, created with the name and
+    /// priority given in the configuration. It takes ownership of the bus driver, and executes
+    /// received transactions one at the time. Before the transfer, bus is configured for the
+    /// target device if it was configured for another one, and the device is selected. Response
+    /// with the read data or the error is sent to the reply queue of the transaction, if it has
+    /// one. Transaction with a [timeout](crate::BusTransaction::with_timeout) that fails after the
+    /// timeout expired returns [BusError::Timeout](crate::BusError::Timeout), and the bus is
+    /// [recovered](crate::BusDriver::recover) before the next one. Transactions are received by reference, so they are not copied out of the request
+    /// queue.
+    ///
+    /// Bus manager tasklet counts towards `AERUGO_TASKLET_COUNT` limit.
+    ///
+    /// # Generic Parameters
+    /// * `B` - Type of the bus driver.
+    /// * `N` - Size of the request queue.
+    /// * `L` - Size of the transaction buffer.
+    ///
+    /// # Parameters
+    /// * `config` - Bus manager configuration.
+    /// * `driver` - Bus driver.
+    /// * `requests` - Handle to the queue with transactions.
+    /// * `storage` - Static memory storage where the bus manager should be allocated.
+    ///
+    /// # Safety
+    /// This function shouldn't be called after the system was started, because bus manager tasklet
+    /// is safe to create only before that.
+    ///
+    /// # Example
+    /// ```
+    /// # use aerugo::{
+    /// #     Aerugo, BusDeadline, BusDriver, BusManagerConfig, BusManagerStorage, BusResponse,
+    /// #     BusTransaction, Duration, InitApi, MessageQueueStorage, SystemHardwareConfig,
+    /// # };
+    /// #
+    /// struct SpiDevice {
+    ///     chip_select: u8,
+    ///     frequency_hz: u32,
+    /// }
+    ///
+    /// struct Spi;
+    ///
+    /// impl BusDriver for Spi {
+    ///     type DeviceConfig = SpiDevice;
+    ///     type Error = ();
+    ///
+    ///     fn configure(&mut self, device: &SpiDevice) -> Result<(), ()> {
+    ///         // Set clock frequency.
+    ///         Ok(())
+    ///     }
+    ///
+    ///     fn transfer(
+    ///         &mut self,
+    ///         device: &SpiDevice,
+    ///         write: &[u8],
+    ///         read: &mut [u8],
+    ///         deadline: &BusDeadline,
+    ///     ) -> Result<(), ()> {
+    ///         // Transfer data with device chip select asserted, until the deadline expires.
+    ///         Ok(())
+    ///     }
+    /// }
+    ///
+    /// const IMU: usize = 0;
+    /// static DEVICES: [SpiDevice; 1] = [SpiDevice { chip_select: 1, frequency_hz: 1_000_000 }];
+    ///
+    /// static SPI_REQUESTS: MessageQueueStorage<BusTransaction<(), 16>, 4> = MessageQueueStorage::new();
+    /// static IMU_REPLIES: MessageQueueStorage<BusResponse<(), 16>, 2> = MessageQueueStorage::new();
+    /// static SPI_MANAGER: BusManagerStorage<Spi, 16> = BusManagerStorage::new();
+    ///
+    /// fn main() {
+    ///     # let (aerugo, _) = Aerugo::initialize(SystemHardwareConfig::default());
+    ///     aerugo.create_message_queue(&SPI_REQUESTS);
+    ///     aerugo.create_message_queue(&IMU_REPLIES);
+    ///
+    ///     let config = BusManagerConfig {
+    ///         name: "SpiManager",
+    ///         priority: 2,
+    ///         devices: &DEVICES,
+    ///     };
+    ///     let requests = SPI_REQUESTS.create_handle().unwrap();
+    ///     aerugo.create_bus_manager(config, Spi, &requests, &SPI_MANAGER);
+    ///     #
+    ///     # assert!(SPI_MANAGER.is_initialized());
+    ///
+    ///     // Read WHO_AM_I register of the IMU.
+    ///     let transaction = BusTransaction::new(IMU, &[0x8F], 1)
+    ///         .unwrap()
+    ///         .with_timeout(Duration::millis(2))
+    ///         .with_reply_queue(IMU_REPLIES.create_handle().unwrap());
+    ///     requests.send_data(transaction).unwrap();
+    /// }
+    /// ```
+    fn create_bus_manager<B: BusDriver, const N: usize, const L: usize>(
+        &'static self,
+        config: BusManagerConfig<B>,
+        driver: B,
+        requests: &MessageQueueHandle<BusTransaction<B::Error, L>, N>,
+        storage: &'static BusManagerStorage<B, L>,
+    ) {
+        let tasklet_config = TaskletConfig {
+            name: config.name,
+            priority: config.priority,
+            deadline: None,
+            yield_budget: None,
+            finalize: None,
+        };
+        let manager = BusManager::new(&config, driver, storage.stats(), &self.time_source);
+        let tasklet_storage = storage.tasklet_storage();
+
+        self.create_tasklet_by_reference_with_context(
+            tasklet_config,
+            bus_step,
+            manager,
+            tasklet_storage,
+        );
+
+        let tasklet_handle = tasklet_storage
+            .create_handle()
+            .expect("Failed to create handle to the bus manager tasklet");
+        self.subscribe_tasklet_to_queue(&tasklet_handle, requests);
+    }
+
+    /// Creates new limit monitor checking values from the queue.
+    ///
+    /// Limit monitor is a tasklet subscribed to the source queue, created with the name and
+    /// priority given in the configuration. Each received value is checked against warning and
+    /// critical limits. When [severity](crate::Severity) of the value changes, event configured for
+    /// the new severity is emitted, and if limits are violated, fault record is stored in the fault
+    /// recorder. Severity is lowered only after the value gets back within the limits by the
+    /// hysteresis margin.
+    ///
+    /// Limit monitor tasklet counts towards `AERUGO_TASKLET_COUNT` limit.
+    ///
+    /// # Generic Parameters
+    /// * `T` - Type of the monitored value.
+    /// * `N` - Size of the source queue.
+    ///
+    /// # Parameters
+    /// * `config` - Limit monitor configuration.
+    /// * `source` - Handle to the queue with monitored values.
+    /// * `storage` - Static memory storage where the limit monitor should be allocated.
+    ///
+    /// # Safety
+    /// This function shouldn't be called after the system was started, because limit monitor
+    /// tasklet is safe to create only before that.
+    ///
+    /// # Example
+    /// ```
+    /// # use aerugo::{
+    /// #     Aerugo, EventId, InitApi, LimitLevel, LimitMonitorConfig, LimitMonitorStorage,
+    /// #     MessageQueueStorage, Severity, SystemHardwareConfig,
+    /// # };
+    /// #
+    /// static TEMPERATURE_QUEUE: MessageQueueStorage<i16, 4> = MessageQueueStorage::new();
+    /// static TEMPERATURE_MONITOR: LimitMonitorStorage<i16> = LimitMonitorStorage::new();
+    ///
+    /// const OVERHEAT_EVENT: EventId = 1;
+    ///
+    /// fn main() {
+    ///     # let (aerugo, _) = Aerugo::initialize(SystemHardwareConfig::default());
+    ///     aerugo.create_message_queue(&TEMPERATURE_QUEUE);
+    ///
+    ///     let config = LimitMonitorConfig {
+    ///         name: "TemperatureMonitor",
+    ///         priority: 1,
+    ///         warning: LimitLevel {
+    ///             fault_code: 100,
+    ///             ..LimitLevel::new(-10, 40)
+    ///         },
+    ///         critical: LimitLevel {
+    ///             event: Some(OVERHEAT_EVENT),
+    ///             fault_code: 101,
+    ///             ..LimitLevel::new(-20, 60)
+    ///         },
+    ///         hysteresis: 2,
+    ///         nominal_event: None,
+    ///     };
+    ///     aerugo.create_limit_monitor(
+    ///         config,
+    ///         &TEMPERATURE_QUEUE.create_handle().unwrap(),
+    ///         &TEMPERATURE_MONITOR,
+    ///     );
+    ///     #
+    ///     # assert!(TEMPERATURE_MONITOR.is_initialized());
+    ///     # assert_eq!(TEMPERATURE_MONITOR.severity(), Severity::Nominal);
+    /// }
+    /// ```
+    fn create_limit_monitor<T, const N: usize>(
+        &'static self,
+        config: LimitMonitorConfig<T>,
+        source: &MessageQueueHandle<T, N>,
+        storage: &'static LimitMonitorStorage<T>,
+    ) where
+        T: Copy + PartialOrd + Add<Output = T> + Sub<Output = T>,
+    {
+        let tasklet_config = TaskletConfig {
+            name: config.name,
+            priority: config.priority,
+            deadline: None,
+            yield_budget: None,
+            finalize: None,
+        };
+        let monitor = LimitMonitor::new(config, storage.severity_cell());
+        let tasklet_storage = storage.tasklet_storage();
+
+        self.create_tasklet_with_context(
+            tasklet_config,
+            limit_monitor_step,
+            monitor,
+            tasklet_storage,
+        );
+
+        let tasklet_handle = tasklet_storage
+            .create_handle()
+            .expect("Failed to create handle to the limit monitor tasklet");
+        self.subscribe_tasklet_to_queue(&tasklet_handle, source);
+    }
+
+    /// Creates new FDIR engine, mapping fault events to recovery actions.
+    ///
+    /// FDIR engine is a tasklet subscribed to the trigger events of all its rules, created with the
+    /// name and priority given in the configuration. When one of these events is emitted, the
+    /// engine executes recovery action of the corresponding [rule](crate::FdirRule), escalating to
+    /// the next action each time the rule is triggered again within the escalation timeout.
+    ///
+    /// FDIR engine tasklet counts towards `AERUGO_TASKLET_COUNT` limit, and all trigger events have
+    /// to be created before the engine.
+    ///
+    /// # Generic Parameters
+    /// * `RULE_COUNT` - Number of rules.
+    ///
+    /// # Parameters
+    /// * `config` - FDIR engine configuration.
+    /// * `storage` - Static memory storage where the FDIR engine should be allocated.
+    ///
+    /// # Safety
+    /// This function shouldn't be called after the system was started, because FDIR engine
+    /// tasklet is safe to create only before that.
+    ///
+    /// # Example
+    /// ```
+    /// # use aerugo::{
+    /// #     Aerugo, Duration, EventId, EventStorage, FdirConfig, FdirRule, FdirStorage, InitApi,
+    /// #     RateLimit, RecoveryAction, RuntimeApi, SystemHardwareConfig,
+    /// # };
+    /// #
+    /// const UART_TIMEOUT_EVENT: EventId = 1;
+    /// const RESTART_COMM_EVENT: EventId = 2;
+    ///
+    /// static UART_TIMEOUT_EVENT_STORAGE: EventStorage = EventStorage::new();
+    /// static RESTART_COMM_EVENT_STORAGE: EventStorage = EventStorage::new();
+    /// static FDIR: FdirStorage<1> = FdirStorage::new();
+    ///
+    /// fn power_cycle_uart(_api: &'static dyn RuntimeApi) {
+    ///     // Reset and reconfigure the UART peripheral.
+    /// }
+    ///
+    /// static UART_TIMEOUT_ACTIONS: [RecoveryAction; 3] = [
+    ///     RecoveryAction::EmitEvent(RESTART_COMM_EVENT),
+    ///     RecoveryAction::Call(power_cycle_uart),
+    ///     RecoveryAction::Reset,
+    /// ];
+    ///
+    /// static FDIR_RULES: [FdirRule; 1] = [FdirRule {
+    ///     trigger: UART_TIMEOUT_EVENT,
+    ///     actions: &UART_TIMEOUT_ACTIONS,
+    ///     escalation_timeout: Duration::secs(60),
+    ///     rate_limit: Some(RateLimit {
+    ///         max_count: 1,
+    ///         period: Duration::secs(5),
+    ///     }),
+    /// }];
+    ///
+    /// fn main() {
+    ///     # let (aerugo, _) = Aerugo::initialize(SystemHardwareConfig::default());
+    ///     aerugo.create_event(UART_TIMEOUT_EVENT, &UART_TIMEOUT_EVENT_STORAGE);
+    ///     aerugo.create_event(RESTART_COMM_EVENT, &RESTART_COMM_EVENT_STORAGE);
+    ///
+    ///     let config = FdirConfig {
+    ///         name: "FDIR",
+    ///         priority: 3,
+    ///         rules: &FDIR_RULES,
+    ///     };
+    ///     aerugo.create_fdir_engine(config, &FDIR);
+    ///     #
+    ///     # assert!(FDIR.is_initialized());
+    /// }
+    /// ```
+    fn create_fdir_engine<const RULE_COUNT: usize>(
+        &'static self,
+        config: FdirConfig<RULE_COUNT>,
+        storage: &'static FdirStorage<RULE_COUNT>,
+    ) {
+        let tasklet_config = TaskletConfig {
+            name: config.name,
+            priority: config.priority,
+            deadline: None,
+            yield_budget: None,
+            finalize: None,
+        };
+        let engine = FdirEngine::new(&config);
+        let tasklet_storage = storage.tasklet_storage();
+
+        self.create_tasklet_with_context(tasklet_config, fdir_step, engine, tasklet_storage);
+
+        let tasklet_handle = tasklet_storage
+            .create_handle()
+            .expect("Failed to create handle to the FDIR engine tasklet");
+        let triggers = config.rules.map(|rule| rule.trigger);
+        self.subscribe_tasklet_to_events(&tasklet_handle, triggers);
+    }
+
+    /// Adds external watchdog fed by the system.
+    ///
+    /// External watchdog is fed each time the internal watchdog is fed, that is after each
+    /// iteration of the scheduler loop. If the loop hangs, none of the watchdogs will be fed.
+    ///
+    /// Maximum number of external watchdogs is configured with `AERUGO_EXTERNAL_WATCHDOG_COUNT`
+    /// environmental variable.
+    ///
+    /// # Parameters
+    /// * `watchdog` - External watchdog driver.
+    ///
+    /// # Safety
+    /// This function shouldn't be called after the system was started, because watchdog list is
+    /// safe to modify only before that.
+    ///
+    /// # Example
+    /// ```
+    /// # use aerugo::{Aerugo, ExternalWatchdog, InitApi, SystemHardwareConfig};
+    /// #
+    /// struct SupervisorChip;
+    ///
+    /// impl ExternalWatchdog for SupervisorChip {
+    ///     fn feed(&mut self) {
+    ///         // Strobe the supervisor's input pin.
+    ///     }
+    /// }
+    ///
+    /// static mut SUPERVISOR: SupervisorChip = SupervisorChip;
+    ///
+    /// fn main() {
+    ///     # let (aerugo, _) = Aerugo::initialize(SystemHardwareConfig::default());
+    ///     #
+    ///     // SAFETY: This is the only reference to the supervisor.
+    ///     aerugo.add_external_watchdog(unsafe { &mut SUPERVISOR });
+    /// }
+    /// ```
+    fn add_external_watchdog(&'static self, watchdog: &'static mut dyn ExternalWatchdog) {
+        // SAFETY: This is safe because this function can be called only during system initialization
+        // and can't be interrupted.
+        critical_section::with(|_| unsafe {
+            self.external_watchdogs
+                .add(Mutex::new(watchdog))
+                .map_err(|_| SystemError::ExternalWatchdogListFull)
+                .expect("Failed to add an external watchdog")
+        });
+    }
+
+    /// Creates new software watchdog.
+    ///
+    /// Software watchdog checks that each of the configured tasklets is executed at least once per
+    /// its timeout, while the watchdog's mode condition is `true` (or always, if it has no mode).
+    /// After the mode is entered, each tasklet gets a full timeout before it's checked. When the
+    /// watchdog expires, a fault record with its name and fault code is stored in the fault recorder,
+    /// and the system stops feeding internal and external watchdogs until the mode is left. Internal
+    /// watchdog has to be enabled for the expiration to reset the system.
+    ///
+    /// Maximum number of software watchdogs is configured with `AERUGO_SOFTWARE_WATCHDOG_COUNT`
+    /// environmental variable.
+    ///
+    /// # Generic Parameters
+    /// * `N` - Number of checked tasklets.
+    ///
+    /// # Parameters
+    /// * `config` - Software watchdog configuration.
+    /// * `storage` - Static memory storage where the watchdog should be allocated.
+    ///
+    /// # Safety
+    /// This function shouldn't be called after the system was started, because watchdog list is
+    /// safe to modify only before that.
+    ///
+    /// # Example
+    /// ```
+    /// # use aerugo::{Aerugo, BooleanConditionStorage, InitApi, SoftwareWatchdogConfig,
+    /// #     SoftwareWatchdogStorage, SystemHardwareConfig, TaskletConfig, TaskletStorage,
+    /// #     WatchdogEntry};
+    /// # use aerugo::Duration;
+    /// #
+    /// # fn housekeeping(_: (), _: &mut (), _: &'static dyn aerugo::RuntimeApi) {}
+    /// #
+    /// static NOMINAL_MODE: BooleanConditionStorage = BooleanConditionStorage::new();
+    /// static HOUSEKEEPING_STORAGE: TaskletStorage<(), (), 0> = TaskletStorage::new();
+    /// static NOMINAL_WATCHDOG: SoftwareWatchdogStorage<1> = SoftwareWatchdogStorage::new();
+    ///
+    /// fn main() {
+    ///     # let (aerugo, _) = Aerugo::initialize(SystemHardwareConfig::default());
+    ///     #
+    ///     aerugo.create_boolean_condition(true, &NOMINAL_MODE);
+    ///     let nominal_mode = NOMINAL_MODE.create_handle().unwrap();
+    ///
+    ///     let tasklet_config = TaskletConfig {
+    ///         name: "Housekeeping",
+    ///         ..Default::default()
+    ///     };
+    ///     aerugo.create_tasklet(tasklet_config, housekeeping, &HOUSEKEEPING_STORAGE);
+    ///     let housekeeping = HOUSEKEEPING_STORAGE.create_handle().unwrap();
+    ///
+    ///     let config = SoftwareWatchdogConfig {
+    ///         name: "NominalWatchdog",
+    ///         mode: Some(nominal_mode),
+    ///         entries: [WatchdogEntry {
+    ///             tasklet: housekeeping.get_id(),
+    ///             timeout: Duration::millis(500),
+    ///         }],
+    ///         fault_code: 0x10,
+    ///     };
+    ///     aerugo.create_software_watchdog(config, &NOMINAL_WATCHDOG);
+    /// }
+    /// ```
+    fn create_software_watchdog<const N: usize>(
+        &'static self,
+        config: SoftwareWatchdogConfig<N>,
+        storage: &'static SoftwareWatchdogStorage<N>,
+    ) {
+        // SAFETY: This is safe because this function can be called only during system initialization
+        // and can't be interrupted.
+        critical_section::with(|_| unsafe {
+            storage
+                .init(config)
+                .expect("Failed to initialize software watchdog storage");
+            SOFTWARE_WATCHDOG_MANAGER
+                .register_watchdog(storage)
+                .expect("Failed to register a software watchdog")
+        });
+    }
+
+    /// Makes tasklet monitored by the health monitor.
+    ///
+    /// Monitored tasklet has to [check in](crate::api::RuntimeApi::checkin) at least once per
+    /// given period, confirming that it makes progress. Tasklet gets a full period for the first
+    /// check-in after the scheduler is started. When it misses its period, a
+    /// [FaultRecord](crate::FaultRecord) with the tasklet name and given fault code is stored, and
+    /// the system stops feeding hardware and external watchdogs, so a single stuck tasklet resets
+    /// the system even when other tasklets keep running.
+    ///
+    /// # Generic Parameters
+    /// * `T` - Type of the data.
+    /// * `C` - Type of the structure with tasklet context data.
+    /// * `COND_COUNT` - Number of conditions.
+    ///
+    /// # Parameters
+    /// * `tasklet_handle` - Handle to the monitored tasklet.
+    /// * `period` - Maximum time between check-ins.
+    /// * `fault_code` - Code of the fault stored when tasklet misses its check-in.
+    ///
+    /// # Example
+    /// ```
+    /// # use aerugo::{Aerugo, Duration, InitApi, RuntimeApi, SystemHardwareConfig, TaskletConfig, TaskletStorage};
+    /// fn sensor_reader(_: (), _: &mut (), api: &'static dyn RuntimeApi) {
+    ///     // Read the sensor...
+    ///     api.checkin();
+    /// }
+    ///
+    /// static SENSOR_READER_STORAGE: TaskletStorage<(), (), 0> = TaskletStorage::new();
+    ///
+    /// fn main() {
+    ///     # let (aerugo, _) = Aerugo::initialize(SystemHardwareConfig::default());
+    ///     aerugo.create_tasklet(TaskletConfig::default(), sensor_reader, &SENSOR_READER_STORAGE);
+    ///     let sensor_reader = SENSOR_READER_STORAGE.create_handle().unwrap();
+    ///
+    ///     aerugo.monitor_tasklet(&sensor_reader, Duration::millis(500), 0x20);
+    /// }
+    /// ```
+    fn monitor_tasklet<T, C, const COND_COUNT: usize>(
+        &'static self,
+        tasklet_handle: &TaskletHandle<T, C, COND_COUNT>,
+        period: Duration,
+        fault_code: u32,
+    ) {
+        HEALTH_MONITOR
+            .monitor(
+                tasklet_handle.get_id(),
+                tasklet_handle.get_name(),
+                period,
+                fault_code,
+            )
+            .expect("Failed to monitor a tasklet");
+    }
+
+    /// Makes message queue monitored by the growth monitor.
+    ///
+    /// Consumer that is too slow or not executed at all doesn't cause any error until the queue
+    /// overflows. Monitored queue is checked in each pass of the executor loop, and when its
+    /// occupancy grows without ever dropping for longer than `horizon`, queue name is logged and
+    /// given event is emitted. Event is emitted once per growth, and can be emitted again after
+    /// occupancy of the queue drops.
+    ///
+    /// # Generic Parameters
+    /// * `T` - Type of the data stored in the queue.
+    /// * `QUEUE_SIZE` - Size of the queue.
+    ///
+    /// # Parameters
+    /// * `queue_handle` - Handle to the monitored queue.
+    /// * `name` - Name of the queue, logged when the queue is flagged.
+    /// * `horizon` - Time of growth after which the queue is flagged.
+    /// * `event_handle` - Event emitted when the queue is flagged.
+    ///
+    /// # Example
+    /// ```
+    /// # use aerugo::{Aerugo, Duration, EventStorage, InitApi, MessageQueueStorage, SystemHardwareConfig};
+    /// static SAMPLES: MessageQueueStorage<u16, 16> = MessageQueueStorage::new();
+    /// static SAMPLES_BACKLOG_EVENT: EventStorage = EventStorage::new();
+    ///
+    /// fn main() {
+    ///     # let (aerugo, _) = Aerugo::initialize(SystemHardwareConfig::default());
+    ///     aerugo.create_message_queue(&SAMPLES);
+    ///     aerugo.create_event(10, &SAMPLES_BACKLOG_EVENT);
+    ///
+    ///     aerugo.monitor_queue_growth(
+    ///         &SAMPLES.create_handle().unwrap(),
+    ///         "Samples",
+    ///         Duration::millis(200),
+    ///         &SAMPLES_BACKLOG_EVENT.create_handle().unwrap(),
+    ///     );
+    /// }
+    /// ```
+    fn monitor_queue_growth<T, const QUEUE_SIZE: usize>(
+        &'static self,
+        queue_handle: &MessageQueueHandle<T, QUEUE_SIZE>,
+        name: &'static str,
+        horizon: Duration,
+        event_handle: &EventHandle,
+    ) {
+        QUEUE_GROWTH_MONITOR
+            .monitor(name, queue_handle.queue(), horizon, event_handle.event())
+            .expect("Failed to monitor growth of a queue");
+    }
+
+    /// Sets behavior of the system when there is nothing to execute.
+    ///
+    /// With [`IdleStrategy::Sleep`](crate::IdleStrategy::Sleep), CPU sleeps when the execution
+    /// queue is empty, until the nearest cyclic execution, scheduled event, timer or heartbeat
+    /// toggle, or until any interrupt wakes it up. Sleep is not supported on x86 target, where the
+    /// executor always spins.
+    ///
+    /// # Parameters
+    /// * `strategy` - Idle strategy.
+    ///
+    /// # Example
+    /// ```
+    /// # use aerugo::{Aerugo, Duration, IdleStrategy, InitApi, SystemHardwareConfig};
+    /// fn main() {
+    ///     # let (aerugo, _) = Aerugo::initialize(SystemHardwareConfig::default());
+    ///     aerugo.set_idle_strategy(IdleStrategy::Sleep {
+    ///         max_sleep: Duration::millis(100),
+    ///     });
+    /// }
+    /// ```
+    fn set_idle_strategy(&'static self, strategy: IdleStrategy) {
+        self.idle_strategy.lock(|current| *current = strategy);
+    }
+
+    /// Sets order of execution of tasklets with the same priority.
+    ///
+    /// By default tasklet that was executed the longest time ago goes first. As execution time is
+    /// measured with the system timer, tasklet that keeps having work can starve other tasklets
+    /// with the same priority when they are executed within the same timer tick. With
+    /// [`EqualPriorityPolicy::RoundRobin`](crate::EqualPriorityPolicy::RoundRobin), tasklets with
+    /// the same priority are executed in the order in which they were put into the execution queue,
+    /// so each waiting tasklet is executed before any of them runs again. Scheduling tie breaker
+    /// used in tests has no effect with that policy, as the order is always defined.
+    ///
+    /// # Parameters
+    /// * `policy` - Equal priority policy.
+    ///
+    /// # Example
+    /// ```
+    /// # use aerugo::{Aerugo, EqualPriorityPolicy, InitApi, SystemHardwareConfig};
+    /// fn main() {
+    ///     # let (aerugo, _) = Aerugo::initialize(SystemHardwareConfig::default());
+    ///     aerugo.set_equal_priority_policy(EqualPriorityPolicy::RoundRobin);
+    /// }
+    /// ```
+    fn set_equal_priority_policy(&'static self, policy: EqualPriorityPolicy) {
+        EXECUTOR.set_equal_priority_policy(policy);
+    }
+
+    /// Sets a function called by the executor whenever no tasklet is ready for execution.
+    ///
+    /// Hook is called from the scheduler loop after an iteration in which no tasklet was executed,
+    /// before the CPU is put to sleep by the [idle strategy](crate::IdleStrategy). It can be used
+    /// for background work like feeding a watchdog or toggling a status LED, without a dedicated
+    /// cyclic tasklet. Hook is not called while tasklets are being executed, so it has to be short
+    /// and shouldn't be relied on in systems which are never idle.
+    ///
+    /// # Parameters
+    /// * `hook` - Idle hook.
+    ///
+    /// # Safety
+    /// This is unsafe, because it mutably borrows the idle hook stored in the executor. This is
+    /// safe to call before the system initialization.
+    ///
+    /// # Example
+    /// ```
+    /// # use aerugo::{Aerugo, InitApi, RuntimeApi, SystemHardwareConfig};
+    /// fn idle_hook(_: &'static dyn RuntimeApi) {
+    ///     // Feed a watchdog, toggle a LED...
+    /// }
+    ///
+    /// fn main() {
+    ///     # let (aerugo, _) = Aerugo::initialize(SystemHardwareConfig::default());
+    ///     aerugo.set_idle_hook(idle_hook);
+    /// }
+    /// ```
+    fn set_idle_hook(&'static self, hook: IdleHook) {
+        // SAFETY: This is safe because this function can be called only during system initialization
+        // and can't be interrupted.
+        critical_section::with(|_| unsafe {
+            EXECUTOR
+                .set_idle_hook(hook)
+                .expect("Failed to set idle hook")
+        });
+    }
+
+    /// Sets a function called when an internal operation of the system fails at runtime.
+    ///
+    /// Without the hook, failures of the executor (tasklet that can't be scheduled because the
+    /// execution queue is full, overwritten guards of the tasklet context) and broken configuration
+    /// seal panic in the scheduler loop. With the hook, the error is passed to it instead and the
+    /// system continues, so the application can record a fault, switch to a safe mode or
+    /// [reset](crate::api::RuntimeApi::shutdown) the system in a controlled way. Tasklet that
+    /// couldn't be scheduled goes to sleep and is executed again when it's woken, tasklet with
+    /// corrupted context is never executed again.
+    ///
+    /// Hook can be called from the interrupt context, when a tasklet woken by an interrupt handler
+    /// can't be scheduled, so it has to be short.
+    ///
+    /// # Parameters
+    /// * `hook` - Error hook.
+    ///
+    /// # Example
+    /// ```
+    /// # use aerugo::{Aerugo, InitApi, RuntimeError, SystemHardwareConfig};
+    /// fn error_hook(_error: RuntimeError) {
+    ///     // Record a fault, increment a telemetry counter...
+    /// }
+    ///
+    /// fn main() {
+    ///     # let (aerugo, _) = Aerugo::initialize(SystemHardwareConfig::default());
+    ///     aerugo.set_error_hook(error_hook);
+    /// }
+    /// ```
+    fn set_error_hook(&'static self, hook: ErrorHook) {
+        // SAFETY: This is safe because this function can be called only during system initialization
+        // and can't be interrupted.
+        critical_section::with(|_| unsafe {
+            EXECUTOR
+                .set_error_hook(hook)
+                .expect("Failed to set error hook")
+        });
+    }
+
+    /// Sets period of the system configuration integrity check.
+    ///
+    /// Configuration of the system (created tasklets and their subscriptions) is sealed with a CRC
+    /// when the system is started, and the seal is verified by the executor with given period.
+    /// Time of the check grows with the number of tasklets, so the period is a trade-off between
+    /// the scheduling latency and the time to detect the corruption. Default period is 100 ms, and
+    /// zero period checks the configuration on each iteration of the executor loop. Corruption is
+    /// passed to the [error hook](crate::api::InitApi::set_error_hook), or causes a panic if the
+    /// hook wasn't set.
+    ///
+    /// # Parameters
+    /// * `period` - Period of the check.
+    ///
+    /// # Example
+    /// ```
+    /// # use aerugo::{Aerugo, Duration, InitApi, SystemHardwareConfig};
+    /// fn main() {
+    ///     # let (aerugo, _) = Aerugo::initialize(SystemHardwareConfig::default());
+    ///     aerugo.set_configuration_check_period(Duration::millis(500));
+    /// }
+    /// ```
+    fn set_configuration_check_period(&'static self, period: Duration) {
+        self.configuration_seal.set_check_period(period);
+    }
+
+    /// Enables monitoring of the executor loop from a system timer interrupt.
+    ///
+    /// Watchdog checks that the executor loop makes at least one iteration per given period. If
+    /// it doesn't, for example when a step function never returns, a [FaultRecord] with the name
+    /// of the executed tasklet and given fault code is recorded, and given action is performed.
+    /// Action is performed once per stall, from the interrupt context.
+    ///
+    /// On SAMV71 target the loop is checked every 65.536 ms, so shorter periods are detected late,
+    /// and the interrupt handler is available only when the system is built with `rt` feature. On
+    /// x86 target the loop is checked every millisecond from a separate thread.
+    ///
+    /// # Parameters
+    /// * `period` - Maximum time between iterations of the executor loop.
+    /// * `action` - Action performed when the loop stalls.
+    /// * `fault_code` - Code of the fault recorded when the loop stalls.
+    ///
+    /// # Example
+    /// ```
+    /// # use aerugo::{Aerugo, Duration, InitApi, StallAction, SystemHardwareConfig};
+    /// fn main() {
+    ///     # let (aerugo, _) = Aerugo::initialize(SystemHardwareConfig::default());
+    ///     aerugo.enable_scheduler_watchdog(Duration::millis(500), StallAction::Reset, 0x51);
     /// }
     /// ```
-    fn subscribe_tasklet_to_condition<C, const COND_COUNT: usize>(
+    fn enable_scheduler_watchdog(
         &'static self,
-        tasklet_handle: &TaskletHandle<bool, C, COND_COUNT>,
-        condition_handle: &BooleanConditionHandle,
+        period: Duration,
+        action: StallAction,
+        fault_code: u32,
     ) {
-        let tasklet = tasklet_handle.tasklet();
-        let condition = condition_handle.condition();
-
         // SAFETY: This is safe because this function can be called only during system initialization
         // and can't be interrupted.
         critical_section::with(|_| unsafe {
-            condition
-                .register_tasklet(tasklet.ptr())
-                .expect("Failed to register tasklet in a condition");
-
-            tasklet
-                .subscribe(condition)
-                .expect("Failed to subscribe tasklet to a condition");
+            SCHEDULER_WATCHDOG
+                .enable(period, action, fault_code)
+                .expect("Failed to enable scheduler watchdog")
         });
     }
 
-    /// Subscribes tasklet to the cyclic execution.
+    /// Enables containment of tasklets that overrun their deadlines.
     ///
-    /// Tasklet subscribes for cyclic execution. Tasklet will be executed in specified period,
-    /// or will be always ready for execution if such period was not specified. On execution
-    /// tasklet won't receive any data, so cycling tasklets are useful mostly as ex. producers or
-    /// some periodic housekeeping operations.
+    /// Executed step is checked from a system timer interrupt. When a step of a tasklet with a
+    /// [deadline](crate::TaskletConfig::deadline) runs longer than its deadline extended by given
+    /// grace time, the tasklet is suspended, a [FaultRecord] with its name and given fault code is
+    /// recorded, and the step is abandoned. This contains a single failing tasklet without resetting
+    /// the whole system, and the tasklet can be [resumed](crate::api::RuntimeApi::resume_tasklet)
+    /// after the application recovers it.
     ///
-    /// Each tasklet can be subscribed to at maximum on data provider.
+    /// Step can't be forcibly terminated, as that would skip destructors and leave its context in
+    /// an undefined state. Abandoned step is [cancelled](crate::CancellationToken), so
+    /// [should_yield](crate::api::RuntimeApi::should_yield) returns `true` until the step returns,
+    /// and steps of long-running tasklets should check it, for example with
+    /// [yield_point](crate::yield_point). Step that never returns has to be handled by the
+    /// [scheduler watchdog](Aerugo::enable_scheduler_watchdog).
     ///
-    /// # Generic Parameters
-    /// * `C` - Type of the structure with tasklet context data.
-    /// * `COND_COUNT` - Number of tasklet conditions.
+    /// Timer interrupt is the same as for the scheduler watchdog, so on SAMV71 target steps are
+    /// checked every 65.536 ms and the system has to be built with `rt` feature.
     ///
     /// # Parameters
-    /// * `tasklet` - Handle to the target tasklet.
-    /// * `period` - Period of execution, `None` if should be woke whenever possible.
-    /// * `offset` - Offset of first execution after scheduled start, `None` if should be executed instantly.
-    ///
-    /// # Return
-    /// `()` if successful, `InitError` otherwise.
-    ///
-    /// # Safety
-    /// This function shouldn't be called after the system was started, because subscription is safe
-    /// only before that.
+    /// * `grace` - Time by which a step can overrun its deadline before the tasklet is contained.
+    /// * `fault_code` - Code of the fault recorded when a tasklet is contained.
     ///
     /// # Example
     /// ```
-    /// # use aerugo::{Aerugo, InitApi, RuntimeApi, SystemHardwareConfig, TaskletConfig, TaskletStorage};
-    /// #
-    /// # fn task(_: (), _: &mut (), _: &dyn RuntimeApi) {}
-    /// #
-    /// # static TASK_STORAGE: TaskletStorage<(), (), 0> = TaskletStorage::new();
-    /// #
+    /// # use aerugo::{Aerugo, Duration, InitApi, SystemHardwareConfig};
     /// fn main() {
     ///     # let (aerugo, _) = Aerugo::initialize(SystemHardwareConfig::default());
-    ///     # let task_config = TaskletConfig::default();
-    ///     #
-    ///     # aerugo.create_tasklet(TaskletConfig::default(), task, &TASK_STORAGE);
-    ///     #
-    ///     let task_handle = TASK_STORAGE.create_handle().unwrap();
-    ///
-    ///     aerugo.subscribe_tasklet_to_cyclic(&task_handle, None, None);
+    ///     aerugo.enable_tasklet_containment(Duration::millis(100), 0x52);
     /// }
     /// ```
-    fn subscribe_tasklet_to_cyclic<C, const COND_COUNT: usize>(
-        &'static self,
-        tasklet_handle: &TaskletHandle<(), C, COND_COUNT>,
-        period: Option<Duration>,
-        offset: Option<Duration>,
-    ) {
-        let tasklet = tasklet_handle.tasklet();
-
+    fn enable_tasklet_containment(&'static self, grace: Duration, fault_code: u32) {
         // SAFETY: This is safe because this function can be called only during system initialization
         // and can't be interrupted.
         critical_section::with(|_| unsafe {
-            let cyclic_execution = CYCLIC_EXECUTION_MANAGER
-                .create_cyclic_execution(tasklet.ptr(), period, offset)
-                .expect("Failed to create a cyclic execution");
-
-            tasklet
-                .subscribe(cyclic_execution)
-                .expect("Failed to subscribe tasklet to a cyclic exection");
+            TASKLET_CONTAINMENT
+                .enable(grace, fault_code)
+                .expect("Failed to enable tasklet containment")
         });
     }
 
-    /// Sets tasklet condition set.
-    ///
-    /// Tasklet can use a set of BooleanConditions as a execution condition. Before tasklet is
-    /// scheduled and then executed it's condition is checked to verify whether this tasklet is
-    /// active. Tasklet will be woken when value of any of the condition in the set changes.
+    /// Installs logger forwarding records of the `log` crate facade to the system log.
     ///
-    /// # Generic Parameters
-    /// * `T` - Type of the data.
-    /// * `C` - Type of the structure with tasklet context data.
-    /// * `COND_COUNT` - Number of conditions.
+    /// Records from third-party crates using the `log` facade are written to the same stream as
+    /// system messages, prefixed with the time since system initialization in microseconds, level
+    /// and target. Records
+    /// with level above `max_level` are discarded by the facade macros, without formatting.
     ///
     /// # Parameters
-    /// * `tasklet` - Handle to the target tasklet.
-    /// * `condition` - Set of conditions.
-    ///
-    /// # Return
-    /// `()` if successful, `InitError` otherwise.
-    ///
+    /// * `max_level` - Maximum level of forwarded records.
     ///
     /// # Safety
-    /// This function shouldn't be called after the system was started, because subscription is safe
-    /// only before that.
+    /// This function shouldn't be called more than once, as the logger can be installed only once.
     ///
     /// # Example
     /// ```
-    /// # use aerugo::{Aerugo, BooleanConditionSet, BooleanConditionSetType, BooleanConditionStorage, InitApi,
-    ///     RuntimeApi, SystemHardwareConfig, TaskletConfig, TaskletStorage};
-    /// #
-    /// # fn task(_: (), _: &mut (), _: &dyn RuntimeApi) {}
-    /// #
-    /// # static TASK_STORAGE: TaskletStorage<(), (), 2> = TaskletStorage::new();
-    /// # static CONDITION_X_STORAGE: BooleanConditionStorage = BooleanConditionStorage::new();
-    /// # static CONDITION_Y_STORAGE: BooleanConditionStorage = BooleanConditionStorage::new();
-    /// #
+    /// # use aerugo::{Aerugo, InitApi, SystemHardwareConfig};
     /// fn main() {
     ///     # let (aerugo, _) = Aerugo::initialize(SystemHardwareConfig::default());
-    ///     # let task_config = TaskletConfig::default();
-    ///     #
-    ///     # aerugo.create_tasklet(TaskletConfig::default(), task, &TASK_STORAGE);
-    ///     # aerugo.create_boolean_condition(true, &CONDITION_X_STORAGE);
-    ///     # aerugo.create_boolean_condition(true, &CONDITION_Y_STORAGE);
-    ///     #
-    ///     let task_handle = TASK_STORAGE.create_handle().unwrap();
-    ///     let condition_x_handle = CONDITION_X_STORAGE.create_handle().unwrap();
-    ///     let condition_y_handle = CONDITION_Y_STORAGE.create_handle().unwrap();
+    ///     aerugo.enable_log_facade(log::LevelFilter::Info);
     ///
-    ///     let mut condition_set = BooleanConditionSet::<2>::new(BooleanConditionSetType::And);
-    ///     condition_set.add(&condition_x_handle);
-    ///     condition_set.add(&condition_y_handle);
+    ///     log::info!("driver initialized");
+    /// }
+    /// ```
+    #[cfg(feature = "log-facade")]
+    fn enable_log_facade(&'static self, max_level: log::LevelFilter) {
+        log::set_logger(&crate::log_facade::LOGGER).expect("Failed to install log facade logger");
+        log::set_max_level(max_level);
+    }
+
+    /// Writes machine-readable manifest of the system configuration.
     ///
-    ///     aerugo.set_tasklet_conditions(&task_handle, condition_set);
+    /// Manifest is written in [JSON Lines](https://jsonlines.org) format, with one line
+    /// describing the system, one line for each tasklet (with its priority, deadline, data source
+    /// and condition set) and one line for each event (with tasklets subscribed to it). It should be
+    /// called when the system is fully configured, right before [start](InitApi::start). Tasklets
+    /// spawned at runtime are not included. If the system configuration is corrupted, error is
+    /// reported and nothing is written.
+    ///
+    /// Writer decides where the manifest goes, for example it can write the lines to the system
+    /// log, or on x86 target append them to a file on the host.
+    ///
+    /// # Parameters
+    /// * `writer` - Function called with each line of the manifest.
+    ///
+    /// # Example
+    /// ```
+    /// # use aerugo::{logln, Aerugo, InitApi, SystemHardwareConfig};
+    /// fn write_manifest_line(line: core::fmt::Arguments) {
+    ///     logln!("{}", line);
     /// }
-    fn set_tasklet_conditions<T, C, const COND_COUNT: usize>(
-        &'static self,
-        tasklet_handle: &TaskletHandle<T, C, COND_COUNT>,
-        condition_set: BooleanConditionSet<COND_COUNT>,
-    ) {
-        let tasklet = tasklet_handle.tasklet();
+    ///
+    /// fn main() {
+    ///     # let (aerugo, _) = Aerugo::initialize(SystemHardwareConfig::default());
+    ///     aerugo.write_manifest(write_manifest_line);
+    /// }
+    /// ```
+    fn write_manifest(&'static self, writer: ManifestWriter) {
+        if !self.is_configuration_intact() {
+            return;
+        }
 
-        // SAFETY: This is safe because this function can be called only during system initialization
-        // and can't be interrupted.
-        critical_section::with(|_| unsafe {
-            condition_set
-                .register_tasklet(tasklet.ptr())
-                .expect("Failed to register a tasklet in a condition set");
+        writer(format_args!(
+            "{{\"type\":\"system\",\"tasklets\":{},\"events\":{}}}",
+            self.tasklets.len(),
+            EVENT_MANAGER.events().count()
+        ));
 
-            tasklet
-                .set_condition_set(condition_set)
-                .expect("Failed to set a condition set for tasklet");
-        });
+        for tasklet in &self.tasklets {
+            writer(format_args!("{}", tasklet.manifest()));
+        }
+
+        for event in EVENT_MANAGER.events() {
+            writer(format_args!(
+                "{}",
+                EventManifest {
+                    id: event.id(),
+                    subscribers: event.subscribers(),
+                }
+            ));
+        }
     }
 
-    fn set_execution_time_exceeded_maximum_event(
-        &'static self,
-        event_handle: &EventHandle,
-        time: Duration,
-    ) {
-        unsafe {
-            EXECUTION_MONITOR
-                .set_time_exceeded_event(event_handle.event(), time)
-                .expect("Failed to set event for exceeding maximum tasklet execution time.")
-        };
+    /// Marks the end of a system configuration step in the startup report.
+    ///
+    /// Startup report records durations of the hardware configuration, startup path and optional
+    /// components. Configuration done afterwards, until the system is started, is recorded as a
+    /// single `Configuration` step, unless it's split into steps with this function. Step lasts
+    /// from the end of the previous one, so it's marked when it ends. Report can be read with
+    /// [get_startup_report](crate::api::RuntimeApi::get_startup_report) after the system is
+    /// started.
+    ///
+    /// # Parameters
+    /// * `name` - Name of the step.
+    ///
+    /// # Example
+    /// ```
+    /// # use aerugo::{Aerugo, InitApi, SystemHardwareConfig};
+    /// fn main() {
+    ///     # let (aerugo, _) = Aerugo::initialize(SystemHardwareConfig::default());
+    ///     // Create tasklets, queues and events.
+    ///     aerugo.mark_startup_step("Tasklets");
+    ///
+    ///     // Subscribe tasklets.
+    ///     aerugo.mark_startup_step("Subscriptions");
+    /// }
+    /// ```
+    fn mark_startup_step(&'static self, name: &'static str) {
+        STARTUP_MANAGER.record_step(name);
     }
 
     /// Starts the system.
@@ -1049,12 +4072,36 @@ impl InitApi for Aerugo {
     /// # Safety
     /// This shouldn't be called more than once.
     fn start(&'static self) -> ! {
+        STARTUP_MANAGER.record_step("Configuration");
+
         self.validate().expect("Failed to start the system");
+        Hal::verify_fpu_context_policy()
+            .expect("FPU configuration doesn't match FPU context policy");
+
+        // SAFETY: This is safe, because system is started only once.
+        unsafe { self.seal_configuration() }.expect("Failed to seal system configuration");
+
+        let ticks = Hal::get_system_time().ticks();
+        #[cfg_attr(not(feature = "log"), allow(unused_variables))]
+        if let Some(seed) = TIE_BREAKER.initialize((ticks ^ (ticks >> 32)) as u32) {
+            crate::logln!("Aerugo: scheduling seed {}", seed);
+        }
 
         // SAFETY: This is safe, because it's called from non-IRQ context, and
         // system time cannot be accessed from IRQ context
         unsafe { self.time_source.set_system_start() }
 
+        STARTUP_MANAGER.record_step("Validation");
+
+        if SCHEDULER_WATCHDOG.is_enabled() {
+            SCHEDULER_WATCHDOG.start(Hal::get_system_time());
+        }
+
+        if SCHEDULER_WATCHDOG.is_enabled() || TASKLET_CONTAINMENT.is_enabled() {
+            Hal::start_monitor_timer(Aerugo::on_monitor_timer)
+                .expect("Failed to start monitor timer");
+        }
+
         self.run()
     }
 }
@@ -1096,6 +4143,17 @@ impl RuntimeApi for Aerugo {
         EVENT_MANAGER.is_scheduled(event_id)
     }
 
+    fn emit_event_periodic(
+        &'static self,
+        event_id: EventId,
+        period: Duration,
+        count: Option<u32>,
+    ) -> Result<bool, RuntimeError> {
+        let first_time = self.get_system_time() + period;
+
+        EVENT_MANAGER.schedule_periodic(event_id, first_time, period, count)
+    }
+
     fn cancel_event(&'static self, event_id: EventId) -> Result<bool, RuntimeError> {
         EVENT_MANAGER.cancel(event_id)
     }
@@ -1104,6 +4162,10 @@ impl RuntimeApi for Aerugo {
         EVENT_MANAGER.clear()
     }
 
+    fn clear_pending_events(&'static self, tasklet: &dyn IdentifiableTasklet) -> usize {
+        EVENT_MANAGER.clear_pending(tasklet.get_id())
+    }
+
     fn get_system_time(&'static self) -> Instant {
         self.time_source.system_time()
     }
@@ -1112,13 +4174,96 @@ impl RuntimeApi for Aerugo {
         self.time_source.elapsed_time()
     }
 
+    fn should_yield(&'static self) -> bool {
+        EXECUTOR.should_yield()
+    }
+
+    fn yield_needed(&'static self) -> bool {
+        EXECUTOR.yield_needed()
+    }
+
+    fn cancellation_token(&'static self) -> CancellationToken {
+        CancellationToken::new(&EXECUTOR)
+    }
+
+    fn cancel_step(&'static self) -> bool {
+        EXECUTOR.cancel_current_step().is_some()
+    }
+
+    fn checkin(&'static self) {
+        if let Some(tasklet) = EXECUTOR.current_tasklet() {
+            HEALTH_MONITOR.checkin(tasklet.get_id(), self.time_source.elapsed_time());
+        }
+    }
+
     fn set_system_time_offset(&'static self, offset: Duration) -> Result<(), RuntimeError> {
         // SAFETY: This is safe, because it's called from non-IRQ context, and
         // system time cannot be accessed from IRQ context
         unsafe { self.time_source.set_user_offset(offset) }
     }
 
+    fn suspend_tasklet(&'static self, tasklet: &dyn SuspendableTasklet) -> bool {
+        tasklet.suspend()
+    }
+
+    fn resume_tasklet(&'static self, tasklet: &dyn SuspendableTasklet) -> bool {
+        tasklet.resume()
+    }
+
+    fn terminate_tasklet(&'static self, tasklet: &dyn SuspendableTasklet) -> bool {
+        tasklet.terminate()
+    }
+
+    fn shutdown(&'static self, reason: ShutdownReason) {
+        self.shutdown_requested.lock(|requested| {
+            requested.get_or_insert(reason);
+        })
+    }
+
+    /// Spawns tasklet reserved during system initialization.
+    ///
+    /// Tasklet is created and registered immediately, in a critical section, and is scheduled for
+    /// execution if its data provider already has data waiting. Configuration seal is verified
+    /// before the tasklet is registered and updated after that. This should be called only from
+    /// tasklets, never from interrupt handlers.
+    ///
+    /// # Example
+    /// ```
+    /// # use aerugo::{RuntimeApi, SpawnableTasklet, TaskletSpawner, TaskletStorage};
+    /// #
+    /// static DIAGNOSTICS_STORAGE: TaskletStorage<u32, (), 0> = TaskletStorage::new();
+    /// static DIAGNOSTICS: TaskletSpawner<u32, (), 0> = TaskletSpawner::new(&DIAGNOSTICS_STORAGE);
+    ///
+    /// fn fault_detector(fault_code: u32, _: &mut (), api: &'static dyn RuntimeApi) {
+    ///     if fault_code != 0 && !DIAGNOSTICS.is_spawned() {
+    ///         let _ = api.spawn_tasklet(&DIAGNOSTICS);
+    ///     }
+    /// }
+    /// ```
+    fn spawn_tasklet(
+        &'static self,
+        tasklet: &dyn SpawnableTasklet,
+    ) -> Result<DataReceiver, RuntimeError> {
+        tasklet.spawn()
+    }
+
+    fn get_startup_info(&'static self) -> StartupInfo {
+        STARTUP_MANAGER.info()
+    }
+
+    fn get_startup_report(&'static self) -> StartupReport {
+        STARTUP_MANAGER.report()
+    }
+
+    fn get_component_status(&'static self, name: &str) -> Option<ComponentStatus> {
+        STARTUP_MANAGER.component_status(name)
+    }
+
     fn query_tasklets(&'static self) -> core::slice::Iter<TaskletId> {
+        if !self.is_configuration_intact() {
+            return [].iter();
+        }
+
         self.tasklet_ids.iter()
     }
 
@@ -1128,9 +4273,101 @@ impl RuntimeApi for Aerugo {
         self.time_source.startup_duration()
     }
 
-    fn get_execution_statistics(&'static self, tasklet_id: &TaskletId) -> Option<ExecutionStats> {
+    fn get_execution_statistics(
+        &'static self,
+        tasklet: &dyn IdentifiableTasklet,
+    ) -> Option<ExecutionStats> {
+        if !self.is_configuration_intact() {
+            return None;
+        }
+
+        // This is safe, because `EXECUTION_MONITOR` is not available from the IRQ context.
+        unsafe { EXECUTION_MONITOR.get_stats(&tasklet.get_id()) }
+    }
+
+    fn get_cpu_load(&'static self) -> Option<u8> {
+        // This is safe, because `EXECUTION_MONITOR` is not available from the IRQ context.
+        unsafe { EXECUTION_MONITOR.cpu_load() }
+    }
+
+    fn get_busy_time(&'static self) -> Duration {
+        // This is safe, because `EXECUTION_MONITOR` is not available from the IRQ context.
+        unsafe { EXECUTION_MONITOR.busy_time() }
+    }
+
+    fn get_idle_time(&'static self) -> Duration {
         // This is safe, because `EXECUTION_MONITOR` is not available from the IRQ context.
-        unsafe { EXECUTION_MONITOR.get_stats(tasklet_id) }
+        unsafe { EXECUTION_MONITOR.idle_time() }
+    }
+
+    fn get_stack_usage(&'static self, tasklet: &dyn IdentifiableTasklet) -> Option<usize> {
+        self.get_execution_statistics(tasklet)?
+            .maximum_stack_usage()
+    }
+
+    fn get_firmware_info(&'static self) -> Result<FirmwareInfo, RuntimeError> {
+        match self.firmware_info.get() {
+            Some(verification) => *verification,
+            None => Err(RuntimeError::FirmwareInfoNotSet),
+        }
+    }
+
+    fn get_partition_overrun_count(&'static self, partition: PartitionId) -> u32 {
+        PARTITION_MANAGER.overrun_count(partition)
+    }
+
+    fn get_schedule_overrun_count(&'static self, tasklet: &dyn IdentifiableTasklet) -> Option<u32> {
+        SCHEDULE_TABLE_MANAGER.overrun_count(tasklet.get_id())
+    }
+
+    fn get_missed_activation_count(
+        &'static self,
+        tasklet: &dyn IdentifiableTasklet,
+    ) -> Option<u32> {
+        CYCLIC_EXECUTION_MANAGER.missed_activation_count(tasklet.get_id())
+    }
+
+    fn sync_cyclic_execution(
+        &'static self,
+        tasklet: &dyn IdentifiableTasklet,
+        sync_time: Instant,
+    ) -> Result<bool, RuntimeError> {
+        CYCLIC_EXECUTION_MANAGER.sync(tasklet.get_id(), sync_time)
+    }
+
+    fn dump_system_state(&'static self, writer: &mut dyn fmt::Write) -> fmt::Result {
+        writeln!(writer, "System time: {}", self.time_source.system_time())?;
+
+        if !self.is_configuration_intact() {
+            return writeln!(writer, "System configuration corrupted");
+        }
+
+        for tasklet in &self.tasklets {
+            writeln!(writer, "{}", tasklet.state())?;
+        }
+
+        for event in EVENT_MANAGER.events() {
+            let state = EventState {
+                id: event.id(),
+                scheduled: EVENT_MANAGER.scheduled_time(event),
+                pending: event.pending_count(),
+            };
+            writeln!(writer, "{}", state)?;
+        }
+
+        Ok(())
+    }
+
+    fn get_latched_fault(&'static self) -> Option<FaultRecord> {
+        FAULT_MANAGER.latched_fault()
+    }
+
+    fn clear_latched_fault(&'static self) {
+        FAULT_MANAGER.clear_latched_fault()
+    }
+
+    fn get_fault_record(&'static self, index: usize) -> Option<FaultRecord> {
+        FAULT_MANAGER.fault_record(index)
     }
 
     fn execute_critical<F, R>(f: F) -> R
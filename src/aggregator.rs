@@ -0,0 +1,116 @@
+//! Aggregator downsampling high-rate data.
+//!
+//! Aggregator is a system tasklet that takes samples from the source queue and, once per window,
+//! sends an [Aggregate] of all samples received in that window (minimum, maximum, mean and last
+//! value) to the destination queue. It's used to turn high-rate signals into low-rate telemetry
+//! without writing a dedicated tasklet for each of them.
+//!
+//! Windows are aligned to the time of the first received sample. Aggregate of the window is sent
+//! when the first sample from one of the following windows is received, so when no new samples
+//! arrive, the last window is not closed.
+
+mod aggregate;
+mod aggregator_config;
+mod aggregator_storage;
+
+pub use self::aggregate::Aggregate;
+pub use self::aggregator_config::AggregatorConfig;
+pub use self::aggregator_storage::AggregatorStorage;
+
+use crate::api::RuntimeApi;
+use crate::message_queue::MessageQueueHandle;
+use crate::mutex::Mutex;
+use crate::time::{Duration, Instant};
+
+/// Aggregator downsampling high-rate data.
+///
+/// This is a context of the aggregator tasklet.
+///
+/// # Generic Parameters
+/// * `T` - Type of the samples.
+/// * `M` - Size of the destination queue.
+pub(crate) struct Aggregator<T: 'static, const M: usize> {
+    /// Length of the aggregation window.
+    window: Duration,
+    /// Destination queue.
+    destination: MessageQueueHandle<Aggregate<T>, M>,
+    /// Start of the current window.
+    window_start: Option<Instant>,
+    /// Aggregate of the current window.
+    aggregate: Option<Aggregate<T>>,
+    /// Sum of the samples in the current window.
+    sum: f64,
+    /// Number of aggregates that didn't fit in the destination queue.
+    dropped: &'static Mutex<u32>,
+}
+
+impl<T: Copy + PartialOrd + Into<f64>, const M: usize> Aggregator<T, M> {
+    /// Creates new aggregator.
+    ///
+    /// # Parameters
+    /// * `config` - Aggregator configuration.
+    /// * `destination` - Destination queue.
+    /// * `dropped` - Counter of dropped aggregates.
+    pub(crate) fn new(
+        config: &AggregatorConfig,
+        destination: MessageQueueHandle<Aggregate<T>, M>,
+        dropped: &'static Mutex<u32>,
+    ) -> Self {
+        Aggregator {
+            window: config.window,
+            destination,
+            window_start: None,
+            aggregate: None,
+            sum: 0.0,
+            dropped,
+        }
+    }
+
+    /// Adds sample to the aggregate, sending the aggregate if the window has ended.
+    ///
+    /// # Parameters
+    /// * `sample` - Received sample.
+    /// * `time` - Current system time.
+    fn add_sample(&mut self, sample: T, time: Instant) {
+        let window_start = *self.window_start.get_or_insert(time);
+
+        if time >= window_start + self.window {
+            self.send_aggregate();
+
+            let elapsed_windows = (time - window_start).ticks() / self.window.ticks();
+            self.window_start =
+                Some(window_start + Duration::from_ticks(elapsed_windows * self.window.ticks()));
+        }
+
+        self.sum += sample.into();
+        self.aggregate = Some(match self.aggregate {
+            Some(aggregate) => aggregate.with_sample(sample, self.sum),
+            None => Aggregate::from_sample(sample),
+        });
+    }
+
+    /// Sends aggregate of the current window and clears it.
+    fn send_aggregate(&mut self) {
+        if let Some(aggregate) = self.aggregate.take() {
+            if self.destination.send_data(aggregate).is_err() {
+                self.dropped.lock(|count| *count = count.wrapping_add(1));
+            }
+        }
+
+        self.sum = 0.0;
+    }
+}
+
+/// Step function of the aggregator tasklet.
+///
+/// # Parameters
+/// * `sample` - Sample from the source queue.
+/// * `aggregator` - Aggregator context.
+/// * `api` - System runtime API.
+pub(crate) fn aggregator_step<T: Copy + PartialOrd + Into<f64>, const M: usize>(
+    sample: T,
+    aggregator: &mut Aggregator<T, M>,
+    api: &'static dyn RuntimeApi,
+) {
+    aggregator.add_sample(sample, api.get_system_time());
+}
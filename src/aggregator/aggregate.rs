@@ -0,0 +1,75 @@
+//! Aggregate of the samples from a single window.
+
+/// Aggregate of the samples from a single window.
+///
+/// # Generic Parameters
+/// * `T` - Type of the samples.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Aggregate<T> {
+    /// Minimum value.
+    pub min: T,
+    /// Maximum value.
+    pub max: T,
+    /// Mean value.
+    pub mean: f64,
+    /// Last received value.
+    pub last: T,
+    /// Number of samples.
+    pub count: u32,
+}
+
+impl<T: Copy + PartialOrd + Into<f64>> Aggregate<T> {
+    /// Creates aggregate of a single sample.
+    ///
+    /// # Parameters
+    /// * `sample` - First sample of the window.
+    pub(crate) fn from_sample(sample: T) -> Self {
+        Aggregate {
+            min: sample,
+            max: sample,
+            mean: sample.into(),
+            last: sample,
+            count: 1,
+        }
+    }
+
+    /// Returns this aggregate updated with the next sample.
+    ///
+    /// # Parameters
+    /// * `sample` - Next sample of the window.
+    /// * `sum` - Sum of all samples of the window, including the next one.
+    pub(crate) fn with_sample(self, sample: T, sum: f64) -> Self {
+        let count = self.count + 1;
+
+        Aggregate {
+            min: if sample < self.min { sample } else { self.min },
+            max: if sample > self.max { sample } else { self.max },
+            mean: sum / count as f64,
+            last: sample,
+            count,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn aggregate_samples() {
+        let samples = [3i16, -2, 7, 4];
+
+        let mut sum = samples[0] as f64;
+        let mut aggregate = Aggregate::from_sample(samples[0]);
+        for sample in &samples[1..] {
+            sum += *sample as f64;
+            aggregate = aggregate.with_sample(*sample, sum);
+        }
+
+        assert_eq!(aggregate.min, -2);
+        assert_eq!(aggregate.max, 7);
+        assert_eq!(aggregate.mean, 3.0);
+        assert_eq!(aggregate.last, 4);
+        assert_eq!(aggregate.count, 4);
+    }
+}
@@ -0,0 +1,24 @@
+//! Configuration for creating aggregators.
+
+use crate::time::Duration;
+
+/// Configuration for aggregators.
+#[derive(Copy, Clone)]
+pub struct AggregatorConfig {
+    /// Name of the aggregator tasklet.
+    pub name: &'static str,
+    /// Priority of the aggregator tasklet.
+    pub priority: u8,
+    /// Length of the aggregation window.
+    pub window: Duration,
+}
+
+impl Default for AggregatorConfig {
+    fn default() -> Self {
+        AggregatorConfig {
+            name: "MISSING_AGGREGATOR_NAME",
+            priority: 0,
+            window: Duration::secs(1),
+        }
+    }
+}
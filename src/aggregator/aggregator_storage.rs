@@ -0,0 +1,55 @@
+//! Static storage for [aggregator](crate::aggregator::Aggregator).
+//!
+//! This module contains an aggregator storage, which is a statically allocated memory that will
+//! store the aggregator tasklet for the duration of the system life.
+
+use super::Aggregator;
+
+use crate::mutex::Mutex;
+use crate::tasklet::TaskletStorage;
+
+/// Structure containing memory for aggregator creation.
+///
+/// Storage shall be a static variable and shall be initialized only once, before the system is started.
+///
+/// # Generic Parameters
+/// * `T` - Type of the samples.
+/// * `M` - Size of the destination queue.
+pub struct AggregatorStorage<T: 'static, const M: usize> {
+    /// Storage for the aggregator tasklet.
+    tasklet_storage: TaskletStorage<T, Aggregator<T, M>, 0>,
+    /// Number of aggregates that didn't fit in the destination queue.
+    dropped: Mutex<u32>,
+}
+
+impl<T: 'static, const M: usize> AggregatorStorage<T, M> {
+    /// Creates new storage.
+    pub const fn new() -> Self {
+        AggregatorStorage {
+            tasklet_storage: TaskletStorage::new(),
+            dropped: Mutex::new(0),
+        }
+    }
+
+    /// Returns initialization status of this storage.
+    pub fn is_initialized(&'static self) -> bool {
+        self.tasklet_storage.is_initialized()
+    }
+
+    /// Returns number of aggregates that didn't fit in the destination queue.
+    pub fn dropped_count(&'static self) -> u32 {
+        self.dropped.lock(|count| *count)
+    }
+
+    /// Returns storage for the aggregator tasklet.
+    pub(crate) fn tasklet_storage(
+        &'static self,
+    ) -> &'static TaskletStorage<T, Aggregator<T, M>, 0> {
+        &self.tasklet_storage
+    }
+
+    /// Returns counter of dropped aggregates.
+    pub(crate) fn dropped(&'static self) -> &'static Mutex<u32> {
+        &self.dropped
+    }
+}
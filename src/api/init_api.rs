@@ -5,13 +5,42 @@
 //! # Safety
 //! Functions from this trait shouldn't be called after the system was started.
 
+use core::ops::{Add, Sub};
+
+use aerugo_hal::ExternalWatchdog;
+
+use crate::aggregator::{Aggregate, AggregatorConfig, AggregatorStorage};
+use crate::barrier::{BarrierHandle, BarrierStorage};
 use crate::boolean_condition::{
     BooleanConditionHandle, BooleanConditionSet, BooleanConditionStorage,
 };
-use crate::event::{EventHandle, EventId, EventStorage};
-use crate::message_queue::{MessageQueueHandle, MessageQueueStorage};
-use crate::tasklet::{StepFn, TaskletConfig, TaskletHandle, TaskletStorage};
+use crate::bus_manager::{BusDriver, BusManagerConfig, BusManagerStorage, BusTransaction};
+use crate::data_provider::{DataProvider, DataReceiver};
+use crate::equal_priority_policy::EqualPriorityPolicy;
+use crate::event::{
+    DataEventHandle, DataEventStorage, EventGroup, EventHandle, EventId, EventStorage,
+};
+use crate::execution_monitor::DeadlineMissHandler;
+use crate::executor::{ErrorHook, IdleHook};
+use crate::fdir::{FdirConfig, FdirStorage};
+use crate::firmware_info::FirmwareInfo;
+use crate::idle_strategy::IdleStrategy;
+use crate::limit_monitor::{LimitMonitorConfig, LimitMonitorStorage};
+use crate::manifest::ManifestWriter;
+use crate::message_queue::{MessageQueueHandle, MessageQueueStorage, OverflowPolicy};
+use crate::missed_activation_policy::MissedActivationPolicy;
+use crate::partition::{PartitionId, PartitionWindow};
+use crate::queue_bridge::{QueueBridgeConfig, QueueBridgeStorage};
+use crate::schedule_table::{ScheduleSlot, ScheduleTableConfig};
+use crate::scheduler_watchdog::StallAction;
+use crate::software_watchdog::{SoftwareWatchdogConfig, SoftwareWatchdogStorage};
+use crate::tasklet::{
+    RefStepFn, ResumableStepFn, StepFn, TaskletChain, TaskletConfig, TaskletDataSource,
+    TaskletHandle, TaskletSpawner, TaskletStorage,
+};
+use crate::telemetry::{Telemetry, TelemetrySample, TelemetryStorage};
 use crate::time::Duration;
+use crate::timer::{TimerAction, TimerStorage};
 
 /// System initialization API
 ///
@@ -65,6 +94,89 @@ pub trait InitApi {
         storage: &'static TaskletStorage<T, C, COND_COUNT>,
     );
 
+    /// Creates new tasklet in the system, which receives the data by reference.
+    ///
+    /// # Generic Parameters
+    /// * `T` - Type of the data processed by the tasklet.
+    /// * `C` - Type of the structure with tasklet context data.
+    /// * `COND_COUNT` - Number of tasklet conditions.
+    ///
+    /// # Parameters
+    /// * `config` - Tasklet creation configuration.
+    /// * `step_fn` - Tasklet step function.
+    /// * `storage` - Static memory storage where the tasklet should be allocated.
+    ///
+    /// # Return
+    /// `()` if successful, `InitError` otherwise.
+    fn create_tasklet_by_reference<T, C: Default, const COND_COUNT: usize>(
+        &'static self,
+        config: TaskletConfig,
+        step_fn: RefStepFn<T, C>,
+        storage: &'static TaskletStorage<T, C, COND_COUNT>,
+    );
+
+    /// Creates new tasklet in the system with initialized context data, which receives the data
+    /// by reference.
+    ///
+    /// # Generic Parameters
+    /// * `T` - Type of the data processed by the tasklet.
+    /// * `C` - Type of the structure with tasklet context data.
+    /// * `COND_COUNT` - Number of tasklet conditions.
+    ///
+    /// # Parameters
+    /// * `config` - Tasklet creation configuration.
+    /// * `step_fn` - Tasklet step function.
+    /// * `context` - Tasklet context data.
+    /// * `storage` - Static memory storage where the tasklet should be allocated.
+    ///
+    /// # Return
+    /// `()` if successful, `InitError` otherwise.
+    fn create_tasklet_by_reference_with_context<T, C, const COND_COUNT: usize>(
+        &'static self,
+        config: TaskletConfig,
+        step_fn: RefStepFn<T, C>,
+        context: C,
+        storage: &'static TaskletStorage<T, C, COND_COUNT>,
+    );
+
+    /// Creates new resumable tasklet in the system.
+    ///
+    /// # Generic Parameters
+    /// * `T` - Type of the data processed by the tasklet.
+    /// * `C` - Type of the structure with tasklet context data.
+    /// * `COND_COUNT` - Number of tasklet conditions.
+    ///
+    /// # Parameters
+    /// * `config` - Tasklet creation configuration.
+    /// * `step_fn` - Tasklet step function.
+    /// * `storage` - Static memory storage where the tasklet should be allocated.
+    fn create_resumable_tasklet<T, C: Default, const COND_COUNT: usize>(
+        &'static self,
+        config: TaskletConfig,
+        step_fn: ResumableStepFn<T, C>,
+        storage: &'static TaskletStorage<T, C, COND_COUNT>,
+    );
+
+    /// Reserves tasklet that can be spawned after the system is started.
+    ///
+    /// # Generic Parameters
+    /// * `T` - Type of the data processed by the tasklet.
+    /// * `C` - Type of the structure with tasklet context data.
+    /// * `COND_COUNT` - Number of tasklet conditions.
+    ///
+    /// # Parameters
+    /// * `config` - Tasklet creation configuration.
+    /// * `step_fn` - Tasklet step function.
+    /// * `provider` - Data provider the tasklet is subscribed to when spawned.
+    /// * `spawner` - Static spawner of the tasklet.
+    fn reserve_tasklet<T, C: Default, const COND_COUNT: usize>(
+        &'static self,
+        config: TaskletConfig,
+        step_fn: StepFn<T, C>,
+        provider: &'static (dyn DataProvider<T> + Sync),
+        spawner: &'static TaskletSpawner<T, C, COND_COUNT>,
+    );
+
     /// Creates new message queue in the system.
     ///
     /// # Generic Parameters
@@ -81,6 +193,24 @@ pub trait InitApi {
         storage: &'static MessageQueueStorage<T, QUEUE_SIZE>,
     );
 
+    /// Creates new message queue with given overflow policy in the system.
+    ///
+    /// # Generic Parameters
+    /// * `T` - Type of the data stored in the queue.
+    /// * `QUEUE_SIZE` - Size of the queue.
+    ///
+    /// # Parameters
+    /// * `storage` - Static memory storage where the queue should be allocated.
+    /// * `overflow_policy` - Policy of handling data sent to the full queue.
+    ///
+    /// # Return
+    /// `()` if successful, `InitError` otherwise.
+    fn create_message_queue_with_policy<T, const QUEUE_SIZE: usize>(
+        &'static self,
+        storage: &'static MessageQueueStorage<T, QUEUE_SIZE>,
+        overflow_policy: OverflowPolicy,
+    );
+
     /// Creates new event in the system.
     ///
     /// # Parameters
@@ -90,6 +220,21 @@ pub trait InitApi {
     /// `()` if successful, `InitError` otherwise.
     fn create_event(&'static self, event_id: EventId, storage: &'static EventStorage);
 
+    /// Creates new event carrying typed data in the system.
+    ///
+    /// # Generic Parameters
+    /// * `T` - Type of the payload.
+    /// * `N` - Size of the payload queue of each subscribed tasklet.
+    ///
+    /// # Parameters
+    /// * `event_id` - Identifier of this event.
+    /// * `storage` - Static memory storage where the event should be allocated.
+    fn create_data_event<T: Clone, const N: usize>(
+        &'static self,
+        event_id: EventId,
+        storage: &'static DataEventStorage<T, N>,
+    );
+
     /// Creates new boolean condition in the system.
     ///
     /// # Parameters
@@ -103,6 +248,41 @@ pub trait InitApi {
         storage: &'static BooleanConditionStorage,
     );
 
+    /// Creates new barrier in the system.
+    ///
+    /// # Generic Parameters
+    /// * `N` - Maximum number of participants.
+    ///
+    /// # Parameters
+    /// * `storage` - Static memory storage where the barrier should be allocated.
+    fn create_barrier<const N: usize>(&'static self, storage: &'static BarrierStorage<N>);
+
+    /// Creates new timer in the system.
+    ///
+    /// # Parameters
+    /// * `action` - Action performed when the timer fires.
+    /// * `storage` - Static memory storage where the timer should be allocated.
+    fn create_timer(&'static self, action: TimerAction, storage: &'static TimerStorage);
+
+    /// Subscribes tasklet to the source of its data.
+    ///
+    /// Source has to provide data of the type processed by the tasklet, which is checked at
+    /// compile time.
+    ///
+    /// # Generic Parameters
+    /// * `T` - Type of the data.
+    /// * `C` - Type of the structure with tasklet context data.
+    /// * `COND_COUNT` - Number of tasklet conditions.
+    ///
+    /// # Parameters
+    /// * `tasklet_handle` - Handle to the target tasklet.
+    /// * `source` - Source of the data, ex. handle to the queue.
+    fn subscribe_tasklet<T, C, const COND_COUNT: usize>(
+        &'static self,
+        tasklet_handle: &TaskletHandle<T, C, COND_COUNT>,
+        source: &impl TaskletDataSource<T>,
+    );
+
     /// Subscribes tasklet to the queue.
     ///
     /// # Generic Parameters
@@ -140,6 +320,39 @@ pub trait InitApi {
         events: [EventId; EVENT_COUNT],
     );
 
+    /// Subscribes tasklet to the group of events, triggered by any or all of them.
+    ///
+    /// # Generic Parameters
+    /// * `C` - Type of the structure with tasklet context data.
+    /// * `COND_COUNT` - Number of tasklet conditions.
+    /// * `EVENT_COUNT` - Number of events in the group.
+    ///
+    /// # Parameters
+    /// * `tasklet_handle` - Handle to the target tasklet.
+    /// * `group` - Group of events.
+    fn subscribe_tasklet_to_event_group<C, const COND_COUNT: usize, const EVENT_COUNT: usize>(
+        &'static self,
+        tasklet_handle: &TaskletHandle<EventId, C, COND_COUNT>,
+        group: EventGroup<EVENT_COUNT>,
+    );
+
+    /// Subscribes tasklet to the event carrying typed data.
+    ///
+    /// # Generic Parameters
+    /// * `T` - Type of the payload.
+    /// * `C` - Type of the structure with tasklet context data.
+    /// * `COND_COUNT` - Number of tasklet conditions.
+    /// * `N` - Size of the payload queue.
+    ///
+    /// # Parameters
+    /// * `tasklet_handle` - Handle to the target tasklet.
+    /// * `event_handle` - Handle to the target event.
+    fn subscribe_tasklet_to_data_event<T: Clone, C, const COND_COUNT: usize, const N: usize>(
+        &'static self,
+        tasklet_handle: &TaskletHandle<T, C, COND_COUNT>,
+        event_handle: &DataEventHandle<T, N>,
+    );
+
     /// Subscribes tasklet to the boolean condition.
     ///
     /// # Generic Parameters
@@ -158,6 +371,22 @@ pub trait InitApi {
         condition_handle: &BooleanConditionHandle,
     );
 
+    /// Subscribes tasklet to the barrier, as one of its participants.
+    ///
+    /// # Generic Parameters
+    /// * `C` - Type of the structure with tasklet context data.
+    /// * `COND_COUNT` - Number of tasklet conditions.
+    /// * `N` - Maximum number of participants.
+    ///
+    /// # Parameters
+    /// * `tasklet_handle` - Handle to the target tasklet.
+    /// * `barrier_handle` - Handle to the target barrier.
+    fn subscribe_tasklet_to_barrier<C, const COND_COUNT: usize, const N: usize>(
+        &'static self,
+        tasklet_handle: &TaskletHandle<u32, C, COND_COUNT>,
+        barrier_handle: &BarrierHandle<N>,
+    );
+
     /// Subscribes tasklet to the cyclic execution.
     ///
     /// # Generic Parameters
@@ -178,12 +407,123 @@ pub trait InitApi {
         offset: Option<Duration>,
     );
 
+    /// Sets handling of missed activations of the cyclically executed tasklet.
+    ///
+    /// Missed activations are counted regardless of the policy, and are available with
+    /// [`RuntimeApi::get_missed_activation_count`](crate::api::RuntimeApi::get_missed_activation_count).
+    /// Tasklet has to be subscribed to the cyclic execution first.
+    ///
+    /// # Parameters
+    /// * `tasklet_handle` - Handle to the target tasklet.
+    /// * `policy` - Handling of missed activations.
+    /// * `event_handle` - Event emitted when activations are missed, `None` if shouldn't be emitted.
+    fn set_missed_activation_policy<C, const COND_COUNT: usize>(
+        &'static self,
+        tasklet_handle: &TaskletHandle<(), C, COND_COUNT>,
+        policy: MissedActivationPolicy,
+        event_handle: Option<&EventHandle>,
+    );
+
+    /// Locks phase of the cyclically executed tasklet to the external timebase.
+    ///
+    /// Activations are shifted on each
+    /// [`RuntimeApi::sync_cyclic_execution`](crate::api::RuntimeApi::sync_cyclic_execution), so
+    /// they occur `phase` after the sync signal. Tasklet has to be subscribed to the cyclic
+    /// execution with a period first.
+    ///
+    /// # Parameters
+    /// * `tasklet_handle` - Handle to the target tasklet.
+    /// * `phase` - Time from the sync signal to the activation.
+    /// * `max_correction` - Maximum shift of the activations on a single sync.
+    fn set_cyclic_phase_lock<C, const COND_COUNT: usize>(
+        &'static self,
+        tasklet_handle: &TaskletHandle<(), C, COND_COUNT>,
+        phase: Duration,
+        max_correction: Duration,
+    );
+
+    /// Subscribes tasklet to the custom data provider.
+    ///
+    /// Provider has to follow the [implementation contract](DataProvider#implementation-contract),
+    /// and notify returned receiver whenever new data becomes available.
+    ///
+    /// # Generic Parameters
+    /// * `T` - Type of the data.
+    /// * `C` - Type of the structure with tasklet context data.
+    /// * `COND_COUNT` - Number of tasklet conditions.
+    ///
+    /// # Parameters
+    /// * `tasklet_handle` - Handle to the target tasklet.
+    /// * `provider` - Custom data provider.
+    ///
+    /// # Return
+    /// Receiver which should be notified about new data.
+    fn subscribe_tasklet_to_provider<T, C, const COND_COUNT: usize>(
+        &'static self,
+        tasklet_handle: &TaskletHandle<T, C, COND_COUNT>,
+        provider: &'static (dyn DataProvider<T> + Sync),
+    ) -> DataReceiver;
+
+    /// Chains consumer tasklet to the producer tasklet.
+    ///
+    /// Each executed step of the producer schedules the consumer. Completions are coalesced, so
+    /// the consumer is executed once even if the producer was executed multiple times before it.
+    ///
+    /// # Generic Parameters
+    /// * `T` - Type of the data processed by the producer.
+    /// * `C` - Type of the structure with producer context data.
+    /// * `D` - Type of the structure with consumer context data.
+    /// * `P_COND_COUNT` - Number of producer conditions.
+    /// * `C_COND_COUNT` - Number of consumer conditions.
+    ///
+    /// # Parameters
+    /// * `producer_handle` - Handle to the producer tasklet.
+    /// * `consumer_handle` - Handle to the consumer tasklet.
+    fn chain_tasklets<T, C, D, const P_COND_COUNT: usize, const C_COND_COUNT: usize>(
+        &'static self,
+        producer_handle: &TaskletHandle<T, C, P_COND_COUNT>,
+        consumer_handle: &TaskletHandle<(), D, C_COND_COUNT>,
+    );
+
+    /// Chains consumer tasklet to the producer tasklet, passing output of the producer to the
+    /// consumer.
+    ///
+    /// After each executed step of the producer, its output is derived from its context by the
+    /// chain, and the consumer is scheduled to process it. If the producer is executed multiple
+    /// times before the consumer, the consumer receives only the latest output.
+    ///
+    /// # Generic Parameters
+    /// * `T` - Type of the data processed by the producer.
+    /// * `C` - Type of the structure with producer context data.
+    /// * `U` - Type of the output, processed by the consumer.
+    /// * `D` - Type of the structure with consumer context data.
+    /// * `P_COND_COUNT` - Number of producer conditions.
+    /// * `C_COND_COUNT` - Number of consumer conditions.
+    ///
+    /// # Parameters
+    /// * `producer_handle` - Handle to the producer tasklet.
+    /// * `consumer_handle` - Handle to the consumer tasklet.
+    /// * `chain` - Chain deriving the output.
+    fn chain_tasklets_with_output<
+        T,
+        C,
+        U: Send,
+        D,
+        const P_COND_COUNT: usize,
+        const C_COND_COUNT: usize,
+    >(
+        &'static self,
+        producer_handle: &TaskletHandle<T, C, P_COND_COUNT>,
+        consumer_handle: &TaskletHandle<U, D, C_COND_COUNT>,
+        chain: &'static TaskletChain<C, U>,
+    );
+
     /// Sets tasklet condition set.
     ///
     /// # Generic Parameters
     /// * `T` - Type of the data.
     /// * `C` - Type of the structure with tasklet context data.
-    /// * `COND_COUNT` - Number of conditions.
+    /// * `COND_COUNT` - Number of conditions and nested sets.
     ///
     /// # Parameters
     /// * `tasklet` - Handle to the target tasklet.
@@ -204,6 +544,332 @@ pub trait InitApi {
         time: Duration,
     );
 
+    /// Sets a handler which should be called when tasklet step execution exceeds its deadline.
+    ///
+    /// Deadline is set per tasklet in [`TaskletConfig::deadline`]. Handler is called after the
+    /// overrunning step finishes, with the name of the tasklet and the overrun time.
+    ///
+    /// # Parameters
+    /// * `handler` - Deadline miss handler.
+    fn set_deadline_miss_handler(&'static self, handler: DeadlineMissHandler);
+
+    /// Sets a handler which should be called when the system is overloaded.
+    ///
+    /// Overload is detected with `edf-scheduling` feature, when a step of a cyclic tasklet ends
+    /// after the absolute deadline of its release. Handler is called after that step finishes,
+    /// with the name of the tasklet and the time by which the deadline was missed.
+    ///
+    /// # Parameters
+    /// * `handler` - Overload handler.
+    fn set_overload_handler(&'static self, handler: DeadlineMissHandler);
+
+    /// Sets length of the window over which CPU load is measured.
+    ///
+    /// Default window is 1 second. Load is available with
+    /// [`RuntimeApi::get_cpu_load`](crate::api::RuntimeApi::get_cpu_load) after the first window
+    /// ends.
+    ///
+    /// # Parameters
+    /// * `window` - Length of the window.
+    fn set_cpu_load_window(&'static self, window: Duration);
+
+    /// Sets firmware image metadata, and verifies the image.
+    ///
+    /// Metadata is declared with [firmware_info](crate::firmware_info) macro. Image is verified
+    /// once, as its CRC calculation takes time proportional to the image size. Result of the
+    /// verification is available with
+    /// [`RuntimeApi::get_firmware_info`](crate::api::RuntimeApi::get_firmware_info).
+    ///
+    /// # Parameters
+    /// * `info` - Firmware image metadata.
+    fn set_firmware_info(&'static self, info: &'static FirmwareInfo);
+
+    /// Adds partition window at the end of the major frame.
+    ///
+    /// # Parameters
+    /// * `window` - Window to add.
+    fn add_partition_window(&'static self, window: PartitionWindow);
+
+    /// Assigns tasklet to a partition.
+    ///
+    /// # Generic Parameters
+    /// * `T` - Type of the data.
+    /// * `C` - Type of the structure with tasklet context data.
+    /// * `COND_COUNT` - Number of conditions.
+    ///
+    /// # Parameters
+    /// * `tasklet` - Handle to the target tasklet.
+    /// * `partition` - Partition to assign tasklet to.
+    fn assign_tasklet_to_partition<T, C, const COND_COUNT: usize>(
+        &'static self,
+        tasklet_handle: &TaskletHandle<T, C, COND_COUNT>,
+        partition: PartitionId,
+    );
+
+    /// Configures time-triggered schedule table.
+    ///
+    /// # Parameters
+    /// * `config` - Schedule table configuration.
+    fn configure_schedule_table(&'static self, config: ScheduleTableConfig);
+
+    /// Subscribes tasklet to the schedule table.
+    ///
+    /// # Generic Parameters
+    /// * `C` - Type of the structure with tasklet context data.
+    /// * `COND_COUNT` - Number of conditions.
+    ///
+    /// # Parameters
+    /// * `tasklet` - Handle to the target tasklet.
+    /// * `slots` - Slots in which tasklet is executed.
+    fn subscribe_tasklet_to_schedule<C, const COND_COUNT: usize>(
+        &'static self,
+        tasklet_handle: &TaskletHandle<(), C, COND_COUNT>,
+        slots: &'static [ScheduleSlot],
+    );
+
+    /// Creates new bridge forwarding messages from one queue to another.
+    ///
+    /// # Generic Parameters
+    /// * `T` - Type of the messages in the source queue.
+    /// * `U` - Type of the messages in the destination queue.
+    /// * `N` - Size of the source queue.
+    /// * `M` - Size of the destination queue.
+    ///
+    /// # Parameters
+    /// * `config` - Bridge configuration.
+    /// * `source` - Handle to the source queue.
+    /// * `destination` - Handle to the destination queue.
+    /// * `storage` - Static memory storage where the bridge should be allocated.
+    fn create_queue_bridge<T, U, const N: usize, const M: usize>(
+        &'static self,
+        config: QueueBridgeConfig<T, U>,
+        source: &MessageQueueHandle<T, N>,
+        destination: &MessageQueueHandle<U, M>,
+        storage: &'static QueueBridgeStorage<T, U, M>,
+    );
+
+    /// Creates new aggregator sending periodic aggregates of the samples from one queue to another.
+    ///
+    /// # Generic Parameters
+    /// * `T` - Type of the samples.
+    /// * `N` - Size of the source queue.
+    /// * `M` - Size of the destination queue.
+    ///
+    /// # Parameters
+    /// * `config` - Aggregator configuration.
+    /// * `source` - Handle to the queue with samples.
+    /// * `destination` - Handle to the queue for aggregates.
+    /// * `storage` - Static memory storage where the aggregator should be allocated.
+    fn create_aggregator<T: Copy + PartialOrd + Into<f64>, const N: usize, const M: usize>(
+        &'static self,
+        config: AggregatorConfig,
+        source: &MessageQueueHandle<T, N>,
+        destination: &MessageQueueHandle<Aggregate<T>, M>,
+        storage: &'static AggregatorStorage<T, M>,
+    );
+
+    /// Publishes fields of the tasklet context in telemetry.
+    ///
+    /// # Generic Parameters
+    /// * `T` - Type of the data.
+    /// * `C` - Type of the structure with tasklet context data.
+    /// * `COND_COUNT` - Number of conditions.
+    /// * `N` - Size of the destination queue.
+    ///
+    /// # Parameters
+    /// * `tasklet_handle` - Handle to the sampled tasklet.
+    /// * `period` - Minimum time between samples.
+    /// * `destination` - Handle to the queue for samples.
+    /// * `storage` - Static memory storage where the telemetry publisher should be allocated.
+    fn publish_context_telemetry<T, C: Telemetry, const COND_COUNT: usize, const N: usize>(
+        &'static self,
+        tasklet_handle: &TaskletHandle<T, C, COND_COUNT>,
+        period: Duration,
+        destination: &MessageQueueHandle<TelemetrySample, N>,
+        storage: &'static TelemetryStorage<N>,
+    );
+
+    /// Creates new bus manager executing transactions from the queue on the shared bus.
+    ///
+    /// # Generic Parameters
+    /// * `B` - Type of the bus driver.
+    /// * `N` - Size of the request queue.
+    /// * `L` - Size of the transaction buffer.
+    ///
+    /// # Parameters
+    /// * `config` - Bus manager configuration.
+    /// * `driver` - Bus driver.
+    /// * `requests` - Handle to the queue with transactions.
+    /// * `storage` - Static memory storage where the bus manager should be allocated.
+    fn create_bus_manager<B: BusDriver, const N: usize, const L: usize>(
+        &'static self,
+        config: BusManagerConfig<B>,
+        driver: B,
+        requests: &MessageQueueHandle<BusTransaction<B::Error, L>, N>,
+        storage: &'static BusManagerStorage<B, L>,
+    );
+
+    /// Creates new limit monitor checking values from the queue.
+    ///
+    /// # Generic Parameters
+    /// * `T` - Type of the monitored value.
+    /// * `N` - Size of the source queue.
+    ///
+    /// # Parameters
+    /// * `config` - Limit monitor configuration.
+    /// * `source` - Handle to the queue with monitored values.
+    /// * `storage` - Static memory storage where the limit monitor should be allocated.
+    fn create_limit_monitor<T, const N: usize>(
+        &'static self,
+        config: LimitMonitorConfig<T>,
+        source: &MessageQueueHandle<T, N>,
+        storage: &'static LimitMonitorStorage<T>,
+    ) where
+        T: Copy + PartialOrd + Add<Output = T> + Sub<Output = T>;
+
+    /// Creates new FDIR engine, mapping fault events to recovery actions.
+    ///
+    /// # Generic Parameters
+    /// * `RULE_COUNT` - Number of rules.
+    ///
+    /// # Parameters
+    /// * `config` - FDIR engine configuration.
+    /// * `storage` - Static memory storage where the FDIR engine should be allocated.
+    fn create_fdir_engine<const RULE_COUNT: usize>(
+        &'static self,
+        config: FdirConfig<RULE_COUNT>,
+        storage: &'static FdirStorage<RULE_COUNT>,
+    );
+
+    /// Adds external watchdog fed by the system.
+    ///
+    /// # Parameters
+    /// * `watchdog` - External watchdog driver.
+    fn add_external_watchdog(&'static self, watchdog: &'static mut dyn ExternalWatchdog);
+
+    /// Creates new software watchdog checking liveness of tasklets in an operational mode.
+    ///
+    /// # Generic Parameters
+    /// * `N` - Number of checked tasklets.
+    ///
+    /// # Parameters
+    /// * `config` - Software watchdog configuration.
+    /// * `storage` - Static memory storage where the watchdog should be allocated.
+    fn create_software_watchdog<const N: usize>(
+        &'static self,
+        config: SoftwareWatchdogConfig<N>,
+        storage: &'static SoftwareWatchdogStorage<N>,
+    );
+
+    /// Makes tasklet monitored by the health monitor, which feeds hardware watchdog only if the
+    /// tasklet checks in at least once per given period.
+    ///
+    /// # Generic Parameters
+    /// * `T` - Type of the data.
+    /// * `C` - Type of the structure with tasklet context data.
+    /// * `COND_COUNT` - Number of conditions.
+    ///
+    /// # Parameters
+    /// * `tasklet_handle` - Handle to the monitored tasklet.
+    /// * `period` - Maximum time between check-ins.
+    /// * `fault_code` - Code of the fault stored when tasklet misses its check-in.
+    fn monitor_tasklet<T, C, const COND_COUNT: usize>(
+        &'static self,
+        tasklet_handle: &TaskletHandle<T, C, COND_COUNT>,
+        period: Duration,
+        fault_code: u32,
+    );
+
+    /// Makes message queue monitored by the growth monitor, which emits given event when occupancy
+    /// of the queue grows without dropping for longer than given horizon.
+    ///
+    /// # Generic Parameters
+    /// * `T` - Type of the data stored in the queue.
+    /// * `QUEUE_SIZE` - Size of the queue.
+    ///
+    /// # Parameters
+    /// * `queue_handle` - Handle to the monitored queue.
+    /// * `name` - Name of the queue, logged when the queue is flagged.
+    /// * `horizon` - Time of growth after which the queue is flagged.
+    /// * `event_handle` - Event emitted when the queue is flagged.
+    fn monitor_queue_growth<T, const QUEUE_SIZE: usize>(
+        &'static self,
+        queue_handle: &MessageQueueHandle<T, QUEUE_SIZE>,
+        name: &'static str,
+        horizon: Duration,
+        event_handle: &EventHandle,
+    );
+
+    /// Sets behavior of the system when there is nothing to execute.
+    ///
+    /// # Parameters
+    /// * `strategy` - Idle strategy.
+    fn set_idle_strategy(&'static self, strategy: IdleStrategy);
+
+    /// Sets order of execution of tasklets with the same priority.
+    ///
+    /// # Parameters
+    /// * `policy` - Equal priority policy.
+    fn set_equal_priority_policy(&'static self, policy: EqualPriorityPolicy);
+
+    /// Sets a function called by the executor whenever no tasklet is ready for execution.
+    ///
+    /// # Parameters
+    /// * `hook` - Idle hook.
+    fn set_idle_hook(&'static self, hook: IdleHook);
+
+    /// Sets a function called when an internal operation of the system fails at runtime, instead
+    /// of panicking.
+    ///
+    /// # Parameters
+    /// * `hook` - Error hook.
+    fn set_error_hook(&'static self, hook: ErrorHook);
+
+    /// Sets period of the system configuration integrity check.
+    ///
+    /// # Parameters
+    /// * `period` - Period of the check.
+    fn set_configuration_check_period(&'static self, period: Duration);
+
+    /// Enables monitoring of the executor loop from a system timer interrupt.
+    ///
+    /// # Parameters
+    /// * `period` - Maximum time between iterations of the executor loop.
+    /// * `action` - Action performed when the loop stalls.
+    /// * `fault_code` - Code of the fault recorded when the loop stalls.
+    fn enable_scheduler_watchdog(
+        &'static self,
+        period: Duration,
+        action: StallAction,
+        fault_code: u32,
+    );
+
+    /// Enables containment of tasklets that overrun their deadlines.
+    ///
+    /// # Parameters
+    /// * `grace` - Time by which a step can overrun its deadline before the tasklet is contained.
+    /// * `fault_code` - Code of the fault recorded when a tasklet is contained.
+    fn enable_tasklet_containment(&'static self, grace: Duration, fault_code: u32);
+
+    /// Installs logger forwarding records of the `log` crate facade to the system log.
+    ///
+    /// # Parameters
+    /// * `max_level` - Maximum level of forwarded records.
+    #[cfg(feature = "log-facade")]
+    fn enable_log_facade(&'static self, max_level: log::LevelFilter);
+
+    /// Writes machine-readable manifest of the system configuration.
+    ///
+    /// # Parameters
+    /// * `writer` - Function called with each line of the manifest.
+    fn write_manifest(&'static self, writer: ManifestWriter);
+
+    /// Marks the end of a system configuration step in the startup report.
+    ///
+    /// # Parameters
+    /// * `name` - Name of the step.
+    fn mark_startup_step(&'static self, name: &'static str);
+
     /// Starts the system.
     fn start(&'static self) -> !;
 }
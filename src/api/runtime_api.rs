@@ -2,12 +2,21 @@
 //!
 //! This API can be used by the user in tasklet functions to interact with the system.
 
+use core::fmt;
+
 use critical_section::CriticalSection;
 
+use crate::cancellation_token::CancellationToken;
+use crate::data_provider::DataReceiver;
 use crate::error::RuntimeError;
 use crate::event::EventId;
 use crate::execution_monitor::ExecutionStats;
-use crate::tasklet::TaskletId;
+use crate::fault::FaultRecord;
+use crate::firmware_info::FirmwareInfo;
+use crate::partition::PartitionId;
+use crate::shutdown_reason::ShutdownReason;
+use crate::startup::{ComponentStatus, StartupInfo, StartupReport};
+use crate::tasklet::{IdentifiableTasklet, SpawnableTasklet, SuspendableTasklet, TaskletId};
 use crate::time::{Duration, Instant};
 
 /// System runtime API.
@@ -74,6 +83,27 @@ pub trait RuntimeApi {
         time: Duration,
     ) -> Result<bool, RuntimeError>;
 
+    /// Schedules event of given ID to be emitted periodically, starting one period from current
+    /// system time.
+    ///
+    /// Emissions are managed by the system, so the event can be used ex. as a heartbeat without a
+    /// cyclic tasklet dedicated to emitting it. Periodic emission replaces previous schedule of the
+    /// event, and can be stopped with [cancel_event](RuntimeApi::cancel_event).
+    ///
+    /// # Parameters
+    /// * `event_id` - ID of event to emit.
+    /// * `period` - Period of the emission.
+    /// * `count` - Number of emissions, `None` if event should be emitted until cancelled.
+    ///
+    /// # Return
+    /// `bool` indicating if event was rescheduled, `RuntimeError` if some error occurred.
+    fn emit_event_periodic(
+        &'static self,
+        event_id: EventId,
+        period: Duration,
+        count: Option<u32>,
+    ) -> Result<bool, RuntimeError>;
+
     /// Checks if event of given ID is scheduled to be emitted.
     ///
     /// If event was already scheduled at this time, that event will be rescheduled to the given time.
@@ -97,12 +127,68 @@ pub trait RuntimeApi {
     /// Clears event queue.
     fn clear_event_queue(&'static self);
 
+    /// Clears events pending for given tasklet.
+    ///
+    /// This cancels scheduled emissions of the events the tasklet is subscribed to, and discards
+    /// events that were already emitted, but not processed by the tasklet. It's meant to be used
+    /// when switching modes, so the tasklet doesn't react to stale events. Scheduled emission is
+    /// shared by all tasklets subscribed to the event, so it's cancelled for all of them.
+    ///
+    /// # Parameters
+    /// * `tasklet` - Handle to the tasklet.
+    ///
+    /// # Return
+    /// Number of cancelled and discarded events.
+    fn clear_pending_events(&'static self, tasklet: &dyn IdentifiableTasklet) -> usize;
+
     /// Gets current system time timestamp.
     fn get_system_time(&'static self) -> Instant;
 
     /// Gets time elapsed since execution started.
     fn get_elapsed_time(&'static self) -> Duration;
 
+    /// Checks if currently executed tasklet step should give way to other tasklets.
+    ///
+    /// Step should yield when it runs longer than the
+    /// [yield budget](crate::TaskletConfig::yield_budget) of the tasklet, or a tasklet with higher
+    /// priority is waiting for execution, or the step was [cancelled](crate::CancellationToken).
+    /// Checkpoints of resumable tasklets use [yield_needed](RuntimeApi::yield_needed) instead.
+    fn should_yield(&'static self) -> bool;
+
+    /// Checkpoint of the resumable tasklet step.
+    ///
+    /// Returns `true` when the step [should yield](RuntimeApi::should_yield) and can do it without
+    /// losing data, that is when it's a step of the
+    /// [resumable tasklet](crate::api::InitApi::create_resumable_tasklet). Such step stores the
+    /// progress in the tasklet context and returns [`StepResult::Incomplete`](crate::StepResult),
+    /// the tasklet is rescheduled and its next step is called with `None` to continue the work.
+    /// Data waiting for the tasklet stays in its queue until the step returns
+    /// [`StepResult::Complete`](crate::StepResult). Always `false` for other tasklets, which have
+    /// to finish processing of the received data in a single step.
+    fn yield_needed(&'static self) -> bool;
+
+    /// Returns token signalling cancellation of the currently executed tasklet step.
+    ///
+    /// Long steps should poll the token and return as soon as possible when it's cancelled.
+    fn cancellation_token(&'static self) -> CancellationToken;
+
+    /// Cancels currently executed tasklet step.
+    ///
+    /// Step is not interrupted, but its [cancellation token](crate::CancellationToken) is
+    /// cancelled until it returns. This can be called from interrupt handlers, ex. when a mode
+    /// switch or shutdown is commanded.
+    ///
+    /// # Return
+    /// `true` if step was cancelled, `false` if no step is executed or it was already cancelled.
+    fn cancel_step(&'static self) -> bool;
+
+    /// Reports that currently executed tasklet is healthy.
+    ///
+    /// Tasklets [monitored](crate::api::InitApi::monitor_tasklet) by the health monitor have to
+    /// check in at least once per configured period, otherwise hardware watchdog is not fed. Does
+    /// nothing if called outside of a monitored tasklet.
+    fn checkin(&'static self);
+
     /// Sets system time offset.
     ///
     /// # Parameters
@@ -115,16 +201,219 @@ pub trait RuntimeApi {
 
     /// Returns execution statistics for given tasklet.
     ///
+    /// Statistics (execution count, minimum, maximum and average step duration) are updated by
+    /// the executor after each step of the tasklet.
+    ///
+    /// # Parameters
+    /// * `tasklet` - Handle or ID of the tasklet.
+    ///
+    /// # Return
+    /// Execution statistics for this tasklet, or `None` if the tasklet wasn't woken yet. `None` is
+    /// also returned if the system configuration is corrupted, which is reported to the error hook.
+    fn get_execution_statistics(
+        &'static self,
+        tasklet: &dyn IdentifiableTasklet,
+    ) -> Option<ExecutionStats>;
+
+    /// Returns CPU load of the executor.
+    ///
+    /// Load is a percentage of time spent in executor loop iterations in which a tasklet was
+    /// executed, measured over a [configurable window](crate::api::InitApi::set_cpu_load_window).
+    ///
+    /// # Return
+    /// Load in the last complete window in percent, or `None` if the first window hasn't ended
+    /// yet.
+    fn get_cpu_load(&'static self) -> Option<u8>;
+
+    /// Returns total time of executor loop iterations in which a tasklet was executed.
+    fn get_busy_time(&'static self) -> Duration;
+
+    /// Returns total time of executor loop iterations in which no tasklet was executed.
+    fn get_idle_time(&'static self) -> Duration;
+
+    /// Returns highest stack usage of given tasklet.
+    ///
+    /// Stack usage is monitored only if `AERUGO_STACK_PAINT_SIZE` is set to a non-zero size of
+    /// the stack painted before each tasklet execution.
+    ///
+    /// # Parameters
+    /// * `tasklet` - Handle or ID of the tasklet.
+    ///
+    /// # Return
+    /// Stack usage high-watermark in bytes, or `None` if stack usage is not monitored or the
+    /// tasklet wasn't executed yet.
+    fn get_stack_usage(&'static self, tasklet: &dyn IdentifiableTasklet) -> Option<usize>;
+
+    /// Returns firmware image metadata.
+    ///
+    /// Metadata is available if it was [set](crate::api::InitApi::set_firmware_info) and the image
+    /// matches its CRC. On platforms without firmware image in memory (x86), image is not verified.
+    ///
+    /// # Return
+    /// Firmware image metadata if successful, `RuntimeError` otherwise.
+    fn get_firmware_info(&'static self) -> Result<FirmwareInfo, RuntimeError>;
+
+    /// Suspends tasklet.
+    ///
+    /// Suspended tasklet is not scheduled for execution, but stays subscribed to its data
+    /// provider. Data sent to the tasklet's queue accumulates until the tasklet is resumed.
+    ///
+    /// # Parameters
+    /// * `tasklet` - Handle to the tasklet.
+    ///
+    /// # Return
+    /// `true` if tasklet was suspended, `false` if it was already suspended.
+    fn suspend_tasklet(&'static self, tasklet: &dyn SuspendableTasklet) -> bool;
+
+    /// Resumes suspended tasklet.
+    ///
+    /// If data accumulated while the tasklet was suspended, tasklet is scheduled for execution.
+    ///
+    /// # Parameters
+    /// * `tasklet` - Handle to the tasklet.
+    ///
+    /// # Return
+    /// `true` if tasklet was resumed, `false` if it wasn't suspended.
+    fn resume_tasklet(&'static self, tasklet: &dyn SuspendableTasklet) -> bool;
+
+    /// Terminates tasklet.
+    ///
+    /// Terminated tasklet is suspended permanently and can't be resumed, and inputs waiting for it
+    /// are discarded. Its [finalizer](crate::TaskletConfig::finalize) is called immediately, or
+    /// after the step returns if the tasklet terminates itself. This should be called only from
+    /// tasklets, never from interrupt handlers.
+    ///
+    /// # Parameters
+    /// * `tasklet` - Handle to the tasklet.
+    ///
+    /// # Return
+    /// `true` if tasklet was terminated, `false` if it was already terminated.
+    fn terminate_tasklet(&'static self, tasklet: &dyn SuspendableTasklet) -> bool;
+
+    /// Requests controlled shutdown of the system.
+    ///
+    /// Currently executed step is finished and no other tasklet is scheduled. Then
+    /// [finalizers](crate::TaskletConfig::finalize) of all tasklets are called, in the order of
+    /// creation, reason is stored in memory preserved over the reset and the system is reset.
+    /// After the restart reason is available in the
+    /// [startup information](crate::StartupInfo::shutdown_reason). If shutdown is requested more
+    /// than once, the first reason is stored.
+    ///
+    /// # Parameters
+    /// * `reason` - Reason of the shutdown.
+    fn shutdown(&'static self, reason: ShutdownReason);
+
+    /// Spawns tasklet reserved during system initialization.
+    ///
     /// # Parameters
-    /// * `task_id` - ID of the task to
+    /// * `tasklet` - Spawner of the reserved tasklet.
     ///
     /// # Return
-    /// Execution statistics for this tasklet.
-    fn get_execution_statistics(&'static self, tasklet_id: &TaskletId) -> Option<ExecutionStats>;
+    /// Receiver of the tasklet data provider if successful, `RuntimeError` otherwise. Custom data
+    /// providers should notify the receiver when new data becomes available.
+    fn spawn_tasklet(
+        &'static self,
+        tasklet: &dyn SpawnableTasklet,
+    ) -> Result<DataReceiver, RuntimeError>;
+
+    /// Returns information about the system startup: reset cause and executed initialization path.
+    fn get_startup_info(&'static self) -> StartupInfo;
+
+    /// Returns durations of the system startup steps.
+    fn get_startup_report(&'static self) -> StartupReport;
+
+    /// Returns status of the optional component.
+    ///
+    /// # Parameters
+    /// * `name` - Name of the component.
+    ///
+    /// # Return
+    /// Status of the component, `None` if there is no optional component with that name.
+    fn get_component_status(&'static self, name: &str) -> Option<ComponentStatus>;
 
     /// Returns an iterator to the list with IDs of registered tasklets.
+    ///
+    /// If the system configuration is corrupted, it's reported to the error hook and the returned
+    /// list is empty.
     fn query_tasklets(&'static self) -> core::slice::Iter<TaskletId>;
 
+    /// Returns number of executions of tasklets from given partition that ended after their window.
+    ///
+    /// # Parameters
+    /// * `partition` - ID of the partition.
+    fn get_partition_overrun_count(&'static self, partition: PartitionId) -> u32;
+
+    /// Returns number of executions of the tasklet that ended after their schedule table slot.
+    ///
+    /// # Parameters
+    /// * `tasklet` - Tasklet, or its ID.
+    ///
+    /// # Return
+    /// Number of overruns, `None` if tasklet is not subscribed to the schedule table.
+    fn get_schedule_overrun_count(&'static self, tasklet: &dyn IdentifiableTasklet) -> Option<u32>;
+
+    /// Returns number of activations of the cyclically executed tasklet missed since the start of
+    /// the system.
+    ///
+    /// # Parameters
+    /// * `tasklet` - Tasklet, or its ID.
+    ///
+    /// # Return
+    /// Number of missed activations, `None` if tasklet is not subscribed to the cyclic execution.
+    fn get_missed_activation_count(&'static self, tasklet: &dyn IdentifiableTasklet)
+        -> Option<u32>;
+
+    /// Synchronizes phase locked cyclic execution with the external sync signal.
+    ///
+    /// This is meant to be called from the interrupt handler receiving the sync signal. Next
+    /// activation of the tasklet is shifted towards the phase set with
+    /// [`InitApi::set_cyclic_phase_lock`](crate::api::InitApi::set_cyclic_phase_lock), by no more
+    /// than the maximum correction.
+    ///
+    /// # Parameters
+    /// * `tasklet` - Tasklet, or its ID.
+    /// * `sync_time` - System time of the sync signal.
+    ///
+    /// # Return
+    /// `true` if activations are locked to the sync signal after the shift, `false` if the shift
+    /// was limited by the maximum correction, `RuntimeError` if the tasklet is not phase locked.
+    fn sync_cyclic_execution(
+        &'static self,
+        tasklet: &dyn IdentifiableTasklet,
+        sync_time: Instant,
+    ) -> Result<bool, RuntimeError>;
+
+    /// Writes snapshot of the system state.
+    ///
+    /// Snapshot contains status, last execution time, number of elements waiting in the data
+    /// source and values of conditions of each tasklet, and scheduled emission time and number of
+    /// pending activations of each event, written as one line per tasklet or event. It's meant
+    /// for debugging, ex. by writing it to the serial console on a command. If the system
+    /// configuration is corrupted, error is reported and only the system time is written, followed
+    /// by a line saying that the configuration is corrupted.
+    ///
+    /// # Parameters
+    /// * `writer` - Sink to which the snapshot is written.
+    ///
+    /// # Return
+    /// `()` if successful, error returned by the writer otherwise.
+    fn dump_system_state(&'static self, writer: &mut dyn fmt::Write) -> fmt::Result;
+
+    /// Returns the first fault reported since the latched fault was last cleared.
+    fn get_latched_fault(&'static self) -> Option<FaultRecord>;
+
+    /// Clears latched fault.
+    fn clear_latched_fault(&'static self);
+
+    /// Returns recorded fault of given age.
+    ///
+    /// # Parameters
+    /// * `index` - Age of the record, `0` being the most recent one.
+    ///
+    /// # Return
+    /// Fault record, or `None` if there is no record of that age.
+    fn get_fault_record(&'static self, index: usize) -> Option<FaultRecord>;
+
     /// Executes closure `f` in an interrupt-free context.
     ///
     /// # Generic Parameters
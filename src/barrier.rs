@@ -0,0 +1,207 @@
+//! Barrier synchronizing phases of tasklets.
+//!
+//! Barrier is a data source shared by a group of tasklets (participants), that are executed in
+//! lock-step phases, ex. processing of the sensor data before it's fused. Each participant receives
+//! the number of the phase it's released to, and [arrives](BarrierHandle::arrive) at the barrier
+//! when its part of the phase is done. When all participants have arrived, the next phase starts
+//! and all of them are released to it and woken.
+//!
+//! Participants are released to phase 0 when they are subscribed, so each of them is executed
+//! once after system start. Participant that hasn't arrived at the barrier is not executed again
+//! until the next phase starts, so it should arrive in the step in which its work for the phase is
+//! complete, ex. when a [resumable tasklet](crate::api::InitApi::create_resumable_tasklet) returns
+//! [StepResult::Complete](crate::StepResult::Complete).
+
+mod barrier_handle;
+mod barrier_storage;
+
+pub use self::barrier_handle::BarrierHandle;
+pub use self::barrier_storage::BarrierStorage;
+
+use crate::aerugo::Aerugo;
+use crate::data_provider::DataProvider;
+use crate::error::{RuntimeError, SystemError};
+use crate::internal_list::InternalList;
+use crate::manifest::DataSourceKind;
+use crate::mutex::Mutex;
+use crate::tasklet::TaskletPtr;
+
+/// Type for list of barrier participants.
+type ParticipantList<const N: usize> = InternalList<BarrierParticipant<N>, N>;
+
+/// Barrier synchronizing phases of tasklets.
+///
+/// # Generic Parameters
+/// * `N` - Maximum number of participants.
+pub(crate) struct Barrier<const N: usize> {
+    /// Participants of this barrier.
+    participants: ParticipantList<N>,
+    /// Number of the current phase.
+    phase: Mutex<u32>,
+}
+
+/// It is safe assuming that Barrier is not available from IRQ context before it's created and
+/// that modifications cannot be interrupted.
+///
+/// Barrier structure is hidden from the user. Functionalities are exposed to the user via
+/// [BarrierHandle].
+///
+/// Participants are only added with
+/// [subscribe_tasklet_to_barrier](crate::api::InitApi::subscribe_tasklet_to_barrier) which is not
+/// accessible from the IRQ context. State of the phase is guarded with [Mutex] which ensures that
+/// modifications cannot be interrupted.
+unsafe impl<const N: usize> Sync for Barrier<N> {}
+
+impl<const N: usize> Barrier<N> {
+    /// Creates new barrier.
+    pub(crate) const fn new() -> Self {
+        Barrier {
+            participants: ParticipantList::new(),
+            phase: Mutex::new(0),
+        }
+    }
+
+    /// Returns number of the current phase.
+    pub(crate) fn phase(&self) -> u32 {
+        self.phase.lock(|phase| *phase)
+    }
+
+    /// Returns number of participants of this barrier.
+    pub(crate) fn participant_count(&self) -> usize {
+        self.participants.len()
+    }
+
+    /// Subscribes tasklet to this barrier, and releases it to the current phase.
+    ///
+    /// # Parameters
+    /// * `tasklet` - Tasklet to subscribe.
+    ///
+    /// # Return
+    /// Reference to the participant, which is the data provider for the tasklet, if successful.
+    /// `SystemError` otherwise.
+    ///
+    /// # Safety
+    /// This is unsafe, because it mutably borrows the list of participants.
+    /// This is safe if it's executed in a critical section during system initialization
+    /// (before scheduler is started).
+    /// Accessing barrier from IRQ context during subscribing is undefined behaviour.
+    pub(crate) unsafe fn subscribe(
+        &'static self,
+        tasklet: TaskletPtr,
+    ) -> Result<&'static BarrierParticipant<N>, SystemError> {
+        match self
+            .participants
+            .add(BarrierParticipant::new(tasklet, self))
+        {
+            Ok(_) => {
+                let participant = self
+                    .participants
+                    .last()
+                    .expect("Failed to get reference to the participant");
+                participant.release(self.phase());
+
+                Ok(participant)
+            }
+            Err(_) => Err(SystemError::TaskletListFull),
+        }
+    }
+
+    /// Marks currently executed tasklet as arrived at this barrier. When all participants have
+    /// arrived, next phase is started and all participants are released to it.
+    ///
+    /// # Return
+    /// `true` if this arrival started the next phase, `false` if other participants haven't
+    /// arrived yet. `RuntimeError` if this is not called from a participant of this barrier.
+    pub(crate) fn arrive(&self) -> Result<bool, RuntimeError> {
+        let participant = Aerugo::current_tasklet()
+            .and_then(|current| {
+                self.participants
+                    .iter()
+                    .find(|participant| participant.tasklet == current)
+            })
+            .ok_or(RuntimeError::NotBarrierParticipant)?;
+
+        participant.arrived.lock(|arrived| *arrived = true);
+
+        let all_arrived = self
+            .participants
+            .iter()
+            .all(|participant| participant.arrived.lock(|arrived| *arrived));
+        if !all_arrived {
+            return Ok(false);
+        }
+
+        let phase = self.phase.lock(|phase| {
+            *phase = phase.wrapping_add(1);
+            *phase
+        });
+        for participant in &self.participants {
+            participant.release(phase);
+        }
+
+        Ok(true)
+    }
+}
+
+/// Participant of the barrier.
+///
+/// Participant is used as a data provider for the tasklet. It stores the phase the tasklet was
+/// released to, until the tasklet is executed.
+pub(crate) struct BarrierParticipant<const N: usize> {
+    /// Participating tasklet.
+    tasklet: TaskletPtr,
+    /// Barrier of the participant.
+    barrier: &'static Barrier<N>,
+    /// Phase the tasklet was released to, but wasn't executed in yet.
+    released: Mutex<Option<u32>>,
+    /// Whether the tasklet has arrived at the barrier in the current phase.
+    arrived: Mutex<bool>,
+}
+
+impl<const N: usize> BarrierParticipant<N> {
+    /// Creates new participant.
+    fn new(tasklet: TaskletPtr, barrier: &'static Barrier<N>) -> Self {
+        BarrierParticipant {
+            tasklet,
+            barrier,
+            released: Mutex::new(None),
+            arrived: Mutex::new(false),
+        }
+    }
+
+    /// Releases the participating tasklet to the given phase, and wakes it.
+    ///
+    /// # Parameters
+    /// * `phase` - Number of the phase.
+    fn release(&self, phase: u32) {
+        self.arrived.lock(|arrived| *arrived = false);
+        self.released.lock(|released| *released = Some(phase));
+
+        Aerugo::wake_tasklet(&self.tasklet);
+    }
+}
+
+impl<const N: usize> DataProvider<u32> for BarrierParticipant<N> {
+    /// Returns the phase the tasklet was released to.
+    fn get_data(&self) -> Option<u32> {
+        self.released.lock(|released| released.take())
+    }
+
+    /// Checks if the tasklet was released to a phase it wasn't executed in yet.
+    fn data_waiting(&self) -> bool {
+        self.released.lock(|released| released.is_some())
+    }
+
+    /// Discards the phase the tasklet was released to. Tasklet still has to arrive at the barrier
+    /// to let other participants continue.
+    fn flush(&self) -> usize {
+        self.released
+            .lock(|released| released.take().map_or(0, |_| 1))
+    }
+
+    fn source_kind(&self) -> DataSourceKind {
+        DataSourceKind::Barrier {
+            participant_count: self.barrier.participant_count(),
+        }
+    }
+}
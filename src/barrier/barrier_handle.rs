@@ -0,0 +1,48 @@
+//! Handle to a barrier.
+
+use crate::barrier::Barrier;
+use crate::error::RuntimeError;
+
+/// Barrier handle.
+///
+/// Handle is used to reference the barrier, and by its participants to arrive at it.
+///
+/// # Generic Parameters
+/// * `N` - Maximum number of participants.
+#[derive(Copy, Clone)]
+pub struct BarrierHandle<const N: usize> {
+    /// Reference to the barrier.
+    barrier: &'static Barrier<N>,
+}
+
+impl<const N: usize> BarrierHandle<N> {
+    /// Creates new barrier handle.
+    ///
+    /// # Parameters
+    /// * `barrier` - Reference to the barrier.
+    pub(crate) fn new(barrier: &'static Barrier<N>) -> Self {
+        BarrierHandle { barrier }
+    }
+
+    /// Returns number of the current phase.
+    pub fn phase(&self) -> u32 {
+        self.barrier.phase()
+    }
+
+    /// Marks currently executed tasklet as arrived at the barrier.
+    ///
+    /// When all participants have arrived, next phase is started, and all participants are
+    /// released to it and woken. Arriving again in the same phase has no effect.
+    ///
+    /// # Return
+    /// `true` if this arrival started the next phase, `false` if other participants haven't
+    /// arrived yet. `RuntimeError` if this is not called from a tasklet subscribed to the barrier.
+    pub fn arrive(&self) -> Result<bool, RuntimeError> {
+        self.barrier.arrive()
+    }
+
+    /// Returns reference to the barrier.
+    pub(crate) fn barrier(&self) -> &'static Barrier<N> {
+        self.barrier
+    }
+}
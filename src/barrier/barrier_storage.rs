@@ -0,0 +1,91 @@
+//! Static storage for [barrier](crate::barrier::Barrier).
+//!
+//! This module contains a barrier storage, which is a statically allocated memory that will
+//! store barrier structure for the duration of the system life.
+
+use core::cell::OnceCell;
+
+use crate::barrier::{Barrier, BarrierHandle};
+use crate::error::SystemError;
+
+/// Structure containing memory for Barrier creation.
+///
+/// As this system cannot use dynamic memory allocation, all structures have to be allocated
+/// statically. Per good practices user is separated from the actual implementation and instead
+/// only has to provide a static memory (via this structure) where the Barrier will be allocated.
+///
+/// # Generic Parameters
+/// * `N` - Maximum number of participants.
+pub struct BarrierStorage<const N: usize> {
+    /// Stored barrier.
+    barrier: OnceCell<Barrier<N>>,
+}
+
+/// It is safe assuming that stored Barrier is not available from the IRQ context before it is
+/// created and that initialization cannot be interrupted.
+///
+/// BarrierStorage is initialized only in [create_barrier](crate::api::InitApi::create_barrier)
+/// implemented in [Aerugo](crate::aerugo::Aerugo) which is not accessible from the IRQ context.
+///
+/// It's not possible to access the stored Barrier with mutable reference, so safety of Barrier
+/// modification are subject of its implementation.
+unsafe impl<const N: usize> Sync for BarrierStorage<N> {}
+
+impl<const N: usize> BarrierStorage<N> {
+    /// Creates new storage.
+    pub const fn new() -> Self {
+        BarrierStorage {
+            barrier: OnceCell::new(),
+        }
+    }
+
+    /// Returns initialization status of this storage.
+    pub fn is_initialized(&'static self) -> bool {
+        self.barrier.get().is_some()
+    }
+
+    /// Creates new handle to a barrier allocated in this storage.
+    ///
+    /// # Return
+    /// `Some(handle)` if this storage has been initialized. `None` otherwise.
+    pub fn create_handle(&'static self) -> Option<BarrierHandle<N>> {
+        self.barrier.get().map(BarrierHandle::new)
+    }
+
+    /// Initializes this storage.
+    ///
+    /// # Return
+    /// `()` if successful, `SystemError` otherwise.
+    ///
+    /// # Safety
+    /// This is unsafe, because it mutably borrows the stored barrier.
+    /// This is safe to call during system initialization (before scheduler is started).
+    /// Accessing storage from IRQ context during initialization is undefined behaviour.
+    pub(crate) unsafe fn init(&'static self) -> Result<(), SystemError> {
+        match self.barrier.set(Barrier::new()) {
+            Ok(_) => Ok(()),
+            Err(_) => Err(SystemError::StorageAlreadyInitialized),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn initialize() {
+        static STORAGE: BarrierStorage<2> = BarrierStorage::new();
+
+        assert!(!STORAGE.is_initialized());
+        assert!(STORAGE.create_handle().is_none());
+
+        let init_result = unsafe { STORAGE.init() };
+        assert!(init_result.is_ok());
+        assert!(STORAGE.is_initialized());
+        assert_eq!(STORAGE.create_handle().unwrap().phase(), 0);
+
+        let init_result = unsafe { STORAGE.init() };
+        assert_eq!(init_result, Err(SystemError::StorageAlreadyInitialized));
+    }
+}
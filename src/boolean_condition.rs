@@ -9,10 +9,13 @@ pub use self::boolean_condition_set::BooleanConditionSet;
 pub use self::boolean_condition_set::BooleanConditionSetType;
 pub use self::boolean_condition_storage::BooleanConditionStorage;
 
+pub(crate) use self::boolean_condition_set::ConditionTerm;
+
 use crate::aerugo::Aerugo;
 use crate::data_provider::DataProvider;
 use crate::error::SystemError;
 use crate::internal_list::InternalList;
+use crate::manifest::DataSourceKind;
 use crate::mutex::Mutex;
 use crate::tasklet::TaskletPtr;
 
@@ -116,4 +119,13 @@ impl DataProvider<bool> for BooleanCondition {
     fn data_waiting(&self) -> bool {
         false
     }
+
+    /// Does nothing, as there is no waiting data in the condition.
+    fn flush(&self) -> usize {
+        0
+    }
+
+    fn source_kind(&self) -> DataSourceKind {
+        DataSourceKind::BooleanCondition
+    }
 }
@@ -1,4 +1,9 @@
 //! Boolean condition set.
+//!
+//! Set combines its terms with a logical `and` or `or`. Terms are conditions, negated conditions
+//! and nested sets, so expressions like `(A && !B) || C` can be evaluated without a tasklet
+//! computing derived conditions. Nested sets are stored in the terms list of the outer set, so set
+//! capacity is the total number of conditions and nested sets in the whole expression.
 
 use heapless::Vec;
 
@@ -6,15 +11,37 @@ use crate::boolean_condition::{BooleanCondition, BooleanConditionHandle};
 use crate::error::SystemError;
 use crate::tasklet::TaskletPtr;
 
-/// Type of the set conditions list.
-type ConditionsList<const N: usize> = Vec<&'static BooleanCondition, N>;
+/// Type of the set terms list.
+type TermsList<const N: usize> = Vec<ConditionTerm, N>;
+
+/// Term of the condition set.
+#[derive(Copy, Clone)]
+pub(crate) enum ConditionTerm {
+    /// Condition.
+    Condition {
+        /// Condition.
+        condition: &'static BooleanCondition,
+        /// `true` if value of the condition is negated.
+        negated: bool,
+    },
+    /// Nested set, made of the terms following this one.
+    Set {
+        /// Type of the nested set.
+        set_type: BooleanConditionSetType,
+        /// Number of terms of the nested set, including terms of sets nested in it.
+        len: usize,
+    },
+}
 
 /// Set of boolean conditions.
+///
+/// # Generic Parameters
+/// * `N` - Maximum number of conditions and nested sets in the set.
 pub struct BooleanConditionSet<const N: usize> {
     /// Type of the set.
     set_type: BooleanConditionSetType,
-    /// Set conditions.
-    conditions: ConditionsList<N>,
+    /// Set terms.
+    terms: TermsList<N>,
 }
 
 impl<const N: usize> BooleanConditionSet<N> {
@@ -25,7 +52,7 @@ impl<const N: usize> BooleanConditionSet<N> {
     pub fn new(set_type: BooleanConditionSetType) -> Self {
         BooleanConditionSet {
             set_type,
-            conditions: ConditionsList::new(),
+            terms: TermsList::new(),
         }
     }
 
@@ -40,8 +67,11 @@ impl<const N: usize> BooleanConditionSet<N> {
     ) -> Self {
         BooleanConditionSet {
             set_type,
-            conditions: ConditionsList::from_slice(&conditions.map(|handle| handle.condition()))
-                .unwrap(),
+            terms: TermsList::from_slice(&conditions.map(|handle| ConditionTerm::Condition {
+                condition: handle.condition(),
+                negated: false,
+            }))
+            .unwrap(),
         }
     }
 
@@ -50,10 +80,48 @@ impl<const N: usize> BooleanConditionSet<N> {
     /// # Parameters
     /// * `handle` - Handle to the condition.
     pub fn add(&mut self, handle: &BooleanConditionHandle) -> Result<(), BooleanConditionSetError> {
-        match self.conditions.push(handle.condition()) {
-            Ok(_) => Ok(()),
-            Err(_) => Err(BooleanConditionSetError::SetFull),
+        self.push_condition(handle, false)
+    }
+
+    /// Add a negated condition to the set.
+    ///
+    /// Negated condition is `true` when the condition is `false`.
+    ///
+    /// # Parameters
+    /// * `handle` - Handle to the condition.
+    pub fn add_negated(
+        &mut self,
+        handle: &BooleanConditionHandle,
+    ) -> Result<(), BooleanConditionSetError> {
+        self.push_condition(handle, true)
+    }
+
+    /// Add a nested set to the set.
+    ///
+    /// Nested set is evaluated as a single term of this set. It takes one place in this set, and
+    /// a place for each of its own terms.
+    ///
+    /// # Parameters
+    /// * `set` - Nested set.
+    pub fn add_set<const M: usize>(
+        &mut self,
+        set: BooleanConditionSet<M>,
+    ) -> Result<(), BooleanConditionSetError> {
+        if self.terms.capacity() - self.terms.len() < set.terms.len() + 1 {
+            return Err(BooleanConditionSetError::SetFull);
         }
+
+        // This cannot fail, because free space was checked above.
+        self.terms
+            .push(ConditionTerm::Set {
+                set_type: set.set_type,
+                len: set.terms.len(),
+            })
+            .ok()
+            .unwrap();
+        self.terms.extend_from_slice(&set.terms).unwrap();
+
+        Ok(())
     }
 
     /// Registers tasklet to each condition in this set.
@@ -69,8 +137,10 @@ impl<const N: usize> BooleanConditionSet<N> {
     /// This is safe to call during system initialization (before scheduler is started).
     /// Accessing condition set from IRQ context during registering is undefined behaviour.
     pub(crate) unsafe fn register_tasklet(&self, tasklet: TaskletPtr) -> Result<(), SystemError> {
-        for cond in &self.conditions {
-            cond.register_tasklet(tasklet.clone())?;
+        for term in &self.terms {
+            if let ConditionTerm::Condition { condition, .. } = term {
+                condition.register_tasklet(tasklet.clone())?;
+            }
         }
 
         Ok(())
@@ -78,20 +148,33 @@ impl<const N: usize> BooleanConditionSet<N> {
 
     /// Evaluates value of this condition set.
     pub(crate) fn evaluate(&self) -> bool {
-        match self.set_type {
-            BooleanConditionSetType::And => self.evaluate_and(),
-            BooleanConditionSetType::Or => self.evaluate_or(),
-        }
+        evaluate_terms(self.set_type, &self.terms)
     }
 
-    /// Evaluates value of this condition set for `and` type.
-    fn evaluate_and(&self) -> bool {
-        self.conditions.iter().all(|cond| cond.get_value())
+    /// Returns type and terms of the set, described in the configuration manifest.
+    pub(crate) fn manifest(&'static self) -> (BooleanConditionSetType, &'static [ConditionTerm]) {
+        (self.set_type, &self.terms)
     }
 
-    /// Evaluates value of this condition set for `or` type.
-    fn evaluate_or(&self) -> bool {
-        self.conditions.iter().any(|cond| cond.get_value())
+    /// Adds condition term to the set.
+    ///
+    /// # Parameters
+    /// * `handle` - Handle to the condition.
+    /// * `negated` - `true` if value of the condition is negated.
+    fn push_condition(
+        &mut self,
+        handle: &BooleanConditionHandle,
+        negated: bool,
+    ) -> Result<(), BooleanConditionSetError> {
+        let term = ConditionTerm::Condition {
+            condition: handle.condition(),
+            negated,
+        };
+
+        match self.terms.push(term) {
+            Ok(_) => Ok(()),
+            Err(_) => Err(BooleanConditionSetError::SetFull),
+        }
     }
 }
 
@@ -100,7 +183,7 @@ impl Default for BooleanConditionSet<1> {
     fn default() -> Self {
         BooleanConditionSet {
             set_type: BooleanConditionSetType::And,
-            conditions: ConditionsList::new(),
+            terms: TermsList::new(),
         }
     }
 }
@@ -108,14 +191,45 @@ impl Default for BooleanConditionSet<1> {
 impl From<BooleanConditionHandle> for BooleanConditionSet<1> {
     /// Creates new condition set with given condition.
     fn from(handle: BooleanConditionHandle) -> Self {
-        BooleanConditionSet {
-            set_type: BooleanConditionSetType::And,
-            conditions: ConditionsList::from_slice(&[handle.condition()]).unwrap(),
-        }
+        BooleanConditionSet::from_array([&handle], BooleanConditionSetType::And)
     }
 }
 
+/// Evaluates terms of a set.
+///
+/// # Parameters
+/// * `set_type` - Type of the set.
+/// * `terms` - Terms of the set, with terms of the nested sets.
+fn evaluate_terms(set_type: BooleanConditionSetType, terms: &[ConditionTerm]) -> bool {
+    let mut value = match set_type {
+        BooleanConditionSetType::And => true,
+        BooleanConditionSetType::Or => false,
+    };
+
+    let mut remaining = terms;
+    while let Some((term, rest)) = remaining.split_first() {
+        let (term_value, rest) = match *term {
+            ConditionTerm::Condition { condition, negated } => {
+                (condition.get_value() != negated, rest)
+            }
+            ConditionTerm::Set { set_type, len } => {
+                let (nested, rest) = rest.split_at(len);
+                (evaluate_terms(set_type, nested), rest)
+            }
+        };
+
+        value = match set_type {
+            BooleanConditionSetType::And => value && term_value,
+            BooleanConditionSetType::Or => value || term_value,
+        };
+        remaining = rest;
+    }
+
+    value
+}
+
 /// Type of the boolean condition set
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
 pub enum BooleanConditionSetType {
     /// All conditions in the set has to be true.
     And,
@@ -247,4 +361,51 @@ mod tests {
 
         assert!(!condition_set.evaluate());
     }
+
+    #[cfg_attr(not(doc), test)]
+    fn evaluate_negated_and_nested_set() {
+        static CONDITION_A_STORAGE: BooleanConditionStorage = BooleanConditionStorage::new();
+        static CONDITION_B_STORAGE: BooleanConditionStorage = BooleanConditionStorage::new();
+        static CONDITION_C_STORAGE: BooleanConditionStorage = BooleanConditionStorage::new();
+        unsafe {
+            CONDITION_A_STORAGE
+                .init(true)
+                .expect("ConditionA init error");
+            CONDITION_B_STORAGE
+                .init(false)
+                .expect("ConditionB init error");
+            CONDITION_C_STORAGE
+                .init(false)
+                .expect("ConditionC init error");
+        };
+        let condition_a_handle = CONDITION_A_STORAGE.create_handle().unwrap();
+        let condition_b_handle = CONDITION_B_STORAGE.create_handle().unwrap();
+        let condition_c_handle = CONDITION_C_STORAGE.create_handle().unwrap();
+
+        // (A && !B) || C
+        let mut nested_set = BooleanConditionSet::<2>::new(BooleanConditionSetType::And);
+        nested_set.add(&condition_a_handle).unwrap();
+        nested_set.add_negated(&condition_b_handle).unwrap();
+
+        let mut condition_set = BooleanConditionSet::<4>::new(BooleanConditionSetType::Or);
+        condition_set.add_set(nested_set).unwrap();
+        condition_set.add(&condition_c_handle).unwrap();
+        assert!(condition_set.evaluate());
+
+        condition_b_handle.set_value(true);
+        assert!(!condition_set.evaluate());
+
+        condition_c_handle.set_value(true);
+        assert!(condition_set.evaluate());
+
+        // Nested set takes a place for itself and for each of its terms.
+        let mut full_set = BooleanConditionSet::<2>::new(BooleanConditionSetType::Or);
+        let mut nested_set = BooleanConditionSet::<2>::new(BooleanConditionSetType::And);
+        nested_set.add(&condition_a_handle).unwrap();
+        nested_set.add(&condition_b_handle).unwrap();
+        assert_eq!(
+            full_set.add_set(nested_set),
+            Err(BooleanConditionSetError::SetFull)
+        );
+    }
 }
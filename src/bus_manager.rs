@@ -0,0 +1,290 @@
+//! Manager serializing access to a bus shared by multiple devices.
+//!
+//! Bus manager is a system tasklet that owns the driver of a shared bus, like SPI or I2C. Tasklets
+//! don't access the driver directly, instead they send [transactions](BusTransaction) to the
+//! request queue of the manager. Manager executes them one at the time, applying configuration of
+//! the target device (chip select, clock speed) before each transfer, and sends the
+//! [response](BusResponse) to the reply queue given in the transaction. This replaces mutexes
+//! around the bus drivers, and keeps the bus configuration in one place.
+//!
+//! Number of completed and failed transactions is available via [BusManagerStorage].
+
+mod bus_driver;
+mod bus_manager_config;
+mod bus_manager_storage;
+mod bus_transaction;
+
+pub use self::bus_driver::{BusDeadline, BusDriver};
+pub use self::bus_manager_config::BusManagerConfig;
+pub use self::bus_manager_storage::BusManagerStorage;
+pub use self::bus_transaction::{BusError, BusResponse, BusTransaction, ReplyQueue};
+
+pub(crate) use self::bus_manager_storage::BusManagerStats;
+
+use heapless::Vec;
+
+use crate::api::RuntimeApi;
+use crate::time_source::TimeSource;
+
+/// Manager serializing access to a bus shared by multiple devices.
+///
+/// This is a context of the bus manager tasklet.
+///
+/// # Generic Parameters
+/// * `B` - Type of the bus driver.
+/// * `L` - Size of the transaction buffer.
+pub(crate) struct BusManager<B: BusDriver + 'static, const L: usize> {
+    /// Bus driver.
+    driver: B,
+    /// Configurations of the devices on the bus.
+    devices: &'static [B::DeviceConfig],
+    /// Index of the device the bus is currently configured for.
+    configured_device: Option<usize>,
+    /// Bus manager statistics.
+    stats: &'static BusManagerStats,
+    /// Time source used for transaction timeouts.
+    time_source: &'static TimeSource,
+}
+
+impl<B: BusDriver, const L: usize> BusManager<B, L> {
+    /// Creates new bus manager.
+    ///
+    /// # Parameters
+    /// * `config` - Bus manager configuration.
+    /// * `driver` - Bus driver.
+    /// * `stats` - Bus manager statistics.
+    /// * `time_source` - Time source used for transaction timeouts.
+    pub(crate) fn new(
+        config: &BusManagerConfig<B>,
+        driver: B,
+        stats: &'static BusManagerStats,
+        time_source: &'static TimeSource,
+    ) -> Self {
+        BusManager {
+            driver,
+            devices: config.devices,
+            configured_device: None,
+            stats,
+            time_source,
+        }
+    }
+
+    /// Executes single transaction.
+    ///
+    /// Bus is configured for the target device if previous transaction targeted other device, or
+    /// failed to configure the bus. If the transfer fails after its timeout, bus is recovered and
+    /// configured again for the next transaction.
+    ///
+    /// # Parameters
+    /// * `transaction` - Transaction to execute.
+    ///
+    /// # Return
+    /// Data read from the device if successful, `BusError` otherwise.
+    fn execute(
+        &mut self,
+        transaction: &BusTransaction<B::Error, L>,
+    ) -> Result<Vec<u8, L>, BusError<B::Error>> {
+        let device_index = transaction.device();
+        let device = self
+            .devices
+            .get(device_index)
+            .ok_or(BusError::InvalidDevice)?;
+
+        if self.configured_device != Some(device_index) {
+            self.configured_device = None;
+            self.driver.configure(device).map_err(BusError::Driver)?;
+            self.configured_device = Some(device_index);
+        }
+
+        let mut read = Vec::new();
+        read.resize(transaction.read_len(), 0)
+            .map_err(|_| BusError::InvalidLength)?;
+
+        let end = transaction
+            .timeout()
+            .map(|timeout| self.time_source.system_time() + timeout);
+        let deadline = BusDeadline::new(self.time_source, end);
+
+        self.driver.select(device);
+        let result = self
+            .driver
+            .transfer(device, transaction.write_data(), &mut read, &deadline);
+        self.driver.deselect(device);
+
+        match result {
+            Ok(_) => Ok(read),
+            Err(_) if deadline.is_expired() => {
+                self.driver.recover(device);
+                self.configured_device = None;
+                Err(BusError::Timeout)
+            }
+            Err(error) => Err(BusError::Driver(error)),
+        }
+    }
+
+    /// Executes transaction and sends the response.
+    ///
+    /// # Parameters
+    /// * `transaction` - Transaction to execute.
+    fn process(&mut self, transaction: &BusTransaction<B::Error, L>) {
+        let result = self.execute(transaction);
+
+        match result {
+            Ok(_) => self.stats.increment_completed(),
+            Err(_) => self.stats.increment_failed(),
+        }
+
+        if let Some(reply_queue) = transaction.reply_queue() {
+            let response = BusResponse {
+                device: transaction.device(),
+                tag: transaction.tag(),
+                result,
+            };
+
+            if reply_queue.send(response).is_err() {
+                self.stats.increment_lost_responses();
+            }
+        }
+    }
+}
+
+/// Step function of the bus manager tasklet.
+///
+/// # Parameters
+/// * `transaction` - Transaction from the request queue.
+/// * `manager` - Bus manager context.
+/// * `api` - System runtime API.
+pub(crate) fn bus_step<B: BusDriver, const L: usize>(
+    transaction: &BusTransaction<B::Error, L>,
+    manager: &mut BusManager<B, L>,
+    _: &'static dyn RuntimeApi,
+) {
+    manager.process(transaction);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::time::Duration;
+
+    /// Bus driver recording the transfers. Device 30 never completes the transfer.
+    #[derive(Default)]
+    struct MockBus {
+        /// Number of bus configurations.
+        configure_count: usize,
+        /// Number of bus recoveries.
+        recover_count: usize,
+        /// Device currently selected.
+        selected: Option<u8>,
+    }
+
+    impl BusDriver for MockBus {
+        type DeviceConfig = u8;
+        type Error = ();
+
+        fn configure(&mut self, _: &u8) -> Result<(), ()> {
+            self.configure_count += 1;
+            Ok(())
+        }
+
+        fn select(&mut self, device: &u8) {
+            self.selected = Some(*device);
+        }
+
+        fn deselect(&mut self, _: &u8) {
+            self.selected = None;
+        }
+
+        fn transfer(
+            &mut self,
+            device: &u8,
+            write: &[u8],
+            read: &mut [u8],
+            deadline: &BusDeadline,
+        ) -> Result<(), ()> {
+            assert_eq!(self.selected, Some(*device));
+            if *device == 30 {
+                while !deadline.is_expired() {}
+                return Err(());
+            }
+
+            for (r, w) in read.iter_mut().zip(write) {
+                *r = w + device;
+            }
+            Ok(())
+        }
+
+        fn recover(&mut self, _: &u8) {
+            self.recover_count += 1;
+        }
+    }
+
+    #[test]
+    fn execute_transactions() {
+        static DEVICES: [u8; 2] = [10, 20];
+        static STORAGE: BusManagerStorage<MockBus, 4> = BusManagerStorage::new();
+
+        let config = BusManagerConfig {
+            name: "BusManager",
+            priority: 0,
+            devices: &DEVICES,
+        };
+        static TIME_SOURCE: TimeSource = TimeSource::new();
+        let mut manager = BusManager::<MockBus, 4>::new(
+            &config,
+            MockBus::default(),
+            STORAGE.stats(),
+            &TIME_SOURCE,
+        );
+
+        let read = manager.execute(&BusTransaction::new(0, &[1, 2], 2).unwrap());
+        assert_eq!(read.as_deref(), Ok([11, 12].as_slice()));
+        let read = manager.execute(&BusTransaction::new(0, &[3], 1).unwrap());
+        assert_eq!(read.as_deref(), Ok([13].as_slice()));
+        assert_eq!(manager.driver.configure_count, 1);
+
+        let read = manager.execute(&BusTransaction::new(1, &[1], 1).unwrap());
+        assert_eq!(read.as_deref(), Ok([21].as_slice()));
+        assert_eq!(manager.driver.configure_count, 2);
+
+        let read = manager.execute(&BusTransaction::new(2, &[1], 1).unwrap());
+        assert_eq!(read, Err(BusError::InvalidDevice));
+        assert!(matches!(
+            BusTransaction::<(), 4>::new(0, &[0; 5], 0),
+            Err(BusError::InvalidLength)
+        ));
+    }
+
+    #[test]
+    fn recover_timed_out_transaction() {
+        static DEVICES: [u8; 2] = [10, 30];
+        static STORAGE: BusManagerStorage<MockBus, 4> = BusManagerStorage::new();
+        static TIME_SOURCE: TimeSource = TimeSource::new();
+        unsafe { TIME_SOURCE.set_system_start() };
+
+        let config = BusManagerConfig {
+            name: "BusManager",
+            priority: 0,
+            devices: &DEVICES,
+        };
+        let mut manager = BusManager::<MockBus, 4>::new(
+            &config,
+            MockBus::default(),
+            STORAGE.stats(),
+            &TIME_SOURCE,
+        );
+
+        let transaction = BusTransaction::new(1, &[1], 1)
+            .unwrap()
+            .with_timeout(Duration::millis(1));
+        assert_eq!(manager.execute(&transaction), Err(BusError::Timeout));
+        assert_eq!(manager.driver.recover_count, 1);
+        assert_eq!(manager.driver.selected, None);
+
+        // Bus is configured again after the recovery.
+        let read = manager.execute(&BusTransaction::new(0, &[1], 1).unwrap());
+        assert_eq!(read.as_deref(), Ok([11].as_slice()));
+        assert_eq!(manager.driver.configure_count, 2);
+    }
+}
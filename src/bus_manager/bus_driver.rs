@@ -0,0 +1,94 @@
+//! Driver of the bus shared by multiple devices.
+
+use crate::time::Instant;
+use crate::time_source::TimeSource;
+
+/// Deadline of the bus transfer.
+///
+/// Drivers waiting for the bus status should check the deadline in their wait loops, and return
+/// an error when it expired, so a stuck device doesn't block the bus manager forever.
+pub struct BusDeadline {
+    /// Time source of the system timebase.
+    time_source: &'static TimeSource,
+    /// Time after which the transfer is timed out, `None` if transfer has no timeout.
+    end: Option<Instant>,
+}
+
+impl BusDeadline {
+    /// Creates new deadline.
+    ///
+    /// # Parameters
+    /// * `time_source` - Time source of the system timebase.
+    /// * `end` - Time after which the transfer is timed out, `None` if transfer has no timeout.
+    pub(crate) fn new(time_source: &'static TimeSource, end: Option<Instant>) -> Self {
+        BusDeadline { time_source, end }
+    }
+
+    /// Returns `true` if the deadline has expired. Always `false` if transfer has no timeout.
+    pub fn is_expired(&self) -> bool {
+        self.end
+            .is_some_and(|end| self.time_source.system_time() >= end)
+    }
+}
+
+/// Driver of the bus shared by multiple devices.
+///
+/// Bus driver is owned by the [bus manager](crate::aerugo::Aerugo::create_bus_manager), which is
+/// the only one accessing it. Manager configures the bus for each device before its transaction,
+/// so drivers don't have to be guarded with mutexes.
+pub trait BusDriver {
+    /// Configuration of the device on the bus, for example chip select, clock speed or address.
+    type DeviceConfig: 'static;
+    /// Error returned by the driver.
+    type Error: 'static;
+
+    /// Configures the bus for the device, for example sets the clock speed and mode.
+    ///
+    /// This is called only when transaction targets a different device than the previous one.
+    ///
+    /// # Parameters
+    /// * `device` - Configuration of the device.
+    ///
+    /// # Return
+    /// `()` if successful, `Self::Error` otherwise.
+    fn configure(&mut self, device: &Self::DeviceConfig) -> Result<(), Self::Error>;
+
+    /// Selects the device before the transfer, for example asserts its chip select.
+    ///
+    /// # Parameters
+    /// * `device` - Configuration of the device.
+    fn select(&mut self, _device: &Self::DeviceConfig) {}
+
+    /// Deselects the device after the transfer, for example deasserts its chip select.
+    ///
+    /// # Parameters
+    /// * `device` - Configuration of the device.
+    fn deselect(&mut self, _device: &Self::DeviceConfig) {}
+
+    /// Transfers data to and from the device.
+    ///
+    /// # Parameters
+    /// * `device` - Configuration of the device.
+    /// * `write` - Data written to the device.
+    /// * `read` - Buffer for the data read from the device.
+    /// * `deadline` - Deadline of the transfer, checked when waiting for the bus.
+    ///
+    /// # Return
+    /// `()` if successful, `Self::Error` otherwise, also when the deadline has expired.
+    fn transfer(
+        &mut self,
+        device: &Self::DeviceConfig,
+        write: &[u8],
+        read: &mut [u8],
+        deadline: &BusDeadline,
+    ) -> Result<(), Self::Error>;
+
+    /// Recovers the bus after a timed out transfer, for example resets the peripheral, or clocks
+    /// out a device holding the bus.
+    ///
+    /// Bus is configured again for the next transaction.
+    ///
+    /// # Parameters
+    /// * `device` - Configuration of the device that timed out.
+    fn recover(&mut self, _device: &Self::DeviceConfig) {}
+}
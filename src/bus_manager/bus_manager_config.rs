@@ -0,0 +1,17 @@
+//! Configuration for creating bus managers.
+
+use super::BusDriver;
+
+/// Configuration for bus managers.
+///
+/// # Generic Parameters
+/// * `B` - Type of the bus driver.
+pub struct BusManagerConfig<B: BusDriver> {
+    /// Name of the bus manager tasklet.
+    pub name: &'static str,
+    /// Priority of the bus manager tasklet.
+    pub priority: u8,
+    /// Configurations of the devices on the bus. Transactions refer to devices by their index in
+    /// this table.
+    pub devices: &'static [B::DeviceConfig],
+}
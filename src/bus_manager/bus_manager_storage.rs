@@ -0,0 +1,102 @@
+//! Static storage for [bus manager](crate::bus_manager::BusManager).
+//!
+//! This module contains a bus manager storage, which is a statically allocated memory that will
+//! store the bus manager tasklet and its statistics for the duration of the system life.
+
+use super::{BusDriver, BusManager, BusTransaction};
+
+use crate::mutex::Mutex;
+use crate::tasklet::TaskletStorage;
+
+/// Statistics of the bus manager.
+pub(crate) struct BusManagerStats {
+    /// Number of completed transactions.
+    completed: Mutex<u32>,
+    /// Number of failed transactions.
+    failed: Mutex<u32>,
+    /// Number of responses that didn't fit in the reply queue.
+    lost_responses: Mutex<u32>,
+}
+
+impl BusManagerStats {
+    /// Creates new, empty statistics.
+    const fn new() -> Self {
+        BusManagerStats {
+            completed: Mutex::new(0),
+            failed: Mutex::new(0),
+            lost_responses: Mutex::new(0),
+        }
+    }
+
+    /// Increments number of completed transactions.
+    pub(crate) fn increment_completed(&self) {
+        self.completed.lock(|count| *count = count.wrapping_add(1));
+    }
+
+    /// Increments number of failed transactions.
+    pub(crate) fn increment_failed(&self) {
+        self.failed.lock(|count| *count = count.wrapping_add(1));
+    }
+
+    /// Increments number of lost responses.
+    pub(crate) fn increment_lost_responses(&self) {
+        self.lost_responses
+            .lock(|count| *count = count.wrapping_add(1));
+    }
+}
+
+/// Structure containing memory for bus manager creation.
+///
+/// Storage shall be a static variable and shall be initialized only once, before the system is started.
+///
+/// # Generic Parameters
+/// * `B` - Type of the bus driver.
+/// * `L` - Size of the transaction buffer.
+pub struct BusManagerStorage<B: BusDriver + 'static, const L: usize> {
+    /// Storage for the bus manager tasklet.
+    tasklet_storage: TaskletStorage<BusTransaction<B::Error, L>, BusManager<B, L>, 0>,
+    /// Bus manager statistics.
+    stats: BusManagerStats,
+}
+
+impl<B: BusDriver + 'static, const L: usize> BusManagerStorage<B, L> {
+    /// Creates new storage.
+    pub const fn new() -> Self {
+        BusManagerStorage {
+            tasklet_storage: TaskletStorage::new(),
+            stats: BusManagerStats::new(),
+        }
+    }
+
+    /// Returns initialization status of this storage.
+    pub fn is_initialized(&'static self) -> bool {
+        self.tasklet_storage.is_initialized()
+    }
+
+    /// Returns number of transactions completed successfully.
+    pub fn completed_count(&'static self) -> u32 {
+        self.stats.completed.lock(|count| *count)
+    }
+
+    /// Returns number of failed transactions.
+    pub fn failed_count(&'static self) -> u32 {
+        self.stats.failed.lock(|count| *count)
+    }
+
+    /// Returns number of responses dropped because the reply queue was full.
+    pub fn lost_response_count(&'static self) -> u32 {
+        self.stats.lost_responses.lock(|count| *count)
+    }
+
+    /// Returns storage for the bus manager tasklet.
+    pub(crate) fn tasklet_storage(
+        &'static self,
+    ) -> &'static TaskletStorage<BusTransaction<B::Error, L>, BusManager<B, L>, 0> {
+        &self.tasklet_storage
+    }
+
+    /// Returns bus manager statistics.
+    pub(crate) fn stats(&'static self) -> &'static BusManagerStats {
+        &self.stats
+    }
+}
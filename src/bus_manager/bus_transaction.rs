@@ -0,0 +1,193 @@
+//! Transactions executed by the bus manager.
+
+use heapless::Vec;
+
+use crate::error::RuntimeError;
+use crate::message_queue::{MessageQueue, MessageQueueHandle};
+use crate::time::Duration;
+
+/// Error of the bus transaction.
+///
+/// # Generic Parameters
+/// * `E` - Type of the bus driver error.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum BusError<E> {
+    /// Transaction targets device that is not configured in the bus manager.
+    InvalidDevice,
+    /// Transaction data doesn't fit in the transaction buffer.
+    InvalidLength,
+    /// Bus driver failed to execute the transaction.
+    Driver(E),
+    /// Transaction didn't complete before its timeout. Bus was recovered.
+    Timeout,
+}
+
+/// Response to the bus transaction.
+///
+/// # Generic Parameters
+/// * `E` - Type of the bus driver error.
+/// * `L` - Size of the transaction buffer.
+pub struct BusResponse<E: 'static, const L: usize> {
+    /// Index of the device the transaction was executed on.
+    pub device: usize,
+    /// Tag of the transaction.
+    pub tag: u32,
+    /// Data read from the device, or an error.
+    pub result: Result<Vec<u8, L>, BusError<E>>,
+}
+
+/// Queue to which the data can be sent.
+///
+/// This erases the size of the message queue.
+trait ReplySink<T> {
+    /// Sends data to the queue.
+    fn send(&self, data: T) -> Result<(), RuntimeError>;
+}
+
+impl<T, const N: usize> ReplySink<T> for MessageQueue<T, N> {
+    fn send(&self, data: T) -> Result<(), RuntimeError> {
+        self.send_data(data)
+    }
+}
+
+/// Queue receiving responses to the bus transactions.
+///
+/// It can be created from a handle to a message queue of any size.
+///
+/// # Generic Parameters
+/// * `T` - Type of the response.
+pub struct ReplyQueue<T: 'static> {
+    /// Reference to the queue.
+    queue: &'static dyn ReplySink<T>,
+}
+
+impl<T> ReplyQueue<T> {
+    /// Sends response to the queue.
+    ///
+    /// # Parameters
+    /// * `response` - Response to send.
+    ///
+    /// # Return
+    /// `()` if successful, `RuntimeError` otherwise.
+    pub(crate) fn send(&self, response: T) -> Result<(), RuntimeError> {
+        self.queue.send(response)
+    }
+}
+
+impl<T, const N: usize> From<MessageQueueHandle<T, N>> for ReplyQueue<T> {
+    fn from(handle: MessageQueueHandle<T, N>) -> Self {
+        ReplyQueue {
+            queue: handle.queue(),
+        }
+    }
+}
+
+/// Transaction executed by the bus manager.
+///
+/// # Generic Parameters
+/// * `E` - Type of the bus driver error.
+/// * `L` - Size of the transaction buffer.
+pub struct BusTransaction<E: 'static, const L: usize> {
+    /// Index of the target device.
+    device: usize,
+    /// Tag passed back in the response, to match it with the transaction.
+    tag: u32,
+    /// Data written to the device.
+    write: Vec<u8, L>,
+    /// Number of bytes read from the device.
+    read_len: usize,
+    /// Maximum duration of the transfer, `None` if it's not limited.
+    timeout: Option<Duration>,
+    /// Queue receiving the response, `None` if response is not needed.
+    reply_queue: Option<ReplyQueue<BusResponse<E, L>>>,
+}
+
+impl<E, const L: usize> BusTransaction<E, L> {
+    /// Creates new transaction.
+    ///
+    /// # Parameters
+    /// * `device` - Index of the target device in the [devices table](super::BusManagerConfig).
+    /// * `write` - Data written to the device.
+    /// * `read_len` - Number of bytes read from the device.
+    ///
+    /// # Return
+    /// Transaction if successful, `BusError::InvalidLength` if the data doesn't fit in the
+    /// transaction buffer.
+    pub fn new(device: usize, write: &[u8], read_len: usize) -> Result<Self, BusError<E>> {
+        if read_len > L {
+            return Err(BusError::InvalidLength);
+        }
+
+        Ok(BusTransaction {
+            device,
+            tag: 0,
+            write: Vec::from_slice(write).map_err(|_| BusError::InvalidLength)?,
+            read_len,
+            timeout: None,
+            reply_queue: None,
+        })
+    }
+
+    /// Sets tag of the transaction, which is passed back in the response.
+    ///
+    /// # Parameters
+    /// * `tag` - Transaction tag.
+    pub fn with_tag(self, tag: u32) -> Self {
+        BusTransaction { tag, ..self }
+    }
+
+    /// Sets maximum duration of the transfer, measured with the system time.
+    ///
+    /// Transfer that doesn't complete in time fails with [BusError::Timeout], and the bus is
+    /// [recovered](super::BusDriver::recover).
+    ///
+    /// # Parameters
+    /// * `timeout` - Maximum duration of the transfer.
+    pub fn with_timeout(self, timeout: Duration) -> Self {
+        BusTransaction {
+            timeout: Some(timeout),
+            ..self
+        }
+    }
+
+    /// Sets queue receiving the response to the transaction.
+    ///
+    /// # Parameters
+    /// * `reply_queue` - Queue receiving the response.
+    pub fn with_reply_queue(self, reply_queue: impl Into<ReplyQueue<BusResponse<E, L>>>) -> Self {
+        BusTransaction {
+            reply_queue: Some(reply_queue.into()),
+            ..self
+        }
+    }
+
+    /// Returns index of the target device.
+    pub fn device(&self) -> usize {
+        self.device
+    }
+
+    /// Returns tag of the transaction.
+    pub fn tag(&self) -> u32 {
+        self.tag
+    }
+
+    /// Returns data written to the device.
+    pub(crate) fn write_data(&self) -> &[u8] {
+        &self.write
+    }
+
+    /// Returns number of bytes read from the device.
+    pub(crate) fn read_len(&self) -> usize {
+        self.read_len
+    }
+
+    /// Returns maximum duration of the transfer.
+    pub(crate) fn timeout(&self) -> Option<Duration> {
+        self.timeout
+    }
+
+    /// Returns queue receiving the response.
+    pub(crate) fn reply_queue(&self) -> Option<&ReplyQueue<BusResponse<E, L>>> {
+        self.reply_queue.as_ref()
+    }
+}
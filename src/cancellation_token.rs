@@ -0,0 +1,46 @@
+//! Cooperative cancellation of tasklet steps.
+//!
+//! Executor is cooperative, so a long step can't be interrupted. Instead, step is cancelled and is
+//! expected to notice that by polling the [CancellationToken] returned by
+//! [cancellation_token](crate::api::RuntimeApi::cancellation_token), and return as soon as
+//! possible.
+//!
+//! Step is cancelled when:
+//! * [cancel_step](crate::api::RuntimeApi::cancel_step) is called, ex. by an interrupt handler
+//!   receiving a mode switch or shutdown command,
+//! * its tasklet becomes inactive, because it was suspended or its condition set evaluates to
+//!   `false` after a mode switch,
+//! * it is abandoned by the [tasklet containment](crate::aerugo::Aerugo::enable_tasklet_containment).
+//!
+//! Polling the token, or [should_yield](crate::api::RuntimeApi::should_yield) which returns `true`
+//! for cancelled steps, acknowledges the cancellation. Steps that return without acknowledging an
+//! explicit cancellation are counted in the
+//! [execution statistics](crate::ExecutionStats::ignored_cancellation_count), so tasklets that
+//! don't poll the token can be identified in test.
+
+use crate::executor::Executor;
+
+/// Token signalling cancellation of the currently executed tasklet step.
+#[derive(Copy, Clone)]
+pub struct CancellationToken {
+    /// Executor of the tasklet steps.
+    executor: &'static Executor,
+}
+
+impl CancellationToken {
+    /// Creates new cancellation token.
+    ///
+    /// # Parameters
+    /// * `executor` - Executor of the tasklet steps.
+    pub(crate) const fn new(executor: &'static Executor) -> Self {
+        CancellationToken { executor }
+    }
+
+    /// Returns `true` if currently executed step was cancelled.
+    ///
+    /// Returning `true` acknowledges the cancellation. Returns `false` if called outside of a
+    /// tasklet step.
+    pub fn is_cancelled(&self) -> bool {
+        self.executor.is_step_cancelled()
+    }
+}
@@ -0,0 +1,327 @@
+//! Integrity seal of the system configuration.
+//!
+//! System configuration (created tasklets and their subscriptions) is created during
+//! initialization and is assumed to be constant after the scheduler is started. Configuration seal
+//! is a CRC calculated over that configuration right before the scheduler start. Verifying the seal
+//! later allows to detect runtime corruption of the configuration. Configuration can be changed
+//! after the start only by spawning a reserved tasklet, which seals it again.
+//!
+//! Calculating the CRC takes time proportional to the number of tasklets, so the seal is verified
+//! by the executor periodically, with a configurable period, instead of on each loop iteration.
+
+use crate::error::SystemError;
+use crate::mutex::Mutex;
+use crate::tasklet::{TaskletId, TaskletPtr};
+use crate::time::Duration;
+
+/// CRC-32 (IEEE 802.3) calculator.
+#[derive(Copy, Clone)]
+pub(crate) struct Crc32 {
+    /// Current CRC value.
+    value: u32,
+}
+
+impl Crc32 {
+    /// Reversed CRC-32 polynomial.
+    const POLYNOMIAL: u32 = 0xEDB8_8320;
+
+    /// Creates new CRC calculator.
+    pub(crate) const fn new() -> Self {
+        Crc32 { value: 0xFFFF_FFFF }
+    }
+
+    /// Updates CRC with given bytes.
+    ///
+    /// # Parameters
+    /// * `bytes` - Bytes to update CRC with.
+    pub(crate) fn update(mut self, bytes: &[u8]) -> Self {
+        for byte in bytes {
+            self.value ^= *byte as u32;
+            for _ in 0..8 {
+                let mask = (self.value & 1).wrapping_neg();
+                self.value = (self.value >> 1) ^ (Self::POLYNOMIAL & mask);
+            }
+        }
+
+        self
+    }
+
+    /// Updates CRC with memory representation of the given value.
+    ///
+    /// # Parameters
+    /// * `value` - Value to update CRC with. Type of the value shouldn't contain any padding.
+    pub(crate) fn update_with<T: ?Sized>(self, value: &T) -> Self {
+        // SAFETY: This is safe, because the reference is valid for `size_of_val` bytes.
+        let bytes = unsafe {
+            core::slice::from_raw_parts(
+                value as *const T as *const u8,
+                core::mem::size_of_val(value),
+            )
+        };
+
+        self.update(bytes)
+    }
+
+    /// Returns calculated CRC value.
+    pub(crate) fn finalize(self) -> u32 {
+        !self.value
+    }
+}
+
+/// Calculates CRC of the system configuration.
+///
+/// # Parameters
+/// * `tasklets` - Tasklets created in the system.
+/// * `tasklet_ids` - IDs of tasklets created in the system.
+///
+/// # Return
+/// CRC of the configuration.
+pub(crate) fn configuration_crc(tasklets: &[TaskletPtr], tasklet_ids: &[TaskletId]) -> u32 {
+    let crc = Crc32::new().update_with(tasklets).update_with(tasklet_ids);
+
+    tasklets
+        .iter()
+        .fold(crc, |crc, tasklet_ptr| {
+            tasklet_ptr.update_configuration_crc(crc)
+        })
+        .finalize()
+}
+
+/// Configuration seal.
+pub(crate) struct ConfigurationSeal {
+    /// CRC of the sealed configuration.
+    crc: Mutex<Option<u32>>,
+    /// Period of the seal verification.
+    check_period: Mutex<Duration>,
+    /// Time since system start of the next seal verification.
+    next_check: Mutex<Duration>,
+}
+
+impl ConfigurationSeal {
+    /// Default period of the seal verification.
+    pub(crate) const DEFAULT_CHECK_PERIOD: Duration = Duration::millis(100);
+
+    /// Creates new, not sealed configuration seal.
+    pub(crate) const fn new() -> Self {
+        ConfigurationSeal {
+            crc: Mutex::new(None),
+            check_period: Mutex::new(Self::DEFAULT_CHECK_PERIOD),
+            next_check: Mutex::new(Duration::from_ticks(0)),
+        }
+    }
+
+    /// Sets period of the seal verification.
+    ///
+    /// # Parameters
+    /// * `period` - Period of the verification. Zero period verifies the seal on each executor
+    ///   loop iteration.
+    pub(crate) fn set_check_period(&self, period: Duration) {
+        self.check_period
+            .lock(|check_period| *check_period = period);
+    }
+
+    /// Checks if the seal should be verified, and if so, schedules the next verification.
+    ///
+    /// # Parameters
+    /// * `time` - Time since system start.
+    ///
+    /// # Return
+    /// `true` if the seal should be verified now.
+    pub(crate) fn is_check_due(&self, time: Duration) -> bool {
+        let period = self.check_period.lock(|period| *period);
+
+        self.next_check.lock(|next_check| {
+            if time < *next_check {
+                return false;
+            }
+
+            *next_check = time + period;
+            true
+        })
+    }
+
+    /// Seals the configuration.
+    ///
+    /// # Parameters
+    /// * `crc` - CRC of the configuration.
+    ///
+    /// # Return
+    /// `()` if successful, `SystemError` if configuration was already sealed.
+    ///
+    /// # Safety
+    /// This is safe to call once, when the scheduler is started.
+    pub(crate) unsafe fn seal(&self, crc: u32) -> Result<(), SystemError> {
+        self.crc.lock(|sealed_crc| match sealed_crc {
+            Some(_) => Err(SystemError::ConfigurationAlreadySealed),
+            None => {
+                *sealed_crc = Some(crc);
+                Ok(())
+            }
+        })
+    }
+
+    /// Seals the modified configuration again.
+    ///
+    /// # Parameters
+    /// * `previous_crc` - CRC of the configuration before the modification.
+    /// * `crc` - CRC of the modified configuration.
+    ///
+    /// # Return
+    /// `()` if successful, `SystemError` if configuration before the modification didn't match the
+    /// seal. Configuration that was not sealed yet is not sealed by this function.
+    ///
+    /// # Safety
+    /// This is safe to call only in the same critical section in which the configuration was
+    /// modified.
+    pub(crate) unsafe fn reseal(&self, previous_crc: u32, crc: u32) -> Result<(), SystemError> {
+        self.crc.lock(|sealed_crc| match sealed_crc {
+            Some(sealed) if *sealed != previous_crc => Err(SystemError::ConfigurationSealBroken),
+            Some(sealed) => {
+                *sealed = crc;
+                Ok(())
+            }
+            None => Ok(()),
+        })
+    }
+
+    /// Verifies the configuration against the seal.
+    ///
+    /// Configuration that was not sealed yet is always valid, as it can still be modified.
+    ///
+    /// # Parameters
+    /// * `crc` - Current CRC of the configuration.
+    ///
+    /// # Return
+    /// `()` if configuration is valid, `SystemError` otherwise.
+    pub(crate) fn verify(&self, crc: u32) -> Result<(), SystemError> {
+        self.crc.lock(|sealed_crc| match sealed_crc {
+            Some(sealed_crc) if *sealed_crc != crc => Err(SystemError::ConfigurationSealBroken),
+            _ => Ok(()),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::boolean_condition::{BooleanConditionSet, BooleanConditionSetType};
+    use crate::guard_zone::{ContextGuard, GuardZone};
+    use crate::tasklet::{Tasklet, TaskletConfig, TaskletStep};
+    use crate::tests::{MockConditionSet, MockDataProvider, MockRuntimeApi};
+
+    #[test]
+    fn crc32_check_value() {
+        let crc = Crc32::new().update(b"123456789").finalize();
+
+        assert_eq!(crc, 0xCBF4_3926);
+    }
+
+    #[test]
+    fn verify_seal() {
+        let seal = ConfigurationSeal::new();
+        assert!(seal.verify(0x1234).is_ok());
+
+        unsafe { seal.seal(0x1234).unwrap() };
+        assert!(seal.verify(0x1234).is_ok());
+        assert_eq!(
+            seal.verify(0x4321),
+            Err(SystemError::ConfigurationSealBroken)
+        );
+        assert_eq!(
+            unsafe { seal.seal(0x4321) },
+            Err(SystemError::ConfigurationAlreadySealed)
+        );
+    }
+
+    #[test]
+    fn periodic_check() {
+        let seal = ConfigurationSeal::new();
+        seal.set_check_period(Duration::millis(10));
+
+        assert!(seal.is_check_due(Duration::millis(0)));
+        assert!(!seal.is_check_due(Duration::millis(5)));
+        assert!(seal.is_check_due(Duration::millis(10)));
+        assert!(!seal.is_check_due(Duration::millis(19)));
+        assert!(seal.is_check_due(Duration::millis(25)));
+        assert!(!seal.is_check_due(Duration::millis(34)));
+        assert!(seal.is_check_due(Duration::millis(35)));
+
+        seal.set_check_period(Duration::millis(0));
+        assert!(seal.is_check_due(Duration::millis(45)));
+        assert!(seal.is_check_due(Duration::millis(45)));
+    }
+
+    #[cfg_attr(not(doc), test)]
+    #[allow(non_upper_case_globals)]
+    fn detect_corrupted_configuration() {
+        static mock_condition_set: MockConditionSet<0> = MockConditionSet::new();
+        let _ = mock_condition_set
+            .storage
+            .set(BooleanConditionSet::new(BooleanConditionSetType::And));
+        static mock_runtime_api: MockRuntimeApi = MockRuntimeApi {};
+        static mut tasklet_context: () = ();
+        static tasklet_context_guard_head: GuardZone = GuardZone::new();
+        static tasklet_context_guard_tail: GuardZone = GuardZone::new();
+        static tasklet: Tasklet<(), (), 0> = Tasklet::new(
+            TaskletId(0),
+            TaskletConfig {
+                name: "TestTasklet",
+                priority: 0,
+                deadline: None,
+                yield_budget: None,
+                finalize: None,
+            },
+            TaskletStep::ByValue(|_, _, _| {}),
+            unsafe { &mut tasklet_context },
+            ContextGuard::new(&tasklet_context_guard_head, &tasklet_context_guard_tail),
+            &mock_condition_set.storage,
+            &mock_runtime_api,
+        );
+
+        let tasklets = [tasklet.ptr()];
+        let mut tasklet_ids = [TaskletId(0)];
+
+        let seal = ConfigurationSeal::new();
+        unsafe {
+            seal.seal(configuration_crc(&tasklets, &tasklet_ids))
+                .unwrap()
+        };
+        assert!(seal
+            .verify(configuration_crc(&tasklets, &tasklet_ids))
+            .is_ok());
+
+        // Single bit flip in the tasklet list.
+        tasklet_ids[0] = TaskletId(1);
+        assert_eq!(
+            seal.verify(configuration_crc(&tasklets, &tasklet_ids)),
+            Err(SystemError::ConfigurationSealBroken)
+        );
+
+        // Tasklet subscribed after the configuration was sealed.
+        tasklet_ids[0] = TaskletId(0);
+        static mut mock_data_provider: MockDataProvider = MockDataProvider::new();
+        unsafe { tasklet.subscribe(&mock_data_provider).unwrap() };
+        assert_eq!(
+            seal.verify(configuration_crc(&tasklets, &tasklet_ids)),
+            Err(SystemError::ConfigurationSealBroken)
+        );
+    }
+
+    #[test]
+    fn reseal_modified_configuration() {
+        let seal = ConfigurationSeal::new();
+        unsafe { seal.seal(0x1234).unwrap() };
+
+        assert_eq!(
+            unsafe { seal.reseal(0x4321, 0x5678) },
+            Err(SystemError::ConfigurationSealBroken)
+        );
+        assert!(unsafe { seal.reseal(0x1234, 0x5678) }.is_ok());
+        assert!(seal.verify(0x5678).is_ok());
+        assert_eq!(
+            seal.verify(0x1234),
+            Err(SystemError::ConfigurationSealBroken)
+        );
+    }
+}
@@ -2,10 +2,17 @@
 //!
 //! This module contains a structure which holds information about cyclic execution of tasklets.
 
+use aerugo_hal::AerugoHal;
+
 use crate::aerugo::Aerugo;
 use crate::data_provider::DataProvider;
+use crate::event::Event;
+use crate::hal::Hal;
+use crate::manifest::DataSourceKind;
+use crate::missed_activation_policy::MissedActivationPolicy;
 use crate::mutex::Mutex;
-use crate::tasklet::TaskletPtr;
+use crate::phase_lock::PhaseLock;
+use crate::tasklet::{TaskletPtr, TaskletStatus};
 use crate::time::{Duration, Instant};
 
 /// Cyclic execution information.
@@ -14,8 +21,22 @@ pub(crate) struct CyclicExecution {
     next_execution_time: Mutex<Instant>,
     /// Period of cyclic execution.
     period: Option<Duration>,
+    /// Delay of the next wakeup after the next execution time, selected by the HAL.
+    jitter: Mutex<Duration>,
     /// Tasklet subscribed for cyclic execution.
     tasklet: TaskletPtr,
+    /// `true` if pending wakeup of the tasklet was cancelled by flushing.
+    wakeup_cancelled: Mutex<bool>,
+    /// Number of activations waiting for the execution of the tasklet.
+    pending_activations: Mutex<u32>,
+    /// Number of activations missed since the start of the system.
+    missed_activations: Mutex<u32>,
+    /// Handling of missed activations.
+    missed_activation_policy: Mutex<MissedActivationPolicy>,
+    /// Event emitted when activations are missed.
+    missed_activation_event: Mutex<Option<&'static Event>>,
+    /// Phase lock to the external timebase, `None` if activations are aligned to the system start.
+    phase_lock: Mutex<Option<PhaseLock>>,
 }
 
 impl CyclicExecution {
@@ -39,44 +60,195 @@ impl CyclicExecution {
         CyclicExecution {
             next_execution_time,
             period,
+            jitter: Duration::from_ticks(0).into(),
             tasklet,
+            wakeup_cancelled: false.into(),
+            pending_activations: 0.into(),
+            missed_activations: 0.into(),
+            missed_activation_policy: MissedActivationPolicy::default().into(),
+            missed_activation_event: None.into(),
+            phase_lock: None.into(),
         }
     }
 
+    /// Returns tasklet subscribed for cyclic execution.
+    pub(crate) fn tasklet(&self) -> &TaskletPtr {
+        &self.tasklet
+    }
+
+    /// Sets handling of missed activations.
+    ///
+    /// # Parameters
+    /// * `policy` - Handling of missed activations.
+    /// * `event` - Event emitted when activations are missed, `None` if shouldn't be emitted.
+    pub(crate) fn set_missed_activation_policy(
+        &self,
+        policy: MissedActivationPolicy,
+        event: Option<&'static Event>,
+    ) {
+        self.missed_activation_policy.lock(|p| *p = policy);
+        self.missed_activation_event.lock(|e| *e = event);
+    }
+
+    /// Sets phase lock of the activations to the external timebase.
+    ///
+    /// # Parameters
+    /// * `phase_lock` - Phase lock configuration.
+    ///
+    /// # Return
+    /// `true` if successful, `false` if the execution has no period.
+    pub(crate) fn set_phase_lock(&self, phase_lock: PhaseLock) -> bool {
+        if self.period.is_none() {
+            return false;
+        }
+
+        self.phase_lock.lock(|lock| *lock = Some(phase_lock));
+        true
+    }
+
+    /// Shifts next activation towards the external timebase.
+    ///
+    /// # Parameters
+    /// * `sync_time` - Time of the sync signal.
+    ///
+    /// # Return
+    /// `true` if the activations are locked after the shift, `false` if the shift was limited, or
+    /// `None` if the execution is not phase locked.
+    pub(crate) fn sync(&self, sync_time: Instant) -> Option<bool> {
+        let phase_lock = self.phase_lock.lock(|lock| *lock)?;
+        let period = self.period?;
+
+        Some(self.next_execution_time.lock(|next| {
+            let (corrected, locked) = phase_lock.correct(*next, sync_time, period);
+            *next = corrected;
+            locked
+        }))
+    }
+
+    /// Returns number of activations missed since the start of the system.
+    pub(crate) fn missed_activation_count(&self) -> u32 {
+        self.missed_activations.lock(|missed| *missed)
+    }
+
     /// Wakes that stored tasklet if the time for it's execution has come.
     ///
     /// # Parameters
     /// * `current_time` - Current system time.
     pub(crate) fn wake_if_should_execute(&self, current_time: Instant) {
         if let Some(period) = self.period {
-            if self.next_execution_time.lock(|next| current_time >= *next) {
-                Aerugo::wake_tasklet(&self.tasklet);
+            let jitter = self.jitter.lock(|jitter| *jitter);
+            if self
+                .next_execution_time
+                .lock(|next| current_time >= *next + jitter)
+            {
+                self.wakeup_cancelled.lock(|cancelled| *cancelled = false);
+                // Deadline of the release is the start of the next period.
+                if self.tasklet.get_status() == TaskletStatus::Sleeping {
+                    let release_time = self.next_execution_time.lock(|next| *next);
+                    self.tasklet
+                        .set_absolute_deadline(Some(release_time + period));
+                }
 
-                // Calculate next execution time, skipping any missed executions
-                self.next_execution_time.lock(|next| {
+                // Calculate next execution time, skipping any missed executions. Jitter doesn't
+                // accumulate, so the tasklet stays in phase with its period.
+                let missed = self.next_execution_time.lock(|next| {
+                    let mut activations = 0u32;
                     while current_time >= *next {
-                        *next += period
+                        *next += period;
+                        activations = activations.saturating_add(1);
                     }
+                    activations.saturating_sub(1)
                 });
+
+                let policy = self.missed_activation_policy.lock(|policy| *policy);
+                self.pending_activations
+                    .lock(|pending| *pending = policy.pending_activations(*pending, missed));
+
+                if missed > 0 {
+                    self.missed_activations
+                        .lock(|count| *count = count.saturating_add(missed));
+                    if let Some(event) = self.missed_activation_event.lock(|event| *event) {
+                        event.emit();
+                    }
+                }
+
+                Aerugo::wake_tasklet(&self.tasklet);
+                self.jitter
+                    .lock(|jitter| *jitter = Hal::wakeup_jitter(period));
             }
         } else {
+            self.wakeup_cancelled.lock(|cancelled| *cancelled = false);
             Aerugo::wake_tasklet(&self.tasklet);
         }
     }
 }
 
+impl CyclicExecution {
+    /// Returns time until the next wakeup of the stored tasklet.
+    ///
+    /// # Parameters
+    /// * `current_time` - Current system time.
+    ///
+    /// # Return
+    /// Time until the next wakeup, zero if it's due or tasklet is woken whenever possible.
+    pub(crate) fn time_to_next_execution(&self, current_time: Instant) -> Duration {
+        if self.period.is_none() {
+            return Duration::from_ticks(0);
+        }
+
+        let jitter = self.jitter.lock(|jitter| *jitter);
+        self.next_execution_time.lock(|next| {
+            (*next + jitter)
+                .checked_duration_since(current_time)
+                .unwrap_or(Duration::from_ticks(0))
+        })
+    }
+}
+
 impl DataProvider<()> for CyclicExecution {
-    /// Returns `Some()`.
+    /// Returns `Some()` and consumes one pending activation, unless pending wakeup was cancelled.
     fn get_data(&self) -> Option<()> {
-        Some(())
+        if self.wakeup_cancelled.lock(core::mem::take) {
+            None
+        } else {
+            self.pending_activations
+                .lock(|pending| *pending = pending.saturating_sub(1));
+            Some(())
+        }
     }
 
-    /// Returns false, as there is no waiting data for the execution.
+    /// Returns `true` if there are pending activations.
     ///
-    /// Cyclic execution has a period for execution, and is scheduled by the
-    /// [crate::cyclic_execution_manager::CyclicExecutionManager], but doesn't store any
-    /// data that is 'waiting' for the scheduling purposes.
+    /// Cyclic execution is scheduled by the
+    /// [crate::cyclic_execution_manager::CyclicExecutionManager], and activations are pending
+    /// only after a wake, or when missed activations are caught up.
     fn data_waiting(&self) -> bool {
-        false
+        self.pending_activations.lock(|pending| *pending > 0)
+    }
+
+    fn waiting_count(&self) -> usize {
+        self.pending_activations.lock(|pending| *pending as usize)
+    }
+
+    /// Cancels pending wakeup of the tasklet and discards pending activations.
+    ///
+    /// Next execution time is not changed, so the tasklet stays in phase with its period.
+    fn flush(&self) -> usize {
+        let pending = self.pending_activations.lock(core::mem::take) as usize;
+
+        self.wakeup_cancelled
+            .lock(|cancelled| match self.tasklet.get_status() {
+                TaskletStatus::Waiting => {
+                    *cancelled = true;
+                    pending.max(1)
+                }
+                _ => pending,
+            })
+    }
+
+    fn source_kind(&self) -> DataSourceKind {
+        DataSourceKind::CyclicExecution {
+            period: self.period,
+        }
     }
 }
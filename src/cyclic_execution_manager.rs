@@ -5,10 +5,13 @@
 
 use crate::aerugo::Aerugo;
 use crate::cyclic_execution::CyclicExecution;
-use crate::error::SystemError;
+use crate::error::{RuntimeError, SystemError};
+use crate::event::Event;
 use crate::internal_list::InternalList;
-use crate::tasklet::TaskletPtr;
-use crate::time::Duration;
+use crate::missed_activation_policy::MissedActivationPolicy;
+use crate::phase_lock::PhaseLock;
+use crate::tasklet::{TaskletId, TaskletPtr};
+use crate::time::{Duration, Instant};
 use crate::time_source::TimeSource;
 
 /// List of cyclic executions registered in the system.
@@ -75,6 +78,108 @@ impl CyclicExecutionManager {
         Ok(self.cyclic_executions.last().unwrap())
     }
 
+    /// Sets handling of missed activations of the tasklet.
+    ///
+    /// # Parameters
+    /// * `tasklet` - Tasklet subscribed for cyclic execution.
+    /// * `policy` - Handling of missed activations.
+    /// * `event` - Event emitted when activations are missed, `None` if shouldn't be emitted.
+    ///
+    /// # Return
+    /// `()` if successful, `SystemError` if tasklet is not subscribed for cyclic execution.
+    pub(crate) fn set_missed_activation_policy(
+        &'static self,
+        tasklet: &TaskletPtr,
+        policy: MissedActivationPolicy,
+        event: Option<&'static Event>,
+    ) -> Result<(), SystemError> {
+        match self.cyclic_execution(tasklet.get_id()) {
+            Some(cyclic_execution) => {
+                cyclic_execution.set_missed_activation_policy(policy, event);
+                Ok(())
+            }
+            None => Err(SystemError::TaskletNotSubscribed(tasklet.get_name())),
+        }
+    }
+
+    /// Sets phase lock of the tasklet activations to the external timebase.
+    ///
+    /// # Parameters
+    /// * `tasklet` - Tasklet subscribed for cyclic execution.
+    /// * `phase` - Time from the sync signal to the activation.
+    /// * `max_correction` - Maximum shift of the activations on a single sync.
+    ///
+    /// # Return
+    /// `()` if successful, `SystemError` if tasklet is not subscribed for cyclic execution with a
+    /// period.
+    pub(crate) fn set_phase_lock(
+        &'static self,
+        tasklet: &TaskletPtr,
+        phase: Duration,
+        max_correction: Duration,
+    ) -> Result<(), SystemError> {
+        let phase_lock = PhaseLock::new(phase, max_correction);
+
+        match self.cyclic_execution(tasklet.get_id()) {
+            Some(cyclic_execution) if cyclic_execution.set_phase_lock(phase_lock) => Ok(()),
+            _ => Err(SystemError::TaskletNotSubscribed(tasklet.get_name())),
+        }
+    }
+
+    /// Shifts next activation of the tasklet towards the external timebase.
+    ///
+    /// # Parameters
+    /// * `tasklet_id` - ID of the tasklet.
+    /// * `sync_time` - Time of the sync signal.
+    ///
+    /// # Return
+    /// `true` if the activations are locked after the shift, `false` if the shift was limited,
+    /// `RuntimeError` if the tasklet activations are not phase locked.
+    pub(crate) fn sync(
+        &'static self,
+        tasklet_id: TaskletId,
+        sync_time: Instant,
+    ) -> Result<bool, RuntimeError> {
+        self.cyclic_execution(tasklet_id)
+            .and_then(|cyclic_execution| cyclic_execution.sync(sync_time))
+            .ok_or(RuntimeError::CyclicExecutionNotPhaseLocked)
+    }
+
+    /// Returns number of missed activations of the tasklet.
+    ///
+    /// # Parameters
+    /// * `tasklet_id` - ID of the tasklet.
+    ///
+    /// # Return
+    /// Number of missed activations, `None` if tasklet is not subscribed for cyclic execution.
+    pub(crate) fn missed_activation_count(&'static self, tasklet_id: TaskletId) -> Option<u32> {
+        self.cyclic_execution(tasklet_id)
+            .map(|cyclic_execution| cyclic_execution.missed_activation_count())
+    }
+
+    /// Returns cyclic execution of the tasklet.
+    ///
+    /// # Parameters
+    /// * `tasklet_id` - ID of the tasklet.
+    fn cyclic_execution(&'static self, tasklet_id: TaskletId) -> Option<&'static CyclicExecution> {
+        self.cyclic_executions
+            .iter()
+            .find(|ce| ce.tasklet().get_id() == tasklet_id)
+    }
+
+    /// Returns time until the nearest wakeup of a cyclically executed tasklet.
+    ///
+    /// # Return
+    /// Time until the nearest wakeup, `None` if there are no cyclic executions.
+    pub(crate) fn time_to_next_execution(&'static self) -> Option<Duration> {
+        let system_time = self.time_source.system_time();
+
+        self.cyclic_executions
+            .iter()
+            .map(|ce| ce.time_to_next_execution(system_time))
+            .min()
+    }
+
     /// Wakes tasklet which next activation is scheduled for the current time.
     pub(crate) fn wake_tasklets(&'static self) {
         for ce in &self.cyclic_executions {
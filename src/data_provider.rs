@@ -3,21 +3,89 @@
 //! This module contains a trait for a data provider. In the system, data providers are structures
 //! that store the data that can be then passed to a data receiver (currently only
 //! [Tasklet](crate::tasklet::Tasklet) is such a receiver).
+//!
+//! Besides providers built into the system (message queues, events, boolean conditions and cyclic
+//! execution), users can implement their own data sources, and subscribe tasklets to them with
+//! [subscribe_tasklet_to_provider](crate::api::InitApi::subscribe_tasklet_to_provider).
+
+mod data_receiver;
+
+pub use self::data_receiver::DataReceiver;
+
+use crate::manifest::DataSourceKind;
 
 /// Trait with data provider functionality.
 ///
 /// Data provider is a structure that provides some kind of data to the
 /// [Tasklet](crate::tasklet::Tasklet)
 ///
+/// # Implementation contract
+/// Custom data providers are called by the executor, and the data they provide usually comes from
+/// the interrupt context, so implementations have to uphold following rules, or the tasklet
+/// subscribed to the provider will be scheduled incorrectly:
+/// * None of the methods can block or wait for the data. They are called from the scheduler loop
+///   and blocking them stops the whole system.
+/// * State shared with the interrupt context has to be accessed in a critical section, for example
+///   by storing it in a [Mutex](crate::Mutex).
+/// * If [data_waiting](DataProvider::data_waiting) returns `true`, following call to
+///   [get_data](DataProvider::get_data) has to return `Some`, unless the data was
+///   [flushed](DataProvider::flush) in between.
+/// * When new data becomes available, provider has to [notify](DataReceiver::notify) the receiver
+///   returned on subscription. Tasklet that isn't notified is not scheduled, even if its provider
+///   has data waiting.
+///
 /// # Generic Parameters
 /// * `T` - Type of the provided data.
-pub(crate) trait DataProvider<T> {
+pub trait DataProvider<T> {
     /// Provides data.
     ///
     /// # Return
     /// `Some(T)` if there was data available, `None` otherwise.
     fn get_data(&self) -> Option<T>;
 
+    /// Provides reference to the data.
+    ///
+    /// This is used by tasklets receiving the data by reference. Providers storing the data can
+    /// override it to pass a reference to the stored data, without copying it, and discard the
+    /// data after `f` returns. Default implementation passes reference to the data returned by
+    /// [get_data](DataProvider::get_data).
+    ///
+    /// # Parameters
+    /// * `f` - Function called with the reference to the data.
+    ///
+    /// # Return
+    /// `true` if there was data available and `f` was called, `false` otherwise.
+    fn with_data(&self, f: &mut dyn FnMut(&T)) -> bool {
+        match self.get_data() {
+            Some(data) => {
+                f(&data);
+                true
+            }
+            None => false,
+        }
+    }
+
     /// Checks if there is data waiting for being handled.
     fn data_waiting(&self) -> bool;
+
+    /// Returns number of elements waiting for being handled.
+    ///
+    /// This is used only in the [system state](crate::api::RuntimeApi::dump_system_state).
+    /// Default implementation returns 1 if [data_waiting](DataProvider::data_waiting) returns
+    /// `true`.
+    fn waiting_count(&self) -> usize {
+        self.data_waiting() as usize
+    }
+
+    /// Discards all data waiting for being handled.
+    ///
+    /// # Return
+    /// Number of discarded elements.
+    fn flush(&self) -> usize;
+
+    /// Returns kind of this data source, described in the
+    /// [configuration manifest](crate::api::InitApi::write_manifest).
+    fn source_kind(&self) -> DataSourceKind {
+        DataSourceKind::Custom
+    }
 }
@@ -0,0 +1,38 @@
+//! Receiver of the data from a custom data provider.
+
+use crate::aerugo::Aerugo;
+use crate::tasklet::TaskletPtr;
+
+/// Receiver of the data from a custom data provider.
+///
+/// Receiver is returned when a tasklet is
+/// [subscribed to a custom provider](crate::api::InitApi::subscribe_tasklet_to_provider). Provider
+/// should store it, and notify it every time new data becomes available.
+#[derive(Clone)]
+pub struct DataReceiver {
+    /// Subscribed tasklet.
+    tasklet: TaskletPtr,
+}
+
+/// Receiver only wakes the tasklet, which schedules it in the executor queue guarded with
+/// [Mutex](crate::mutex::Mutex). This is the same operation that message queues and events perform
+/// when data is sent from the IRQ context, so it's safe to notify receiver from any context.
+unsafe impl Send for DataReceiver {}
+unsafe impl Sync for DataReceiver {}
+
+impl DataReceiver {
+    /// Creates new data receiver.
+    ///
+    /// # Parameters
+    /// * `tasklet` - Subscribed tasklet.
+    pub(crate) fn new(tasklet: TaskletPtr) -> Self {
+        DataReceiver { tasklet }
+    }
+
+    /// Notifies receiver that new data is available.
+    ///
+    /// This schedules subscribed tasklet for execution, if it's not already scheduled.
+    pub fn notify(&self) {
+        Aerugo::wake_tasklet(&self.tasklet);
+    }
+}
@@ -0,0 +1,21 @@
+//! Order of execution of tasklets with the same priority.
+//!
+//! Executor orders tasklets by their priorities, or by deadlines of their releases first when built
+//! with `edf-scheduling` feature. By default, among tasklets with the same priority the one that
+//! was executed the longest time ago goes first. Execution time is measured with the system timer, so
+//! tasklets executed within the same timer tick are considered equal, and one tasklet that keeps
+//! having work can be picked over and over, starving the others. With
+//! [`EqualPriorityPolicy::RoundRobin`], tasklets with the same priority are executed in the order
+//! in which they were put into the execution queue. Tasklet rescheduled
+//! after its execution goes after all tasklets with the same priority that are already waiting, so
+//! each of them is executed once before it runs again.
+
+/// Order of execution of tasklets with the same priority.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq)]
+pub enum EqualPriorityPolicy {
+    /// Tasklet that was executed the longest time ago goes first.
+    #[default]
+    LastExecutionTime,
+    /// Tasklets are executed in the order in which they were put into the execution queue.
+    RoundRobin,
+}
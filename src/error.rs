@@ -1,5 +1,8 @@
 //! Module with system errors.
 
+pub mod aerugo_error;
+pub use self::aerugo_error::AerugoError;
+
 pub mod runtime_error;
 pub use self::runtime_error::RuntimeError;
 
@@ -0,0 +1,34 @@
+//! Errors of runtime operations of the system.
+
+use crate::error::RuntimeError;
+use crate::message_queue::IsrSendError;
+
+/// Error of a runtime operation of the system.
+///
+/// Each operation returns its own, specific error type, so it can be handled where it occurs.
+/// Application code that forwards errors of various operations to a single place, like a fault
+/// log or a telemetry counter, can convert them into this type with `?` or `into()`.
+///
+/// This covers errors returned by the [runtime API](crate::api::RuntimeApi), message queues and
+/// other handles, and errors passed to the [error hook](crate::api::InitApi::set_error_hook).
+/// Invalid system configuration during initialization with [InitApi](crate::api::InitApi), and
+/// failures of system startup in [start](crate::api::InitApi::start), still panic.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum AerugoError {
+    /// Error of a runtime operation.
+    Runtime(RuntimeError),
+    /// Error of sending data to a message queue from an interrupt handler.
+    IsrSend(IsrSendError),
+}
+
+impl From<RuntimeError> for AerugoError {
+    fn from(error: RuntimeError) -> Self {
+        AerugoError::Runtime(error)
+    }
+}
+
+impl From<IsrSendError> for AerugoError {
+    fn from(error: IsrSendError) -> Self {
+        AerugoError::IsrSend(error)
+    }
+}
@@ -17,4 +17,38 @@ pub enum RuntimeError {
     DataQueueFull,
     /// Event with given ID was not found.
     EventNotFound(EventId),
+    /// Event with given ID was scheduled for periodic emission with zero period or count.
+    InvalidEventPeriod(EventId),
+    /// Accessed partition port from outside of the partition it is connected to.
+    PartitionPortAccessDenied,
+    /// Firmware info was not set.
+    FirmwareInfoNotSet,
+    /// Firmware info doesn't contain valid magic value.
+    FirmwareInfoInvalid,
+    /// Firmware image size and CRC were not written into the image.
+    FirmwareImageNotSealed,
+    /// Firmware image doesn't match its CRC.
+    FirmwareImageCorrupted,
+    /// Tried to spawn a tasklet that wasn't reserved.
+    TaskletNotReserved,
+    /// Tried to spawn a tasklet that was already spawned.
+    TaskletAlreadySpawned,
+    /// Spawned tasklet was already subscribed to a data provider.
+    TaskletAlreadySubscribed,
+    /// Spawned tasklet couldn't be added to the tasklet list, because it was full.
+    TaskletListFull,
+    /// Tried to lock a mutex that was already locked.
+    MutexLocked,
+    /// Mutex stayed locked for the whole timeout of waiting for it.
+    MutexLockTimeout,
+    /// Tried to synchronize cyclic execution that is not phase locked.
+    CyclicExecutionNotPhaseLocked,
+    /// Tried to arrive at a barrier from outside of its participants.
+    NotBarrierParticipant,
+    /// Tasklet couldn't be scheduled, because the executor queue was full.
+    ExecutorQueueFull,
+    /// Guard zones around the context of the tasklet with given name were overwritten.
+    TaskletContextCorrupted(&'static str),
+    /// System configuration doesn't match the seal created when the system was started.
+    ConfigurationCorrupted,
 }
@@ -2,7 +2,9 @@
 
 use core::fmt;
 
+use crate::error::RuntimeError;
 use crate::event::EventId;
+use crate::partition::PartitionId;
 
 /// System error.
 #[derive(Copy, Clone, Eq, PartialEq)]
@@ -39,6 +41,82 @@ pub(crate) enum SystemError {
     ExecutionStatsListFull,
     /// Event for tasklet execution exceeding maximum was already set.
     TimeExceededEventAlreadySet,
+    /// Tasklet deadline miss handler was already set.
+    DeadlineMissHandlerAlreadySet,
+    /// Overload handler was already set.
+    OverloadHandlerAlreadySet,
+    /// Idle hook was already set.
+    IdleHookAlreadySet,
+    /// Error hook was already set.
+    ErrorHookAlreadySet,
+    /// Scheduler watchdog was already enabled.
+    SchedulerWatchdogAlreadyEnabled,
+    /// Tasklet containment was already enabled.
+    TaskletContainmentAlreadyEnabled,
+    /// Guard zones around tasklet context were overwritten.
+    TaskletContextCorrupted(&'static str),
+    /// System configuration was already sealed.
+    ConfigurationAlreadySealed,
+    /// System configuration doesn't match its seal.
+    ConfigurationSealBroken,
+    /// Partition window list was full when tried to add a new one.
+    PartitionWindowListFull,
+    /// Partition assignment list was full when tried to assign a tasklet.
+    PartitionAssignmentListFull,
+    /// Tasklet is already assigned to a partition.
+    TaskletAlreadyAssignedToPartition(&'static str),
+    /// Partition has tasklets assigned, but no windows.
+    PartitionWithoutWindow(PartitionId),
+    /// External watchdog list was full when tried to add a new one.
+    ExternalWatchdogListFull,
+    /// Timer list was full when tried to add a new one.
+    TimerListFull,
+    /// Firmware info was already set.
+    FirmwareInfoAlreadySet,
+    /// Tasklet already has a tasklet chained to it.
+    TaskletAlreadyChained(&'static str),
+    /// Tasklet chain already has a consumer tasklet.
+    TaskletChainAlreadyConnected,
+    /// Software watchdog list was full when tried to add a new one.
+    SoftwareWatchdogListFull,
+    /// Tasklet was already reserved.
+    TaskletAlreadyReserved(&'static str),
+    /// Tasklet was already monitored by the health monitor.
+    TaskletAlreadyMonitored(&'static str),
+    /// Tasklet already has a context telemetry publisher.
+    TaskletAlreadyHasTelemetry(&'static str),
+    /// Schedule table has zero-length minor frame or no minor frames.
+    InvalidScheduleTable,
+    /// Schedule table was already configured.
+    ScheduleTableAlreadyConfigured,
+    /// Tasklets are subscribed to the schedule table, but it wasn't configured.
+    ScheduleTableNotConfigured,
+    /// Scheduled tasklet list was full when tried to subscribe a tasklet to the schedule table.
+    ScheduledTaskletListFull,
+    /// Monitored queue list was full when tried to monitor growth of a queue.
+    QueueMonitorListFull,
+    /// Slot of the tasklet is empty or doesn't fit in its minor frame.
+    InvalidScheduleSlot(&'static str),
+    /// Slots of the tasklets overlap.
+    ScheduleSlotOverlap(&'static str, &'static str),
+}
+
+impl SystemError {
+    /// Returns runtime error reported to the [error hook](crate::api::InitApi::set_error_hook)
+    /// when this error occurs at runtime.
+    ///
+    /// # Return
+    /// `Some(RuntimeError)` if the system can continue after this error, `None` otherwise.
+    pub(crate) fn runtime_error(&self) -> Option<RuntimeError> {
+        match *self {
+            SystemError::ExecutorTaskletQueueFull => Some(RuntimeError::ExecutorQueueFull),
+            SystemError::TaskletContextCorrupted(tasklet_name) => {
+                Some(RuntimeError::TaskletContextCorrupted(tasklet_name))
+            }
+            SystemError::ConfigurationSealBroken => Some(RuntimeError::ConfigurationCorrupted),
+            _ => None,
+        }
+    }
 }
 
 impl fmt::Debug for SystemError {
@@ -138,6 +216,142 @@ impl fmt::Debug for SystemError {
                     "Event for the tasklet execution exceeding maximum time was already set."
                 )
             }
+            SystemError::DeadlineMissHandlerAlreadySet => {
+                write!(f, "Handler for the tasklet deadline miss was already set.")
+            }
+            SystemError::OverloadHandlerAlreadySet => {
+                write!(f, "Handler for the system overload was already set.")
+            }
+            SystemError::IdleHookAlreadySet => {
+                write!(f, "Idle hook was already set.")
+            }
+            SystemError::ErrorHookAlreadySet => {
+                write!(f, "Error hook was already set.")
+            }
+            SystemError::SchedulerWatchdogAlreadyEnabled => {
+                write!(f, "Scheduler watchdog was already enabled.")
+            }
+            SystemError::TaskletContainmentAlreadyEnabled => {
+                write!(f, "Tasklet containment was already enabled.")
+            }
+            SystemError::TaskletContextCorrupted(tasklet_name) => {
+                write!(f,
+                    "guard zones around the context of tasklet '{}' were overwritten. This means that the tasklet
+                    step function wrote outside of its context data (ex. buffer overrun), and the memory around it
+                    can no longer be trusted.",
+                    tasklet_name)
+            }
+            SystemError::ConfigurationAlreadySealed => {
+                write!(f,
+                    "internal system error. System configuration is sealed once, when the system is started.
+                    This error means that the system was started more than once.")
+            }
+            SystemError::ConfigurationSealBroken => {
+                write!(f,
+                    "system configuration doesn't match the seal created when the system was started. This
+                    means that the list of tasklets or their subscriptions were corrupted at runtime.")
+            }
+            SystemError::PartitionWindowListFull => {
+                write!(f,
+                    "partition window list is full. To configure number of partition windows in the system use
+                    the AERUGO_PARTITION_WINDOW_COUNT enviromental variable.")
+            }
+            SystemError::PartitionAssignmentListFull => {
+                write!(f,
+                    "internal system error. Partition manager stores a list of tasklet assignments of size equal
+                    to the maximum number of tasklets that can be created in the system. Each tasklet should be
+                    assigned at maximum once. This error means that there is some fault logic in partition
+                    assignment.")
+            }
+            SystemError::TaskletAlreadyAssignedToPartition(tasklet_name) => {
+                write!(f,
+                    "tasklet '{}' is already assigned to a partition. Tasklet can be assigned to only one partition.",
+                    tasklet_name)
+            }
+            SystemError::PartitionWithoutWindow(partition) => {
+                write!(f,
+                    "partition {} has tasklets assigned, but doesn't have any window in the major frame. Tasklets
+                    from this partition would never be executed.",
+                    partition)
+            }
+            SystemError::ExternalWatchdogListFull => {
+                write!(f,
+                    "external watchdog list is full. To configure number of external watchdogs in the system use
+                    the AERUGO_EXTERNAL_WATCHDOG_COUNT enviromental variable.")
+            }
+            SystemError::TimerListFull => {
+                write!(f,
+                    "timer list is full. To configure number of timers in the system use the AERUGO_TIMER_COUNT
+                    enviromental variable.")
+            }
+            SystemError::FirmwareInfoAlreadySet => {
+                write!(f, "Firmware info was already set.")
+            }
+            SystemError::TaskletAlreadyChained(tasklet_name) => {
+                write!(
+                    f,
+                    "Tasklet '{}' already has a tasklet chained to it.",
+                    tasklet_name
+                )
+            }
+            SystemError::TaskletChainAlreadyConnected => {
+                write!(f, "Tasklet chain already has a consumer tasklet.")
+            }
+            SystemError::SoftwareWatchdogListFull => {
+                write!(f,
+                    "software watchdog list is full. To configure number of software watchdogs in the system use
+                    the AERUGO_SOFTWARE_WATCHDOG_COUNT enviromental variable.")
+            }
+            SystemError::TaskletAlreadyReserved(tasklet_name) => {
+                write!(f, "Tasklet '{}' was already reserved.", tasklet_name)
+            }
+            SystemError::TaskletAlreadyMonitored(tasklet_name) => {
+                write!(f, "Tasklet '{}' is already monitored.", tasklet_name)
+            }
+            SystemError::TaskletAlreadyHasTelemetry(tasklet_name) => {
+                write!(
+                    f,
+                    "Tasklet '{}' already has a context telemetry publisher.",
+                    tasklet_name
+                )
+            }
+            SystemError::InvalidScheduleTable => {
+                write!(f,
+                    "schedule table must have at least one minor frame, and minor frame duration must not be
+                    zero.")
+            }
+            SystemError::ScheduleTableAlreadyConfigured => {
+                write!(f, "Schedule table was already configured.")
+            }
+            SystemError::ScheduleTableNotConfigured => {
+                write!(f,
+                    "tasklets are subscribed to the schedule table, but it wasn't configured. Tasklets would
+                    never be executed.")
+            }
+            SystemError::ScheduledTaskletListFull => {
+                write!(f,
+                    "internal system error. Schedule table manager stores a list of scheduled tasklets of size
+                    equal to the maximum number of tasklets that can be created in the system. Each tasklet
+                    should be subscribed to the schedule table at maximum once. This error means that there is
+                    some fault logic in schedule table subscription.")
+            }
+            SystemError::QueueMonitorListFull => {
+                write!(f,
+                    "monitored queue list is full. Growth of no more queues than the number of tasklets can be
+                    monitored.")
+            }
+            SystemError::InvalidScheduleSlot(tasklet_name) => {
+                write!(
+                    f,
+                    "slot of tasklet '{}' is empty or doesn't fit in its minor frame.",
+                    tasklet_name
+                )
+            }
+            SystemError::ScheduleSlotOverlap(tasklet_name, other_tasklet_name) => {
+                write!(f,
+                    "slots of tasklets '{}' and '{}' overlap. Slots in the schedule table must be disjoint.",
+                    tasklet_name, other_tasklet_name)
+            }
         }
     }
 }
@@ -1,17 +1,26 @@
 //! Module containing sturctures related to system events.
 
+mod data_event;
+mod data_event_handle;
+mod data_event_storage;
+mod event_group;
 mod event_handle;
 mod event_set;
 mod event_storage;
 
+pub use self::data_event_handle::DataEventHandle;
+pub use self::data_event_storage::DataEventStorage;
+pub use self::event_group::{EventGroup, EventGroupMode};
 pub use self::event_handle::EventHandle;
 pub use self::event_storage::EventStorage;
 
+pub(crate) use self::data_event::DataEvent;
 pub(crate) use self::event_set::EventSet;
 
 use crate::aerugo::Aerugo;
 use crate::error::SystemError;
 use crate::internal_list::InternalList;
+use crate::tasklet::TaskletId;
 
 /// System event ID.
 pub type EventId = u32;
@@ -42,7 +51,7 @@ unsafe impl Sync for Event {}
 
 impl Event {
     /// Creates new event.
-    pub(crate) fn new(id: EventId) -> Self {
+    pub(crate) const fn new(id: EventId) -> Self {
         Self {
             id,
             sets: EventSetList::new(),
@@ -54,6 +63,19 @@ impl Event {
         self.id
     }
 
+    /// Returns IDs of the tasklets subscribed to this event.
+    pub(crate) fn subscribers(&self) -> impl Iterator<Item = TaskletId> + Clone + '_ {
+        self.sets.iter().map(|event_set| event_set.tasklet_id())
+    }
+
+    /// Returns number of tasklets for which this event was emitted, but not processed yet.
+    pub(crate) fn pending_count(&self) -> usize {
+        self.sets
+            .iter()
+            .filter(|event_set| event_set.is_pending(self.id))
+            .count()
+    }
+
     /// Adds new set to the list.
     ///
     /// # Parameters
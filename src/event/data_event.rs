@@ -0,0 +1,175 @@
+//! Module containing event carrying typed data.
+
+use heapless::spsc::Queue;
+
+use crate::aerugo::Aerugo;
+use crate::data_provider::DataProvider;
+use crate::error::{RuntimeError, SystemError};
+use crate::event::EventId;
+use crate::internal_list::InternalList;
+use crate::manifest::DataSourceKind;
+use crate::mutex::Mutex;
+use crate::tasklet::TaskletPtr;
+
+/// Type for list of data event subscriptions.
+type SubscriptionList<T, const N: usize> =
+    InternalList<DataEventSubscription<T, N>, { Aerugo::TASKLET_COUNT }>;
+
+/// Event carrying typed data.
+///
+/// Unlike [Event](crate::event::Event), which is a pure signal, data event delivers a payload to
+/// all subscribed tasklets. Each subscription has its own payload queue, so every subscribed
+/// tasklet receives every emitted payload.
+///
+/// # Generic Parameters
+/// * `T` - Type of the payload.
+/// * `N` - Size of the payload queue of each subscription.
+pub(crate) struct DataEvent<T, const N: usize> {
+    /// ID of this event.
+    id: EventId,
+    /// Subscriptions of this event.
+    subscriptions: SubscriptionList<T, N>,
+}
+
+/// It is safe assuming that DataEvent is not available from IRQ context before it's created and
+/// that modifications cannot be interrupted.
+///
+/// DataEvent structure is hidden from the user. Functionalities are exposed to the user via
+/// [DataEventHandle](crate::event::DataEventHandle).
+///
+/// Subscriptions are only added with
+/// [subscribe_tasklet_to_data_event](crate::api::InitApi::subscribe_tasklet_to_data_event) which
+/// is not accessible from the IRQ context. Payload queues are guarded with [Mutex] which ensures
+/// that modifications cannot be interrupted.
+unsafe impl<T, const N: usize> Sync for DataEvent<T, N> {}
+
+impl<T: Clone, const N: usize> DataEvent<T, N> {
+    /// Creates new data event.
+    pub(crate) const fn new(id: EventId) -> Self {
+        DataEvent {
+            id,
+            subscriptions: SubscriptionList::new(),
+        }
+    }
+
+    /// Returns ID of this event.
+    pub(crate) fn id(&self) -> EventId {
+        self.id
+    }
+
+    /// Subscribes tasklet to this event.
+    ///
+    /// # Parameters
+    /// * `tasklet` - Tasklet to subscribe.
+    ///
+    /// # Return
+    /// Reference to the subscription, which is the data provider for the tasklet, if successful.
+    /// `SystemError` otherwise.
+    ///
+    /// # Safety
+    /// This is unsafe, because it mutably borrows the list of subscriptions.
+    /// This is safe if it's executed in a critical section during system initialization
+    /// (before scheduler is started).
+    /// Accessing event from IRQ context during subscribing is undefined behaviour.
+    pub(crate) unsafe fn subscribe(
+        &'static self,
+        tasklet: TaskletPtr,
+    ) -> Result<&'static DataEventSubscription<T, N>, SystemError> {
+        match self.subscriptions.add(DataEventSubscription::new(tasklet)) {
+            Ok(_) => Ok(self
+                .subscriptions
+                .last()
+                .expect("Failed to get reference to the subscription")),
+            Err(_) => Err(SystemError::TaskletListFull),
+        }
+    }
+
+    /// Emits this event with given payload.
+    ///
+    /// Payload is delivered to each subscription, and subscribed tasklets are woken.
+    ///
+    /// # Parameters
+    /// * `payload` - Data delivered with the event.
+    ///
+    /// # Return
+    /// `()` if successful, `RuntimeError` if payload queue of any subscription was full. Payload is
+    /// delivered to the remaining subscriptions anyway.
+    pub(crate) fn emit(&self, payload: T) -> Result<(), RuntimeError> {
+        let mut result = Ok(());
+
+        for subscription in &self.subscriptions {
+            if subscription.deliver(payload.clone()).is_err() {
+                result = Err(RuntimeError::DataQueueFull);
+            }
+        }
+
+        result
+    }
+}
+
+/// Subscription of a tasklet to the data event.
+///
+/// Subscription is used as a data provider for the tasklet. It stores payloads that were emitted
+/// but not yet processed.
+pub(crate) struct DataEventSubscription<T, const N: usize> {
+    /// Subscribed tasklet.
+    tasklet: TaskletPtr,
+    /// Queue of the payloads.
+    payload_queue: Mutex<Queue<T, N>>,
+}
+
+impl<T, const N: usize> DataEventSubscription<T, N> {
+    /// Creates new subscription.
+    fn new(tasklet: TaskletPtr) -> Self {
+        DataEventSubscription {
+            tasklet,
+            payload_queue: Queue::new().into(),
+        }
+    }
+
+    /// Delivers payload to the subscribed tasklet.
+    ///
+    /// # Parameters
+    /// * `payload` - Data to deliver.
+    ///
+    /// # Return
+    /// `()` if successful, payload back if the queue was full.
+    fn deliver(&self, payload: T) -> Result<(), T> {
+        self.payload_queue.lock(|queue| queue.enqueue(payload))?;
+
+        Aerugo::wake_tasklet(&self.tasklet);
+
+        Ok(())
+    }
+}
+
+impl<T, const N: usize> DataProvider<T> for DataEventSubscription<T, N> {
+    /// Returns the oldest payload delivered to this subscription.
+    fn get_data(&self) -> Option<T> {
+        self.payload_queue.lock(|queue| queue.dequeue())
+    }
+
+    /// Checks if any payload is waiting.
+    fn data_waiting(&self) -> bool {
+        self.payload_queue.lock(|queue| !queue.is_empty())
+    }
+
+    fn waiting_count(&self) -> usize {
+        self.payload_queue.lock(|queue| queue.len())
+    }
+
+    /// Discards all waiting payloads.
+    fn flush(&self) -> usize {
+        self.payload_queue.lock(|queue| {
+            let count = queue.len();
+            while queue.dequeue().is_some() {}
+            count
+        })
+    }
+
+    fn source_kind(&self) -> DataSourceKind {
+        DataSourceKind::DataEvent {
+            capacity: self.payload_queue.lock(|queue| queue.capacity()),
+        }
+    }
+}
@@ -0,0 +1,50 @@
+//! Handle to a data event.
+
+use crate::error::RuntimeError;
+use crate::event::{DataEvent, EventId};
+
+/// Data event handle.
+///
+/// Handle is used to reference and emit the data event.
+///
+/// # Generic Parameters
+/// * `T` - Type of the payload.
+/// * `N` - Size of the payload queue of each subscription.
+#[derive(Copy, Clone)]
+pub struct DataEventHandle<T: 'static, const N: usize> {
+    /// Reference to the event.
+    event: &'static DataEvent<T, N>,
+}
+
+impl<T: Clone, const N: usize> DataEventHandle<T, N> {
+    /// Creates new event handle.
+    ///
+    /// # Parameters
+    /// * `event` - Reference to the event.
+    pub(crate) fn new(event: &'static DataEvent<T, N>) -> Self {
+        DataEventHandle { event }
+    }
+
+    /// Returns ID of the event.
+    pub fn id(&self) -> EventId {
+        self.event.id()
+    }
+
+    /// Emits the event with given payload.
+    ///
+    /// Payload is delivered to all subscribed tasklets, which are then woken.
+    ///
+    /// # Parameters
+    /// * `payload` - Data delivered with the event.
+    ///
+    /// # Return
+    /// `()` if successful, `RuntimeError` if payload queue of any subscribed tasklet was full.
+    pub fn emit_event_with_data(&self, payload: T) -> Result<(), RuntimeError> {
+        self.event.emit(payload)
+    }
+
+    /// Returns reference to the event.
+    pub(crate) fn event(&self) -> &'static DataEvent<T, N> {
+        self.event
+    }
+}
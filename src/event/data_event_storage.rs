@@ -0,0 +1,100 @@
+//! Static storage for [data event](crate::event::DataEvent).
+//!
+//! This module contains a data event storage, which is a statically allocated memory that will
+//! store data event structure for the duration of the system life.
+
+use core::cell::OnceCell;
+
+use crate::error::SystemError;
+use crate::event::{DataEvent, DataEventHandle, EventId};
+
+/// Structure containing memory for DataEvent creation.
+///
+/// As this system cannot use dynamic memory allocation, all structures have to be allocated
+/// statically. Per good practices user is separated from the actual implementation and instead
+/// only has to provide a static memory (via this structure) where the DataEvent will be allocated.
+///
+/// Each tasklet subscribed to the event has its own payload queue, so this storage reserves memory
+/// for `AERUGO_TASKLET_COUNT` queues of size `N`.
+///
+/// # Generic Parameters
+/// * `T` - Type of the payload.
+/// * `N` - Size of the payload queue of each subscription.
+pub struct DataEventStorage<T, const N: usize> {
+    /// Stored event.
+    event: OnceCell<DataEvent<T, N>>,
+}
+
+/// It is safe assuming that stored DataEvent is not available from the IRQ context before it is
+/// created and that initialization cannot be interrupted.
+///
+/// DataEventStorage is initialized only in
+/// [create_data_event](crate::api::InitApi::create_data_event) implemented in
+/// [Aerugo](crate::aerugo::Aerugo) which is not accessible from the IRQ context.
+///
+/// It's not possible to access the stored DataEvent with mutable reference, so safety of DataEvent
+/// modification are subject of its implementation. Interrupt can emit the event using
+/// [`DataEventHandle`].
+unsafe impl<T, const N: usize> Sync for DataEventStorage<T, N> {}
+
+impl<T: Clone, const N: usize> DataEventStorage<T, N> {
+    /// Creates new storage.
+    pub const fn new() -> Self {
+        DataEventStorage {
+            event: OnceCell::new(),
+        }
+    }
+
+    /// Returns initialization status of this storage.
+    pub fn is_initialized(&'static self) -> bool {
+        self.event.get().is_some()
+    }
+
+    /// Creates new handle to an event allocated in this storage.
+    ///
+    /// # Return
+    /// `Some(handle)` if this storage has been initialized. `None` otherwise.
+    pub fn create_handle(&'static self) -> Option<DataEventHandle<T, N>> {
+        self.event.get().map(DataEventHandle::new)
+    }
+
+    /// Initializes this storage.
+    ///
+    /// # Parameters
+    /// * `event_id` - ID of the new event.
+    ///
+    /// # Return
+    /// `()` if successful, `SystemError` otherwise.
+    ///
+    /// # Safety
+    /// This is unsafe, because it mutably borrows the stored event.
+    /// This is safe to call during system initialization (before scheduler is started).
+    /// Accessing storage from IRQ context during initialization is undefined behaviour.
+    pub(crate) unsafe fn init(&'static self, event_id: EventId) -> Result<(), SystemError> {
+        match self.event.set(DataEvent::new(event_id)) {
+            Ok(_) => Ok(()),
+            Err(_) => Err(SystemError::StorageAlreadyInitialized),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn initialize() {
+        static STORAGE: DataEventStorage<u8, 2> = DataEventStorage::new();
+
+        assert!(!STORAGE.is_initialized());
+        assert!(STORAGE.create_handle().is_none());
+
+        let init_result = unsafe { STORAGE.init(0) };
+        assert!(init_result.is_ok());
+        assert!(STORAGE.is_initialized());
+        assert_eq!(STORAGE.create_handle().unwrap().id(), 0);
+
+        let init_result = unsafe { STORAGE.init(1) };
+        assert_eq!(init_result, Err(SystemError::StorageAlreadyInitialized));
+    }
+}
@@ -0,0 +1,85 @@
+//! Module containing event group.
+
+use crate::api::InitApi;
+use crate::event::EventId;
+use crate::tasklet::{TaskletDataSource, TaskletHandle};
+
+/// Condition under which event group triggers its tasklet.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum EventGroupMode {
+    /// Tasklet is triggered by each emitted event.
+    Any,
+    /// Tasklet is triggered when all events of the group were emitted since the last trigger.
+    All,
+}
+
+/// Group of events that a tasklet can be subscribed to.
+///
+/// Tasklet subscribed to the group receives ID of the event that triggered it. In
+/// [All](EventGroupMode::All) mode this is the last of the group events that was emitted, and
+/// events emitted more than once before the group completes are counted once.
+///
+/// # Generic Parameters
+/// * `N` - Number of events in the group.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct EventGroup<const N: usize> {
+    /// Events in the group.
+    pub events: [EventId; N],
+    /// Condition under which the group triggers its tasklet.
+    pub mode: EventGroupMode,
+}
+
+impl<const N: usize> EventGroup<N> {
+    /// Creates group triggered by each of the events.
+    ///
+    /// # Parameters
+    /// * `events` - Events in the group.
+    pub const fn any(events: [EventId; N]) -> Self {
+        EventGroup {
+            events,
+            mode: EventGroupMode::Any,
+        }
+    }
+
+    /// Creates group triggered when all of the events were emitted.
+    ///
+    /// # Parameters
+    /// * `events` - Events in the group.
+    pub const fn all(events: [EventId; N]) -> Self {
+        EventGroup {
+            events,
+            mode: EventGroupMode::All,
+        }
+    }
+
+    /// Returns number of distinct events in the group.
+    pub(crate) fn distinct_count(&self) -> usize {
+        self.events
+            .iter()
+            .enumerate()
+            .filter(|(index, event_id)| !self.events[..*index].contains(event_id))
+            .count()
+    }
+}
+
+impl<const N: usize> TaskletDataSource<EventId> for EventGroup<N> {
+    fn subscribe<C, const COND_COUNT: usize>(
+        &self,
+        api: &'static impl InitApi,
+        tasklet_handle: &TaskletHandle<EventId, C, COND_COUNT>,
+    ) {
+        api.subscribe_tasklet_to_event_group(tasklet_handle, *self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn count_distinct_events() {
+        assert_eq!(EventGroup::all([1, 2, 3]).distinct_count(), 3);
+        assert_eq!(EventGroup::all([1, 2, 1, 2]).distinct_count(), 2);
+        assert_eq!(EventGroup::<0>::any([]).distinct_count(), 0);
+    }
+}
@@ -47,6 +47,17 @@ impl EventHandle {
         self.event_manager.schedule(self.event.id(), time)
     }
 
+    /// Cancels scheduled emission of this event.
+    ///
+    /// This cancels both one-shot and periodic emission.
+    ///
+    /// # Return
+    /// `bool` indicating if event was cancelled, `RuntimeError` if some error occurred.
+    #[inline(always)]
+    pub fn cancel_scheduled(&self) -> Result<bool, RuntimeError> {
+        self.event_manager.cancel(self.event.id())
+    }
+
     /// Returns reference to the event.
     pub(crate) fn event(&self) -> &'static Event {
         self.event
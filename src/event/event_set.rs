@@ -1,39 +1,75 @@
 //! Module containing event set.
 
 use heapless::spsc::Queue;
+use heapless::Vec;
 
 use crate::aerugo::Aerugo;
 use crate::data_provider::DataProvider;
 use crate::error::SystemError;
-use crate::event::EventId;
+use crate::event::{EventGroupMode, EventId};
 use crate::event_manager::EventManager;
+use crate::manifest::DataSourceKind;
 use crate::mutex::Mutex;
-use crate::tasklet::TaskletPtr;
+use crate::tasklet::{TaskletId, TaskletPtr};
 use crate::utils::max;
 
 /// Type for event queue.
 type EventQueue = Queue<EventId, { max(EventManager::EVENT_COUNT, 2) }>;
 
+/// Type for list of events received by the group.
+type ReceivedEvents = Vec<EventId, { EventManager::EVENT_COUNT }>;
+
 /// Event set.
 ///
 /// Event set is used as a data provider for the Tasklet. It keeps track to which events is given
 /// tasklet subscribed to and which events are active.
+///
+/// In [All](EventGroupMode::All) mode, events are activated only after all events of the set were
+/// emitted.
 pub(crate) struct EventSet {
     /// Tasklet assigned to this set.
     tasklet: TaskletPtr,
     /// Event queue.
     event_queue: Mutex<EventQueue>,
+    /// Condition under which events are activated.
+    mode: EventGroupMode,
+    /// Number of distinct events in this set.
+    event_count: usize,
+    /// Events emitted since the last activation, used in [All](EventGroupMode::All) mode.
+    received_events: Mutex<ReceivedEvents>,
 }
 
 impl EventSet {
     /// Creates new event set.
-    pub(crate) fn new(tasklet: TaskletPtr) -> Self {
+    ///
+    /// # Parameters
+    /// * `tasklet` - Tasklet assigned to this set.
+    /// * `mode` - Condition under which events are activated.
+    /// * `event_count` - Number of distinct events in this set.
+    pub(crate) fn new(tasklet: TaskletPtr, mode: EventGroupMode, event_count: usize) -> Self {
         EventSet {
             tasklet,
             event_queue: EventQueue::new().into(),
+            mode,
+            event_count,
+            received_events: ReceivedEvents::new().into(),
         }
     }
 
+    /// Returns ID of the tasklet assigned to this set.
+    pub(crate) fn tasklet_id(&self) -> TaskletId {
+        self.tasklet.get_id()
+    }
+
+    /// Returns `true` if event with given ID is active in this set.
+    ///
+    /// # Parameters
+    /// * `event_id` - Event ID.
+    pub(crate) fn is_pending(&self, event_id: EventId) -> bool {
+        self.event_queue
+            .lock(|event_queue| event_queue.iter().any(|&id| id == event_id))
+    }
+
     /// Activates event
     ///
     /// # Parameters
@@ -43,6 +79,10 @@ impl EventSet {
     /// `true` if successfully activated event, `false` if event was already on the event queue
     /// and is waiting for trigger, `SystemError` otherwise.
     pub(crate) fn activate_event(&self, event_id: EventId) -> Result<bool, SystemError> {
+        if self.mode == EventGroupMode::All && !self.complete_group(event_id) {
+            return Ok(false);
+        }
+
         let event_activated = self.event_queue.lock(|event_queue| {
             let found_event = event_queue.iter().find(|&&id| id == event_id);
 
@@ -61,6 +101,30 @@ impl EventSet {
 
         Ok(event_activated)
     }
+
+    /// Marks event as received, and checks if all events of the set were received.
+    ///
+    /// # Parameters
+    /// * `event_id` - ID of the received event.
+    ///
+    /// # Return
+    /// `true` if all events were received, in which case received events are cleared, `false`
+    /// otherwise.
+    fn complete_group(&self, event_id: EventId) -> bool {
+        self.received_events.lock(|received_events| {
+            if !received_events.contains(&event_id) {
+                // This cannot fail, as there is no more distinct events than events in the system.
+                let _ = received_events.push(event_id);
+            }
+
+            if received_events.len() < self.event_count {
+                return false;
+            }
+
+            received_events.clear();
+            true
+        })
+    }
 }
 
 impl DataProvider<EventId> for EventSet {
@@ -78,4 +142,28 @@ impl DataProvider<EventId> for EventSet {
     fn data_waiting(&self) -> bool {
         self.event_queue.lock(|event_queue| !event_queue.is_empty())
     }
+
+    fn waiting_count(&self) -> usize {
+        self.event_queue.lock(|event_queue| event_queue.len())
+    }
+
+    /// Deactivates all events in this set.
+    ///
+    /// Events received by incomplete group are also cleared, but they are not counted.
+    fn flush(&self) -> usize {
+        self.received_events
+            .lock(|received_events| received_events.clear());
+
+        self.event_queue.lock(|event_queue| {
+            let count = event_queue.len();
+            while event_queue.dequeue().is_some() {}
+            count
+        })
+    }
+
+    fn source_kind(&self) -> DataSourceKind {
+        DataSourceKind::Events {
+            event_count: self.event_count,
+        }
+    }
 }
@@ -7,12 +7,13 @@ use env_parser::read_env;
 use heapless::Vec;
 
 use crate::aerugo::Aerugo;
+use crate::data_provider::DataProvider;
 use crate::error::{RuntimeError, SystemError};
-use crate::event::{Event, EventId, EventSet};
+use crate::event::{Event, EventGroupMode, EventId, EventSet};
 use crate::internal_list::InternalList;
 use crate::mutex::Mutex;
-use crate::tasklet::TaskletPtr;
-use crate::time::Instant;
+use crate::tasklet::{TaskletId, TaskletPtr};
+use crate::time::{Duration, Instant};
 use crate::time_source::TimeSource;
 
 /// Type for list of events.
@@ -28,6 +29,42 @@ struct ScheduledEvent {
     pub event: &'static Event,
     /// Time when event should become active.
     pub time: Instant,
+    /// Period of the emission, `None` if event is emitted once.
+    pub period: Option<Duration>,
+    /// Number of emissions left, `None` if event is emitted until cancelled.
+    pub count: Option<u32>,
+}
+
+impl ScheduledEvent {
+    /// Advances periodic event to its next emission, after it was emitted.
+    ///
+    /// Emissions missed because the system was busy are skipped, and are not counted.
+    ///
+    /// # Parameters
+    /// * `current_time` - Current system time.
+    ///
+    /// # Return
+    /// `true` if event should be emitted again, `false` otherwise.
+    fn advance(&mut self, current_time: Instant) -> bool {
+        let Some(period) = self.period else {
+            return false;
+        };
+
+        if let Some(count) = &mut self.count {
+            *count -= 1;
+            if *count == 0 {
+                return false;
+            }
+        }
+
+        self.time += period;
+        if let Some(delay) = current_time.checked_duration_since(self.time) {
+            let missed = delay.ticks() / period.ticks() + 1;
+            self.time += Duration::from_ticks(period.ticks() * missed);
+        }
+
+        true
+    }
 }
 
 /// System events manager.
@@ -108,10 +145,17 @@ impl EventManager {
             .copied()
     }
 
+    /// Returns iterator over events in the system.
+    pub(crate) fn events(&'static self) -> impl Iterator<Item = &'static Event> {
+        self.events.iter().copied()
+    }
+
     /// Creates new event set.
     ///
     /// # Parameters
     /// * `tasklet` - Tasklet that will be assigned to this event set.
+    /// * `mode` - Condition under which events of the set are activated.
+    /// * `event_count` - Number of distinct events in the set.
     ///
     /// # Returns
     /// Reference to `EventSet` if successful, `SystemError` otherwise.
@@ -122,8 +166,10 @@ impl EventManager {
     pub(crate) unsafe fn create_event_set(
         &'static self,
         tasklet: TaskletPtr,
+        mode: EventGroupMode,
+        event_count: usize,
     ) -> Result<&'static EventSet, SystemError> {
-        let event_set = EventSet::new(tasklet);
+        let event_set = EventSet::new(tasklet, mode, event_count);
 
         match self.event_sets.add(event_set) {
             Ok(_) => (),
@@ -171,17 +217,46 @@ impl EventManager {
             None => return Err(RuntimeError::EventNotFound(event_id)),
         };
 
-        let reschedule = self.is_scheduled(event_id).unwrap();
+        self.insert(ScheduledEvent {
+            event,
+            time,
+            period: None,
+            count: None,
+        })
+    }
 
-        if reschedule {
-            self.reschedule_event(event, time)
-                .expect("Failed to reschedule event");
-        } else {
-            self.schedule_event(event, time)
-                .expect("Failed to schedule event");
+    /// Schedule event of given ID to be emitted periodically.
+    ///
+    /// # Parameters
+    /// * `event_id` - ID of event to emit.
+    /// * `time` - Time of the first emission.
+    /// * `period` - Period of the emission.
+    /// * `count` - Number of emissions, `None` if event should be emitted until cancelled.
+    ///
+    /// # Return
+    /// `bool` indicating if event was rescheduled, `RuntimeError` if some error occurred.
+    pub(crate) fn schedule_periodic(
+        &'static self,
+        event_id: EventId,
+        time: Instant,
+        period: Duration,
+        count: Option<u32>,
+    ) -> Result<bool, RuntimeError> {
+        let event = match self.get_event(event_id) {
+            Some(event) => event,
+            None => return Err(RuntimeError::EventNotFound(event_id)),
+        };
+
+        if period.ticks() == 0 || count == Some(0) {
+            return Err(RuntimeError::InvalidEventPeriod(event_id));
         }
 
-        Ok(reschedule)
+        self.insert(ScheduledEvent {
+            event,
+            time,
+            period: Some(period),
+            count,
+        })
     }
 
     /// Checks if event of given ID is scheduled to be emitted.
@@ -207,6 +282,21 @@ impl EventManager {
         Ok(is_scheduled)
     }
 
+    /// Returns time of the scheduled emission of the event.
+    ///
+    /// # Parameters
+    /// * `event` - Event to check.
+    ///
+    /// # Return
+    /// Time of the next emission, `None` if event is not scheduled.
+    pub(crate) fn scheduled_time(&'static self, event: &'static Event) -> Option<Instant> {
+        self.scheduled_events.lock(|se| {
+            se.iter()
+                .find(|scheduled_event| scheduled_event.event == event)
+                .map(|scheduled_event| scheduled_event.time)
+        })
+    }
+
     /// Cancels event with the given ID.
     ///
     /// # Parameters
@@ -234,6 +324,38 @@ impl EventManager {
         })
     }
 
+    /// Clears events pending for the tasklet with given ID.
+    ///
+    /// This cancels scheduled emissions of the events the tasklet is subscribed to, and discards
+    /// events that were already emitted, but not processed by the tasklet.
+    ///
+    /// # Parameters
+    /// * `tasklet_id` - ID of the tasklet.
+    ///
+    /// # Return
+    /// Number of cancelled and discarded events.
+    pub(crate) fn clear_pending(&'static self, tasklet_id: TaskletId) -> usize {
+        let cancelled = self.scheduled_events.lock(|se| {
+            let scheduled_count = se.len();
+            se.retain(|scheduled_event| {
+                !scheduled_event
+                    .event
+                    .subscribers()
+                    .any(|subscriber| subscriber == tasklet_id)
+            });
+            scheduled_count - se.len()
+        });
+
+        let discarded = self
+            .event_sets
+            .iter()
+            .filter(|event_set| event_set.tasklet_id() == tasklet_id)
+            .map(|event_set| event_set.flush())
+            .sum::<usize>();
+
+        cancelled + discarded
+    }
+
     /// Clears event queue
     pub(crate) fn clear(&'static self) {
         self.scheduled_events.lock(|se| se.clear())
@@ -242,12 +364,12 @@ impl EventManager {
     /// Activate events that were scheduled for the current time.
     pub(crate) fn activate_scheduled_events(&'static self) {
         self.scheduled_events.lock(|se| {
-            se.retain(|scheduled_event| {
+            se.retain_mut(|scheduled_event| {
                 let current_time = self.time_source.system_time();
 
                 if current_time >= scheduled_event.time {
                     scheduled_event.event.emit();
-                    false
+                    scheduled_event.advance(current_time)
                 } else {
                     true
                 }
@@ -255,21 +377,56 @@ impl EventManager {
         })
     }
 
-    /// Schedules event for a given time.
+    /// Returns time until the nearest scheduled event becomes active.
+    ///
+    /// # Return
+    /// Time until the nearest event, zero if it's overdue, `None` if no event is scheduled.
+    pub(crate) fn time_to_next_event(&'static self) -> Option<Duration> {
+        let current_time = self.time_source.system_time();
+
+        self.scheduled_events.lock(|se| {
+            se.iter()
+                .map(|scheduled_event| {
+                    scheduled_event
+                        .time
+                        .checked_duration_since(current_time)
+                        .unwrap_or(Duration::from_ticks(0))
+                })
+                .min()
+        })
+    }
+
+    /// Inserts event into the list of scheduled events.
+    ///
+    /// If event was already scheduled, its previous schedule is replaced.
     ///
     /// # Parameters
-    /// * `event` - Event to schedule.
-    /// * `time` - Time for event to become active.
+    /// * `scheduled_event` - Event to schedule.
     ///
     /// # Return
-    /// `()` if successful, `SystemError` in case of an error.
-    fn schedule_event(
-        &'static self,
-        event: &'static Event,
-        time: Instant,
-    ) -> Result<(), SystemError> {
-        let scheduled_event = ScheduledEvent { event, time };
+    /// `bool` indicating if event was rescheduled, `RuntimeError` if some error occurred.
+    fn insert(&'static self, scheduled_event: ScheduledEvent) -> Result<bool, RuntimeError> {
+        let reschedule = self.is_scheduled(scheduled_event.event.id()).unwrap();
 
+        if reschedule {
+            self.reschedule_event(scheduled_event)
+                .expect("Failed to reschedule event");
+        } else {
+            self.schedule_event(scheduled_event)
+                .expect("Failed to schedule event");
+        }
+
+        Ok(reschedule)
+    }
+
+    /// Schedules event.
+    ///
+    /// # Parameters
+    /// * `scheduled_event` - Event to schedule.
+    ///
+    /// # Return
+    /// `()` if successful, `SystemError` in case of an error.
+    fn schedule_event(&'static self, scheduled_event: ScheduledEvent) -> Result<(), SystemError> {
         self.scheduled_events
             .lock(|se| match se.push(scheduled_event) {
                 Ok(_) => Ok(()),
@@ -277,28 +434,49 @@ impl EventManager {
             })
     }
 
-    /// Reschedules event to a new given time.
+    /// Replaces schedule of already scheduled event.
     ///
     /// # Parameters
-    /// * `event` - Event to reschedule.
-    /// * `new_time` - New time for the event to become active.
+    /// * `new_schedule` - New schedule of the event.
     ///
     /// # Return
     /// `()` if successful, `SystemError` in case of an error.
-    fn reschedule_event(
-        &'static self,
-        event: &'static Event,
-        new_time: Instant,
-    ) -> Result<(), SystemError> {
+    fn reschedule_event(&'static self, new_schedule: ScheduledEvent) -> Result<(), SystemError> {
         self.scheduled_events.lock(|se| {
             let scheduled_event = se
                 .iter_mut()
-                .find(|scheduled_event| scheduled_event.event == event)
+                .find(|scheduled_event| scheduled_event.event == new_schedule.event)
                 .unwrap();
 
-            scheduled_event.time = new_time;
+            *scheduled_event = new_schedule;
 
             Ok(())
         })
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn advance_periodic_event() {
+        static EVENT: Event = Event::new(0);
+
+        let mut scheduled_event = ScheduledEvent {
+            event: &EVENT,
+            time: Instant::from_ticks(1000),
+            period: Some(Duration::from_ticks(1000)),
+            count: Some(3),
+        };
+
+        assert!(scheduled_event.advance(Instant::from_ticks(1000)));
+        assert_eq!(scheduled_event.time, Instant::from_ticks(2000));
+
+        // Missed emissions are skipped.
+        assert!(scheduled_event.advance(Instant::from_ticks(4500)));
+        assert_eq!(scheduled_event.time, Instant::from_ticks(5000));
+
+        assert!(!scheduled_event.advance(Instant::from_ticks(5000)));
+    }
+}
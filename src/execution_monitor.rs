@@ -1,12 +1,21 @@
 //! Tasklet execution monitoring.
 
+mod cpu_load;
 mod execution_data;
 mod execution_stats;
 
 pub use self::execution_stats::ExecutionStats;
 
+use self::cpu_load::CpuLoad;
+
 pub(crate) use self::execution_data::ExecutionData;
 
+/// Function called when tasklet execution exceeds its deadline.
+///
+/// Handler is called with the name of the tasklet and the time by which its step overran the
+/// deadline.
+pub type DeadlineMissHandler = fn(&'static str, Duration);
+
 use core::cell::{OnceCell, UnsafeCell};
 
 use heapless::Vec;
@@ -15,7 +24,7 @@ use crate::aerugo::Aerugo;
 use crate::error::SystemError;
 use crate::event::Event;
 use crate::tasklet::TaskletId;
-use crate::time::Duration;
+use crate::time::{Duration, Instant};
 
 /// Monitor for tasklet execution.
 ///
@@ -25,6 +34,12 @@ pub(crate) struct ExecutionMonitor {
     execution_stats: UnsafeCell<Vec<ExecutionStats, { Aerugo::TASKLET_COUNT }>>,
     /// Tasklet execution time exceeded maximum event.
     time_exceeded_event: OnceCell<(&'static Event, Duration)>,
+    /// Tasklet deadline miss handler.
+    deadline_miss_handler: OnceCell<DeadlineMissHandler>,
+    /// Overload handler, called when release deadline is missed.
+    overload_handler: OnceCell<DeadlineMissHandler>,
+    /// CPU load of the executor loop.
+    cpu_load: UnsafeCell<CpuLoad>,
 }
 
 /// This is safe on single-threaded platform when `ExecutionMonitor` is not available from the IRQ
@@ -42,6 +57,9 @@ impl ExecutionMonitor {
         Self {
             execution_stats: UnsafeCell::new(Vec::new()),
             time_exceeded_event: OnceCell::new(),
+            deadline_miss_handler: OnceCell::new(),
+            overload_handler: OnceCell::new(),
+            cpu_load: UnsafeCell::new(CpuLoad::new(CpuLoad::DEFAULT_WINDOW)),
         }
     }
 
@@ -60,6 +78,88 @@ impl ExecutionMonitor {
         }
     }
 
+    /// Sets a handler that should be called when tasklet execution exceeds its deadline.
+    ///
+    /// # Parameter
+    /// * `handler` - Deadline miss handler.
+    pub(crate) unsafe fn set_deadline_miss_handler(
+        &'static self,
+        handler: DeadlineMissHandler,
+    ) -> Result<(), SystemError> {
+        match self.deadline_miss_handler.set(handler) {
+            Ok(_) => Ok(()),
+            Err(_) => Err(SystemError::DeadlineMissHandlerAlreadySet),
+        }
+    }
+
+    /// Sets a handler that should be called when execution of a tasklet ends after the absolute
+    /// deadline of its release.
+    ///
+    /// # Parameter
+    /// * `handler` - Overload handler.
+    pub(crate) unsafe fn set_overload_handler(
+        &'static self,
+        handler: DeadlineMissHandler,
+    ) -> Result<(), SystemError> {
+        match self.overload_handler.set(handler) {
+            Ok(_) => Ok(()),
+            Err(_) => Err(SystemError::OverloadHandlerAlreadySet),
+        }
+    }
+
+    /// Sets length of the CPU load measurement window.
+    ///
+    /// # Parameters
+    /// * `window` - Length of the window.
+    ///
+    /// # Safety
+    /// This is safe to call only before the system is started.
+    pub(crate) unsafe fn set_cpu_load_window(&'static self, window: Duration) {
+        (*self.cpu_load.get()).set_window(window);
+    }
+
+    /// Updates CPU load with executor loop iteration.
+    ///
+    /// # Parameters
+    /// * `start` - Start of the iteration.
+    /// * `end` - End of the iteration.
+    /// * `busy` - Whether a tasklet was executed in the iteration.
+    ///
+    /// # Safety
+    /// This is considered safe on single-threaded platform if `ExecutionMonitor` is not available
+    /// from the IRQ context.
+    pub(crate) unsafe fn update_cpu_load(&'static self, start: Instant, end: Instant, busy: bool) {
+        (*self.cpu_load.get()).update(start, end, busy);
+    }
+
+    /// Returns CPU load in the last complete measurement window in percent, or `None` if no
+    /// window was completed yet.
+    ///
+    /// # Safety
+    /// This is considered safe on single-threaded platform if `ExecutionMonitor` is not available
+    /// from the IRQ context.
+    pub(crate) unsafe fn cpu_load(&'static self) -> Option<u8> {
+        (*self.cpu_load.get()).load()
+    }
+
+    /// Returns total time of executor loop iterations in which a tasklet was executed.
+    ///
+    /// # Safety
+    /// This is considered safe on single-threaded platform if `ExecutionMonitor` is not available
+    /// from the IRQ context.
+    pub(crate) unsafe fn busy_time(&'static self) -> Duration {
+        (*self.cpu_load.get()).total_busy_time()
+    }
+
+    /// Returns total time of executor loop iterations in which no tasklet was executed.
+    ///
+    /// # Safety
+    /// This is considered safe on single-threaded platform if `ExecutionMonitor` is not available
+    /// from the IRQ context.
+    pub(crate) unsafe fn idle_time(&'static self) -> Duration {
+        (*self.cpu_load.get()).total_idle_time()
+    }
+
     /// Returns execution statistics for tasklet of given ID.
     ///
     /// # Parameters
@@ -104,6 +204,18 @@ impl ExecutionMonitor {
             }
         }
 
+        if let Some(handler) = self.deadline_miss_handler.get() {
+            if let Some(overrun) = execution_data.deadline_overrun() {
+                handler(execution_data.tasklet_name(), overrun);
+            }
+        }
+
+        if let Some(handler) = self.overload_handler.get() {
+            if let Some(lateness) = execution_data.release_lateness() {
+                handler(execution_data.tasklet_name(), lateness);
+            }
+        }
+
         let tasklet_id = execution_data.tasklet_id();
 
         let mut execution_stats = self.take_or_create_stats(tasklet_id);
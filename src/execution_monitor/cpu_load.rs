@@ -0,0 +1,114 @@
+//! CPU load measurement.
+
+use crate::time::{Duration, Instant};
+
+/// CPU load measured over consecutive windows of time.
+///
+/// Executor loop iterations are classified as busy, if a tasklet was executed in them, or idle
+/// otherwise. Load is the percentage of the window time spent in busy iterations, and is
+/// available after each window ends.
+pub(crate) struct CpuLoad {
+    /// Length of the measurement window.
+    window: Duration,
+    /// Start of the current window, `None` before the first iteration.
+    window_start: Option<Instant>,
+    /// Busy time in the current window.
+    busy_time: Duration,
+    /// Total busy time since the start of the measurement.
+    total_busy_time: Duration,
+    /// Total idle time since the start of the measurement.
+    total_idle_time: Duration,
+    /// Load in the last complete window, in percent.
+    load: Option<u8>,
+}
+
+impl CpuLoad {
+    /// Default length of the measurement window.
+    pub(crate) const DEFAULT_WINDOW: Duration = Duration::secs(1);
+
+    /// Creates new CPU load measurement.
+    ///
+    /// # Parameters
+    /// * `window` - Length of the measurement window.
+    pub(crate) const fn new(window: Duration) -> Self {
+        Self {
+            window,
+            window_start: None,
+            busy_time: Duration::from_ticks(0),
+            total_busy_time: Duration::from_ticks(0),
+            total_idle_time: Duration::from_ticks(0),
+            load: None,
+        }
+    }
+
+    /// Sets length of the measurement window.
+    ///
+    /// # Parameters
+    /// * `window` - Length of the measurement window.
+    pub(crate) fn set_window(&mut self, window: Duration) {
+        self.window = window;
+    }
+
+    /// Returns load in the last complete window in percent, or `None` if no window was completed.
+    pub(crate) fn load(&self) -> Option<u8> {
+        self.load
+    }
+
+    /// Returns total busy time.
+    pub(crate) fn total_busy_time(&self) -> Duration {
+        self.total_busy_time
+    }
+
+    /// Returns total idle time.
+    pub(crate) fn total_idle_time(&self) -> Duration {
+        self.total_idle_time
+    }
+
+    /// Updates the measurement with executor loop iteration.
+    ///
+    /// # Parameters
+    /// * `start` - Start of the iteration.
+    /// * `end` - End of the iteration.
+    /// * `busy` - Whether a tasklet was executed in the iteration.
+    pub(crate) fn update(&mut self, start: Instant, end: Instant, busy: bool) {
+        let window_start = *self.window_start.get_or_insert(start);
+        let duration = end - start;
+
+        if busy {
+            self.busy_time += duration;
+            self.total_busy_time += duration;
+        } else {
+            self.total_idle_time += duration;
+        }
+
+        let window_duration = end - window_start;
+        if window_duration >= self.window && window_duration.ticks() > 0 {
+            let load = self.busy_time.ticks() * 100 / window_duration.ticks();
+            self.load = Some(load.min(100) as u8);
+            self.window_start = Some(end);
+            self.busy_time = Duration::from_ticks(0);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn load_in_window() {
+        let mut cpu_load = CpuLoad::new(Duration::from_ticks(100));
+
+        cpu_load.update(Instant::from_ticks(0), Instant::from_ticks(30), true);
+        cpu_load.update(Instant::from_ticks(30), Instant::from_ticks(60), false);
+        assert_eq!(cpu_load.load(), None);
+
+        cpu_load.update(Instant::from_ticks(60), Instant::from_ticks(100), true);
+        assert_eq!(cpu_load.load(), Some(70));
+        assert_eq!(cpu_load.total_busy_time(), Duration::from_ticks(70));
+        assert_eq!(cpu_load.total_idle_time(), Duration::from_ticks(30));
+
+        cpu_load.update(Instant::from_ticks(100), Instant::from_ticks(200), false);
+        assert_eq!(cpu_load.load(), Some(0));
+    }
+}
@@ -7,22 +7,49 @@ use crate::time::{Duration, Instant};
 pub(crate) struct ExecutionData {
     /// Tasklet ID.
     tasklet_id: TaskletId,
+    /// Tasklet name.
+    tasklet_name: &'static str,
+    /// Maximum execution time of the tasklet step.
+    deadline: Option<Duration>,
     /// Whether tasklet was executed or only woken up.
     executed: bool,
     /// Timestamp for the start of the execution.
     execution_start: Option<Instant>,
     /// Timestamp for the end of the execution.
     execution_end: Option<Instant>,
+    /// Stack usage of the execution in bytes.
+    stack_usage: Option<usize>,
+    /// Time from the wake of the tasklet from an interrupt handler to the start of the execution.
+    wake_latency: Option<Duration>,
+    /// Whether the execution was cancelled and returned without noticing it.
+    cancellation_ignored: bool,
+    /// Absolute deadline of the executed release of a cyclic tasklet.
+    release_deadline: Option<Instant>,
 }
 
 impl ExecutionData {
     /// Creates new instance.
-    pub(crate) const fn new(tasklet_id: TaskletId) -> Self {
+    ///
+    /// # Parameters
+    /// * `tasklet_id` - Tasklet ID.
+    /// * `tasklet_name` - Tasklet name.
+    /// * `deadline` - Maximum execution time of the tasklet step, `None` if not monitored.
+    pub(crate) const fn new(
+        tasklet_id: TaskletId,
+        tasklet_name: &'static str,
+        deadline: Option<Duration>,
+    ) -> Self {
         Self {
             tasklet_id,
+            tasklet_name,
+            deadline,
             executed: false,
             execution_start: None,
             execution_end: None,
+            stack_usage: None,
+            wake_latency: None,
+            cancellation_ignored: false,
+            release_deadline: None,
         }
     }
 
@@ -31,6 +58,11 @@ impl ExecutionData {
         &self.tasklet_id
     }
 
+    /// Returns tasklet name.
+    pub(crate) fn tasklet_name(&self) -> &'static str {
+        self.tasklet_name
+    }
+
     /// Returns whether tasklet was executed or just woken up.
     pub(crate) fn was_executed(&self) -> bool {
         self.executed
@@ -51,6 +83,44 @@ impl ExecutionData {
         self.execution_end.replace(execution_end);
     }
 
+    /// Saves stack usage of the execution.
+    pub(crate) fn set_stack_usage(&mut self, stack_usage: usize) {
+        self.stack_usage.replace(stack_usage);
+    }
+
+    /// Returns stack usage of the execution, or `None` if it wasn't measured.
+    pub(crate) fn stack_usage(&self) -> Option<usize> {
+        self.stack_usage
+    }
+
+    /// Saves time from the wake of the tasklet from an interrupt handler to the start of the
+    /// execution.
+    #[cfg_attr(not(feature = "wake-latency"), allow(dead_code))]
+    pub(crate) fn set_wake_latency(&mut self, wake_latency: Duration) {
+        self.wake_latency.replace(wake_latency);
+    }
+
+    /// Returns wake latency of the execution, or `None` if tasklet wasn't woken from an interrupt
+    /// handler or latency wasn't measured.
+    pub(crate) fn wake_latency(&self) -> Option<Duration> {
+        self.wake_latency
+    }
+
+    /// Marks that the execution was cancelled and returned without noticing it.
+    pub(crate) fn set_cancellation_ignored(&mut self) {
+        self.cancellation_ignored = true;
+    }
+
+    /// Returns whether the execution was cancelled and returned without noticing it.
+    pub(crate) fn cancellation_ignored(&self) -> bool {
+        self.cancellation_ignored
+    }
+
+    /// Saves absolute deadline of the executed release of a cyclic tasklet.
+    pub(crate) fn set_release_deadline(&mut self, release_deadline: Instant) {
+        self.release_deadline.replace(release_deadline);
+    }
+
     /// Calculates the execution duration. Returns `None` if tasklet wasn't executed.
     pub(crate) fn execution_duration(&self) -> Option<Duration> {
         match (self.execution_start, self.execution_end) {
@@ -58,4 +128,77 @@ impl ExecutionData {
             (_, _) => None,
         }
     }
+
+    /// Calculates by how much the execution exceeded the deadline.
+    ///
+    /// # Return
+    /// Overrun if tasklet was executed and its execution took longer than the deadline, `None`
+    /// otherwise.
+    pub(crate) fn deadline_overrun(&self) -> Option<Duration> {
+        match (self.execution_duration(), self.deadline) {
+            (Some(duration), Some(deadline)) if duration > deadline => Some(duration - deadline),
+            (_, _) => None,
+        }
+    }
+
+    /// Calculates by how much the execution ended after the absolute deadline of its release.
+    ///
+    /// # Return
+    /// Lateness if tasklet was executed and its execution ended after the deadline of the release,
+    /// `None` otherwise.
+    pub(crate) fn release_lateness(&self) -> Option<Duration> {
+        match (self.execution_end, self.release_deadline) {
+            (Some(end), Some(deadline)) if end > deadline => Some(end - deadline),
+            (_, _) => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deadline_overrun() {
+        let tasklet_id = TaskletId(0);
+
+        let mut within_deadline =
+            ExecutionData::new(tasklet_id, "Tasklet", Some(Duration::from_ticks(100)));
+        within_deadline.set_execution_start(Instant::from_ticks(0));
+        within_deadline.set_execution_end(Instant::from_ticks(100));
+        assert_eq!(within_deadline.deadline_overrun(), None);
+
+        let mut overrun =
+            ExecutionData::new(tasklet_id, "Tasklet", Some(Duration::from_ticks(100)));
+        overrun.set_execution_start(Instant::from_ticks(50));
+        overrun.set_execution_end(Instant::from_ticks(180));
+        assert_eq!(overrun.deadline_overrun(), Some(Duration::from_ticks(30)));
+
+        let mut no_deadline = ExecutionData::new(tasklet_id, "Tasklet", None);
+        no_deadline.set_execution_start(Instant::from_ticks(0));
+        no_deadline.set_execution_end(Instant::from_ticks(1000));
+        assert_eq!(no_deadline.deadline_overrun(), None);
+    }
+
+    #[test]
+    fn release_lateness() {
+        let tasklet_id = TaskletId(0);
+
+        let mut on_time = ExecutionData::new(tasklet_id, "Tasklet", None);
+        on_time.set_release_deadline(Instant::from_ticks(200));
+        on_time.set_execution_start(Instant::from_ticks(150));
+        on_time.set_execution_end(Instant::from_ticks(200));
+        assert_eq!(on_time.release_lateness(), None);
+
+        let mut late = ExecutionData::new(tasklet_id, "Tasklet", None);
+        late.set_release_deadline(Instant::from_ticks(200));
+        late.set_execution_start(Instant::from_ticks(190));
+        late.set_execution_end(Instant::from_ticks(240));
+        assert_eq!(late.release_lateness(), Some(Duration::from_ticks(40)));
+
+        let mut no_release = ExecutionData::new(tasklet_id, "Tasklet", None);
+        no_release.set_execution_start(Instant::from_ticks(0));
+        no_release.set_execution_end(Instant::from_ticks(1000));
+        assert_eq!(no_release.release_lateness(), None);
+    }
 }
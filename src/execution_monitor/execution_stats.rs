@@ -21,6 +21,20 @@ pub struct ExecutionStats {
     minimum_execution_time: Option<Duration>,
     /// Longes execution time.
     maximum_execution_time: Option<Duration>,
+    /// Highest stack usage in bytes.
+    maximum_stack_usage: Option<usize>,
+    /// Number of executions with measured wake latency.
+    wake_latency_count: u32,
+    /// Total wake latency.
+    total_wake_latency: Duration,
+    /// Longest wake latency.
+    maximum_wake_latency: Option<Duration>,
+    /// Number of cancelled executions that returned without noticing the cancellation.
+    ignored_cancellation_count: u32,
+    /// Number of executions that ended after the absolute deadline of their release.
+    release_deadline_miss_count: u32,
+    /// Longest time by which an execution ended after the absolute deadline of its release.
+    maximum_release_lateness: Option<Duration>,
 }
 
 impl ExecutionStats {
@@ -33,6 +47,13 @@ impl ExecutionStats {
             total_execution_time: Duration::from_ticks(0),
             minimum_execution_time: None,
             maximum_execution_time: None,
+            maximum_stack_usage: None,
+            wake_latency_count: 0,
+            total_wake_latency: Duration::from_ticks(0),
+            maximum_wake_latency: None,
+            ignored_cancellation_count: 0,
+            release_deadline_miss_count: 0,
+            maximum_release_lateness: None,
         }
     }
 
@@ -66,6 +87,56 @@ impl ExecutionStats {
         self.maximum_execution_time
     }
 
+    /// Returns highest stack usage (high-watermark) in bytes.
+    ///
+    /// This is `None` if stack usage is not monitored. If it's equal to the painted stack size
+    /// (`AERUGO_STACK_PAINT_SIZE`), actual usage may be higher.
+    pub fn maximum_stack_usage(&self) -> Option<usize> {
+        self.maximum_stack_usage
+    }
+
+    /// Returns longest time from the wake of the tasklet from an interrupt handler to the start of
+    /// its execution.
+    ///
+    /// This is `None` if latency is not measured (`wake-latency` feature is disabled), or tasklet
+    /// was never woken from an interrupt handler.
+    pub fn maximum_wake_latency(&self) -> Option<Duration> {
+        self.maximum_wake_latency
+    }
+
+    /// Returns average time from the wake of the tasklet from an interrupt handler to the start of
+    /// its execution.
+    pub fn average_wake_latency(&self) -> Option<Duration> {
+        if self.wake_latency_count > 0 {
+            Some(self.total_wake_latency / self.wake_latency_count)
+        } else {
+            None
+        }
+    }
+
+    /// Returns number of executions that were [cancelled](crate::api::RuntimeApi::cancel_step)
+    /// and returned without polling the [cancellation token](crate::CancellationToken).
+    ///
+    /// Non-zero count means that the step doesn't poll the token often enough, or at all.
+    pub fn ignored_cancellation_count(&self) -> u32 {
+        self.ignored_cancellation_count
+    }
+
+    /// Returns number of executions that ended after the absolute deadline of their release.
+    ///
+    /// Releases have deadlines only with `edf-scheduling` feature enabled, for tasklets subscribed
+    /// to cyclic execution with a period. Missed release deadline means that the system is
+    /// overloaded.
+    pub fn release_deadline_miss_count(&self) -> u32 {
+        self.release_deadline_miss_count
+    }
+
+    /// Returns longest time by which an execution ended after the absolute deadline of its
+    /// release, or `None` if no release deadline was missed.
+    pub fn maximum_release_lateness(&self) -> Option<Duration> {
+        self.maximum_release_lateness
+    }
+
     /// Returns average execution time.
     pub fn average_execution_time(&self) -> Option<Duration> {
         if self.execution_count > 0 {
@@ -97,6 +168,34 @@ impl ExecutionStats {
             });
 
             self.total_execution_time += execution_time;
+
+            if let Some(stack_usage) = execution_data.stack_usage() {
+                self.maximum_stack_usage = Some(match self.maximum_stack_usage {
+                    Some(usage) => core::cmp::max(usage, stack_usage),
+                    None => stack_usage,
+                });
+            }
+
+            if let Some(wake_latency) = execution_data.wake_latency() {
+                self.wake_latency_count += 1;
+                self.total_wake_latency += wake_latency;
+                self.maximum_wake_latency = Some(match self.maximum_wake_latency {
+                    Some(latency) => core::cmp::max(latency, wake_latency),
+                    None => wake_latency,
+                });
+            }
+
+            if execution_data.cancellation_ignored() {
+                self.ignored_cancellation_count += 1;
+            }
+
+            if let Some(lateness) = execution_data.release_lateness() {
+                self.release_deadline_miss_count += 1;
+                self.maximum_release_lateness = Some(match self.maximum_release_lateness {
+                    Some(time) => core::cmp::max(time, lateness),
+                    None => lateness,
+                });
+            }
         }
     }
 }
@@ -115,6 +214,32 @@ impl fmt::Display for ExecutionStats {
         if let Some(time) = self.average_execution_time() {
             writeln!(f, "Average execution time: {}", time)?;
         }
+        if let Some(usage) = self.maximum_stack_usage() {
+            writeln!(f, "Maximum stack usage: {} B", usage)?;
+        }
+        if let Some(latency) = self.maximum_wake_latency() {
+            writeln!(f, "Maximum wake latency: {}", latency)?;
+        }
+        if let Some(latency) = self.average_wake_latency() {
+            writeln!(f, "Average wake latency: {}", latency)?;
+        }
+        if self.ignored_cancellation_count() > 0 {
+            writeln!(
+                f,
+                "Ignored cancellations: {}",
+                self.ignored_cancellation_count()
+            )?;
+        }
+        if self.release_deadline_miss_count() > 0 {
+            writeln!(
+                f,
+                "Release deadline misses: {}",
+                self.release_deadline_miss_count()
+            )?;
+        }
+        if let Some(lateness) = self.maximum_release_lateness() {
+            writeln!(f, "Maximum release lateness: {}", lateness)?;
+        }
 
         Ok(())
     }
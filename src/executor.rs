@@ -5,17 +5,112 @@
 //! aerugo is build around an executor that run tasklets, which are fine-grained units of
 //! computation. Executor is a cooperative scheduler, that doesn't support preemption.
 
+use core::cell::OnceCell;
+use core::cmp::Ordering;
+use core::marker::PhantomData;
+
 use heapless::binary_heap::{BinaryHeap, Max};
+use heapless::Vec;
 
 use crate::aerugo::Aerugo;
-use crate::error::SystemError;
+use crate::api::RuntimeApi;
+#[cfg(feature = "fault-handler")]
+use crate::arch::fault_handler;
+#[cfg(feature = "instrumented")]
+use crate::arch::trace_execution;
+use crate::equal_priority_policy::EqualPriorityPolicy;
+use crate::error::{RuntimeError, SystemError};
 use crate::execution_monitor::ExecutionData;
 use crate::mutex::Mutex;
+use crate::partition_manager::PartitionManager;
+use crate::scheduling_policy::{SchedulingPolicy, SystemSchedulingPolicy, Urgency};
+use crate::stack_monitor;
 use crate::tasklet::{TaskletPtr, TaskletStatus};
+use crate::tie_breaker::TieBreaker;
+use crate::time::{Duration, Instant};
 use crate::time_source::TimeSource;
 
+/// Function called by the executor when no tasklet is ready for execution.
+pub type IdleHook = fn(&'static dyn RuntimeApi);
+
+/// Function called by the system when an internal operation fails at runtime.
+pub type ErrorHook = fn(RuntimeError);
+
 /// Type for the tasklet execution queue
-type TaskletQueue<const N: usize> = BinaryHeap<TaskletPtr, Max, N>;
+type TaskletQueue<P, const N: usize> = BinaryHeap<QueuedTasklet<P>, Max, N>;
+
+/// Tasklet waiting in the execution queue.
+///
+/// # Generic Parameters
+/// * `P` - Scheduling policy ordering the queue.
+struct QueuedTasklet<P> {
+    /// Queued tasklet.
+    tasklet: TaskletPtr,
+    /// Number of the insertion into the queue, `0` if tasklets are not executed round-robin.
+    sequence: u64,
+    /// Absolute deadline of the tasklet release, `None` if tasklet has no deadline.
+    deadline: Option<Instant>,
+    /// Key ordering this tasklet against otherwise equal tasklets.
+    tie_break_key: u32,
+    /// Time when the tasklet was put into the queue.
+    #[cfg(feature = "instrumented")]
+    enqueue_time: Instant,
+    /// Marker of the scheduling policy.
+    _policy: PhantomData<P>,
+}
+
+impl<P> QueuedTasklet<P> {
+    /// Returns current urgency of the queued tasklet.
+    fn urgency(&self) -> Urgency {
+        Urgency {
+            priority: self.tasklet.get_priority(),
+            deadline: self.deadline,
+        }
+    }
+}
+
+impl<P: SchedulingPolicy> Ord for QueuedTasklet<P> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Tasklet inserted earlier has lower sequence number and goes first.
+        P::compare(&self.urgency(), &other.urgency())
+            .then(other.sequence.cmp(&self.sequence))
+            .then(self.tasklet.cmp(&other.tasklet))
+            .then(self.tie_break_key.cmp(&other.tie_break_key))
+    }
+}
+
+impl<P: SchedulingPolicy> PartialOrd for QueuedTasklet<P> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<P: SchedulingPolicy> Eq for QueuedTasklet<P> {}
+
+impl<P: SchedulingPolicy> PartialEq for QueuedTasklet<P> {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+/// Cancellation of the tasklet step.
+#[derive(Copy, Clone)]
+struct StepCancellation {
+    /// `true` if the step was explicitly cancelled.
+    requested: bool,
+    /// `true` if the step noticed that it was cancelled.
+    acknowledged: bool,
+}
+
+impl StepCancellation {
+    /// Creates cancellation state of a new step.
+    const fn new() -> Self {
+        StepCancellation {
+            requested: false,
+            acknowledged: false,
+        }
+    }
+}
 
 /// System scheduler.
 ///
@@ -23,28 +118,62 @@ type TaskletQueue<const N: usize> = BinaryHeap<TaskletPtr, Max, N>;
 /// It should be used as a singleton (crate::aerugo::EXECUTOR) and shouldn't be directly accessed
 /// by any other part of the system. It's functionality shall be exposed for rest of the system
 /// via system API in [Aerugo].
-pub(crate) struct Executor {
+///
+/// # Generic Parameters
+/// * `P` - Scheduling policy ordering the execution queue.
+pub(crate) struct Executor<P: SchedulingPolicy = SystemSchedulingPolicy> {
     /// Tasklet queue.
-    tasklet_queue: Mutex<TaskletQueue<{ Aerugo::TASKLET_COUNT }>>,
+    tasklet_queue: Mutex<TaskletQueue<P, { Aerugo::TASKLET_COUNT }>>,
     /// Time source.
     time_source: &'static TimeSource,
+    /// Partition manager, deciding which tasklets can be executed at the given time.
+    partition_manager: &'static PartitionManager,
+    /// Tie breaker, ordering tasklets with the same priority and last execution time.
+    tie_breaker: &'static TieBreaker,
+    /// Order of execution of tasklets with the same priority.
+    equal_priority_policy: Mutex<EqualPriorityPolicy>,
+    /// Number of insertions into the queue, used to execute tasklets round-robin.
+    sequence: Mutex<u64>,
+    /// Currently executed tasklet.
+    current_tasklet: Mutex<Option<TaskletPtr>>,
+    /// Start time of the current tasklet step.
+    current_step_start: Mutex<Instant>,
+    /// Cancellation of the current tasklet step.
+    current_step_cancellation: Mutex<StepCancellation>,
+    /// Function called when no tasklet is ready for execution.
+    idle_hook: OnceCell<IdleHook>,
+    /// Function called when an internal operation fails at runtime.
+    error_hook: OnceCell<ErrorHook>,
 }
 
 /// Executor stores a queue of tasklets to be executed. That queue is guarded with [Mutex] which
 /// ensures that modifications cannot be interrupted. `TaskletPtr`s stored in that queue are not
 /// accessible from the IRQ context, and Tasklet is always statically allocated, so the pointer is
 /// valid for the whole application lifetime.
-unsafe impl Sync for Executor {}
+unsafe impl<P: SchedulingPolicy> Sync for Executor<P> {}
 
-impl Executor {
+impl<P: SchedulingPolicy> Executor<P> {
     /// Creates new executor instance.
     ///
     /// # Safety
     /// This shouldn't be called more than once.
-    pub(crate) const fn new(time_source: &'static TimeSource) -> Self {
+    pub(crate) const fn new(
+        time_source: &'static TimeSource,
+        partition_manager: &'static PartitionManager,
+        tie_breaker: &'static TieBreaker,
+    ) -> Self {
         Executor {
             tasklet_queue: Mutex::new(BinaryHeap::new()),
             time_source,
+            partition_manager,
+            tie_breaker,
+            equal_priority_policy: Mutex::new(EqualPriorityPolicy::LastExecutionTime),
+            sequence: Mutex::new(0),
+            current_tasklet: Mutex::new(None),
+            current_step_start: Mutex::new(Instant::from_ticks(0)),
+            current_step_cancellation: Mutex::new(StepCancellation::new()),
+            idle_hook: OnceCell::new(),
+            error_hook: OnceCell::new(),
         }
     }
 
@@ -54,13 +183,24 @@ impl Executor {
     /// do (ex. there are still data in the queue) tasklet will be rescheduled, otherwise it will
     /// be put to sleep.
     ///
+    /// If guard zones of the tasklet context were overwritten during the step, tasklet is
+    /// terminated and put to sleep, and the error is passed to the error hook. Error is returned
+    /// only if the hook didn't handle it.
+    ///
     /// # Returns
     /// Value indicating if tasklet was executed, `SystemError` otherwise.
     pub(crate) fn execute_next_tasklet(
         &'static self,
     ) -> Result<Option<ExecutionData>, SystemError> {
-        if let Some(tasklet) = self.get_tasklet_for_execution() {
-            let mut execution_data = ExecutionData::new(tasklet.get_id());
+        if let Some(queued) = self.get_tasklet_for_execution() {
+            let tasklet = queued.tasklet;
+            let mut execution_data =
+                ExecutionData::new(tasklet.get_id(), tasklet.get_name(), tasklet.get_deadline());
+
+            // Tasklet woken from an interrupt is executed now, or goes to sleep and will be woken
+            // again, so its wake time is taken in both cases.
+            #[cfg(feature = "wake-latency")]
+            let interrupt_wake_time = tasklet.take_interrupt_wake_time();
 
             if !tasklet.is_active() {
                 tasklet.set_status(TaskletStatus::Sleeping);
@@ -69,20 +209,84 @@ impl Executor {
 
             tasklet.set_status(TaskletStatus::Working);
 
+            self.partition_manager.enter_partition(&tasklet);
+
+            let stack_paint = stack_monitor::paint_stack();
             let execution_start_timestamp = self.time_source.system_time();
+            self.current_step_start
+                .lock(|start| *start = execution_start_timestamp);
+            self.current_step_cancellation
+                .lock(|cancellation| *cancellation = StepCancellation::new());
+            self.current_tasklet
+                .lock(|current| *current = Some(tasklet.clone()));
+            #[cfg(feature = "fault-handler")]
+            fault_handler::set_current_tasklet(Some(tasklet.get_name()));
             let executed = tasklet.execute();
+            #[cfg(feature = "fault-handler")]
+            fault_handler::set_current_tasklet(None);
+            self.current_tasklet.lock(|current| *current = None);
+            let cancellation = self
+                .current_step_cancellation
+                .lock(|cancellation| *cancellation);
             let execution_end_timestamp = self.time_source.system_time();
+            let stack_usage = stack_paint.map(|paint| stack_monitor::stack_usage(&paint));
+
+            self.partition_manager.exit_partition();
+
+            // Tasklet with overwritten context guards can't be safely executed again, so it's
+            // terminated, and its step is recorded before the error is reported.
+            let context_corrupted = !tasklet.is_context_intact();
+            if context_corrupted {
+                tasklet.terminate();
+            }
+
+            // Tasklet that terminated itself is finalized after its step.
+            if tasklet.is_terminated() {
+                tasklet.finalize();
+            }
 
             if executed {
                 execution_data.set_executed();
                 execution_data.set_execution_start(execution_start_timestamp);
                 execution_data.set_execution_end(execution_end_timestamp);
+                if let Some(stack_usage) = stack_usage {
+                    execution_data.set_stack_usage(stack_usage);
+                }
+                if cancellation.requested && !cancellation.acknowledged {
+                    execution_data.set_cancellation_ignored();
+                }
+                if let Some(deadline) = queued.deadline {
+                    execution_data.set_release_deadline(deadline);
+                }
+                #[cfg(feature = "wake-latency")]
+                if let Some(wake_latency) = interrupt_wake_time.and_then(|wake_time| {
+                    execution_start_timestamp.checked_duration_since(wake_time)
+                }) {
+                    execution_data.set_wake_latency(wake_latency);
+                }
+
+                #[cfg(feature = "instrumented")]
+                trace_execution(
+                    tasklet.get_id().0,
+                    queued.enqueue_time.duration_since_epoch().to_micros(),
+                    execution_start_timestamp.duration_since_epoch().to_micros(),
+                    execution_end_timestamp.duration_since_epoch().to_micros(),
+                );
 
                 let system_time = self.time_source.system_time();
                 tasklet.set_last_execution_time(system_time);
             }
 
-            self.try_reschedule_tasklet(tasklet)?;
+            if context_corrupted {
+                tasklet.set_status(TaskletStatus::Sleeping);
+
+                let error = SystemError::TaskletContextCorrupted(tasklet.get_name());
+                if !self.report_error(error) {
+                    return Err(error);
+                }
+            } else {
+                self.try_reschedule_tasklet(tasklet)?;
+            }
 
             Ok(Some(execution_data))
         } else {
@@ -113,6 +317,202 @@ impl Executor {
         }
     }
 
+    /// Sets function called when no tasklet is ready for execution.
+    ///
+    /// # Parameters
+    /// * `hook` - Idle hook.
+    ///
+    /// # Safety
+    /// This is unsafe, because it mutably borrows the idle hook cell. This is safe to call during
+    /// system initialization.
+    pub(crate) unsafe fn set_idle_hook(&'static self, hook: IdleHook) -> Result<(), SystemError> {
+        match self.idle_hook.set(hook) {
+            Ok(_) => Ok(()),
+            Err(_) => Err(SystemError::IdleHookAlreadySet),
+        }
+    }
+
+    /// Sets function called when an internal operation fails at runtime.
+    ///
+    /// # Parameters
+    /// * `hook` - Error hook.
+    ///
+    /// # Safety
+    /// This is unsafe, because it mutably borrows the error hook cell. This is safe to call during
+    /// system initialization.
+    pub(crate) unsafe fn set_error_hook(&'static self, hook: ErrorHook) -> Result<(), SystemError> {
+        match self.error_hook.set(hook) {
+            Ok(_) => Ok(()),
+            Err(_) => Err(SystemError::ErrorHookAlreadySet),
+        }
+    }
+
+    /// Reports failure of an internal operation to the error hook.
+    ///
+    /// # Parameters
+    /// * `error` - Error of the operation.
+    ///
+    /// # Return
+    /// `true` if the error was passed to the error hook, `false` if the hook wasn't set or the
+    /// system can't continue after this error.
+    pub(crate) fn report_error(&'static self, error: SystemError) -> bool {
+        match (self.error_hook.get(), error.runtime_error()) {
+            (Some(hook), Some(runtime_error)) => {
+                hook(runtime_error);
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Sets order of execution of tasklets with the same priority.
+    ///
+    /// # Parameters
+    /// * `policy` - Equal priority policy.
+    pub(crate) fn set_equal_priority_policy(&'static self, policy: EqualPriorityPolicy) {
+        self.equal_priority_policy.lock(|current| *current = policy);
+    }
+
+    /// Calls the idle hook, if it was set.
+    ///
+    /// # Parameters
+    /// * `api` - Runtime API passed to the hook.
+    pub(crate) fn run_idle_hook(&'static self, api: &'static dyn RuntimeApi) {
+        if let Some(hook) = self.idle_hook.get() {
+            hook(api);
+        }
+    }
+
+    /// Returns currently executed tasklet, or `None` if no tasklet is executed.
+    pub(crate) fn current_tasklet(&'static self) -> Option<TaskletPtr> {
+        self.current_tasklet.lock(|current| current.clone())
+    }
+
+    /// Returns time since the start of the current tasklet step.
+    ///
+    /// # Return
+    /// Time since the start of the step, or `None` if no tasklet is executed.
+    pub(crate) fn current_step_time(&'static self) -> Option<Duration> {
+        let step_start = self.current_step_start.lock(|start| *start);
+        self.current_tasklet()?;
+
+        self.time_source
+            .system_time()
+            .checked_duration_since(step_start)
+    }
+
+    /// Cancels current tasklet step.
+    ///
+    /// Cancelled step is not interrupted, but [is_step_cancelled](Executor::is_step_cancelled) and
+    /// [should_yield](Executor::should_yield) return `true` until it returns.
+    ///
+    /// # Return
+    /// Cancelled tasklet, or `None` if no tasklet is executed or its step was already cancelled.
+    pub(crate) fn cancel_current_step(&'static self) -> Option<TaskletPtr> {
+        let tasklet = self.current_tasklet()?;
+        let cancelled = self
+            .current_step_cancellation
+            .lock(|cancellation| core::mem::replace(&mut cancellation.requested, true));
+
+        (!cancelled).then_some(tasklet)
+    }
+
+    /// Checks if current tasklet step was cancelled, and acknowledges the cancellation.
+    ///
+    /// # Return
+    /// `true` if current step was cancelled or its tasklet became inactive, `false` otherwise or
+    /// if no tasklet is executed.
+    pub(crate) fn is_step_cancelled(&'static self) -> bool {
+        let Some(tasklet) = self.current_tasklet() else {
+            return false;
+        };
+
+        let requested = self
+            .current_step_cancellation
+            .lock(|cancellation| cancellation.requested);
+        let cancelled = requested || !tasklet.is_active();
+
+        if cancelled {
+            self.current_step_cancellation
+                .lock(|cancellation| cancellation.acknowledged = true);
+        }
+
+        cancelled
+    }
+
+    /// Checks if currently executed tasklet should give way to other tasklets.
+    ///
+    /// # Return
+    /// `true` if current step was cancelled, runs longer than the tasklet yield budget, or tasklet
+    /// with higher priority is waiting for execution, `false` otherwise.
+    pub(crate) fn should_yield(&'static self) -> bool {
+        let Some(tasklet) = self.current_tasklet() else {
+            return false;
+        };
+
+        if self.is_step_cancelled() {
+            return true;
+        }
+
+        if let Some(yield_budget) = tasklet.get_yield_budget() {
+            let step_start = self.current_step_start.lock(|start| *start);
+            let step_time = self
+                .time_source
+                .system_time()
+                .checked_duration_since(step_start);
+
+            if step_time.is_some_and(|step_time| step_time >= yield_budget) {
+                return true;
+            }
+        }
+
+        let priority = tasklet.get_priority();
+        self.tasklet_queue.lock(|q| {
+            q.iter()
+                .any(|queued| queued.tasklet.get_priority() > priority)
+        })
+    }
+
+    /// Checks if currently executed resumable tasklet step should return
+    /// [`StepResult::Incomplete`](crate::StepResult::Incomplete) and continue in the next step.
+    ///
+    /// # Return
+    /// `true` if current tasklet is resumable and [should yield](Executor::should_yield), `false`
+    /// otherwise.
+    pub(crate) fn yield_needed(&'static self) -> bool {
+        let Some(tasklet) = self.current_tasklet() else {
+            return false;
+        };
+
+        tasklet.is_resumable() && self.should_yield()
+    }
+
+    /// Returns `true` if there are no tasklets waiting for execution.
+    pub(crate) fn is_queue_empty(&'static self) -> bool {
+        self.tasklet_queue.lock(|q| q.is_empty())
+    }
+
+    /// Restores order of the execution queue.
+    ///
+    /// Queue is ordered by the tasklet priorities at the time of insertion, so this has to be
+    /// called after priority of any queued tasklet changes.
+    pub(crate) fn reorder_queue(&'static self) {
+        self.tasklet_queue.lock(|q| {
+            let mut queued = Vec::<QueuedTasklet<P>, { Aerugo::TASKLET_COUNT }>::new();
+
+            while let Some(tasklet) = q.pop() {
+                // This cannot fail, because tasklets were just popped from the queue of the same
+                // capacity.
+                queued.push(tasklet).ok().unwrap();
+            }
+
+            for tasklet in queued {
+                // This cannot fail, because tasklets were just popped from this queue.
+                q.push(tasklet).ok().unwrap();
+            }
+        })
+    }
+
     /// Schedules tasklet if there is more work to do, or sets it sleeping otherwise.
     ///
     /// # Parameters
@@ -132,24 +532,67 @@ impl Executor {
 
     /// Adds given tasklet to the execution queue.
     ///
-    /// This marks tasklet as waiting.
+    /// This marks tasklet as waiting. If the queue is full, tasklet is marked as sleeping, so it
+    /// can be woken again.
     ///
     /// # Return
     /// `()` if successful, `SystemError` otherwise.
     fn add_tasklet_to_queue(&'static self, tasklet: TaskletPtr) -> Result<(), SystemError> {
+        let tie_break_key = self.tie_breaker.next_key();
+        let sequence = match self.equal_priority_policy.lock(|policy| *policy) {
+            EqualPriorityPolicy::LastExecutionTime => 0,
+            EqualPriorityPolicy::RoundRobin => self.sequence.lock(|sequence| {
+                *sequence += 1;
+                *sequence
+            }),
+        };
+
         self.tasklet_queue.lock(|q| {
             tasklet.set_status(TaskletStatus::Waiting);
 
-            match q.push(tasklet) {
+            match q.push(QueuedTasklet {
+                deadline: P::release_deadline(&tasklet),
+                tasklet,
+                sequence,
+                tie_break_key,
+                #[cfg(feature = "instrumented")]
+                enqueue_time: self.time_source.system_time(),
+                _policy: PhantomData,
+            }) {
                 Ok(_) => Ok(()),
-                Err(_) => Err(SystemError::ExecutorTaskletQueueFull),
+                Err(queued) => {
+                    queued.tasklet.set_status(TaskletStatus::Sleeping);
+                    Err(SystemError::ExecutorTaskletQueueFull)
+                }
             }
         })
     }
 
-    /// Returns next tasklet that is due for execution, or `None` if the execution queue is empty.
-    fn get_tasklet_for_execution(&'static self) -> Option<TaskletPtr> {
-        self.tasklet_queue.lock(|q| q.pop())
+    /// Returns next tasklet that is due for execution, or `None` if the execution queue is empty
+    /// or none of the queued tasklets can be executed in the current partition window.
+    fn get_tasklet_for_execution(&'static self) -> Option<QueuedTasklet<P>> {
+        self.tasklet_queue.lock(|q| {
+            let mut postponed = Vec::<QueuedTasklet<P>, { Aerugo::TASKLET_COUNT }>::new();
+
+            let tasklet = loop {
+                match q.pop() {
+                    Some(queued) if self.partition_manager.can_execute(&queued.tasklet) => {
+                        break Some(queued)
+                    }
+                    // This cannot fail, because postponed tasklets were just popped from the queue
+                    // of the same capacity.
+                    Some(queued) => postponed.push(queued).ok().unwrap(),
+                    None => break None,
+                }
+            };
+
+            for queued in postponed {
+                // This cannot fail, because postponed tasklets were just popped from this queue.
+                q.push(queued).ok().unwrap();
+            }
+
+            tasklet
+        })
     }
 }
 
@@ -158,7 +601,9 @@ mod tests {
     use super::*;
 
     use crate::boolean_condition::{BooleanConditionSet, BooleanConditionSetType};
-    use crate::tasklet::{Tasklet, TaskletConfig, TaskletId};
+    use crate::guard_zone::{ContextGuard, GuardZone};
+    use crate::scheduling_policy::{EarliestDeadlineFirst, PriorityScheduling};
+    use crate::tasklet::{StepResult, Tasklet, TaskletConfig, TaskletId, TaskletStep};
     use crate::tests::{MockConditionSet, MockDataProvider, MockRuntimeApi};
 
     /// @SRS{ROS-FUN-RTOS-050}
@@ -178,15 +623,21 @@ mod tests {
         static mock_runtime_api: MockRuntimeApi = MockRuntimeApi {};
 
         static mut tasklet_context: () = ();
+        static tasklet_context_guard_head: GuardZone = GuardZone::new();
+        static tasklet_context_guard_tail: GuardZone = GuardZone::new();
         static mut tasklet_config: TaskletConfig = TaskletConfig {
             name: "TestTasklet",
             priority: 0,
+            deadline: None,
+            yield_budget: None,
+            finalize: None,
         };
         static tasklet: Tasklet<(), (), 0> = Tasklet::new(
             TaskletId(0),
             unsafe { tasklet_config },
-            |_, _, _| {},
+            TaskletStep::ByValue(|_, _, _| {}),
             unsafe { &mut tasklet_context },
+            ContextGuard::new(&tasklet_context_guard_head, &tasklet_context_guard_tail),
             &mock_condition_set.storage,
             &mock_runtime_api,
         );
@@ -197,7 +648,9 @@ mod tests {
         static time_source: TimeSource = TimeSource::new();
         unsafe { time_source.set_system_start() };
 
-        static executor: Executor = Executor::new(&time_source);
+        static partition_manager: PartitionManager = PartitionManager::new(&time_source);
+        static tie_breaker: TieBreaker = TieBreaker::new();
+        static executor: Executor = Executor::new(&time_source, &partition_manager, &tie_breaker);
 
         // Unscheduled tasklet is `Sleeping`.
         assert_eq!(tasklet.get_status(), TaskletStatus::Sleeping);
@@ -217,4 +670,519 @@ mod tests {
         assert!(execution_data.is_ok());
         assert!(execution_data.unwrap().unwrap().was_executed());
     }
+
+    #[cfg_attr(not(doc), test)]
+    #[allow(non_upper_case_globals)]
+    fn resumable_tasklet_rescheduled_until_complete() {
+        static mut mock_data_provider: MockDataProvider = MockDataProvider::new();
+        static mock_condition_set: MockConditionSet<0> = MockConditionSet::new();
+        static mock_runtime_api: MockRuntimeApi = MockRuntimeApi {};
+
+        static mut tasklet_context: u8 = 0;
+        static tasklet_context_guard_head: GuardZone = GuardZone::new();
+        static tasklet_context_guard_tail: GuardZone = GuardZone::new();
+        static tasklet: Tasklet<(), u8, 0> = Tasklet::new(
+            TaskletId(0),
+            TaskletConfig {
+                name: "Resumable",
+                priority: 0,
+                deadline: None,
+                yield_budget: None,
+                finalize: None,
+            },
+            TaskletStep::Resumable(|data, steps, _| {
+                if data.is_some() {
+                    *steps = 0;
+                }
+                *steps += 1;
+
+                assert!(!executor.yield_needed());
+                assert!(executor.cancel_current_step().is_some());
+                assert!(executor.yield_needed());
+
+                if *steps < 3 {
+                    StepResult::Incomplete
+                } else {
+                    StepResult::Complete
+                }
+            }),
+            unsafe { &mut tasklet_context },
+            ContextGuard::new(&tasklet_context_guard_head, &tasklet_context_guard_tail),
+            &mock_condition_set.storage,
+            &mock_runtime_api,
+        );
+
+        unsafe { tasklet.subscribe(&mock_data_provider).unwrap() };
+
+        static time_source: TimeSource = TimeSource::new();
+        unsafe { time_source.set_system_start() };
+        static partition_manager: PartitionManager = PartitionManager::new(&time_source);
+        static tie_breaker: TieBreaker = TieBreaker::new();
+        static executor: Executor = Executor::new(&time_source, &partition_manager, &tie_breaker);
+
+        unsafe { mock_data_provider.set_data_waiting(true) };
+        assert_eq!(executor.schedule_tasklet(&tasklet.ptr()), Ok(true));
+
+        let execution_data = executor.execute_next_tasklet().unwrap().unwrap();
+        assert!(execution_data.was_executed());
+        unsafe { mock_data_provider.set_data_waiting(false) };
+
+        // Incomplete step is continued without new data.
+        assert_eq!(tasklet.get_status(), TaskletStatus::Waiting);
+        executor.execute_next_tasklet().unwrap();
+        assert_eq!(tasklet.get_status(), TaskletStatus::Waiting);
+        executor.execute_next_tasklet().unwrap();
+
+        assert_eq!(unsafe { tasklet_context }, 3);
+        assert_eq!(tasklet.get_status(), TaskletStatus::Sleeping);
+    }
+
+    #[cfg_attr(not(doc), test)]
+    #[allow(non_upper_case_globals)]
+    fn inherited_priority_reorders_queue() {
+        static mock_runtime_api: MockRuntimeApi = MockRuntimeApi {};
+        static mock_condition_set: MockConditionSet<0> = MockConditionSet::new();
+
+        static mut low_context: () = ();
+        static low_guard_head: GuardZone = GuardZone::new();
+        static low_guard_tail: GuardZone = GuardZone::new();
+        static low: Tasklet<(), (), 0> = Tasklet::new(
+            TaskletId(0),
+            TaskletConfig {
+                name: "Low",
+                priority: 1,
+                deadline: None,
+                yield_budget: None,
+                finalize: None,
+            },
+            TaskletStep::ByValue(|_, _, _| {}),
+            unsafe { &mut low_context },
+            ContextGuard::new(&low_guard_head, &low_guard_tail),
+            &mock_condition_set.storage,
+            &mock_runtime_api,
+        );
+
+        static mut high_context: () = ();
+        static high_guard_head: GuardZone = GuardZone::new();
+        static high_guard_tail: GuardZone = GuardZone::new();
+        static high: Tasklet<(), (), 0> = Tasklet::new(
+            TaskletId(1),
+            TaskletConfig {
+                name: "High",
+                priority: 2,
+                deadline: None,
+                yield_budget: None,
+                finalize: None,
+            },
+            TaskletStep::ByValue(|_, _, _| {}),
+            unsafe { &mut high_context },
+            ContextGuard::new(&high_guard_head, &high_guard_tail),
+            &mock_condition_set.storage,
+            &mock_runtime_api,
+        );
+
+        static time_source: TimeSource = TimeSource::new();
+        static partition_manager: PartitionManager = PartitionManager::new(&time_source);
+        static tie_breaker: TieBreaker = TieBreaker::new();
+        static executor: Executor = Executor::new(&time_source, &partition_manager, &tie_breaker);
+
+        assert_eq!(executor.schedule_tasklet(&low.ptr()), Ok(true));
+        assert_eq!(executor.schedule_tasklet(&high.ptr()), Ok(true));
+
+        low.set_inherited_priority(Some(3));
+        executor.reorder_queue();
+
+        let first = executor.get_tasklet_for_execution().unwrap();
+        assert_eq!(first.tasklet.get_id(), TaskletId(0));
+
+        low.set_inherited_priority(None);
+        assert_eq!(low.get_priority(), 1);
+    }
+
+    #[cfg_attr(not(doc), test)]
+    #[allow(non_upper_case_globals)]
+    fn round_robin_equal_priority() {
+        static mut mock_data_provider: MockDataProvider = MockDataProvider::new();
+        static mock_runtime_api: MockRuntimeApi = MockRuntimeApi {};
+        static mock_condition_set: MockConditionSet<0> = MockConditionSet::new();
+
+        static mut first_context: () = ();
+        static first_guard_head: GuardZone = GuardZone::new();
+        static first_guard_tail: GuardZone = GuardZone::new();
+        static first: Tasklet<(), (), 0> = Tasklet::new(
+            TaskletId(0),
+            TaskletConfig {
+                name: "First",
+                priority: 1,
+                deadline: None,
+                yield_budget: None,
+                finalize: None,
+            },
+            TaskletStep::ByValue(|_, _, _| {}),
+            unsafe { &mut first_context },
+            ContextGuard::new(&first_guard_head, &first_guard_tail),
+            &mock_condition_set.storage,
+            &mock_runtime_api,
+        );
+
+        static mut second_context: () = ();
+        static second_guard_head: GuardZone = GuardZone::new();
+        static second_guard_tail: GuardZone = GuardZone::new();
+        static second: Tasklet<(), (), 0> = Tasklet::new(
+            TaskletId(1),
+            TaskletConfig {
+                name: "Second",
+                priority: 1,
+                deadline: None,
+                yield_budget: None,
+                finalize: None,
+            },
+            TaskletStep::ByValue(|_, _, _| {}),
+            unsafe { &mut second_context },
+            ContextGuard::new(&second_guard_head, &second_guard_tail),
+            &mock_condition_set.storage,
+            &mock_runtime_api,
+        );
+
+        unsafe {
+            first.subscribe(&mock_data_provider).unwrap();
+            second.subscribe(&mock_data_provider).unwrap();
+            mock_data_provider.set_data_waiting(true);
+        }
+
+        static time_source: TimeSource = TimeSource::new();
+        unsafe { time_source.set_system_start() };
+        static partition_manager: PartitionManager = PartitionManager::new(&time_source);
+        static tie_breaker: TieBreaker = TieBreaker::new();
+        static executor: Executor = Executor::new(&time_source, &partition_manager, &tie_breaker);
+        executor.set_equal_priority_policy(EqualPriorityPolicy::RoundRobin);
+
+        assert_eq!(executor.schedule_tasklet(&first.ptr()), Ok(true));
+        assert_eq!(executor.schedule_tasklet(&second.ptr()), Ok(true));
+
+        // Both tasklets always have work, so they are rescheduled after each execution.
+        for expected in [0, 1, 0, 1, 0, 1] {
+            let execution_data = executor.execute_next_tasklet().unwrap().unwrap();
+            assert_eq!(*execution_data.tasklet_id(), TaskletId(expected));
+        }
+    }
+
+    #[cfg_attr(not(doc), test)]
+    #[allow(non_upper_case_globals)]
+    fn earliest_deadline_first() {
+        static mock_runtime_api: MockRuntimeApi = MockRuntimeApi {};
+        static mock_condition_set: MockConditionSet<0> = MockConditionSet::new();
+
+        static mut early_context: () = ();
+        static early_guard_head: GuardZone = GuardZone::new();
+        static early_guard_tail: GuardZone = GuardZone::new();
+        static early: Tasklet<(), (), 0> = Tasklet::new(
+            TaskletId(0),
+            TaskletConfig {
+                name: "Early",
+                priority: 1,
+                deadline: None,
+                yield_budget: None,
+                finalize: None,
+            },
+            TaskletStep::ByValue(|_, _, _| {}),
+            unsafe { &mut early_context },
+            ContextGuard::new(&early_guard_head, &early_guard_tail),
+            &mock_condition_set.storage,
+            &mock_runtime_api,
+        );
+
+        static mut late_context: () = ();
+        static late_guard_head: GuardZone = GuardZone::new();
+        static late_guard_tail: GuardZone = GuardZone::new();
+        static late: Tasklet<(), (), 0> = Tasklet::new(
+            TaskletId(1),
+            TaskletConfig {
+                name: "Late",
+                priority: 2,
+                deadline: None,
+                yield_budget: None,
+                finalize: None,
+            },
+            TaskletStep::ByValue(|_, _, _| {}),
+            unsafe { &mut late_context },
+            ContextGuard::new(&late_guard_head, &late_guard_tail),
+            &mock_condition_set.storage,
+            &mock_runtime_api,
+        );
+
+        static mut background_context: () = ();
+        static background_guard_head: GuardZone = GuardZone::new();
+        static background_guard_tail: GuardZone = GuardZone::new();
+        static background: Tasklet<(), (), 0> = Tasklet::new(
+            TaskletId(2),
+            TaskletConfig {
+                name: "Background",
+                priority: 3,
+                deadline: None,
+                yield_budget: None,
+                finalize: None,
+            },
+            TaskletStep::ByValue(|_, _, _| {}),
+            unsafe { &mut background_context },
+            ContextGuard::new(&background_guard_head, &background_guard_tail),
+            &mock_condition_set.storage,
+            &mock_runtime_api,
+        );
+
+        static time_source: TimeSource = TimeSource::new();
+        static partition_manager: PartitionManager = PartitionManager::new(&time_source);
+        static tie_breaker: TieBreaker = TieBreaker::new();
+        static edf_executor: Executor<EarliestDeadlineFirst> =
+            Executor::new(&time_source, &partition_manager, &tie_breaker);
+        static priority_executor: Executor<PriorityScheduling> =
+            Executor::new(&time_source, &partition_manager, &tie_breaker);
+
+        early.set_absolute_deadline(Some(Instant::from_ticks(1000)));
+        late.set_absolute_deadline(Some(Instant::from_ticks(2000)));
+
+        assert_eq!(edf_executor.schedule_tasklet(&background.ptr()), Ok(true));
+        assert_eq!(edf_executor.schedule_tasklet(&late.ptr()), Ok(true));
+        assert_eq!(edf_executor.schedule_tasklet(&early.ptr()), Ok(true));
+
+        // Tasklets with deadline go first regardless of their priority.
+        for expected in [0, 1, 2] {
+            let queued = edf_executor.get_tasklet_for_execution().unwrap();
+            assert_eq!(queued.tasklet.get_id(), TaskletId(expected));
+            queued.tasklet.set_status(TaskletStatus::Sleeping);
+        }
+
+        assert_eq!(
+            priority_executor.schedule_tasklet(&background.ptr()),
+            Ok(true)
+        );
+        assert_eq!(priority_executor.schedule_tasklet(&late.ptr()), Ok(true));
+        assert_eq!(priority_executor.schedule_tasklet(&early.ptr()), Ok(true));
+
+        // Deadlines are ignored by the priority scheduling.
+        for expected in [2, 1, 0] {
+            let queued = priority_executor.get_tasklet_for_execution().unwrap();
+            assert_eq!(queued.tasklet.get_id(), TaskletId(expected));
+            assert_eq!(queued.deadline, None);
+        }
+    }
+
+    #[cfg(feature = "wake-latency")]
+    #[cfg_attr(not(doc), test)]
+    #[allow(non_upper_case_globals)]
+    fn interrupt_wake_latency() {
+        static mut mock_data_provider: MockDataProvider = MockDataProvider::new();
+        static mock_condition_set: MockConditionSet<0> = MockConditionSet::new();
+        static mock_runtime_api: MockRuntimeApi = MockRuntimeApi {};
+
+        static mut tasklet_context: () = ();
+        static tasklet_context_guard_head: GuardZone = GuardZone::new();
+        static tasklet_context_guard_tail: GuardZone = GuardZone::new();
+        static tasklet: Tasklet<(), (), 0> = Tasklet::new(
+            TaskletId(0),
+            TaskletConfig {
+                name: "Woken",
+                priority: 0,
+                deadline: None,
+                yield_budget: None,
+                finalize: None,
+            },
+            TaskletStep::ByValue(|_, _, _| {}),
+            unsafe { &mut tasklet_context },
+            ContextGuard::new(&tasklet_context_guard_head, &tasklet_context_guard_tail),
+            &mock_condition_set.storage,
+            &mock_runtime_api,
+        );
+
+        unsafe { tasklet.subscribe(&mock_data_provider).unwrap() };
+
+        static time_source: TimeSource = TimeSource::new();
+        unsafe { time_source.set_system_start() };
+        static partition_manager: PartitionManager = PartitionManager::new(&time_source);
+        static tie_breaker: TieBreaker = TieBreaker::new();
+        static executor: Executor = Executor::new(&time_source, &partition_manager, &tie_breaker);
+
+        unsafe { mock_data_provider.set_data_waiting(true) };
+
+        // Earlier wake from an interrupt is kept until the tasklet is executed.
+        let later_wake = time_source.system_time();
+        tasklet.set_interrupt_wake_time(Instant::from_ticks(0));
+        tasklet.set_interrupt_wake_time(later_wake);
+        assert_eq!(executor.schedule_tasklet(&tasklet.ptr()), Ok(true));
+
+        let execution_data = executor.execute_next_tasklet().unwrap().unwrap();
+        assert!(execution_data.was_executed());
+        let wake_latency = execution_data.wake_latency().unwrap();
+        assert!(wake_latency >= later_wake.duration_since_epoch());
+
+        // Wake from outside of an interrupt isn't measured.
+        unsafe { mock_data_provider.set_data_waiting(false) };
+        executor.execute_next_tasklet().unwrap();
+        unsafe { mock_data_provider.set_data_waiting(true) };
+        assert_eq!(executor.schedule_tasklet(&tasklet.ptr()), Ok(true));
+
+        let execution_data = executor.execute_next_tasklet().unwrap().unwrap();
+        assert!(execution_data.was_executed());
+        assert_eq!(execution_data.wake_latency(), None);
+    }
+
+    #[cfg_attr(not(doc), test)]
+    #[allow(non_upper_case_globals)]
+    fn ignored_cancellation() {
+        static mut mock_data_provider: MockDataProvider = MockDataProvider::new();
+        static mock_condition_set: MockConditionSet<0> = MockConditionSet::new();
+        static mock_runtime_api: MockRuntimeApi = MockRuntimeApi {};
+
+        // First step ignores the cancellation, next ones poll it.
+        static mut tasklet_context: u32 = 0;
+        static tasklet_context_guard_head: GuardZone = GuardZone::new();
+        static tasklet_context_guard_tail: GuardZone = GuardZone::new();
+        static tasklet: Tasklet<(), u32, 0> = Tasklet::new(
+            TaskletId(0),
+            TaskletConfig {
+                name: "Cancelled",
+                priority: 0,
+                deadline: None,
+                yield_budget: None,
+                finalize: None,
+            },
+            TaskletStep::ByValue(|_, step_count, _| {
+                assert!(!executor.is_step_cancelled());
+                assert!(executor.cancel_current_step().is_some());
+                assert!(executor.cancel_current_step().is_none());
+                if *step_count > 0 {
+                    assert!(executor.should_yield());
+                    // Step of this tasklet can't be continued.
+                    assert!(!executor.yield_needed());
+                }
+                *step_count += 1;
+            }),
+            unsafe { &mut tasklet_context },
+            ContextGuard::new(&tasklet_context_guard_head, &tasklet_context_guard_tail),
+            &mock_condition_set.storage,
+            &mock_runtime_api,
+        );
+
+        unsafe {
+            tasklet.subscribe(&mock_data_provider).unwrap();
+            mock_data_provider.set_data_waiting(true);
+        }
+
+        static time_source: TimeSource = TimeSource::new();
+        unsafe { time_source.set_system_start() };
+        static partition_manager: PartitionManager = PartitionManager::new(&time_source);
+        static tie_breaker: TieBreaker = TieBreaker::new();
+        static executor: Executor = Executor::new(&time_source, &partition_manager, &tie_breaker);
+
+        assert_eq!(executor.schedule_tasklet(&tasklet.ptr()), Ok(true));
+
+        let execution_data = executor.execute_next_tasklet().unwrap().unwrap();
+        assert!(execution_data.was_executed());
+        assert!(execution_data.cancellation_ignored());
+
+        let execution_data = executor.execute_next_tasklet().unwrap().unwrap();
+        assert!(execution_data.was_executed());
+        assert!(!execution_data.cancellation_ignored());
+
+        // Cancellation applies only to the executed step.
+        assert!(executor.cancel_current_step().is_none());
+        assert!(!executor.is_step_cancelled());
+    }
+
+    #[cfg_attr(not(doc), test)]
+    #[allow(non_upper_case_globals)]
+    fn report_error_to_hook() {
+        static reported_error: Mutex<Option<RuntimeError>> = Mutex::new(None);
+
+        static time_source: TimeSource = TimeSource::new();
+        static partition_manager: PartitionManager = PartitionManager::new(&time_source);
+        static tie_breaker: TieBreaker = TieBreaker::new();
+        static executor: Executor = Executor::new(&time_source, &partition_manager, &tie_breaker);
+
+        assert!(!executor.report_error(SystemError::ExecutorTaskletQueueFull));
+
+        let hook_result = unsafe {
+            executor.set_error_hook(|error| reported_error.lock(|reported| *reported = Some(error)))
+        };
+        assert!(hook_result.is_ok());
+
+        assert!(executor.report_error(SystemError::ExecutorTaskletQueueFull));
+        assert_eq!(
+            reported_error.lock(|reported| reported.take()),
+            Some(RuntimeError::ExecutorQueueFull)
+        );
+
+        // Errors after which the system can't continue are not passed to the hook.
+        assert!(!executor.report_error(SystemError::StorageAlreadyInitialized));
+        assert_eq!(reported_error.lock(|reported| reported.take()), None);
+    }
+
+    #[cfg_attr(not(doc), test)]
+    #[allow(non_upper_case_globals)]
+    fn terminate_tasklet_with_corrupted_context() {
+        static reported_error: Mutex<Option<RuntimeError>> = Mutex::new(None);
+
+        static mut mock_data_provider: MockDataProvider = MockDataProvider::new();
+        static mock_condition_set: MockConditionSet<0> = MockConditionSet::new();
+        static mock_runtime_api: MockRuntimeApi = MockRuntimeApi {};
+
+        static mut tasklet_context: u8 = 0;
+        static tasklet_context_guard_head: GuardZone = GuardZone::new();
+        static mut tasklet_context_guard_tail: GuardZone = GuardZone::new();
+        static tasklet: Tasklet<(), u8, 0> = Tasklet::new(
+            TaskletId(0),
+            TaskletConfig {
+                name: "Overrunning",
+                priority: 0,
+                deadline: None,
+                yield_budget: None,
+                finalize: Some(|context, _| {
+                    if let Some(count) = context.downcast_mut::<u8>() {
+                        *count += 1;
+                    }
+                }),
+            },
+            TaskletStep::ByValue(|_, _, _| {
+                // Overrun of the context overwrites the guard zone placed after it.
+                let guard = core::ptr::addr_of_mut!(tasklet_context_guard_tail) as *mut u32;
+                unsafe { guard.write_volatile(0) };
+            }),
+            unsafe { &mut tasklet_context },
+            ContextGuard::new(&tasklet_context_guard_head, unsafe {
+                &*core::ptr::addr_of!(tasklet_context_guard_tail)
+            }),
+            &mock_condition_set.storage,
+            &mock_runtime_api,
+        );
+
+        unsafe { tasklet.subscribe(&mock_data_provider).unwrap() };
+
+        static time_source: TimeSource = TimeSource::new();
+        unsafe { time_source.set_system_start() };
+        static partition_manager: PartitionManager = PartitionManager::new(&time_source);
+        static tie_breaker: TieBreaker = TieBreaker::new();
+        static executor: Executor = Executor::new(&time_source, &partition_manager, &tie_breaker);
+
+        let hook_result = unsafe {
+            executor.set_error_hook(|error| reported_error.lock(|reported| *reported = Some(error)))
+        };
+        assert!(hook_result.is_ok());
+
+        unsafe { mock_data_provider.set_data_waiting(true) };
+        assert_eq!(executor.schedule_tasklet(&tasklet.ptr()), Ok(true));
+
+        // Error is recovered by the hook, and the step is still recorded.
+        let execution_data = executor.execute_next_tasklet().unwrap().unwrap();
+        assert!(execution_data.was_executed());
+        assert_eq!(
+            reported_error.lock(|reported| reported.take()),
+            Some(RuntimeError::TaskletContextCorrupted("Overrunning"))
+        );
+
+        // Tasklet is terminated and finalized, and can't be scheduled again.
+        assert_eq!(tasklet.get_status(), TaskletStatus::Sleeping);
+        assert!(tasklet.is_terminated());
+        assert_eq!(unsafe { tasklet_context }, 1);
+        assert_eq!(executor.schedule_tasklet(&tasklet.ptr()), Ok(false));
+    }
 }
@@ -0,0 +1,140 @@
+//! Application faults.
+//!
+//! Fault is a violation of an assumption made by the application code, detected at runtime with
+//! [`kernel_assert`](crate::kernel_assert) or [`require`](crate::require) macros. Instead of a bare
+//! panic, each fault creates a [`FaultRecord`] which is stored in the fault recorder and handled
+//! according to the system's [`FaultPolicy`].
+//!
+//! In debug builds every fault causes a panic, so it can't be missed during development. In
+//! release builds fault policy is selected with `AERUGO_FAULT_POLICY` environment variable:
+//! * `0` - [`FaultPolicy::Ignore`],
+//! * `1` - [`FaultPolicy::Log`] (default),
+//! * `2` - [`FaultPolicy::Reset`].
+
+use core::fmt;
+
+/// Record of a detected fault.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct FaultRecord {
+    /// Source file in which fault was detected, or name of the system component that detected it.
+    pub file: &'static str,
+    /// Line in which fault was detected, `0` if it was detected by a system component.
+    pub line: u32,
+    /// Application-defined fault code.
+    pub code: u32,
+}
+
+impl FaultRecord {
+    /// Creates new fault record.
+    ///
+    /// # Parameters
+    /// * `file` - Source file in which fault was detected.
+    /// * `line` - Line in which fault was detected.
+    /// * `code` - Application-defined fault code.
+    pub const fn new(file: &'static str, line: u32, code: u32) -> Self {
+        FaultRecord { file, line, code }
+    }
+}
+
+impl fmt::Display for FaultRecord {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "fault {} at {}:{}", self.code, self.file, self.line)
+    }
+}
+
+/// Policy of handling faults in release builds.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum FaultPolicy {
+    /// Fault is only stored in the fault recorder.
+    Ignore,
+    /// Fault is stored in the fault recorder and logged.
+    Log,
+    /// Fault is stored in the fault recorder and logged, then the system is reset.
+    Reset,
+}
+
+impl FaultPolicy {
+    /// Converts policy ID (value of `AERUGO_FAULT_POLICY` environment variable) to fault policy.
+    ///
+    /// # Parameters
+    /// * `id` - Policy ID.
+    pub(crate) const fn from_id(id: u8) -> Self {
+        match id {
+            0 => FaultPolicy::Ignore,
+            1 => FaultPolicy::Log,
+            2 => FaultPolicy::Reset,
+            _ => panic!("Invalid fault policy ID"),
+        }
+    }
+}
+
+/// Asserts that a condition is true at runtime.
+///
+/// If the condition is false, [`FaultRecord`] with current file, line and given fault code is
+/// reported to the system with [`Aerugo::report_fault`](crate::Aerugo::report_fault). If the fault
+/// policy allows it, execution continues after the assertion.
+///
+/// # Parameters
+/// * `cond` - Asserted condition.
+/// * `code` - Application-defined fault code, `0` if not given.
+///
+/// # Example
+/// ```
+/// # use aerugo::kernel_assert;
+/// fn set_speed(speed: u32) {
+///     kernel_assert!(speed <= 100, 12);
+/// }
+/// # set_speed(50);
+/// ```
+#[macro_export]
+macro_rules! kernel_assert {
+    ($cond:expr) => {
+        $crate::kernel_assert!($cond, 0)
+    };
+    ($cond:expr, $code:expr) => {
+        if !($cond) {
+            $crate::Aerugo::report_fault($crate::FaultRecord::new(
+                ::core::file!(),
+                ::core::line!(),
+                $code,
+            ));
+        }
+    };
+}
+
+/// Checks a precondition of a function at runtime.
+///
+/// If the condition is false, [`FaultRecord`] with current file, line and given fault code is
+/// reported to the system with [`Aerugo::report_fault`](crate::Aerugo::report_fault). If the fault
+/// policy allows it, enclosing function returns, with given value if one is given.
+///
+/// # Parameters
+/// * `cond` - Required condition.
+/// * `code` - Application-defined fault code.
+/// * `ret` - Value returned from the enclosing function if condition is false.
+///
+/// # Example
+/// ```
+/// # use aerugo::require;
+/// fn divide(a: u32, b: u32) -> Option<u32> {
+///     require!(b != 0, 3, None);
+///     Some(a / b)
+/// }
+/// # divide(4, 2);
+/// ```
+#[macro_export]
+macro_rules! require {
+    ($cond:expr, $code:expr) => {
+        $crate::require!($cond, $code, ())
+    };
+    ($cond:expr, $code:expr, $ret:expr) => {
+        if !($cond) {
+            $crate::Aerugo::report_fault($crate::FaultRecord::new(
+                ::core::file!(),
+                ::core::line!(),
+                $code,
+            ));
+            return $ret;
+        }
+    };
+}
@@ -0,0 +1,160 @@
+//! Fault manager.
+//!
+//! This module contains fault manager. It's responsibility is to record reported faults and handle
+//! them according to the fault policy.
+
+use aerugo_hal::AerugoHal;
+use env_parser::read_env;
+use heapless::Deque;
+
+use crate::fault::{FaultPolicy, FaultRecord};
+use crate::hal::Hal;
+use crate::mutex::Mutex;
+
+/// List of the most recent fault records.
+type FaultRecords = Deque<FaultRecord, { FaultManager::RECORD_COUNT }>;
+
+/// Fault manager.
+///
+/// This shouldn't be created by hand by the user or anywhere else in the code.
+/// It should be used as a singleton (crate::aerugo::FAULT_MANAGER) and shouldn't be directly
+/// accessed by any other part of the system.
+pub(crate) struct FaultManager {
+    /// Most recent fault records, from the oldest to the newest.
+    records: Mutex<FaultRecords>,
+    /// First fault reported since the last clear.
+    latched_fault: Mutex<Option<FaultRecord>>,
+}
+
+impl FaultManager {
+    /// Number of the most recent fault records stored by the manager.
+    #[read_env("AERUGO_FAULT_RECORD_COUNT")]
+    pub(crate) const RECORD_COUNT: usize = 8;
+
+    /// ID of the fault policy used in release builds.
+    #[read_env("AERUGO_FAULT_POLICY")]
+    const POLICY_ID: u8 = 1;
+
+    /// Fault policy used in release builds.
+    pub(crate) const POLICY: FaultPolicy = FaultPolicy::from_id(Self::POLICY_ID);
+
+    /// Creates new fault manager instance.
+    ///
+    /// # Safety
+    /// This shouldn't be called more than once.
+    pub(crate) const fn new() -> Self {
+        FaultManager {
+            records: Mutex::new(FaultRecords::new()),
+            latched_fault: Mutex::new(None),
+        }
+    }
+
+    /// Reports a fault.
+    ///
+    /// Fault is recorded and then handled according to the fault policy. In debug builds this
+    /// function panics.
+    ///
+    /// # Parameters
+    /// * `fault` - Reported fault.
+    pub(crate) fn report(&'static self, fault: FaultRecord) {
+        self.record(fault);
+
+        if cfg!(debug_assertions) {
+            panic!("Unhandled {}", fault);
+        }
+
+        match Self::POLICY {
+            FaultPolicy::Ignore => (),
+            FaultPolicy::Log => {
+                crate::logln!("Aerugo: {}", fault);
+            }
+            FaultPolicy::Reset => {
+                crate::logln!("Aerugo: {}, resetting the system", fault);
+                Hal::reset_system();
+            }
+        }
+    }
+
+    /// Records a fault, without handling it.
+    ///
+    /// If recorder is full, the oldest record is dropped.
+    ///
+    /// # Parameters
+    /// * `fault` - Fault to record.
+    pub(crate) fn record(&'static self, fault: FaultRecord) {
+        self.store(fault);
+
+        self.latched_fault.lock(|latched| {
+            if latched.is_none() {
+                *latched = Some(fault);
+            }
+        });
+    }
+
+    /// Stores a fault in the recorder, without latching it.
+    ///
+    /// If recorder is full, the oldest record is dropped.
+    ///
+    /// # Parameters
+    /// * `fault` - Fault to store.
+    pub(crate) fn store(&'static self, fault: FaultRecord) {
+        self.records.lock(|records| {
+            if records.is_full() {
+                records.pop_front();
+            }
+            // Can't fail, as there is always space left after removing the oldest record.
+            let _ = records.push_back(fault);
+        });
+    }
+
+    /// Returns fault record of given age.
+    ///
+    /// # Parameters
+    /// * `index` - Age of the record, `0` being the most recent one.
+    pub(crate) fn fault_record(&'static self, index: usize) -> Option<FaultRecord> {
+        self.records
+            .lock(|records| records.iter().rev().nth(index).copied())
+    }
+
+    /// Returns the first fault reported since the last clear.
+    pub(crate) fn latched_fault(&'static self) -> Option<FaultRecord> {
+        self.latched_fault.lock(|latched| *latched)
+    }
+
+    /// Clears latched fault.
+    pub(crate) fn clear_latched_fault(&'static self) {
+        self.latched_fault.lock(|latched| *latched = None);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn record_faults() {
+        static FAULT_MANAGER: FaultManager = FaultManager::new();
+
+        for line in 0..(FaultManager::RECORD_COUNT as u32 + 1) {
+            FAULT_MANAGER.record(FaultRecord::new("file.rs", line, 7));
+        }
+
+        let newest_line = FaultManager::RECORD_COUNT as u32;
+        assert_eq!(
+            FAULT_MANAGER.fault_record(0),
+            Some(FaultRecord::new("file.rs", newest_line, 7))
+        );
+        assert_eq!(
+            FAULT_MANAGER.fault_record(FaultManager::RECORD_COUNT - 1),
+            Some(FaultRecord::new("file.rs", 1, 7))
+        );
+        assert_eq!(FAULT_MANAGER.fault_record(FaultManager::RECORD_COUNT), None);
+
+        assert_eq!(
+            FAULT_MANAGER.latched_fault(),
+            Some(FaultRecord::new("file.rs", 0, 7))
+        );
+        FAULT_MANAGER.clear_latched_fault();
+        assert_eq!(FAULT_MANAGER.latched_fault(), None);
+    }
+}
@@ -0,0 +1,223 @@
+//! Table-driven fault detection, isolation and recovery (FDIR).
+//!
+//! FDIR engine is a system tasklet that maps fault events to recovery actions, as described by a
+//! static table of [rules](FdirRule). Fault events can be emitted by the application, by the
+//! [limit monitors](crate::aerugo::Aerugo::create_limit_monitor) or by the execution monitoring.
+//!
+//! Each rule has a list of actions ordered by escalation. First trigger of the rule executes the
+//! first action, each following trigger executes the next one, and the last action is repeated
+//! if the rule is still being triggered. Escalation is reset when the rule isn't triggered for
+//! the escalation timeout. Triggers exceeding the rate limit of the rule are ignored.
+//!
+//! Each executed action is stored in the fault recorder, with the engine name and ID of the
+//! trigger event as a fault code.
+
+mod fdir_config;
+mod fdir_rule;
+mod fdir_storage;
+
+pub use self::fdir_config::FdirConfig;
+pub use self::fdir_rule::{FdirRule, RecoveryAction};
+pub use self::fdir_storage::FdirStorage;
+
+use aerugo_hal::AerugoHal;
+
+use crate::aerugo::Aerugo;
+use crate::api::RuntimeApi;
+use crate::event::EventId;
+use crate::fault::FaultRecord;
+use crate::hal::Hal;
+use crate::rate_limit::RateLimiter;
+use crate::time::Instant;
+
+/// State of a single FDIR rule.
+struct RuleState {
+    /// Index of the action executed on the next trigger.
+    level: usize,
+    /// Time of the last executed action.
+    last_action: Option<Instant>,
+    /// Limiter of executed actions.
+    rate_limiter: RateLimiter,
+}
+
+/// FDIR engine.
+///
+/// This is a context of the FDIR engine tasklet.
+///
+/// # Generic Parameters
+/// * `RULE_COUNT` - Number of rules.
+pub(crate) struct FdirEngine<const RULE_COUNT: usize> {
+    /// Name of the engine.
+    name: &'static str,
+    /// Rules of the engine.
+    rules: &'static [FdirRule; RULE_COUNT],
+    /// States of the rules.
+    states: [RuleState; RULE_COUNT],
+}
+
+impl<const RULE_COUNT: usize> FdirEngine<RULE_COUNT> {
+    /// Creates new FDIR engine.
+    ///
+    /// # Parameters
+    /// * `config` - Engine configuration.
+    pub(crate) fn new(config: &FdirConfig<RULE_COUNT>) -> Self {
+        FdirEngine {
+            name: config.name,
+            rules: config.rules,
+            states: config.rules.map(|rule| RuleState {
+                level: 0,
+                last_action: None,
+                rate_limiter: RateLimiter::new(rule.rate_limit),
+            }),
+        }
+    }
+
+    /// Selects action for the trigger.
+    ///
+    /// # Parameters
+    /// * `trigger` - ID of the trigger event.
+    /// * `time` - Current system time.
+    ///
+    /// # Return
+    /// Action to execute, or `None` if there is no rule for this event or it was rate limited.
+    fn select_action(&mut self, trigger: EventId, time: Instant) -> Option<RecoveryAction> {
+        let (rule, state) = self
+            .rules
+            .iter()
+            .zip(self.states.iter_mut())
+            .find(|(rule, _)| rule.trigger == trigger)?;
+
+        if !state.rate_limiter.allow(time) {
+            return None;
+        }
+
+        match state.last_action {
+            Some(last_action) if time < last_action + rule.escalation_timeout => (),
+            _ => state.level = 0,
+        }
+
+        let action = *rule.actions.get(state.level)?;
+
+        state.last_action = Some(time);
+        state.level = (state.level + 1).min(rule.actions.len() - 1);
+
+        Some(action)
+    }
+
+    /// Handles the trigger event.
+    ///
+    /// # Parameters
+    /// * `trigger` - ID of the trigger event.
+    /// * `api` - System runtime API.
+    fn handle(&mut self, trigger: EventId, api: &'static dyn RuntimeApi) {
+        let action = match self.select_action(trigger, api.get_system_time()) {
+            Some(action) => action,
+            None => return,
+        };
+
+        Aerugo::store_fault(FaultRecord::new(self.name, 0, trigger));
+
+        match action {
+            RecoveryAction::EmitEvent(event) => {
+                // Failure to emit the event means that it wasn't created by the application. This
+                // is a configuration error, but it shouldn't stop handling other faults.
+                let _ = api.emit_event(event);
+            }
+            RecoveryAction::SetCondition(condition, value) => {
+                if let Some(condition) = condition.create_handle() {
+                    condition.set_value(value);
+                }
+            }
+            RecoveryAction::Call(recovery_fn) => recovery_fn(api),
+            RecoveryAction::Reset => {
+                crate::logln!(
+                    "Aerugo: {} resets the system on event {}",
+                    self.name,
+                    trigger
+                );
+                Hal::reset_system();
+            }
+        }
+    }
+}
+
+/// Step function of the FDIR engine tasklet.
+///
+/// # Parameters
+/// * `trigger` - ID of the trigger event.
+/// * `engine` - FDIR engine context.
+/// * `api` - System runtime API.
+pub(crate) fn fdir_step<const RULE_COUNT: usize>(
+    trigger: EventId,
+    engine: &mut FdirEngine<RULE_COUNT>,
+    api: &'static dyn RuntimeApi,
+) {
+    engine.handle(trigger, api);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::rate_limit::RateLimit;
+    use crate::time::Duration;
+
+    const RESTART_EVENT: EventId = 10;
+    const POWER_CYCLE_EVENT: EventId = 11;
+
+    static ACTIONS: [RecoveryAction; 3] = [
+        RecoveryAction::EmitEvent(RESTART_EVENT),
+        RecoveryAction::EmitEvent(POWER_CYCLE_EVENT),
+        RecoveryAction::Reset,
+    ];
+
+    static RULES: [FdirRule; 1] = [FdirRule {
+        trigger: 1,
+        actions: &ACTIONS,
+        escalation_timeout: Duration::secs(10),
+        rate_limit: Some(RateLimit {
+            max_count: 3,
+            period: Duration::secs(1),
+        }),
+    }];
+
+    fn time(millis: u64) -> Instant {
+        Instant::from_ticks(0) + Duration::millis(millis)
+    }
+
+    fn emitted_event(action: Option<RecoveryAction>) -> Option<EventId> {
+        match action {
+            Some(RecoveryAction::EmitEvent(event)) => Some(event),
+            _ => None,
+        }
+    }
+
+    #[test]
+    fn escalate_actions() {
+        let mut engine = FdirEngine::new(&FdirConfig {
+            name: "FDIR",
+            priority: 0,
+            rules: &RULES,
+        });
+
+        assert!(engine.select_action(2, time(0)).is_none());
+
+        let action = engine.select_action(1, time(0));
+        assert_eq!(emitted_event(action), Some(RESTART_EVENT));
+        let action = engine.select_action(1, time(100));
+        assert_eq!(emitted_event(action), Some(POWER_CYCLE_EVENT));
+        let action = engine.select_action(1, time(200));
+        assert!(matches!(action, Some(RecoveryAction::Reset)));
+
+        // Rate limited.
+        assert!(engine.select_action(1, time(300)).is_none());
+
+        // Last action is repeated.
+        let action = engine.select_action(1, time(1500));
+        assert!(matches!(action, Some(RecoveryAction::Reset)));
+
+        // Escalation is reset after timeout.
+        let action = engine.select_action(1, time(20000));
+        assert_eq!(emitted_event(action), Some(RESTART_EVENT));
+    }
+}
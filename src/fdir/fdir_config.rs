@@ -0,0 +1,17 @@
+//! Configuration for creating FDIR engine.
+
+use super::FdirRule;
+
+/// Configuration for FDIR engine.
+///
+/// # Generic Parameters
+/// * `RULE_COUNT` - Number of rules.
+#[derive(Copy, Clone)]
+pub struct FdirConfig<const RULE_COUNT: usize> {
+    /// Name of the FDIR engine tasklet, also used in the fault records.
+    pub name: &'static str,
+    /// Priority of the FDIR engine tasklet.
+    pub priority: u8,
+    /// Rules of the engine. Each rule has to be triggered by a different event.
+    pub rules: &'static [FdirRule; RULE_COUNT],
+}
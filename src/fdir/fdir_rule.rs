@@ -0,0 +1,34 @@
+//! Rules of the FDIR engine.
+
+use crate::api::RuntimeApi;
+use crate::boolean_condition::BooleanConditionStorage;
+use crate::event::EventId;
+use crate::rate_limit::RateLimit;
+use crate::time::Duration;
+
+/// Action executed to recover from a fault.
+#[derive(Copy, Clone)]
+pub enum RecoveryAction {
+    /// Emits an event, for example to restart a group of tasklets subscribed to it.
+    EmitEvent(EventId),
+    /// Sets value of a condition, for example to switch the system mode by enabling or disabling
+    /// tasklets using that condition.
+    SetCondition(&'static BooleanConditionStorage, bool),
+    /// Calls application-defined recovery function, for example power-cycling a peripheral.
+    Call(fn(&'static dyn RuntimeApi)),
+    /// Resets the system.
+    Reset,
+}
+
+/// Rule mapping a fault event to recovery actions.
+#[derive(Copy, Clone)]
+pub struct FdirRule {
+    /// ID of the event triggering this rule.
+    pub trigger: EventId,
+    /// Recovery actions, ordered by escalation.
+    pub actions: &'static [RecoveryAction],
+    /// Time without triggers after which escalation is reset.
+    pub escalation_timeout: Duration,
+    /// Limit of executed actions, `None` if every trigger should execute an action.
+    pub rate_limit: Option<RateLimit>,
+}
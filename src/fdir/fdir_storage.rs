@@ -0,0 +1,41 @@
+//! Static storage for [FDIR engine](crate::fdir::FdirEngine).
+//!
+//! This module contains a FDIR engine storage, which is a statically allocated memory that will
+//! store the FDIR engine tasklet for the duration of the system life.
+
+use super::FdirEngine;
+
+use crate::event::EventId;
+use crate::tasklet::TaskletStorage;
+
+/// Structure containing memory for FDIR engine creation.
+///
+/// Storage shall be a static variable and shall be initialized only once, before the system is started.
+///
+/// # Generic Parameters
+/// * `RULE_COUNT` - Number of rules.
+pub struct FdirStorage<const RULE_COUNT: usize> {
+    /// Storage for the FDIR engine tasklet.
+    tasklet_storage: TaskletStorage<EventId, FdirEngine<RULE_COUNT>, 0>,
+}
+
+impl<const RULE_COUNT: usize> FdirStorage<RULE_COUNT> {
+    /// Creates new storage.
+    pub const fn new() -> Self {
+        FdirStorage {
+            tasklet_storage: TaskletStorage::new(),
+        }
+    }
+
+    /// Returns initialization status of this storage.
+    pub fn is_initialized(&'static self) -> bool {
+        self.tasklet_storage.is_initialized()
+    }
+
+    /// Returns storage for the FDIR engine tasklet.
+    pub(crate) fn tasklet_storage(
+        &'static self,
+    ) -> &'static TaskletStorage<EventId, FdirEngine<RULE_COUNT>, 0> {
+        &self.tasklet_storage
+    }
+}
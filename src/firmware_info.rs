@@ -0,0 +1,221 @@
+//! Firmware image metadata.
+//!
+//! [FirmwareInfo] is a structure placed in the firmware image, in the `.rodata.aerugo_firmware_info`
+//! section, describing the image - version of the application, build ID, and size and CRC of the
+//! image. It's declared with [firmware_info](crate::firmware_info) macro, which fills the version
+//! from the application crate, and registered in the system with
+//! [set_firmware_info](crate::api::InitApi::set_firmware_info).
+//!
+//! Build ID, image size and CRC are not known at compile time, so they are written into the binary
+//! image after the build by the `scripts/seal_firmware.py` helper. Helper finds the structure in the
+//! image by its [magic](FirmwareInfo::MAGIC) value, so the same procedure can be used by a
+//! bootloader to verify the image before starting it.
+//!
+//! Image CRC is CRC-32 (IEEE 802.3) of the whole image, starting at the vector table, excluding the
+//! CRC field itself.
+
+use aerugo_hal::AerugoHal;
+
+use crate::configuration_seal::Crc32;
+use crate::error::RuntimeError;
+use crate::hal::Hal;
+
+/// Firmware image metadata.
+///
+/// Layout of this structure is fixed, all fields are little-endian:
+///
+/// | Offset | Size | Field                                      |
+/// |--------|------|--------------------------------------------|
+/// | 0      | 8    | Magic, `AERUGOFW`                          |
+/// | 8      | 16   | Version, zero-padded string                |
+/// | 24     | 20   | Build ID, zero-padded string               |
+/// | 44     | 4    | Image size in bytes, `0` if not sealed     |
+/// | 48     | 4    | Image CRC                                  |
+#[repr(C)]
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct FirmwareInfo {
+    /// Magic value identifying the structure in the image.
+    magic: [u8; 8],
+    /// Version of the application.
+    version: [u8; FirmwareInfo::VERSION_SIZE],
+    /// Build ID.
+    build_id: [u8; FirmwareInfo::BUILD_ID_SIZE],
+    /// Size of the image in bytes.
+    image_size: u32,
+    /// CRC of the image.
+    crc: u32,
+}
+
+impl FirmwareInfo {
+    /// Magic value identifying the structure in the image.
+    pub const MAGIC: [u8; 8] = *b"AERUGOFW";
+    /// Maximum length of the version string.
+    pub const VERSION_SIZE: usize = 16;
+    /// Maximum length of the build ID string.
+    pub const BUILD_ID_SIZE: usize = 20;
+
+    /// Offset of the CRC field from the start of the structure.
+    const CRC_OFFSET: usize = 48;
+
+    /// Creates new, not sealed firmware metadata.
+    ///
+    /// Build ID, image size and CRC are written into the image by the seal helper.
+    ///
+    /// # Parameters
+    /// * `version` - Version of the application. Longer versions are truncated.
+    pub const fn new(version: &str) -> Self {
+        let mut info = FirmwareInfo {
+            magic: FirmwareInfo::MAGIC,
+            version: [0; FirmwareInfo::VERSION_SIZE],
+            build_id: [0; FirmwareInfo::BUILD_ID_SIZE],
+            image_size: 0,
+            crc: 0,
+        };
+
+        let version = version.as_bytes();
+        let mut index = 0;
+        while index < version.len() && index < FirmwareInfo::VERSION_SIZE {
+            info.version[index] = version[index];
+            index += 1;
+        }
+
+        info
+    }
+
+    /// Returns version of the application.
+    pub fn version(&self) -> &str {
+        Self::as_str(&self.version)
+    }
+
+    /// Returns build ID, or empty string if the image is not sealed.
+    pub fn build_id(&self) -> &str {
+        Self::as_str(&self.build_id)
+    }
+
+    /// Returns size of the image in bytes, or `0` if the image is not sealed.
+    pub fn image_size(&self) -> u32 {
+        self.image_size
+    }
+
+    /// Returns CRC of the image.
+    pub fn crc(&self) -> u32 {
+        self.crc
+    }
+
+    /// Reads metadata from the image and verifies the image.
+    ///
+    /// Image is verified only on platforms where it's available in memory.
+    ///
+    /// # Parameters
+    /// * `info` - Metadata placed in the image.
+    ///
+    /// # Return
+    /// Copy of the metadata if the image is valid, `RuntimeError` otherwise.
+    pub(crate) fn verify(info: &'static FirmwareInfo) -> Result<FirmwareInfo, RuntimeError> {
+        // SAFETY: This is safe, because the reference is valid. Volatile read is used, because
+        // the metadata is modified in the image after the build, and the compiler would
+        // otherwise assume it's equal to its initial value.
+        let copy = unsafe { core::ptr::read_volatile(info) };
+
+        if copy.magic != FirmwareInfo::MAGIC {
+            return Err(RuntimeError::FirmwareInfoInvalid);
+        }
+
+        let Some(image_start) = Hal::firmware_image_start() else {
+            return Ok(copy);
+        };
+
+        if copy.image_size == 0 {
+            return Err(RuntimeError::FirmwareImageNotSealed);
+        }
+
+        let image_size = copy.image_size as usize;
+        let crc_offset = (info as *const FirmwareInfo as usize + FirmwareInfo::CRC_OFFSET)
+            .checked_sub(image_start as usize)
+            .filter(|offset| offset + 4 <= image_size)
+            .ok_or(RuntimeError::FirmwareImageCorrupted)?;
+
+        // SAFETY: This is safe, because the image is placed in memory and is never modified at
+        // runtime. Image size was checked to contain the metadata, so it's likely valid.
+        let image = unsafe { core::slice::from_raw_parts(image_start, image_size) };
+        let crc = Crc32::new()
+            .update(&image[..crc_offset])
+            .update(&image[crc_offset + 4..])
+            .finalize();
+
+        if crc == copy.crc {
+            Ok(copy)
+        } else {
+            Err(RuntimeError::FirmwareImageCorrupted)
+        }
+    }
+
+    /// Converts zero-padded string to `&str`.
+    fn as_str(bytes: &[u8]) -> &str {
+        let length = bytes.iter().position(|&b| b == 0).unwrap_or(bytes.len());
+        core::str::from_utf8(&bytes[..length]).unwrap_or("")
+    }
+}
+
+/// Declares firmware image metadata.
+///
+/// Metadata is placed in the `.rodata.aerugo_firmware_info` section, with version set to the
+/// version of the crate in which the macro is used. It has to be registered in the system with
+/// [set_firmware_info](crate::api::InitApi::set_firmware_info), which also keeps it from being
+/// removed by the linker.
+///
+/// # Parameters
+/// * `name` - Name of the declared static.
+///
+/// # Example
+/// ```
+/// # use aerugo::{firmware_info, Aerugo, InitApi, SystemHardwareConfig};
+/// firmware_info!(FIRMWARE_INFO);
+///
+/// fn main() {
+///     # let (aerugo, _) = Aerugo::initialize(SystemHardwareConfig::default());
+///     aerugo.set_firmware_info(&FIRMWARE_INFO);
+/// }
+/// ```
+#[macro_export]
+macro_rules! firmware_info {
+    ($name:ident) => {
+        #[link_section = ".rodata.aerugo_firmware_info"]
+        static $name: $crate::FirmwareInfo =
+            $crate::FirmwareInfo::new(::core::env!("CARGO_PKG_VERSION"));
+    };
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn layout() {
+        let info = FirmwareInfo::new("1.2.3-a-very-long-version");
+
+        assert_eq!(core::mem::size_of::<FirmwareInfo>(), 52);
+        assert_eq!(
+            &info.crc as *const u32 as usize - &info as *const FirmwareInfo as usize,
+            FirmwareInfo::CRC_OFFSET
+        );
+        assert_eq!(info.version(), "1.2.3-a-very-lon");
+        assert_eq!(info.build_id(), "");
+        assert_eq!(info.image_size(), 0);
+    }
+
+    #[test]
+    fn verify_magic() {
+        static VALID: FirmwareInfo = FirmwareInfo::new("1.0.0");
+        static INVALID: FirmwareInfo = FirmwareInfo {
+            magic: *b"AERUGOXX",
+            ..FirmwareInfo::new("1.0.0")
+        };
+
+        assert_eq!(FirmwareInfo::verify(&VALID), Ok(VALID));
+        assert_eq!(
+            FirmwareInfo::verify(&INVALID),
+            Err(RuntimeError::FirmwareInfoInvalid)
+        );
+    }
+}
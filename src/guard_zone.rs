@@ -0,0 +1,89 @@
+//! Guard zones for detecting memory overruns.
+//!
+//! Guard zone is a small, canary-filled gap placed around statically allocated memory that is
+//! written by the user code (ex. tasklet context). Buffer overrun from that memory will overwrite
+//! the canary pattern, which is then detected on verification, instead of silently corrupting
+//! the data stored next to it in RAM.
+//!
+//! Detection is done in software only, when the guard zones are verified, so an overrun is found
+//! after it happened, not when it's written.
+
+/// Number of words in a guard zone.
+const GUARD_ZONE_WORDS: usize = 4;
+
+/// Guard zone filled with canary pattern.
+#[repr(C, align(4))]
+pub(crate) struct GuardZone {
+    /// Canary words.
+    canary: [u32; GUARD_ZONE_WORDS],
+}
+
+impl GuardZone {
+    /// Pattern that guard zone is filled with.
+    pub(crate) const PATTERN: u32 = 0xDEAD_BEEF;
+
+    /// Creates new guard zone filled with canary pattern.
+    pub(crate) const fn new() -> Self {
+        GuardZone {
+            canary: [Self::PATTERN; GUARD_ZONE_WORDS],
+        }
+    }
+
+    /// Checks if canary pattern in this guard zone is intact.
+    pub(crate) fn is_intact(&self) -> bool {
+        self.canary.iter().all(|word| {
+            // SAFETY: This is safe, because the reference is valid and aligned. Volatile read is
+            // used to prevent the compiler from assuming that the canary was never modified.
+            unsafe { core::ptr::read_volatile(word) == Self::PATTERN }
+        })
+    }
+}
+
+/// Pair of guard zones placed around a memory area.
+#[derive(Copy, Clone)]
+pub(crate) struct ContextGuard {
+    /// Guard zone placed before the memory area.
+    head: &'static GuardZone,
+    /// Guard zone placed after the memory area.
+    tail: &'static GuardZone,
+}
+
+impl ContextGuard {
+    /// Creates new context guard.
+    ///
+    /// # Parameters
+    /// * `head` - Guard zone placed before guarded memory.
+    /// * `tail` - Guard zone placed after guarded memory.
+    pub(crate) const fn new(head: &'static GuardZone, tail: &'static GuardZone) -> Self {
+        ContextGuard { head, tail }
+    }
+
+    /// Checks if both guard zones are intact.
+    pub(crate) fn is_intact(&self) -> bool {
+        self.head.is_intact() && self.tail.is_intact()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn new_guard_is_intact() {
+        static HEAD: GuardZone = GuardZone::new();
+        static TAIL: GuardZone = GuardZone::new();
+
+        let guard = ContextGuard::new(&HEAD, &TAIL);
+
+        assert!(guard.is_intact());
+    }
+
+    #[test]
+    fn detect_overwritten_canary() {
+        let mut zone = GuardZone::new();
+        assert!(zone.is_intact());
+
+        zone.canary[GUARD_ZONE_WORDS - 1] = 0;
+        assert!(!zone.is_intact());
+    }
+}
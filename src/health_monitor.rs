@@ -0,0 +1,170 @@
+//! Health monitor gating hardware watchdog with tasklet check-ins.
+//!
+//! Software watchdogs consider tasklet alive when it's executed, which doesn't detect a tasklet
+//! that keeps being executed but doesn't make progress (for example waits for a peripheral that
+//! stopped responding). Monitored tasklets have to explicitly
+//! [check in](crate::api::RuntimeApi::checkin) at least once per configured period, when they
+//! confirm that their work is done. When any of them misses its period, a
+//! [FaultRecord](crate::FaultRecord) with the tasklet name and configured fault code is stored in
+//! the fault recorder, and the system stops feeding hardware and external watchdogs, so the system
+//! is reset by them.
+
+use heapless::Vec;
+
+use crate::aerugo::Aerugo;
+use crate::error::SystemError;
+use crate::fault::FaultRecord;
+use crate::mutex::Mutex;
+use crate::tasklet::TaskletId;
+use crate::time::Duration;
+
+/// List of monitored tasklets.
+type MonitoredList = Vec<MonitoredTasklet, { Aerugo::TASKLET_COUNT }>;
+
+/// Tasklet monitored by the health monitor.
+struct MonitoredTasklet {
+    /// ID of the tasklet.
+    id: TaskletId,
+    /// Name of the tasklet.
+    name: &'static str,
+    /// Maximum time between check-ins.
+    period: Duration,
+    /// Code of the fault stored when tasklet misses its check-in.
+    fault_code: u32,
+    /// Time of the last check-in.
+    last_checkin: Duration,
+    /// Whether tasklet missed its check-in.
+    expired: bool,
+}
+
+/// Health monitor.
+///
+/// This shouldn't be created by hand by the user or anywhere else in the code.
+/// It should be used as a singleton (crate::aerugo::HEALTH_MONITOR) and shouldn't be directly
+/// accessed by any other part of the system.
+pub(crate) struct HealthMonitor {
+    /// Monitored tasklets.
+    tasklets: Mutex<MonitoredList>,
+}
+
+impl HealthMonitor {
+    /// Creates new health monitor instance.
+    ///
+    /// # Safety
+    /// This shouldn't be called more than once.
+    pub(crate) const fn new() -> Self {
+        HealthMonitor {
+            tasklets: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// Adds tasklet to the monitored tasklets.
+    ///
+    /// Tasklet gets a full period for the first check-in after the scheduler is started.
+    ///
+    /// # Parameters
+    /// * `id` - ID of the tasklet.
+    /// * `name` - Name of the tasklet.
+    /// * `period` - Maximum time between check-ins.
+    /// * `fault_code` - Code of the fault stored when tasklet misses its check-in.
+    ///
+    /// # Return
+    /// `()` if successful, `SystemError` otherwise.
+    pub(crate) fn monitor(
+        &'static self,
+        id: TaskletId,
+        name: &'static str,
+        period: Duration,
+        fault_code: u32,
+    ) -> Result<(), SystemError> {
+        self.tasklets.lock(|tasklets| {
+            if tasklets.iter().any(|tasklet| tasklet.id == id) {
+                return Err(SystemError::TaskletAlreadyMonitored(name));
+            }
+
+            tasklets
+                .push(MonitoredTasklet {
+                    id,
+                    name,
+                    period,
+                    fault_code,
+                    last_checkin: Duration::from_ticks(0),
+                    expired: false,
+                })
+                .map_err(|_| SystemError::TaskletListFull)
+        })
+    }
+
+    /// Marks tasklet as healthy. Does nothing if tasklet is not monitored.
+    ///
+    /// # Parameters
+    /// * `id` - ID of the tasklet.
+    /// * `time` - Time elapsed since system start.
+    pub(crate) fn checkin(&'static self, id: TaskletId, time: Duration) {
+        self.tasklets.lock(|tasklets| {
+            if let Some(tasklet) = tasklets.iter_mut().find(|tasklet| tasklet.id == id) {
+                tasklet.last_checkin = time;
+            }
+        })
+    }
+
+    /// Checks whether all monitored tasklets checked in within their periods.
+    ///
+    /// Fault is stored once for each tasklet that missed its check-in, and the expiration is
+    /// latched, as the system is expected to be reset by the hardware watchdog.
+    ///
+    /// # Parameters
+    /// * `time` - Time elapsed since system start.
+    ///
+    /// # Return
+    /// `true` if all monitored tasklets are healthy, `false` otherwise.
+    pub(crate) fn check(&'static self, time: Duration) -> bool {
+        let mut healthy = true;
+
+        self.tasklets.lock(|tasklets| {
+            for tasklet in tasklets.iter_mut() {
+                if !tasklet.expired && time > tasklet.last_checkin + tasklet.period {
+                    tasklet.expired = true;
+                    Aerugo::store_fault(FaultRecord::new(tasklet.name, 0, tasklet.fault_code));
+                }
+
+                healthy &= !tasklet.expired;
+            }
+        });
+
+        healthy
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn expires_without_checkin() {
+        static MONITOR: HealthMonitor = HealthMonitor::new();
+
+        let tasklet_a = TaskletId(2000);
+        let tasklet_b = TaskletId(2001);
+
+        MONITOR
+            .monitor(tasklet_a, "TaskA", Duration::millis(10), 1)
+            .unwrap();
+        MONITOR
+            .monitor(tasklet_b, "TaskB", Duration::millis(20), 2)
+            .unwrap();
+        assert!(MONITOR
+            .monitor(tasklet_a, "TaskA", Duration::millis(10), 1)
+            .is_err());
+
+        assert!(MONITOR.check(Duration::millis(10)));
+        MONITOR.checkin(tasklet_a, Duration::millis(8));
+        assert!(MONITOR.check(Duration::millis(18)));
+
+        MONITOR.checkin(tasklet_b, Duration::millis(19));
+        assert!(!MONITOR.check(Duration::millis(19)));
+
+        MONITOR.checkin(tasklet_a, Duration::millis(20));
+        assert!(!MONITOR.check(Duration::millis(21)));
+    }
+}
@@ -0,0 +1,94 @@
+//! System heartbeat.
+//!
+//! Heartbeat output is toggled by the system with a fixed period, but only as long as the scheduler
+//! is healthy. Toggling is done in the scheduler loop, so it stops when any tasklet hangs, and it's
+//! skipped when there is a latched fault. This allows external supervisor to monitor the system
+//! without any application code.
+
+use crate::mutex::Mutex;
+use crate::time::Duration;
+
+/// System heartbeat.
+pub(crate) struct Heartbeat {
+    /// Time between consecutive toggles, `None` if heartbeat is disabled.
+    period: Mutex<Option<Duration>>,
+    /// Time of the last toggle.
+    last_toggle: Mutex<Duration>,
+}
+
+impl Heartbeat {
+    /// Creates new, disabled heartbeat.
+    pub(crate) const fn new() -> Self {
+        Heartbeat {
+            period: Mutex::new(None),
+            last_toggle: Mutex::new(Duration::from_ticks(0)),
+        }
+    }
+
+    /// Sets heartbeat period.
+    ///
+    /// # Parameters
+    /// * `period` - Time between consecutive toggles, `None` to disable heartbeat.
+    pub(crate) fn set_period(&'static self, period: Option<Duration>) {
+        self.period.lock(|current| *current = period);
+    }
+
+    /// Returns time until the next toggle of the heartbeat output.
+    ///
+    /// # Parameters
+    /// * `time` - Current time.
+    ///
+    /// # Return
+    /// Time until the next toggle, zero if it's due, `None` if heartbeat is disabled.
+    pub(crate) fn time_to_toggle(&'static self, time: Duration) -> Option<Duration> {
+        let period = self.period.lock(|period| *period)?;
+
+        let next_toggle = self.last_toggle.lock(|last_toggle| *last_toggle + period);
+        Some(
+            next_toggle
+                .checked_sub(time)
+                .unwrap_or(Duration::from_ticks(0)),
+        )
+    }
+
+    /// Checks if heartbeat output should be toggled.
+    ///
+    /// # Parameters
+    /// * `time` - Current time.
+    /// * `healthy` - Whether the system is healthy.
+    ///
+    /// # Return
+    /// `true` if output should be toggled now, `false` otherwise.
+    pub(crate) fn update(&'static self, time: Duration, healthy: bool) -> bool {
+        let period = match self.period.lock(|period| *period) {
+            Some(period) if healthy => period,
+            _ => return false,
+        };
+
+        self.last_toggle.lock(|last_toggle| {
+            if time >= *last_toggle + period {
+                *last_toggle = time;
+                true
+            } else {
+                false
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn toggle_with_period() {
+        static HEARTBEAT: Heartbeat = Heartbeat::new();
+        assert!(!HEARTBEAT.update(Duration::millis(100), true));
+
+        HEARTBEAT.set_period(Some(Duration::millis(100)));
+        assert!(HEARTBEAT.update(Duration::millis(100), true));
+        assert!(!HEARTBEAT.update(Duration::millis(150), true));
+        assert!(!HEARTBEAT.update(Duration::millis(200), false));
+        assert!(HEARTBEAT.update(Duration::millis(210), true));
+    }
+}
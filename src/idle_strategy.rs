@@ -0,0 +1,63 @@
+//! Behavior of the system when there is nothing to execute.
+//!
+//! By default, executor spins in the scheduler loop when its queue is empty. With
+//! [`IdleStrategy::Sleep`], it computes time of the nearest system activity (cyclic execution,
+//! scheduled event, timer or heartbeat toggle) and puts the CPU to sleep until then. Any interrupt
+//! wakes the CPU earlier, so tasklets woken from interrupts are executed without delay.
+
+use crate::time::Duration;
+
+/// Behavior of the system when there is nothing to execute.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq)]
+pub enum IdleStrategy {
+    /// Executor spins in the scheduler loop.
+    #[default]
+    BusyWait,
+    /// CPU sleeps until the nearest system activity.
+    Sleep {
+        /// Maximum time of a single sleep. Watchdogs are fed only between sleeps, so this has to
+        /// be shorter than the watchdog timeouts.
+        max_sleep: Duration,
+    },
+}
+
+/// Returns time until the nearest of given system activities.
+///
+/// # Parameters
+/// * `max_sleep` - Maximum time of a sleep.
+/// * `activities` - Time until each activity, `None` if it's not expected.
+///
+/// # Return
+/// Time until the nearest activity, but not longer than `max_sleep`.
+pub(crate) fn sleep_time(
+    max_sleep: Duration,
+    activities: impl IntoIterator<Item = Option<Duration>>,
+) -> Duration {
+    activities
+        .into_iter()
+        .flatten()
+        .fold(max_sleep, |sleep_time, time| sleep_time.min(time))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn nearest_activity() {
+        let max_sleep = Duration::millis(10);
+
+        assert_eq!(sleep_time(max_sleep, [None, None]), max_sleep);
+        assert_eq!(
+            sleep_time(
+                max_sleep,
+                [Some(Duration::millis(3)), None, Some(Duration::millis(2))]
+            ),
+            Duration::millis(2)
+        );
+        assert_eq!(
+            sleep_time(max_sleep, [Some(Duration::millis(30))]),
+            max_sleep
+        );
+    }
+}
@@ -0,0 +1,139 @@
+//! Mutex with priority inheritance.
+//!
+//! Tasklets are executed to completion, so [Mutex](crate::Mutex) is never contended between them.
+//! Resource often has to be held across multiple executions though, ex. when a transaction
+//! consists of multiple steps. If such resource is owned by a low priority tasklet, a high priority
+//! tasklet waiting for it can be delayed indefinitely by tasklets of middle priority, that keep
+//! being executed instead of the owner (unbounded priority inversion).
+//!
+//! [InheritanceMutex] records the tasklet owning it. When a tasklet fails to acquire it, the owner
+//! inherits priority of the waiting tasklet until the mutex is released, so the executor runs the
+//! owner before the tasklets of lower priority than the waiting one.
+
+use core::cell::UnsafeCell;
+
+use crate::aerugo::Aerugo;
+use crate::mutex::Mutex;
+use crate::tasklet::{TaskletId, TaskletPtr};
+
+/// Mutex owned by a tasklet, with priority inheritance.
+///
+/// Mutex can be used only from the tasklets, it cannot be accessed from the IRQ context. Tasklet
+/// that fails to [acquire](InheritanceMutex::try_acquire) the mutex should retry in its next
+/// execution, ex. keeping received data in its context until then.
+///
+/// Inherited priority is kept by the owner until it releases the mutex. Tasklet owning multiple
+/// mutexes loses inherited priority when it releases any of them - it's inherited again when the
+/// waiting tasklets retry.
+///
+/// # Generic Parameters
+/// * `T` - Type of the stored value.
+pub struct InheritanceMutex<T> {
+    /// Stored value.
+    value: UnsafeCell<T>,
+    /// Tasklet owning the mutex.
+    owner: Mutex<Option<TaskletPtr>>,
+}
+
+/// Mutex is `Sync` because `aerugo` is a single-threaded system, mutex cannot be used from the IRQ
+/// context, and the value can be accessed only by the owning tasklet.
+unsafe impl<T: Send> Sync for InheritanceMutex<T> {}
+
+impl<T> InheritanceMutex<T> {
+    /// Creates new mutex with given value.
+    ///
+    /// # Parameters
+    /// * `value` - Value to initialize the mutex with.
+    pub const fn new(value: T) -> Self {
+        InheritanceMutex {
+            value: UnsafeCell::new(value),
+            owner: Mutex::new(None),
+        }
+    }
+
+    /// Tries to acquire the mutex for the currently executed tasklet.
+    ///
+    /// If the mutex is owned by other tasklet with lower priority, that tasklet inherits priority
+    /// of the current one.
+    ///
+    /// # Return
+    /// `true` if the mutex is now owned by the current tasklet, `false` if it's owned by other
+    /// tasklet or this is not called from a tasklet.
+    pub fn try_acquire(&self) -> bool {
+        let Some(current) = Aerugo::current_tasklet() else {
+            return false;
+        };
+
+        let owner = self.owner.lock(|owner| match owner {
+            Some(owner) => Some(owner.clone()),
+            None => {
+                *owner = Some(current.clone());
+                None
+            }
+        });
+
+        match owner {
+            None => true,
+            Some(owner) if owner == current => true,
+            Some(owner) => {
+                if owner.get_priority() < current.get_priority() {
+                    Aerugo::set_inherited_priority(&owner, Some(current.get_priority()));
+                }
+                false
+            }
+        }
+    }
+
+    /// Releases the mutex owned by the currently executed tasklet.
+    ///
+    /// Owner loses inherited priority.
+    ///
+    /// # Return
+    /// `true` if the mutex was released, `false` if it's not owned by the current tasklet.
+    pub fn release(&self) -> bool {
+        let Some(current) = Aerugo::current_tasklet() else {
+            return false;
+        };
+
+        let released = self.owner.lock(|owner| {
+            if owner.as_ref() == Some(&current) {
+                *owner = None;
+                true
+            } else {
+                false
+            }
+        });
+
+        if released {
+            Aerugo::set_inherited_priority(&current, None);
+        }
+
+        released
+    }
+
+    /// Gives access to the value to the owning tasklet.
+    ///
+    /// # Parameters
+    /// * `f` - Lambda to execute.
+    ///
+    /// # Return
+    /// Result of the executed lambda, or `None` if the mutex is not owned by the currently
+    /// executed tasklet.
+    pub fn lock<R>(&self, f: impl FnOnce(&mut T) -> R) -> Option<R> {
+        let current = Aerugo::current_tasklet()?;
+
+        if self.owner.lock(|owner| owner.as_ref() == Some(&current)) {
+            // SAFETY: Value is accessed only by the owner, and only one tasklet is executed at the
+            // given time.
+            Some(f(unsafe { &mut *self.value.get() }))
+        } else {
+            None
+        }
+    }
+
+    /// Returns ID of the tasklet owning the mutex, or `None` if it's not owned.
+    pub fn owner(&self) -> Option<TaskletId> {
+        self.owner
+            .lock(|owner| owner.as_ref().map(|owner| owner.get_id()))
+    }
+}
@@ -12,37 +12,137 @@
 #![feature(const_mut_refs)]
 
 mod aerugo;
+mod aggregator;
 mod api;
+mod barrier;
 mod boolean_condition;
+mod bus_manager;
+mod cancellation_token;
+mod configuration_seal;
 mod cyclic_execution;
 mod cyclic_execution_manager;
 mod data_provider;
+mod equal_priority_policy;
 mod error;
 mod event;
 mod event_manager;
 mod execution_monitor;
 mod executor;
+mod fault;
+mod fault_manager;
+mod fdir;
+mod firmware_info;
+mod guard_zone;
+mod health_monitor;
+mod heartbeat;
+mod idle_strategy;
+mod inheritance_mutex;
 mod internal_list;
+mod limit_monitor;
+#[cfg(feature = "log-facade")]
+mod log_facade;
+#[cfg(feature = "log-prefix")]
+mod log_prefix;
+mod manifest;
+mod memory_pool;
 mod message_queue;
+mod missed_activation_policy;
 mod mutex;
+mod partition;
+mod partition_manager;
+mod partition_port;
+mod phase_lock;
+mod queue_bridge;
+mod queue_growth_monitor;
+mod rate_limit;
+mod schedule_table;
+mod schedule_table_manager;
+mod scheduler_watchdog;
+mod scheduling_policy;
+mod shutdown_reason;
+mod software_watchdog;
+mod software_watchdog_manager;
+mod stack_monitor;
+mod startup;
+mod startup_manager;
+#[cfg(feature = "stimulus")]
+mod stimulus;
 mod stubs;
+mod system_definition;
+mod system_state;
 mod tasklet;
+mod tasklet_containment;
+mod telemetry;
+mod tie_breaker;
 mod time_source;
+mod timer;
+mod timer_manager;
 mod utils;
 
 #[cfg(any(doc, test))]
 mod tests;
 
 pub use self::aerugo::Aerugo;
+pub use self::aggregator::{Aggregate, AggregatorConfig, AggregatorStorage};
 pub use self::api::{InitApi, RuntimeApi};
+pub use self::barrier::{BarrierHandle, BarrierStorage};
 pub use self::boolean_condition::{
     BooleanConditionHandle, BooleanConditionSet, BooleanConditionSetType, BooleanConditionStorage,
 };
-pub use self::event::{EventHandle, EventId, EventStorage};
-pub use self::execution_monitor::ExecutionStats;
-pub use self::message_queue::{MessageQueueHandle, MessageQueueStorage};
+pub use self::bus_manager::{
+    BusDeadline, BusDriver, BusError, BusManagerConfig, BusManagerStorage, BusResponse,
+    BusTransaction, ReplyQueue,
+};
+pub use self::cancellation_token::CancellationToken;
+pub use self::data_provider::{DataProvider, DataReceiver};
+pub use self::equal_priority_policy::EqualPriorityPolicy;
+pub use self::error::{AerugoError, RuntimeError};
+pub use self::event::{
+    DataEventHandle, DataEventStorage, EventGroup, EventGroupMode, EventHandle, EventId,
+    EventStorage,
+};
+pub use self::execution_monitor::{DeadlineMissHandler, ExecutionStats};
+pub use self::executor::{ErrorHook, IdleHook};
+pub use self::fault::{FaultPolicy, FaultRecord};
+pub use self::fdir::{FdirConfig, FdirRule, FdirStorage, RecoveryAction};
+pub use self::firmware_info::FirmwareInfo;
+pub use self::idle_strategy::IdleStrategy;
+pub use self::inheritance_mutex::InheritanceMutex;
+pub use self::limit_monitor::{LimitLevel, LimitMonitorConfig, LimitMonitorStorage, Severity};
+#[cfg(feature = "log-facade")]
+pub use self::log_facade::AerugoLogger;
+#[cfg(feature = "log-prefix")]
+#[doc(hidden)]
+pub use self::log_prefix::log_prefixed;
+pub use self::manifest::{DataSourceKind, ManifestWriter};
+pub use self::memory_pool::{MemoryBlock, MemoryPoolStorage};
+pub use self::message_queue::{
+    IsrSendError, MessageQueueHandle, MessageQueueStorage, OverflowPolicy,
+};
+pub use self::missed_activation_policy::MissedActivationPolicy;
 pub use self::mutex::Mutex;
-pub use self::tasklet::{TaskletConfig, TaskletId, TaskletStorage};
+pub use self::partition::{PartitionId, PartitionWindow};
+pub use self::partition_port::{QueuingPort, SampledValue, SamplingPort};
+pub use self::queue_bridge::{QueueBridgeConfig, QueueBridgeStorage};
+pub use self::rate_limit::RateLimit;
+pub use self::schedule_table::{ScheduleSlot, ScheduleTableConfig};
+pub use self::scheduler_watchdog::{StallAction, StallHandler};
+pub use self::shutdown_reason::ShutdownReason;
+pub use self::software_watchdog::{SoftwareWatchdogConfig, SoftwareWatchdogStorage, WatchdogEntry};
+pub use self::startup::{
+    ComponentStatus, OptionalComponent, OptionalInitFn, StartupConfig, StartupFn, StartupInfo,
+    StartupPath, StartupReport, StartupStep,
+};
+#[cfg(feature = "stimulus")]
+pub use self::stimulus::{
+    StimulusError, StimulusErrorKind, StimulusScript, StimulusStatus, StimulusTarget,
+};
+pub use self::tasklet::{
+    IdentifiableTasklet, SpawnableTasklet, StepResult, SuspendableTasklet, TaskletChain,
+    TaskletConfig, TaskletDataSource, TaskletFinalizer, TaskletId, TaskletSpawner, TaskletStorage,
+};
+pub use self::telemetry::{Telemetry, TelemetrySample, TelemetryStorage, TelemetryValue};
+pub use self::timer::{TimerAction, TimerHandle, TimerStorage};
 
 /// Module for re-exporting time structures.
 pub mod time {
@@ -51,19 +151,29 @@ pub mod time {
 }
 pub use time::*;
 
-pub use aerugo_hal::SystemHardwareConfig;
+pub use aerugo_hal::{
+    ExternalWatchdog, FpuContextPolicy, HeartbeatConfig, ResetCause, SystemHardwareConfig,
+};
+pub use event_derive::AerugoEvents;
+pub use telemetry_derive::AerugoTelemetry;
 
 #[cfg(feature = "use-aerugo-cortex-m")]
-#[cfg(feature = "log")]
+#[cfg(any(feature = "log", feature = "instrumented", feature = "fault-handler"))]
 pub(crate) use aerugo_cortex_m as arch;
 #[cfg(feature = "use-aerugo-cortex-m")]
+pub use aerugo_cortex_m::{IrqCell, IrqCellRef, StaticDmaCell};
+#[cfg(feature = "use-aerugo-cortex-m")]
 pub use aerugo_samv71_hal as hal;
+#[cfg(all(feature = "use-aerugo-cortex-m", feature = "fault-handler"))]
+pub use arch::fault_handler;
 
 #[cfg(feature = "use-aerugo-x86")]
-#[cfg(feature = "log")]
+#[cfg(any(feature = "log", feature = "instrumented", feature = "fault-handler"))]
 pub(crate) use aerugo_x86 as arch;
 #[cfg(feature = "use-aerugo-x86")]
 pub use aerugo_x86_hal as hal;
 
 #[cfg(feature = "log")]
-pub use arch::{log, logln};
+pub use arch::log;
+#[cfg(all(feature = "log", not(feature = "log-prefix")))]
+pub use arch::logln;
@@ -0,0 +1,190 @@
+//! Monitor checking values against warning and critical limits.
+//!
+//! Limit monitor is a system tasklet that takes values from the source queue and checks them
+//! against two levels of limits, warning and critical. Each time the [severity](Severity) of the
+//! monitored value changes, event configured for the new severity is emitted. Each violation of
+//! the limits is also stored in the fault recorder as a [FaultRecord](crate::FaultRecord) with
+//! the monitor name and fault code configured for the violated level. Limit violations are not
+//! latched, as they are expected to be handled by the application via emitted events.
+//!
+//! Severity is raised as soon as the value crosses the limit, but it's lowered only after the
+//! value gets back within the limits by the configured hysteresis margin. This prevents a noisy
+//! value oscillating around the limit from flooding the system with events.
+
+mod limit_monitor_config;
+mod limit_monitor_storage;
+mod severity;
+
+pub use self::limit_monitor_config::{LimitLevel, LimitMonitorConfig};
+pub use self::limit_monitor_storage::LimitMonitorStorage;
+pub use self::severity::Severity;
+
+use core::ops::{Add, Sub};
+
+use crate::aerugo::Aerugo;
+use crate::api::RuntimeApi;
+use crate::event::EventId;
+use crate::fault::FaultRecord;
+use crate::mutex::Mutex;
+
+/// Monitor checking values against warning and critical limits.
+///
+/// This is a context of the limit monitor tasklet.
+///
+/// # Generic Parameters
+/// * `T` - Type of the monitored value.
+pub(crate) struct LimitMonitor<T: 'static> {
+    /// Monitor configuration.
+    config: LimitMonitorConfig<T>,
+    /// Current severity of the monitored value.
+    severity: &'static Mutex<Severity>,
+}
+
+impl<T> LimitMonitor<T>
+where
+    T: Copy + PartialOrd + Add<Output = T> + Sub<Output = T>,
+{
+    /// Creates new limit monitor.
+    ///
+    /// # Parameters
+    /// * `config` - Monitor configuration.
+    /// * `severity` - Current severity of the monitored value.
+    pub(crate) fn new(config: LimitMonitorConfig<T>, severity: &'static Mutex<Severity>) -> Self {
+        LimitMonitor { config, severity }
+    }
+
+    /// Evaluates severity of the value.
+    ///
+    /// # Parameters
+    /// * `value` - Monitored value.
+    /// * `current` - Current severity.
+    ///
+    /// # Return
+    /// New severity of the value.
+    fn evaluate(&self, value: T, current: Severity) -> Severity {
+        let severity = self.classify(value, None);
+
+        if severity >= current {
+            severity
+        } else {
+            self.classify(value, Some(self.config.hysteresis))
+                .min(current)
+        }
+    }
+
+    /// Classifies the value against the limits.
+    ///
+    /// # Parameters
+    /// * `value` - Monitored value.
+    /// * `margin` - Margin by which the limits are narrowed.
+    ///
+    /// # Return
+    /// Severity of the value.
+    fn classify(&self, value: T, margin: Option<T>) -> Severity {
+        if !self.config.critical.contains(value, margin) {
+            Severity::Critical
+        } else if !self.config.warning.contains(value, margin) {
+            Severity::Warning
+        } else {
+            Severity::Nominal
+        }
+    }
+
+    /// Returns event that should be emitted when the value gets given severity.
+    ///
+    /// # Parameters
+    /// * `severity` - New severity.
+    fn event(&self, severity: Severity) -> Option<EventId> {
+        match severity {
+            Severity::Nominal => self.config.nominal_event,
+            Severity::Warning => self.config.warning.event,
+            Severity::Critical => self.config.critical.event,
+        }
+    }
+
+    /// Returns fault code of the limit violation with given severity.
+    ///
+    /// # Parameters
+    /// * `severity` - Severity of the violation.
+    fn fault_code(&self, severity: Severity) -> Option<u32> {
+        match severity {
+            Severity::Nominal => None,
+            Severity::Warning => Some(self.config.warning.fault_code),
+            Severity::Critical => Some(self.config.critical.fault_code),
+        }
+    }
+
+    /// Checks the value, reporting the change of its severity.
+    ///
+    /// # Parameters
+    /// * `value` - Monitored value.
+    /// * `api` - System runtime API.
+    fn check(&self, value: T, api: &'static dyn RuntimeApi) {
+        let current = self.severity.lock(|severity| *severity);
+        let severity = self.evaluate(value, current);
+
+        if severity == current {
+            return;
+        }
+
+        self.severity.lock(|current| *current = severity);
+
+        if let Some(fault_code) = self.fault_code(severity) {
+            Aerugo::store_fault(FaultRecord::new(self.config.name, 0, fault_code));
+        }
+
+        if let Some(event) = self.event(severity) {
+            // Failure to emit the event means that it wasn't created by the application. This is a
+            // configuration error, but it shouldn't stop the monitoring.
+            let _ = api.emit_event(event);
+        }
+    }
+}
+
+/// Step function of the limit monitor tasklet.
+///
+/// # Parameters
+/// * `value` - Value from the source queue.
+/// * `monitor` - Limit monitor context.
+/// * `api` - System runtime API.
+pub(crate) fn limit_monitor_step<T>(
+    value: T,
+    monitor: &mut LimitMonitor<T>,
+    api: &'static dyn RuntimeApi,
+) where
+    T: Copy + PartialOrd + Add<Output = T> + Sub<Output = T>,
+{
+    monitor.check(value, api);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn severity_with_hysteresis() {
+        static SEVERITY: Mutex<Severity> = Mutex::new(Severity::Nominal);
+
+        let monitor = LimitMonitor::new(
+            LimitMonitorConfig {
+                name: "TemperatureMonitor",
+                priority: 0,
+                warning: LimitLevel::new(-10, 40),
+                critical: LimitLevel::new(-20, 60),
+                hysteresis: 5,
+                nominal_event: None,
+            },
+            &SEVERITY,
+        );
+
+        assert_eq!(monitor.evaluate(20, Severity::Nominal), Severity::Nominal);
+        assert_eq!(monitor.evaluate(41, Severity::Nominal), Severity::Warning);
+        assert_eq!(monitor.evaluate(61, Severity::Nominal), Severity::Critical);
+        assert_eq!(monitor.evaluate(58, Severity::Critical), Severity::Critical);
+        assert_eq!(monitor.evaluate(50, Severity::Critical), Severity::Warning);
+        assert_eq!(monitor.evaluate(37, Severity::Warning), Severity::Warning);
+        assert_eq!(monitor.evaluate(30, Severity::Critical), Severity::Nominal);
+        assert_eq!(monitor.evaluate(-12, Severity::Nominal), Severity::Warning);
+        assert_eq!(monitor.evaluate(-5, Severity::Warning), Severity::Nominal);
+    }
+}
@@ -0,0 +1,70 @@
+//! Configuration for creating limit monitors.
+
+use core::ops::{Add, Sub};
+
+use crate::event::EventId;
+
+/// Single level of limits.
+///
+/// # Generic Parameters
+/// * `T` - Type of the monitored value.
+#[derive(Copy, Clone)]
+pub struct LimitLevel<T> {
+    /// Lowest allowed value.
+    pub low: T,
+    /// Highest allowed value.
+    pub high: T,
+    /// Event emitted when the value violates this level, `None` if no event should be emitted.
+    pub event: Option<EventId>,
+    /// Code of the fault stored in the fault recorder when the value violates this level.
+    pub fault_code: u32,
+}
+
+impl<T: Copy + PartialOrd + Add<Output = T> + Sub<Output = T>> LimitLevel<T> {
+    /// Creates new limit level, without event and with fault code `0`.
+    ///
+    /// # Parameters
+    /// * `low` - Lowest allowed value.
+    /// * `high` - Highest allowed value.
+    pub const fn new(low: T, high: T) -> Self {
+        LimitLevel {
+            low,
+            high,
+            event: None,
+            fault_code: 0,
+        }
+    }
+
+    /// Checks if the value is within this level.
+    ///
+    /// # Parameters
+    /// * `value` - Checked value.
+    /// * `margin` - Margin by which the limits are narrowed.
+    pub(crate) fn contains(&self, value: T, margin: Option<T>) -> bool {
+        match margin {
+            Some(margin) => value >= self.low + margin && value <= self.high - margin,
+            None => value >= self.low && value <= self.high,
+        }
+    }
+}
+
+/// Configuration for limit monitors.
+///
+/// # Generic Parameters
+/// * `T` - Type of the monitored value.
+#[derive(Copy, Clone)]
+pub struct LimitMonitorConfig<T> {
+    /// Name of the limit monitor tasklet, also used in the fault records.
+    pub name: &'static str,
+    /// Priority of the limit monitor tasklet.
+    pub priority: u8,
+    /// Warning limits.
+    pub warning: LimitLevel<T>,
+    /// Critical limits, which should contain warning limits.
+    pub critical: LimitLevel<T>,
+    /// Margin by which the value has to get back within the limits to lower the severity.
+    pub hysteresis: T,
+    /// Event emitted when the value gets back within the warning limits, `None` if no event
+    /// should be emitted.
+    pub nominal_event: Option<EventId>,
+}
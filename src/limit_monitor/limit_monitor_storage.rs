@@ -0,0 +1,52 @@
+//! Static storage for [limit monitor](crate::limit_monitor::LimitMonitor).
+//!
+//! This module contains a limit monitor storage, which is a statically allocated memory that will
+//! store the limit monitor tasklet for the duration of the system life.
+
+use super::{LimitMonitor, Severity};
+
+use crate::mutex::Mutex;
+use crate::tasklet::TaskletStorage;
+
+/// Structure containing memory for limit monitor creation.
+///
+/// Storage shall be a static variable and shall be initialized only once, before the system is started.
+///
+/// # Generic Parameters
+/// * `T` - Type of the monitored value.
+pub struct LimitMonitorStorage<T: 'static> {
+    /// Storage for the limit monitor tasklet.
+    tasklet_storage: TaskletStorage<T, LimitMonitor<T>, 0>,
+    /// Current severity of the monitored value.
+    severity: Mutex<Severity>,
+}
+
+impl<T: 'static> LimitMonitorStorage<T> {
+    /// Creates new storage.
+    pub const fn new() -> Self {
+        LimitMonitorStorage {
+            tasklet_storage: TaskletStorage::new(),
+            severity: Mutex::new(Severity::Nominal),
+        }
+    }
+
+    /// Returns initialization status of this storage.
+    pub fn is_initialized(&'static self) -> bool {
+        self.tasklet_storage.is_initialized()
+    }
+
+    /// Returns current severity of the monitored value.
+    pub fn severity(&'static self) -> Severity {
+        self.severity.lock(|severity| *severity)
+    }
+
+    /// Returns storage for the limit monitor tasklet.
+    pub(crate) fn tasklet_storage(&'static self) -> &'static TaskletStorage<T, LimitMonitor<T>, 0> {
+        &self.tasklet_storage
+    }
+
+    /// Returns current severity of the monitored value.
+    pub(crate) fn severity_cell(&'static self) -> &'static Mutex<Severity> {
+        &self.severity
+    }
+}
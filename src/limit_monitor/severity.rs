@@ -0,0 +1,12 @@
+//! Severity of the monitored value.
+
+/// Severity of the monitored value.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd)]
+pub enum Severity {
+    /// Value is within the warning limits.
+    Nominal,
+    /// Value is outside the warning limits, but within the critical limits.
+    Warning,
+    /// Value is outside the critical limits.
+    Critical,
+}
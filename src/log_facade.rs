@@ -0,0 +1,49 @@
+//! Frontend for the `log` crate facade.
+//!
+//! Third-party crates often report diagnostics with the `log` facade macros. [AerugoLogger]
+//! forwards such records to the system log backend (RTT on Cortex-M, standard output on x86), so
+//! they are captured in the same stream as system messages. Each record is prefixed with the
+//! time since system initialization in microseconds, level and target of the record. Hardware
+//! time is used, so records logged before the scheduler is started are timestamped too, and the
+//! timestamps are not affected by system time offset. With `log-prefix` feature, the time is added by
+//! [`logln!`](crate::logln) together with the name of the executed tasklet.
+
+#[cfg(not(feature = "log-prefix"))]
+use aerugo_hal::AerugoHal;
+
+#[cfg(not(feature = "log-prefix"))]
+use crate::hal::Hal;
+
+/// Logger forwarding records of the `log` facade to the system log backend.
+///
+/// Logger is installed with [enable_log_facade](crate::api::InitApi::enable_log_facade).
+pub struct AerugoLogger;
+
+/// Logger instance installed in the `log` facade.
+pub(crate) static LOGGER: AerugoLogger = AerugoLogger;
+
+impl log::Log for AerugoLogger {
+    fn enabled(&self, metadata: &log::Metadata) -> bool {
+        metadata.level() <= log::max_level()
+    }
+
+    fn log(&self, record: &log::Record) {
+        if !self.enabled(record.metadata()) {
+            return;
+        }
+
+        #[cfg(feature = "log-prefix")]
+        crate::logln!("{} {}: {}", record.level(), record.target(), record.args());
+
+        #[cfg(not(feature = "log-prefix"))]
+        crate::logln!(
+            "[{}] {} {}: {}",
+            Hal::get_system_time().duration_since_epoch().to_micros(),
+            record.level(),
+            record.target(),
+            record.args()
+        );
+    }
+
+    fn flush(&self) {}
+}
@@ -0,0 +1,42 @@
+//! Log lines prefixed with time and name of the executed tasklet.
+//!
+//! When built with `log-prefix` feature, [`logln!`](crate::logln) prefixes each line with the
+//! time since system initialization in microseconds and, if logged during a tasklet execution, the
+//! name of that tasklet, for example `[1520] [TaskB] value: 3`. Lines logged outside of tasklets
+//! (during initialization or from interrupts which preempted the executor loop) carry only the
+//! timestamp. Interrupts preempting a tasklet are tagged with the preempted tasklet name.
+//!
+//! [`log!`](crate::log) is not prefixed, so a line assembled from several `log!` calls should be
+//! finished with a single `logln!` call carrying the whole line.
+
+use aerugo_hal::AerugoHal;
+
+use crate::aerugo::Aerugo;
+use crate::hal::Hal;
+
+/// Prints a line prefixed with current time and name of the executed tasklet.
+///
+/// This function is used by [`logln!`](crate::logln) macro and shouldn't be called directly.
+///
+/// # Parameters
+/// * `args` - Formatted message.
+#[doc(hidden)]
+pub fn log_prefixed(args: core::fmt::Arguments) {
+    let time = Hal::get_system_time().duration_since_epoch().to_micros();
+
+    match Aerugo::current_tasklet() {
+        Some(tasklet) => crate::arch::logln!("[{}] [{}] {}", time, tasklet.get_name(), args),
+        None => crate::arch::logln!("[{}] {}", time, args),
+    }
+}
+
+/// Prints a line prefixed with current time and name of the executed tasklet.
+#[macro_export]
+macro_rules! logln {
+    () => {
+        $crate::log_prefixed(core::format_args!(""))
+    };
+    ($($arg:tt)*) => {
+        $crate::log_prefixed(core::format_args!($($arg)*))
+    };
+}
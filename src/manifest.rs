@@ -0,0 +1,340 @@
+//! Machine-readable manifest of the system configuration.
+//!
+//! Manifest describes the configured system as data, so it can be used in design reviews and for
+//! documentation generation without reading the code. It's written as
+//! [JSON Lines](https://jsonlines.org), one object per line, each with a `type` field:
+//! * `system` - number of tasklets and events in the system.
+//! * `tasklet` - ID, name, priority, deadline, data source (with its kind and size), successor and
+//!   condition set of the tasklet.
+//! * `event` - ID of the event and IDs of the tasklets subscribed to it.
+//!
+//! Message queues, boolean conditions and cyclic executions are not registered in the system
+//! directly, so they are described as data sources of the tasklets subscribed to them. Data
+//! sources, successors and conditions are identified with opaque object IDs, equal for all
+//! tasklets sharing the same object, so the connections between tasklets can be reconstructed
+//! from the manifest (ex. with `scripts/manifest_graph.py`). Successor of a tasklet is the
+//! [chain](crate::TaskletChain) that is a data source of the next tasklet.
+
+use core::fmt;
+
+use crate::boolean_condition::{BooleanConditionSetType, ConditionTerm};
+use crate::event::EventId;
+use crate::tasklet::TaskletId;
+use crate::time::Duration;
+
+/// Function called with each line of the configuration manifest.
+pub type ManifestWriter = fn(fmt::Arguments);
+
+/// Kind of the tasklet data source, described in the configuration manifest.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum DataSourceKind {
+    /// Message queue.
+    MessageQueue {
+        /// Maximum number of messages in the queue.
+        capacity: usize,
+    },
+    /// Set of events.
+    Events {
+        /// Number of distinct events in the set.
+        event_count: usize,
+    },
+    /// Subscription to the data event.
+    DataEvent {
+        /// Maximum number of payloads waiting for the tasklet.
+        capacity: usize,
+    },
+    /// Boolean condition.
+    BooleanCondition,
+    /// Cyclic execution.
+    CyclicExecution {
+        /// Period of execution, `None` if tasklet is executed whenever possible.
+        period: Option<Duration>,
+    },
+    /// Slots in the schedule table.
+    ScheduleTable {
+        /// Number of slots of the tasklet.
+        slot_count: usize,
+    },
+    /// Completion of the preceding tasklet in a chain.
+    TaskletChain,
+    /// Phases of a barrier.
+    Barrier {
+        /// Number of the barrier participants.
+        participant_count: usize,
+    },
+    /// Data source implemented by the user.
+    Custom,
+}
+
+impl fmt::Display for DataSourceKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DataSourceKind::MessageQueue { capacity } => {
+                write!(f, "{{\"kind\":\"message_queue\",\"capacity\":{capacity}}}")
+            }
+            DataSourceKind::Events { event_count } => {
+                write!(f, "{{\"kind\":\"events\",\"event_count\":{event_count}}}")
+            }
+            DataSourceKind::DataEvent { capacity } => {
+                write!(f, "{{\"kind\":\"data_event\",\"capacity\":{capacity}}}")
+            }
+            DataSourceKind::BooleanCondition => write!(f, "{{\"kind\":\"boolean_condition\"}}"),
+            DataSourceKind::CyclicExecution { period } => {
+                write!(f, "{{\"kind\":\"cyclic_execution\",\"period_us\":")?;
+                write_optional_duration(f, *period)?;
+                write!(f, "}}")
+            }
+            DataSourceKind::ScheduleTable { slot_count } => {
+                write!(
+                    f,
+                    "{{\"kind\":\"schedule_table\",\"slot_count\":{slot_count}}}"
+                )
+            }
+            DataSourceKind::TaskletChain => write!(f, "{{\"kind\":\"tasklet_chain\"}}"),
+            DataSourceKind::Barrier { participant_count } => {
+                write!(
+                    f,
+                    "{{\"kind\":\"barrier\",\"participant_count\":{participant_count}}}"
+                )
+            }
+            DataSourceKind::Custom => write!(f, "{{\"kind\":\"custom\"}}"),
+        }
+    }
+}
+
+/// Description of the tasklet in the configuration manifest.
+pub(crate) struct TaskletManifest {
+    /// Tasklet ID.
+    pub(crate) id: TaskletId,
+    /// Tasklet name.
+    pub(crate) name: &'static str,
+    /// Tasklet priority.
+    pub(crate) priority: u8,
+    /// Maximum execution time of a single step.
+    pub(crate) deadline: Option<Duration>,
+    /// Kind of the data source, `None` if tasklet isn't subscribed.
+    pub(crate) source: Option<DataSourceKind>,
+    /// Object ID of the data source, `None` if tasklet isn't subscribed.
+    pub(crate) source_id: Option<usize>,
+    /// Object ID of the listener notified after each step, `None` if it's not set.
+    pub(crate) successor_id: Option<usize>,
+    /// Type and terms of the condition set, if it's set.
+    pub(crate) conditions: Option<(BooleanConditionSetType, &'static [ConditionTerm])>,
+}
+
+impl fmt::Display for TaskletManifest {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{{\"type\":\"tasklet\",\"id\":{},\"name\":{},\"priority\":{},\"deadline_us\":",
+            self.id,
+            JsonString(self.name),
+            self.priority
+        )?;
+        write_optional_duration(f, self.deadline)?;
+
+        write!(f, ",\"source\":")?;
+        match &self.source {
+            Some(source) => write!(f, "{source}")?,
+            None => write!(f, "null")?,
+        }
+
+        write!(f, ",\"source_id\":")?;
+        write_optional(f, self.source_id)?;
+        write!(f, ",\"successor_id\":")?;
+        write_optional(f, self.successor_id)?;
+
+        write!(f, ",\"conditions\":")?;
+        match self.conditions {
+            Some((set_type, terms)) => {
+                write!(f, "{{\"type\":\"{}\",\"ids\":[", set_type_name(set_type))?;
+                let conditions = terms.iter().filter_map(|term| match term {
+                    ConditionTerm::Condition { condition, .. } => Some(object_id(*condition)),
+                    ConditionTerm::Set { .. } => None,
+                });
+                for (index, condition_id) in conditions.enumerate() {
+                    if index > 0 {
+                        write!(f, ",")?;
+                    }
+                    write!(f, "{condition_id}")?;
+                }
+                write!(f, "],\"expression\":\"")?;
+                write_condition_expression(f, set_type, terms)?;
+                write!(f, "\"}}")?;
+            }
+            None => write!(f, "null")?,
+        }
+
+        write!(f, "}}")
+    }
+}
+
+/// Description of the event in the configuration manifest.
+///
+/// # Generic Parameters
+/// * `I` - Iterator over IDs of the subscribed tasklets.
+pub(crate) struct EventManifest<I> {
+    /// Event ID.
+    pub(crate) id: EventId,
+    /// IDs of the tasklets subscribed to the event.
+    pub(crate) subscribers: I,
+}
+
+impl<I: Iterator<Item = TaskletId> + Clone> fmt::Display for EventManifest<I> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{{\"type\":\"event\",\"id\":{},\"subscribers\":[",
+            self.id
+        )?;
+
+        for (index, subscriber) in self.subscribers.clone().enumerate() {
+            if index > 0 {
+                write!(f, ",")?;
+            }
+            write!(f, "{subscriber}")?;
+        }
+
+        write!(f, "]}}")
+    }
+}
+
+/// String formatted as JSON string literal.
+struct JsonString<'a>(&'a str);
+
+impl fmt::Display for JsonString<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "\"")?;
+        for c in self.0.chars() {
+            match c {
+                '"' => write!(f, "\\\"")?,
+                '\\' => write!(f, "\\\\")?,
+                c if c.is_control() => write!(f, "\\u{:04x}", c as u32)?,
+                c => write!(f, "{c}")?,
+            }
+        }
+        write!(f, "\"")
+    }
+}
+
+/// Returns object ID of the system object, used to identify it in the manifest.
+///
+/// # Parameters
+/// * `object` - Reference to the object.
+pub(crate) fn object_id<T: ?Sized>(object: &T) -> usize {
+    object as *const T as *const () as usize
+}
+
+/// Returns name of the condition set type.
+///
+/// # Parameters
+/// * `set_type` - Type of the condition set.
+pub(crate) fn set_type_name(set_type: BooleanConditionSetType) -> &'static str {
+    match set_type {
+        BooleanConditionSetType::And => "and",
+        BooleanConditionSetType::Or => "or",
+    }
+}
+
+/// Writes condition set as a logical expression of condition object IDs, ex. `(1 && !2) || 3`.
+///
+/// # Parameters
+/// * `f` - Formatter.
+/// * `set_type` - Type of the condition set.
+/// * `terms` - Terms of the set, with terms of the nested sets.
+fn write_condition_expression(
+    f: &mut fmt::Formatter<'_>,
+    set_type: BooleanConditionSetType,
+    terms: &[ConditionTerm],
+) -> fmt::Result {
+    let operator = match set_type {
+        BooleanConditionSetType::And => " && ",
+        BooleanConditionSetType::Or => " || ",
+    };
+
+    let mut remaining = terms;
+    let mut first = true;
+    while let Some((term, rest)) = remaining.split_first() {
+        if !first {
+            write!(f, "{operator}")?;
+        }
+        first = false;
+
+        remaining = match *term {
+            ConditionTerm::Condition { condition, negated } => {
+                let negation = if negated { "!" } else { "" };
+                write!(f, "{negation}{}", object_id(condition))?;
+                rest
+            }
+            ConditionTerm::Set { set_type, len } => {
+                let (nested, rest) = rest.split_at(len);
+                write!(f, "(")?;
+                write_condition_expression(f, set_type, nested)?;
+                write!(f, ")")?;
+                rest
+            }
+        };
+    }
+
+    Ok(())
+}
+
+/// Writes value, or `null` if it's not set.
+///
+/// # Parameters
+/// * `f` - Formatter.
+/// * `value` - Value to write.
+fn write_optional<T: fmt::Display>(f: &mut fmt::Formatter<'_>, value: Option<T>) -> fmt::Result {
+    match value {
+        Some(value) => write!(f, "{value}"),
+        None => write!(f, "null"),
+    }
+}
+
+/// Writes duration in microseconds, or `null` if it's not set.
+///
+/// # Parameters
+/// * `f` - Formatter.
+/// * `duration` - Duration to write.
+fn write_optional_duration(f: &mut fmt::Formatter<'_>, duration: Option<Duration>) -> fmt::Result {
+    write_optional(f, duration.map(|duration| duration.to_micros()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use core::fmt::Write;
+
+    #[test]
+    fn manifest_lines_are_json() {
+        let mut line = heapless::String::<256>::new();
+
+        let tasklet = TaskletManifest {
+            id: TaskletId(4),
+            name: "Task \"A\"",
+            priority: 2,
+            deadline: Some(Duration::millis(5)),
+            source: Some(DataSourceKind::MessageQueue { capacity: 8 }),
+            source_id: Some(1024),
+            successor_id: None,
+            conditions: Some((BooleanConditionSetType::Or, &[])),
+        };
+        write!(line, "{tasklet}").unwrap();
+        assert_eq!(
+            line,
+            "{\"type\":\"tasklet\",\"id\":4,\"name\":\"Task \\\"A\\\"\",\"priority\":2,\
+             \"deadline_us\":5000,\"source\":{\"kind\":\"message_queue\",\"capacity\":8},\
+             \"source_id\":1024,\"successor_id\":null,\"conditions\":{\"type\":\"or\",\"ids\":[],\
+             \"expression\":\"\"}}"
+        );
+
+        line.clear();
+        let event = EventManifest {
+            id: 7,
+            subscribers: [TaskletId(1), TaskletId(3)].into_iter(),
+        };
+        write!(line, "{event}").unwrap();
+        assert_eq!(line, "{\"type\":\"event\",\"id\":7,\"subscribers\":[1,3]}");
+    }
+}
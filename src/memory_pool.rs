@@ -0,0 +1,25 @@
+//! Pool of fixed-size memory blocks.
+//!
+//! System doesn't use dynamic memory allocation, so buffers for data of varying size (DMA buffers,
+//! message payloads) would have to be statically allocated for the worst case of each user.
+//! Memory pool is a static set of equally sized blocks that can be allocated when needed and
+//! returned to the pool when they are no longer used, so the memory is shared by all users of the
+//! pool. Allocated [block](MemoryBlock) is returned to the pool when it's dropped, and it can be
+//! sent in a message queue, so the data doesn't have to be copied.
+//!
+//! Pool can be used both from tasklets and from the interrupt context.
+
+mod memory_block;
+mod memory_pool_storage;
+
+pub use self::memory_block::MemoryBlock;
+pub use self::memory_pool_storage::MemoryPoolStorage;
+
+/// Pool to which allocated blocks are returned.
+pub(crate) trait BlockPool {
+    /// Returns block to the pool.
+    ///
+    /// # Parameters
+    /// * `index` - Index of the block in the pool.
+    fn release(&self, index: usize);
+}
@@ -0,0 +1,73 @@
+//! Block of memory allocated from the memory pool.
+
+use core::fmt;
+use core::ops::{Deref, DerefMut};
+
+use super::BlockPool;
+
+/// Block of memory allocated from the [memory pool](crate::MemoryPoolStorage).
+///
+/// Block is returned to the pool when it's dropped or explicitly [freed](MemoryBlock::free).
+/// Content of the block is not cleared, newly allocated block contains data left by its previous
+/// owner.
+///
+/// # Generic Parameters
+/// * `BLOCK_SIZE` - Size of the block in bytes.
+pub struct MemoryBlock<const BLOCK_SIZE: usize> {
+    /// Memory of the block.
+    data: &'static mut [u8; BLOCK_SIZE],
+    /// Index of the block in the pool.
+    index: usize,
+    /// Pool that the block was allocated from.
+    pool: &'static dyn BlockPool,
+}
+
+impl<const BLOCK_SIZE: usize> MemoryBlock<BLOCK_SIZE> {
+    /// Creates new memory block.
+    ///
+    /// # Parameters
+    /// * `data` - Memory of the block.
+    /// * `index` - Index of the block in the pool.
+    /// * `pool` - Pool that the block was allocated from.
+    pub(crate) fn new(
+        data: &'static mut [u8; BLOCK_SIZE],
+        index: usize,
+        pool: &'static dyn BlockPool,
+    ) -> Self {
+        MemoryBlock { data, index, pool }
+    }
+
+    /// Returns the block to the pool.
+    ///
+    /// This is equivalent to dropping the block.
+    pub fn free(self) {}
+}
+
+impl<const BLOCK_SIZE: usize> Deref for MemoryBlock<BLOCK_SIZE> {
+    type Target = [u8; BLOCK_SIZE];
+
+    fn deref(&self) -> &Self::Target {
+        self.data
+    }
+}
+
+impl<const BLOCK_SIZE: usize> DerefMut for MemoryBlock<BLOCK_SIZE> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.data
+    }
+}
+
+impl<const BLOCK_SIZE: usize> Drop for MemoryBlock<BLOCK_SIZE> {
+    fn drop(&mut self) {
+        self.pool.release(self.index);
+    }
+}
+
+impl<const BLOCK_SIZE: usize> fmt::Debug for MemoryBlock<BLOCK_SIZE> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MemoryBlock")
+            .field("index", &self.index)
+            .field("size", &BLOCK_SIZE)
+            .finish()
+    }
+}
@@ -0,0 +1,133 @@
+//! Static storage for the memory pool.
+//!
+//! This module contains a memory pool storage, which is a statically allocated memory for the
+//! blocks of the pool, together with the allocation state of each block.
+
+use core::cell::UnsafeCell;
+
+use super::{BlockPool, MemoryBlock};
+
+use crate::mutex::Mutex;
+
+/// Block of the pool, aligned to the word size so it can be used as a DMA buffer.
+#[repr(C, align(4))]
+#[derive(Copy, Clone)]
+struct Block<const BLOCK_SIZE: usize>([u8; BLOCK_SIZE]);
+
+/// Allocation state of the pool.
+struct PoolState<const N: usize> {
+    /// `true` for each allocated block.
+    allocated: [bool; N],
+    /// Number of allocated blocks.
+    allocated_count: usize,
+    /// Highest number of blocks allocated at once.
+    high_watermark: usize,
+}
+
+/// Structure containing memory for the pool of fixed-size blocks.
+///
+/// Storage shall be a static variable. It doesn't require initialization, and blocks can be
+/// [allocated](MemoryPoolStorage::allocate) both from tasklets and from the interrupt context.
+///
+/// # Generic Parameters
+/// * `BLOCK_SIZE` - Size of a single block in bytes.
+/// * `N` - Number of blocks in the pool.
+pub struct MemoryPoolStorage<const BLOCK_SIZE: usize, const N: usize> {
+    /// Memory of the blocks.
+    blocks: UnsafeCell<[Block<BLOCK_SIZE>; N]>,
+    /// Allocation state of the blocks.
+    state: Mutex<PoolState<N>>,
+}
+
+/// It is safe, because allocation state is accessed only in the critical section, and each block
+/// is borrowed only by the [MemoryBlock] it was allocated to, until that block is dropped.
+unsafe impl<const BLOCK_SIZE: usize, const N: usize> Sync for MemoryPoolStorage<BLOCK_SIZE, N> {}
+
+impl<const BLOCK_SIZE: usize, const N: usize> MemoryPoolStorage<BLOCK_SIZE, N> {
+    /// Creates new storage.
+    pub const fn new() -> Self {
+        MemoryPoolStorage {
+            blocks: UnsafeCell::new([Block([0; BLOCK_SIZE]); N]),
+            state: Mutex::new(PoolState {
+                allocated: [false; N],
+                allocated_count: 0,
+                high_watermark: 0,
+            }),
+        }
+    }
+
+    /// Allocates a block from the pool.
+    ///
+    /// # Return
+    /// Allocated block, or `None` if all blocks are allocated.
+    pub fn allocate(&'static self) -> Option<MemoryBlock<BLOCK_SIZE>> {
+        let index = self.state.lock(|state| {
+            let index = state.allocated.iter().position(|allocated| !allocated)?;
+
+            state.allocated[index] = true;
+            state.allocated_count += 1;
+            state.high_watermark = state.high_watermark.max(state.allocated_count);
+
+            Some(index)
+        })?;
+
+        // SAFETY: Block was marked as allocated, so it's not borrowed by any other `MemoryBlock`
+        // until this one is dropped. Pointer is in bounds, as `index` is lower than `N`.
+        let data = unsafe { &mut (*(self.blocks.get() as *mut Block<BLOCK_SIZE>).add(index)).0 };
+
+        Some(MemoryBlock::new(data, index, self))
+    }
+
+    /// Returns number of blocks in the pool.
+    pub fn capacity(&'static self) -> usize {
+        N
+    }
+
+    /// Returns number of currently allocated blocks.
+    pub fn allocated_count(&'static self) -> usize {
+        self.state.lock(|state| state.allocated_count)
+    }
+
+    /// Returns highest number of blocks allocated at once.
+    pub fn high_watermark(&'static self) -> usize {
+        self.state.lock(|state| state.high_watermark)
+    }
+}
+
+impl<const BLOCK_SIZE: usize, const N: usize> BlockPool for MemoryPoolStorage<BLOCK_SIZE, N> {
+    fn release(&self, index: usize) {
+        self.state.lock(|state| {
+            if state.allocated[index] {
+                state.allocated[index] = false;
+                state.allocated_count -= 1;
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn blocks_are_recycled() {
+        static POOL: MemoryPoolStorage<16, 2> = MemoryPoolStorage::new();
+
+        let mut first = POOL.allocate().unwrap();
+        let second = POOL.allocate().unwrap();
+        assert!(POOL.allocate().is_none());
+        assert_eq!(POOL.allocated_count(), 2);
+
+        first[0] = 0xAB;
+        second.free();
+        assert_eq!(POOL.allocated_count(), 1);
+
+        let third = POOL.allocate().unwrap();
+        assert_eq!(first[0], 0xAB);
+        drop(first);
+        drop(third);
+
+        assert_eq!(POOL.allocated_count(), 0);
+        assert_eq!(POOL.high_watermark(), 2);
+    }
+}
@@ -1,23 +1,41 @@
 //! Message queue used for exchanging data between tasklets.
 
+mod isr_send_error;
 mod message_queue_handle;
 mod message_queue_storage;
+mod overflow_policy;
 
+pub use self::isr_send_error::IsrSendError;
 pub use self::message_queue_handle::MessageQueueHandle;
 pub use self::message_queue_storage::MessageQueueStorage;
+pub use self::overflow_policy::OverflowPolicy;
 
 pub(crate) use self::message_queue_storage::QueueData;
 
+use aerugo_hal::AerugoHal;
+
 use crate::aerugo::Aerugo;
 use crate::data_provider::DataProvider;
 use crate::error::{RuntimeError, SystemError};
+use crate::hal::Hal;
 use crate::internal_list::InternalList;
+use crate::manifest::DataSourceKind;
 use crate::mutex::Mutex;
+use crate::queue_growth_monitor::QueueOccupancy;
 use crate::tasklet::TaskletPtr;
+use crate::time::{Duration, Instant};
 
 /// List of tasklets registered to a queue
 type TaskletList = InternalList<TaskletPtr, { Aerugo::TASKLET_COUNT }>;
 
+/// Element stored in the message queue.
+pub(crate) struct QueueElement<T> {
+    /// Stored data.
+    data: T,
+    /// Time when the data was sent to the queue.
+    enqueue_time: Instant,
+}
+
 /// Message queue used for exchanging data between tasklets.
 ///
 /// # Generic Parameters
@@ -29,6 +47,17 @@ pub(crate) struct MessageQueue<T: 'static, const N: usize> {
     data_queue: &'static Mutex<QueueData<T, N>>,
     /// Tasklets registered to this queue.
     registered_tasklets: TaskletList,
+    /// Policy of handling data sent to the full queue.
+    overflow_policy: OverflowPolicy,
+    /// Time after which data in the queue is considered stale, `None` if data never expires.
+    time_to_live: Mutex<Option<Duration>>,
+    /// Number of elements dropped because they expired.
+    expired_count: Mutex<u32>,
+    /// Borrow of the oldest element, passed by reference to the tasklet step. `None` if it's not
+    /// borrowed, or number of elements following it that were flushed while it was borrowed.
+    borrow: Mutex<Option<usize>>,
+    /// Maximum number of elements stored in the queue at once.
+    high_watermark: Mutex<usize>,
 }
 
 /// It is safe assuming that MessageQueue is not available from IRQ context before it's created
@@ -48,10 +77,18 @@ unsafe impl<T, const N: usize> Sync for MessageQueue<T, N> {}
 
 impl<T, const N: usize> MessageQueue<T, N> {
     /// Creates new `MessageQueue`.
-    pub(crate) fn new(data_queue: &'static Mutex<QueueData<T, N>>) -> Self {
+    pub(crate) fn new(
+        data_queue: &'static Mutex<QueueData<T, N>>,
+        overflow_policy: OverflowPolicy,
+    ) -> Self {
         MessageQueue {
             data_queue,
             registered_tasklets: TaskletList::new(),
+            overflow_policy,
+            time_to_live: Mutex::new(None),
+            expired_count: Mutex::new(0),
+            borrow: Mutex::new(None),
+            high_watermark: Mutex::new(0),
         }
     }
 
@@ -77,13 +114,35 @@ impl<T, const N: usize> MessageQueue<T, N> {
 
     /// Sends given data to this queue.
     ///
+    /// If the queue is full, data is handled according to the queue overflow policy. Oldest
+    /// element is never overwritten while it's borrowed by the tasklet.
+    ///
     /// # Parameters
     /// * `data` - Data to send.
     ///
     /// # Return
     /// `()` if successful, `RuntimeError` otherwise.
-    fn send_data(&self, data: T) -> Result<(), RuntimeError> {
-        match self.data_queue.lock(|q| q.enqueue(data)) {
+    pub(crate) fn send_data(&self, data: T) -> Result<(), RuntimeError> {
+        let element = QueueElement {
+            data,
+            enqueue_time: Hal::get_system_time(),
+        };
+
+        let enqueue_result = self.data_queue.lock(|q| {
+            if q.is_full()
+                && self.overflow_policy == OverflowPolicy::OverwriteOldest
+                && self.borrow.lock(|borrow| borrow.is_none())
+            {
+                q.dequeue();
+            }
+
+            let result = q.enqueue(element);
+            self.high_watermark
+                .lock(|watermark| *watermark = (*watermark).max(q.len()));
+            result
+        });
+
+        match enqueue_result {
             Ok(_) => (),
             Err(_) => return Err(RuntimeError::DataQueueFull),
         };
@@ -93,9 +152,118 @@ impl<T, const N: usize> MessageQueue<T, N> {
         Ok(())
     }
 
+    /// Sends given data to this queue from an interrupt handler.
+    ///
+    /// Data is enqueued in a single critical section, which doesn't depend on the size of the
+    /// queue. Data overwritten with `OverwriteOldest` policy, or rejected by the full queue, is
+    /// dropped after the critical section ends. Registered tasklets are then woken, each in its
+    /// own critical section. Failure to wake a tasklet is returned instead of being reported to the
+    /// error hook, so this never panics.
+    ///
+    /// # Parameters
+    /// * `data` - Data to send.
+    ///
+    /// # Return
+    /// `()` if successful, `IsrSendError` otherwise.
+    pub(crate) fn send_data_from_isr(&self, data: T) -> Result<(), IsrSendError> {
+        let element = QueueElement {
+            data,
+            enqueue_time: Hal::get_system_time(),
+        };
+
+        let (enqueue_result, overwritten) = self.data_queue.lock(|q| {
+            let overwritten = if q.is_full()
+                && self.overflow_policy == OverflowPolicy::OverwriteOldest
+                && self.borrow.lock(|borrow| borrow.is_none())
+            {
+                q.dequeue()
+            } else {
+                None
+            };
+
+            let result = q.enqueue(element);
+            self.high_watermark
+                .lock(|watermark| *watermark = (*watermark).max(q.len()));
+            (result, overwritten)
+        });
+
+        drop(overwritten);
+        if enqueue_result.is_err() {
+            return Err(IsrSendError::QueueFull);
+        }
+
+        let mut result = Ok(());
+        for t in &self.registered_tasklets {
+            if Aerugo::try_wake_tasklet(t).is_err() {
+                result = Err(IsrSendError::SchedulingFailed);
+            }
+        }
+
+        result
+    }
+
     /// Clears this queue.
     pub(crate) fn clear(&self) {
-        self.data_queue.lock(|q| while q.dequeue().is_some() {})
+        self.flush();
+    }
+
+    /// Calls given function with reference to the oldest data in this queue, without removing it
+    /// from the queue. Expired data is skipped.
+    ///
+    /// # Parameters
+    /// * `f` - Function called with the reference to the data.
+    ///
+    /// # Return
+    /// Value returned by `f`, or `None` if the queue is empty.
+    pub(crate) fn peek_with<R>(&self, f: impl FnOnce(&T) -> R) -> Option<R> {
+        let time_to_live = self.time_to_live.lock(|ttl| *ttl);
+        let current_time = Hal::get_system_time();
+
+        self.data_queue.lock(|q| {
+            q.iter()
+                .skip(self.borrowed_count())
+                .find(|element| !element.is_expired(time_to_live, current_time))
+                .map(|element| f(&element.data))
+        })
+    }
+
+    /// Returns number of elements at the front of the queue that are borrowed or flushed, and
+    /// will be removed when the borrow is released.
+    fn borrowed_count(&self) -> usize {
+        self.borrow
+            .lock(|borrow| borrow.map_or(0, |flushed| flushed + 1))
+    }
+
+    /// Sets time after which data in this queue is considered stale.
+    ///
+    /// # Parameters
+    /// * `time_to_live` - Maximum age of the data, `None` if data never expires.
+    pub(crate) fn set_time_to_live(&self, time_to_live: Option<Duration>) {
+        self.time_to_live.lock(|ttl| *ttl = time_to_live)
+    }
+
+    /// Returns number of elements dropped because they expired.
+    pub(crate) fn expired_count(&self) -> u32 {
+        self.expired_count.lock(|count| *count)
+    }
+
+    /// Returns number of elements waiting in this queue.
+    ///
+    /// Element borrowed by the tasklet, and elements flushed while it's borrowed, are not counted.
+    pub(crate) fn len(&self) -> usize {
+        self.data_queue.lock(|q| q.len() - self.borrowed_count())
+    }
+
+    /// Returns maximum number of elements that can be stored in this queue.
+    pub(crate) fn capacity(&self) -> usize {
+        self.data_queue.lock(|q| q.capacity())
+    }
+
+    /// Returns maximum number of elements stored in this queue at once since it was created.
+    ///
+    /// Borrowed element is counted, as it occupies place in the queue until it's released.
+    pub(crate) fn high_watermark(&self) -> usize {
+        self.high_watermark.lock(|watermark| *watermark)
     }
 
     /// Wakes tasklets registered to this queue.
@@ -106,26 +274,128 @@ impl<T, const N: usize> MessageQueue<T, N> {
     }
 }
 
+impl<T> QueueElement<T> {
+    /// Checks if this element spent more than `time_to_live` in the queue.
+    fn is_expired(&self, time_to_live: Option<Duration>, current_time: Instant) -> bool {
+        let age = current_time.checked_duration_since(self.enqueue_time);
+        matches!((time_to_live, age), (Some(ttl), Some(age)) if age > ttl)
+    }
+}
+
+impl<T, const N: usize> QueueOccupancy for MessageQueue<T, N> {
+    fn occupancy(&self) -> usize {
+        self.len()
+    }
+}
+
 impl<T, const N: usize> DataProvider<T> for MessageQueue<T, N> {
     /// Returns elements from this queue.
     ///
-    /// Deqeueues next element.
+    /// Deqeueues next element. If time to live is set, expired elements are dropped and counted.
     ///
     /// # Return
     /// `Some(T)` if there was data available, `None` otherwise.
     fn get_data(&self) -> Option<T> {
-        self.data_queue.lock(|q| q.dequeue())
+        let time_to_live = self.time_to_live.lock(|ttl| *ttl);
+        let current_time = Hal::get_system_time();
+
+        while let Some(element) = self.data_queue.lock(|q| q.dequeue()) {
+            if element.is_expired(time_to_live, current_time) {
+                self.expired_count
+                    .lock(|count| *count = count.saturating_add(1));
+            } else {
+                return Some(element.data);
+            }
+        }
+
+        None
+    }
+
+    /// Passes reference to the oldest data to `f`, without moving it out of the queue.
+    ///
+    /// Data stays in the queue until `f` returns. In the meantime it's not overwritten, and
+    /// flushing the queue only discards the data following it.
+    fn with_data(&self, f: &mut dyn FnMut(&T)) -> bool {
+        let time_to_live = self.time_to_live.lock(|ttl| *ttl);
+        let current_time = Hal::get_system_time();
+
+        let element = self.data_queue.lock(|q| {
+            while q
+                .peek()
+                .is_some_and(|element| element.is_expired(time_to_live, current_time))
+            {
+                q.dequeue();
+                self.expired_count
+                    .lock(|count| *count = count.saturating_add(1));
+            }
+
+            let element = q.peek().map(|element| element as *const QueueElement<T>);
+            if element.is_some() {
+                self.borrow.lock(|borrow| *borrow = Some(0));
+            }
+            element
+        });
+
+        let Some(element) = element else {
+            return false;
+        };
+
+        // SAFETY: This is safe, because borrowed element stays at the front of the queue until
+        // the borrow is released below. Elements are only removed from the front of the queue by
+        // the executor, which is busy with this call, or by overwriting and flushing, which skip
+        // the borrowed element.
+        f(unsafe { &(*element).data });
+
+        self.data_queue.lock(|q| {
+            let flushed = self.borrow.lock(|borrow| borrow.take()).unwrap_or(0);
+            for _ in 0..=flushed {
+                q.dequeue();
+            }
+        });
+
+        true
     }
 
     /// Checks if there is any data in the queue.
     fn data_waiting(&self) -> bool {
-        self.data_queue.lock(|q| !q.is_empty())
+        self.data_queue.lock(|q| q.len() > self.borrowed_count())
+    }
+
+    fn waiting_count(&self) -> usize {
+        self.len()
+    }
+
+    /// Clears the queue.
+    ///
+    /// Queue is shared by all tasklets registered to it, so the data is discarded for all of them.
+    /// If the oldest element is borrowed, it's discarded together with the flushed elements when
+    /// the borrow is released.
+    fn flush(&self) -> usize {
+        self.data_queue.lock(|q| {
+            let borrowed_count = self.borrowed_count();
+            let count = q.len() - borrowed_count;
+
+            if borrowed_count == 0 {
+                while q.dequeue().is_some() {}
+            } else {
+                self.borrow.lock(|borrow| *borrow = Some(q.len() - 1));
+            }
+
+            count
+        })
+    }
+
+    fn source_kind(&self) -> DataSourceKind {
+        DataSourceKind::MessageQueue {
+            capacity: self.capacity(),
+        }
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::message_queue::MessageQueueStorage;
 
     #[test]
     fn const_size() {
@@ -141,4 +411,136 @@ mod tests {
         assert_eq!(queue2u8_size, stub_size);
         assert_eq!(queue100u64_size, stub_size);
     }
+
+    #[test]
+    fn drop_expired_data() {
+        static STORAGE: MessageQueueStorage<u8, 2> = MessageQueueStorage::new();
+        unsafe { STORAGE.init(OverflowPolicy::Reject).unwrap() };
+        let queue = STORAGE.create_handle().unwrap().queue();
+
+        queue.set_time_to_live(Some(Duration::from_ticks(0)));
+        queue.send_data(1).unwrap();
+        let send_time = Hal::get_system_time();
+        while Hal::get_system_time() == send_time {}
+
+        assert_eq!(queue.get_data(), None);
+        assert_eq!(queue.expired_count(), 1);
+
+        queue.set_time_to_live(None);
+        queue.send_data(2).unwrap();
+
+        assert_eq!(queue.get_data(), Some(2));
+        assert_eq!(queue.expired_count(), 1);
+    }
+
+    #[test]
+    fn overflow_policy() {
+        static REJECT_STORAGE: MessageQueueStorage<u8, 3> = MessageQueueStorage::new();
+        unsafe { REJECT_STORAGE.init(OverflowPolicy::Reject).unwrap() };
+        let queue = REJECT_STORAGE.create_handle().unwrap().queue();
+
+        queue.send_data(1).unwrap();
+        queue.send_data(2).unwrap();
+        assert_eq!(queue.send_data(3), Err(RuntimeError::DataQueueFull));
+        assert_eq!(queue.get_data(), Some(1));
+        assert_eq!(queue.get_data(), Some(2));
+
+        static OVERWRITE_STORAGE: MessageQueueStorage<u8, 3> = MessageQueueStorage::new();
+        unsafe {
+            OVERWRITE_STORAGE
+                .init(OverflowPolicy::OverwriteOldest)
+                .unwrap()
+        };
+        let queue = OVERWRITE_STORAGE.create_handle().unwrap().queue();
+
+        queue.send_data(1).unwrap();
+        queue.send_data(2).unwrap();
+        assert_eq!(queue.send_data(3), Ok(()));
+        assert_eq!(queue.get_data(), Some(2));
+        assert_eq!(queue.get_data(), Some(3));
+    }
+
+    #[test]
+    fn borrow_data() {
+        static STORAGE: MessageQueueStorage<u8, 4> = MessageQueueStorage::new();
+        unsafe { STORAGE.init(OverflowPolicy::OverwriteOldest).unwrap() };
+        let queue = STORAGE.create_handle().unwrap().queue();
+
+        queue.send_data(1).unwrap();
+        queue.send_data(2).unwrap();
+        assert_eq!(queue.peek_with(|data| *data), Some(1));
+
+        let mut borrowed = None;
+        let called = queue.with_data(&mut |data| {
+            borrowed = Some(*data);
+
+            assert_eq!(queue.peek_with(|data| *data), Some(2));
+            assert_eq!(queue.flush(), 1);
+            assert!(!queue.data_waiting());
+
+            queue.send_data(3).unwrap();
+            assert_eq!(queue.send_data(4), Err(RuntimeError::DataQueueFull));
+        });
+
+        assert!(called);
+        assert_eq!(borrowed, Some(1));
+        assert_eq!(queue.get_data(), Some(3));
+        assert!(!queue.with_data(&mut |_| panic!("queue should be empty")));
+    }
+
+    #[test]
+    fn fill_level() {
+        static STORAGE: MessageQueueStorage<u8, 4> = MessageQueueStorage::new();
+        unsafe { STORAGE.init(OverflowPolicy::Reject).unwrap() };
+        let queue = STORAGE.create_handle().unwrap().queue();
+
+        assert_eq!(queue.capacity(), 3);
+        assert_eq!(queue.len(), 0);
+        assert_eq!(queue.high_watermark(), 0);
+
+        queue.send_data(1).unwrap();
+        queue.send_data(2).unwrap();
+        assert_eq!(queue.len(), 2);
+        assert_eq!(queue.high_watermark(), 2);
+
+        queue.with_data(&mut |_| {
+            assert_eq!(queue.len(), 1);
+            queue.send_data(3).unwrap();
+            assert_eq!(queue.send_data(4), Err(RuntimeError::DataQueueFull));
+        });
+        assert_eq!(queue.len(), 2);
+        assert_eq!(queue.high_watermark(), 3);
+
+        queue.flush();
+        assert_eq!(queue.len(), 0);
+        assert_eq!(queue.high_watermark(), 3);
+    }
+
+    #[test]
+    fn send_from_isr() {
+        static REJECT_STORAGE: MessageQueueStorage<u8, 3> = MessageQueueStorage::new();
+        unsafe { REJECT_STORAGE.init(OverflowPolicy::Reject).unwrap() };
+        let queue = REJECT_STORAGE.create_handle().unwrap().queue();
+
+        assert_eq!(queue.send_data_from_isr(1), Ok(()));
+        assert_eq!(queue.send_data_from_isr(2), Ok(()));
+        assert_eq!(queue.send_data_from_isr(3), Err(IsrSendError::QueueFull));
+        assert_eq!(queue.get_data(), Some(1));
+        assert_eq!(queue.get_data(), Some(2));
+
+        static OVERWRITE_STORAGE: MessageQueueStorage<u8, 3> = MessageQueueStorage::new();
+        unsafe {
+            OVERWRITE_STORAGE
+                .init(OverflowPolicy::OverwriteOldest)
+                .unwrap()
+        };
+        let queue = OVERWRITE_STORAGE.create_handle().unwrap().queue();
+
+        queue.send_data_from_isr(1).unwrap();
+        queue.send_data_from_isr(2).unwrap();
+        assert_eq!(queue.send_data_from_isr(3), Ok(()));
+        assert_eq!(queue.get_data(), Some(2));
+        assert_eq!(queue.get_data(), Some(3));
+        assert_eq!(queue.high_watermark(), 2);
+    }
 }
@@ -0,0 +1,15 @@
+//! Errors of sending data to a message queue from an interrupt handler.
+
+/// Error of sending data to a message queue from an interrupt handler.
+///
+/// Error is a single byte code, so it can be stored or reported by the interrupt handler without
+/// formatting, ex. in a status register of a telemetry frame.
+#[repr(u8)]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum IsrSendError {
+    /// Queue is full, and its overflow policy doesn't allow overwriting the oldest data.
+    QueueFull = 1,
+    /// Data was enqueued, but at least one of the tasklets subscribed to the queue couldn't be
+    /// scheduled for execution, because the executor queue was full.
+    SchedulingFailed = 2,
+}
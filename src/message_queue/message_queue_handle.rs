@@ -4,7 +4,8 @@
 //! system.
 
 use crate::error::RuntimeError;
-use crate::message_queue::MessageQueue;
+use crate::message_queue::{IsrSendError, MessageQueue};
+use crate::time::Duration;
 
 /// Message queue handle.
 ///
@@ -41,11 +42,111 @@ impl<T, const N: usize> MessageQueueHandle<T, N> {
         self.queue.send_data(data)
     }
 
+    /// Send data to the stored queue from an interrupt handler.
+    ///
+    /// Unlike [`MessageQueueHandle::send_data`], this never drops data while interrupts are masked
+    /// and never panics, so its latency can be bounded. If a subscribed tasklet can't be scheduled,
+    /// [`IsrSendError::SchedulingFailed`] is returned instead of reporting the error.
+    ///
+    /// # Parameters
+    /// * `data` - Data to send.
+    ///
+    /// # Return
+    /// `()` if successful, `IsrSendError` otherwise.
+    ///
+    /// # Worst-case duration
+    /// * Read of the system time, before interrupts are masked.
+    /// * One critical section, in which `T` is moved into the queue, at most one element is
+    ///   dequeued and the high watermark is updated. Its duration doesn't depend on the size of
+    ///   the queue.
+    /// * Drop of the overwritten or rejected element, with interrupts enabled.
+    /// * For each tasklet subscribed to the queue:
+    ///   * Read of the tasklet status and suspension flag, and evaluation of its condition set,
+    ///     each in a separate critical section. Evaluation of a condition set reads each of its
+    ///     conditions, and nested condition sets recursively. Tasklets that are not sleeping are
+    ///     skipped after reading the status, and inactive ones after the evaluation.
+    ///   * Read of the system time with `wake-latency` feature.
+    ///   * Three to four short critical sections taking the tie-break key, the equal priority
+    ///     policy and, with round robin policy, the sequence number.
+    ///   * One critical section in which the tasklet is pushed to the executor queue, in
+    ///     `O(log TASKLET_COUNT)`, with a read of the system time with `instrumented` feature.
+    #[inline(always)]
+    pub fn send_data_from_isr(&self, data: T) -> Result<(), IsrSendError> {
+        self.queue.send_data_from_isr(data)
+    }
+
+    /// Returns copy of the oldest data in stored queue, without removing it from the queue.
+    ///
+    /// To inspect the data without copying it, use [`MessageQueueHandle::peek_with`].
+    ///
+    /// # Return
+    /// Oldest data, or `None` if the queue is empty.
+    pub fn peek(&self) -> Option<T>
+    where
+        T: Clone,
+    {
+        self.queue.peek_with(T::clone)
+    }
+
+    /// Calls given function with reference to the oldest data in stored queue, without removing
+    /// it from the queue.
+    ///
+    /// Function is called in a critical section, so it should be kept short.
+    ///
+    /// # Parameters
+    /// * `f` - Function called with the reference to the data.
+    ///
+    /// # Return
+    /// Value returned by `f`, or `None` if the queue is empty.
+    pub fn peek_with<R>(&self, f: impl FnOnce(&T) -> R) -> Option<R> {
+        self.queue.peek_with(f)
+    }
+
     /// Clears stored queue.
     pub fn clear(&self) {
         self.queue.clear()
     }
 
+    /// Sets time to live of the data in stored queue.
+    ///
+    /// Data that spent more than this time in the queue is considered stale. It's dropped when
+    /// it's dequeued, and counted in [`MessageQueueHandle::expired_count`]. Age of the data is
+    /// measured with hardware time, so it's not affected by system time offset.
+    ///
+    /// # Parameters
+    /// * `time_to_live` - Maximum age of the data, `None` if data never expires (default).
+    pub fn set_time_to_live(&self, time_to_live: Option<Duration>) {
+        self.queue.set_time_to_live(time_to_live)
+    }
+
+    /// Returns number of elements dropped from stored queue because they expired.
+    pub fn expired_count(&self) -> u32 {
+        self.queue.expired_count()
+    }
+
+    /// Returns number of elements waiting in stored queue.
+    pub fn len(&self) -> usize {
+        self.queue.len()
+    }
+
+    /// Checks if stored queue has no elements waiting.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns maximum number of elements that can be stored in stored queue.
+    pub fn capacity(&self) -> usize {
+        self.queue.capacity()
+    }
+
+    /// Returns maximum number of elements stored in stored queue at once since it was created.
+    ///
+    /// High watermark close to the [capacity](MessageQueueHandle::capacity) observed during
+    /// integration testing indicates that the queue is too small for the actual load.
+    pub fn high_watermark(&self) -> usize {
+        self.queue.high_watermark()
+    }
+
     /// Returns reference to the queue.
     pub(crate) fn queue(&self) -> &'static MessageQueue<T, N> {
         self.queue
@@ -10,13 +10,13 @@ use core::cell::OnceCell;
 use heapless::Vec;
 
 use crate::error::SystemError;
-use crate::message_queue::MessageQueueHandle;
+use crate::message_queue::{MessageQueueHandle, OverflowPolicy, QueueElement};
 use crate::mutex::Mutex;
 
 /// Type of the queue buffer storage.
 pub(crate) type QueueBuffer = Vec<u8, { core::mem::size_of::<MessageQueue<(), 0>>() }>;
 /// Type of the queue data storage.
-pub(crate) type QueueData<T, const N: usize> = heapless::spsc::Queue<T, N>;
+pub(crate) type QueueData<T, const N: usize> = heapless::spsc::Queue<QueueElement<T>, N>;
 
 /// Structure containing memory for MessageQueue creation.
 ///
@@ -76,6 +76,9 @@ impl<T, const N: usize> MessageQueueStorage<T, N> {
 
     /// Initializes this storage.
     ///
+    /// # Parameters
+    /// * `overflow_policy` - Policy of handling data sent to the full queue.
+    ///
     /// # Return
     /// `()` if successful, `SystemError` otherwise.
     ///
@@ -83,12 +86,15 @@ impl<T, const N: usize> MessageQueueStorage<T, N> {
     /// This is unsafe, because it mutably borrows the stored queue and queue data buffers.
     /// This is safe to call during system initialization (before scheduler is started).
     /// Accessing storage from IRQ context during initialization is undefined behaviour.
-    pub(crate) unsafe fn init(&'static self) -> Result<(), SystemError> {
+    pub(crate) unsafe fn init(
+        &'static self,
+        overflow_policy: OverflowPolicy,
+    ) -> Result<(), SystemError> {
         if self.initialized.get().is_some() {
             return Err(SystemError::StorageAlreadyInitialized);
         }
 
-        let queue = MessageQueue::<T, N>::new(&self.queue_data);
+        let queue = MessageQueue::<T, N>::new(&self.queue_data, overflow_policy);
 
         // This is safe, because `queue_buffer` doesn't contain any value yet, and it's size is
         // guaranteed to be large enough to store queue structure.
@@ -139,7 +145,7 @@ mod tests {
     fn initialize() {
         static STORAGE: MessageQueueStorage<u8, 2> = MessageQueueStorage::new();
 
-        let init_result = unsafe { STORAGE.init() };
+        let init_result = unsafe { STORAGE.init(OverflowPolicy::Reject) };
         assert!(init_result.is_ok());
         assert!(STORAGE.is_initialized());
     }
@@ -148,10 +154,10 @@ mod tests {
     fn fail_double_initialization() {
         static STORAGE: MessageQueueStorage<u8, 2> = MessageQueueStorage::new();
 
-        let mut init_result = unsafe { STORAGE.init() };
+        let mut init_result = unsafe { STORAGE.init(OverflowPolicy::Reject) };
         assert!(init_result.is_ok());
 
-        init_result = unsafe { STORAGE.init() };
+        init_result = unsafe { STORAGE.init(OverflowPolicy::Reject) };
         assert!(init_result.is_err());
         assert_eq!(
             init_result.err().unwrap(),
@@ -163,7 +169,7 @@ mod tests {
     fn create_handle() {
         static STORAGE: MessageQueueStorage<u8, 2> = MessageQueueStorage::new();
 
-        let _ = unsafe { STORAGE.init() };
+        let _ = unsafe { STORAGE.init(OverflowPolicy::Reject) };
 
         let handle = STORAGE.create_handle();
         assert!(handle.is_some());
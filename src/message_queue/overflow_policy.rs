@@ -0,0 +1,13 @@
+//! Policy of handling data sent to a full message queue.
+
+/// Policy of handling data sent to a full message queue.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq)]
+pub enum OverflowPolicy {
+    /// Data sent to a full queue is rejected with `DataQueueFull` error.
+    #[default]
+    Reject,
+    /// Oldest data in a full queue is dropped to make space for the sent data, so producers can
+    /// always push the freshest sample. Sending data fails only if the oldest data is being
+    /// processed by a tasklet receiving the data by reference.
+    OverwriteOldest,
+}
@@ -0,0 +1,91 @@
+//! Handling of missed activations of cyclic tasklets.
+//!
+//! Cyclic tasklet is woken at the start of each period. When the system is overloaded, or
+//! interrupts are disabled for too long, whole periods can pass before the tasklet is woken again.
+//! Activations from those periods are missed. They are always counted, and can be reported with a
+//! diagnostics event set with
+//! [set_missed_activation_policy](crate::api::InitApi::set_missed_activation_policy). By default
+//! they are skipped, so the tasklet stays in phase with its period and is executed once. Tasklets
+//! that integrate over time, ex. counters or filters, can instead catch up, and be executed again
+//! for some of the missed activations.
+
+/// Handling of missed activations of a cyclic tasklet.
+#[derive(Debug, Default, Copy, Clone, Eq, PartialEq)]
+pub enum MissedActivationPolicy {
+    /// Missed activations are skipped, tasklet is executed once.
+    #[default]
+    Skip,
+    /// Tasklet is executed once more if any activation was missed.
+    CatchUpOnce,
+    /// Tasklet is executed once more for each missed activation, up to given number of times.
+    CatchUp(u32),
+}
+
+impl MissedActivationPolicy {
+    /// Returns maximum number of pending catch-up executions.
+    fn catch_up_limit(&self) -> u32 {
+        match self {
+            MissedActivationPolicy::Skip => 0,
+            MissedActivationPolicy::CatchUpOnce => 1,
+            MissedActivationPolicy::CatchUp(limit) => *limit,
+        }
+    }
+
+    /// Calculates number of pending activations after the tasklet is woken.
+    ///
+    /// # Parameters
+    /// * `pending` - Number of activations pending before the wake.
+    /// * `missed` - Number of activations missed before the wake.
+    ///
+    /// # Return
+    /// Number of pending activations, including the current one.
+    pub(crate) fn pending_activations(&self, pending: u32, missed: u32) -> u32 {
+        let limit = self.catch_up_limit().saturating_add(1);
+        let pending = pending.max(1);
+
+        pending.max(pending.saturating_add(missed).min(limit))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pending_activations() {
+        assert_eq!(MissedActivationPolicy::Skip.pending_activations(0, 0), 1);
+        assert_eq!(MissedActivationPolicy::Skip.pending_activations(0, 5), 1);
+        // Wake of a tasklet that is still waiting doesn't add an activation.
+        assert_eq!(MissedActivationPolicy::Skip.pending_activations(1, 0), 1);
+
+        assert_eq!(
+            MissedActivationPolicy::CatchUpOnce.pending_activations(0, 0),
+            1
+        );
+        assert_eq!(
+            MissedActivationPolicy::CatchUpOnce.pending_activations(0, 5),
+            2
+        );
+        assert_eq!(
+            MissedActivationPolicy::CatchUpOnce.pending_activations(2, 5),
+            2
+        );
+
+        assert_eq!(
+            MissedActivationPolicy::CatchUp(3).pending_activations(0, 2),
+            3
+        );
+        assert_eq!(
+            MissedActivationPolicy::CatchUp(3).pending_activations(0, 5),
+            4
+        );
+        assert_eq!(
+            MissedActivationPolicy::CatchUp(3).pending_activations(2, 1),
+            3
+        );
+        assert_eq!(
+            MissedActivationPolicy::CatchUp(3).pending_activations(4, 1),
+            4
+        );
+    }
+}
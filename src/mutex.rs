@@ -3,15 +3,37 @@
 //! This mutex is used for the safe access to the data that have to be declared as static. Access to
 //! the internal value can be only done by performing a lock on the mutex which enables critical
 //! section for the duration.
+//!
+//! Critical section can't be interrupted, so the mutex is never contended between tasklets and
+//! interrupt handlers. It can be found locked only when it's locked again while the lock is held,
+//! ex. by a driver function called both from a tasklet and from a closure that already holds the
+//! lock. Borrowing the value for the second time would be undefined behavior, so
+//! [try_lock](Mutex::try_lock) returns an error in that case, and
+//! [lock_with_timeout](Mutex::lock_with_timeout) returns an error if the mutex stays locked for
+//! the given time.
+//!
+//! To detect that, each mutex stores a lock flag, which is set and restored in the critical
+//! section of each lock, including [lock](Mutex::lock). This costs one byte of memory and two
+//! stores per lock.
+
+use core::cell::{Cell, UnsafeCell};
 
-use core::cell::UnsafeCell;
+use aerugo_hal::AerugoHal;
+
+use crate::error::RuntimeError;
+use crate::hal::Hal;
+use crate::time::Duration;
 
 /// Mutex based on the critical section.
 ///
 /// # Generic Parameters
 /// * `T` - Type of the stored value.
-#[repr(transparent)]
-pub struct Mutex<T: ?Sized>(UnsafeCell<T>);
+pub struct Mutex<T: ?Sized> {
+    /// Whether the mutex is locked.
+    locked: Cell<bool>,
+    /// Stored value.
+    value: UnsafeCell<T>,
+}
 
 /// Mutex is `Sync` because `aerugo` is a single-threaded system and critical section prevents any access
 /// to the data from interrupts. Value cannot be borrowed outside of the critical section.
@@ -24,7 +46,10 @@ impl<T> Mutex<T> {
     /// * `value` - Value to initialize the mutex with.
     #[inline(always)]
     pub const fn new(value: T) -> Self {
-        Mutex(UnsafeCell::new(value))
+        Mutex {
+            locked: Cell::new(false),
+            value: UnsafeCell::new(value),
+        }
     }
 }
 
@@ -42,14 +67,99 @@ impl<T: ?Sized> Mutex<T> {
     /// Result of the executed lambda.
     #[inline(always)]
     pub fn lock<R>(&self, f: impl FnOnce(&mut T) -> R) -> R {
-        unsafe { critical_section::with(|_| f(self.as_mut_ref())) }
+        unsafe { critical_section::with(|_| self.locked_with(f)) }
+    }
+
+    /// Gives access to the value in critical section, if the mutex is not already locked.
+    ///
+    /// Given lambda is passed a mutable reference to the value and executed in critical section,
+    /// same as in [lock](Mutex::lock). Mutex is locked only if this is called from inside of a
+    /// lambda that already holds the lock, in which case the lambda is not executed.
+    ///
+    /// # Parameters
+    /// * `f` - Lambda to execute.
+    ///
+    /// # Return
+    /// Result of the executed lambda if successful, `RuntimeError::MutexLocked` if mutex was
+    /// already locked.
+    #[inline(always)]
+    pub fn try_lock<R>(&self, f: impl FnOnce(&mut T) -> R) -> Result<R, RuntimeError> {
+        critical_section::with(|_| {
+            if self.locked.get() {
+                Err(RuntimeError::MutexLocked)
+            } else {
+                Ok(unsafe { self.locked_with(f) })
+            }
+        })
+    }
+
+    /// Gives access to the value in critical section, waiting at most given time for the mutex to
+    /// be unlocked.
+    ///
+    /// Mutex is checked with [try_lock](Mutex::try_lock) until it's unlocked or the timeout
+    /// passes, with interrupts enabled between the checks if they were enabled by the caller.
+    /// Executor is cooperative, so a mutex locked by the caller can't be released while it waits,
+    /// and the wait only bounds the time lost in that case. Mutex can be released during the wait
+    /// only by a preempting context.
+    ///
+    /// # Parameters
+    /// * `timeout` - Maximum time of waiting for the mutex.
+    /// * `f` - Lambda to execute.
+    ///
+    /// # Return
+    /// Result of the executed lambda if successful, `RuntimeError::MutexLockTimeout` if mutex was
+    /// locked for the whole `timeout`.
+    pub fn lock_with_timeout<R>(
+        &self,
+        timeout: Duration,
+        f: impl FnOnce(&mut T) -> R,
+    ) -> Result<R, RuntimeError> {
+        let wait_start = Hal::get_system_time();
+        let mut f = Some(f);
+
+        loop {
+            let result = critical_section::with(|_| {
+                if self.locked.get() {
+                    None
+                } else {
+                    f.take().map(|f| unsafe { self.locked_with(f) })
+                }
+            });
+
+            if let Some(result) = result {
+                return Ok(result);
+            }
+
+            let waited = Hal::get_system_time().checked_duration_since(wait_start);
+            if waited.is_some_and(|waited| waited >= timeout) {
+                return Err(RuntimeError::MutexLockTimeout);
+            }
+        }
+    }
+
+    /// Returns `true` if the mutex is locked.
+    #[inline(always)]
+    pub fn is_locked(&self) -> bool {
+        critical_section::with(|_| self.locked.get())
+    }
+
+    /// Executes given lambda with the mutex marked as locked.
+    ///
+    /// # Safety
+    /// This has to be called in critical section.
+    #[inline(always)]
+    unsafe fn locked_with<R>(&self, f: impl FnOnce(&mut T) -> R) -> R {
+        let was_locked = self.locked.replace(true);
+        let result = f(self.as_mut_ref());
+        self.locked.set(was_locked);
+        result
     }
 
     /// Returns a mutable reference to the stored value.
     #[inline(always)]
     #[allow(clippy::mut_from_ref)]
     unsafe fn as_mut_ref(&self) -> &mut T {
-        &mut *self.0.get()
+        &mut *self.value.get()
     }
 }
 
@@ -64,3 +174,45 @@ impl<T> From<T> for Mutex<T> {
         Mutex::new(t)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn try_lock_while_locked() {
+        let mutex = Mutex::new(1u32);
+
+        assert_eq!(mutex.try_lock(|value| *value), Ok(1));
+        assert!(!mutex.is_locked());
+
+        mutex.lock(|value| {
+            *value = 2;
+            assert!(mutex.is_locked());
+            assert_eq!(
+                mutex.try_lock(|value| *value),
+                Err(RuntimeError::MutexLocked)
+            );
+        });
+
+        assert_eq!(mutex.try_lock(|value| *value), Ok(2));
+    }
+
+    #[test]
+    fn lock_with_timeout_while_locked() {
+        let mutex = Mutex::new(1u32);
+
+        assert_eq!(
+            mutex.lock_with_timeout(Duration::millis(1), |value| *value),
+            Ok(1)
+        );
+
+        mutex.lock(|_| {
+            assert_eq!(
+                mutex.lock_with_timeout(Duration::millis(1), |value| *value),
+                Err(RuntimeError::MutexLockTimeout)
+            );
+        });
+        assert!(!mutex.is_locked());
+    }
+}
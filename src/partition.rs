@@ -0,0 +1,117 @@
+//! Time partitions.
+//!
+//! Partition is a group of tasklets that are allowed to execute only in their partition's time
+//! windows. Windows are executed one after another in a fixed order, creating a major frame,
+//! which is then repeated for the whole system lifetime.
+//!
+//! As the executor is cooperative, tasklet can't be stopped when its window ends. Instead,
+//! tasklet is not started when its longest observed execution time doesn't fit in the remaining
+//! time of the window. Executions that still end after their window (window overruns) are counted
+//! for each partition.
+//!
+//! Tasklets that are not assigned to any partition can execute in any window.
+
+use core::fmt;
+
+use crate::mutex::Mutex;
+use crate::tasklet::TaskletPtr;
+use crate::time::Duration;
+
+/// Partition ID.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct PartitionId(pub u8);
+
+impl fmt::Display for PartitionId {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+/// Time window of a partition.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct PartitionWindow {
+    /// Partition that can execute in this window.
+    pub partition: PartitionId,
+    /// Duration of the window.
+    pub duration: Duration,
+}
+
+/// Assignment of a tasklet to a partition.
+pub(crate) struct PartitionAssignment {
+    /// Assigned tasklet.
+    tasklet: TaskletPtr,
+    /// Partition the tasklet is assigned to.
+    partition: PartitionId,
+    /// Longest observed execution time of the tasklet.
+    longest_execution: Mutex<Duration>,
+    /// Time that was left in the window when the last execution was started.
+    execution_budget: Mutex<Duration>,
+    /// Number of executions that ended after their window.
+    overrun_count: Mutex<u32>,
+}
+
+impl PartitionAssignment {
+    /// Creates new partition assignment.
+    ///
+    /// # Parameters
+    /// * `tasklet` - Assigned tasklet.
+    /// * `partition` - Partition the tasklet is assigned to.
+    pub(crate) const fn new(tasklet: TaskletPtr, partition: PartitionId) -> Self {
+        PartitionAssignment {
+            tasklet,
+            partition,
+            longest_execution: Mutex::new(Duration::from_ticks(0)),
+            execution_budget: Mutex::new(Duration::from_ticks(0)),
+            overrun_count: Mutex::new(0),
+        }
+    }
+
+    /// Returns assigned tasklet.
+    pub(crate) fn tasklet(&self) -> &TaskletPtr {
+        &self.tasklet
+    }
+
+    /// Returns partition the tasklet is assigned to.
+    pub(crate) fn partition(&self) -> PartitionId {
+        self.partition
+    }
+
+    /// Returns number of window overruns.
+    pub(crate) fn overrun_count(&self) -> u32 {
+        self.overrun_count.lock(|count| *count)
+    }
+
+    /// Checks if tasklet can be started with given time left in the window.
+    ///
+    /// If it can, given time is stored as a budget for this execution.
+    ///
+    /// # Parameters
+    /// * `time_left` - Time left in the window.
+    pub(crate) fn try_start_execution(&self, time_left: Duration) -> bool {
+        let longest_execution = self.longest_execution.lock(|longest| *longest);
+
+        if longest_execution <= time_left {
+            self.execution_budget.lock(|budget| *budget = time_left);
+            true
+        } else {
+            false
+        }
+    }
+
+    /// Updates assignment with the duration of the finished execution.
+    ///
+    /// # Parameters
+    /// * `duration` - Duration of the execution.
+    pub(crate) fn finish_execution(&self, duration: Duration) {
+        self.longest_execution.lock(|longest| {
+            if duration > *longest {
+                *longest = duration;
+            }
+        });
+
+        if duration > self.execution_budget.lock(|budget| *budget) {
+            self.overrun_count
+                .lock(|count| *count = count.saturating_add(1));
+        }
+    }
+}
@@ -0,0 +1,247 @@
+//! Partition manager.
+//!
+//! This module contains partition manager. It's responsibility is to keep track of partition
+//! windows and decide which tasklets are allowed to execute at the current time.
+
+use env_parser::read_env;
+
+use crate::aerugo::Aerugo;
+use crate::error::SystemError;
+use crate::execution_monitor::ExecutionData;
+use crate::internal_list::InternalList;
+use crate::mutex::Mutex;
+use crate::partition::{PartitionAssignment, PartitionId, PartitionWindow};
+use crate::tasklet::TaskletPtr;
+use crate::time::Duration;
+use crate::time_source::TimeSource;
+
+/// List of partition windows, in order of execution.
+type PartitionWindows = InternalList<PartitionWindow, { PartitionManager::WINDOW_COUNT }>;
+/// List of tasklet assignments to partitions.
+type PartitionAssignments = InternalList<PartitionAssignment, { Aerugo::TASKLET_COUNT }>;
+
+/// Partition manager.
+///
+/// This shouldn't be created by hand by the user or anywhere else in the code.
+/// It should be used as a singleton (crate::aerugo::PARTITION_MANAGER) and shouldn't be directly
+/// accessed by any other part of the system.
+pub(crate) struct PartitionManager {
+    /// Partition windows.
+    windows: PartitionWindows,
+    /// Tasklet assignments.
+    assignments: PartitionAssignments,
+    /// Partition of the currently executed tasklet.
+    current_partition: Mutex<Option<PartitionId>>,
+    /// Time source.
+    time_source: &'static TimeSource,
+}
+
+/// It is safe assuming that lists are modified only during system initialization (before scheduler
+/// is started) and those modifications cannot be interrupted. Assignments state is guarded with
+/// [Mutex](crate::mutex::Mutex).
+unsafe impl Sync for PartitionManager {}
+
+impl PartitionManager {
+    /// Maximum number of partition windows in the major frame.
+    #[read_env("AERUGO_PARTITION_WINDOW_COUNT")]
+    pub(crate) const WINDOW_COUNT: usize = 0;
+
+    /// Creates new partition manager instance.
+    ///
+    /// # Safety
+    /// This shouldn't be called more than once.
+    pub(crate) const fn new(time_source: &'static TimeSource) -> Self {
+        PartitionManager {
+            windows: PartitionWindows::new(),
+            assignments: PartitionAssignments::new(),
+            current_partition: Mutex::new(None),
+            time_source,
+        }
+    }
+
+    /// Adds window at the end of the major frame.
+    ///
+    /// # Parameters
+    /// * `window` - Window to add.
+    ///
+    /// # Return
+    /// `()` if successful, `SystemError` otherwise.
+    ///
+    /// # Safety
+    /// This is unsafe, because it mutably borrows the list of windows.
+    /// This is safe to call during system initialization (before scheduler is started).
+    pub(crate) unsafe fn add_window(
+        &'static self,
+        window: PartitionWindow,
+    ) -> Result<(), SystemError> {
+        match self.windows.add(window) {
+            Ok(_) => Ok(()),
+            Err(_) => Err(SystemError::PartitionWindowListFull),
+        }
+    }
+
+    /// Assigns tasklet to the partition.
+    ///
+    /// # Parameters
+    /// * `tasklet` - Tasklet to assign.
+    /// * `partition` - Partition to assign tasklet to.
+    ///
+    /// # Return
+    /// `()` if successful, `SystemError` otherwise.
+    ///
+    /// # Safety
+    /// This is unsafe, because it mutably borrows the list of assignments.
+    /// This is safe to call during system initialization (before scheduler is started).
+    pub(crate) unsafe fn assign_tasklet(
+        &'static self,
+        tasklet: TaskletPtr,
+        partition: PartitionId,
+    ) -> Result<(), SystemError> {
+        if self.assignment(&tasklet).is_some() {
+            return Err(SystemError::TaskletAlreadyAssignedToPartition(
+                tasklet.get_name(),
+            ));
+        }
+
+        match self
+            .assignments
+            .add(PartitionAssignment::new(tasklet, partition))
+        {
+            Ok(_) => Ok(()),
+            Err(_) => Err(SystemError::PartitionAssignmentListFull),
+        }
+    }
+
+    /// Checks if each tasklet is assigned to a partition that has at least one window.
+    ///
+    /// # Return
+    /// `()` if configuration is valid, `SystemError` otherwise.
+    pub(crate) fn validate(&'static self) -> Result<(), SystemError> {
+        for assignment in &self.assignments {
+            let has_window = self
+                .windows
+                .iter()
+                .any(|window| window.partition == assignment.partition());
+
+            if !has_window {
+                return Err(SystemError::PartitionWithoutWindow(assignment.partition()));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Checks if given tasklet can be executed at the current time.
+    ///
+    /// Tasklet that is not assigned to any partition can always be executed. Assigned tasklet can
+    /// be executed only in its partition window, if its longest observed execution time fits in
+    /// the time left in the window.
+    ///
+    /// # Parameters
+    /// * `tasklet` - Tasklet to check.
+    pub(crate) fn can_execute(&'static self, tasklet: &TaskletPtr) -> bool {
+        let assignment = match self.assignment(tasklet) {
+            Some(assignment) => assignment,
+            None => return true,
+        };
+
+        match self.active_window() {
+            Some((partition, time_left)) if partition == assignment.partition() => {
+                assignment.try_start_execution(time_left)
+            }
+            _ => false,
+        }
+    }
+
+    /// Marks partition of the given tasklet as the currently executing one.
+    ///
+    /// # Parameters
+    /// * `tasklet` - Tasklet that is going to be executed.
+    pub(crate) fn enter_partition(&'static self, tasklet: &TaskletPtr) {
+        let partition = self
+            .assignment(tasklet)
+            .map(|assignment| assignment.partition());
+
+        self.current_partition.lock(|current| *current = partition);
+    }
+
+    /// Clears currently executing partition.
+    pub(crate) fn exit_partition(&'static self) {
+        self.current_partition.lock(|current| *current = None);
+    }
+
+    /// Returns partition of the currently executed tasklet, or `None` if no tasklet is executed,
+    /// or executed tasklet is not assigned to any partition.
+    pub(crate) fn current_partition(&'static self) -> Option<PartitionId> {
+        self.current_partition.lock(|current| *current)
+    }
+
+    /// Updates partition data with tasklet execution data.
+    ///
+    /// # Parameters
+    /// * `execution_data` - Data from the tasklet execution.
+    pub(crate) fn update(&'static self, execution_data: &ExecutionData) {
+        let assignment = self
+            .assignments
+            .iter()
+            .find(|assignment| assignment.tasklet().get_id() == *execution_data.tasklet_id());
+
+        if let (Some(assignment), Some(duration)) =
+            (assignment, execution_data.execution_duration())
+        {
+            assignment.finish_execution(duration);
+        }
+    }
+
+    /// Returns number of window overruns of tasklets in the given partition.
+    ///
+    /// # Parameters
+    /// * `partition` - Partition ID.
+    pub(crate) fn overrun_count(&'static self, partition: PartitionId) -> u32 {
+        self.assignments
+            .iter()
+            .filter(|assignment| assignment.partition() == partition)
+            .map(|assignment| assignment.overrun_count())
+            .sum()
+    }
+
+    /// Returns assignment of the given tasklet.
+    ///
+    /// # Parameters
+    /// * `tasklet` - Tasklet to find assignment for.
+    fn assignment(&'static self, tasklet: &TaskletPtr) -> Option<&'static PartitionAssignment> {
+        self.assignments
+            .iter()
+            .find(|assignment| assignment.tasklet() == tasklet)
+    }
+
+    /// Returns partition of the currently active window and time left in that window, or `None`
+    /// if no windows are configured.
+    fn active_window(&'static self) -> Option<(PartitionId, Duration)> {
+        let major_frame: u64 = self
+            .windows
+            .iter()
+            .map(|window| window.duration.ticks())
+            .sum();
+        if major_frame == 0 {
+            return None;
+        }
+
+        let mut frame_time = self.time_source.elapsed_time().ticks() % major_frame;
+
+        for window in &self.windows {
+            let window_duration = window.duration.ticks();
+
+            if frame_time < window_duration {
+                return Some((
+                    window.partition,
+                    Duration::from_ticks(window_duration - frame_time),
+                ));
+            }
+
+            frame_time -= window_duration;
+        }
+
+        None
+    }
+}
@@ -0,0 +1,33 @@
+//! Ports used for exchanging data between partitions.
+//!
+//! Port connects exactly two partitions. Only tasklets from the source partition can write to the
+//! port, and only tasklets from the destination partition can read from it. Access is checked by
+//! the system on each operation, using the partition of the currently executed tasklet.
+//!
+//! There are two types of ports:
+//! * [SamplingPort] - Stores only the latest written value, together with its age.
+//! * [QueuingPort] - Stores written values in a FIFO queue.
+
+mod queuing_port;
+mod sampling_port;
+
+pub use self::queuing_port::QueuingPort;
+pub use self::sampling_port::{SampledValue, SamplingPort};
+
+use crate::aerugo::Aerugo;
+use crate::error::RuntimeError;
+use crate::partition::PartitionId;
+
+/// Checks if currently executed tasklet belongs to the given partition.
+///
+/// # Parameters
+/// * `partition` - Partition that is allowed to access the port.
+///
+/// # Return
+/// `()` if access is allowed, `RuntimeError` otherwise.
+fn check_access(partition: PartitionId) -> Result<(), RuntimeError> {
+    match Aerugo::current_partition() {
+        Some(current_partition) if current_partition == partition => Ok(()),
+        _ => Err(RuntimeError::PartitionPortAccessDenied),
+    }
+}
@@ -0,0 +1,82 @@
+//! Queuing port.
+
+use heapless::Deque;
+
+use crate::error::RuntimeError;
+use crate::mutex::Mutex;
+use crate::partition::PartitionId;
+use crate::partition_port::check_access;
+
+/// Queuing port.
+///
+/// Queuing port stores written values in a FIFO queue. Each value can be received only once.
+///
+/// Port should be a static variable.
+///
+/// # Generic Parameters
+/// * `T` - Type of the queued values.
+/// * `N` - Size of the queue.
+pub struct QueuingPort<T, const N: usize> {
+    /// Partition that can send to this port.
+    source: PartitionId,
+    /// Partition that can receive from this port.
+    destination: PartitionId,
+    /// Queued values.
+    queue: Mutex<Deque<T, N>>,
+}
+
+impl<T, const N: usize> QueuingPort<T, N> {
+    /// Creates new queuing port.
+    ///
+    /// # Parameters
+    /// * `source` - Partition that can send to this port.
+    /// * `destination` - Partition that can receive from this port.
+    pub const fn new(source: PartitionId, destination: PartitionId) -> Self {
+        QueuingPort {
+            source,
+            destination,
+            queue: Mutex::new(Deque::new()),
+        }
+    }
+
+    /// Sends value to the port.
+    ///
+    /// # Parameters
+    /// * `value` - Value to send.
+    ///
+    /// # Return
+    /// `()` if successful, `RuntimeError` if not called from the source partition, or if the queue
+    /// is full.
+    pub fn send(&'static self, value: T) -> Result<(), RuntimeError> {
+        check_access(self.source)?;
+
+        self.queue.lock(|queue| match queue.push_back(value) {
+            Ok(_) => Ok(()),
+            Err(_) => Err(RuntimeError::DataQueueFull),
+        })
+    }
+
+    /// Receives the oldest value from the port.
+    ///
+    /// # Return
+    /// Oldest value, or `None` if queue is empty, if successful. `RuntimeError` if not called from
+    /// the destination partition.
+    pub fn receive(&'static self) -> Result<Option<T>, RuntimeError> {
+        check_access(self.destination)?;
+
+        Ok(self.queue.lock(|queue| queue.pop_front()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deny_access_outside_of_partition() {
+        static PORT: QueuingPort<u8, 2> = QueuingPort::new(PartitionId(0), PartitionId(1));
+
+        assert_eq!(PORT.send(1), Err(RuntimeError::PartitionPortAccessDenied));
+        assert_eq!(PORT.receive(), Err(RuntimeError::PartitionPortAccessDenied));
+    }
+}
@@ -0,0 +1,100 @@
+//! Sampling port.
+
+use crate::aerugo::Aerugo;
+use crate::error::RuntimeError;
+use crate::mutex::Mutex;
+use crate::partition::PartitionId;
+use crate::partition_port::check_access;
+use crate::time::{Duration, Instant};
+
+/// Sampling port.
+///
+/// Sampling port stores only the latest written value. Each write overwrites the previous value.
+/// Value is valid for the configured validity period after it was written.
+///
+/// Port should be a static variable.
+///
+/// # Generic Parameters
+/// * `T` - Type of the sampled value.
+pub struct SamplingPort<T: Copy> {
+    /// Partition that can write to this port.
+    source: PartitionId,
+    /// Partition that can read from this port.
+    destination: PartitionId,
+    /// Period for which written value is valid.
+    validity_period: Duration,
+    /// Latest value, with the time it was written.
+    sample: Mutex<Option<(T, Instant)>>,
+}
+
+impl<T: Copy> SamplingPort<T> {
+    /// Creates new sampling port.
+    ///
+    /// # Parameters
+    /// * `source` - Partition that can write to this port.
+    /// * `destination` - Partition that can read from this port.
+    /// * `validity_period` - Period for which written value is valid.
+    pub const fn new(
+        source: PartitionId,
+        destination: PartitionId,
+        validity_period: Duration,
+    ) -> Self {
+        SamplingPort {
+            source,
+            destination,
+            validity_period,
+            sample: Mutex::new(None),
+        }
+    }
+
+    /// Writes new value to the port.
+    ///
+    /// # Parameters
+    /// * `value` - Value to write.
+    ///
+    /// # Return
+    /// `()` if successful, `RuntimeError` if not called from the source partition.
+    pub fn write(&'static self, value: T) -> Result<(), RuntimeError> {
+        check_access(self.source)?;
+
+        let timestamp = Aerugo::system_time();
+        self.sample
+            .lock(|sample| *sample = Some((value, timestamp)));
+
+        Ok(())
+    }
+
+    /// Reads the latest value from the port.
+    ///
+    /// # Return
+    /// Latest value, or `None` if no value was written yet, if successful. `RuntimeError` if not
+    /// called from the destination partition.
+    pub fn read(&'static self) -> Result<Option<SampledValue<T>>, RuntimeError> {
+        check_access(self.destination)?;
+
+        let sample = match self.sample.lock(|sample| *sample) {
+            Some(sample) => sample,
+            None => return Ok(None),
+        };
+
+        let (value, timestamp) = sample;
+        let age = Aerugo::system_time() - timestamp;
+
+        Ok(Some(SampledValue {
+            value,
+            age,
+            valid: age <= self.validity_period,
+        }))
+    }
+}
+
+/// Value read from the sampling port.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct SampledValue<T> {
+    /// Sampled value.
+    pub value: T,
+    /// Time since the value was written.
+    pub age: Duration,
+    /// Whether value was read within its validity period.
+    pub valid: bool,
+}
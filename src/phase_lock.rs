@@ -0,0 +1,103 @@
+//! Phase lock of cyclic execution to an external timebase.
+//!
+//! Activations of a cyclic tasklet form a grid with its period, aligned to the system start.
+//! Devices sampling synchronously (ex. on a CAN sync message, GPS pulse-per-second or a frame
+//! pulse from the bus master) need their grids aligned to a common, external timebase instead.
+//! Interrupt handler receiving the sync signal passes its timestamp to
+//! [sync_cyclic_execution](crate::api::RuntimeApi::sync_cyclic_execution), and the grid is shifted
+//! so the activations occur at configured phase after the sync signal (and whole periods after
+//! that). Shift applied on a single sync is limited, so a missing or spurious sync signal can't
+//! move the activations by more than the configured correction.
+
+use crate::time::{Duration, Instant};
+
+/// Configuration of the phase lock.
+#[derive(Copy, Clone)]
+pub(crate) struct PhaseLock {
+    /// Time from the sync signal to the activation.
+    phase: Duration,
+    /// Maximum shift of the activations on a single sync.
+    max_correction: Duration,
+}
+
+impl PhaseLock {
+    /// Creates new phase lock configuration.
+    ///
+    /// # Parameters
+    /// * `phase` - Time from the sync signal to the activation.
+    /// * `max_correction` - Maximum shift of the activations on a single sync.
+    pub(crate) const fn new(phase: Duration, max_correction: Duration) -> Self {
+        PhaseLock {
+            phase,
+            max_correction,
+        }
+    }
+
+    /// Calculates next activation time shifted towards the external timebase.
+    ///
+    /// Grid is shifted in the direction of the nearest activation of the external grid, so it's
+    /// never shifted by more than half of the period.
+    ///
+    /// # Parameters
+    /// * `next` - Next activation time.
+    /// * `sync_time` - Time of the sync signal.
+    /// * `period` - Period of the activations.
+    ///
+    /// # Return
+    /// Next activation time, and `true` if the grid is locked after the shift (whole phase error
+    /// was corrected).
+    pub(crate) fn correct(
+        &self,
+        next: Instant,
+        sync_time: Instant,
+        period: Duration,
+    ) -> (Instant, bool) {
+        let period = period.ticks() as i64;
+        if period == 0 {
+            return (next, true);
+        }
+
+        let target = (sync_time + self.phase).ticks() as i64;
+        let offset = (next.ticks() as i64 - target).rem_euclid(period);
+        let error = if offset > period / 2 {
+            offset - period
+        } else {
+            offset
+        };
+
+        let max_correction = self.max_correction.ticks() as i64;
+        let correction = error.clamp(-max_correction, max_correction);
+        let corrected = (next.ticks() as i64 - correction).max(0) as u64;
+
+        (Instant::from_ticks(corrected), correction == error)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn correct_towards_sync() {
+        let period = Duration::from_ticks(1000);
+        let lock = PhaseLock::new(Duration::from_ticks(100), Duration::from_ticks(50));
+
+        // Activation 30 ticks after the target is moved back.
+        let (next, locked) =
+            lock.correct(Instant::from_ticks(5130), Instant::from_ticks(3000), period);
+        assert_eq!(next, Instant::from_ticks(5100));
+        assert!(locked);
+
+        // Activation 200 ticks before the target is moved forward by the maximum correction.
+        let (next, locked) =
+            lock.correct(Instant::from_ticks(4900), Instant::from_ticks(4000), period);
+        assert_eq!(next, Instant::from_ticks(4950));
+        assert!(!locked);
+
+        // Activation that is already locked is not moved.
+        let (next, locked) =
+            lock.correct(Instant::from_ticks(7100), Instant::from_ticks(4000), period);
+        assert_eq!(next, Instant::from_ticks(7100));
+        assert!(locked);
+    }
+}
@@ -0,0 +1,179 @@
+//! Bridge forwarding messages between queues.
+//!
+//! Bridge is a system tasklet that takes messages from the source queue, optionally converts them
+//! and sends them to the destination queue. It's used to split or merge data paths between
+//! subsystems without writing forwarding tasklets by hand.
+//!
+//! Forwarding can be rate limited. Messages exceeding the limit are dropped, the same as messages
+//! that don't fit in the destination queue or are discarded by the transform function. Number of
+//! forwarded and dropped messages is available via [QueueBridgeStorage].
+
+mod queue_bridge_config;
+mod queue_bridge_storage;
+
+pub use self::queue_bridge_config::QueueBridgeConfig;
+pub use self::queue_bridge_storage::QueueBridgeStorage;
+
+pub(crate) use self::queue_bridge_storage::QueueBridgeStats;
+
+use crate::api::RuntimeApi;
+use crate::message_queue::MessageQueueHandle;
+use crate::rate_limit::RateLimiter;
+use crate::time::Instant;
+
+/// Bridge forwarding messages between queues.
+///
+/// This is a context of the bridge tasklet.
+///
+/// # Generic Parameters
+/// * `T` - Type of the messages in the source queue.
+/// * `U` - Type of the messages in the destination queue.
+/// * `M` - Size of the destination queue.
+pub(crate) struct QueueBridge<T: 'static, U: 'static, const M: usize> {
+    /// Function converting forwarded messages.
+    transform: fn(T) -> Option<U>,
+    /// Destination queue.
+    destination: MessageQueueHandle<U, M>,
+    /// Limiter of forwarded messages.
+    rate_limiter: RateLimiter,
+    /// Bridge statistics.
+    stats: &'static QueueBridgeStats,
+}
+
+impl<T, U, const M: usize> QueueBridge<T, U, M> {
+    /// Creates new bridge.
+    ///
+    /// # Parameters
+    /// * `config` - Bridge configuration.
+    /// * `destination` - Destination queue.
+    /// * `stats` - Bridge statistics.
+    pub(crate) fn new(
+        config: &QueueBridgeConfig<T, U>,
+        destination: MessageQueueHandle<U, M>,
+        stats: &'static QueueBridgeStats,
+    ) -> Self {
+        QueueBridge {
+            transform: config.transform,
+            destination,
+            rate_limiter: RateLimiter::new(config.rate_limit),
+            stats,
+        }
+    }
+
+    /// Forwards single message.
+    ///
+    /// # Parameters
+    /// * `message` - Message from the source queue.
+    /// * `time` - Current system time.
+    fn forward(&mut self, message: T, time: Instant) {
+        let forwarded = self.rate_limiter.allow(time)
+            && (self.transform)(message)
+                .map(|message| self.destination.send_data(message).is_ok())
+                .unwrap_or(false);
+
+        if forwarded {
+            self.stats.increment_forwarded();
+        } else {
+            self.stats.increment_dropped();
+        }
+    }
+}
+
+/// Step function of the bridge tasklet.
+///
+/// # Parameters
+/// * `message` - Message from the source queue.
+/// * `bridge` - Bridge context.
+/// * `api` - System runtime API.
+pub(crate) fn bridge_step<T, U, const M: usize>(
+    message: T,
+    bridge: &mut QueueBridge<T, U, M>,
+    api: &'static dyn RuntimeApi,
+) {
+    bridge.forward(message, api.get_system_time());
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::data_provider::DataProvider;
+    use crate::message_queue::{MessageQueueStorage, OverflowPolicy};
+    use crate::rate_limit::RateLimit;
+    use crate::time::Duration;
+
+    fn drain<T, const N: usize>(queue: &MessageQueueHandle<T, N>) -> heapless::Vec<T, N> {
+        core::iter::from_fn(|| queue.queue().get_data()).collect()
+    }
+
+    fn skip_three(message: u8) -> Option<u16> {
+        (message != 3).then_some(message as u16 * 10)
+    }
+
+    #[test]
+    fn forward_in_order() {
+        static DESTINATION: MessageQueueStorage<u16, 4> = MessageQueueStorage::new();
+        static BRIDGE: QueueBridgeStorage<u8, u16, 4> = QueueBridgeStorage::new();
+        unsafe { DESTINATION.init(OverflowPolicy::Reject).unwrap() };
+        let destination = DESTINATION.create_handle().unwrap();
+
+        let config = QueueBridgeConfig::with_transform("Bridge", skip_three);
+        let mut bridge = QueueBridge::new(&config, destination, BRIDGE.stats());
+
+        // Message 3 is discarded by the transform, and message 5 doesn't fit in the destination.
+        for message in 1..=5 {
+            bridge.forward(message, Instant::from_ticks(0));
+        }
+
+        assert_eq!(drain(&destination), [10, 20, 40]);
+        assert_eq!(BRIDGE.forwarded_count(), 3);
+        assert_eq!(BRIDGE.dropped_count(), 2);
+    }
+
+    #[test]
+    fn overwrite_oldest_in_destination() {
+        static DESTINATION: MessageQueueStorage<u8, 3> = MessageQueueStorage::new();
+        static BRIDGE: QueueBridgeStorage<u8, u8, 3> = QueueBridgeStorage::new();
+        unsafe { DESTINATION.init(OverflowPolicy::OverwriteOldest).unwrap() };
+        let destination = DESTINATION.create_handle().unwrap();
+
+        let config = QueueBridgeConfig::default();
+        let mut bridge = QueueBridge::new(&config, destination, BRIDGE.stats());
+
+        for message in 1..=5 {
+            bridge.forward(message, Instant::from_ticks(0));
+        }
+
+        // Destination keeps the freshest messages, so nothing is dropped by the bridge.
+        assert_eq!(drain(&destination), [4, 5]);
+        assert_eq!(BRIDGE.forwarded_count(), 5);
+        assert_eq!(BRIDGE.dropped_count(), 0);
+    }
+
+    #[test]
+    fn rate_limited_forwarding() {
+        static DESTINATION: MessageQueueStorage<u8, 8> = MessageQueueStorage::new();
+        static BRIDGE: QueueBridgeStorage<u8, u8, 8> = QueueBridgeStorage::new();
+        unsafe { DESTINATION.init(OverflowPolicy::Reject).unwrap() };
+        let destination = DESTINATION.create_handle().unwrap();
+
+        let config = QueueBridgeConfig {
+            rate_limit: Some(RateLimit {
+                max_count: 2,
+                period: Duration::millis(100),
+            }),
+            ..QueueBridgeConfig::default()
+        };
+        let mut bridge = QueueBridge::new(&config, destination, BRIDGE.stats());
+
+        let start = Instant::from_ticks(0);
+        bridge.forward(1, start);
+        bridge.forward(2, start + Duration::millis(10));
+        bridge.forward(3, start + Duration::millis(20));
+        bridge.forward(4, start + Duration::millis(100));
+
+        assert_eq!(drain(&destination), [1, 2, 4]);
+        assert_eq!(BRIDGE.forwarded_count(), 3);
+        assert_eq!(BRIDGE.dropped_count(), 1);
+    }
+}
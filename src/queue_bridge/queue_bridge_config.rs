@@ -0,0 +1,46 @@
+//! Configuration for creating queue bridges.
+
+use crate::rate_limit::RateLimit;
+
+/// Configuration for queue bridges.
+///
+/// # Generic Parameters
+/// * `T` - Type of the messages in the source queue.
+/// * `U` - Type of the messages in the destination queue.
+pub struct QueueBridgeConfig<T, U> {
+    /// Name of the bridge tasklet.
+    pub name: &'static str,
+    /// Priority of the bridge tasklet.
+    pub priority: u8,
+    /// Function converting forwarded messages. Messages for which it returns `None` are dropped.
+    pub transform: fn(T) -> Option<U>,
+    /// Limit of forwarded messages, `None` if all messages should be forwarded.
+    pub rate_limit: Option<RateLimit>,
+}
+
+impl<T, U> QueueBridgeConfig<T, U> {
+    /// Creates new bridge configuration with given transform function.
+    ///
+    /// # Parameters
+    /// * `name` - Name of the bridge tasklet.
+    /// * `transform` - Function converting forwarded messages.
+    pub fn with_transform(name: &'static str, transform: fn(T) -> Option<U>) -> Self {
+        QueueBridgeConfig {
+            name,
+            priority: 0,
+            transform,
+            rate_limit: None,
+        }
+    }
+}
+
+impl<T> Default for QueueBridgeConfig<T, T> {
+    fn default() -> Self {
+        QueueBridgeConfig {
+            name: "MISSING_BRIDGE_NAME",
+            priority: 0,
+            transform: Some,
+            rate_limit: None,
+        }
+    }
+}
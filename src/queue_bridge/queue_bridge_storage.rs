@@ -0,0 +1,89 @@
+//! Static storage for [queue bridge](crate::queue_bridge::QueueBridge).
+//!
+//! This module contains a queue bridge storage, which is a statically allocated memory that will
+//! store the bridge tasklet and bridge statistics for the duration of the system life.
+
+use super::QueueBridge;
+
+use crate::mutex::Mutex;
+use crate::tasklet::TaskletStorage;
+
+/// Statistics of the queue bridge.
+pub(crate) struct QueueBridgeStats {
+    /// Number of forwarded messages.
+    forwarded: Mutex<u32>,
+    /// Number of dropped messages.
+    dropped: Mutex<u32>,
+}
+
+impl QueueBridgeStats {
+    /// Creates new, empty statistics.
+    const fn new() -> Self {
+        QueueBridgeStats {
+            forwarded: Mutex::new(0),
+            dropped: Mutex::new(0),
+        }
+    }
+
+    /// Increments number of forwarded messages.
+    pub(crate) fn increment_forwarded(&self) {
+        self.forwarded.lock(|count| *count = count.wrapping_add(1));
+    }
+
+    /// Increments number of dropped messages.
+    pub(crate) fn increment_dropped(&self) {
+        self.dropped.lock(|count| *count = count.wrapping_add(1));
+    }
+}
+
+/// Structure containing memory for queue bridge creation.
+///
+/// Storage shall be a static variable and shall be initialized only once, before the system is started.
+///
+/// # Generic Parameters
+/// * `T` - Type of the messages in the source queue.
+/// * `U` - Type of the messages in the destination queue.
+/// * `M` - Size of the destination queue.
+pub struct QueueBridgeStorage<T: 'static, U: 'static, const M: usize> {
+    /// Storage for the bridge tasklet.
+    tasklet_storage: TaskletStorage<T, QueueBridge<T, U, M>, 0>,
+    /// Bridge statistics.
+    stats: QueueBridgeStats,
+}
+
+impl<T: 'static, U: 'static, const M: usize> QueueBridgeStorage<T, U, M> {
+    /// Creates new storage.
+    pub const fn new() -> Self {
+        QueueBridgeStorage {
+            tasklet_storage: TaskletStorage::new(),
+            stats: QueueBridgeStats::new(),
+        }
+    }
+
+    /// Returns initialization status of this storage.
+    pub fn is_initialized(&'static self) -> bool {
+        self.tasklet_storage.is_initialized()
+    }
+
+    /// Returns number of messages forwarded by the bridge.
+    pub fn forwarded_count(&'static self) -> u32 {
+        self.stats.forwarded.lock(|count| *count)
+    }
+
+    /// Returns number of messages dropped by the bridge.
+    pub fn dropped_count(&'static self) -> u32 {
+        self.stats.dropped.lock(|count| *count)
+    }
+
+    /// Returns storage for the bridge tasklet.
+    pub(crate) fn tasklet_storage(
+        &'static self,
+    ) -> &'static TaskletStorage<T, QueueBridge<T, U, M>, 0> {
+        &self.tasklet_storage
+    }
+
+    /// Returns bridge statistics.
+    pub(crate) fn stats(&'static self) -> &'static QueueBridgeStats {
+        &self.stats
+    }
+}
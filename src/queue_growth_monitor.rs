@@ -0,0 +1,190 @@
+//! Monitor of the message queue occupancy growth.
+//!
+//! Consumer of a message queue that is too slow, or is not executed at all (ex. it's suspended,
+//! its condition is never met or it hangs in a lower partition), doesn't cause any error until the
+//! queue overflows, and with the overflow policy that drops data not even then. Monitored queue is
+//! checked in each pass of the executor loop, and when its occupancy grows without ever dropping
+//! for longer than the configured horizon, configured event is emitted and the queue name is
+//! logged. Queue is flagged once per growth, and can be flagged again after its occupancy drops.
+
+use heapless::Vec;
+
+use crate::aerugo::Aerugo;
+use crate::error::SystemError;
+use crate::event::Event;
+use crate::mutex::Mutex;
+use crate::time::Duration;
+
+/// List of monitored queues. Each monitored queue has a consumer, so there are no more of them
+/// than tasklets.
+type MonitoredList = Vec<MonitoredQueue, { Aerugo::TASKLET_COUNT }>;
+
+/// Queue which occupancy can be monitored.
+pub(crate) trait QueueOccupancy: Sync {
+    /// Returns number of elements waiting in the queue.
+    fn occupancy(&self) -> usize;
+}
+
+/// Queue monitored by the growth monitor.
+struct MonitoredQueue {
+    /// Name of the queue.
+    #[cfg_attr(not(feature = "log"), allow(dead_code))]
+    name: &'static str,
+    /// Monitored queue.
+    queue: &'static dyn QueueOccupancy,
+    /// Time of growth after which the queue is flagged.
+    horizon: Duration,
+    /// Event emitted when the queue is flagged.
+    event: &'static Event,
+    /// Occupancy of the queue at the last check.
+    last_occupancy: usize,
+    /// Time when the current growth started, `None` if occupancy isn't growing.
+    growth_start: Option<Duration>,
+    /// Whether the current growth was already flagged.
+    flagged: bool,
+}
+
+impl MonitoredQueue {
+    /// Updates growth of the queue with its current occupancy.
+    ///
+    /// # Parameters
+    /// * `occupancy` - Current occupancy of the queue.
+    /// * `time` - Time elapsed since system start.
+    ///
+    /// # Return
+    /// `true` if the queue has just been flagged, `false` otherwise.
+    fn update(&mut self, occupancy: usize, time: Duration) -> bool {
+        if occupancy < self.last_occupancy || occupancy == 0 {
+            self.growth_start = None;
+            self.flagged = false;
+        } else if occupancy > self.last_occupancy {
+            self.growth_start.get_or_insert(time);
+        }
+        self.last_occupancy = occupancy;
+
+        match self.growth_start {
+            Some(start) if !self.flagged && time >= start + self.horizon => {
+                self.flagged = true;
+                true
+            }
+            _ => false,
+        }
+    }
+}
+
+/// Monitor of the message queue occupancy growth.
+///
+/// This shouldn't be created by hand by the user or anywhere else in the code.
+/// It should be used as a singleton (crate::aerugo::QUEUE_GROWTH_MONITOR) and shouldn't be directly
+/// accessed by any other part of the system.
+pub(crate) struct QueueGrowthMonitor {
+    /// Monitored queues.
+    queues: Mutex<MonitoredList>,
+}
+
+impl QueueGrowthMonitor {
+    /// Creates new queue growth monitor instance.
+    ///
+    /// # Safety
+    /// This shouldn't be called more than once.
+    pub(crate) const fn new() -> Self {
+        QueueGrowthMonitor {
+            queues: Mutex::new(Vec::new()),
+        }
+    }
+
+    /// Adds queue to the monitored queues.
+    ///
+    /// # Parameters
+    /// * `name` - Name of the queue.
+    /// * `queue` - Monitored queue.
+    /// * `horizon` - Time of growth after which the queue is flagged.
+    /// * `event` - Event emitted when the queue is flagged.
+    ///
+    /// # Return
+    /// `()` if successful, `SystemError` otherwise.
+    pub(crate) fn monitor(
+        &'static self,
+        name: &'static str,
+        queue: &'static dyn QueueOccupancy,
+        horizon: Duration,
+        event: &'static Event,
+    ) -> Result<(), SystemError> {
+        self.queues.lock(|queues| {
+            queues
+                .push(MonitoredQueue {
+                    name,
+                    queue,
+                    horizon,
+                    event,
+                    last_occupancy: 0,
+                    growth_start: None,
+                    flagged: false,
+                })
+                .map_err(|_| SystemError::QueueMonitorListFull)
+        })
+    }
+
+    /// Checks growth of all monitored queues, and flags the ones that grow for longer than their
+    /// horizon.
+    ///
+    /// # Parameters
+    /// * `time` - Time elapsed since system start.
+    pub(crate) fn check(&'static self, time: Duration) {
+        self.queues.lock(|queues| {
+            for queue in queues.iter_mut() {
+                if queue.update(queue.queue.occupancy(), time) {
+                    crate::logln!(
+                        "Aerugo: queue '{}' grows for {} with {} waiting elements",
+                        queue.name,
+                        queue.horizon,
+                        queue.last_occupancy
+                    );
+                    queue.event.emit();
+                }
+            }
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct FixedQueue;
+
+    impl QueueOccupancy for FixedQueue {
+        fn occupancy(&self) -> usize {
+            0
+        }
+    }
+
+    #[test]
+    fn flags_monotonic_growth() {
+        static QUEUE: FixedQueue = FixedQueue;
+        static EVENT: Event = Event::new(1);
+
+        let mut queue = MonitoredQueue {
+            name: "Queue",
+            queue: &QUEUE,
+            horizon: Duration::millis(10),
+            event: &EVENT,
+            last_occupancy: 0,
+            growth_start: None,
+            flagged: false,
+        };
+
+        assert!(!queue.update(1, Duration::millis(0)));
+        assert!(!queue.update(1, Duration::millis(5)));
+        assert!(!queue.update(2, Duration::millis(8)));
+        assert!(queue.update(3, Duration::millis(10)));
+        // Growth is flagged once.
+        assert!(!queue.update(4, Duration::millis(20)));
+
+        // Drop of the occupancy restarts the growth.
+        assert!(!queue.update(2, Duration::millis(21)));
+        assert!(!queue.update(3, Duration::millis(22)));
+        assert!(!queue.update(3, Duration::millis(31)));
+        assert!(queue.update(3, Duration::millis(32)));
+    }
+}
@@ -0,0 +1,85 @@
+//! Limiting the number of operations in a period of time.
+
+use crate::time::{Duration, Instant};
+
+/// Limit of the number of operations performed in a period of time.
+#[derive(Copy, Clone)]
+pub struct RateLimit {
+    /// Maximum number of operations performed in a single period.
+    pub max_count: u32,
+    /// Length of the period.
+    pub period: Duration,
+}
+
+/// Rate limiter, checking operations against the [RateLimit].
+///
+/// Periods start with the first operation performed after the previous period has ended.
+pub(crate) struct RateLimiter {
+    /// Applied limit, `None` if operations are not limited.
+    limit: Option<RateLimit>,
+    /// Start of the current period.
+    period_start: Option<Instant>,
+    /// Number of operations performed in the current period.
+    period_count: u32,
+}
+
+impl RateLimiter {
+    /// Creates new rate limiter.
+    ///
+    /// # Parameters
+    /// * `limit` - Applied limit, `None` if operations are not limited.
+    pub(crate) const fn new(limit: Option<RateLimit>) -> Self {
+        RateLimiter {
+            limit,
+            period_start: None,
+            period_count: 0,
+        }
+    }
+
+    /// Checks if operation can be performed within the limit, and counts it if so.
+    ///
+    /// # Parameters
+    /// * `time` - Current system time.
+    ///
+    /// # Return
+    /// `true` if operation can be performed, `false` otherwise.
+    pub(crate) fn allow(&mut self, time: Instant) -> bool {
+        let limit = match self.limit {
+            Some(limit) => limit,
+            None => return true,
+        };
+
+        match self.period_start {
+            Some(period_start) if time < period_start + limit.period => (),
+            _ => {
+                self.period_start = Some(time);
+                self.period_count = 0;
+            }
+        }
+
+        if self.period_count < limit.max_count {
+            self.period_count += 1;
+            true
+        } else {
+            false
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn limit_in_period() {
+        let mut limiter = RateLimiter::new(Some(RateLimit {
+            max_count: 2,
+            period: Duration::millis(100),
+        }));
+
+        assert!(limiter.allow(Instant::from_ticks(0)));
+        assert!(limiter.allow(Instant::from_ticks(10)));
+        assert!(!limiter.allow(Instant::from_ticks(50)));
+        assert!(limiter.allow(Instant::from_ticks(0) + Duration::millis(100)));
+    }
+}
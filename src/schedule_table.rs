@@ -0,0 +1,301 @@
+//! Time-triggered schedule table.
+//!
+//! Schedule table is a static, fully deterministic schedule of tasklet executions. Major frame is
+//! divided into minor frames of equal length, and is repeated for the whole system lifetime. Each
+//! tasklet subscribed to the schedule table has a list of slots, each of them being a window at
+//! given offset in given minor frame. Tasklet is woken up once at the start of each of its slots,
+//! independently of any data, so the order and timing of executions is fixed during the system
+//! configuration. Slots of different tasklets can't overlap.
+//!
+//! As the executor is cooperative, tasklet can't be stopped when its slot ends. Instead,
+//! executions that end after their slot window (slot overruns) are counted for each tasklet.
+
+use crate::aerugo::Aerugo;
+use crate::data_provider::DataProvider;
+use crate::manifest::DataSourceKind;
+use crate::mutex::Mutex;
+use crate::tasklet::{TaskletPtr, TaskletStatus};
+use crate::time::Duration;
+
+/// Schedule table configuration.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct ScheduleTableConfig {
+    /// Duration of a minor frame.
+    pub minor_frame: Duration,
+    /// Number of minor frames in the major frame.
+    pub minor_frame_count: u32,
+}
+
+impl ScheduleTableConfig {
+    /// Returns duration of the major frame.
+    pub(crate) fn major_frame(&self) -> Duration {
+        Duration::from_ticks(self.minor_frame.ticks() * self.minor_frame_count as u64)
+    }
+}
+
+/// Time slot of the tasklet in the schedule table.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct ScheduleSlot {
+    /// Index of the minor frame, starting from 0.
+    pub minor_frame: u32,
+    /// Offset of the slot start from the start of the minor frame.
+    pub offset: Duration,
+    /// Duration of the slot window.
+    pub window: Duration,
+}
+
+impl ScheduleSlot {
+    /// Returns start of the slot since the start of the major frame.
+    ///
+    /// # Parameters
+    /// * `config` - Schedule table configuration.
+    pub(crate) fn start(&self, config: &ScheduleTableConfig) -> Duration {
+        Duration::from_ticks(
+            config.minor_frame.ticks() * self.minor_frame as u64 + self.offset.ticks(),
+        )
+    }
+
+    /// Returns end of the slot since the start of the major frame.
+    ///
+    /// # Parameters
+    /// * `config` - Schedule table configuration.
+    pub(crate) fn end(&self, config: &ScheduleTableConfig) -> Duration {
+        self.start(config) + self.window
+    }
+
+    /// Checks if slot is not empty and fits in its minor frame.
+    ///
+    /// # Parameters
+    /// * `config` - Schedule table configuration.
+    pub(crate) fn is_valid(&self, config: &ScheduleTableConfig) -> bool {
+        self.minor_frame < config.minor_frame_count
+            && self.window.ticks() > 0
+            && self.offset + self.window <= config.minor_frame
+    }
+
+    /// Checks if slot overlaps with the other one.
+    ///
+    /// # Parameters
+    /// * `other` - Other slot.
+    /// * `config` - Schedule table configuration.
+    pub(crate) fn overlaps(&self, other: &ScheduleSlot, config: &ScheduleTableConfig) -> bool {
+        self.start(config) < other.end(config) && other.start(config) < self.end(config)
+    }
+}
+
+/// Release of the tasklet in one of its slots.
+#[derive(Copy, Clone, Eq, PartialEq)]
+struct SlotRelease {
+    /// Number of the major frame since the system start.
+    major_frame: u64,
+    /// Index of the slot.
+    slot: usize,
+    /// End of the slot window, as time since the system start.
+    window_end: Duration,
+}
+
+/// Tasklet subscribed to the schedule table.
+pub(crate) struct ScheduledTasklet {
+    /// Subscribed tasklet.
+    tasklet: TaskletPtr,
+    /// Slots of the tasklet.
+    slots: &'static [ScheduleSlot],
+    /// Last release of the tasklet.
+    last_release: Mutex<Option<SlotRelease>>,
+    /// `true` if tasklet was released, but its execution hasn't started yet.
+    pending: Mutex<bool>,
+    /// Number of executions that ended after their slot window.
+    overrun_count: Mutex<u32>,
+}
+
+impl ScheduledTasklet {
+    /// Creates new instance.
+    ///
+    /// # Parameters
+    /// * `tasklet` - Subscribed tasklet.
+    /// * `slots` - Slots of the tasklet.
+    pub(crate) const fn new(tasklet: TaskletPtr, slots: &'static [ScheduleSlot]) -> Self {
+        ScheduledTasklet {
+            tasklet,
+            slots,
+            last_release: Mutex::new(None),
+            pending: Mutex::new(false),
+            overrun_count: Mutex::new(0),
+        }
+    }
+
+    /// Returns subscribed tasklet.
+    pub(crate) fn tasklet(&self) -> &TaskletPtr {
+        &self.tasklet
+    }
+
+    /// Returns slots of the tasklet.
+    pub(crate) fn slots(&self) -> &'static [ScheduleSlot] {
+        self.slots
+    }
+
+    /// Returns number of slot overruns.
+    pub(crate) fn overrun_count(&self) -> u32 {
+        self.overrun_count.lock(|count| *count)
+    }
+
+    /// Wakes the tasklet if one of its slots has started and wasn't released yet.
+    ///
+    /// # Parameters
+    /// * `config` - Schedule table configuration.
+    /// * `elapsed_time` - Time since the system start.
+    pub(crate) fn wake_if_slot_started(
+        &self,
+        config: &ScheduleTableConfig,
+        elapsed_time: Duration,
+    ) {
+        let major_frame = config.major_frame().ticks();
+        let frame_number = elapsed_time.ticks() / major_frame;
+        let frame_time = Duration::from_ticks(elapsed_time.ticks() % major_frame);
+
+        let started_slot = self
+            .slots
+            .iter()
+            .position(|slot| slot.start(config) <= frame_time && frame_time < slot.end(config));
+
+        if let Some(slot) = started_slot {
+            let release = SlotRelease {
+                major_frame: frame_number,
+                slot,
+                window_end: Duration::from_ticks(frame_number * major_frame)
+                    + self.slots[slot].end(config),
+            };
+
+            let is_new = self.last_release.lock(|last_release| {
+                let is_new = last_release.map_or(true, |last| {
+                    (last.major_frame, last.slot) != (release.major_frame, release.slot)
+                });
+                if is_new {
+                    *last_release = Some(release);
+                }
+                is_new
+            });
+
+            if is_new {
+                self.pending.lock(|pending| *pending = true);
+                Aerugo::wake_tasklet(&self.tasklet);
+            }
+        }
+    }
+
+    /// Returns time until the start of the next slot of the tasklet.
+    ///
+    /// # Parameters
+    /// * `config` - Schedule table configuration.
+    /// * `elapsed_time` - Time since the system start.
+    pub(crate) fn time_to_next_slot(
+        &self,
+        config: &ScheduleTableConfig,
+        elapsed_time: Duration,
+    ) -> Option<Duration> {
+        let major_frame = config.major_frame().ticks();
+        let frame_time = elapsed_time.ticks() % major_frame;
+
+        self.slots
+            .iter()
+            .map(|slot| {
+                let start = slot.start(config).ticks();
+                if start > frame_time {
+                    start - frame_time
+                } else {
+                    major_frame - frame_time + start
+                }
+            })
+            .min()
+            .map(Duration::from_ticks)
+    }
+
+    /// Checks if the last execution ended after its slot window.
+    ///
+    /// # Parameters
+    /// * `execution_end` - End of the execution, as time since the system start.
+    pub(crate) fn finish_execution(&self, execution_end: Duration) {
+        let overrun = self
+            .last_release
+            .lock(|last_release| last_release.is_some_and(|last| execution_end > last.window_end));
+
+        if overrun {
+            self.overrun_count.lock(|count| *count += 1);
+        }
+    }
+}
+
+impl DataProvider<()> for ScheduledTasklet {
+    /// Returns `Some()` if tasklet was released in its slot.
+    fn get_data(&self) -> Option<()> {
+        if self.pending.lock(core::mem::take) {
+            Some(())
+        } else {
+            None
+        }
+    }
+
+    /// Returns false, as tasklet is woken up by the
+    /// [crate::schedule_table_manager::ScheduleTableManager] at the start of its slots.
+    fn data_waiting(&self) -> bool {
+        false
+    }
+
+    /// Cancels pending release of the tasklet.
+    fn flush(&self) -> usize {
+        self.pending
+            .lock(|pending| match (self.tasklet.get_status(), *pending) {
+                (TaskletStatus::Waiting, true) => {
+                    *pending = false;
+                    1
+                }
+                _ => 0,
+            })
+    }
+
+    fn source_kind(&self) -> DataSourceKind {
+        DataSourceKind::ScheduleTable {
+            slot_count: self.slots.len(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn slots_are_placed_in_minor_frames() {
+        let config = ScheduleTableConfig {
+            minor_frame: Duration::millis(10),
+            minor_frame_count: 4,
+        };
+        let slot_a = ScheduleSlot {
+            minor_frame: 1,
+            offset: Duration::millis(2),
+            window: Duration::millis(5),
+        };
+        let slot_b = ScheduleSlot {
+            minor_frame: 1,
+            offset: Duration::millis(7),
+            window: Duration::millis(3),
+        };
+        let slot_c = ScheduleSlot {
+            minor_frame: 1,
+            offset: Duration::millis(6),
+            window: Duration::millis(5),
+        };
+
+        assert_eq!(config.major_frame(), Duration::millis(40));
+        assert_eq!(slot_a.start(&config), Duration::millis(12));
+        assert_eq!(slot_a.end(&config), Duration::millis(17));
+
+        assert!(slot_a.is_valid(&config));
+        assert!(slot_b.is_valid(&config));
+        assert!(!slot_c.is_valid(&config));
+
+        assert!(!slot_a.overlaps(&slot_b, &config));
+        assert!(slot_a.overlaps(&slot_c, &config));
+        assert!(slot_c.overlaps(&slot_b, &config));
+    }
+}
@@ -0,0 +1,219 @@
+//! Schedule table manager.
+//!
+//! This module contains schedule table manager. It's responsibility is to keep track of tasklets
+//! subscribed to the schedule table and wake them up at the start of their slots.
+
+use core::cell::OnceCell;
+
+use crate::aerugo::Aerugo;
+use crate::error::SystemError;
+use crate::execution_monitor::ExecutionData;
+use crate::internal_list::InternalList;
+use crate::schedule_table::{ScheduleSlot, ScheduleTableConfig, ScheduledTasklet};
+use crate::tasklet::{TaskletId, TaskletPtr};
+use crate::time::Duration;
+use crate::time_source::TimeSource;
+
+/// List of tasklets subscribed to the schedule table.
+type ScheduledTasklets = InternalList<ScheduledTasklet, { Aerugo::TASKLET_COUNT }>;
+
+/// Schedule table manager.
+///
+/// This shouldn't be created by hand by the user or anywhere else in the code.
+/// It should be used as a singleton (crate::aerugo::SCHEDULE_TABLE_MANAGER) and shouldn't be
+/// directly accessed by any other part of the system.
+pub(crate) struct ScheduleTableManager {
+    /// Schedule table configuration.
+    config: OnceCell<ScheduleTableConfig>,
+    /// Tasklets subscribed to the schedule table.
+    scheduled_tasklets: ScheduledTasklets,
+    /// Time source.
+    time_source: &'static TimeSource,
+}
+
+/// It is safe assuming that configuration and list of tasklets are modified only during system
+/// initialization (before scheduler is started) and those modifications cannot be interrupted.
+unsafe impl Sync for ScheduleTableManager {}
+
+impl ScheduleTableManager {
+    /// Creates new schedule table manager instance.
+    ///
+    /// # Safety
+    /// This shouldn't be called more than once.
+    pub(crate) const fn new(time_source: &'static TimeSource) -> Self {
+        ScheduleTableManager {
+            config: OnceCell::new(),
+            scheduled_tasklets: ScheduledTasklets::new(),
+            time_source,
+        }
+    }
+
+    /// Sets schedule table configuration.
+    ///
+    /// # Parameters
+    /// * `config` - Schedule table configuration.
+    ///
+    /// # Return
+    /// `()` if successful, `SystemError` otherwise.
+    ///
+    /// # Safety
+    /// This is safe to call during system initialization (before scheduler is started).
+    pub(crate) unsafe fn configure(
+        &'static self,
+        config: ScheduleTableConfig,
+    ) -> Result<(), SystemError> {
+        if config.minor_frame.ticks() == 0 || config.minor_frame_count == 0 {
+            return Err(SystemError::InvalidScheduleTable);
+        }
+
+        self.config
+            .set(config)
+            .map_err(|_| SystemError::ScheduleTableAlreadyConfigured)
+    }
+
+    /// Subscribes tasklet to the schedule table.
+    ///
+    /// # Parameters
+    /// * `tasklet` - Tasklet to subscribe.
+    /// * `slots` - Slots of the tasklet.
+    ///
+    /// # Return
+    /// Reference to the scheduled tasklet data if successful, `SystemError` otherwise.
+    ///
+    /// # Safety
+    /// This is unsafe, because it mutably borrows the list of scheduled tasklets.
+    /// This is safe to call during system initialization (before scheduler is started).
+    pub(crate) unsafe fn create_scheduled_tasklet(
+        &'static self,
+        tasklet: TaskletPtr,
+        slots: &'static [ScheduleSlot],
+    ) -> Result<&'static ScheduledTasklet, SystemError> {
+        match self
+            .scheduled_tasklets
+            .add(ScheduledTasklet::new(tasklet, slots))
+        {
+            Ok(_) => Ok(self.scheduled_tasklets.last().unwrap()),
+            Err(_) => Err(SystemError::ScheduledTaskletListFull),
+        }
+    }
+
+    /// Checks if schedule table is configured when it's used, and if slots fit in their minor
+    /// frames and don't overlap.
+    ///
+    /// # Return
+    /// `()` if configuration is valid, `SystemError` otherwise.
+    pub(crate) fn validate(&'static self) -> Result<(), SystemError> {
+        if self.scheduled_tasklets.is_empty() {
+            return Ok(());
+        }
+
+        let config = self
+            .config
+            .get()
+            .ok_or(SystemError::ScheduleTableNotConfigured)?;
+
+        for (index, scheduled) in self.scheduled_tasklets.iter().enumerate() {
+            let tasklet_name = scheduled.tasklet().get_name();
+
+            for (slot_index, slot) in scheduled.slots().iter().enumerate() {
+                if !slot.is_valid(config) {
+                    return Err(SystemError::InvalidScheduleSlot(tasklet_name));
+                }
+
+                let overlap = self
+                    .scheduled_tasklets
+                    .iter()
+                    .enumerate()
+                    .skip(index)
+                    .flat_map(|(other_index, other)| {
+                        // Slots of the same tasklet are compared only with the following ones.
+                        let skipped = if other_index == index {
+                            slot_index + 1
+                        } else {
+                            0
+                        };
+                        other
+                            .slots()
+                            .iter()
+                            .skip(skipped)
+                            .map(move |other_slot| (other, other_slot))
+                    })
+                    .find(|(_, other_slot)| slot.overlaps(other_slot, config));
+
+                if let Some((other, _)) = overlap {
+                    return Err(SystemError::ScheduleSlotOverlap(
+                        tasklet_name,
+                        other.tasklet().get_name(),
+                    ));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Wakes tasklets which slots have started.
+    pub(crate) fn wake_tasklets(&'static self) {
+        let config = match self.config.get() {
+            Some(config) => config,
+            None => return,
+        };
+
+        for scheduled in &self.scheduled_tasklets {
+            scheduled.wake_if_slot_started(config, self.time_source.elapsed_time());
+        }
+    }
+
+    /// Returns time until the nearest slot start.
+    ///
+    /// # Return
+    /// Time until the nearest slot start, `None` if there are no scheduled tasklets.
+    pub(crate) fn time_to_next_slot(&'static self) -> Option<Duration> {
+        let config = self.config.get()?;
+        let elapsed_time = self.time_source.elapsed_time();
+
+        self.scheduled_tasklets
+            .iter()
+            .filter_map(|scheduled| scheduled.time_to_next_slot(config, elapsed_time))
+            .min()
+    }
+
+    /// Updates slot overruns with tasklet execution data.
+    ///
+    /// # Parameters
+    /// * `execution_data` - Data from the tasklet execution.
+    pub(crate) fn update(&'static self, execution_data: &ExecutionData) {
+        if !execution_data.was_executed() {
+            return;
+        }
+
+        if let Some(scheduled) = self.scheduled_tasklet(*execution_data.tasklet_id()) {
+            scheduled.finish_execution(self.time_source.elapsed_time());
+        }
+    }
+
+    /// Returns number of slot overruns of the given tasklet.
+    ///
+    /// # Parameters
+    /// * `tasklet_id` - ID of the tasklet.
+    ///
+    /// # Return
+    /// Number of overruns, `None` if tasklet is not subscribed to the schedule table.
+    pub(crate) fn overrun_count(&'static self, tasklet_id: TaskletId) -> Option<u32> {
+        self.scheduled_tasklet(tasklet_id)
+            .map(|scheduled| scheduled.overrun_count())
+    }
+
+    /// Returns scheduled tasklet data of the given tasklet.
+    ///
+    /// # Parameters
+    /// * `tasklet_id` - ID of the tasklet.
+    fn scheduled_tasklet(
+        &'static self,
+        tasklet_id: TaskletId,
+    ) -> Option<&'static ScheduledTasklet> {
+        self.scheduled_tasklets
+            .iter()
+            .find(|scheduled| scheduled.tasklet().get_id() == tasklet_id)
+    }
+}
@@ -0,0 +1,274 @@
+//! Scheduler watchdog, detecting stalls of the executor loop.
+//!
+//! Software watchdogs and the health monitor are checked in the executor loop, so they can't detect
+//! that the loop itself stopped, for example when a step function never returns. Hardware watchdog
+//! resets the system in that case, but only if it's configured, and without any information about
+//! the cause. Scheduler watchdog counts iterations of the executor loop, and checks from a system
+//! timer interrupt that the count changes at least once per configured period. When the loop
+//! stalls, a [FaultRecord](crate::FaultRecord) with the name of the executed tasklet and configured
+//! fault code is stored in the fault recorder, and configured [StallAction] is performed.
+
+use core::cell::OnceCell;
+
+use aerugo_hal::AerugoHal;
+
+use crate::error::SystemError;
+use crate::executor::Executor;
+use crate::fault::FaultRecord;
+use crate::fault_manager::FaultManager;
+use crate::hal::Hal;
+use crate::mutex::Mutex;
+use crate::time::{Duration, Instant};
+
+/// Function called from the timer interrupt when the executor loop stalls.
+///
+/// Handler is called with the name of the executed tasklet (`None` if the loop stalled outside of
+/// a tasklet) and the time since the last iteration of the loop.
+pub type StallHandler = fn(Option<&'static str>, Duration);
+
+/// Action performed when the executor loop stalls.
+#[derive(Debug, Copy, Clone)]
+pub enum StallAction {
+    /// Stall is logged.
+    Log,
+    /// Handler is called from the timer interrupt.
+    Handler(StallHandler),
+    /// Stall is logged and the system is reset.
+    Reset,
+}
+
+/// Configuration of the scheduler watchdog.
+#[derive(Copy, Clone)]
+struct SchedulerWatchdogConfig {
+    /// Maximum time between iterations of the executor loop.
+    period: Duration,
+    /// Action performed when the loop stalls.
+    action: StallAction,
+    /// Code of the fault stored when the loop stalls.
+    fault_code: u32,
+}
+
+/// Progress of the executor loop.
+struct LoopProgress {
+    /// Number of the loop iterations.
+    iterations: u32,
+    /// Number of the loop iterations seen in the last check.
+    checked_iterations: u32,
+    /// Time of the last check in which the number of iterations changed.
+    last_progress: Instant,
+    /// Whether the stall was already reported.
+    stalled: bool,
+}
+
+/// Stall of the executor loop, detected by the scheduler watchdog.
+pub(crate) struct Stall {
+    /// Time since the last iteration of the loop.
+    pub(crate) duration: Duration,
+    /// Action that should be performed.
+    pub(crate) action: StallAction,
+    /// Code of the fault that should be stored.
+    pub(crate) fault_code: u32,
+}
+
+/// Scheduler watchdog.
+///
+/// This shouldn't be created by hand by the user or anywhere else in the code.
+/// It should be used as a singleton (crate::aerugo::SCHEDULER_WATCHDOG) and shouldn't be directly
+/// accessed by any other part of the system.
+pub(crate) struct SchedulerWatchdog {
+    /// Configuration, not set if the watchdog is disabled.
+    config: OnceCell<SchedulerWatchdogConfig>,
+    /// Progress of the loop, `None` until the watchdog is started.
+    progress: Mutex<Option<LoopProgress>>,
+}
+
+/// It is safe assuming that configuration is modified only during system initialization (before
+/// scheduler is started), and progress of the loop is modified only with [Mutex], so those
+/// modifications cannot be interrupted by the timer interrupt.
+unsafe impl Sync for SchedulerWatchdog {}
+
+impl SchedulerWatchdog {
+    /// Creates new, disabled scheduler watchdog.
+    ///
+    /// # Safety
+    /// This shouldn't be called more than once.
+    pub(crate) const fn new() -> Self {
+        SchedulerWatchdog {
+            config: OnceCell::new(),
+            progress: Mutex::new(None),
+        }
+    }
+
+    /// Enables the watchdog.
+    ///
+    /// # Parameters
+    /// * `period` - Maximum time between iterations of the executor loop.
+    /// * `action` - Action performed when the loop stalls.
+    /// * `fault_code` - Code of the fault stored when the loop stalls.
+    ///
+    /// # Return
+    /// `()` if successful, `SystemError` otherwise.
+    ///
+    /// # Safety
+    /// This is unsafe, because it mutably borrows the configuration. This is safe to call during
+    /// system initialization (before scheduler is started).
+    pub(crate) unsafe fn enable(
+        &'static self,
+        period: Duration,
+        action: StallAction,
+        fault_code: u32,
+    ) -> Result<(), SystemError> {
+        let config = SchedulerWatchdogConfig {
+            period,
+            action,
+            fault_code,
+        };
+
+        match self.config.set(config) {
+            Ok(_) => Ok(()),
+            Err(_) => Err(SystemError::SchedulerWatchdogAlreadyEnabled),
+        }
+    }
+
+    /// Returns `true` if the watchdog is enabled.
+    pub(crate) fn is_enabled(&'static self) -> bool {
+        self.config.get().is_some()
+    }
+
+    /// Starts monitoring of the executor loop.
+    ///
+    /// # Parameters
+    /// * `time` - Current time.
+    pub(crate) fn start(&'static self, time: Instant) {
+        self.progress.lock(|progress| {
+            *progress = Some(LoopProgress {
+                iterations: 0,
+                checked_iterations: 0,
+                last_progress: time,
+                stalled: false,
+            })
+        });
+    }
+
+    /// Marks an iteration of the executor loop.
+    pub(crate) fn progress(&'static self) {
+        self.progress.lock(|progress| {
+            if let Some(progress) = progress {
+                progress.iterations = progress.iterations.wrapping_add(1);
+            }
+        });
+    }
+
+    /// Checks progress of the executor loop.
+    ///
+    /// This is called from the system timer interrupt.
+    ///
+    /// # Parameters
+    /// * `time` - Current time.
+    ///
+    /// # Return
+    /// Detected stall, if loop didn't progress for longer than the configured period. Each stall
+    /// is returned only once, until the loop progresses again.
+    pub(crate) fn check(&'static self, time: Instant) -> Option<Stall> {
+        let config = self.config.get()?;
+
+        self.progress.lock(|progress| {
+            let progress = progress.as_mut()?;
+
+            if progress.iterations != progress.checked_iterations {
+                progress.checked_iterations = progress.iterations;
+                progress.last_progress = time;
+                progress.stalled = false;
+                return None;
+            }
+
+            let duration = time.checked_duration_since(progress.last_progress)?;
+            if duration < config.period || progress.stalled {
+                return None;
+            }
+
+            progress.stalled = true;
+            Some(Stall {
+                duration,
+                action: config.action,
+                fault_code: config.fault_code,
+            })
+        })
+    }
+
+    /// Checks progress of the executor loop and handles detected stall.
+    ///
+    /// This is called from the system timer interrupt. When the loop stalls, a fault is recorded
+    /// and the configured action is performed.
+    ///
+    /// # Parameters
+    /// * `executor` - System executor.
+    /// * `fault_manager` - Fault manager recording the fault.
+    pub(crate) fn supervise(
+        &'static self,
+        executor: &'static Executor,
+        fault_manager: &'static FaultManager,
+    ) {
+        let Some(stall) = self.check(Hal::get_system_time()) else {
+            return;
+        };
+
+        let tasklet = executor.current_tasklet().map(|tasklet| tasklet.get_name());
+        fault_manager.record(FaultRecord::new(
+            tasklet.unwrap_or("Executor"),
+            0,
+            stall.fault_code,
+        ));
+
+        match stall.action {
+            StallAction::Log => {
+                crate::logln!(
+                    "Aerugo: executor loop stalled for {} in '{}'",
+                    stall.duration,
+                    tasklet.unwrap_or("Executor")
+                );
+            }
+            StallAction::Handler(handler) => handler(tasklet, stall.duration),
+            StallAction::Reset => {
+                crate::logln!(
+                    "Aerugo: executor loop stalled for {} in '{}', resetting the system",
+                    stall.duration,
+                    tasklet.unwrap_or("Executor")
+                );
+                Hal::reset_system();
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn detect_stall() {
+        static WATCHDOG: SchedulerWatchdog = SchedulerWatchdog::new();
+
+        unsafe {
+            WATCHDOG
+                .enable(Duration::millis(10), StallAction::Log, 7)
+                .unwrap()
+        };
+        WATCHDOG.start(Instant::from_ticks(0));
+
+        WATCHDOG.progress();
+        assert!(WATCHDOG.check(Instant::from_ticks(5000)).is_none());
+        // Period is measured from the check that noticed the last progress.
+        assert!(WATCHDOG.check(Instant::from_ticks(12000)).is_none());
+
+        let stall = WATCHDOG.check(Instant::from_ticks(15000)).unwrap();
+        assert_eq!(stall.duration, Duration::millis(10));
+        assert_eq!(stall.fault_code, 7);
+
+        // Stall is reported once, until the loop progresses again.
+        assert!(WATCHDOG.check(Instant::from_ticks(20000)).is_none());
+        WATCHDOG.progress();
+        assert!(WATCHDOG.check(Instant::from_ticks(25000)).is_none());
+        assert!(WATCHDOG.check(Instant::from_ticks(35000)).is_some());
+    }
+}
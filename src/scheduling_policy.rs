@@ -0,0 +1,146 @@
+//! Policies ordering tasklets in the execution queue.
+//!
+//! Executor is generic over the [SchedulingPolicy], which decides which of the waiting tasklets is
+//! more urgent. Tasklets that are equally urgent are further ordered by the
+//! [equal priority policy](crate::EqualPriorityPolicy).
+//!
+//! Policy of the system executor is selected at compile time. By default tasklets are ordered by
+//! their priorities ([PriorityScheduling]). With `edf-scheduling` feature, releases of the cyclic
+//! tasklets are ordered by their absolute deadlines ([EarliestDeadlineFirst]).
+
+use core::cmp::Ordering;
+
+use crate::tasklet::TaskletPtr;
+use crate::time::Instant;
+
+/// Scheduling policy of the system executor.
+#[cfg(not(feature = "edf-scheduling"))]
+pub(crate) type SystemSchedulingPolicy = PriorityScheduling;
+
+/// Scheduling policy of the system executor.
+#[cfg(feature = "edf-scheduling")]
+pub(crate) type SystemSchedulingPolicy = EarliestDeadlineFirst;
+
+/// Urgency of a tasklet waiting in the execution queue.
+#[derive(Copy, Clone)]
+pub(crate) struct Urgency {
+    /// Current priority of the tasklet, including the inherited priority.
+    pub(crate) priority: u8,
+    /// Absolute deadline of the queued release, `None` if the release has no deadline.
+    #[cfg_attr(not(feature = "edf-scheduling"), allow(dead_code))]
+    pub(crate) deadline: Option<Instant>,
+}
+
+/// Policy ordering tasklets in the execution queue.
+pub(crate) trait SchedulingPolicy {
+    /// Returns absolute deadline of the tasklet release that is put into the execution queue.
+    ///
+    /// Deadline is taken when the tasklet is queued, and is reported in the execution statistics
+    /// of the release.
+    ///
+    /// # Parameters
+    /// * `tasklet` - Queued tasklet.
+    ///
+    /// # Return
+    /// Deadline of the release, or `None` if the policy doesn't use deadlines.
+    fn release_deadline(tasklet: &TaskletPtr) -> Option<Instant>;
+
+    /// Compares urgency of two queued tasklets.
+    ///
+    /// # Parameters
+    /// * `urgency` - Urgency of the first tasklet.
+    /// * `other` - Urgency of the second tasklet.
+    ///
+    /// # Return
+    /// `Ordering::Greater` if the first tasklet should be executed before the second one,
+    /// `Ordering::Less` if after it, and `Ordering::Equal` if they are equally urgent.
+    fn compare(urgency: &Urgency, other: &Urgency) -> Ordering;
+}
+
+/// Fixed-priority scheduling.
+///
+/// Tasklet with the higher priority goes first.
+pub(crate) struct PriorityScheduling;
+
+impl SchedulingPolicy for PriorityScheduling {
+    fn release_deadline(_: &TaskletPtr) -> Option<Instant> {
+        None
+    }
+
+    fn compare(urgency: &Urgency, other: &Urgency) -> Ordering {
+        urgency.priority.cmp(&other.priority)
+    }
+}
+
+/// Earliest-deadline-first scheduling.
+///
+/// Each release of a tasklet subscribed to cyclic execution with a period has an absolute deadline
+/// at the start of its next period. Releases with deadlines go first, starting from the earliest
+/// one, regardless of the tasklet priorities. Remaining tasklets are ordered by their priorities.
+#[cfg_attr(not(feature = "edf-scheduling"), allow(dead_code))]
+pub(crate) struct EarliestDeadlineFirst;
+
+impl SchedulingPolicy for EarliestDeadlineFirst {
+    fn release_deadline(tasklet: &TaskletPtr) -> Option<Instant> {
+        tasklet.get_absolute_deadline()
+    }
+
+    fn compare(urgency: &Urgency, other: &Urgency) -> Ordering {
+        let by_deadline = match (urgency.deadline, other.deadline) {
+            (Some(deadline), Some(other_deadline)) => other_deadline.cmp(&deadline),
+            (Some(_), None) => Ordering::Greater,
+            (None, Some(_)) => Ordering::Less,
+            (None, None) => Ordering::Equal,
+        };
+
+        by_deadline.then(PriorityScheduling::compare(urgency, other))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn urgency(priority: u8, deadline: Option<u64>) -> Urgency {
+        Urgency {
+            priority,
+            deadline: deadline.map(Instant::from_ticks),
+        }
+    }
+
+    #[test]
+    fn priority_ordering() {
+        assert_eq!(
+            PriorityScheduling::compare(&urgency(2, None), &urgency(1, None)),
+            Ordering::Greater
+        );
+        assert_eq!(
+            PriorityScheduling::compare(&urgency(1, Some(100)), &urgency(2, None)),
+            Ordering::Less
+        );
+        assert_eq!(
+            PriorityScheduling::compare(&urgency(1, Some(100)), &urgency(1, Some(200))),
+            Ordering::Equal
+        );
+    }
+
+    #[test]
+    fn earliest_deadline_ordering() {
+        assert_eq!(
+            EarliestDeadlineFirst::compare(&urgency(1, Some(100)), &urgency(2, Some(200))),
+            Ordering::Greater
+        );
+        assert_eq!(
+            EarliestDeadlineFirst::compare(&urgency(1, Some(100)), &urgency(3, None)),
+            Ordering::Greater
+        );
+        assert_eq!(
+            EarliestDeadlineFirst::compare(&urgency(1, None), &urgency(2, None)),
+            Ordering::Less
+        );
+        assert_eq!(
+            EarliestDeadlineFirst::compare(&urgency(2, Some(100)), &urgency(1, Some(100))),
+            Ordering::Greater
+        );
+    }
+}
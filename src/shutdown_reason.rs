@@ -0,0 +1,75 @@
+//! Reason of the controlled system shutdown.
+//!
+//! Controlled [shutdown](crate::api::RuntimeApi::shutdown) finalizes all tasklets and resets the
+//! system. Code of its reason is stored by the HAL in memory preserved over the reset (general
+//! purpose backup registers on SAMV71), and is available after the restart in the
+//! [startup information](crate::StartupInfo::shutdown_reason), so the application can tell an
+//! orderly restart from a crash.
+
+/// Flag marking codes of the application-defined reasons.
+const APPLICATION_FLAG: u32 = 1 << 16;
+
+/// Reason of the controlled system shutdown.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum ShutdownReason {
+    /// System is restarted on request, ex. from a ground command.
+    Restart,
+    /// System is restarted to apply a configuration change.
+    ConfigurationChange,
+    /// System is restarted to boot an updated firmware image.
+    FirmwareUpdate,
+    /// Application-defined reason.
+    Application(u16),
+}
+
+impl ShutdownReason {
+    /// Returns code of the reason, stored over the reset.
+    pub(crate) fn code(&self) -> u32 {
+        match self {
+            ShutdownReason::Restart => 1,
+            ShutdownReason::ConfigurationChange => 2,
+            ShutdownReason::FirmwareUpdate => 3,
+            ShutdownReason::Application(code) => APPLICATION_FLAG | *code as u32,
+        }
+    }
+
+    /// Creates reason from its code.
+    ///
+    /// # Parameters
+    /// * `code` - Code of the reason.
+    ///
+    /// # Return
+    /// Reason, or `None` if code is not valid.
+    pub(crate) fn from_code(code: u32) -> Option<Self> {
+        match code {
+            1 => Some(ShutdownReason::Restart),
+            2 => Some(ShutdownReason::ConfigurationChange),
+            3 => Some(ShutdownReason::FirmwareUpdate),
+            code if code & !0xFFFF == APPLICATION_FLAG => {
+                Some(ShutdownReason::Application(code as u16))
+            }
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn code_round_trip() {
+        for reason in [
+            ShutdownReason::Restart,
+            ShutdownReason::ConfigurationChange,
+            ShutdownReason::FirmwareUpdate,
+            ShutdownReason::Application(0),
+            ShutdownReason::Application(0xBEEF),
+        ] {
+            assert_eq!(ShutdownReason::from_code(reason.code()), Some(reason));
+        }
+
+        assert_eq!(ShutdownReason::from_code(0), None);
+        assert_eq!(ShutdownReason::from_code(APPLICATION_FLAG << 1), None);
+    }
+}
@@ -0,0 +1,46 @@
+//! Software watchdogs checking liveness of tasklets in each operational mode.
+//!
+//! Hardware watchdog is fed by the scheduler loop, so it only detects a hang of the whole system.
+//! Software watchdog extends it to single tasklets - each watchdog has a set of tasklets that are
+//! expected to be executed at least once per configured timeout. Different operational modes
+//! usually have different expectations (for example in SAFE mode only housekeeping tasklets are
+//! running, while in NOMINAL mode payload tasklets are expected to run as well), so each watchdog
+//! can be bound to a [boolean condition](crate::BooleanConditionHandle) representing the mode, and
+//! is checked only while that condition is `true`.
+//!
+//! When the watchdog becomes active, all of its tasklets get a full timeout to execute. When any of
+//! them doesn't execute in time, watchdog expires: a [FaultRecord](crate::FaultRecord) with the
+//! watchdog name and configured fault code is stored in the fault recorder, and the system stops
+//! feeding hardware and external watchdogs, so the system is reset by them. Expiration is latched
+//! until the watchdog becomes inactive.
+
+mod software_watchdog_config;
+mod software_watchdog_storage;
+
+pub use self::software_watchdog_config::{SoftwareWatchdogConfig, WatchdogEntry};
+pub use self::software_watchdog_storage::SoftwareWatchdogStorage;
+
+use crate::tasklet::TaskletId;
+use crate::time::Duration;
+
+/// Set of tasklets checked by a software watchdog.
+///
+/// This is used by the [software watchdog manager](crate::software_watchdog_manager) to access
+/// watchdogs of different sizes.
+pub(crate) trait WatchdogSet {
+    /// Marks tasklet as alive.
+    ///
+    /// # Parameters
+    /// * `tasklet` - ID of the executed tasklet.
+    /// * `time` - Time of the execution.
+    fn alive(&self, tasklet: &TaskletId, time: Duration);
+
+    /// Checks whether all tasklets of an active watchdog were executed in time.
+    ///
+    /// # Parameters
+    /// * `time` - Current time.
+    ///
+    /// # Return
+    /// `false` if watchdog is active and expired, `true` otherwise.
+    fn check(&self, time: Duration) -> bool;
+}
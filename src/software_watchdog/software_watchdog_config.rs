@@ -0,0 +1,31 @@
+//! Configuration for creating software watchdogs.
+
+use crate::boolean_condition::BooleanConditionHandle;
+use crate::tasklet::TaskletId;
+use crate::time::Duration;
+
+/// Liveness expectation of a single tasklet.
+#[derive(Copy, Clone)]
+pub struct WatchdogEntry {
+    /// ID of the checked tasklet.
+    pub tasklet: TaskletId,
+    /// Maximum time between consecutive executions of the tasklet.
+    pub timeout: Duration,
+}
+
+/// Software watchdog configuration.
+///
+/// # Generic Parameters
+/// * `N` - Number of checked tasklets.
+#[derive(Copy, Clone)]
+pub struct SoftwareWatchdogConfig<const N: usize> {
+    /// Watchdog name, used in the fault records.
+    pub name: &'static str,
+    /// Condition representing operational mode in which the watchdog is active, `None` if it's
+    /// active in all modes.
+    pub mode: Option<BooleanConditionHandle>,
+    /// Liveness expectations of the checked tasklets.
+    pub entries: [WatchdogEntry; N],
+    /// Code of the fault stored in the fault recorder when the watchdog expires.
+    pub fault_code: u32,
+}
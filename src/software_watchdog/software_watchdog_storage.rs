@@ -0,0 +1,192 @@
+//! Static storage for [software watchdog](crate::software_watchdog).
+//!
+//! This module contains a software watchdog storage, which is a statically allocated memory that
+//! will store the watchdog configuration and state for the duration of the system life.
+
+use core::cell::OnceCell;
+
+use super::{SoftwareWatchdogConfig, WatchdogSet};
+
+use crate::aerugo::Aerugo;
+use crate::error::SystemError;
+use crate::fault::FaultRecord;
+use crate::mutex::Mutex;
+use crate::tasklet::TaskletId;
+use crate::time::Duration;
+
+/// State of the software watchdog.
+struct WatchdogState<const N: usize> {
+    /// Time of the last execution of each checked tasklet.
+    last_alive: [Duration; N],
+    /// Whether the watchdog was active during the last check.
+    active: bool,
+    /// Whether the watchdog expired since it became active.
+    expired: bool,
+}
+
+/// Structure containing memory for software watchdog creation.
+///
+/// Storage shall be a static variable and shall be initialized only once, before the system is started.
+///
+/// # Generic Parameters
+/// * `N` - Number of checked tasklets.
+pub struct SoftwareWatchdogStorage<const N: usize> {
+    /// Watchdog configuration.
+    config: OnceCell<SoftwareWatchdogConfig<N>>,
+    /// Watchdog state.
+    state: Mutex<WatchdogState<N>>,
+}
+
+/// SAFETY: Configuration is set only during system initialization, and state is accessed only in
+/// a critical section.
+unsafe impl<const N: usize> Sync for SoftwareWatchdogStorage<N> {}
+
+impl<const N: usize> SoftwareWatchdogStorage<N> {
+    /// Creates new storage.
+    pub const fn new() -> Self {
+        SoftwareWatchdogStorage {
+            config: OnceCell::new(),
+            state: Mutex::new(WatchdogState {
+                last_alive: [Duration::from_ticks(0); N],
+                active: false,
+                expired: false,
+            }),
+        }
+    }
+
+    /// Returns initialization status of this storage.
+    pub fn is_initialized(&'static self) -> bool {
+        self.config.get().is_some()
+    }
+
+    /// Returns whether the watchdog expired since it became active.
+    pub fn is_expired(&'static self) -> bool {
+        self.state.lock(|state| state.expired)
+    }
+
+    /// Initializes this storage.
+    ///
+    /// # Parameters
+    /// * `config` - Watchdog configuration.
+    ///
+    /// # Return
+    /// `()` if successful, `SystemError` otherwise.
+    ///
+    /// # Safety
+    /// This is safe to call during system initialization (before scheduler is started).
+    pub(crate) unsafe fn init(
+        &'static self,
+        config: SoftwareWatchdogConfig<N>,
+    ) -> Result<(), SystemError> {
+        self.config
+            .set(config)
+            .map_err(|_| SystemError::StorageAlreadyInitialized)
+    }
+}
+
+impl<const N: usize> WatchdogSet for SoftwareWatchdogStorage<N> {
+    fn alive(&self, tasklet: &TaskletId, time: Duration) {
+        let Some(config) = self.config.get() else {
+            return;
+        };
+
+        self.state.lock(|state| {
+            for (entry, last_alive) in config.entries.iter().zip(state.last_alive.iter_mut()) {
+                if entry.tasklet == *tasklet {
+                    *last_alive = time;
+                }
+            }
+        })
+    }
+
+    fn check(&self, time: Duration) -> bool {
+        let Some(config) = self.config.get() else {
+            return true;
+        };
+        let active = config.mode.map_or(true, |mode| mode.get_value());
+
+        let (expired, expired_now) = self.state.lock(|state| {
+            if !active {
+                state.active = false;
+                state.expired = false;
+                return (false, false);
+            }
+
+            if !state.active {
+                // Each tasklet gets a full timeout after the mode is entered.
+                state.active = true;
+                state.last_alive = [time; N];
+            }
+
+            if state.expired {
+                return (true, false);
+            }
+
+            state.expired = config
+                .entries
+                .iter()
+                .zip(state.last_alive.iter())
+                .any(|(entry, last_alive)| time > *last_alive + entry.timeout);
+            (state.expired, state.expired)
+        });
+
+        if expired_now {
+            Aerugo::store_fault(FaultRecord::new(config.name, 0, config.fault_code));
+        }
+
+        !expired
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::boolean_condition::BooleanConditionStorage;
+    use crate::software_watchdog::WatchdogEntry;
+
+    #[test]
+    fn expires_only_in_active_mode() {
+        static MODE: BooleanConditionStorage = BooleanConditionStorage::new();
+        static STORAGE: SoftwareWatchdogStorage<2> = SoftwareWatchdogStorage::new();
+
+        let tasklet_a = TaskletId(1000);
+        let tasklet_b = TaskletId(1001);
+
+        unsafe { MODE.init(false) }.unwrap();
+        let mode = MODE.create_handle().unwrap();
+
+        let config = SoftwareWatchdogConfig {
+            name: "NominalWatchdog",
+            mode: Some(mode),
+            entries: [
+                WatchdogEntry {
+                    tasklet: tasklet_a,
+                    timeout: Duration::millis(10),
+                },
+                WatchdogEntry {
+                    tasklet: tasklet_b,
+                    timeout: Duration::millis(20),
+                },
+            ],
+            fault_code: 1,
+        };
+        unsafe { STORAGE.init(config) }.unwrap();
+
+        assert!(STORAGE.check(Duration::millis(100)));
+
+        mode.set_value(true);
+        assert!(STORAGE.check(Duration::millis(105)));
+        STORAGE.alive(&tasklet_a, Duration::millis(112));
+        assert!(STORAGE.check(Duration::millis(120)));
+        assert!(!STORAGE.is_expired());
+
+        STORAGE.alive(&tasklet_b, Duration::millis(122));
+        assert!(!STORAGE.check(Duration::millis(123)));
+        assert!(STORAGE.is_expired());
+
+        mode.set_value(false);
+        assert!(STORAGE.check(Duration::millis(200)));
+        assert!(!STORAGE.is_expired());
+    }
+}
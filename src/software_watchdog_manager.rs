@@ -0,0 +1,101 @@
+//! Software watchdog manager.
+//!
+//! This module contains software watchdog manager. It's responsibility is to keep track of the
+//! [software watchdogs](crate::software_watchdog) created in the system, mark tasklets executed by
+//! the scheduler as alive and check whether hardware watchdogs can be fed.
+
+use env_parser::read_env;
+
+use crate::error::SystemError;
+use crate::execution_monitor::ExecutionData;
+use crate::internal_list::InternalList;
+use crate::software_watchdog::WatchdogSet;
+use crate::time::Duration;
+
+/// List of software watchdogs registered in the system.
+type WatchdogList =
+    InternalList<&'static dyn WatchdogSet, { SoftwareWatchdogManager::SOFTWARE_WATCHDOG_COUNT }>;
+
+/// Software watchdog manager.
+///
+/// This shouldn't be created by hand by the user or anywhere else in the code.
+/// It should be used as a singleton (crate::aerugo::SOFTWARE_WATCHDOG_MANAGER) and shouldn't be
+/// directly accessed by any other part of the system.
+pub(crate) struct SoftwareWatchdogManager {
+    /// Registered watchdogs.
+    watchdogs: WatchdogList,
+}
+
+/// It is safe assuming that it's modified only during system initialization (before scheduler is
+/// started) and those modifications cannot be interrupted.
+unsafe impl Sync for SoftwareWatchdogManager {}
+
+impl SoftwareWatchdogManager {
+    /// Number of software watchdogs in the system.
+    #[read_env("AERUGO_SOFTWARE_WATCHDOG_COUNT")]
+    pub(crate) const SOFTWARE_WATCHDOG_COUNT: usize = 0;
+
+    /// Creates new software watchdog manager instance.
+    ///
+    /// # Safety
+    /// This shouldn't be called more than once.
+    pub(crate) const fn new() -> Self {
+        SoftwareWatchdogManager {
+            watchdogs: WatchdogList::new(),
+        }
+    }
+
+    /// Registers software watchdog in the manager.
+    ///
+    /// # Parameters
+    /// * `watchdog` - Watchdog to register.
+    ///
+    /// # Return
+    /// `()` if successful, `SystemError` otherwise.
+    ///
+    /// # Safety
+    /// This is unsafe, because it mutably borrows the list of watchdogs.
+    /// This is safe to call during system initialization (before scheduler is started).
+    pub(crate) unsafe fn register_watchdog(
+        &'static self,
+        watchdog: &'static dyn WatchdogSet,
+    ) -> Result<(), SystemError> {
+        match self.watchdogs.add(watchdog) {
+            Ok(_) => Ok(()),
+            Err(_) => Err(SystemError::SoftwareWatchdogListFull),
+        }
+    }
+
+    /// Marks executed tasklet as alive in all watchdogs.
+    ///
+    /// # Parameters
+    /// * `data` - Data from the tasklet execution.
+    /// * `time` - Time elapsed since system start.
+    pub(crate) fn update(&'static self, data: &ExecutionData, time: Duration) {
+        if !data.was_executed() {
+            return;
+        }
+
+        for watchdog in &self.watchdogs {
+            watchdog.alive(data.tasklet_id(), time);
+        }
+    }
+
+    /// Checks all watchdogs.
+    ///
+    /// # Parameters
+    /// * `time` - Time elapsed since system start.
+    ///
+    /// # Return
+    /// `true` if none of the active watchdogs expired, `false` otherwise.
+    pub(crate) fn check(&'static self, time: Duration) -> bool {
+        let mut healthy = true;
+
+        // All watchdogs are checked, so each expired one stores its fault.
+        for watchdog in &self.watchdogs {
+            healthy &= watchdog.check(time);
+        }
+
+        healthy
+    }
+}
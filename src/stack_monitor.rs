@@ -0,0 +1,44 @@
+//! Tasklet stack usage monitoring.
+//!
+//! Tasklets are executed one at a time on the system stack, each starting from the same frame of
+//! the executor. Before each execution, [`STACK_PAINT_SIZE`] bytes of unused stack below the
+//! executor frame are painted, and after the execution the depth to which the paint was
+//! overwritten is the stack usage of that execution.
+//!
+//! Painting takes time proportional to the painted size, so monitoring is disabled by default.
+//! Painted size should be larger than the expected stack usage of any tasklet, usage reaching it
+//! means that the actual usage is unknown.
+
+use aerugo_hal::{AerugoHal, StackPaint};
+use env_parser::read_env;
+
+use crate::hal::Hal;
+
+/// Size of the stack painted before each tasklet execution in bytes. Stack usage is not monitored
+/// if it's 0.
+#[read_env("AERUGO_STACK_PAINT_SIZE")]
+pub(crate) const STACK_PAINT_SIZE: usize = 0;
+
+/// Paints the stack before tasklet execution.
+///
+/// # Return
+/// Painted region, or `None` if stack usage is not monitored.
+#[inline(always)]
+pub(crate) fn paint_stack() -> Option<StackPaint> {
+    if STACK_PAINT_SIZE == 0 {
+        return None;
+    }
+
+    Hal::paint_stack(STACK_PAINT_SIZE)
+}
+
+/// Measures stack usage after tasklet execution.
+///
+/// # Parameters
+/// * `paint` - Region painted before the execution.
+///
+/// # Return
+/// Stack usage in bytes.
+pub(crate) fn stack_usage(paint: &StackPaint) -> usize {
+    Hal::stack_usage(paint)
+}
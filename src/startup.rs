@@ -0,0 +1,347 @@
+//! Cold-start and warm-start initialization paths.
+//!
+//! After a reset caused by the running system itself (watchdog or software reset, see
+//! [ResetCause::is_warm](crate::ResetCause::is_warm)), external devices were not power-cycled and
+//! kept their configuration, so their slow bring-up can be skipped. Application can register
+//! separate initialization functions for cold and warm start, and the system selects one of them
+//! based on the reset cause when it's [initialized](crate::Aerugo::initialize_with_startup).
+//!
+//! Each path has a timing budget. Watchdog is fed before and after the initialization function,
+//! and if the function takes longer than its budget, a [FaultRecord](crate::FaultRecord) with the
+//! path name and configured fault code is stored in the fault recorder.
+//!
+//! Components that the system can run without (ex. Ethernet telemetry link) can be registered as
+//! optional. They are initialized after the selected path, and when initialization of such
+//! component fails, a fault is stored and its fallback (ex. telemetry over UART) is initialized
+//! instead, so the system starts in a degraded mode instead of failing completely. Status of each
+//! optional component can be read at runtime.
+//!
+//! Duration of each startup step is recorded in the [StartupReport]: hardware configuration,
+//! startup delay, initialization path, each optional component, steps of the system configuration
+//! marked by the application, and validation of the configuration when the system is started.
+//! Report is logged when the system is started, and can be read at runtime, so the boot time
+//! budget can be verified step by step.
+
+use core::fmt;
+
+use aerugo_hal::ResetCause;
+use heapless::Vec;
+
+use crate::hal::UserPeripherals;
+use crate::shutdown_reason::ShutdownReason;
+use crate::time::{Duration, Instant};
+
+/// Initialization function of the application, called with peripherals available to the user.
+pub type StartupFn = fn(&mut UserPeripherals);
+
+/// Initialization function of an optional component, called with peripherals available to the
+/// user. Returns `true` if the component was initialized, `false` otherwise.
+pub type OptionalInitFn = fn(&mut UserPeripherals) -> bool;
+
+/// Maximum number of optional components.
+pub(crate) const MAX_OPTIONAL_COMPONENTS: usize = 8;
+
+/// Maximum number of steps in the startup report.
+pub(crate) const MAX_STARTUP_STEPS: usize = 16;
+
+/// Statuses of the optional components, with their names.
+pub(crate) type ComponentStatusList = Vec<(&'static str, ComponentStatus), MAX_OPTIONAL_COMPONENTS>;
+
+/// Initialization path of the application.
+#[derive(Copy, Clone)]
+pub struct StartupPath {
+    /// Initialization function.
+    pub init: StartupFn,
+    /// Maximum execution time of the initialization function.
+    pub budget: Duration,
+    /// Code of the fault stored in the fault recorder when the budget is exceeded.
+    pub fault_code: u32,
+}
+
+/// Configuration of the system startup.
+#[derive(Copy, Clone)]
+pub struct StartupConfig {
+    /// Time waited after the hardware is configured, before the initialization path is executed.
+    pub delay: Duration,
+    /// Path executed after power-on and external resets, and after warm resets if there is no
+    /// warm-start path.
+    pub cold_start: Option<StartupPath>,
+    /// Path executed after warm resets.
+    pub warm_start: Option<StartupPath>,
+    /// Components initialized after the path, which system can run without. There can be up to 8
+    /// optional components.
+    pub optional_components: &'static [OptionalComponent],
+}
+
+impl Default for StartupConfig {
+    fn default() -> Self {
+        StartupConfig {
+            delay: Duration::from_ticks(0),
+            cold_start: None,
+            warm_start: None,
+            optional_components: &[],
+        }
+    }
+}
+
+impl StartupConfig {
+    /// Selects initialization path for the reset cause.
+    ///
+    /// # Parameters
+    /// * `reset_cause` - Cause of the last reset.
+    ///
+    /// # Return
+    /// Selected path with `true` if it's the warm-start path, or `None` if there is no path to
+    /// execute.
+    pub(crate) fn select_path(&self, reset_cause: ResetCause) -> Option<(StartupPath, bool)> {
+        match self.warm_start {
+            Some(path) if reset_cause.is_warm() => Some((path, true)),
+            _ => self.cold_start.map(|path| (path, false)),
+        }
+    }
+}
+
+/// Component that the system can run without.
+#[derive(Copy, Clone)]
+pub struct OptionalComponent {
+    /// Name of the component.
+    pub name: &'static str,
+    /// Initialization function.
+    pub init: OptionalInitFn,
+    /// Initialization of the degraded mode, executed when initialization of the component fails,
+    /// `None` if component is simply left out.
+    pub fallback: Option<OptionalInitFn>,
+    /// Code of the fault stored in the fault recorder when initialization fails.
+    pub fault_code: u32,
+}
+
+impl OptionalComponent {
+    /// Initializes the component, or its fallback if initialization fails.
+    ///
+    /// # Parameters
+    /// * `peripherals` - Peripherals available to the user.
+    ///
+    /// # Return
+    /// Status of the component.
+    pub(crate) fn initialize(&self, peripherals: &mut UserPeripherals) -> ComponentStatus {
+        if (self.init)(peripherals) {
+            return ComponentStatus::Nominal;
+        }
+
+        match self.fallback {
+            Some(fallback) if fallback(peripherals) => ComponentStatus::Degraded,
+            _ => ComponentStatus::Failed,
+        }
+    }
+}
+
+/// Status of the optional component.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum ComponentStatus {
+    /// Component was initialized.
+    Nominal,
+    /// Component failed to initialize, and its fallback is used instead.
+    Degraded,
+    /// Component failed to initialize, and there is no working fallback.
+    Failed,
+}
+
+/// Information about the system startup.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct StartupInfo {
+    /// Cause of the last reset.
+    pub reset_cause: ResetCause,
+    /// Reason of the controlled shutdown before the last reset, `None` if the reset wasn't
+    /// caused by a [controlled shutdown](crate::api::RuntimeApi::shutdown).
+    pub shutdown_reason: Option<ShutdownReason>,
+    /// Whether warm-start path was executed.
+    pub warm_start: bool,
+    /// Whether the executed path exceeded its timing budget.
+    pub budget_exceeded: bool,
+    /// Whether any optional component failed to initialize.
+    pub degraded: bool,
+}
+
+/// Step of the system startup.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct StartupStep {
+    /// Name of the step.
+    pub name: &'static str,
+    /// Duration of the step.
+    pub duration: Duration,
+}
+
+/// Durations of the system startup steps.
+///
+/// Steps follow each other, so each of them lasts from the end of the previous one. First step
+/// is measured from the start of the system timer, which is started during the hardware
+/// configuration.
+#[derive(Debug, Clone)]
+pub struct StartupReport {
+    /// Recorded steps.
+    steps: Vec<StartupStep, MAX_STARTUP_STEPS>,
+    /// End of the last recorded step.
+    last_step_end: Instant,
+    /// Whether any step wasn't recorded, because the report was full.
+    truncated: bool,
+}
+
+impl StartupReport {
+    /// Creates new, empty report.
+    pub(crate) const fn new() -> Self {
+        StartupReport {
+            steps: Vec::new(),
+            last_step_end: Instant::from_ticks(0),
+            truncated: false,
+        }
+    }
+
+    /// Records a step that ended at given time.
+    ///
+    /// # Parameters
+    /// * `name` - Name of the step.
+    /// * `end` - End of the step.
+    pub(crate) fn record(&mut self, name: &'static str, end: Instant) {
+        let duration = end
+            .checked_duration_since(self.last_step_end)
+            .unwrap_or(Duration::from_ticks(0));
+        self.last_step_end = end;
+
+        if self.steps.push(StartupStep { name, duration }).is_err() {
+            self.truncated = true;
+        }
+    }
+
+    /// Returns recorded steps.
+    pub fn steps(&self) -> &[StartupStep] {
+        &self.steps
+    }
+
+    /// Returns total duration of the startup, until the end of the last recorded step.
+    pub fn total(&self) -> Duration {
+        self.last_step_end.duration_since_epoch()
+    }
+
+    /// Returns `true` if some steps weren't recorded, because there were more than 16 of them.
+    ///
+    /// Duration of a step that wasn't recorded is still included in the total duration.
+    pub fn is_truncated(&self) -> bool {
+        self.truncated
+    }
+}
+
+impl fmt::Display for StartupReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Startup took {}", self.total())?;
+        for step in self.steps() {
+            write!(f, ", {}: {}", step.name, step.duration)?;
+        }
+        if self.is_truncated() {
+            write!(f, ", ...")?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn cold_init(_: &mut UserPeripherals) {}
+
+    fn warm_init(_: &mut UserPeripherals) {}
+
+    #[test]
+    fn select_path_by_reset_cause() {
+        let path = |init: StartupFn| StartupPath {
+            init,
+            budget: Duration::millis(10),
+            fault_code: 0,
+        };
+        let config = StartupConfig {
+            cold_start: Some(path(cold_init)),
+            warm_start: Some(path(warm_init)),
+            ..Default::default()
+        };
+
+        let (_, warm) = config.select_path(ResetCause::PowerOn).unwrap();
+        assert!(!warm);
+        let (_, warm) = config.select_path(ResetCause::External).unwrap();
+        assert!(!warm);
+        let (_, warm) = config.select_path(ResetCause::Watchdog).unwrap();
+        assert!(warm);
+
+        let cold_only = StartupConfig {
+            warm_start: None,
+            ..config
+        };
+        let (_, warm) = cold_only.select_path(ResetCause::Software).unwrap();
+        assert!(!warm);
+
+        assert!(StartupConfig::default()
+            .select_path(ResetCause::PowerOn)
+            .is_none());
+    }
+
+    #[cfg(feature = "use-aerugo-x86")]
+    fn init_ok(_: &mut UserPeripherals) -> bool {
+        true
+    }
+
+    #[cfg(feature = "use-aerugo-x86")]
+    fn init_failed(_: &mut UserPeripherals) -> bool {
+        false
+    }
+
+    // SAMV71 peripherals can't be created outside of the HAL initialization.
+    #[cfg(feature = "use-aerugo-x86")]
+    #[test]
+    fn optional_component_fallback() {
+        let component =
+            |init: OptionalInitFn, fallback: Option<OptionalInitFn>| OptionalComponent {
+                name: "Telemetry",
+                init,
+                fallback,
+                fault_code: 0,
+            };
+        let mut peripherals = UserPeripherals::default();
+
+        assert_eq!(
+            component(init_ok, Some(init_failed)).initialize(&mut peripherals),
+            ComponentStatus::Nominal
+        );
+        assert_eq!(
+            component(init_failed, Some(init_ok)).initialize(&mut peripherals),
+            ComponentStatus::Degraded
+        );
+        assert_eq!(
+            component(init_failed, Some(init_failed)).initialize(&mut peripherals),
+            ComponentStatus::Failed
+        );
+        assert_eq!(
+            component(init_failed, None).initialize(&mut peripherals),
+            ComponentStatus::Failed
+        );
+    }
+
+    #[test]
+    fn startup_report_steps() {
+        let mut report = StartupReport::new();
+        report.record("Hardware", Instant::from_ticks(100));
+        report.record("ColdStart", Instant::from_ticks(350));
+        report.record("Configuration", Instant::from_ticks(400));
+
+        assert_eq!(report.total(), Duration::from_ticks(400));
+        assert_eq!(report.steps().len(), 3);
+        assert_eq!(report.steps()[1].name, "ColdStart");
+        assert_eq!(report.steps()[1].duration, Duration::from_ticks(250));
+        assert!(!report.is_truncated());
+
+        for _ in 0..MAX_STARTUP_STEPS {
+            report.record("Step", Instant::from_ticks(500));
+        }
+        assert!(report.is_truncated());
+        assert_eq!(report.total(), Duration::from_ticks(500));
+    }
+}
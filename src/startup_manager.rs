@@ -0,0 +1,153 @@
+//! Startup manager.
+//!
+//! This module contains startup manager, which executes initialization path selected by the reset
+//! cause, initializes optional components, and records duration of each startup step in the
+//! [StartupReport].
+
+use core::cell::OnceCell;
+
+use aerugo_hal::AerugoHal;
+
+use crate::aerugo::Aerugo;
+use crate::fault::FaultRecord;
+use crate::hal::{Hal, UserPeripherals};
+use crate::mutex::Mutex;
+use crate::shutdown_reason::ShutdownReason;
+use crate::startup::{
+    ComponentStatus, ComponentStatusList, StartupConfig, StartupInfo, StartupReport,
+};
+
+/// Startup manager.
+///
+/// This shouldn't be created by hand by the user or anywhere else in the code.
+/// It should be used as a singleton (crate::aerugo::STARTUP_MANAGER) and shouldn't be directly
+/// accessed by any other part of the system.
+pub(crate) struct StartupManager {
+    /// Information about the system startup.
+    info: OnceCell<StartupInfo>,
+    /// Statuses of the optional components.
+    component_status: OnceCell<ComponentStatusList>,
+    /// Durations of the startup steps.
+    report: Mutex<StartupReport>,
+}
+
+/// Startup information is set only once, during system initialization, which can't be
+/// interrupted, and is only read after that.
+unsafe impl Sync for StartupManager {}
+
+impl StartupManager {
+    /// Creates new startup manager instance.
+    ///
+    /// # Safety
+    /// This shouldn't be called more than once.
+    pub(crate) const fn new() -> Self {
+        StartupManager {
+            info: OnceCell::new(),
+            component_status: OnceCell::new(),
+            report: Mutex::new(StartupReport::new()),
+        }
+    }
+
+    /// Waits for the startup delay and executes initialization path selected by the reset cause,
+    /// and then initializes optional components.
+    ///
+    /// # Parameters
+    /// * `startup` - Startup configuration.
+    /// * `peripherals` - Peripherals available to the user.
+    pub(crate) fn execute(
+        &'static self,
+        startup: &StartupConfig,
+        peripherals: &mut UserPeripherals,
+    ) {
+        let delay_end = Hal::get_system_time() + startup.delay;
+        while Hal::get_system_time() < delay_end {
+            Hal::feed_watchdog();
+        }
+        if startup.delay.ticks() > 0 {
+            self.record_step("StartupDelay");
+        }
+
+        let reset_cause = Hal::reset_cause();
+        let mut info = StartupInfo {
+            reset_cause,
+            shutdown_reason: Hal::take_shutdown_reason().and_then(ShutdownReason::from_code),
+            warm_start: false,
+            budget_exceeded: false,
+            degraded: false,
+        };
+
+        if let Some((path, warm_start)) = startup.select_path(reset_cause) {
+            Hal::feed_watchdog();
+            let start = Hal::get_system_time();
+            (path.init)(peripherals);
+            let duration = Hal::get_system_time() - start;
+            Hal::feed_watchdog();
+
+            let name = if warm_start { "WarmStart" } else { "ColdStart" };
+            self.record_step(name);
+
+            info.warm_start = warm_start;
+            info.budget_exceeded = duration > path.budget;
+            if info.budget_exceeded {
+                Aerugo::store_fault(FaultRecord::new(name, 0, path.fault_code));
+            }
+        }
+
+        let mut component_status = ComponentStatusList::new();
+        for component in startup.optional_components {
+            Hal::feed_watchdog();
+            let status = component.initialize(peripherals);
+            Hal::feed_watchdog();
+            self.record_step(component.name);
+
+            if status != ComponentStatus::Nominal {
+                info.degraded = true;
+                Aerugo::store_fault(FaultRecord::new(component.name, 0, component.fault_code));
+            }
+
+            if component_status.push((component.name, status)).is_err() {
+                panic!("Too many optional components");
+            }
+        }
+
+        self.info.set(info).expect("System was already initialized");
+        self.component_status
+            .set(component_status)
+            .expect("System was already initialized");
+    }
+
+    /// Records the end of a startup step in the startup report.
+    ///
+    /// # Parameters
+    /// * `name` - Name of the step.
+    pub(crate) fn record_step(&'static self, name: &'static str) {
+        let end = Hal::get_system_time();
+        self.report.lock(|report| report.record(name, end));
+    }
+
+    /// Returns information about the system startup.
+    pub(crate) fn info(&'static self) -> StartupInfo {
+        *self.info.get().expect("System is not initialized")
+    }
+
+    /// Returns durations of the startup steps recorded so far.
+    pub(crate) fn report(&'static self) -> StartupReport {
+        self.report.lock(|report| report.clone())
+    }
+
+    /// Returns status of the optional component.
+    ///
+    /// # Parameters
+    /// * `name` - Name of the component.
+    ///
+    /// # Return
+    /// Status of the component, or `None` if there is no component with given name.
+    pub(crate) fn component_status(&'static self, name: &str) -> Option<ComponentStatus> {
+        self.component_status
+            .get()
+            .expect("System is not initialized")
+            .iter()
+            .find(|(component, _)| *component == name)
+            .map(|(_, status)| *status)
+    }
+}
@@ -0,0 +1,370 @@
+//! Stimulus scripts for functional tests.
+//!
+//! Stimulus script describes inputs delivered to the system at given times, so functional test
+//! scenarios can be written as text and executed by a single test binary, instead of writing a new
+//! binary for each case. Script consists of statements, separated with new lines or `;`:
+//! * `at <time> send <target> <value>` - sends value to the message queue,
+//! * `at <time> emit <target>` - emits an event,
+//! * `at <time> set <target> <true|false>` - sets value of the boolean condition,
+//! * `at <time> stop` - ends the scenario,
+//! * `expect ...` - expectation checked by the test harness, ignored by the script.
+//!
+//! Time is given in `us`, `ms` or `s` since the start of the system (ex. `150ms`), and statements
+//! have to be ordered by time. Lines starting with `#` are comments. Targets are referred to by
+//! names under which they were [registered](StimulusScript::register) by the test binary.
+//!
+//! ```text
+//! at 100ms send QueueX 5; at 200ms emit EventStart
+//! at 300ms stop
+//! expect stdout contains "TaskA: 5"
+//! ```
+
+use core::cell::OnceCell;
+use core::str::FromStr;
+
+use heapless::Vec;
+
+use crate::boolean_condition::BooleanConditionStorage;
+use crate::event::EventStorage;
+use crate::message_queue::MessageQueueStorage;
+use crate::mutex::Mutex;
+use crate::time::Duration;
+
+/// Target of the stimulus, which can be referred to by name in the script.
+pub trait StimulusTarget {
+    /// Applies stimulus to the target.
+    ///
+    /// # Parameters
+    /// * `action` - Name of the action (ex. `send`).
+    /// * `argument` - Argument of the action, if given.
+    ///
+    /// # Return
+    /// `()` if successful, `StimulusErrorKind` otherwise.
+    fn stimulate(
+        &'static self,
+        action: &str,
+        argument: Option<&str>,
+    ) -> Result<(), StimulusErrorKind>;
+}
+
+impl<T: FromStr, const N: usize> StimulusTarget for MessageQueueStorage<T, N> {
+    fn stimulate(
+        &'static self,
+        action: &str,
+        argument: Option<&str>,
+    ) -> Result<(), StimulusErrorKind> {
+        if action != "send" {
+            return Err(StimulusErrorKind::UnsupportedAction);
+        }
+
+        let value = argument
+            .and_then(|argument| argument.parse().ok())
+            .ok_or(StimulusErrorKind::InvalidArgument)?;
+
+        self.create_handle()
+            .ok_or(StimulusErrorKind::NotInitialized)?
+            .send_data(value)
+            .map_err(|_| StimulusErrorKind::Rejected)
+    }
+}
+
+impl StimulusTarget for EventStorage {
+    fn stimulate(
+        &'static self,
+        action: &str,
+        argument: Option<&str>,
+    ) -> Result<(), StimulusErrorKind> {
+        match (action, argument) {
+            ("emit", None) => {
+                self.create_handle()
+                    .ok_or(StimulusErrorKind::NotInitialized)?
+                    .emit();
+                Ok(())
+            }
+            ("emit", Some(_)) => Err(StimulusErrorKind::InvalidArgument),
+            _ => Err(StimulusErrorKind::UnsupportedAction),
+        }
+    }
+}
+
+impl StimulusTarget for BooleanConditionStorage {
+    fn stimulate(
+        &'static self,
+        action: &str,
+        argument: Option<&str>,
+    ) -> Result<(), StimulusErrorKind> {
+        if action != "set" {
+            return Err(StimulusErrorKind::UnsupportedAction);
+        }
+
+        let value = argument
+            .and_then(|argument| argument.parse().ok())
+            .ok_or(StimulusErrorKind::InvalidArgument)?;
+
+        self.create_handle()
+            .ok_or(StimulusErrorKind::NotInitialized)?
+            .set_value(value);
+        Ok(())
+    }
+}
+
+/// Kind of the stimulus script error.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum StimulusErrorKind {
+    /// Statement couldn't be parsed.
+    Syntax,
+    /// Statement refers to a target that wasn't registered.
+    UnknownTarget,
+    /// Target doesn't support the action.
+    UnsupportedAction,
+    /// Argument is missing or invalid for the target.
+    InvalidArgument,
+    /// Target wasn't initialized.
+    NotInitialized,
+    /// Target rejected the stimulus (ex. queue was full).
+    Rejected,
+}
+
+/// Stimulus script error.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct StimulusError {
+    /// Number of the script line with the failed statement, starting from 1.
+    pub line: usize,
+    /// Kind of the error.
+    pub kind: StimulusErrorKind,
+}
+
+/// State of the stimulus script.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum StimulusStatus {
+    /// Some statements are still waiting for their time.
+    Running,
+    /// All statements were executed, or `stop` statement was reached.
+    Finished,
+}
+
+/// Stimulus script interpreter.
+///
+/// Script should be a static variable. Test binary [registers](StimulusScript::register) targets
+/// under their names, [loads](StimulusScript::load) the script, and periodically
+/// [runs](StimulusScript::run) it (ex. from a cyclic tasklet) with the time elapsed since the start
+/// of the system.
+///
+/// # Generic Parameters
+/// * `N` - Maximum number of registered targets.
+pub struct StimulusScript<const N: usize> {
+    /// Registered targets with their names.
+    targets: Mutex<Vec<(&'static str, &'static dyn StimulusTarget), N>>,
+    /// Text of the script.
+    script: OnceCell<&'static str>,
+    /// Number of statements that were already executed.
+    executed: Mutex<usize>,
+}
+
+/// It is safe assuming that script is loaded only once, before the system is started. Registered
+/// targets and the script state are accessed in the critical section.
+unsafe impl<const N: usize> Sync for StimulusScript<N> {}
+
+impl<const N: usize> StimulusScript<N> {
+    /// Creates new stimulus script interpreter.
+    pub const fn new() -> Self {
+        StimulusScript {
+            targets: Mutex::new(Vec::new()),
+            script: OnceCell::new(),
+            executed: Mutex::new(0),
+        }
+    }
+
+    /// Registers target under given name.
+    ///
+    /// # Parameters
+    /// * `name` - Name of the target used in the script.
+    /// * `target` - Stimulus target (ex. message queue, event or boolean condition storage).
+    ///
+    /// # Return
+    /// `()` if successful, `name` back if the target list is full.
+    pub fn register(
+        &'static self,
+        name: &'static str,
+        target: &'static dyn StimulusTarget,
+    ) -> Result<(), &'static str> {
+        self.targets
+            .lock(|targets| targets.push((name, target)))
+            .map_err(|(name, _)| name)
+    }
+
+    /// Loads the script.
+    ///
+    /// # Parameters
+    /// * `script` - Text of the script.
+    ///
+    /// # Return
+    /// `()` if successful, `script` back if a script was already loaded.
+    pub fn load(&'static self, script: &'static str) -> Result<(), &'static str> {
+        self.script.set(script)
+    }
+
+    /// Executes statements due at given time.
+    ///
+    /// # Parameters
+    /// * `time` - Time elapsed since the start of the system.
+    ///
+    /// # Return
+    /// Status of the script if successful, `StimulusError` for the first failed statement
+    /// otherwise.
+    pub fn run(&'static self, time: Duration) -> Result<StimulusStatus, StimulusError> {
+        let script = self.script.get().copied().unwrap_or_default();
+        let executed = self.executed.lock(|executed| *executed);
+
+        for (line, statement) in statements(script).skip(executed) {
+            let error = |kind| StimulusError { line, kind };
+            let mut words = statement.split_whitespace();
+
+            match words.next() {
+                None | Some("expect") => (),
+                Some("at") => {
+                    let statement_time = words
+                        .next()
+                        .and_then(parse_time)
+                        .ok_or(error(StimulusErrorKind::Syntax))?;
+
+                    if statement_time > time {
+                        return Ok(StimulusStatus::Running);
+                    }
+
+                    match (words.next(), words.next(), words.next(), words.next()) {
+                        (Some("stop"), None, _, _) => {
+                            self.executed.lock(|executed| *executed = usize::MAX);
+                            return Ok(StimulusStatus::Finished);
+                        }
+                        (Some("stop"), ..) => return Err(error(StimulusErrorKind::Syntax)),
+                        (Some(action), Some(name), argument, None) => {
+                            self.stimulate(name, action, argument).map_err(error)?
+                        }
+                        _ => return Err(error(StimulusErrorKind::Syntax)),
+                    }
+                }
+                Some(_) => return Err(error(StimulusErrorKind::Syntax)),
+            }
+
+            self.executed.lock(|executed| *executed += 1);
+        }
+
+        Ok(StimulusStatus::Finished)
+    }
+
+    /// Applies stimulus to the target with given name.
+    ///
+    /// # Parameters
+    /// * `name` - Name of the target.
+    /// * `action` - Name of the action.
+    /// * `argument` - Argument of the action, if given.
+    ///
+    /// # Return
+    /// `()` if successful, `StimulusErrorKind` otherwise.
+    fn stimulate(
+        &'static self,
+        name: &str,
+        action: &str,
+        argument: Option<&str>,
+    ) -> Result<(), StimulusErrorKind> {
+        let target = self
+            .targets
+            .lock(|targets| {
+                targets
+                    .iter()
+                    .find(|(target_name, _)| *target_name == name)
+                    .map(|(_, target)| *target)
+            })
+            .ok_or(StimulusErrorKind::UnknownTarget)?;
+
+        target.stimulate(action, argument)
+    }
+}
+
+/// Returns statements of the script with their line numbers, skipping comments.
+///
+/// # Parameters
+/// * `script` - Text of the script.
+fn statements(script: &str) -> impl Iterator<Item = (usize, &str)> {
+    script
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim_start().starts_with('#'))
+        .flat_map(|(index, line)| line.split(';').map(move |statement| (index + 1, statement)))
+}
+
+/// Parses time with unit (`us`, `ms` or `s`).
+///
+/// # Parameters
+/// * `time` - Time to parse.
+///
+/// # Return
+/// Parsed time, or `None` if it's invalid.
+fn parse_time(time: &str) -> Option<Duration> {
+    let (value, constructor): (&str, fn(u64) -> Duration) =
+        if let Some(value) = time.strip_suffix("us") {
+            (value, Duration::micros)
+        } else if let Some(value) = time.strip_suffix("ms") {
+            (value, Duration::millis)
+        } else {
+            (time.strip_suffix('s')?, Duration::secs)
+        };
+
+    value.parse().ok().map(constructor)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::message_queue::OverflowPolicy;
+
+    #[test]
+    fn statements_are_executed_on_time() {
+        static QUEUE: MessageQueueStorage<u32, 4> = MessageQueueStorage::new();
+        static SCRIPT: StimulusScript<2> = StimulusScript::new();
+        unsafe { QUEUE.init(OverflowPolicy::Reject).unwrap() };
+        let queue = QUEUE.create_handle().unwrap();
+
+        SCRIPT.register("QueueX", &QUEUE).unwrap();
+        SCRIPT
+            .load(
+                "# Scenario\n\
+                 at 10ms send QueueX 5; at 20ms send QueueX 7\n\
+                 expect stdout contains \"5\"\n\
+                 at 1s stop\n\
+                 at 2s send QueueY 1",
+            )
+            .unwrap();
+
+        assert_eq!(
+            SCRIPT.run(Duration::millis(15)),
+            Ok(StimulusStatus::Running)
+        );
+        assert_eq!(queue.len(), 1);
+
+        assert_eq!(
+            SCRIPT.run(Duration::millis(25)),
+            Ok(StimulusStatus::Running)
+        );
+        assert_eq!(queue.len(), 2);
+
+        assert_eq!(SCRIPT.run(Duration::secs(3)), Ok(StimulusStatus::Finished));
+        assert_eq!(SCRIPT.run(Duration::secs(4)), Ok(StimulusStatus::Finished));
+    }
+
+    #[test]
+    fn errors_point_to_line() {
+        static SCRIPT: StimulusScript<1> = StimulusScript::new();
+        SCRIPT.load("at 1ms stop now\nat 2ms emit Event").unwrap();
+
+        assert_eq!(
+            SCRIPT.run(Duration::millis(5)),
+            Err(StimulusError {
+                line: 1,
+                kind: StimulusErrorKind::Syntax
+            })
+        );
+    }
+}
@@ -0,0 +1,216 @@
+//! Declarative definition of the system.
+//!
+//! Each tasklet, queue, event and condition requires a storage static, creation in the system,
+//! creation of a handle and subscription. With many tasklets that boilerplate is easy to get wrong,
+//! for example by subscribing a tasklet to a wrong queue. [system](crate::system) macro declares
+//! all of them in a single block, and generates the storage statics and the initialization code.
+
+/// Declares static system structure.
+///
+/// Macro generates storage statics for the declared message queues, events, boolean conditions and
+/// tasklets, and an initialization function that creates them in the system, and subscribes
+/// tasklets to their data sources and condition sets. Initialization function has to be called
+/// after [initialize](crate::aerugo::Aerugo::initialize) and before
+/// [start](crate::api::InitApi::start). Storages can be still used directly, for example to create
+/// handles used in interrupt handlers.
+///
+/// Sections have to be given in the order shown below, and each of them can be omitted. Tasklet is
+/// created with the default context if `context` is not given. Tasklet can be subscribed to:
+/// * `queue(QUEUE)` - message queue declared in `queues` section,
+/// * `events [EVENT, ...]` - list of events declared in `events` section,
+/// * `condition(CONDITION)` - boolean condition declared in `conditions` section,
+/// * `cyclic(period)` - cyclic execution with given period.
+///
+/// Data sources and conditions are resolved by the names given in their sections, so subscribing a
+/// tasklet to a source that wasn't declared fails to compile instead of subscribing it to a wrong
+/// one.
+///
+/// Tasklet conditions are given as the [type](crate::BooleanConditionSetType) of the set and a
+/// list of conditions declared in `conditions` section. Size of the list has to match the number
+/// of conditions in the tasklet storage type.
+///
+/// # Example
+/// ```
+/// # use aerugo::{
+/// #     Aerugo, BooleanConditionStorage, Duration, EventId, EventStorage, MessageQueueStorage,
+/// #     RuntimeApi, SystemHardwareConfig, TaskletConfig, TaskletStorage,
+/// # };
+/// #[derive(Default)]
+/// struct ProducerContext {
+///     value: u8,
+/// }
+///
+/// fn producer(_: (), context: &mut ProducerContext, _: &'static dyn RuntimeApi) {
+///     context.value = context.value.wrapping_add(1);
+/// }
+///
+/// fn consumer(_: u8, _: &mut (), _: &'static dyn RuntimeApi) {}
+///
+/// fn monitor(_: EventId, _: &mut (), _: &'static dyn RuntimeApi) {}
+///
+/// aerugo::system! {
+///     fn init_system;
+///
+///     queues {
+///         DATA_QUEUE: MessageQueueStorage<u8, 8>;
+///     }
+///
+///     events {
+///         START_EVENT: 1;
+///         STOP_EVENT: 2;
+///     }
+///
+///     conditions {
+///         ENABLED: true;
+///     }
+///
+///     tasklets {
+///         PRODUCER: TaskletStorage<(), ProducerContext, 1> {
+///             config: TaskletConfig { name: "Producer", ..Default::default() },
+///             step: producer,
+///             context: ProducerContext { value: 10 },
+///             subscribe: cyclic(Duration::millis(100)),
+///             conditions: And [ENABLED],
+///         }
+///         CONSUMER: TaskletStorage<u8, (), 0> {
+///             config: TaskletConfig { name: "Consumer", ..Default::default() },
+///             step: consumer,
+///             subscribe: queue(DATA_QUEUE),
+///         }
+///         MONITOR: TaskletStorage<EventId, (), 0> {
+///             config: TaskletConfig { name: "Monitor", ..Default::default() },
+///             step: monitor,
+///             subscribe: events [START_EVENT, STOP_EVENT],
+///         }
+///     }
+/// }
+///
+/// fn main() {
+///     let (aerugo, _) = Aerugo::initialize(SystemHardwareConfig::default());
+///     init_system(aerugo);
+///     # assert!(PRODUCER.create_handle().is_some());
+/// }
+/// ```
+///
+/// Subscription to an event that wasn't declared is rejected:
+/// ```compile_fail
+/// # use aerugo::{Aerugo, EventId, RuntimeApi, SystemHardwareConfig, TaskletConfig, TaskletStorage};
+/// fn monitor(_: EventId, _: &mut (), _: &'static dyn RuntimeApi) {}
+///
+/// aerugo::system! {
+///     fn init_system;
+///
+///     events {
+///         START_EVENT: 1;
+///     }
+///
+///     tasklets {
+///         MONITOR: TaskletStorage<EventId, (), 0> {
+///             config: TaskletConfig { name: "Monitor", ..Default::default() },
+///             step: monitor,
+///             subscribe: events [START_EVENT, STOP_EVENT],
+///         }
+///     }
+/// }
+/// #
+/// # fn main() {}
+/// ```
+#[macro_export]
+macro_rules! system {
+    (@create $aerugo:ident, $storage:ident, $config:expr, $step:expr) => {
+        $aerugo.create_tasklet($config, $step, &$storage);
+    };
+    (@create $aerugo:ident, $storage:ident, $config:expr, $step:expr, $context:expr) => {
+        $aerugo.create_tasklet_with_context($config, $step, $context, &$storage);
+    };
+    (@subscribe $aerugo:ident, $handle:ident, queue($queue:ident)) => {
+        $aerugo.subscribe_tasklet_to_queue(
+            &$handle,
+            &$queue
+                .create_handle()
+                .expect(concat!("Failed to create handle of ", stringify!($queue))),
+        );
+    };
+    (@subscribe $aerugo:ident, $handle:ident, events [$($event:ident),* $(,)?]) => {
+        $aerugo.subscribe_tasklet_to_events(&$handle, [$(system_event_id!($event)),*]);
+    };
+    (@subscribe $aerugo:ident, $handle:ident, condition($condition:ident)) => {
+        $aerugo.subscribe_tasklet_to_condition(
+            &$handle,
+            &$condition
+                .create_handle()
+                .expect(concat!("Failed to create handle of ", stringify!($condition))),
+        );
+    };
+    (@subscribe $aerugo:ident, $handle:ident, cyclic($period:expr)) => {
+        $aerugo.subscribe_tasklet_to_cyclic(&$handle, Some($period), None);
+    };
+    (
+        $vis:vis fn $init:ident;
+
+        $(queues {
+            $($queue:ident : $queue_type:ty;)*
+        })?
+
+        $(events {
+            $($event:ident : $event_id:expr;)*
+        })?
+
+        $(conditions {
+            $($condition:ident : $condition_value:expr;)*
+        })?
+
+        $(tasklets {
+            $($tasklet:ident : $tasklet_type:ty {
+                config: $config:expr,
+                step: $step:expr,
+                $(context: $context:expr,)?
+                $(subscribe: $source:ident $source_args:tt,)?
+                $(conditions: $set_type:ident [$($tasklet_condition:ident),* $(,)?],)?
+            })*
+        })?
+    ) => {
+        $($(static $queue: $queue_type = <$queue_type>::new();)*)?
+        $($(static $event: $crate::EventStorage = $crate::EventStorage::new();)*)?
+        $($(
+            static $condition: $crate::BooleanConditionStorage =
+                $crate::BooleanConditionStorage::new();
+        )*)?
+        $($(static $tasklet: $tasklet_type = <$tasklet_type>::new();)*)?
+
+        /// Creates declared system structure.
+        ///
+        /// # Parameters
+        /// * `aerugo` - System instance.
+        $vis fn $init(aerugo: &'static impl $crate::InitApi) {
+            $($(aerugo.create_message_queue(&$queue);)*)?
+            $($(aerugo.create_event($event_id, &$event);)*)?
+            $($(aerugo.create_boolean_condition($condition_value, &$condition);)*)?
+            $($($crate::system!(@create aerugo, $tasklet, $config, $step $(, $context)?);)*)?
+
+            // Resolves IDs of the events declared in `events` section by their names.
+            $(macro_rules! system_event_id {
+                $(($event) => { $event_id };)*
+            })?
+
+            $($({
+                #[allow(unused_variables)]
+                let handle = $tasklet
+                    .create_handle()
+                    .expect(concat!("Failed to create handle of ", stringify!($tasklet)));
+
+                $($crate::system!(@subscribe aerugo, handle, $source $source_args);)?
+                $(aerugo.set_tasklet_conditions(
+                    &handle,
+                    $crate::BooleanConditionSet::from_array(
+                        [$(&$tasklet_condition.create_handle().expect(concat!(
+                            "Failed to create handle of ",
+                            stringify!($tasklet_condition)
+                        ))),*],
+                        $crate::BooleanConditionSetType::$set_type,
+                    ),
+                );)?
+            })*)?
+        }
+    };
+}
@@ -0,0 +1,225 @@
+//! Snapshot of the system state.
+//!
+//! Snapshot is written with [dump_system_state](crate::api::RuntimeApi::dump_system_state) as
+//! human-readable text, one line per object, so it can be printed on a serial console when
+//! debugging a device in the field:
+//! * tasklet - ID, name, status, time of the last execution, number of elements waiting in its
+//!   data source and values of its conditions,
+//! * event - ID, time of the scheduled emission and number of tasklets for which it's pending.
+//!
+//! Conditions are identified with the same opaque object IDs as in the
+//! [configuration manifest](crate::api::InitApi::write_manifest). Conditions of nested condition
+//! sets are written in parentheses, preceded by the type of the set, ex. `1=true, or(2=false)`.
+
+use core::fmt;
+
+use crate::boolean_condition::ConditionTerm;
+use crate::event::EventId;
+use crate::manifest::{object_id, set_type_name};
+use crate::tasklet::{TaskletId, TaskletStatus};
+use crate::time::Instant;
+
+/// State of the tasklet.
+pub(crate) struct TaskletState {
+    /// Tasklet ID.
+    pub(crate) id: TaskletId,
+    /// Tasklet name.
+    pub(crate) name: &'static str,
+    /// Tasklet status.
+    pub(crate) status: TaskletStatus,
+    /// `true` if tasklet is suspended.
+    pub(crate) suspended: bool,
+    /// Time of the last execution.
+    pub(crate) last_execution_time: Instant,
+    /// Number of elements waiting in the data source, `None` if tasklet isn't subscribed.
+    pub(crate) waiting: Option<usize>,
+    /// Value and terms of the condition set, if it's set.
+    pub(crate) conditions: Option<(bool, &'static [ConditionTerm])>,
+}
+
+impl fmt::Display for TaskletState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Tasklet #{} {}: {:?}{}, last execution at {}, ",
+            self.id,
+            self.name,
+            self.status,
+            if self.suspended { " (suspended)" } else { "" },
+            self.last_execution_time
+        )?;
+
+        match self.waiting {
+            Some(waiting) => write!(f, "{waiting} waiting")?,
+            None => write!(f, "not subscribed")?,
+        }
+
+        if let Some((value, terms)) = self.conditions {
+            write!(f, ", conditions {value} [")?;
+            write_condition_values(f, terms)?;
+            write!(f, "]")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Writes values of the conditions, with conditions of the nested sets in parentheses.
+///
+/// # Parameters
+/// * `f` - Formatter.
+/// * `terms` - Terms of the set, with terms of the nested sets.
+fn write_condition_values(f: &mut fmt::Formatter<'_>, terms: &[ConditionTerm]) -> fmt::Result {
+    let mut remaining = terms;
+    let mut first = true;
+    while let Some((term, rest)) = remaining.split_first() {
+        if !first {
+            write!(f, ", ")?;
+        }
+        first = false;
+
+        remaining = match *term {
+            ConditionTerm::Condition { condition, .. } => {
+                write!(f, "{}={}", object_id(condition), condition.get_value())?;
+                rest
+            }
+            ConditionTerm::Set { set_type, len } => {
+                let (nested, rest) = rest.split_at(len);
+                write!(f, "{}(", set_type_name(set_type))?;
+                write_condition_values(f, nested)?;
+                write!(f, ")")?;
+                rest
+            }
+        };
+    }
+
+    Ok(())
+}
+
+/// State of the event.
+pub(crate) struct EventState {
+    /// Event ID.
+    pub(crate) id: EventId,
+    /// Time of the scheduled emission, `None` if event is not scheduled.
+    pub(crate) scheduled: Option<Instant>,
+    /// Number of tasklets for which the event was emitted, but not processed yet.
+    pub(crate) pending: usize,
+}
+
+impl fmt::Display for EventState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "Event #{}: pending for {} tasklets",
+            self.id, self.pending
+        )?;
+
+        match self.scheduled {
+            Some(time) => write!(f, ", scheduled at {time}"),
+            None => write!(f, ", not scheduled"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use core::fmt::Write;
+    use core::ptr::addr_of_mut;
+
+    use crate::boolean_condition::{BooleanConditionSetType, BooleanConditionStorage};
+
+    #[test]
+    fn state_lines() {
+        let mut line = heapless::String::<256>::new();
+
+        let tasklet = TaskletState {
+            id: TaskletId(2),
+            name: "Task",
+            status: TaskletStatus::Waiting,
+            suspended: true,
+            last_execution_time: Instant::from_ticks(1500),
+            waiting: Some(3),
+            conditions: Some((true, &[])),
+        };
+        write!(line, "{tasklet}").unwrap();
+        assert_eq!(
+            line,
+            "Tasklet #2 Task: Waiting (suspended), last execution at 1500 us, 3 waiting, \
+             conditions true []"
+        );
+
+        line.clear();
+        let event = EventState {
+            id: 7,
+            scheduled: None,
+            pending: 1,
+        };
+        write!(line, "{event}").unwrap();
+        assert_eq!(line, "Event #7: pending for 1 tasklets, not scheduled");
+    }
+
+    #[test]
+    fn nested_condition_values() {
+        static CONDITION_X_STORAGE: BooleanConditionStorage = BooleanConditionStorage::new();
+        static CONDITION_Y_STORAGE: BooleanConditionStorage = BooleanConditionStorage::new();
+        static CONDITION_Z_STORAGE: BooleanConditionStorage = BooleanConditionStorage::new();
+        static mut TERMS: Option<[ConditionTerm; 4]> = None;
+
+        unsafe {
+            CONDITION_X_STORAGE.init(true).unwrap();
+            CONDITION_Y_STORAGE.init(false).unwrap();
+            CONDITION_Z_STORAGE.init(true).unwrap();
+        }
+        let x = CONDITION_X_STORAGE.create_handle().unwrap().condition();
+        let y = CONDITION_Y_STORAGE.create_handle().unwrap().condition();
+        let z = CONDITION_Z_STORAGE.create_handle().unwrap().condition();
+
+        // x && (y || !z)
+        let terms: &'static [ConditionTerm] = unsafe {
+            (*addr_of_mut!(TERMS)).insert([
+                ConditionTerm::Condition {
+                    condition: x,
+                    negated: false,
+                },
+                ConditionTerm::Set {
+                    set_type: BooleanConditionSetType::Or,
+                    len: 2,
+                },
+                ConditionTerm::Condition {
+                    condition: y,
+                    negated: false,
+                },
+                ConditionTerm::Condition {
+                    condition: z,
+                    negated: true,
+                },
+            ])
+        };
+
+        let mut line = heapless::String::<256>::new();
+        let tasklet = TaskletState {
+            id: TaskletId(3),
+            name: "Task",
+            status: TaskletStatus::Sleeping,
+            suspended: false,
+            last_execution_time: Instant::from_ticks(0),
+            waiting: None,
+            conditions: Some((false, terms)),
+        };
+        write!(line, "{tasklet}").unwrap();
+
+        let mut expected = heapless::String::<256>::new();
+        write!(
+            expected,
+            "Tasklet #3 Task: Sleeping, last execution at 0 us, not subscribed, \
+             conditions false [{}=true, or({}=false, {}=true)]",
+            object_id(x),
+            object_id(y),
+            object_id(z)
+        )
+        .unwrap();
+        assert_eq!(line, expected);
+    }
+}
@@ -9,35 +9,78 @@
 //! one tasklet is expected to perform one specific operation over received data. A bunch of
 //! tasklets can create
 
+mod step_result;
+mod tasklet_chain;
 mod tasklet_config;
+mod tasklet_data_source;
 mod tasklet_handle;
 mod tasklet_id;
 mod tasklet_ptr;
+mod tasklet_spawner;
 mod tasklet_status;
 mod tasklet_storage;
 mod tasklet_vtable;
 
+pub(crate) use self::tasklet_chain::CompletionListener;
 pub(crate) use self::tasklet_ptr::TaskletPtr;
 pub(crate) use self::tasklet_status::TaskletStatus;
 pub(crate) use self::tasklet_vtable::{tasklet_vtable, TaskletVTable};
 
-pub use self::tasklet_config::TaskletConfig;
-pub use self::tasklet_handle::TaskletHandle;
-pub use self::tasklet_id::TaskletId;
+pub use self::step_result::StepResult;
+pub use self::tasklet_chain::TaskletChain;
+pub use self::tasklet_config::{TaskletConfig, TaskletFinalizer};
+pub use self::tasklet_data_source::TaskletDataSource;
+pub use self::tasklet_handle::{SuspendableTasklet, TaskletHandle};
+pub use self::tasklet_id::{IdentifiableTasklet, TaskletId};
+pub use self::tasklet_spawner::{SpawnableTasklet, TaskletSpawner};
 pub use self::tasklet_storage::TaskletStorage;
 
 use core::cell::{OnceCell, UnsafeCell};
 
+use crate::aerugo::Aerugo;
 use crate::api::RuntimeApi;
 use crate::boolean_condition::BooleanConditionSet;
+use crate::configuration_seal::Crc32;
 use crate::data_provider::DataProvider;
 use crate::error::SystemError;
+use crate::guard_zone::ContextGuard;
+use crate::manifest::{object_id, TaskletManifest};
 use crate::mutex::Mutex;
-use crate::time::Instant;
+use crate::system_state::TaskletState;
+use crate::time::{Duration, Instant};
 
 /// Type of function that is executed by the tasklet in its step.
 pub(crate) type StepFn<T, C> = fn(T, &mut C, &'static dyn RuntimeApi);
 
+/// Type of function that is executed by the tasklet in its step, receiving reference to the data.
+pub(crate) type RefStepFn<T, C> = fn(&T, &mut C, &'static dyn RuntimeApi);
+
+/// Type of function that is executed by the resumable tasklet in its step. Data is `None` when
+/// the step continues processing after the previous step returned [`StepResult::Incomplete`].
+pub(crate) type ResumableStepFn<T, C> =
+    fn(Option<T>, &mut C, &'static dyn RuntimeApi) -> StepResult;
+
+/// Step function of the tasklet.
+pub(crate) enum TaskletStep<T: 'static, C: 'static> {
+    /// Step function receiving the data by value.
+    ByValue(StepFn<T, C>),
+    /// Step function receiving reference to the data, borrowed from the data provider.
+    ByReference(RefStepFn<T, C>),
+    /// Step function receiving the data by value, which can split processing into many steps.
+    Resumable(ResumableStepFn<T, C>),
+}
+
+impl<T, C> TaskletStep<T, C> {
+    /// Returns address of the step function.
+    fn as_ptr(&self) -> *const () {
+        match self {
+            TaskletStep::ByValue(step_fn) => *step_fn as *const (),
+            TaskletStep::ByReference(step_fn) => *step_fn as *const (),
+            TaskletStep::Resumable(step_fn) => *step_fn as *const (),
+        }
+    }
+}
+
 /// Tasklet structure.
 ///
 /// # Generic Parameters
@@ -52,20 +95,49 @@ pub(crate) struct Tasklet<T: 'static, C: 'static, const COND_COUNT: usize> {
     name: &'static str,
     /// Tasklet priority.
     priority: u8,
+    /// Priority inherited from the tasklets waiting for a resource owned by this tasklet.
+    inherited_priority: Mutex<Option<u8>>,
     /// Tasklet status.
     status: Mutex<TaskletStatus>,
+    /// Maximum execution time of a single step.
+    deadline: Option<Duration>,
+    /// Time after which resumable step should yield.
+    yield_budget: Option<Duration>,
     /// Last execution time.
     last_execution_time: Mutex<Instant>,
+    /// Absolute deadline of the current release, used by the earliest-deadline-first scheduling.
+    absolute_deadline: Mutex<Option<Instant>>,
+    /// Time of the earliest wake from an interrupt handler that wasn't followed by an execution yet.
+    #[cfg(feature = "wake-latency")]
+    interrupt_wake_time: Mutex<Option<Instant>>,
+    /// Function called when the tasklet is terminated or the system is shut down.
+    finalize_fn: Option<TaskletFinalizer>,
     /// Step function.
-    step_fn: StepFn<T, C>,
+    step: TaskletStep<T, C>,
     /// Context data.
     context: UnsafeCell<&'static mut C>,
+    /// Guard zones placed around the context data.
+    context_guard: ContextGuard,
     /// Condition set.
     condition_set: &'static OnceCell<BooleanConditionSet<COND_COUNT>>,
     /// Source of the data.
     data_provider: OnceCell<&'static dyn DataProvider<T>>,
     /// Runtime API.
     runtime_api: &'static dyn RuntimeApi,
+    /// `true` if tasklet is suspended and shouldn't be scheduled.
+    suspended: Mutex<bool>,
+    /// `true` if the last step of resumable tasklet didn't finish processing the data.
+    incomplete: Mutex<bool>,
+    /// `true` if tasklet is terminated and can't be resumed.
+    terminated: Mutex<bool>,
+    /// `true` if finalizer of the tasklet was already called.
+    finalized: Mutex<bool>,
+    /// Chain without output, used as data provider of the tasklet chained to this one.
+    completion: TaskletChain<C, ()>,
+    /// Listener notified after each executed step.
+    successor: OnceCell<&'static dyn CompletionListener<C>>,
+    /// Publisher of the context telemetry, notified after each executed step.
+    telemetry: OnceCell<&'static dyn CompletionListener<C>>,
 }
 
 /// It is safe assuming that Tasklet is not available from IRQ context before it's
@@ -91,8 +163,9 @@ impl<T, C, const COND_COUNT: usize> Tasklet<T, C, COND_COUNT> {
     pub(crate) const fn new(
         id: TaskletId,
         config: TaskletConfig,
-        step_fn: StepFn<T, C>,
+        step: TaskletStep<T, C>,
         context: &'static mut C,
+        context_guard: ContextGuard,
         condition_set: &'static OnceCell<BooleanConditionSet<COND_COUNT>>,
         runtime_api: &'static dyn RuntimeApi,
     ) -> Self {
@@ -100,13 +173,28 @@ impl<T, C, const COND_COUNT: usize> Tasklet<T, C, COND_COUNT> {
             id,
             name: config.name,
             priority: config.priority,
+            inherited_priority: Mutex::new(None),
+            deadline: config.deadline,
+            yield_budget: config.yield_budget,
             status: Mutex::new(TaskletStatus::Sleeping),
             last_execution_time: Mutex::new(Instant::from_ticks(0)),
-            step_fn,
+            absolute_deadline: Mutex::new(None),
+            #[cfg(feature = "wake-latency")]
+            interrupt_wake_time: Mutex::new(None),
+            finalize_fn: config.finalize,
+            step,
             context: UnsafeCell::new(context),
+            context_guard,
             condition_set,
             data_provider: OnceCell::new(),
             runtime_api,
+            suspended: Mutex::new(false),
+            incomplete: Mutex::new(false),
+            terminated: Mutex::new(false),
+            finalized: Mutex::new(false),
+            completion: TaskletChain::without_output(),
+            successor: OnceCell::new(),
+            telemetry: OnceCell::new(),
         }
     }
 
@@ -120,9 +208,37 @@ impl<T, C, const COND_COUNT: usize> Tasklet<T, C, COND_COUNT> {
         self.name
     }
 
+    /// Returns maximum execution time of a single step.
+    pub(crate) fn get_deadline(&self) -> Option<Duration> {
+        self.deadline
+    }
+
+    /// Returns time after which resumable step should yield.
+    pub(crate) fn get_yield_budget(&self) -> Option<Duration> {
+        self.yield_budget
+    }
+
+    /// Returns `true` if step of this tasklet can be continued without new data.
+    pub(crate) fn is_resumable(&self) -> bool {
+        matches!(self.step, TaskletStep::Resumable(_))
+    }
+
     /// Returns task priority.
+    ///
+    /// This is the effective priority, which is the higher of the configured priority and the
+    /// inherited one.
     pub(crate) fn get_priority(&self) -> u8 {
-        self.priority
+        self.inherited_priority
+            .lock(|inherited| inherited.map_or(self.priority, |p| p.max(self.priority)))
+    }
+
+    /// Sets priority inherited by the task.
+    ///
+    /// # Parameters
+    /// * `priority` - Inherited priority, or `None` to restore the configured priority.
+    pub(crate) fn set_inherited_priority(&self, priority: Option<u8>) {
+        self.inherited_priority
+            .lock(|inherited| *inherited = priority)
     }
 
     /// Returns task status.
@@ -151,24 +267,165 @@ impl<T, C, const COND_COUNT: usize> Tasklet<T, C, COND_COUNT> {
         self.last_execution_time.lock(|t| *t = time)
     }
 
+    /// Returns absolute deadline of the current release.
+    pub(crate) fn get_absolute_deadline(&self) -> Option<Instant> {
+        self.absolute_deadline.lock(|d| *d)
+    }
+
+    /// Sets absolute deadline of the current release.
+    ///
+    /// # Parameters
+    /// * `deadline` - Absolute deadline, `None` if the tasklet has no deadline.
+    pub(crate) fn set_absolute_deadline(&self, deadline: Option<Instant>) {
+        self.absolute_deadline.lock(|d| *d = deadline)
+    }
+
+    /// Records wake of this tasklet from an interrupt handler.
+    ///
+    /// If tasklet was already woken from an interrupt and wasn't executed since, the earlier time
+    /// is kept.
+    ///
+    /// # Parameters
+    /// * `time` - Time of the wake.
+    #[cfg(feature = "wake-latency")]
+    pub(crate) fn set_interrupt_wake_time(&self, time: Instant) {
+        self.interrupt_wake_time.lock(|t| {
+            t.get_or_insert(time);
+        })
+    }
+
+    /// Takes time of the earliest wake from an interrupt handler since the last execution.
+    #[cfg(feature = "wake-latency")]
+    pub(crate) fn take_interrupt_wake_time(&self) -> Option<Instant> {
+        self.interrupt_wake_time.lock(|t| t.take())
+    }
+
     /// Check if this tasklet is active.
     ///
-    /// Tasklet is not active if it's condition evaluates to `false`.
+    /// Tasklet is not active if it's suspended, or it's condition evaluates to `false`.
     pub(crate) fn is_active(&self) -> bool {
+        if self.is_suspended() {
+            return false;
+        }
+
         match self.condition_set.get() {
             Some(condition_set) => condition_set.evaluate(),
             None => true,
         }
     }
 
-    /// Checks if this tasklet has data waiting for processing.
+    /// Returns `true` if this tasklet is suspended.
+    pub(crate) fn is_suspended(&self) -> bool {
+        self.suspended.lock(|suspended| *suspended)
+    }
+
+    /// Suspends this tasklet.
+    ///
+    /// Suspended tasklet is not scheduled, but stays subscribed to its data provider, so the data
+    /// accumulates until the tasklet is resumed.
+    ///
+    /// # Return
+    /// `true` if tasklet was suspended, `false` if it was already suspended.
+    pub(crate) fn suspend(&self) -> bool {
+        self.suspended
+            .lock(|suspended| !core::mem::replace(suspended, true))
+    }
+
+    /// Resumes this tasklet.
+    ///
+    /// If there is data that accumulated while the tasklet was suspended, tasklet is scheduled
+    /// for execution. Terminated tasklet can't be resumed.
+    ///
+    /// # Return
+    /// `true` if tasklet was resumed, `false` if it wasn't suspended or is terminated.
+    pub(crate) fn resume(&'static self) -> bool {
+        if self.is_terminated() {
+            return false;
+        }
+
+        let resumed = self
+            .suspended
+            .lock(|suspended| core::mem::replace(suspended, false));
+
+        if resumed && self.has_work() {
+            Aerugo::wake_tasklet(&self.ptr());
+        }
+
+        resumed
+    }
+
+    /// Returns `true` if this tasklet is terminated.
+    pub(crate) fn is_terminated(&self) -> bool {
+        self.terminated.lock(|terminated| *terminated)
+    }
+
+    /// Terminates this tasklet.
+    ///
+    /// Tasklet is suspended permanently and inputs waiting for it are discarded. Finalizer is
+    /// called immediately, or after the step returns if this tasklet is being executed.
+    ///
+    /// # Return
+    /// `true` if tasklet was terminated, `false` if it was already terminated.
+    pub(crate) fn terminate(&self) -> bool {
+        if self
+            .terminated
+            .lock(|terminated| core::mem::replace(terminated, true))
+        {
+            return false;
+        }
+
+        self.suspend();
+        self.flush_inputs();
+
+        if self.get_status() != TaskletStatus::Working {
+            self.finalize();
+        }
+
+        true
+    }
+
+    /// Calls finalizer of this tasklet, if it's set and wasn't called yet.
+    ///
+    /// This mustn't be called while the step of this tasklet is executed.
+    pub(crate) fn finalize(&self) {
+        let Some(finalize_fn) = self.finalize_fn else {
+            return;
+        };
+
+        if self
+            .finalized
+            .lock(|finalized| core::mem::replace(finalized, true))
+        {
+            return;
+        }
+
+        // SAFETY: This is safe, because finalizer is never called while the step is executed, and
+        // it's called only once.
+        let context: &mut C = unsafe { *self.context.get() };
+        finalize_fn(context, self.runtime_api);
+    }
+
+    /// Checks if this tasklet has data waiting for processing, or didn't finish processing the
+    /// data in the last step.
     pub(crate) fn has_work(&self) -> bool {
+        if self.incomplete.lock(|incomplete| *incomplete) {
+            return true;
+        }
+
         match self.data_provider.get() {
             Some(data_provider) => data_provider.data_waiting(),
             None => false,
         }
     }
 
+    /// Checks if guard zones placed around the context data are intact.
+    ///
+    /// Broken guard zone means that the memory around the context data was overwritten, most
+    /// likely by a buffer overrun in the tasklet step function.
+    pub(crate) fn is_context_intact(&self) -> bool {
+        self.context_guard.is_intact()
+    }
+
     /// Sets this tasklet conditions.
     ///
     /// # Return
@@ -217,28 +474,196 @@ impl<T, C, const COND_COUNT: usize> Tasklet<T, C, COND_COUNT> {
         }
     }
 
+    /// Returns chain without output, that can be used as data provider of the tasklet chained to
+    /// this one.
+    pub(crate) fn completion(&'static self) -> &'static TaskletChain<C, ()> {
+        &self.completion
+    }
+
+    /// Sets listener notified after each executed step of this tasklet.
+    ///
+    /// # Parameters
+    /// * `successor` - Completion listener.
+    ///
+    /// # Return
+    /// `SystemError` if tasklet already has a successor, `()` otherwise.
+    ///
+    /// # Safety
+    /// This is unsafe, because it mutably borrows the successor.
+    /// This is safe if it's executed in a critical section during system initialization
+    /// (before scheduler is started).
+    /// Accessing tasklet from IRQ context during setting is undefined behaviour.
+    pub(crate) unsafe fn set_successor(
+        &self,
+        successor: &'static dyn CompletionListener<C>,
+    ) -> Result<(), SystemError> {
+        match self.successor.set(successor) {
+            Ok(_) => Ok(()),
+            Err(_) => Err(SystemError::TaskletAlreadyChained(self.get_name())),
+        }
+    }
+
+    /// Sets publisher of the context telemetry, notified after each executed step of this tasklet.
+    ///
+    /// # Parameters
+    /// * `telemetry` - Context telemetry publisher.
+    ///
+    /// # Return
+    /// `SystemError` if tasklet already has a telemetry publisher, `()` otherwise.
+    ///
+    /// # Safety
+    /// This is unsafe, because it mutably borrows the telemetry publisher.
+    /// This is safe if it's executed in a critical section during system initialization
+    /// (before scheduler is started).
+    /// Accessing tasklet from IRQ context during setting is undefined behaviour.
+    pub(crate) unsafe fn set_telemetry(
+        &self,
+        telemetry: &'static dyn CompletionListener<C>,
+    ) -> Result<(), SystemError> {
+        match self.telemetry.set(telemetry) {
+            Ok(_) => Ok(()),
+            Err(_) => Err(SystemError::TaskletAlreadyHasTelemetry(self.get_name())),
+        }
+    }
+
+    /// Updates configuration CRC with this tasklet configuration.
+    ///
+    /// Configuration consists of the tasklet ID, name, priority, deadline, step function, condition
+    /// set, subscribed data provider, successor and telemetry publisher.
+    ///
+    /// # Parameters
+    /// * `crc` - CRC to update.
+    ///
+    /// # Return
+    /// Updated CRC.
+    pub(crate) fn update_configuration_crc(&self, crc: Crc32) -> Crc32 {
+        let data_provider = self
+            .data_provider
+            .get()
+            .map(|dp| *dp as *const dyn DataProvider<T>);
+        let successor = self
+            .successor
+            .get()
+            .map(|successor| *successor as *const dyn CompletionListener<C>);
+        let telemetry = self
+            .telemetry
+            .get()
+            .map(|telemetry| *telemetry as *const dyn CompletionListener<C>);
+
+        crc.update_with(&self.id.0)
+            .update_with(&self.name.as_ptr())
+            .update_with(&self.priority)
+            .update_with(&self.deadline.map_or(0, |deadline| deadline.ticks()))
+            .update_with(&self.step.as_ptr())
+            .update_with(&(self.condition_set as *const _ as *const ()))
+            .update_with(&self.condition_set.get().is_some())
+            .update_with(&data_provider.map_or(core::ptr::null(), |dp| dp as *const ()))
+            .update_with(&successor.map_or(core::ptr::null(), |s| s as *const ()))
+            .update_with(&telemetry.map_or(core::ptr::null(), |t| t as *const ()))
+    }
+
+    /// Returns description of this tasklet for the configuration manifest.
+    pub(crate) fn manifest(&self) -> TaskletManifest {
+        TaskletManifest {
+            id: self.id,
+            name: self.name,
+            priority: self.priority,
+            deadline: self.deadline,
+            source: self.data_provider.get().map(|dp| dp.source_kind()),
+            source_id: self.data_provider.get().map(|dp| object_id(*dp)),
+            successor_id: self.successor.get().map(|successor| object_id(*successor)),
+            conditions: self.condition_set.get().map(|set| set.manifest()),
+        }
+    }
+
+    /// Returns current state of this tasklet for the system state snapshot.
+    pub(crate) fn state(&self) -> TaskletState {
+        TaskletState {
+            id: self.id,
+            name: self.name,
+            status: self.get_status(),
+            suspended: self.is_suspended(),
+            last_execution_time: self.get_last_execution_time(),
+            waiting: self.data_provider.get().map(|dp| dp.waiting_count()),
+            conditions: self
+                .condition_set
+                .get()
+                .map(|set| (set.evaluate(), set.manifest().1)),
+        }
+    }
+
+    /// Discards all data waiting for this tasklet in its data provider.
+    ///
+    /// # Return
+    /// Number of discarded elements.
+    pub(crate) fn flush_inputs(&self) -> usize {
+        self.data_provider.get().map_or(0, |dp| dp.flush())
+    }
+
     /// Executes task.
     ///
+    /// Successor and telemetry publisher of the tasklet are notified if the step was executed.
+    /// Resumable step that didn't finish processing the data is executed again without new data,
+    /// and they are notified only after the processing is finished.
+    ///
     /// # Return
     /// `true` if tasklet was executed, `false` otherwise
     pub(crate) fn execute(&self) -> bool {
-        match self.data_provider.get() {
-            Some(dp) => {
+        let Some(dp) = self.data_provider.get() else {
+            return false;
+        };
+
+        // SAFETY: This is safe, because this field is only accessed here, and given tasklet can
+        // be executed only once at a given time.
+        let context: &mut C = unsafe { *self.context.get() };
+
+        let executed = match &self.step {
+            TaskletStep::ByValue(step_fn) => {
                 let value = dp.get_data();
 
                 if let Some(val) = value {
-                    // SAFETY: This is safe, because this field is only accessed here, and given tasklet can
-                    // be executed only once at a given time.
-                    let context: &mut C = unsafe { *self.context.get() };
-                    (self.step_fn)(val, context, self.runtime_api);
+                    step_fn(val, context, self.runtime_api);
 
                     true
                 } else {
                     false
                 }
             }
-            None => false,
+            TaskletStep::ByReference(step_fn) => {
+                dp.with_data(&mut |val| step_fn(val, context, self.runtime_api))
+            }
+            TaskletStep::Resumable(step_fn) => {
+                let value = if self.incomplete.lock(|incomplete| *incomplete) {
+                    None
+                } else {
+                    match dp.get_data() {
+                        Some(val) => Some(val),
+                        None => return false,
+                    }
+                };
+
+                if step_fn(value, context, self.runtime_api) == StepResult::Incomplete {
+                    self.incomplete.lock(|incomplete| *incomplete = true);
+                    // Successor is notified after the processing is finished.
+                    return true;
+                }
+
+                self.incomplete.lock(|incomplete| *incomplete = false);
+                true
+            }
+        };
+
+        if executed {
+            if let Some(successor) = self.successor.get() {
+                successor.completed(context);
+            }
+
+            if let Some(telemetry) = self.telemetry.get() {
+                telemetry.completed(context);
+            }
         }
+
+        executed
     }
 
     /// Creates pointer to this tasklet.
@@ -278,4 +703,45 @@ mod tests {
         assert_eq!(smallctx_size, stub_size);
         assert_eq!(bigctx_size, stub_size);
     }
+
+    #[test]
+    #[allow(non_upper_case_globals)]
+    fn terminate_finalizes_once() {
+        use crate::guard_zone::GuardZone;
+        use crate::tests::{MockConditionSet, MockRuntimeApi};
+
+        static mock_condition_set: MockConditionSet<0> = MockConditionSet::new();
+        static mock_runtime_api: MockRuntimeApi = MockRuntimeApi {};
+
+        static mut tasklet_context: u8 = 0;
+        static tasklet_context_guard_head: GuardZone = GuardZone::new();
+        static tasklet_context_guard_tail: GuardZone = GuardZone::new();
+        static tasklet: Tasklet<(), u8, 0> = Tasklet::new(
+            TaskletId(0),
+            TaskletConfig {
+                name: "TestTasklet",
+                priority: 0,
+                deadline: None,
+                yield_budget: None,
+                finalize: Some(|context, _| {
+                    if let Some(count) = context.downcast_mut::<u8>() {
+                        *count += 1;
+                    }
+                }),
+            },
+            TaskletStep::ByValue(|_, _, _| {}),
+            unsafe { &mut tasklet_context },
+            ContextGuard::new(&tasklet_context_guard_head, &tasklet_context_guard_tail),
+            &mock_condition_set.storage,
+            &mock_runtime_api,
+        );
+
+        assert!(tasklet.terminate());
+        assert!(tasklet.is_suspended());
+        assert!(!tasklet.resume());
+
+        assert!(!tasklet.terminate());
+        tasklet.finalize();
+        assert_eq!(unsafe { tasklet_context }, 1);
+    }
 }
@@ -0,0 +1,67 @@
+//! Result of the resumable tasklet step.
+//!
+//! Resumable tasklets split long computations into a number of steps. Progress of the computation
+//! is kept in the tasklet context, and the step returns [`StepResult::Incomplete`] when it should
+//! give way to other tasklets. Tasklet is then rescheduled and its step is called again, without
+//! new data, to continue the computation.
+
+/// Result of the resumable tasklet step.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum StepResult {
+    /// Processing of the data is finished, next step receives new data.
+    Complete,
+    /// Processing of the data is not finished. Tasklet is rescheduled and its next step continues
+    /// the processing, with progress retained in the context.
+    Incomplete,
+}
+
+/// Checks if resumable tasklet step should give way to other tasklets, and returns
+/// [`StepResult::Incomplete`] from the step if it should.
+///
+/// Step should yield when it runs longer than the
+/// [yield budget](crate::TaskletConfig::yield_budget) of the tasklet, or a tasklet with higher
+/// priority is waiting for execution, see [`yield_needed`](crate::RuntimeApi::yield_needed).
+/// Progress of the computation has to be stored in the tasklet context before this check.
+///
+/// # Parameters
+/// * `api` - Runtime API passed to the step function.
+///
+/// # Example
+/// ```
+/// # use aerugo::{yield_point, RuntimeApi, StepResult};
+/// #[derive(Default)]
+/// struct ChecksumCtx {
+///     frame: Option<[u8; 4096]>,
+///     position: usize,
+///     checksum: u32,
+/// }
+///
+/// fn checksum(frame: Option<[u8; 4096]>, ctx: &mut ChecksumCtx, api: &'static dyn RuntimeApi) -> StepResult {
+///     if frame.is_some() {
+///         ctx.frame = frame;
+///         ctx.position = 0;
+///         ctx.checksum = 0;
+///     }
+///
+///     let Some(frame) = &ctx.frame else {
+///         return StepResult::Complete;
+///     };
+///
+///     while ctx.position < frame.len() {
+///         ctx.checksum = ctx.checksum.wrapping_add(frame[ctx.position] as u32);
+///         ctx.position += 1;
+///
+///         yield_point!(api);
+///     }
+///
+///     StepResult::Complete
+/// }
+/// ```
+#[macro_export]
+macro_rules! yield_point {
+    ($api:expr) => {
+        if $api.yield_needed() {
+            return $crate::StepResult::Incomplete;
+        }
+    };
+}
@@ -0,0 +1,143 @@
+//! Link between chained tasklets.
+//!
+//! Chained tasklets form a pipeline - each executed step of the producer tasklet schedules the
+//! consumer tasklet, optionally passing it a value derived from the producer's context. Chain is
+//! the consumer's data provider, and the producer notifies it after each executed step.
+//!
+//! Completions of the producer are coalesced - if the producer is executed multiple times before
+//! the consumer, the consumer is executed once, receiving the latest output.
+
+use core::cell::OnceCell;
+
+use crate::data_provider::{DataProvider, DataReceiver};
+use crate::error::SystemError;
+use crate::manifest::DataSourceKind;
+use crate::mutex::Mutex;
+
+/// Listener of the tasklet step completion.
+///
+/// # Generic Parameters
+/// * `C` - Type of the producer tasklet context data.
+pub(crate) trait CompletionListener<C> {
+    /// Notifies the listener that the producer tasklet executed its step.
+    ///
+    /// # Parameters
+    /// * `context` - Context of the producer tasklet.
+    fn completed(&self, context: &C);
+}
+
+/// Link between chained tasklets, passing output of the producer to the consumer.
+///
+/// Chain is used to [chain tasklets with output](crate::api::InitApi::chain_tasklets_with_output).
+/// Chains without output are embedded in the tasklets, and are used with
+/// [chain_tasklets](crate::api::InitApi::chain_tasklets).
+///
+/// # Generic Parameters
+/// * `C` - Type of the producer tasklet context data.
+/// * `U` - Type of the output, which is the type processed by the consumer tasklet.
+pub struct TaskletChain<C: 'static, U: 'static> {
+    /// Function deriving the output from the producer's context.
+    output: fn(&C) -> U,
+    /// Latest output, not yet received by the consumer.
+    value: Mutex<Option<U>>,
+    /// Receiver of the consumer tasklet.
+    consumer: OnceCell<DataReceiver>,
+}
+
+/// Chain is shared between the producer and the consumer, which are executed one at the time.
+/// Output is stored in [Mutex], and consumer is set only during system initialization.
+unsafe impl<C, U: Send> Sync for TaskletChain<C, U> {}
+
+impl<C, U> TaskletChain<C, U> {
+    /// Creates new tasklet chain.
+    ///
+    /// # Parameters
+    /// * `output` - Function deriving the output from the producer's context, called after each
+    ///   executed step of the producer.
+    pub const fn new(output: fn(&C) -> U) -> Self {
+        TaskletChain {
+            output,
+            value: Mutex::new(None),
+            consumer: OnceCell::new(),
+        }
+    }
+
+    /// Connects consumer tasklet to this chain.
+    ///
+    /// # Parameters
+    /// * `consumer` - Receiver of the consumer tasklet.
+    ///
+    /// # Return
+    /// `()` if successful, `SystemError` if the chain already has a consumer.
+    ///
+    /// # Safety
+    /// This is unsafe, because it mutably borrows the consumer receiver.
+    /// This is safe if it's executed in a critical section during system initialization
+    /// (before scheduler is started).
+    pub(crate) unsafe fn connect(&self, consumer: DataReceiver) -> Result<(), SystemError> {
+        self.consumer
+            .set(consumer)
+            .map_err(|_| SystemError::TaskletChainAlreadyConnected)
+    }
+}
+
+impl<C> TaskletChain<C, ()> {
+    /// Creates new tasklet chain without output.
+    pub(crate) const fn without_output() -> Self {
+        TaskletChain::new(no_output::<C>)
+    }
+}
+
+impl<C, U> CompletionListener<C> for TaskletChain<C, U> {
+    fn completed(&self, context: &C) {
+        let value = (self.output)(context);
+        self.value.lock(|v| *v = Some(value));
+
+        if let Some(consumer) = self.consumer.get() {
+            consumer.notify();
+        }
+    }
+}
+
+impl<C, U> DataProvider<U> for TaskletChain<C, U> {
+    fn get_data(&self) -> Option<U> {
+        self.value.lock(|v| v.take())
+    }
+
+    fn data_waiting(&self) -> bool {
+        self.value.lock(|v| v.is_some())
+    }
+
+    fn flush(&self) -> usize {
+        self.value.lock(|v| v.take().map_or(0, |_| 1))
+    }
+
+    fn source_kind(&self) -> DataSourceKind {
+        DataSourceKind::TaskletChain
+    }
+}
+
+/// Output of the chain without output.
+fn no_output<C>(_: &C) {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn completions_are_coalesced() {
+        static CHAIN: TaskletChain<u32, u32> = TaskletChain::new(|context| context * 2);
+
+        assert!(!CHAIN.data_waiting());
+
+        CHAIN.completed(&1);
+        CHAIN.completed(&2);
+        assert!(CHAIN.data_waiting());
+        assert_eq!(CHAIN.get_data(), Some(4));
+        assert_eq!(CHAIN.get_data(), None);
+
+        CHAIN.completed(&3);
+        assert_eq!(CHAIN.flush(), 1);
+        assert!(!CHAIN.data_waiting());
+    }
+}
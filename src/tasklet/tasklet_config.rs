@@ -1,5 +1,16 @@
 //! Configuration for creating tasklets.
 
+use core::any::Any;
+
+use crate::api::RuntimeApi;
+use crate::time::Duration;
+
+/// Type of function called when the tasklet is terminated or the system is shut down.
+///
+/// Function receives the tasklet context, which can be downcast to the context type of the
+/// tasklet.
+pub type TaskletFinalizer = fn(&mut dyn Any, &'static dyn RuntimeApi);
+
 /// Configuration for tasklets.
 #[derive(Copy, Clone)]
 pub struct TaskletConfig {
@@ -7,6 +18,20 @@ pub struct TaskletConfig {
     pub name: &'static str,
     /// Priority of the tasklet.
     pub priority: u8,
+    /// Maximum execution time of a single tasklet step, `None` if not monitored.
+    ///
+    /// Deadline misses are reported to the handler set with
+    /// [`set_deadline_miss_handler`](crate::api::InitApi::set_deadline_miss_handler).
+    pub deadline: Option<Duration>,
+    /// Time after which step of the resumable tasklet should give way to other tasklets, `None`
+    /// if it yields only to tasklets with higher priority.
+    ///
+    /// See [`yield_point`](crate::yield_point).
+    pub yield_budget: Option<Duration>,
+    /// Function called once, when the tasklet is
+    /// [terminated](crate::api::RuntimeApi::terminate_tasklet) or the system is
+    /// [shut down](crate::api::RuntimeApi::shutdown), `None` if tasklet doesn't need finalization.
+    pub finalize: Option<TaskletFinalizer>,
 }
 
 impl Default for TaskletConfig {
@@ -14,6 +39,9 @@ impl Default for TaskletConfig {
         TaskletConfig {
             name: "MISSING_TASKLET_NAME",
             priority: 0,
+            deadline: None,
+            yield_budget: None,
+            finalize: None,
         }
     }
 }
@@ -0,0 +1,89 @@
+//! Sources of the data processed by tasklets.
+
+use crate::api::InitApi;
+use crate::barrier::BarrierHandle;
+use crate::boolean_condition::BooleanConditionHandle;
+use crate::event::{DataEventHandle, EventId};
+use crate::message_queue::MessageQueueHandle;
+use crate::tasklet::TaskletHandle;
+
+/// Source of the data processed by a tasklet.
+///
+/// This trait is implemented for handles of all system structures that a tasklet can be subscribed
+/// to, with `T` being the type of the data they provide. Tasklet can be subscribed to a source with
+/// [subscribe_tasklet](crate::api::InitApi::subscribe_tasklet) only if the source provides data of
+/// the type processed by the tasklet, so mismatched subscriptions are rejected at compile time.
+///
+/// # Generic Parameters
+/// * `T` - Type of the provided data.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` doesn't provide data of type `{T}`",
+    label = "tasklet processes `{T}`, which is not provided by this source",
+    note = "type processed by the tasklet is the first generic parameter of its `TaskletStorage`"
+)]
+pub trait TaskletDataSource<T> {
+    /// Subscribes tasklet to this source.
+    ///
+    /// # Generic Parameters
+    /// * `C` - Type of the structure with tasklet context data.
+    /// * `COND_COUNT` - Number of tasklet conditions.
+    ///
+    /// # Parameters
+    /// * `api` - System initialization API.
+    /// * `tasklet_handle` - Handle to the target tasklet.
+    fn subscribe<C, const COND_COUNT: usize>(
+        &self,
+        api: &'static impl InitApi,
+        tasklet_handle: &TaskletHandle<T, C, COND_COUNT>,
+    );
+}
+
+impl<T, const QUEUE_SIZE: usize> TaskletDataSource<T> for MessageQueueHandle<T, QUEUE_SIZE> {
+    fn subscribe<C, const COND_COUNT: usize>(
+        &self,
+        api: &'static impl InitApi,
+        tasklet_handle: &TaskletHandle<T, C, COND_COUNT>,
+    ) {
+        api.subscribe_tasklet_to_queue(tasklet_handle, self)
+    }
+}
+
+impl<const EVENT_COUNT: usize> TaskletDataSource<EventId> for [EventId; EVENT_COUNT] {
+    fn subscribe<C, const COND_COUNT: usize>(
+        &self,
+        api: &'static impl InitApi,
+        tasklet_handle: &TaskletHandle<EventId, C, COND_COUNT>,
+    ) {
+        api.subscribe_tasklet_to_events(tasklet_handle, *self)
+    }
+}
+
+impl<T: Clone, const N: usize> TaskletDataSource<T> for DataEventHandle<T, N> {
+    fn subscribe<C, const COND_COUNT: usize>(
+        &self,
+        api: &'static impl InitApi,
+        tasklet_handle: &TaskletHandle<T, C, COND_COUNT>,
+    ) {
+        api.subscribe_tasklet_to_data_event(tasklet_handle, self)
+    }
+}
+
+impl TaskletDataSource<bool> for BooleanConditionHandle {
+    fn subscribe<C, const COND_COUNT: usize>(
+        &self,
+        api: &'static impl InitApi,
+        tasklet_handle: &TaskletHandle<bool, C, COND_COUNT>,
+    ) {
+        api.subscribe_tasklet_to_condition(tasklet_handle, self)
+    }
+}
+
+impl<const N: usize> TaskletDataSource<u32> for BarrierHandle<N> {
+    fn subscribe<C, const COND_COUNT: usize>(
+        &self,
+        api: &'static impl InitApi,
+        tasklet_handle: &TaskletHandle<u32, C, COND_COUNT>,
+    ) {
+        api.subscribe_tasklet_to_barrier(tasklet_handle, self)
+    }
+}
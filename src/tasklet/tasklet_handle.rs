@@ -3,7 +3,37 @@
 //! This module contains tasklet handle implementation, which is used to reference a tasklet in the
 //! system.
 
-use crate::tasklet::{Tasklet, TaskletId};
+use crate::tasklet::{IdentifiableTasklet, Tasklet, TaskletId};
+
+/// Tasklet that can be suspended, resumed and terminated.
+///
+/// This trait erases generic parameters of [`TaskletHandle`], so tasklets can be suspended with
+/// [`RuntimeApi`](crate::api::RuntimeApi), which is used as a trait object.
+pub trait SuspendableTasklet {
+    /// Suspends the tasklet.
+    ///
+    /// # Return
+    /// `true` if tasklet was suspended, `false` if it was already suspended.
+    fn suspend(&self) -> bool;
+
+    /// Resumes the tasklet.
+    ///
+    /// # Return
+    /// `true` if tasklet was resumed, `false` if it wasn't suspended.
+    fn resume(&self) -> bool;
+
+    /// Returns `true` if the tasklet is suspended.
+    fn is_suspended(&self) -> bool;
+
+    /// Terminates the tasklet.
+    ///
+    /// # Return
+    /// `true` if tasklet was terminated, `false` if it was already terminated.
+    fn terminate(&self) -> bool;
+
+    /// Returns `true` if the tasklet is terminated.
+    fn is_terminated(&self) -> bool;
+}
 
 /// Tasklet handle.
 ///
@@ -14,13 +44,18 @@ use crate::tasklet::{Tasklet, TaskletId};
 /// # Generic Parameters
 /// * `T` - Type that is processed by the tasklet.
 /// * `C` - Type of tasklet context data.
-/// * `COND_COUNT` - Number of tasklet conditions.
-pub struct TaskletHandle<T: 'static, C: 'static, const COND_COUNT: usize> {
+/// * `COND_COUNT` - Number of tasklet conditions, which is the maximum size of the
+///   [condition set](crate::api::InitApi::set_tasklet_conditions) assigned to the tasklet. Tasklet
+///   without conditions has `COND_COUNT` equal to 0, which is the default, so it can be omitted.
+pub struct TaskletHandle<T: 'static, C: 'static, const COND_COUNT: usize = 0> {
     /// Reference to the tasklet.
     tasklet: &'static Tasklet<T, C, COND_COUNT>,
 }
 
 impl<T, C, const COND_COUNT: usize> TaskletHandle<T, C, COND_COUNT> {
+    /// Maximum number of conditions assigned to this tasklet.
+    pub const CONDITION_COUNT: usize = COND_COUNT;
+
     /// Creates new tasklet handle.
     ///
     /// # Parameters
@@ -41,8 +76,52 @@ impl<T, C, const COND_COUNT: usize> TaskletHandle<T, C, COND_COUNT> {
         self.tasklet.get_name()
     }
 
+    /// Discards all inputs waiting for this tasklet.
+    ///
+    /// This clears queued messages, active events or pending cyclic wakeup, depending on the data
+    /// provider the tasklet is subscribed to. It's meant to be used when switching modes, so a
+    /// resumed tasklet doesn't process stale data. Clearing is done in a critical section, so no
+    /// new data is lost in the process.
+    ///
+    /// Message queue is shared by all tasklets subscribed to it, so flushing inputs of one of
+    /// them discards the messages for all of them.
+    ///
+    /// # Return
+    /// Number of discarded messages, events or wakeups.
+    pub fn flush_inputs(&self) -> usize {
+        self.tasklet.flush_inputs()
+    }
+
     /// Returns reference to the tasklet.
     pub(crate) fn tasklet(&self) -> &'static Tasklet<T, C, COND_COUNT> {
         self.tasklet
     }
 }
+
+impl<T, C, const COND_COUNT: usize> IdentifiableTasklet for TaskletHandle<T, C, COND_COUNT> {
+    fn get_id(&self) -> TaskletId {
+        self.tasklet.get_id()
+    }
+}
+
+impl<T, C, const COND_COUNT: usize> SuspendableTasklet for TaskletHandle<T, C, COND_COUNT> {
+    fn suspend(&self) -> bool {
+        self.tasklet.suspend()
+    }
+
+    fn resume(&self) -> bool {
+        self.tasklet.resume()
+    }
+
+    fn is_suspended(&self) -> bool {
+        self.tasklet.is_suspended()
+    }
+
+    fn terminate(&self) -> bool {
+        self.tasklet.terminate()
+    }
+
+    fn is_terminated(&self) -> bool {
+        self.tasklet.is_terminated()
+    }
+}
@@ -18,6 +18,21 @@ impl TaskletId {
     }
 }
 
+/// Reference to a tasklet by which its ID can be obtained.
+///
+/// Implemented by [TaskletId] and [TaskletHandle](crate::TaskletHandle), so API functions that
+/// query tasklet state accept either of them.
+pub trait IdentifiableTasklet {
+    /// Returns ID of the tasklet.
+    fn get_id(&self) -> TaskletId;
+}
+
+impl IdentifiableTasklet for TaskletId {
+    fn get_id(&self) -> TaskletId {
+        *self
+    }
+}
+
 impl fmt::Display for TaskletId {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "{}", self.0)
@@ -13,8 +13,11 @@
 
 use core::cmp::Ordering;
 
+use crate::configuration_seal::Crc32;
+use crate::manifest::TaskletManifest;
+use crate::system_state::TaskletState;
 use crate::tasklet::{tasklet_vtable, Tasklet, TaskletId, TaskletStatus, TaskletVTable};
-use crate::time::Instant;
+use crate::time::{Duration, Instant};
 
 /// Raw tasklet pointer.
 #[derive(Clone)]
@@ -57,6 +60,30 @@ impl TaskletPtr {
         (self.vtable.get_priority)(self.ptr)
     }
 
+    /// See: [set_inherited_priority](crate::tasklet::Tasklet::set_inherited_priority())
+    #[inline(always)]
+    pub(crate) fn set_inherited_priority(&self, priority: Option<u8>) {
+        (self.vtable.set_inherited_priority)(self.ptr, priority)
+    }
+
+    /// See: [get_deadline](crate::tasklet::Tasklet::get_deadline())
+    #[inline(always)]
+    pub(crate) fn get_deadline(&self) -> Option<Duration> {
+        (self.vtable.get_deadline)(self.ptr)
+    }
+
+    /// See: [get_yield_budget](crate::tasklet::Tasklet::get_yield_budget())
+    #[inline(always)]
+    pub(crate) fn get_yield_budget(&self) -> Option<Duration> {
+        (self.vtable.get_yield_budget)(self.ptr)
+    }
+
+    /// See: [is_resumable](crate::tasklet::Tasklet::is_resumable())
+    #[inline(always)]
+    pub(crate) fn is_resumable(&self) -> bool {
+        (self.vtable.is_resumable)(self.ptr)
+    }
+
     /// See: [get_status](crate::tasklet::Tasklet::get_status())
     #[inline(always)]
     pub(crate) fn get_status(&self) -> TaskletStatus {
@@ -81,6 +108,33 @@ impl TaskletPtr {
         (self.vtable.set_last_execution_time)(self.ptr, time)
     }
 
+    /// See: [get_absolute_deadline](crate::tasklet::Tasklet::get_absolute_deadline())
+    #[cfg_attr(not(feature = "edf-scheduling"), allow(dead_code))]
+    #[inline(always)]
+    pub(crate) fn get_absolute_deadline(&self) -> Option<Instant> {
+        (self.vtable.get_absolute_deadline)(self.ptr)
+    }
+
+    /// See: [set_absolute_deadline](crate::tasklet::Tasklet::set_absolute_deadline())
+    #[inline(always)]
+    pub(crate) fn set_absolute_deadline(&self, deadline: Option<Instant>) {
+        (self.vtable.set_absolute_deadline)(self.ptr, deadline)
+    }
+
+    /// See: [set_interrupt_wake_time](crate::tasklet::Tasklet::set_interrupt_wake_time())
+    #[cfg(feature = "wake-latency")]
+    #[inline(always)]
+    pub(crate) fn set_interrupt_wake_time(&self, time: Instant) {
+        (self.vtable.set_interrupt_wake_time)(self.ptr, time)
+    }
+
+    /// See: [take_interrupt_wake_time](crate::tasklet::Tasklet::take_interrupt_wake_time())
+    #[cfg(feature = "wake-latency")]
+    #[inline(always)]
+    pub(crate) fn take_interrupt_wake_time(&self) -> Option<Instant> {
+        (self.vtable.take_interrupt_wake_time)(self.ptr)
+    }
+
     /// See: [has_work](crate::tasklet::Tasklet::has_work())
     #[inline(always)]
     pub(crate) fn has_work(&self) -> bool {
@@ -93,6 +147,30 @@ impl TaskletPtr {
         (self.vtable.is_active)(self.ptr)
     }
 
+    /// See: [suspend](crate::tasklet::Tasklet::suspend())
+    #[inline(always)]
+    pub(crate) fn suspend(&self) -> bool {
+        (self.vtable.suspend)(self.ptr)
+    }
+
+    /// See: [terminate](crate::tasklet::Tasklet::terminate())
+    #[inline(always)]
+    pub(crate) fn terminate(&self) -> bool {
+        (self.vtable.terminate)(self.ptr)
+    }
+
+    /// See: [is_terminated](crate::tasklet::Tasklet::is_terminated())
+    #[inline(always)]
+    pub(crate) fn is_terminated(&self) -> bool {
+        (self.vtable.is_terminated)(self.ptr)
+    }
+
+    /// See: [finalize](crate::tasklet::Tasklet::finalize())
+    #[inline(always)]
+    pub(crate) fn finalize(&self) {
+        (self.vtable.finalize)(self.ptr)
+    }
+
     /// See: [is_subscribed](crate::tasklet::Tasklet::is_subscribed())
     #[inline(always)]
     pub(crate) fn is_subscribed(&self) -> bool {
@@ -104,6 +182,30 @@ impl TaskletPtr {
     pub(crate) fn execute(&self) -> bool {
         (self.vtable.execute)(self.ptr)
     }
+
+    /// See: [is_context_intact](crate::tasklet::Tasklet::is_context_intact())
+    #[inline(always)]
+    pub(crate) fn is_context_intact(&self) -> bool {
+        (self.vtable.is_context_intact)(self.ptr)
+    }
+
+    /// See: [update_configuration_crc](crate::tasklet::Tasklet::update_configuration_crc())
+    #[inline(always)]
+    pub(crate) fn update_configuration_crc(&self, crc: Crc32) -> Crc32 {
+        (self.vtable.update_configuration_crc)(self.ptr, crc)
+    }
+
+    /// See: [manifest](crate::tasklet::Tasklet::manifest())
+    #[inline(always)]
+    pub(crate) fn manifest(&self) -> TaskletManifest {
+        (self.vtable.manifest)(self.ptr)
+    }
+
+    /// See: [state](crate::tasklet::Tasklet::state())
+    #[inline(always)]
+    pub(crate) fn state(&self) -> TaskletState {
+        (self.vtable.state)(self.ptr)
+    }
 }
 
 impl Ord for TaskletPtr {
@@ -0,0 +1,130 @@
+//! Tasklets created after the system was started.
+//!
+//! Tasklets are usually created during system initialization. Some tasklets are needed only in
+//! specific circumstances (for example diagnostics started after a fault is detected), so instead
+//! they can be [reserved](crate::api::InitApi::reserve_tasklet) during initialization and
+//! [spawned](crate::api::RuntimeApi::spawn_tasklet) later. Reservation takes a place in the
+//! tasklet list, so spawning can't fail because of the lack of space.
+
+use core::cell::OnceCell;
+
+use crate::aerugo::Aerugo;
+use crate::data_provider::{DataProvider, DataReceiver};
+use crate::error::{RuntimeError, SystemError};
+use crate::tasklet::{StepFn, TaskletConfig, TaskletStorage};
+
+/// Tasklet that can be spawned after the system was started.
+///
+/// This trait erases generic parameters of [`TaskletSpawner`], so tasklets can be spawned with
+/// [`RuntimeApi`](crate::api::RuntimeApi), which is used as a trait object.
+pub trait SpawnableTasklet {
+    /// Spawns the tasklet.
+    ///
+    /// # Return
+    /// Receiver of the tasklet data provider if successful, `RuntimeError` otherwise.
+    fn spawn(&self) -> Result<DataReceiver, RuntimeError>;
+
+    /// Returns `true` if the tasklet was spawned.
+    fn is_spawned(&self) -> bool;
+}
+
+/// Parameters of the reserved tasklet.
+struct Reservation<T: 'static, C: 'static> {
+    /// Tasklet creation configuration.
+    config: TaskletConfig,
+    /// Tasklet step function.
+    step_fn: StepFn<T, C>,
+    /// Data provider the tasklet is subscribed to when spawned.
+    provider: &'static (dyn DataProvider<T> + Sync),
+}
+
+/// Spawner of the tasklet reserved during system initialization.
+///
+/// Spawner shall be a static variable and shall be reserved only once, before the system is
+/// started. Spawned tasklet is created in the storage passed to the spawner, so after spawning it
+/// can be accessed with handles created from that storage.
+///
+/// # Generic Parameters
+/// * `T` - Type that is processed by the tasklet.
+/// * `C` - Type of tasklet context data. Context is created with its default value when the
+///   tasklet is spawned.
+/// * `COND_COUNT` - Number of tasklet conditions.
+pub struct TaskletSpawner<T: 'static, C: 'static, const COND_COUNT: usize> {
+    /// Storage where the tasklet is created when spawned.
+    storage: &'static TaskletStorage<T, C, COND_COUNT>,
+    /// Parameters of the reserved tasklet.
+    reservation: OnceCell<Reservation<T, C>>,
+}
+
+/// SAFETY: Reservation is set only during system initialization, and tasklet storage is initialized
+/// only in a critical section when the tasklet is spawned.
+unsafe impl<T, C, const COND_COUNT: usize> Sync for TaskletSpawner<T, C, COND_COUNT> {}
+
+impl<T, C: Default, const COND_COUNT: usize> TaskletSpawner<T, C, COND_COUNT> {
+    /// Creates new spawner.
+    ///
+    /// # Parameters
+    /// * `storage` - Storage where the tasklet is created when spawned.
+    pub const fn new(storage: &'static TaskletStorage<T, C, COND_COUNT>) -> Self {
+        TaskletSpawner {
+            storage,
+            reservation: OnceCell::new(),
+        }
+    }
+
+    /// Returns `true` if the tasklet was reserved.
+    pub fn is_reserved(&self) -> bool {
+        self.reservation.get().is_some()
+    }
+
+    /// Reserves the tasklet.
+    ///
+    /// # Parameters
+    /// * `config` - Tasklet creation configuration.
+    /// * `step_fn` - Tasklet step function.
+    /// * `provider` - Data provider the tasklet is subscribed to when spawned.
+    ///
+    /// # Return
+    /// `()` if successful, `SystemError` otherwise.
+    ///
+    /// # Safety
+    /// This is safe to call during system initialization (before scheduler is started).
+    pub(crate) unsafe fn reserve(
+        &self,
+        config: TaskletConfig,
+        step_fn: StepFn<T, C>,
+        provider: &'static (dyn DataProvider<T> + Sync),
+    ) -> Result<(), SystemError> {
+        if self.storage.is_initialized() {
+            return Err(SystemError::StorageAlreadyInitialized);
+        }
+
+        self.reservation
+            .set(Reservation {
+                config,
+                step_fn,
+                provider,
+            })
+            .map_err(|_| SystemError::TaskletAlreadyReserved(config.name))
+    }
+}
+
+impl<T, C: Default, const COND_COUNT: usize> SpawnableTasklet for TaskletSpawner<T, C, COND_COUNT> {
+    fn spawn(&self) -> Result<DataReceiver, RuntimeError> {
+        let reservation = self
+            .reservation
+            .get()
+            .ok_or(RuntimeError::TaskletNotReserved)?;
+
+        Aerugo::spawn_reserved_tasklet(
+            reservation.config,
+            reservation.step_fn,
+            reservation.provider,
+            self.storage,
+        )
+    }
+
+    fn is_spawned(&self) -> bool {
+        self.storage.is_initialized()
+    }
+}
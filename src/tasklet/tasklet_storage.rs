@@ -13,7 +13,8 @@ use heapless::Vec;
 use crate::api::RuntimeApi;
 use crate::boolean_condition::BooleanConditionSet;
 use crate::error::SystemError;
-use crate::tasklet::{StepFn, TaskletConfig, TaskletHandle, TaskletId};
+use crate::guard_zone::{ContextGuard, GuardZone};
+use crate::tasklet::{TaskletConfig, TaskletHandle, TaskletId, TaskletStep};
 
 /// Type of the tasklet buffer storage.
 pub(crate) type TaskletBuffer = Vec<u8, { core::mem::size_of::<Tasklet<(), (), 0>>() }>;
@@ -26,19 +27,30 @@ pub(crate) type TaskletBuffer = Vec<u8, { core::mem::size_of::<Tasklet<(), (), 0
 ///
 /// Storage shall be a static variable and shall be initialized only once, before the system is started.
 ///
+/// Context data is surrounded with [guard zones](crate::guard_zone::GuardZone), so an overrun of the
+/// context buffer is detected by the system before it can silently corrupt other kernel structures.
+///
 /// # Generic Parameters
-/// * `T` - Type that is processed by the tasklet.
+/// * `T` - Type that is processed by the tasklet. Tasklet can only be subscribed to a
+///   [source](crate::TaskletDataSource) providing this type.
 /// * `C` - Type of tasklet context data.
-/// * `COND_COUNT` - Number of tasklet conditions.
-pub struct TaskletStorage<T, C, const COND_COUNT: usize> {
+/// * `COND_COUNT` - Number of tasklet conditions, which is the maximum size of the
+///   [condition set](crate::api::InitApi::set_tasklet_conditions) assigned to the tasklet. Tasklet
+///   without conditions has `COND_COUNT` equal to 0, which is the default, so it can be omitted.
+#[repr(C)]
+pub struct TaskletStorage<T, C, const COND_COUNT: usize = 0> {
     /// Marks whether this storage is initialized.
     initialized: OnceCell<()>,
     /// Buffer for the tasklet structure.
     tasklet_buffer: OnceCell<TaskletBuffer>,
     /// Storage for the tasklet conditions.
     tasklet_conditions: OnceCell<BooleanConditionSet<COND_COUNT>>,
+    /// Guard zone placed before the context data.
+    context_guard_head: GuardZone,
     /// Storage for the context data.
     tasklet_context: UnsafeCell<MaybeUninit<C>>,
+    /// Guard zone placed after the context data.
+    context_guard_tail: GuardZone,
     /// Marker for the tasklet data type.
     _data_type_marker: PhantomData<T>,
 }
@@ -60,13 +72,18 @@ unsafe impl<T: 'static, C: 'static, const COND_COUNT: usize> Sync
 }
 
 impl<T: 'static, C: 'static, const COND_COUNT: usize> TaskletStorage<T, C, COND_COUNT> {
+    /// Maximum number of conditions assigned to the stored tasklet.
+    pub const CONDITION_COUNT: usize = COND_COUNT;
+
     /// Creates new storage.
     pub const fn new() -> Self {
         TaskletStorage {
             initialized: OnceCell::new(),
             tasklet_buffer: OnceCell::new(),
             tasklet_conditions: OnceCell::new(),
+            context_guard_head: GuardZone::new(),
             tasklet_context: UnsafeCell::new(MaybeUninit::uninit()),
+            context_guard_tail: GuardZone::new(),
             _data_type_marker: PhantomData,
         }
     }
@@ -96,7 +113,7 @@ impl<T: 'static, C: 'static, const COND_COUNT: usize> TaskletStorage<T, C, COND_
     pub(crate) unsafe fn init(
         &'static self,
         config: TaskletConfig,
-        step_fn: StepFn<T, C>,
+        step: TaskletStep<T, C>,
         context: C,
         runtime_api: &'static dyn RuntimeApi,
     ) -> Result<&Tasklet<T, C, COND_COUNT>, SystemError> {
@@ -112,9 +129,10 @@ impl<T: 'static, C: 'static, const COND_COUNT: usize> TaskletStorage<T, C, COND_
         let tasklet = Tasklet::<T, C, COND_COUNT>::new(
             TaskletId::get_next(),
             config,
-            step_fn,
+            step,
             // SAFETY: This is safe, because `tasklet_context` was just initialized.
             unsafe { tasklet_context.assume_init_mut() },
+            ContextGuard::new(&self.context_guard_head, &self.context_guard_tail),
             &self.tasklet_conditions,
             runtime_api,
         );
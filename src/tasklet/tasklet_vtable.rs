@@ -6,8 +6,11 @@
 //!
 //! For more information look at `TaskletPtr` structure.
 
+use crate::configuration_seal::Crc32;
+use crate::manifest::TaskletManifest;
+use crate::system_state::TaskletState;
 use crate::tasklet::{Tasklet, TaskletId, TaskletStatus};
-use crate::time::Instant;
+use crate::time::{Duration, Instant};
 
 /// Hand-made tasklet virtual table.
 pub(crate) struct TaskletVTable {
@@ -17,6 +20,14 @@ pub(crate) struct TaskletVTable {
     pub(crate) get_name: fn(*const ()) -> &'static str,
     /// Pointer to [get_priority](get_priority()) function.
     pub(crate) get_priority: fn(*const ()) -> u8,
+    /// Pointer to [set_inherited_priority](set_inherited_priority()) function.
+    pub(crate) set_inherited_priority: fn(*const (), Option<u8>),
+    /// Pointer to [get_deadline](get_deadline()) function.
+    pub(crate) get_deadline: fn(*const ()) -> Option<Duration>,
+    /// Pointer to [get_yield_budget](get_yield_budget()) function.
+    pub(crate) get_yield_budget: fn(*const ()) -> Option<Duration>,
+    /// Pointer to [is_resumable](is_resumable()) function.
+    pub(crate) is_resumable: fn(*const ()) -> bool,
     /// Pointer to [get_status](get_status()) function.
     pub(crate) get_status: fn(*const ()) -> TaskletStatus,
     /// Pointer to [set_status](set_status()) function.
@@ -25,14 +36,41 @@ pub(crate) struct TaskletVTable {
     pub(crate) get_last_execution_time: fn(*const ()) -> Instant,
     /// Pointer to [set_last_execution_time](set_last_execution_time()) function.
     pub(crate) set_last_execution_time: fn(*const (), Instant),
+    /// Pointer to [get_absolute_deadline](get_absolute_deadline()) function.
+    #[cfg_attr(not(feature = "edf-scheduling"), allow(dead_code))]
+    pub(crate) get_absolute_deadline: fn(*const ()) -> Option<Instant>,
+    /// Pointer to [set_absolute_deadline](set_absolute_deadline()) function.
+    pub(crate) set_absolute_deadline: fn(*const (), Option<Instant>),
+    /// Pointer to [set_interrupt_wake_time](set_interrupt_wake_time()) function.
+    #[cfg(feature = "wake-latency")]
+    pub(crate) set_interrupt_wake_time: fn(*const (), Instant),
+    /// Pointer to [take_interrupt_wake_time](take_interrupt_wake_time()) function.
+    #[cfg(feature = "wake-latency")]
+    pub(crate) take_interrupt_wake_time: fn(*const ()) -> Option<Instant>,
     /// Pointer to [has_work](has_work()) function.
     pub(crate) has_work: fn(*const ()) -> bool,
     /// Pointer to [is_active](is_active()) function.
     pub(crate) is_active: fn(*const ()) -> bool,
+    /// Pointer to [suspend](suspend()) function.
+    pub(crate) suspend: fn(*const ()) -> bool,
+    /// Pointer to [terminate](terminate()) function.
+    pub(crate) terminate: fn(*const ()) -> bool,
+    /// Pointer to [is_terminated](is_terminated()) function.
+    pub(crate) is_terminated: fn(*const ()) -> bool,
+    /// Pointer to [finalize](finalize()) function.
+    pub(crate) finalize: fn(*const ()),
     /// Pointer to [is_subscribed](is_subscribed()) function.
     pub(crate) is_subscribed: fn(*const ()) -> bool,
     /// Pointer to [execute](execute()) function.
     pub(crate) execute: fn(*const ()) -> bool,
+    /// Pointer to [is_context_intact](is_context_intact()) function.
+    pub(crate) is_context_intact: fn(*const ()) -> bool,
+    /// Pointer to [update_configuration_crc](update_configuration_crc()) function.
+    pub(crate) update_configuration_crc: fn(*const (), Crc32) -> Crc32,
+    /// Pointer to [manifest](manifest()) function.
+    pub(crate) manifest: fn(*const ()) -> TaskletManifest,
+    /// Pointer to [state](state()) function.
+    pub(crate) state: fn(*const ()) -> TaskletState,
 }
 
 /// Constructs `Tasklet` virtual table for given `T` and `C` types.
@@ -46,14 +84,32 @@ pub(crate) fn tasklet_vtable<T: 'static, C: 'static, const COND_COUNT: usize>(
         get_id: get_id::<T, C, COND_COUNT>,
         get_name: get_name::<T, C, COND_COUNT>,
         get_priority: get_priority::<T, C, COND_COUNT>,
+        set_inherited_priority: set_inherited_priority::<T, C, COND_COUNT>,
+        get_deadline: get_deadline::<T, C, COND_COUNT>,
+        get_yield_budget: get_yield_budget::<T, C, COND_COUNT>,
+        is_resumable: is_resumable::<T, C, COND_COUNT>,
         get_status: get_status::<T, C, COND_COUNT>,
         set_status: set_status::<T, C, COND_COUNT>,
         get_last_execution_time: get_last_execution_time::<T, C, COND_COUNT>,
         set_last_execution_time: set_last_execution_time::<T, C, COND_COUNT>,
+        get_absolute_deadline: get_absolute_deadline::<T, C, COND_COUNT>,
+        set_absolute_deadline: set_absolute_deadline::<T, C, COND_COUNT>,
+        #[cfg(feature = "wake-latency")]
+        set_interrupt_wake_time: set_interrupt_wake_time::<T, C, COND_COUNT>,
+        #[cfg(feature = "wake-latency")]
+        take_interrupt_wake_time: take_interrupt_wake_time::<T, C, COND_COUNT>,
         has_work: has_work::<T, C, COND_COUNT>,
         is_active: is_active::<T, C, COND_COUNT>,
+        suspend: suspend::<T, C, COND_COUNT>,
+        terminate: terminate::<T, C, COND_COUNT>,
+        is_terminated: is_terminated::<T, C, COND_COUNT>,
+        finalize: finalize::<T, C, COND_COUNT>,
         is_subscribed: is_subscribed::<T, C, COND_COUNT>,
         execute: execute::<T, C, COND_COUNT>,
+        is_context_intact: is_context_intact::<T, C, COND_COUNT>,
+        update_configuration_crc: update_configuration_crc::<T, C, COND_COUNT>,
+        manifest: manifest::<T, C, COND_COUNT>,
+        state: state::<T, C, COND_COUNT>,
     }
 }
 
@@ -90,6 +146,57 @@ fn get_priority<T: 'static, C: 'static, const COND_COUNT: usize>(ptr: *const ())
     tasklet.get_priority()
 }
 
+/// "Virtual" call to the `set_inherited_priority` `Tasklet` function.
+///
+/// See: [set_inherited_priority](crate::tasklet::Tasklet::set_inherited_priority())
+#[inline(always)]
+fn set_inherited_priority<T: 'static, C: 'static, const COND_COUNT: usize>(
+    ptr: *const (),
+    priority: Option<u8>,
+) {
+    // SAFETY: This is safe, because `Tasklet` is the only structure that implements `Task` trait,
+    // and so is the only type that we store in the `*const ()`.
+    let tasklet = unsafe { &*(ptr as *const Tasklet<T, C, COND_COUNT>) };
+    tasklet.set_inherited_priority(priority)
+}
+
+/// "Virtual" call to the `get_deadline` `Tasklet` function.
+///
+/// See: [get_deadline](crate::tasklet::Tasklet::get_deadline())
+#[inline(always)]
+fn get_deadline<T: 'static, C: 'static, const COND_COUNT: usize>(
+    ptr: *const (),
+) -> Option<Duration> {
+    // SAFETY: This is safe, because `Tasklet` is the only structure that implements `Task` trait,
+    // and so is the only type that we store in the `*const ()`.
+    let tasklet = unsafe { &*(ptr as *const Tasklet<T, C, COND_COUNT>) };
+    tasklet.get_deadline()
+}
+
+/// "Virtual" call to the `get_yield_budget` `Tasklet` function.
+///
+/// See: [get_yield_budget](crate::tasklet::Tasklet::get_yield_budget())
+#[inline(always)]
+fn get_yield_budget<T: 'static, C: 'static, const COND_COUNT: usize>(
+    ptr: *const (),
+) -> Option<Duration> {
+    // SAFETY: This is safe, because `Tasklet` is the only structure that implements `Task` trait,
+    // and so is the only type that we store in the `*const ()`.
+    let tasklet = unsafe { &*(ptr as *const Tasklet<T, C, COND_COUNT>) };
+    tasklet.get_yield_budget()
+}
+
+/// "Virtual" call to the `is_resumable` `Tasklet` function.
+///
+/// See: [is_resumable](crate::tasklet::Tasklet::is_resumable())
+#[inline(always)]
+fn is_resumable<T: 'static, C: 'static, const COND_COUNT: usize>(ptr: *const ()) -> bool {
+    // SAFETY: This is safe, because `Tasklet` is the only structure that implements `Task` trait,
+    // and so is the only type that we store in the `*const ()`.
+    let tasklet = unsafe { &*(ptr as *const Tasklet<T, C, COND_COUNT>) };
+    tasklet.is_resumable()
+}
+
 /// "Virtual" call to the `get_status` `Tasklet` function.
 ///
 /// See: [get_status](crate::tasklet::Tasklet::get_status())
@@ -142,6 +249,62 @@ fn set_last_execution_time<T: 'static, C: 'static, const COND_COUNT: usize>(
     tasklet.set_last_execution_time(time)
 }
 
+/// "Virtual" call to the `get_absolute_deadline` `Tasklet` function.
+///
+/// See: [get_absolute_deadline](crate::tasklet::Tasklet::get_absolute_deadline())
+#[inline(always)]
+fn get_absolute_deadline<T: 'static, C: 'static, const COND_COUNT: usize>(
+    ptr: *const (),
+) -> Option<Instant> {
+    // SAFETY: This is safe, because `Tasklet` is the only structure that implements `Task` trait,
+    // and so is the only type that we store in the `*const ()`.
+    let tasklet = unsafe { &*(ptr as *const Tasklet<T, C, COND_COUNT>) };
+    tasklet.get_absolute_deadline()
+}
+
+/// "Virtual" call to the `set_absolute_deadline` `Tasklet` function.
+///
+/// See: [set_absolute_deadline](crate::tasklet::Tasklet::set_absolute_deadline())
+#[inline(always)]
+fn set_absolute_deadline<T: 'static, C: 'static, const COND_COUNT: usize>(
+    ptr: *const (),
+    deadline: Option<Instant>,
+) {
+    // SAFETY: This is safe, because `Tasklet` is the only structure that implements `Task` trait,
+    // and so is the only type that we store in the `*const ()`.
+    let tasklet = unsafe { &*(ptr as *const Tasklet<T, C, COND_COUNT>) };
+    tasklet.set_absolute_deadline(deadline)
+}
+
+/// "Virtual" call to the `set_interrupt_wake_time` `Tasklet` function.
+///
+/// See: [set_interrupt_wake_time](crate::tasklet::Tasklet::set_interrupt_wake_time())
+#[cfg(feature = "wake-latency")]
+#[inline(always)]
+fn set_interrupt_wake_time<T: 'static, C: 'static, const COND_COUNT: usize>(
+    ptr: *const (),
+    time: Instant,
+) {
+    // SAFETY: This is safe, because `Tasklet` is the only structure that implements `Task` trait,
+    // and so is the only type that we store in the `*const ()`.
+    let tasklet = unsafe { &*(ptr as *const Tasklet<T, C, COND_COUNT>) };
+    tasklet.set_interrupt_wake_time(time)
+}
+
+/// "Virtual" call to the `take_interrupt_wake_time` `Tasklet` function.
+///
+/// See: [take_interrupt_wake_time](crate::tasklet::Tasklet::take_interrupt_wake_time())
+#[cfg(feature = "wake-latency")]
+#[inline(always)]
+fn take_interrupt_wake_time<T: 'static, C: 'static, const COND_COUNT: usize>(
+    ptr: *const (),
+) -> Option<Instant> {
+    // SAFETY: This is safe, because `Tasklet` is the only structure that implements `Task` trait,
+    // and so is the only type that we store in the `*const ()`.
+    let tasklet = unsafe { &*(ptr as *const Tasklet<T, C, COND_COUNT>) };
+    tasklet.take_interrupt_wake_time()
+}
+
 /// "Virtual" call to the `has_work` `Tasklet` function.
 ///
 /// See: [has_work](crate::tasklet::Tasklet::has_work())
@@ -164,6 +327,50 @@ fn is_active<T: 'static, C: 'static, const COND_COUNT: usize>(ptr: *const ()) ->
     tasklet.is_active()
 }
 
+/// "Virtual" call to the `suspend` `Tasklet` function.
+///
+/// See: [suspend](crate::tasklet::Tasklet::suspend())
+#[inline(always)]
+fn suspend<T: 'static, C: 'static, const COND_COUNT: usize>(ptr: *const ()) -> bool {
+    // SAFETY: This is safe, because `Tasklet` is the only structure that implements `Task` trait,
+    // and so is the only type that we store in the `*const ()`.
+    let tasklet = unsafe { &*(ptr as *const Tasklet<T, C, COND_COUNT>) };
+    tasklet.suspend()
+}
+
+/// "Virtual" call to the `terminate` `Tasklet` function.
+///
+/// See: [terminate](crate::tasklet::Tasklet::terminate())
+#[inline(always)]
+fn terminate<T: 'static, C: 'static, const COND_COUNT: usize>(ptr: *const ()) -> bool {
+    // SAFETY: This is safe, because `Tasklet` is the only structure that implements `Task` trait,
+    // and so is the only type that we store in the `*const ()`.
+    let tasklet = unsafe { &*(ptr as *const Tasklet<T, C, COND_COUNT>) };
+    tasklet.terminate()
+}
+
+/// "Virtual" call to the `is_terminated` `Tasklet` function.
+///
+/// See: [is_terminated](crate::tasklet::Tasklet::is_terminated())
+#[inline(always)]
+fn is_terminated<T: 'static, C: 'static, const COND_COUNT: usize>(ptr: *const ()) -> bool {
+    // SAFETY: This is safe, because `Tasklet` is the only structure that implements `Task` trait,
+    // and so is the only type that we store in the `*const ()`.
+    let tasklet = unsafe { &*(ptr as *const Tasklet<T, C, COND_COUNT>) };
+    tasklet.is_terminated()
+}
+
+/// "Virtual" call to the `finalize` `Tasklet` function.
+///
+/// See: [finalize](crate::tasklet::Tasklet::finalize())
+#[inline(always)]
+fn finalize<T: 'static, C: 'static, const COND_COUNT: usize>(ptr: *const ()) {
+    // SAFETY: This is safe, because `Tasklet` is the only structure that implements `Task` trait,
+    // and so is the only type that we store in the `*const ()`.
+    let tasklet = unsafe { &*(ptr as *const Tasklet<T, C, COND_COUNT>) };
+    tasklet.finalize()
+}
+
 /// "Virtual" call to the `is_subscribed` `Tasklet` function.
 ///
 /// See: [is_active](crate::tasklet::Tasklet::is_subscribed())
@@ -185,3 +392,50 @@ fn execute<T: 'static, C: 'static, const COND_COUNT: usize>(ptr: *const ()) -> b
     let tasklet = unsafe { &*(ptr as *const Tasklet<T, C, COND_COUNT>) };
     tasklet.execute()
 }
+
+/// "Virtual" call to the `is_context_intact` `Tasklet` function.
+///
+/// See: [is_context_intact](crate::tasklet::Tasklet::is_context_intact())
+#[inline(always)]
+fn is_context_intact<T: 'static, C: 'static, const COND_COUNT: usize>(ptr: *const ()) -> bool {
+    // SAFETY: This is safe, because `Tasklet` is the only structure that implements `Task` trait,
+    // and so is the only type that we store in the `*const ()`.
+    let tasklet = unsafe { &*(ptr as *const Tasklet<T, C, COND_COUNT>) };
+    tasklet.is_context_intact()
+}
+
+/// "Virtual" call to the `update_configuration_crc` `Tasklet` function.
+///
+/// See: [update_configuration_crc](crate::tasklet::Tasklet::update_configuration_crc())
+#[inline(always)]
+fn update_configuration_crc<T: 'static, C: 'static, const COND_COUNT: usize>(
+    ptr: *const (),
+    crc: Crc32,
+) -> Crc32 {
+    // SAFETY: This is safe, because `Tasklet` is the only structure that implements `Task` trait,
+    // and so is the only type that we store in the `*const ()`.
+    let tasklet = unsafe { &*(ptr as *const Tasklet<T, C, COND_COUNT>) };
+    tasklet.update_configuration_crc(crc)
+}
+
+/// "Virtual" call to the `manifest` `Tasklet` function.
+///
+/// See: [manifest](crate::tasklet::Tasklet::manifest())
+#[inline(always)]
+fn manifest<T: 'static, C: 'static, const COND_COUNT: usize>(ptr: *const ()) -> TaskletManifest {
+    // SAFETY: This is safe, because `Tasklet` is the only structure that implements `Task` trait,
+    // and so is the only type that we store in the `*const ()`.
+    let tasklet = unsafe { &*(ptr as *const Tasklet<T, C, COND_COUNT>) };
+    tasklet.manifest()
+}
+
+/// "Virtual" call to the `state` `Tasklet` function.
+///
+/// See: [state](crate::tasklet::Tasklet::state())
+#[inline(always)]
+fn state<T: 'static, C: 'static, const COND_COUNT: usize>(ptr: *const ()) -> TaskletState {
+    // SAFETY: This is safe, because `Tasklet` is the only structure that implements `Task` trait,
+    // and so is the only type that we store in the `*const ()`.
+    let tasklet = unsafe { &*(ptr as *const Tasklet<T, C, COND_COUNT>) };
+    tasklet.state()
+}
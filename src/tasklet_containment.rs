@@ -0,0 +1,175 @@
+//! Containment of runaway tasklets.
+//!
+//! Deadline monitoring reports a step that overran its deadline only after the step returns, and
+//! the scheduler watchdog can only reset the whole system when the step doesn't return at all.
+//! Tasklet containment checks the executed step from the system timer interrupt instead. When a
+//! step of a tasklet with a [deadline](crate::TaskletConfig::deadline) runs longer than its
+//! deadline extended by a configured grace time, the tasklet is marked as failed, a
+//! [FaultRecord](crate::FaultRecord) with its name and configured fault code is stored, and the
+//! step is abandoned.
+//!
+//! Step can't be forcibly terminated without skipping destructors and leaving its context and
+//! locked resources in an undefined state, so abandoning is restricted to the points where the step
+//! checks [should_yield](crate::api::RuntimeApi::should_yield), for example with
+//! [yield_point](crate::yield_point), or its [cancellation token](crate::CancellationToken).
+//! Abandoned step is cancelled, so `should_yield` always returns `true` after that, and the step
+//! should return as soon as possible. Failed tasklet is suspended, so it is
+//! not executed again until it is [resumed](crate::api::RuntimeApi::resume_tasklet). Step that
+//! never checks `should_yield` is still detected and its tasklet is suspended, but it has to be
+//! handled by the [scheduler watchdog](crate::aerugo::Aerugo::enable_scheduler_watchdog).
+
+use core::cell::OnceCell;
+
+use crate::error::SystemError;
+use crate::executor::Executor;
+use crate::fault::FaultRecord;
+use crate::fault_manager::FaultManager;
+use crate::time::Duration;
+
+/// Configuration of the tasklet containment.
+#[derive(Copy, Clone)]
+struct TaskletContainmentConfig {
+    /// Time by which a step can overrun its deadline before the tasklet is contained.
+    grace: Duration,
+    /// Code of the fault stored when a tasklet is contained.
+    fault_code: u32,
+}
+
+/// Tasklet containment.
+///
+/// This shouldn't be created by hand by the user or anywhere else in the code.
+/// It should be used as a singleton (crate::aerugo::TASKLET_CONTAINMENT) and shouldn't be directly
+/// accessed by any other part of the system.
+pub(crate) struct TaskletContainment {
+    /// Configuration, not set if the containment is disabled.
+    config: OnceCell<TaskletContainmentConfig>,
+}
+
+/// It is safe assuming that configuration is modified only during system initialization (before
+/// scheduler is started), and is only read afterwards.
+unsafe impl Sync for TaskletContainment {}
+
+impl TaskletContainment {
+    /// Creates new, disabled tasklet containment.
+    ///
+    /// # Safety
+    /// This shouldn't be called more than once.
+    pub(crate) const fn new() -> Self {
+        TaskletContainment {
+            config: OnceCell::new(),
+        }
+    }
+
+    /// Enables the containment.
+    ///
+    /// # Parameters
+    /// * `grace` - Time by which a step can overrun its deadline before the tasklet is contained.
+    /// * `fault_code` - Code of the fault stored when a tasklet is contained.
+    ///
+    /// # Return
+    /// `()` if successful, `SystemError` otherwise.
+    ///
+    /// # Safety
+    /// This is unsafe, because it mutably borrows the configuration. This is safe to call during
+    /// system initialization (before scheduler is started).
+    pub(crate) unsafe fn enable(
+        &'static self,
+        grace: Duration,
+        fault_code: u32,
+    ) -> Result<(), SystemError> {
+        match self
+            .config
+            .set(TaskletContainmentConfig { grace, fault_code })
+        {
+            Ok(_) => Ok(()),
+            Err(_) => Err(SystemError::TaskletContainmentAlreadyEnabled),
+        }
+    }
+
+    /// Returns `true` if the containment is enabled.
+    pub(crate) fn is_enabled(&'static self) -> bool {
+        self.config.get().is_some()
+    }
+
+    /// Checks if the executed step should be contained.
+    ///
+    /// # Parameters
+    /// * `deadline` - Deadline of the executed tasklet.
+    /// * `step_time` - Time since the start of the executed step.
+    ///
+    /// # Return
+    /// Code of the fault that should be stored if the step runs longer than its deadline extended
+    /// by the grace time, `None` otherwise or if the tasklet has no deadline.
+    pub(crate) fn check(
+        &'static self,
+        deadline: Option<Duration>,
+        step_time: Duration,
+    ) -> Option<u32> {
+        let config = self.config.get()?;
+        let limit = deadline?.checked_add(config.grace)?;
+
+        (step_time > limit).then_some(config.fault_code)
+    }
+
+    /// Contains the executed tasklet if its step overran its deadline by more than the grace time.
+    ///
+    /// This is called from the system timer interrupt. Overrunning tasklet is suspended, a fault is
+    /// recorded and its step is cancelled.
+    ///
+    /// # Parameters
+    /// * `executor` - System executor.
+    /// * `fault_manager` - Fault manager recording the fault.
+    pub(crate) fn contain_overrun(
+        &'static self,
+        executor: &'static Executor,
+        fault_manager: &'static FaultManager,
+    ) {
+        let Some(tasklet) = executor.current_tasklet() else {
+            return;
+        };
+        let Some(step_time) = executor.current_step_time() else {
+            return;
+        };
+        let Some(fault_code) = self.check(tasklet.get_deadline(), step_time) else {
+            return;
+        };
+        let Some(tasklet) = executor.cancel_current_step() else {
+            return;
+        };
+
+        tasklet.suspend();
+        fault_manager.record(FaultRecord::new(tasklet.get_name(), 0, fault_code));
+        crate::logln!(
+            "Aerugo: tasklet '{}' step ran for {}, tasklet was contained",
+            tasklet.get_name(),
+            step_time
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn contain_after_grace() {
+        static CONTAINMENT: TaskletContainment = TaskletContainment::new();
+
+        assert_eq!(
+            CONTAINMENT.check(Some(Duration::millis(1)), Duration::secs(1)),
+            None
+        );
+
+        unsafe { CONTAINMENT.enable(Duration::millis(5), 3).unwrap() };
+
+        assert_eq!(CONTAINMENT.check(None, Duration::secs(1)), None);
+        assert_eq!(
+            CONTAINMENT.check(Some(Duration::millis(10)), Duration::millis(15)),
+            None
+        );
+        assert_eq!(
+            CONTAINMENT.check(Some(Duration::millis(10)), Duration::millis(16)),
+            Some(3)
+        );
+    }
+}
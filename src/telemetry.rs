@@ -0,0 +1,212 @@
+//! Telemetry of tasklet context fields.
+//!
+//! Values kept in tasklet contexts (ex. latest measurement, state of a controller) are often
+//! needed in telemetry. Instead of writing reporting code in each tasklet, context can implement
+//! [Telemetry], usually with [AerugoTelemetry](crate::AerugoTelemetry) derive macro and
+//! `#[telemetry]` attribute on selected fields, and be
+//! [published](crate::api::InitApi::publish_context_telemetry) to a message queue.
+//!
+//! Context is sampled by the executor right after the step of the tasklet, so it's never accessed
+//! concurrently with the step function. Fields are sampled at most once per configured period,
+//! and as context changes only during tasklet steps, no samples are sent while the tasklet isn't
+//! executed.
+
+mod telemetry_storage;
+
+pub use self::telemetry_storage::TelemetryStorage;
+
+use crate::aerugo::Aerugo;
+use crate::message_queue::MessageQueueHandle;
+use crate::mutex::Mutex;
+use crate::tasklet::{CompletionListener, TaskletId};
+use crate::time::{Duration, Instant};
+
+/// Value of the telemetry field.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum TelemetryValue {
+    /// Boolean value.
+    Bool(bool),
+    /// Unsigned integer value.
+    Unsigned(u64),
+    /// Signed integer value.
+    Signed(i64),
+    /// Floating point value.
+    Float(f64),
+}
+
+/// Implements conversion into [TelemetryValue] for given types.
+macro_rules! impl_telemetry_value_from {
+    ($variant:ident, $target:ty, $($source:ty),*) => {
+        $(
+            impl From<$source> for TelemetryValue {
+                fn from(value: $source) -> Self {
+                    TelemetryValue::$variant(value as $target)
+                }
+            }
+        )*
+    };
+}
+
+impl_telemetry_value_from!(Unsigned, u64, u8, u16, u32, u64, usize);
+impl_telemetry_value_from!(Signed, i64, i8, i16, i32, i64, isize);
+impl_telemetry_value_from!(Float, f64, f32, f64);
+
+impl From<bool> for TelemetryValue {
+    fn from(value: bool) -> Self {
+        TelemetryValue::Bool(value)
+    }
+}
+
+/// Structure with fields published in telemetry.
+pub trait Telemetry {
+    /// Reports current value of each telemetry field.
+    ///
+    /// # Parameters
+    /// * `report` - Function called with the name and the value of each field.
+    fn sample(&self, report: &mut dyn FnMut(&'static str, TelemetryValue));
+}
+
+/// Sample of the tasklet context field.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct TelemetrySample {
+    /// ID of the tasklet.
+    pub tasklet: TaskletId,
+    /// Name of the field.
+    pub field: &'static str,
+    /// Value of the field.
+    pub value: TelemetryValue,
+    /// Time when the field was sampled.
+    pub time: Instant,
+}
+
+/// Publisher of the tasklet context telemetry.
+///
+/// # Generic Parameters
+/// * `N` - Size of the destination queue.
+pub(crate) struct ContextTelemetry<const N: usize> {
+    /// ID of the sampled tasklet.
+    tasklet: TaskletId,
+    /// Minimum time between samples.
+    period: Duration,
+    /// Destination queue.
+    destination: MessageQueueHandle<TelemetrySample, N>,
+    /// Time of the last sample.
+    last_sample: Mutex<Option<Instant>>,
+    /// Number of samples that didn't fit in the destination queue.
+    dropped: &'static Mutex<u32>,
+}
+
+impl<const N: usize> ContextTelemetry<N> {
+    /// Creates new context telemetry publisher.
+    ///
+    /// # Parameters
+    /// * `tasklet` - ID of the sampled tasklet.
+    /// * `period` - Minimum time between samples.
+    /// * `destination` - Destination queue.
+    /// * `dropped` - Counter of dropped samples.
+    pub(crate) fn new(
+        tasklet: TaskletId,
+        period: Duration,
+        destination: MessageQueueHandle<TelemetrySample, N>,
+        dropped: &'static Mutex<u32>,
+    ) -> Self {
+        ContextTelemetry {
+            tasklet,
+            period,
+            destination,
+            last_sample: Mutex::new(None),
+            dropped,
+        }
+    }
+
+    /// Sends samples of the context fields, if the period has elapsed since the last sample.
+    ///
+    /// # Parameters
+    /// * `context` - Context of the tasklet.
+    /// * `time` - Current system time.
+    fn publish(&self, context: &dyn Telemetry, time: Instant) {
+        let due = self.last_sample.lock(|last_sample| match last_sample {
+            Some(last) if time < *last + self.period => false,
+            _ => {
+                *last_sample = Some(time);
+                true
+            }
+        });
+
+        if !due {
+            return;
+        }
+
+        context.sample(&mut |field, value| {
+            let sample = TelemetrySample {
+                tasklet: self.tasklet,
+                field,
+                value,
+                time,
+            };
+
+            if self.destination.send_data(sample).is_err() {
+                self.dropped.lock(|count| *count = count.wrapping_add(1));
+            }
+        });
+    }
+}
+
+impl<C: Telemetry, const N: usize> CompletionListener<C> for ContextTelemetry<N> {
+    fn completed(&self, context: &C) {
+        self.publish(context, Aerugo::system_time());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::data_provider::DataProvider;
+    use crate::message_queue::{MessageQueueStorage, OverflowPolicy};
+
+    struct Context {
+        temperature: f32,
+        heater_on: bool,
+    }
+
+    impl Telemetry for Context {
+        fn sample(&self, report: &mut dyn FnMut(&'static str, TelemetryValue)) {
+            report("temperature", self.temperature.into());
+            report("heater_on", self.heater_on.into());
+        }
+    }
+
+    #[test]
+    fn publish_once_per_period() {
+        static QUEUE: MessageQueueStorage<TelemetrySample, 4> = MessageQueueStorage::new();
+        static DROPPED: Mutex<u32> = Mutex::new(0);
+        unsafe { QUEUE.init(OverflowPolicy::Reject).unwrap() };
+        let queue = QUEUE.create_handle().unwrap();
+
+        let telemetry = ContextTelemetry::new(
+            TaskletId(3000),
+            Duration::millis(10),
+            MessageQueueHandle::new(queue.queue()),
+            &DROPPED,
+        );
+        let context = Context {
+            temperature: 21.5,
+            heater_on: false,
+        };
+
+        telemetry.publish(&context, Instant::from_ticks(1000));
+        telemetry.publish(&context, Instant::from_ticks(5000));
+        assert_eq!(queue.len(), 2);
+
+        telemetry.publish(&context, Instant::from_ticks(11000));
+        assert_eq!(queue.len(), 3);
+        assert_eq!(DROPPED.lock(|count| *count), 1);
+
+        let sample = queue.queue().get_data().unwrap();
+        assert_eq!(sample.tasklet, TaskletId(3000));
+        assert_eq!(sample.field, "temperature");
+        assert_eq!(sample.value, TelemetryValue::Float(21.5));
+        assert_eq!(sample.time, Instant::from_ticks(1000));
+    }
+}
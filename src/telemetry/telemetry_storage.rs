@@ -0,0 +1,79 @@
+//! Static storage for [context telemetry](crate::telemetry::ContextTelemetry).
+//!
+//! This module contains a context telemetry storage, which is a statically allocated memory that
+//! will store the telemetry publisher for the duration of the system life.
+
+use core::cell::OnceCell;
+
+use super::ContextTelemetry;
+
+use crate::error::SystemError;
+use crate::mutex::Mutex;
+
+/// Structure containing memory for context telemetry publisher creation.
+///
+/// Storage shall be a static variable and shall be initialized only once, before the system is started.
+///
+/// # Generic Parameters
+/// * `N` - Size of the destination queue.
+pub struct TelemetryStorage<const N: usize> {
+    /// Stored publisher.
+    telemetry: OnceCell<ContextTelemetry<N>>,
+    /// Number of samples that didn't fit in the destination queue.
+    dropped: Mutex<u32>,
+}
+
+/// It is safe assuming that stored publisher is not available from the IRQ context before it is
+/// created and that initialization cannot be interrupted.
+///
+/// TelemetryStorage is initialized only in
+/// [publish_context_telemetry](crate::api::InitApi::publish_context_telemetry) implemented in
+/// [Aerugo](crate::aerugo::Aerugo) which is not accessible from the IRQ context.
+unsafe impl<const N: usize> Sync for TelemetryStorage<N> {}
+
+impl<const N: usize> TelemetryStorage<N> {
+    /// Creates new storage.
+    pub const fn new() -> Self {
+        TelemetryStorage {
+            telemetry: OnceCell::new(),
+            dropped: Mutex::new(0),
+        }
+    }
+
+    /// Returns initialization status of this storage.
+    pub fn is_initialized(&'static self) -> bool {
+        self.telemetry.get().is_some()
+    }
+
+    /// Returns number of samples that didn't fit in the destination queue.
+    pub fn dropped_count(&'static self) -> u32 {
+        self.dropped.lock(|count| *count)
+    }
+
+    /// Returns counter of dropped samples.
+    pub(crate) fn dropped(&'static self) -> &'static Mutex<u32> {
+        &self.dropped
+    }
+
+    /// Initializes this storage.
+    ///
+    /// # Parameters
+    /// * `telemetry` - Context telemetry publisher.
+    ///
+    /// # Return
+    /// Reference to the stored publisher if successful, `SystemError` otherwise.
+    ///
+    /// # Safety
+    /// This is unsafe, because it mutably borrows the stored publisher.
+    /// This is safe to call before the system initialization.
+    pub(crate) unsafe fn init(
+        &'static self,
+        telemetry: ContextTelemetry<N>,
+    ) -> Result<&'static ContextTelemetry<N>, SystemError> {
+        self.telemetry
+            .set(telemetry)
+            .map_err(|_| SystemError::StorageAlreadyInitialized)?;
+
+        Ok(self.telemetry.get().unwrap())
+    }
+}
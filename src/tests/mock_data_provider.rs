@@ -21,6 +21,10 @@ impl DataProvider<()> for MockDataProvider {
         self.data_waiting
     }
 
+    fn flush(&self) -> usize {
+        0
+    }
+
     fn get_data(&self) -> Option<()> {
         if self.data_waiting {
             Some(())
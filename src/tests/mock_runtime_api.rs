@@ -1,10 +1,19 @@
+use core::fmt;
+
 use critical_section::CriticalSection;
 
 use crate::api::RuntimeApi;
+use crate::cancellation_token::CancellationToken;
+use crate::data_provider::DataReceiver;
 use crate::error::RuntimeError;
 use crate::event::EventId;
 use crate::execution_monitor::ExecutionStats;
-use crate::tasklet::TaskletId;
+use crate::fault::FaultRecord;
+use crate::firmware_info::FirmwareInfo;
+use crate::partition::PartitionId;
+use crate::shutdown_reason::ShutdownReason;
+use crate::startup::{ComponentStatus, StartupInfo, StartupReport};
+use crate::tasklet::{IdentifiableTasklet, SpawnableTasklet, SuspendableTasklet, TaskletId};
 use crate::time::{Duration, Instant};
 
 pub(crate) struct MockRuntimeApi;
@@ -38,6 +47,15 @@ impl RuntimeApi for MockRuntimeApi {
         todo!()
     }
 
+    fn emit_event_periodic(
+        &'static self,
+        _event_id: EventId,
+        _period: Duration,
+        _count: Option<u32>,
+    ) -> Result<bool, RuntimeError> {
+        todo!()
+    }
+
     fn is_event_scheduled(&'static self, _event_id: EventId) -> Result<bool, RuntimeError> {
         todo!()
     }
@@ -50,6 +68,10 @@ impl RuntimeApi for MockRuntimeApi {
         todo!()
     }
 
+    fn clear_pending_events(&'static self, _tasklet: &dyn IdentifiableTasklet) -> usize {
+        todo!()
+    }
+
     fn get_system_time(&'static self) -> Instant {
         todo!()
     }
@@ -58,6 +80,26 @@ impl RuntimeApi for MockRuntimeApi {
         todo!()
     }
 
+    fn cancellation_token(&'static self) -> CancellationToken {
+        todo!()
+    }
+
+    fn cancel_step(&'static self) -> bool {
+        todo!()
+    }
+
+    fn should_yield(&'static self) -> bool {
+        todo!()
+    }
+
+    fn yield_needed(&'static self) -> bool {
+        todo!()
+    }
+
+    fn checkin(&'static self) {
+        todo!()
+    }
+
     fn set_system_time_offset(&'static self, _offset: Duration) -> Result<(), RuntimeError> {
         todo!()
     }
@@ -66,7 +108,65 @@ impl RuntimeApi for MockRuntimeApi {
         todo!()
     }
 
-    fn get_execution_statistics(&'static self, _tasklet_id: &TaskletId) -> Option<ExecutionStats> {
+    fn get_execution_statistics(
+        &'static self,
+        _tasklet: &dyn IdentifiableTasklet,
+    ) -> Option<ExecutionStats> {
+        todo!()
+    }
+
+    fn get_cpu_load(&'static self) -> Option<u8> {
+        todo!()
+    }
+
+    fn get_busy_time(&'static self) -> Duration {
+        todo!()
+    }
+
+    fn get_idle_time(&'static self) -> Duration {
+        todo!()
+    }
+
+    fn get_stack_usage(&'static self, _tasklet: &dyn IdentifiableTasklet) -> Option<usize> {
+        todo!()
+    }
+
+    fn get_firmware_info(&'static self) -> Result<FirmwareInfo, RuntimeError> {
+        todo!()
+    }
+
+    fn suspend_tasklet(&'static self, _tasklet: &dyn SuspendableTasklet) -> bool {
+        todo!()
+    }
+
+    fn resume_tasklet(&'static self, _tasklet: &dyn SuspendableTasklet) -> bool {
+        todo!()
+    }
+
+    fn terminate_tasklet(&'static self, _tasklet: &dyn SuspendableTasklet) -> bool {
+        todo!()
+    }
+
+    fn shutdown(&'static self, _reason: ShutdownReason) {
+        todo!()
+    }
+
+    fn spawn_tasklet(
+        &'static self,
+        _tasklet: &dyn SpawnableTasklet,
+    ) -> Result<DataReceiver, RuntimeError> {
+        todo!()
+    }
+
+    fn get_startup_info(&'static self) -> StartupInfo {
+        todo!()
+    }
+
+    fn get_startup_report(&'static self) -> StartupReport {
+        todo!()
+    }
+
+    fn get_component_status(&'static self, _name: &str) -> Option<ComponentStatus> {
         todo!()
     }
 
@@ -74,6 +174,48 @@ impl RuntimeApi for MockRuntimeApi {
         todo!()
     }
 
+    fn get_partition_overrun_count(&'static self, _partition: PartitionId) -> u32 {
+        todo!()
+    }
+
+    fn get_schedule_overrun_count(
+        &'static self,
+        _tasklet: &dyn IdentifiableTasklet,
+    ) -> Option<u32> {
+        todo!()
+    }
+
+    fn get_missed_activation_count(
+        &'static self,
+        _tasklet: &dyn IdentifiableTasklet,
+    ) -> Option<u32> {
+        todo!()
+    }
+
+    fn sync_cyclic_execution(
+        &'static self,
+        _tasklet: &dyn IdentifiableTasklet,
+        _sync_time: Instant,
+    ) -> Result<bool, RuntimeError> {
+        todo!()
+    }
+
+    fn dump_system_state(&'static self, _writer: &mut dyn fmt::Write) -> fmt::Result {
+        todo!()
+    }
+
+    fn get_latched_fault(&'static self) -> Option<FaultRecord> {
+        todo!()
+    }
+
+    fn clear_latched_fault(&'static self) {
+        todo!()
+    }
+
+    fn get_fault_record(&'static self, _index: usize) -> Option<FaultRecord> {
+        todo!()
+    }
+
     fn execute_critical<F, R>(_f: F) -> R
     where
         F: FnOnce(CriticalSection) -> R,
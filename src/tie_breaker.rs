@@ -0,0 +1,120 @@
+//! Scheduling tie breaker.
+//!
+//! When two tasklets waiting for execution have the same priority and were last executed at the
+//! same time, the order in which they are executed is arbitrary. Application must not depend on
+//! that order, but in the practice it's always the same for the given sequence of scheduling
+//! operations, so bugs relying on it can stay hidden.
+//!
+//! For tests on x86 target tie breaker can order such tasklets pseudo-randomly. Seed for the
+//! ordering is set with `AERUGO_SCHEDULING_SEED` environmental variable, so failing test can be
+//! reproduced with the same seed. Setting `AERUGO_RANDOMIZE_SCHEDULING` to `1` selects a new seed
+//! each time the system is started and logs it, so consecutive test runs explore different
+//! orderings. On other targets tie breaking is always disabled.
+
+#[cfg(feature = "use-aerugo-x86")]
+use env_parser::read_env;
+
+use crate::mutex::Mutex;
+
+/// Scheduling tie breaker.
+///
+/// This shouldn't be created by hand by the user or anywhere else in the code.
+/// It should be used as a singleton (crate::aerugo::TIE_BREAKER).
+pub(crate) struct TieBreaker {
+    /// State of the pseudo-random generator, `0` if tie breaking is disabled.
+    state: Mutex<u32>,
+}
+
+impl TieBreaker {
+    /// Seed for the scheduling order, `0` disables tie breaking.
+    #[cfg(feature = "use-aerugo-x86")]
+    #[read_env("AERUGO_SCHEDULING_SEED")]
+    const SEED: u32 = 0;
+    /// Seed for the scheduling order, `0` disables tie breaking.
+    #[cfg(not(feature = "use-aerugo-x86"))]
+    const SEED: u32 = 0;
+
+    /// If set to `1`, new seed is selected each time the system is started.
+    #[cfg(feature = "use-aerugo-x86")]
+    #[read_env("AERUGO_RANDOMIZE_SCHEDULING")]
+    const RANDOMIZE: u8 = 0;
+    /// If set to `1`, new seed is selected each time the system is started.
+    #[cfg(not(feature = "use-aerugo-x86"))]
+    const RANDOMIZE: u8 = 0;
+
+    /// Creates new tie breaker instance.
+    pub(crate) const fn new() -> Self {
+        TieBreaker {
+            state: Mutex::new(Self::SEED),
+        }
+    }
+
+    /// Initializes tie breaker when the system is started.
+    ///
+    /// # Parameters
+    /// * `entropy` - Value used to select a new seed if scheduling is randomized.
+    ///
+    /// # Return
+    /// Seed that is used for the scheduling order, `None` if tie breaking is disabled.
+    pub(crate) fn initialize(&'static self, entropy: u32) -> Option<u32> {
+        self.state.lock(|state| {
+            if Self::RANDOMIZE == 1 {
+                // Zero seed would disable tie breaking.
+                *state = if entropy != 0 { entropy } else { 1 };
+            }
+
+            match *state {
+                0 => None,
+                seed => Some(seed),
+            }
+        })
+    }
+
+    /// Returns key used to order tasklets that are otherwise equal.
+    ///
+    /// # Return
+    /// Next value from the pseudo-random sequence, or `0` if tie breaking is disabled.
+    pub(crate) fn next_key(&'static self) -> u32 {
+        self.state.lock(|state| {
+            // Xorshift generator, which never leaves `0` state.
+            *state ^= *state << 13;
+            *state ^= *state >> 17;
+            *state ^= *state << 5;
+            *state
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn disabled_with_zero_seed() {
+        static TIE_BREAKER: TieBreaker = TieBreaker {
+            state: Mutex::new(0),
+        };
+
+        assert_eq!(TIE_BREAKER.initialize(1234), None);
+        assert_eq!(TIE_BREAKER.next_key(), 0);
+        assert_eq!(TIE_BREAKER.next_key(), 0);
+    }
+
+    #[test]
+    fn same_seed_same_sequence() {
+        static TIE_BREAKER_A: TieBreaker = TieBreaker {
+            state: Mutex::new(42),
+        };
+        static TIE_BREAKER_B: TieBreaker = TieBreaker {
+            state: Mutex::new(42),
+        };
+
+        assert_eq!(TIE_BREAKER_A.initialize(1), Some(42));
+        assert_eq!(TIE_BREAKER_B.initialize(2), Some(42));
+
+        let first_key = TIE_BREAKER_A.next_key();
+        assert_ne!(first_key, 0);
+        assert_eq!(first_key, TIE_BREAKER_B.next_key());
+        assert_ne!(first_key, TIE_BREAKER_A.next_key());
+    }
+}
@@ -0,0 +1,137 @@
+//! Software timer.
+//!
+//! Timer performs an [action](TimerAction) after a delay, once or periodically. It's a lighter
+//! alternative to the cyclic tasklet execution for short timeouts, as it doesn't require a tasklet.
+//! Timers are checked by the [timer manager](crate::timer_manager::TimerManager) in each pass of
+//! the system loop, so they are as precise as the longest tasklet step.
+
+mod timer_action;
+mod timer_handle;
+mod timer_storage;
+
+pub use self::timer_action::TimerAction;
+pub use self::timer_handle::TimerHandle;
+pub use self::timer_storage::TimerStorage;
+
+use crate::mutex::Mutex;
+use crate::time::{Duration, Instant};
+
+/// State of the running timer.
+#[derive(Copy, Clone)]
+struct TimerState {
+    /// Time when the timer fires.
+    deadline: Instant,
+    /// Period of the timer, `None` if it fires only once.
+    period: Option<Duration>,
+}
+
+/// Software timer.
+pub(crate) struct Timer {
+    /// Action performed when the timer fires.
+    action: TimerAction,
+    /// State of the timer, `None` if it's stopped.
+    state: Mutex<Option<TimerState>>,
+}
+
+impl Timer {
+    /// Creates new, stopped timer.
+    ///
+    /// # Parameters
+    /// * `action` - Action performed when the timer fires.
+    pub(crate) const fn new(action: TimerAction) -> Self {
+        Timer {
+            action,
+            state: Mutex::new(None),
+        }
+    }
+
+    /// Returns action performed when the timer fires.
+    pub(crate) fn action(&self) -> TimerAction {
+        self.action
+    }
+
+    /// Starts the timer. Running timer is restarted.
+    ///
+    /// # Parameters
+    /// * `deadline` - Time when the timer fires for the first time.
+    /// * `period` - Period of the timer, `None` if it should fire only once.
+    pub(crate) fn start(&self, deadline: Instant, period: Option<Duration>) {
+        self.state
+            .lock(|state| *state = Some(TimerState { deadline, period }))
+    }
+
+    /// Stops the timer.
+    ///
+    /// # Return
+    /// `true` if timer was running, `false` otherwise.
+    pub(crate) fn stop(&self) -> bool {
+        self.state.lock(|state| state.take().is_some())
+    }
+
+    /// Returns time when the running timer fires next, `None` if it's stopped.
+    pub(crate) fn deadline(&self) -> Option<Instant> {
+        self.state.lock(|state| state.map(|state| state.deadline))
+    }
+
+    /// Returns `true` if the timer is running.
+    pub(crate) fn is_running(&self) -> bool {
+        self.state.lock(|state| state.is_some())
+    }
+
+    /// Checks if the timer fires at given time, and updates its state.
+    ///
+    /// One-shot timer is stopped after it fires. Periodic timer is rearmed for the next period.
+    /// If the timer was late by more than one period, missed periods are skipped.
+    ///
+    /// # Parameters
+    /// * `time` - Current time.
+    ///
+    /// # Return
+    /// `true` if the timer fired, `false` otherwise.
+    pub(crate) fn expire(&self, time: Instant) -> bool {
+        self.state.lock(|state| match *state {
+            Some(TimerState { deadline, period }) if deadline <= time => {
+                *state = period.map(|period| {
+                    let mut deadline = deadline + period;
+                    if deadline <= time {
+                        deadline = time + period;
+                    }
+                    TimerState {
+                        deadline,
+                        period: Some(period),
+                    }
+                });
+                true
+            }
+            _ => false,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn one_shot_and_periodic() {
+        fn action(_: &'static dyn crate::api::RuntimeApi) {}
+        let timer = Timer::new(TimerAction::Callback(action));
+
+        timer.start(Instant::from_ticks(100), None);
+        assert!(!timer.expire(Instant::from_ticks(99)));
+        assert!(timer.expire(Instant::from_ticks(100)));
+        assert!(!timer.is_running());
+        assert!(!timer.expire(Instant::from_ticks(200)));
+
+        timer.start(Instant::from_ticks(100), Some(Duration::from_ticks(50)));
+        assert!(timer.expire(Instant::from_ticks(110)));
+        assert!(!timer.expire(Instant::from_ticks(149)));
+        assert!(timer.expire(Instant::from_ticks(150)));
+        assert!(timer.expire(Instant::from_ticks(420)));
+        assert!(!timer.expire(Instant::from_ticks(469)));
+        assert!(timer.expire(Instant::from_ticks(470)));
+
+        assert!(timer.stop());
+        assert!(!timer.stop());
+    }
+}
@@ -0,0 +1,14 @@
+//! Action performed by the timer.
+
+use crate::api::RuntimeApi;
+use crate::event::EventId;
+
+/// Action performed when the timer fires.
+#[derive(Copy, Clone)]
+pub enum TimerAction {
+    /// Calls the function. Function is called from the system loop, between tasklet steps, so it
+    /// should be kept short.
+    Callback(fn(&'static dyn RuntimeApi)),
+    /// Emits the event.
+    Event(EventId),
+}
@@ -0,0 +1,63 @@
+//! Handle to a timer.
+//!
+//! This module contains timer handle implementation, which is used to reference a timer in the
+//! system.
+
+use aerugo_hal::AerugoHal;
+
+use crate::hal::Hal;
+use crate::time::Duration;
+use crate::timer::Timer;
+
+/// Timer handle.
+///
+/// Timer handle is available to the user of the system to reference and interact with the timer
+/// via exposed interface. Timers can be controlled from tasklets and from the IRQ context, also
+/// before the system is started. Timers use hardware time, so they are not affected by system time
+/// offset.
+#[derive(Copy, Clone)]
+pub struct TimerHandle {
+    /// Reference to the timer.
+    timer: &'static Timer,
+}
+
+impl TimerHandle {
+    /// Creates new timer handle.
+    ///
+    /// # Parameters
+    /// * `timer` - Reference to the timer.
+    pub(crate) fn new(timer: &'static Timer) -> Self {
+        TimerHandle { timer }
+    }
+
+    /// Starts the timer, which fires once after given delay. Running timer is restarted.
+    ///
+    /// # Parameters
+    /// * `delay` - Delay after which the timer fires.
+    pub fn start_once(&self, delay: Duration) {
+        self.timer.start(Hal::get_system_time() + delay, None)
+    }
+
+    /// Starts the timer, which fires periodically, first time after one period. Running timer is
+    /// restarted.
+    ///
+    /// # Parameters
+    /// * `period` - Period of the timer.
+    pub fn start_periodic(&self, period: Duration) {
+        self.timer
+            .start(Hal::get_system_time() + period, Some(period))
+    }
+
+    /// Stops the timer.
+    ///
+    /// # Return
+    /// `true` if the timer was running, `false` otherwise.
+    pub fn stop(&self) -> bool {
+        self.timer.stop()
+    }
+
+    /// Returns `true` if the timer is running.
+    pub fn is_running(&self) -> bool {
+        self.timer.is_running()
+    }
+}
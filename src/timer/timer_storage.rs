@@ -0,0 +1,73 @@
+//! Static storage for [timer](crate::timer::Timer).
+//!
+//! This module contains a timer storage, which is a statically allocated memory that will store
+//! timer structure for the duration of the system life.
+
+use core::cell::OnceCell;
+
+use crate::error::SystemError;
+use crate::timer::{Timer, TimerAction, TimerHandle};
+
+/// Structure containing memory for Timer creation.
+///
+/// As this system cannot use dynamic memory allocation, all structures have to be allocated
+/// statically. Per good practices user is separated from the actual implementation and instead
+/// only has to provide a static memory (via this structure) where the Timer will be allocated.
+pub struct TimerStorage {
+    /// Stored timer.
+    timer: OnceCell<Timer>,
+}
+
+/// It is safe assuming that stored Timer is not available from the IRQ context before it is
+/// created and that initialization cannot be interrupted.
+///
+/// TimerStorage is initialized only in [create_timer](crate::api::InitApi::create_timer)
+/// implemented in [Aerugo](crate::aerugo::Aerugo) which is not accessible from the IRQ context.
+///
+/// It's not possible to access the stored Timer with mutable reference, and its state is guarded
+/// with a [Mutex](crate::Mutex). Interrupt can control the timer using [`TimerHandle`].
+unsafe impl Sync for TimerStorage {}
+
+impl TimerStorage {
+    /// Creates new storage.
+    pub const fn new() -> Self {
+        TimerStorage {
+            timer: OnceCell::new(),
+        }
+    }
+
+    /// Returns initialization status of this storage.
+    pub fn is_initialized(&'static self) -> bool {
+        self.timer.get().is_some()
+    }
+
+    /// Creates new handle to a timer allocated in this storage.
+    ///
+    /// # Return
+    /// `Some(handle)` if this storage has been initialized. `None` otherwise.
+    pub fn create_handle(&'static self) -> Option<TimerHandle> {
+        self.timer.get().map(TimerHandle::new)
+    }
+
+    /// Initializes this storage.
+    ///
+    /// # Parameters
+    /// * `action` - Action performed when the timer fires.
+    ///
+    /// # Return
+    /// Reference to the stored timer if successful, `SystemError` otherwise.
+    ///
+    /// # Safety
+    /// This is unsafe, because it mutably borrows the stored timer.
+    /// This is safe to call during system initialization (before scheduler is started).
+    /// Accessing storage from IRQ context during initialization is undefined behaviour.
+    pub(crate) unsafe fn init(
+        &'static self,
+        action: TimerAction,
+    ) -> Result<&'static Timer, SystemError> {
+        match self.timer.set(Timer::new(action)) {
+            Ok(_) => Ok(self.timer.get().unwrap()),
+            Err(_) => Err(SystemError::StorageAlreadyInitialized),
+        }
+    }
+}
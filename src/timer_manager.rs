@@ -0,0 +1,107 @@
+//! Timer manager.
+//!
+//! This module contains timer manager. It's responsibility is to keep track of the timers created
+//! in the system, and perform actions of the timers that fire.
+
+use aerugo_hal::AerugoHal;
+use env_parser::read_env;
+
+use crate::api::RuntimeApi;
+use crate::error::SystemError;
+use crate::hal::Hal;
+use crate::internal_list::InternalList;
+use crate::time::Duration;
+use crate::timer::{Timer, TimerAction};
+
+/// List of timers registered in the system.
+type TimerList = InternalList<&'static Timer, { TimerManager::TIMER_COUNT }>;
+
+/// Timer manager.
+///
+/// This shouldn't be created by hand by the user or anywhere else in the code.
+/// It should be used as a singleton (crate::aerugo::TIMER_MANAGER) and shouldn't be directly
+/// accessed by any other part of the system.
+pub(crate) struct TimerManager {
+    /// Registered timers.
+    timers: TimerList,
+}
+
+/// It is safe assuming that it's modified only during system initialization (before scheduler is
+/// started) and those modifications cannot be interrupted.
+unsafe impl Sync for TimerManager {}
+
+impl TimerManager {
+    /// Number of timers in the system.
+    #[read_env("AERUGO_TIMER_COUNT")]
+    pub(crate) const TIMER_COUNT: usize = 0;
+
+    /// Creates new timer manager instance.
+    ///
+    /// # Safety
+    /// This shouldn't be called more than once.
+    pub(crate) const fn new() -> Self {
+        TimerManager {
+            timers: TimerList::new(),
+        }
+    }
+
+    /// Registers timer in the manager.
+    ///
+    /// # Parameters
+    /// * `timer` - Timer to register.
+    ///
+    /// # Return
+    /// `()` if successful, `SystemError` otherwise.
+    ///
+    /// # Safety
+    /// This is unsafe, because it mutably borrows the list of timers.
+    /// This is safe to call during system initialization (before scheduler is started).
+    pub(crate) unsafe fn register_timer(
+        &'static self,
+        timer: &'static Timer,
+    ) -> Result<(), SystemError> {
+        match self.timers.add(timer) {
+            Ok(_) => Ok(()),
+            Err(_) => Err(SystemError::TimerListFull),
+        }
+    }
+
+    /// Returns time until the nearest timer fires.
+    ///
+    /// # Return
+    /// Time until the nearest timer fires, zero if it's overdue, `None` if no timer is running.
+    pub(crate) fn time_to_next_expiry(&'static self) -> Option<Duration> {
+        let system_time = Hal::get_system_time();
+
+        self.timers
+            .iter()
+            .filter_map(|timer| timer.deadline())
+            .map(|deadline| {
+                deadline
+                    .checked_duration_since(system_time)
+                    .unwrap_or(Duration::from_ticks(0))
+            })
+            .min()
+    }
+
+    /// Performs actions of the timers that fire at the current time.
+    ///
+    /// # Parameters
+    /// * `api` - System runtime API, passed to the timer callbacks.
+    pub(crate) fn fire_timers(&'static self, api: &'static dyn RuntimeApi) {
+        let system_time = Hal::get_system_time();
+
+        for timer in &self.timers {
+            if !timer.expire(system_time) {
+                continue;
+            }
+
+            match timer.action() {
+                TimerAction::Callback(callback) => callback(api),
+                TimerAction::Event(event_id) => api
+                    .emit_event(event_id)
+                    .expect("Failed to emit timer event"),
+            }
+        }
+    }
+}
@@ -0,0 +1,116 @@
+use core::ffi::{c_char, c_void};
+
+use aerugo::logln;
+use aerugo_capi::{
+    aerugo_capi_version, aerugo_create_tasklet, aerugo_emit_event, aerugo_get_system_time_us,
+    aerugo_initialize, aerugo_start, aerugo_subscribe_tasklet_to_cyclic, AerugoRuntime,
+    AerugoTasklet,
+};
+
+const TASKLET_NAME: &[u8] = b"CTasklet\0";
+
+static mut STEP_COUNT: u32 = 0;
+
+extern "C" fn step(context: *mut c_void, runtime: *const AerugoRuntime) {
+    // SAFETY: Context is a pointer to the step counter, accessed only by this tasklet.
+    let step_count = unsafe { &mut *(context as *mut u32) };
+    *step_count += 1;
+
+    // SAFETY: Runtime is a pointer passed to this step function.
+    unsafe {
+        logln!("Step: {}", step_count);
+        logln!("Emit event: {:?}", aerugo_emit_event(runtime, 42));
+        logln!(
+            "Emit event without runtime: {:?}",
+            aerugo_emit_event(core::ptr::null(), 42)
+        );
+        logln!(
+            "Time without runtime: {}",
+            aerugo_get_system_time_us(core::ptr::null())
+        );
+    }
+
+    if *step_count == 2 {
+        std::process::exit(0);
+    }
+}
+
+fn main() {
+    let version = aerugo_capi_version();
+    logln!("Version: {}.{}", version.major, version.minor);
+
+    let mut tasklet: *const AerugoTasklet = core::ptr::null();
+    // SAFETY: This is safe, because all pointers are valid and system is not started.
+    unsafe {
+        logln!(
+            "Create before initialization: {:?}",
+            aerugo_create_tasklet(
+                TASKLET_NAME.as_ptr() as *const c_char,
+                1,
+                Some(step),
+                core::ptr::addr_of_mut!(STEP_COUNT) as *mut c_void,
+                &mut tasklet,
+            )
+        );
+        logln!(
+            "Subscribe null tasklet: {:?}",
+            aerugo_subscribe_tasklet_to_cyclic(core::ptr::null(), 0, 0)
+        );
+    }
+    logln!("Start before initialization: {:?}", aerugo_start());
+
+    logln!("Initialize: {:?}", aerugo_initialize());
+    logln!("Initialize again: {:?}", aerugo_initialize());
+
+    // SAFETY: This is safe, because all non-null pointers are valid and system is not started.
+    unsafe {
+        logln!(
+            "Create without name: {:?}",
+            aerugo_create_tasklet(
+                core::ptr::null(),
+                1,
+                Some(step),
+                core::ptr::null_mut(),
+                &mut tasklet,
+            )
+        );
+        logln!(
+            "Create without step function: {:?}",
+            aerugo_create_tasklet(
+                TASKLET_NAME.as_ptr() as *const c_char,
+                1,
+                None,
+                core::ptr::null_mut(),
+                &mut tasklet,
+            )
+        );
+        logln!(
+            "Create: {:?}",
+            aerugo_create_tasklet(
+                TASKLET_NAME.as_ptr() as *const c_char,
+                1,
+                Some(step),
+                core::ptr::addr_of_mut!(STEP_COUNT) as *mut c_void,
+                &mut tasklet,
+            )
+        );
+
+        let mut second_tasklet: *const AerugoTasklet = core::ptr::null();
+        logln!(
+            "Create over limit: {:?}",
+            aerugo_create_tasklet(
+                TASKLET_NAME.as_ptr() as *const c_char,
+                1,
+                Some(step),
+                core::ptr::null_mut(),
+                &mut second_tasklet,
+            )
+        );
+        logln!(
+            "Subscribe: {:?}",
+            aerugo_subscribe_tasklet_to_cyclic(tasklet, 0, 0)
+        );
+    }
+
+    aerugo_start();
+}
@@ -97,6 +97,9 @@ fn main() -> ! {
     let setup_config = TaskletConfig {
         name: "Setup",
         priority: 1,
+        deadline: None,
+        yield_budget: None,
+        finalize: None,
     };
     let setup_context = SetupContext {
         setup_condition: setup_condition_handle,
@@ -106,24 +109,36 @@ fn main() -> ! {
     let task_a_config = TaskletConfig {
         name: "TaskA",
         priority: 0,
+        deadline: None,
+        yield_budget: None,
+        finalize: None,
     };
     aerugo.create_tasklet(task_a_config, task_a, &TASK_A_STORAGE);
 
     let task_b_config = TaskletConfig {
         name: "TaskB",
         priority: 0,
+        deadline: None,
+        yield_budget: None,
+        finalize: None,
     };
     aerugo.create_tasklet(task_b_config, task_b, &TASK_B_STORAGE);
 
     let task_c_config = TaskletConfig {
         name: "TaskC",
         priority: 0,
+        deadline: None,
+        yield_budget: None,
+        finalize: None,
     };
     aerugo.create_tasklet(task_c_config, task_c, &TASK_C_STORAGE);
 
     let done_config = TaskletConfig {
         name: "Done",
         priority: 0,
+        deadline: None,
+        yield_budget: None,
+        finalize: None,
     };
     aerugo.create_tasklet(done_config, done, &DONE_STORAGE);
 
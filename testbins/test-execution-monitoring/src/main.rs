@@ -79,18 +79,27 @@ fn main() -> ! {
     let task_a_config = TaskletConfig {
         name: "TaskA",
         priority: 1,
+        deadline: None,
+        yield_budget: None,
+        finalize: None,
     };
     let task_a_context = TaskAContext { cnt: 0 };
 
     let task_b_config = TaskletConfig {
         name: "TaskB",
         priority: 1,
+        deadline: None,
+        yield_budget: None,
+        finalize: None,
     };
     let task_b_context = TaskBContext { cnt: 0 };
 
     let monitor_config = TaskletConfig {
         name: "Monitor",
         priority: 0,
+        deadline: None,
+        yield_budget: None,
+        finalize: None,
     };
     let monitor_context = MonitorContext { cnt: 0 };
 
@@ -54,12 +54,18 @@ fn main() -> ! {
     let task_a_config = TaskletConfig {
         name: "TaskA",
         priority: 1,
+        deadline: None,
+        yield_budget: None,
+        finalize: None,
     };
     let task_a_context = TaskAContext { cnt: 0 };
 
     let task_b_config = TaskletConfig {
         name: "TaskB",
         priority: 1,
+        deadline: None,
+        yield_budget: None,
+        finalize: None,
     };
 
     aerugo.create_tasklet_with_context(task_a_config, task_a, task_a_context, &TASK_A_STORAGE);
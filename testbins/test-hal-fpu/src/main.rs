@@ -31,6 +31,7 @@ fn main() -> ! {
     calldwell::start_session();
     let (aerugo, mut peripherals) = Aerugo::initialize(SystemHardwareConfig {
         watchdog_timeout: WatchdogDuration::secs(16),
+        ..Default::default()
     });
 
     let mut scb = peripherals.scb.take().unwrap();
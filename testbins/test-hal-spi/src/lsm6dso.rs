@@ -27,6 +27,7 @@ use aerugo::hal::{
     interrupt,
     user_peripherals::SPI0,
 };
+use aerugo::IrqCell;
 use calldwell::write_str;
 
 /// LSM6DSO chip select signal.
@@ -40,8 +41,8 @@ const LSM6DSO_BUFFER_SIZE: usize = 32;
 #[allow(non_camel_case_types)]
 type LSM6DSO_SPI = SPI0;
 
-static mut XDMAC_STATUS_READER: Option<XdmacStatusReader> = None;
-static mut XDMAC_CHANNEL_STATUS_READER: Option<ChannelStatusReader> = None;
+static XDMAC_STATUS_READER: IrqCell<XdmacStatusReader> = IrqCell::new();
+static XDMAC_CHANNEL_STATUS_READER: IrqCell<ChannelStatusReader> = IrqCell::new();
 static SPI_TRANSACTION_FINISHED: AtomicBool = AtomicBool::new(false);
 
 pub fn perform_test(spi: Spi<LSM6DSO_SPI, NotConfigured>, xdmac: &mut Xdmac, nvic: &mut NVIC) {
@@ -67,10 +68,16 @@ pub fn perform_test(spi: Spi<LSM6DSO_SPI, NotConfigured>, xdmac: &mut Xdmac, nvi
     let mut command_buffer: [u8; LSM6DSO_BUFFER_SIZE] = [0; LSM6DSO_BUFFER_SIZE];
     let mut response_buffer: [u8; LSM6DSO_BUFFER_SIZE] = [0; LSM6DSO_BUFFER_SIZE];
 
-    unsafe { XDMAC_STATUS_READER = xdmac.take_status_reader() };
+    XDMAC_STATUS_READER
+        .init(xdmac.take_status_reader().unwrap())
+        .ok()
+        .unwrap();
 
     let mut rx_channel = xdmac.take_next_free_channel().unwrap();
-    unsafe { XDMAC_CHANNEL_STATUS_READER = rx_channel.take_status_reader() };
+    XDMAC_CHANNEL_STATUS_READER
+        .init(rx_channel.take_status_reader().unwrap())
+        .ok()
+        .unwrap();
     rx_channel.set_events_state(ChannelEvents {
         end_of_block: false,
         end_of_list: true,
@@ -136,8 +143,8 @@ fn wait_for_spi_transaction() {
 
 #[interrupt]
 fn XDMAC() {
-    let status_reader = unsafe { XDMAC_STATUS_READER.as_mut().unwrap() };
-    let channel_status_reader = unsafe { XDMAC_CHANNEL_STATUS_READER.as_mut().unwrap() };
+    let mut status_reader = XDMAC_STATUS_READER.borrow_mut().unwrap();
+    let channel_status_reader = XDMAC_CHANNEL_STATUS_READER.borrow_mut().unwrap();
 
     if status_reader.get_pending_channels()[channel_status_reader.id()] {
         let status = channel_status_reader.get_pending_events();
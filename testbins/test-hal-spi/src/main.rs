@@ -45,6 +45,7 @@ fn main() -> ! {
     calldwell::start_session();
     let (aerugo, mut peripherals) = Aerugo::initialize(SystemHardwareConfig {
         watchdog_timeout: WatchdogDuration::secs(16),
+        ..Default::default()
     });
 
     let mut pmc = peripherals.pmc.take().unwrap();
@@ -91,6 +91,7 @@ fn main() -> ! {
 
     let (aerugo, _) = Aerugo::initialize(SystemHardwareConfig {
         watchdog_timeout: Milliseconds::secs(5),
+        ..Default::default()
     });
 
     initialize_tasks(aerugo);
@@ -24,7 +24,7 @@ use aerugo::{
         user_peripherals::UART0,
     },
     time::RateExtU32,
-    Mutex,
+    IrqCell, Mutex,
 };
 use calldwell::write_str;
 use heapless::Vec;
@@ -32,16 +32,12 @@ use heapless::Vec;
 /// This storage is used for passing XDMAC's status reader to IRQ.
 /// It must be initialized before starting an IRQ-synchronized XDMAC transaction, otherwise the
 /// program may panic.
-/// This storage can be safely accessed outside of XDMAC IRQ only when no XDMAC transactions are in
-/// progress
-static mut STATUS_READER_STORAGE: Option<StatusReader> = None;
+static STATUS_READER_STORAGE: IrqCell<StatusReader> = IrqCell::new();
 
 /// This storage is used for passing XDMAC's channel status reader to IRQ.
 /// It must be initialized before starting an IRQ-synchronized XDMAC transaction, otherwise the
 /// program may panic.
-/// This storage can be safely accessed outside of XDMAC IRQ only when no XDMAC transactions are in
-/// progress.
-static mut CHANNEL_STATUS_READER_STORAGE: Option<ChannelStatusReader> = None;
+static CHANNEL_STATUS_READER_STORAGE: IrqCell<ChannelStatusReader> = IrqCell::new();
 
 /// This field is used by IRQ to indicate that a new channel event has happened and new ChannelEvents
 /// instance has been placed in [`LAST_CHANNEL_EVENTS`].
@@ -54,8 +50,10 @@ pub fn perform_test(mut xdmac: Xdmac, mut nvic: NVIC, uart: Uart<UART0, NotConfi
     write_str("Performing transfer tests...");
 
     // Place XDMAC status reader in IRQ storage.
-    // This is safe, because XDMAC IRQ should be disabled.
-    unsafe { STATUS_READER_STORAGE.replace(xdmac.take_status_reader().unwrap()) };
+    STATUS_READER_STORAGE
+        .init(xdmac.take_status_reader().unwrap())
+        .ok()
+        .unwrap();
 
     test_mem2mem_32bit_transfer_polling(&mut xdmac);
     test_mem2mem_16bit_transfer_polling(&mut xdmac);
@@ -65,8 +63,7 @@ pub fn perform_test(mut xdmac: Xdmac, mut nvic: NVIC, uart: Uart<UART0, NotConfi
     let _ = test_channel_suspend_and_disable(&mut xdmac, &mut nvic, uart);
 
     // Take XDMAC status reader from IRQ storage.
-    // This is safe, because XDMAC IRQ should be disabled.
-    unsafe { xdmac.return_status_reader(STATUS_READER_STORAGE.take().unwrap()) };
+    xdmac.return_status_reader(STATUS_READER_STORAGE.take().unwrap());
 
     write_str("Transfer tests finished successfully!");
 }
@@ -245,7 +242,10 @@ fn test_mem2mem_irq_transfer(xdmac: &mut Xdmac, nvic: &mut NVIC) {
     channel.enable_interrupt();
 
     let mut channel = channel.configure_transfer(transfer);
-    unsafe { CHANNEL_STATUS_READER_STORAGE.replace(channel.take_status_reader().unwrap()) };
+    CHANNEL_STATUS_READER_STORAGE
+        .init(channel.take_status_reader().unwrap())
+        .ok()
+        .unwrap();
 
     // No more touching the global XDMAC IRQ-related state, except what's guarded by atomics,
     // beyond this point.
@@ -266,7 +266,7 @@ fn test_mem2mem_irq_transfer(xdmac: &mut Xdmac, nvic: &mut NVIC) {
 
     assert_eq!(destination, source);
 
-    channel.return_status_reader(unsafe { CHANNEL_STATUS_READER_STORAGE.take().unwrap() });
+    channel.return_status_reader(CHANNEL_STATUS_READER_STORAGE.take().unwrap());
     // reset_state disables events and channel interrupts.
     xdmac.return_channel(channel.reset_state().unwrap());
 
@@ -388,7 +388,10 @@ fn test_per2mem_and_mem2per_transfers(
     let mut rx_channel = rx_channel.configure_transfer(rx_transfer);
     let mut tx_channel = tx_channel.configure_transfer(tx_transfer);
 
-    unsafe { CHANNEL_STATUS_READER_STORAGE.replace(rx_channel.take_status_reader().unwrap()) };
+    CHANNEL_STATUS_READER_STORAGE
+        .init(rx_channel.take_status_reader().unwrap())
+        .ok()
+        .unwrap();
 
     // No more touching the global XDMAC IRQ-related state, except what's guarded by atomics,
     // beyond this point.
@@ -414,7 +417,7 @@ fn test_per2mem_and_mem2per_transfers(
     nvic.disable(Interrupt::XDMAC);
     // Global state related to XDMAC IRQ can be touched again.
 
-    rx_channel.return_status_reader(unsafe { CHANNEL_STATUS_READER_STORAGE.take().unwrap() });
+    rx_channel.return_status_reader(CHANNEL_STATUS_READER_STORAGE.take().unwrap());
     // reset_state disables events and channel interrupts.
     xdmac.return_channel(rx_channel.reset_state().unwrap());
     xdmac.return_channel(tx_channel.reset_state().unwrap());
@@ -512,7 +515,10 @@ fn test_channel_suspend_and_disable(
     });
     rx_channel.enable_interrupt();
     let mut rx_channel = rx_channel.configure_transfer(rx_transfer);
-    unsafe { CHANNEL_STATUS_READER_STORAGE.replace(rx_channel.take_status_reader().unwrap()) };
+    CHANNEL_STATUS_READER_STORAGE
+        .init(rx_channel.take_status_reader().unwrap())
+        .ok()
+        .unwrap();
 
     // No more touching the global XDMAC IRQ-related state, except what's guarded by atomics,
     // beyond this point.
@@ -594,7 +600,7 @@ fn test_channel_suspend_and_disable(
     nvic.disable(Interrupt::XDMAC);
     // Global state related to XDMAC IRQ can be touched again.
 
-    rx_channel.return_status_reader(unsafe { CHANNEL_STATUS_READER_STORAGE.take().unwrap() });
+    rx_channel.return_status_reader(CHANNEL_STATUS_READER_STORAGE.take().unwrap());
     xdmac.return_channel(rx_channel.reset_state().unwrap());
 
     write_str("Channel management operations (suspend/flush/disable) test successful!");
@@ -605,14 +611,12 @@ fn test_channel_suspend_and_disable(
 
 #[interrupt]
 fn XDMAC() {
-    let channel_status_reader = unsafe { CHANNEL_STATUS_READER_STORAGE.as_mut().unwrap() };
+    let channel_status_reader = CHANNEL_STATUS_READER_STORAGE.borrow_mut().unwrap();
 
-    let status = unsafe {
-        STATUS_READER_STORAGE
-            .as_mut()
-            .unwrap()
-            .get_pending_channels()
-    };
+    let status = STATUS_READER_STORAGE
+        .borrow_mut()
+        .unwrap()
+        .get_pending_channels();
 
     if status[channel_status_reader.id()] {
         let events = channel_status_reader.get_pending_events();
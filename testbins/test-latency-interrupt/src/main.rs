@@ -0,0 +1,167 @@
+#![no_std]
+#![no_main]
+
+extern crate calldwell;
+
+use core::fmt::Write;
+
+use aerugo::hal::Hal;
+use aerugo::{
+    Aerugo, Duration, EventHandle, EventId, EventStorage, InitApi, Instant, MessageQueueHandle,
+    MessageQueueStorage, Mutex, RuntimeApi, SystemHardwareConfig, TaskletConfig, TaskletStorage,
+};
+use aerugo_hal::AerugoHal;
+use calldwell::with_rtt_out;
+use cortex_m::peripheral::syst::SystClkSource;
+use cortex_m_rt::{entry, exception};
+use lazy_static::lazy_static;
+
+/// Number of latency samples for each measured path.
+const SAMPLE_COUNT: u32 = 20;
+/// Number of tasklets generating the load.
+const LOAD_TASKLETS: usize = 2;
+/// Duration of a single step of the load tasklet.
+const LOAD_STEP: Duration = Duration::micros(100);
+
+const LATENCY_EVENT: EventId = 1;
+
+/// Time at which the latency event was emitted.
+static EVENT_TIMESTAMP: Mutex<Option<Instant>> = Mutex::new(None);
+/// Number of the interrupt, even interrupts emit the event, odd ones send to the queue.
+static INTERRUPT_COUNT: Mutex<u32> = Mutex::new(0);
+
+#[derive(Default)]
+struct LatencyStats {
+    count: u32,
+    max_us: u64,
+    total_us: u64,
+}
+
+impl LatencyStats {
+    /// Adds latency sample, and prints statistics when all samples were collected.
+    fn add(&mut self, name: &str, sent: Instant) {
+        let latency = (Hal::get_system_time() - sent).to_micros();
+        self.count += 1;
+        self.max_us = self.max_us.max(latency);
+        self.total_us += latency;
+
+        if self.count == SAMPLE_COUNT {
+            with_rtt_out(|w, _| {
+                write!(
+                    w.writer(),
+                    "{} latency: max {} us, mean {} us",
+                    name,
+                    self.max_us,
+                    self.total_us / self.count as u64
+                )
+                .unwrap()
+            });
+        }
+    }
+}
+
+fn event_task(_: EventId, stats: &mut LatencyStats, _: &'static dyn RuntimeApi) {
+    if let Some(sent) = EVENT_TIMESTAMP.lock(|timestamp| timestamp.take()) {
+        stats.add("event", sent);
+    }
+}
+
+fn queue_task(sent: Instant, stats: &mut LatencyStats, _: &'static dyn RuntimeApi) {
+    stats.add("queue", sent);
+}
+
+fn load_task(_: (), _: &mut (), _: &'static dyn RuntimeApi) {
+    let end = Hal::get_system_time() + LOAD_STEP;
+    while Hal::get_system_time() < end {}
+}
+
+static EVENT_TASK_STORAGE: TaskletStorage<EventId, LatencyStats, 0> = TaskletStorage::new();
+static QUEUE_TASK_STORAGE: TaskletStorage<Instant, LatencyStats, 0> = TaskletStorage::new();
+static LOAD_TASK_STORAGES: [TaskletStorage<(), (), 0>; LOAD_TASKLETS] =
+    [TaskletStorage::new(), TaskletStorage::new()];
+
+static LATENCY_EVENT_STORAGE: EventStorage = EventStorage::new();
+static LATENCY_QUEUE: MessageQueueStorage<Instant, 4> = MessageQueueStorage::new();
+
+#[entry]
+fn main() -> ! {
+    calldwell::start_session();
+
+    let (aerugo, mut peripherals) = Aerugo::initialize(SystemHardwareConfig::default());
+
+    aerugo.create_event(LATENCY_EVENT, &LATENCY_EVENT_STORAGE);
+    aerugo.create_message_queue(&LATENCY_QUEUE);
+
+    let event_task_config = TaskletConfig {
+        name: "EventTask",
+        priority: 1,
+        ..Default::default()
+    };
+    aerugo.create_tasklet_with_context(
+        event_task_config,
+        event_task,
+        LatencyStats::default(),
+        &EVENT_TASK_STORAGE,
+    );
+
+    let queue_task_config = TaskletConfig {
+        name: "QueueTask",
+        priority: 1,
+        ..Default::default()
+    };
+    aerugo.create_tasklet_with_context(
+        queue_task_config,
+        queue_task,
+        LatencyStats::default(),
+        &QUEUE_TASK_STORAGE,
+    );
+
+    let event_task_handle = EVENT_TASK_STORAGE.create_handle().unwrap();
+    let queue_task_handle = QUEUE_TASK_STORAGE.create_handle().unwrap();
+    let queue_handle = LATENCY_QUEUE.create_handle().unwrap();
+
+    aerugo.subscribe_tasklet_to_events(&event_task_handle, [LATENCY_EVENT]);
+    aerugo.subscribe_tasklet_to_queue(&queue_task_handle, &queue_handle);
+
+    for storage in &LOAD_TASK_STORAGES {
+        let load_task_config = TaskletConfig {
+            name: "LoadTask",
+            ..Default::default()
+        };
+        aerugo.create_tasklet(load_task_config, load_task, storage);
+
+        let load_task_handle = storage.create_handle().unwrap();
+        aerugo.subscribe_tasklet_to_cyclic(&load_task_handle, None, None);
+    }
+
+    let mut systick = peripherals.systick.take().unwrap();
+    systick.set_clock_source(SystClkSource::Core);
+    systick.set_reload(0x3FFFFF);
+    systick.enable_interrupt();
+    systick.enable_counter();
+
+    aerugo.start();
+}
+
+#[exception]
+fn SysTick() {
+    lazy_static! {
+        static ref EVENT_HANDLE: EventHandle = LATENCY_EVENT_STORAGE.create_handle().unwrap();
+        static ref QUEUE_HANDLE: MessageQueueHandle<Instant, 4> =
+            LATENCY_QUEUE.create_handle().unwrap();
+    }
+
+    let interrupt = INTERRUPT_COUNT.lock(|count| {
+        *count += 1;
+        *count
+    });
+
+    if interrupt % 2 == 0 {
+        EVENT_TIMESTAMP.lock(|timestamp| *timestamp = Some(Hal::get_system_time()));
+        EVENT_HANDLE.emit();
+    } else {
+        QUEUE_HANDLE
+            .send_data(Hal::get_system_time())
+            .expect("Failed to send data to the queue");
+    }
+}
@@ -0,0 +1,165 @@
+use std::sync::atomic::{AtomicU8, Ordering};
+
+use aerugo::hal::Hal;
+use aerugo::{
+    logln, Aerugo, Duration, EventId, EventStorage, InitApi, Instant, MessageQueueStorage, Mutex,
+    RuntimeApi, SystemHardwareConfig, TaskletConfig, TaskletStorage,
+};
+use aerugo_hal::AerugoHal;
+
+/// Number of latency samples for each measured path.
+const SAMPLE_COUNT: u32 = 100;
+/// Period of the simulated interrupts.
+const INTERRUPT_PERIOD: std::time::Duration = std::time::Duration::from_millis(2);
+/// Maximum number of tasklets generating the load.
+const MAX_LOAD_TASKLETS: usize = 4;
+
+const LATENCY_EVENT: EventId = 1;
+
+/// Time at which the latency event was emitted.
+static EVENT_TIMESTAMP: Mutex<Option<Instant>> = Mutex::new(None);
+/// Number of measured paths that collected all samples.
+static FINISHED_COUNT: AtomicU8 = AtomicU8::new(0);
+
+#[derive(Default)]
+struct LatencyStats {
+    count: u32,
+    max_us: u64,
+    total_us: u64,
+}
+
+impl LatencyStats {
+    /// Adds latency sample, and prints statistics when all samples were collected.
+    fn add(&mut self, name: &str, sent: Instant) {
+        let latency = (Hal::get_system_time() - sent).to_micros();
+        self.count += 1;
+        self.max_us = self.max_us.max(latency);
+        self.total_us += latency;
+
+        if self.count == SAMPLE_COUNT {
+            logln!(
+                "{} latency: max {} us, mean {} us",
+                name,
+                self.max_us,
+                self.total_us / self.count as u64
+            );
+
+            if FINISHED_COUNT.fetch_add(1, Ordering::SeqCst) == 1 {
+                std::process::exit(0);
+            }
+        }
+    }
+}
+
+fn event_task(_: EventId, stats: &mut LatencyStats, _: &'static dyn RuntimeApi) {
+    if let Some(sent) = EVENT_TIMESTAMP.lock(|timestamp| timestamp.take()) {
+        stats.add("event", sent);
+    }
+}
+
+fn queue_task(sent: Instant, stats: &mut LatencyStats, _: &'static dyn RuntimeApi) {
+    stats.add("queue", sent);
+}
+
+fn load_task(_: (), step: &mut Duration, _: &'static dyn RuntimeApi) {
+    let end = Hal::get_system_time() + *step;
+    while Hal::get_system_time() < end {}
+}
+
+static EVENT_TASK_STORAGE: TaskletStorage<EventId, LatencyStats, 0> = TaskletStorage::new();
+static QUEUE_TASK_STORAGE: TaskletStorage<Instant, LatencyStats, 0> = TaskletStorage::new();
+static LOAD_TASK_STORAGES: [TaskletStorage<(), Duration, 0>; MAX_LOAD_TASKLETS] = [
+    TaskletStorage::new(),
+    TaskletStorage::new(),
+    TaskletStorage::new(),
+    TaskletStorage::new(),
+];
+
+static LATENCY_EVENT_STORAGE: EventStorage = EventStorage::new();
+static LATENCY_QUEUE: MessageQueueStorage<Instant, 4> = MessageQueueStorage::new();
+
+/// Reads numeric value of the environmental variable, or returns the default one.
+fn read_env(name: &str, default: u64) -> u64 {
+    std::env::var(name)
+        .ok()
+        .and_then(|value| value.parse().ok())
+        .unwrap_or(default)
+}
+
+/// Periodically simulates interrupts, which alternately emit the event and send to the queue.
+fn simulate_interrupts() {
+    let event_handle = LATENCY_EVENT_STORAGE.create_handle().unwrap();
+    let queue_handle = LATENCY_QUEUE.create_handle().unwrap();
+
+    loop {
+        std::thread::sleep(INTERRUPT_PERIOD);
+        Hal::deliver_interrupt(|| {
+            EVENT_TIMESTAMP.lock(|timestamp| *timestamp = Some(Hal::get_system_time()));
+            event_handle.emit();
+        });
+
+        std::thread::sleep(INTERRUPT_PERIOD);
+        Hal::deliver_interrupt(|| {
+            queue_handle
+                .send_data(Hal::get_system_time())
+                .expect("Failed to send data to the queue");
+        });
+    }
+}
+
+fn main() -> ! {
+    let load_tasklets = read_env("AERUGO_LATENCY_LOAD_TASKLETS", 0) as usize;
+    let load_step = Duration::micros(read_env("AERUGO_LATENCY_LOAD_STEP_US", 100));
+    assert!(load_tasklets <= MAX_LOAD_TASKLETS, "Too many load tasklets");
+
+    let (aerugo, _) = Aerugo::initialize(SystemHardwareConfig::default());
+
+    aerugo.create_event(LATENCY_EVENT, &LATENCY_EVENT_STORAGE);
+    aerugo.create_message_queue(&LATENCY_QUEUE);
+
+    let event_task_config = TaskletConfig {
+        name: "EventTask",
+        priority: 1,
+        ..Default::default()
+    };
+    aerugo.create_tasklet_with_context(
+        event_task_config,
+        event_task,
+        LatencyStats::default(),
+        &EVENT_TASK_STORAGE,
+    );
+
+    let queue_task_config = TaskletConfig {
+        name: "QueueTask",
+        priority: 1,
+        ..Default::default()
+    };
+    aerugo.create_tasklet_with_context(
+        queue_task_config,
+        queue_task,
+        LatencyStats::default(),
+        &QUEUE_TASK_STORAGE,
+    );
+
+    let event_task_handle = EVENT_TASK_STORAGE.create_handle().unwrap();
+    let queue_task_handle = QUEUE_TASK_STORAGE.create_handle().unwrap();
+    let queue_handle = LATENCY_QUEUE.create_handle().unwrap();
+
+    aerugo.subscribe_tasklet_to_events(&event_task_handle, [LATENCY_EVENT]);
+    aerugo.subscribe_tasklet_to_queue(&queue_task_handle, &queue_handle);
+
+    for storage in LOAD_TASK_STORAGES.iter().take(load_tasklets) {
+        let load_task_config = TaskletConfig {
+            name: "LoadTask",
+            ..Default::default()
+        };
+        aerugo.create_tasklet_with_context(load_task_config, load_task, load_step, storage);
+
+        let load_task_handle = storage.create_handle().unwrap();
+        aerugo.subscribe_tasklet_to_cyclic(&load_task_handle, None, None);
+    }
+
+    std::thread::spawn(simulate_interrupts);
+
+    aerugo.start();
+}
@@ -0,0 +1,105 @@
+use aerugo::{
+    logln, Aerugo, BooleanConditionStorage, EventId, EventStorage, InitApi, MessageQueueStorage,
+    RuntimeApi, StimulusScript, StimulusStatus, SystemHardwareConfig, TaskletConfig,
+    TaskletStorage,
+};
+
+const EVENT_START: EventId = 1;
+const EVENT_STOP: EventId = 2;
+
+fn task_a(data: u32, _: &mut (), _: &'static dyn RuntimeApi) {
+    logln!("TaskA: {}", data);
+}
+
+fn task_b(event: EventId, _: &mut (), _: &'static dyn RuntimeApi) {
+    match event {
+        EVENT_START => logln!("TaskB: start"),
+        EVENT_STOP => logln!("TaskB: stop"),
+        _ => logln!("TaskB: unknown event {}", event),
+    }
+}
+
+fn task_c(value: bool, _: &mut (), _: &'static dyn RuntimeApi) {
+    logln!("TaskC: {}", value);
+}
+
+fn stimulus(_: (), _: &mut (), api: &'static dyn RuntimeApi) {
+    match SCRIPT.run(api.get_elapsed_time()) {
+        Ok(StimulusStatus::Running) => (),
+        Ok(StimulusStatus::Finished) => std::process::exit(0),
+        Err(error) => panic!("Stimulus script failed: {:?}", error),
+    }
+}
+
+static TASK_A_STORAGE: TaskletStorage<u32, (), 0> = TaskletStorage::new();
+static TASK_B_STORAGE: TaskletStorage<EventId, (), 0> = TaskletStorage::new();
+static TASK_C_STORAGE: TaskletStorage<bool, (), 0> = TaskletStorage::new();
+static STIMULUS_STORAGE: TaskletStorage<(), (), 0> = TaskletStorage::new();
+
+static QUEUE_X: MessageQueueStorage<u32, 10> = MessageQueueStorage::new();
+static EVENT_START_STORAGE: EventStorage = EventStorage::new();
+static EVENT_STOP_STORAGE: EventStorage = EventStorage::new();
+static ENABLE_CONDITION: BooleanConditionStorage = BooleanConditionStorage::new();
+
+static SCRIPT: StimulusScript<4> = StimulusScript::new();
+
+fn main() -> ! {
+    let script_path =
+        std::env::var("AERUGO_STIMULUS_SCRIPT").expect("AERUGO_STIMULUS_SCRIPT is not set");
+    let script = std::fs::read_to_string(script_path).expect("Unable to read stimulus script");
+    SCRIPT
+        .load(Box::leak(script.into_boxed_str()))
+        .expect("Unable to load stimulus script");
+
+    let (aerugo, _) = Aerugo::initialize(SystemHardwareConfig::default());
+
+    aerugo.create_message_queue(&QUEUE_X);
+    aerugo.create_event(EVENT_START, &EVENT_START_STORAGE);
+    aerugo.create_event(EVENT_STOP, &EVENT_STOP_STORAGE);
+    aerugo.create_boolean_condition(false, &ENABLE_CONDITION);
+
+    SCRIPT.register("QueueX", &QUEUE_X).unwrap();
+    SCRIPT.register("EventStart", &EVENT_START_STORAGE).unwrap();
+    SCRIPT.register("EventStop", &EVENT_STOP_STORAGE).unwrap();
+    SCRIPT.register("Enable", &ENABLE_CONDITION).unwrap();
+
+    let task_a_config = TaskletConfig {
+        name: "TaskA",
+        ..Default::default()
+    };
+    aerugo.create_tasklet(task_a_config, task_a, &TASK_A_STORAGE);
+
+    let task_b_config = TaskletConfig {
+        name: "TaskB",
+        ..Default::default()
+    };
+    aerugo.create_tasklet(task_b_config, task_b, &TASK_B_STORAGE);
+
+    let task_c_config = TaskletConfig {
+        name: "TaskC",
+        ..Default::default()
+    };
+    aerugo.create_tasklet(task_c_config, task_c, &TASK_C_STORAGE);
+
+    let stimulus_config = TaskletConfig {
+        name: "Stimulus",
+        priority: 1,
+        ..Default::default()
+    };
+    aerugo.create_tasklet(stimulus_config, stimulus, &STIMULUS_STORAGE);
+
+    let task_a_handle = TASK_A_STORAGE.create_handle().unwrap();
+    let task_b_handle = TASK_B_STORAGE.create_handle().unwrap();
+    let task_c_handle = TASK_C_STORAGE.create_handle().unwrap();
+    let stimulus_handle = STIMULUS_STORAGE.create_handle().unwrap();
+
+    let queue_x_handle = QUEUE_X.create_handle().unwrap();
+    let enable_condition_handle = ENABLE_CONDITION.create_handle().unwrap();
+
+    aerugo.subscribe_tasklet_to_queue(&task_a_handle, &queue_x_handle);
+    aerugo.subscribe_tasklet_to_events(&task_b_handle, [EVENT_START, EVENT_STOP]);
+    aerugo.subscribe_tasklet_to_condition(&task_c_handle, &enable_condition_handle);
+    aerugo.subscribe_tasklet_to_cyclic(&stimulus_handle, Some(aerugo::Duration::millis(1)), None);
+
+    aerugo.start();
+}
@@ -0,0 +1,85 @@
+use aerugo::{
+    logln, Aerugo, Duration, EventId, InitApi, MessageQueueStorage, RuntimeApi,
+    SystemHardwareConfig, TaskletConfig, TaskletStorage,
+};
+
+#[derive(Default)]
+struct ProducerContext {
+    cnt: u8,
+}
+
+fn producer(_: (), context: &mut ProducerContext, api: &'static dyn RuntimeApi) {
+    context.cnt += 1;
+
+    match context.cnt {
+        1 => DATA_QUEUE
+            .create_handle()
+            .unwrap()
+            .send_data(42)
+            .expect("Failed to send data"),
+        2 => api.emit_event(20).expect("Failed to emit EventB"),
+        3 => api.emit_event(10).expect("Failed to emit EventA"),
+        4 => {
+            ENABLED.create_handle().unwrap().set_value(false);
+            DATA_QUEUE
+                .create_handle()
+                .unwrap()
+                .send_data(43)
+                .expect("Failed to send data");
+        }
+        _ => std::process::exit(0),
+    }
+}
+
+fn consumer(value: u8, _: &mut (), _: &'static dyn RuntimeApi) {
+    logln!("Consumer: {}", value);
+}
+
+fn monitor(event: EventId, _: &mut (), _: &'static dyn RuntimeApi) {
+    logln!("Monitor: {}", event);
+}
+
+aerugo::system! {
+    fn init_system;
+
+    queues {
+        DATA_QUEUE: MessageQueueStorage<u8, 4>;
+    }
+
+    events {
+        EVENT_A: 10;
+        EVENT_B: 20;
+        EVENT_C: 30;
+    }
+
+    conditions {
+        ENABLED: true;
+    }
+
+    tasklets {
+        PRODUCER: TaskletStorage<(), ProducerContext, 0> {
+            config: TaskletConfig { name: "Producer", priority: 1, ..Default::default() },
+            step: producer,
+            subscribe: cyclic(Duration::millis(10)),
+        }
+        CONSUMER: TaskletStorage<u8, (), 1> {
+            config: TaskletConfig { name: "Consumer", priority: 2, ..Default::default() },
+            step: consumer,
+            subscribe: queue(DATA_QUEUE),
+            conditions: And [ENABLED],
+        }
+        MONITOR: TaskletStorage<EventId, (), 0> {
+            config: TaskletConfig { name: "Monitor", priority: 2, ..Default::default() },
+            step: monitor,
+            subscribe: events [EVENT_B, EVENT_A],
+        }
+    }
+}
+
+fn main() -> ! {
+    let (aerugo, _) = Aerugo::initialize(SystemHardwareConfig::default());
+
+    init_system(aerugo);
+
+    aerugo.start();
+}
@@ -70,6 +70,9 @@ fn main() -> ! {
     let task_a_config = TaskletConfig {
         name: "TaskA",
         priority: 0,
+        deadline: None,
+        yield_budget: None,
+        finalize: None,
     };
     let task_a_context = TaskAContext {
         cnt: 0,
@@ -81,6 +84,9 @@ fn main() -> ! {
     let task_b_config = TaskletConfig {
         name: "TaskB",
         priority: 1,
+        deadline: None,
+        yield_budget: None,
+        finalize: None,
     };
     let task_b_context = TaskBContext { cnt: 0 };
     aerugo.create_tasklet_with_context(task_b_config, task_b, task_b_context, &TASK_B_STORAGE);
@@ -88,6 +94,9 @@ fn main() -> ! {
     let task_c_config = TaskletConfig {
         name: "TaskC",
         priority: 0,
+        deadline: None,
+        yield_budget: None,
+        finalize: None,
     };
     let task_c_context = TaskCContext { cnt: 0 };
     aerugo.create_tasklet_with_context(task_c_config, task_c, task_c_context, &TASK_C_STORAGE);
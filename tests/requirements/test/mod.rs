@@ -1,6 +1,7 @@
 mod test_basic_execution;
 mod test_boolean_condition;
 mod test_boolean_condition_interrupt;
+mod test_capi;
 mod test_cyclic_execution;
 mod test_event;
 mod test_event_cancellation;
@@ -20,9 +21,13 @@ mod test_hal_timer;
 mod test_hal_uart;
 mod test_hal_watchdog;
 mod test_hal_xdmac;
+mod test_latency;
+mod test_latency_interrupt;
 mod test_message_queue;
 mod test_message_queue_clear;
 mod test_message_queue_interrupt;
+mod test_stimulus;
+mod test_system_definition;
 mod test_tasklet_not_subscribed;
 mod test_tasklet_priority;
 mod test_tasklet_priority_multiple_queues;
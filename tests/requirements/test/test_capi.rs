@@ -0,0 +1,38 @@
+use assert_cmd::Command;
+use test_binary::build_test_binary;
+
+/// @SRS{ROS-FUN-RTOS-010}
+/// @SRS{ROS-FUN-RTOS-110}
+#[cfg_attr(not(doc), test)]
+fn req_test_capi() {
+    let test_bin_path =
+        build_test_binary("test-capi", "testbins").expect("error building test binary");
+
+    Command::new(test_bin_path)
+        .timeout(std::time::Duration::from_secs(1))
+        .assert()
+        .success()
+        .code(0)
+        .stdout(
+            r"Version: 1.0
+Create before initialization: NotInitialized
+Subscribe null tasklet: InvalidParameter
+Start before initialization: NotInitialized
+Initialize: Ok
+Initialize again: AlreadyInitialized
+Create without name: InvalidParameter
+Create without step function: InvalidParameter
+Create: Ok
+Create over limit: StorageFull
+Subscribe: Ok
+Step: 1
+Emit event: EventNotFound
+Emit event without runtime: InvalidParameter
+Time without runtime: 0
+Step: 2
+Emit event: EventNotFound
+Emit event without runtime: InvalidParameter
+Time without runtime: 0
+",
+        );
+}
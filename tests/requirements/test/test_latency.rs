@@ -0,0 +1,85 @@
+use assert_cmd::Command;
+use test_binary::build_test_binary;
+
+/// Load of the system during latency measurement, with bounds of the measured latency.
+struct LatencyScenario {
+    /// Number of tasklets generating the load.
+    load_tasklets: u32,
+    /// Duration of a single step of the load tasklet, in microseconds.
+    load_step_us: u64,
+    /// Maximum allowed latency, in microseconds.
+    max_latency_us: u64,
+    /// Maximum allowed mean latency, in microseconds.
+    max_mean_latency_us: u64,
+}
+
+// Tests run on x86, where the process can be preempted by the operating system at any time, so
+// the bounds include a wide margin for the OS scheduling. Tight bounds are verified on the target
+// with `test-latency-interrupt`.
+const SCENARIOS: [LatencyScenario; 2] = [
+    LatencyScenario {
+        load_tasklets: 0,
+        load_step_us: 0,
+        max_latency_us: 2000,
+        max_mean_latency_us: 500,
+    },
+    LatencyScenario {
+        load_tasklets: 3,
+        load_step_us: 200,
+        max_latency_us: 2200,
+        max_mean_latency_us: 700,
+    },
+];
+
+/// Parses `<path> latency: max <max> us, mean <mean> us` line.
+fn parse_latency(line: &str) -> (&str, u64, u64) {
+    let (path, values) = line.split_once(" latency: max ").unwrap();
+    let (max, mean) = values
+        .strip_suffix(" us")
+        .and_then(|values| values.split_once(" us, mean "))
+        .unwrap();
+
+    (path, max.parse().unwrap(), mean.parse().unwrap())
+}
+
+/// @SRS{ROS-FUN-RTOS-2010}
+/// @SRS{ROS-FUN-RTOS-3020}
+#[cfg_attr(not(doc), test)]
+fn req_test_latency() {
+    let test_bin_path =
+        build_test_binary("test-latency", "testbins").expect("error building test binary");
+
+    for scenario in SCENARIOS {
+        let command = Command::new(&test_bin_path)
+            .env(
+                "AERUGO_LATENCY_LOAD_TASKLETS",
+                scenario.load_tasklets.to_string(),
+            )
+            .env(
+                "AERUGO_LATENCY_LOAD_STEP_US",
+                scenario.load_step_us.to_string(),
+            )
+            .timeout(std::time::Duration::from_secs(5))
+            .assert()
+            .success()
+            .code(0);
+
+        let output = String::from_utf8(command.get_output().stdout.clone()).unwrap();
+        let mut paths = Vec::new();
+
+        for (path, max, mean) in output.lines().map(parse_latency) {
+            assert!(
+                max <= scenario.max_latency_us && mean <= scenario.max_mean_latency_us,
+                "{} latency with {} load tasklets exceeds bounds: max {} us, mean {} us",
+                path,
+                scenario.load_tasklets,
+                max,
+                mean
+            );
+            paths.push(path.to_string());
+        }
+
+        paths.sort();
+        assert_eq!(paths, ["event", "queue"]);
+    }
+}
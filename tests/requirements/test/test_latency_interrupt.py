@@ -0,0 +1,57 @@
+"""Integration test for latency of tasklet execution after an interrupt"""
+
+
+from __future__ import annotations
+
+import logging
+import re
+import sys
+
+from test_utils import finish_test, init_test
+
+from calldwell import init_default_logger
+
+TEST_NAME = "test-latency-interrupt"
+
+# Bounds of the latency from emitting an event or sending to a queue in an interrupt to the start
+# of the subscribed tasklet. Test binary runs tasklets generating the load with 100 us steps, so
+# the maximum latency is the load step and the scheduler overhead.
+MAX_LATENCY_US = 250
+MAX_MEAN_LATENCY_US = 150
+
+LATENCY_MESSAGE = re.compile(r"^(event|queue) latency: max (\d+) us, mean (\d+) us$")
+
+
+def main() -> None:
+    """Main function of integration test."""
+    _, rtt, ssh = init_test(TEST_NAME)
+
+    measured_paths = set()
+    while measured_paths != {"event", "queue"}:
+        message = rtt.receive_string_stream()
+        logging.info(f"Received '{message}'")
+
+        match = LATENCY_MESSAGE.match(message)
+        if match is None:
+            logging.critical(f"TEST FAILED: UNEXPECTED MESSAGE RECEIVED ('{message}')")
+            finish_test(ssh)
+            sys.exit(2)
+
+        path, max_latency, mean_latency = match[1], int(match[2]), int(match[3])
+        if max_latency > MAX_LATENCY_US or mean_latency > MAX_MEAN_LATENCY_US:
+            logging.critical(
+                f"TEST FAILED: {path} latency exceeds bounds "
+                f"(max {max_latency} us, mean {mean_latency} us, allowed max {MAX_LATENCY_US} us, "
+                f"mean {MAX_MEAN_LATENCY_US} us)",
+            )
+            finish_test(ssh)
+            sys.exit(2)
+
+        measured_paths.add(path)
+
+    finish_test(ssh)
+
+
+if __name__ == "__main__":
+    init_default_logger()
+    main()
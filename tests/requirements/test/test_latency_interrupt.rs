@@ -0,0 +1,15 @@
+/// @SRS{ROS-FUN-RTOS-2010}
+/// @SRS{ROS-FUN-RTOS-3020}
+#[cfg_attr(not(doc), test)]
+#[cfg(feature = "test-aerugo-cortex-m")]
+fn req_test_latency_interrupt() {
+    use assert_cmd::Command;
+
+    // The script will build test binary
+    Command::new("python")
+        .arg("tests/requirements/test/test_latency_interrupt.py")
+        .timeout(std::time::Duration::from_secs(60))
+        .assert()
+        .success()
+        .code(0);
+}
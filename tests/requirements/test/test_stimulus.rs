@@ -0,0 +1,60 @@
+use std::path::Path;
+
+use assert_cmd::Command;
+use test_binary::build_test_binary;
+
+/// Returns texts expected in the standard output, given with `expect stdout contains "<text>"`
+/// statements of the stimulus script.
+fn expected_stdout(script: &str) -> Vec<&str> {
+    script
+        .lines()
+        .flat_map(|line| line.split(';'))
+        .filter_map(|statement| statement.trim().strip_prefix("expect stdout contains "))
+        .map(|text| text.trim_matches('"'))
+        .collect()
+}
+
+/// @SRS{ROS-FUN-RTOS-2010}
+/// @SRS{ROS-FUN-RTOS-3010}
+#[cfg_attr(not(doc), test)]
+fn req_test_stimulus() {
+    let test_bin_path =
+        build_test_binary("test-stimulus", "testbins").expect("error building test binary");
+
+    let scripts_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/requirements/stimulus");
+    let mut scripts = std::fs::read_dir(scripts_dir)
+        .expect("error reading stimulus scripts")
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| {
+            path.extension()
+                .is_some_and(|extension| extension == "stim")
+        })
+        .collect::<Vec<_>>();
+    scripts.sort();
+    assert!(!scripts.is_empty(), "no stimulus scripts found");
+
+    for script_path in scripts {
+        let script = std::fs::read_to_string(&script_path).unwrap();
+
+        let output = Command::new(&test_bin_path)
+            .env("AERUGO_STIMULUS_SCRIPT", &script_path)
+            .timeout(std::time::Duration::from_secs(1))
+            .assert()
+            .success()
+            .code(0)
+            .get_output()
+            .stdout
+            .clone();
+        let stdout = String::from_utf8(output).unwrap();
+
+        for expected in expected_stdout(&script) {
+            assert!(
+                stdout.contains(expected),
+                "{}: expected \"{}\" in stdout:\n{}",
+                script_path.display(),
+                expected,
+                stdout
+            );
+        }
+    }
+}
@@ -0,0 +1,23 @@
+use assert_cmd::Command;
+use test_binary::build_test_binary;
+
+/// @SRS{ROS-FUN-RTOS-010}
+/// @SRS{ROS-FUN-RTOS-2010}
+/// @SRS{ROS-FUN-RTOS-3010}
+#[cfg_attr(not(doc), test)]
+fn req_test_system_definition() {
+    let test_bin_path = build_test_binary("test-system-definition", "testbins")
+        .expect("error building test binary");
+
+    Command::new(test_bin_path)
+        .timeout(std::time::Duration::from_secs(1))
+        .assert()
+        .success()
+        .code(0)
+        .stdout(
+            r"Consumer: 42
+Monitor: 20
+Monitor: 10
+",
+        );
+}
@@ -0,0 +1,61 @@
+#[cfg(test)]
+mod tests {
+    use aerugo::{AerugoEvents, EventId};
+
+    #[derive(AerugoEvents)]
+    enum ExplicitEvent {
+        Start = 0x10,
+        Stop = 0x20,
+    }
+
+    #[derive(AerugoEvents)]
+    enum ImplicitEvent {
+        First = 100,
+        Second,
+        Third = 0x200,
+        Fourth,
+    }
+
+    #[derive(AerugoEvents)]
+    enum DefaultEvent {
+        Zero,
+        One,
+    }
+
+    #[test]
+    fn test_explicit_ids() {
+        assert_eq!(ExplicitEvent::Start.event_id(), 0x10);
+        assert_eq!(ExplicitEvent::Stop.event_id(), 0x20);
+        assert_eq!(ExplicitEvent::EVENT_IDS, [0x10, 0x20]);
+    }
+
+    #[test]
+    fn test_implicit_ids() {
+        assert_eq!(ImplicitEvent::EVENT_IDS, [100, 101, 0x200, 0x201]);
+        assert_eq!(DefaultEvent::EVENT_IDS, [0, 1]);
+    }
+
+    #[test]
+    fn test_into_event_id() {
+        let id: EventId = ImplicitEvent::Second.into();
+        assert_eq!(id, 101);
+    }
+
+    #[test]
+    fn test_separate_storages() {
+        assert!(!core::ptr::eq(
+            ExplicitEvent::Start.storage(),
+            ExplicitEvent::Stop.storage()
+        ));
+        assert!(core::ptr::eq(
+            ExplicitEvent::Start.storage(),
+            ExplicitEvent::Start.storage()
+        ));
+    }
+
+    #[test]
+    fn test_rejected_inputs() {
+        let cases = trybuild::TestCases::new();
+        cases.compile_fail("ui/*.rs");
+    }
+}
@@ -0,0 +1,10 @@
+use aerugo::AerugoEvents;
+
+#[derive(AerugoEvents)]
+enum Event {
+    Start = 0x10,
+    Stop = 0x20,
+    Restart = 0x10,
+}
+
+fn main() {}
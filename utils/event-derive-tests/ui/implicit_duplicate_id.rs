@@ -0,0 +1,10 @@
+use aerugo::AerugoEvents;
+
+#[derive(AerugoEvents)]
+enum Event {
+    Start = 0x10,
+    Stop,
+    Pause = 0x11,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use aerugo::AerugoEvents;
+
+const START_ID: isize = 0x10;
+
+#[derive(AerugoEvents)]
+enum Event {
+    Start = START_ID,
+}
+
+fn main() {}
@@ -0,0 +1,8 @@
+use aerugo::AerugoEvents;
+
+#[derive(AerugoEvents)]
+struct Event {
+    id: u32,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use aerugo::AerugoEvents;
+
+#[derive(AerugoEvents)]
+enum Event {
+    Start,
+    Data(u32),
+}
+
+fn main() {}
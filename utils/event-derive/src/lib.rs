@@ -0,0 +1,156 @@
+//! Derive macro linking enums with aerugo events.
+//!
+//! `#[derive(AerugoEvents)]` assigns an event ID to each variant of an enum, and generates event
+//! storages and handles for them. Event ID of the variant is its discriminant, so IDs are
+//! assigned the same way as enum discriminants: explicitly, or by incrementing the previous one.
+//!
+//! Each ID is additionally exported as a linker symbol, so the same ID used in two different enums
+//! is rejected at build time, instead of failing when events are created at runtime.
+//!
+//! ```ignore
+//! #[derive(AerugoEvents)]
+//! pub enum CommandEvent {
+//!     Start = 0x10,
+//!     Stop = 0x20,
+//! }
+//!
+//! CommandEvent::create_events(aerugo);
+//! api.emit_event(CommandEvent::Start.into());
+//! ```
+
+extern crate proc_macro;
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{format_ident, quote};
+use syn::{parse2, Data, DeriveInput, Error, Expr, ExprLit, Fields, Lit};
+
+#[proc_macro_derive(AerugoEvents)]
+pub fn derive_aerugo_events(item: TokenStream) -> TokenStream {
+    derive_aerugo_events_impl(item.into())
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+pub(crate) fn derive_aerugo_events_impl(item: TokenStream2) -> Result<TokenStream2, Error> {
+    let input: DeriveInput = parse2(item)?;
+    let name = &input.ident;
+
+    let data = match &input.data {
+        Data::Enum(data) => data,
+        _ => {
+            return Err(Error::new_spanned(
+                &input,
+                "AerugoEvents can only be derived for enums",
+            ))
+        }
+    };
+
+    let mut variants = Vec::new();
+    let mut ids = Vec::new();
+    let mut next_id: u32 = 0;
+
+    for variant in &data.variants {
+        if !matches!(variant.fields, Fields::Unit) {
+            return Err(Error::new_spanned(
+                variant,
+                "AerugoEvents supports only enums with unit variants",
+            ));
+        }
+
+        let id = match &variant.discriminant {
+            Some((_, expr)) => parse_event_id(expr)?,
+            None => next_id,
+        };
+
+        if ids.contains(&id) {
+            return Err(Error::new_spanned(
+                variant,
+                format!("event ID 0x{:08X} is already used", id),
+            ));
+        }
+
+        variants.push(&variant.ident);
+        ids.push(id);
+        next_id = id.wrapping_add(1);
+    }
+
+    let event_count = variants.len();
+    let indices = 0..event_count;
+    let storages = variants
+        .iter()
+        .map(|_| quote!(::aerugo::EventStorage::new()));
+    let id_symbols = ids.iter().map(|id| {
+        let symbol_name = format!("__aerugo_event_id_0x{:08X}", id);
+        let symbol_ident = format_ident!("__AERUGO_EVENT_ID_0X{:08X}", id);
+        quote! {
+            #[used]
+            #[doc(hidden)]
+            #[export_name = #symbol_name]
+            static #symbol_ident: u8 = 0;
+        }
+    });
+
+    Ok(quote! {
+        const _: () = {
+            #(#id_symbols)*
+        };
+
+        impl From<#name> for ::aerugo::EventId {
+            fn from(value: #name) -> Self {
+                value.event_id()
+            }
+        }
+
+        impl #name {
+            /// IDs of all events in this enum.
+            pub const EVENT_IDS: [::aerugo::EventId; #event_count] = [#(#ids),*];
+
+            /// Returns ID of this event.
+            pub const fn event_id(&self) -> ::aerugo::EventId {
+                match self {
+                    #(Self::#variants => #ids,)*
+                }
+            }
+
+            /// Returns storage of this event.
+            pub fn storage(&self) -> &'static ::aerugo::EventStorage {
+                static STORAGES: [::aerugo::EventStorage; #event_count] = [#(#storages),*];
+
+                match self {
+                    #(Self::#variants => &STORAGES[#indices],)*
+                }
+            }
+
+            /// Creates new handle to this event.
+            ///
+            /// # Return
+            /// `Some(handle)` if event was created, `None` otherwise.
+            pub fn create_handle(&self) -> Option<::aerugo::EventHandle> {
+                self.storage().create_handle()
+            }
+
+            /// Creates all events in this enum.
+            ///
+            /// # Parameters
+            /// * `aerugo` - System initialization API.
+            pub fn create_events(aerugo: &'static impl ::aerugo::InitApi) {
+                #(aerugo.create_event(#ids, Self::#variants.storage());)*
+            }
+        }
+    })
+}
+
+/// Parses event ID from the variant discriminant.
+fn parse_event_id(expr: &Expr) -> Result<u32, Error> {
+    match expr {
+        Expr::Lit(ExprLit {
+            lit: Lit::Int(literal),
+            ..
+        }) => literal.base10_parse::<u32>(),
+        _ => Err(Error::new_spanned(
+            expr,
+            "event ID has to be an integer literal",
+        )),
+    }
+}
@@ -0,0 +1,225 @@
+//! Polynomial approximations shared by fixed-point and floating-point implementations.
+//!
+//! Fixed-point implementations use Q30 format with `i64` intermediate values. Angles are
+//! represented as a fraction of the full turn in Q32 format (`u32`), so wrapping is free.
+
+/// Sine polynomial coefficients: `sin(pi/2 * z) = z * (A + z^2 * (B + z^2 * C))` for `z` in `[0, 1]`.
+pub(crate) const SIN_COEFFICIENTS: [f32; 3] = [1.570_336_8, -0.642_107_05, 0.071_770_27];
+
+/// Arctangent polynomial coefficients: `atan(r) = r * (A + r^2 * (B + r^2 * (C + r^2 * D)))`
+/// for `r` in `[0, 1]`.
+pub(crate) const ATAN_COEFFICIENTS: [f32; 4] =
+    [0.999_315_7, -0.322_277_05, 0.149_007_9, -0.040_846_016];
+
+/// One in Q30 format.
+pub(crate) const Q30_ONE: i64 = 1 << 30;
+
+/// Pi in Q30 format.
+pub(crate) const Q30_PI: i64 = 3_373_259_426;
+
+/// Inverse of 2 * pi in Q32 format.
+pub(crate) const Q32_INV_2PI: i64 = 683_565_276;
+
+/// Converts coefficient to Q30 format.
+const fn q30(value: f32) -> i64 {
+    (value * Q30_ONE as f32) as i64
+}
+
+/// Sine polynomial coefficients in Q30 format.
+const SIN_COEFFICIENTS_Q30: [i64; 3] = [
+    q30(SIN_COEFFICIENTS[0]),
+    q30(SIN_COEFFICIENTS[1]),
+    q30(SIN_COEFFICIENTS[2]),
+];
+
+/// Arctangent polynomial coefficients in Q30 format.
+const ATAN_COEFFICIENTS_Q30: [i64; 4] = [
+    q30(ATAN_COEFFICIENTS[0]),
+    q30(ATAN_COEFFICIENTS[1]),
+    q30(ATAN_COEFFICIENTS[2]),
+    q30(ATAN_COEFFICIENTS[3]),
+];
+
+/// Multiplies two Q30 values.
+#[inline(always)]
+fn mul_q30(a: i64, b: i64) -> i64 {
+    (a * b) >> 30
+}
+
+/// Evaluates odd polynomial `x * (c0 + x^2 * (c1 + ...))` in Q30 format.
+fn odd_polynomial_q30(x: i64, coefficients: &[i64]) -> i64 {
+    let x2 = mul_q30(x, x);
+    let sum = coefficients
+        .iter()
+        .rev()
+        .fold(0, |sum, coefficient| coefficient + mul_q30(sum, x2));
+
+    mul_q30(sum, x)
+}
+
+/// Calculates sine of the angle.
+///
+/// # Parameters
+/// * `turns` - Angle as a fraction of the full turn, in Q32 format.
+///
+/// # Return
+/// Sine in Q30 format.
+pub(crate) fn sin_q30(turns: u32) -> i64 {
+    let quadrant = turns >> 30;
+    let fraction = (turns & 0x3FFF_FFFF) as i64;
+
+    let z = if quadrant & 1 == 0 {
+        fraction
+    } else {
+        Q30_ONE - fraction
+    };
+    let sin = odd_polynomial_q30(z, &SIN_COEFFICIENTS_Q30);
+
+    if quadrant >= 2 {
+        -sin
+    } else {
+        sin
+    }
+}
+
+/// Calculates arctangent of `y / x`, in the correct quadrant.
+///
+/// # Parameters
+/// * `y` - Y coordinate, in any fixed-point format.
+/// * `x` - X coordinate, in the same format as `y`.
+///
+/// # Return
+/// Angle in radians, in `[-pi, pi]` range, in Q30 format.
+pub(crate) fn atan2_q30(y: i64, x: i64) -> i64 {
+    let (abs_y, abs_x) = (y.abs(), x.abs());
+    if abs_x == 0 && abs_y == 0 {
+        return 0;
+    }
+
+    let ratio = (abs_y.min(abs_x) << 30) / abs_y.max(abs_x);
+    let mut angle = odd_polynomial_q30(ratio, &ATAN_COEFFICIENTS_Q30);
+
+    if abs_y > abs_x {
+        angle = Q30_PI / 2 - angle;
+    }
+    if x < 0 {
+        angle = Q30_PI - angle;
+    }
+    if y < 0 {
+        angle = -angle;
+    }
+
+    angle
+}
+
+/// Evaluates odd polynomial `x * (c0 + x^2 * (c1 + ...))`.
+fn odd_polynomial_f32(x: f32, coefficients: &[f32]) -> f32 {
+    let x2 = x * x;
+    let sum = coefficients
+        .iter()
+        .rev()
+        .fold(0.0, |sum, coefficient| coefficient + sum * x2);
+
+    sum * x
+}
+
+/// Calculates sine of the angle.
+///
+/// # Parameters
+/// * `turns` - Angle as a fraction of the full turn.
+pub(crate) fn sin_f32(turns: f32) -> f32 {
+    // `as` conversion truncates towards zero, so negative angles have to be wrapped up.
+    let mut fraction = turns - (turns as i32) as f32;
+    if fraction < 0.0 {
+        fraction += 1.0;
+    }
+
+    let quarters = fraction * 4.0;
+    let quadrant = quarters as u32;
+    let mut z = quarters - quadrant as f32;
+    if quadrant & 1 == 1 {
+        z = 1.0 - z;
+    }
+
+    let sin = odd_polynomial_f32(z, &SIN_COEFFICIENTS);
+    if quadrant >= 2 {
+        -sin
+    } else {
+        sin
+    }
+}
+
+/// Calculates arctangent of `y / x`, in the correct quadrant.
+///
+/// # Return
+/// Angle in radians, in `[-pi, pi]` range.
+pub(crate) fn atan2_f32(y: f32, x: f32) -> f32 {
+    let (abs_y, abs_x) = (abs_f32(y), abs_f32(x));
+    if abs_x == 0.0 && abs_y == 0.0 {
+        return 0.0;
+    }
+
+    let ratio = abs_y.min(abs_x) / abs_y.max(abs_x);
+    let mut angle = odd_polynomial_f32(ratio, &ATAN_COEFFICIENTS);
+
+    if abs_y > abs_x {
+        angle = core::f32::consts::FRAC_PI_2 - angle;
+    }
+    if x < 0.0 {
+        angle = core::f32::consts::PI - angle;
+    }
+    if y < 0.0 {
+        angle = -angle;
+    }
+
+    angle
+}
+
+/// Returns absolute value.
+#[inline(always)]
+fn abs_f32(value: f32) -> f32 {
+    if value < 0.0 {
+        -value
+    } else {
+        value
+    }
+}
+
+/// Calculates square root of the value.
+///
+/// Uses bit-level initial estimate and three Newton iterations, giving full `f32` precision.
+pub(crate) fn sqrt_f32(value: f32) -> f32 {
+    if value <= 0.0 {
+        return if value == 0.0 { 0.0 } else { f32::NAN };
+    }
+
+    let mut root = f32::from_bits((value.to_bits() >> 1) + 0x1FBD_1DF5);
+    for _ in 0..3 {
+        root = 0.5 * (root + value / root);
+    }
+
+    root
+}
+
+/// Calculates integer square root.
+pub(crate) fn isqrt(value: u64) -> u64 {
+    let mut remainder = value;
+    let mut root = 0;
+    let mut bit = 1u64 << 62;
+
+    while bit > value {
+        bit >>= 2;
+    }
+
+    while bit != 0 {
+        if remainder >= root + bit {
+            remainder -= root + bit;
+            root = (root >> 1) + bit;
+        } else {
+            root >>= 1;
+        }
+        bit >>= 2;
+    }
+
+    root
+}
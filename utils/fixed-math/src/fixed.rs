@@ -0,0 +1,249 @@
+//! Q-format fixed-point numbers.
+
+use core::fmt;
+use core::ops::{Add, AddAssign, Div, Mul, MulAssign, Neg, Sub, SubAssign};
+
+/// Signed fixed-point number in Q-format, stored in `i32`.
+///
+/// Value is equal to the stored bits divided by `2^FRAC`. Arithmetic operations saturate instead of
+/// overflowing, and multiplication and division round to the nearest value.
+///
+/// # Generic Parameters
+/// * `FRAC` - Number of fractional bits, `0` to `31`.
+#[derive(Copy, Clone, Default, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[repr(transparent)]
+pub struct Fixed<const FRAC: u32>(i32);
+
+/// Fixed-point number with 16 integer and 16 fractional bits (Q15.16), range `[-32768, 32768)`.
+pub type Q16 = Fixed<16>;
+/// Fixed-point number with 31 fractional bits (Q0.31), range `[-1, 1)`.
+pub type Q31 = Fixed<31>;
+
+impl<const FRAC: u32> Fixed<FRAC> {
+    /// Zero.
+    pub const ZERO: Self = Fixed(0);
+    /// Largest representable value.
+    pub const MAX: Self = Fixed(i32::MAX);
+    /// Smallest representable value.
+    pub const MIN: Self = Fixed(i32::MIN);
+    /// Smallest positive value.
+    pub const EPSILON: Self = Fixed(1);
+
+    /// Creates number from raw bits.
+    ///
+    /// # Parameters
+    /// * `bits` - Value multiplied by `2^FRAC`.
+    #[inline(always)]
+    pub const fn from_bits(bits: i32) -> Self {
+        Fixed(bits)
+    }
+
+    /// Returns raw bits of the number.
+    #[inline(always)]
+    pub const fn to_bits(self) -> i32 {
+        self.0
+    }
+
+    /// Creates number from an integer, saturating if it's out of range.
+    ///
+    /// # Parameters
+    /// * `value` - Integer value.
+    pub const fn from_int(value: i32) -> Self {
+        Self::saturate((value as i64) << FRAC)
+    }
+
+    /// Creates number from a float, saturating if it's out of range.
+    ///
+    /// # Parameters
+    /// * `value` - Float value.
+    pub fn from_f32(value: f32) -> Self {
+        let bits = value * (1u64 << FRAC) as f32;
+        let rounded = if bits < 0.0 { bits - 0.5 } else { bits + 0.5 };
+
+        // Float to integer conversion saturates, and converts NaN to zero.
+        Fixed(rounded as i32)
+    }
+
+    /// Converts number to a float.
+    pub fn to_f32(self) -> f32 {
+        self.0 as f32 / (1u64 << FRAC) as f32
+    }
+
+    /// Returns integer part of the number, rounded towards negative infinity.
+    pub const fn to_int(self) -> i32 {
+        self.0 >> FRAC
+    }
+
+    /// Returns absolute value, saturating for [`Fixed::MIN`].
+    pub const fn abs(self) -> Self {
+        Fixed(self.0.saturating_abs())
+    }
+
+    /// Saturating addition.
+    pub const fn saturating_add(self, rhs: Self) -> Self {
+        Fixed(self.0.saturating_add(rhs.0))
+    }
+
+    /// Saturating subtraction.
+    pub const fn saturating_sub(self, rhs: Self) -> Self {
+        Fixed(self.0.saturating_sub(rhs.0))
+    }
+
+    /// Saturating multiplication, rounded to the nearest value.
+    pub const fn saturating_mul(self, rhs: Self) -> Self {
+        let product = self.0 as i64 * rhs.0 as i64;
+        Self::saturate(round_shift(product, FRAC))
+    }
+
+    /// Checked division, rounded to the nearest value.
+    ///
+    /// # Return
+    /// Quotient (saturated if out of range), or `None` if `rhs` is zero.
+    pub const fn checked_div(self, rhs: Self) -> Option<Self> {
+        if rhs.0 == 0 {
+            return None;
+        }
+
+        let dividend = (self.0 as i64) << FRAC;
+        let divisor = rhs.0 as i64;
+        let half = divisor.abs() / 2;
+        // Division truncates towards zero, so half of the divisor is added in the dividend's
+        // direction to round to the nearest value.
+        let quotient = if dividend < 0 {
+            (dividend - half) / divisor
+        } else {
+            (dividend + half) / divisor
+        };
+
+        Some(Self::saturate(quotient))
+    }
+
+    /// Converts number to a different Q-format, saturating if it's out of range.
+    pub const fn convert<const TO: u32>(self) -> Fixed<TO> {
+        Fixed::<TO>::from_q(self.0 as i64, FRAC)
+    }
+
+    /// Creates number from a value with given number of fractional bits, saturating if it's out
+    /// of range.
+    pub(crate) const fn from_q(value: i64, frac: u32) -> Self {
+        if frac > FRAC {
+            Self::saturate(round_shift(value, frac - FRAC))
+        } else {
+            Self::saturate(value << (FRAC - frac))
+        }
+    }
+
+    /// Saturates value to `i32` range.
+    const fn saturate(value: i64) -> Self {
+        if value > i32::MAX as i64 {
+            Self::MAX
+        } else if value < i32::MIN as i64 {
+            Self::MIN
+        } else {
+            Fixed(value as i32)
+        }
+    }
+}
+
+/// Shifts value right, rounding to the nearest value.
+const fn round_shift(value: i64, shift: u32) -> i64 {
+    if shift == 0 {
+        value
+    } else {
+        (value + (1 << (shift - 1))) >> shift
+    }
+}
+
+impl<const FRAC: u32> Add for Fixed<FRAC> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        self.saturating_add(rhs)
+    }
+}
+
+impl<const FRAC: u32> Sub for Fixed<FRAC> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        self.saturating_sub(rhs)
+    }
+}
+
+impl<const FRAC: u32> Mul for Fixed<FRAC> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        self.saturating_mul(rhs)
+    }
+}
+
+/// Division by zero saturates to [`Fixed::MAX`] or [`Fixed::MIN`], depending on the dividend's
+/// sign. Use [`Fixed::checked_div`] to detect it.
+impl<const FRAC: u32> Div for Fixed<FRAC> {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self {
+        match self.checked_div(rhs) {
+            Some(quotient) => quotient,
+            None if self.0 < 0 => Self::MIN,
+            None => Self::MAX,
+        }
+    }
+}
+
+impl<const FRAC: u32> Neg for Fixed<FRAC> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Fixed(self.0.saturating_neg())
+    }
+}
+
+impl<const FRAC: u32> AddAssign for Fixed<FRAC> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl<const FRAC: u32> SubAssign for Fixed<FRAC> {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl<const FRAC: u32> MulAssign for Fixed<FRAC> {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = *self * rhs;
+    }
+}
+
+impl<const FRAC: u32> fmt::Debug for Fixed<FRAC> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.to_f32())
+    }
+}
+
+impl<const FRAC: u32> fmt::Display for Fixed<FRAC> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.to_f32())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn arithmetic() {
+        let a = Q16::from_f32(1.5);
+        let b = Q16::from_int(-2);
+
+        assert_eq!(a + b, Q16::from_f32(-0.5));
+        assert_eq!(a * b, Q16::from_int(-3));
+        assert_eq!(b / a, Q16::from_f32(-4.0 / 3.0));
+        assert_eq!(a.checked_div(Q16::ZERO), None);
+        assert_eq!(Q16::MAX + a, Q16::MAX);
+        assert_eq!(Q31::from_f32(0.5).convert::<16>(), Q16::from_f32(0.5));
+    }
+}
@@ -0,0 +1,25 @@
+#![no_std]
+//! Fixed-point math utilities.
+//!
+//! This library provides math used by control and sensor fusion tasklets, without pulling in
+//! float-heavy dependencies:
+//! * [`Fixed`] - Q-format fixed-point number, stored in `i32`,
+//! * [`Scalar`] - common interface of [`Fixed`] and `f32`, with deterministic trigonometric
+//!   and square root approximations,
+//! * [`Matrix`] - small, statically sized matrices.
+//!
+//! Algorithms should be written using [`Scalar`] trait, so the same code can use `f32` on
+//! Cortex-M7 with FPU, and fall back to [`Fixed`] when FPU can't be used (ex. in interrupt handlers
+//! which shouldn't preserve FPU context).
+//!
+//! Trigonometric functions use polynomial approximations with absolute error below `2e-4`,
+//! both for fixed-point and floating-point types.
+
+mod approx;
+mod fixed;
+mod matrix;
+mod scalar;
+
+pub use fixed::{Fixed, Q16, Q31};
+pub use matrix::{Matrix, Vector};
+pub use scalar::Scalar;
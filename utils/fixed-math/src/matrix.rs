@@ -0,0 +1,231 @@
+//! Small, statically sized matrices.
+
+use core::ops::{Add, Index, IndexMut, Mul, Sub};
+
+use crate::Scalar;
+
+/// Matrix with `R` rows and `C` columns.
+///
+/// Matrix is stored by value in row-major order, so it should be used only for small sizes
+/// (ex. state of a Kalman filter).
+///
+/// # Generic Parameters
+/// * `T` - Type of the elements.
+/// * `R` - Number of rows.
+/// * `C` - Number of columns.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub struct Matrix<T: Scalar, const R: usize, const C: usize>(pub [[T; C]; R]);
+
+/// Column vector with `N` elements.
+pub type Vector<T, const N: usize> = Matrix<T, N, 1>;
+
+impl<T: Scalar, const R: usize, const C: usize> Matrix<T, R, C> {
+    /// Creates matrix filled with zeros.
+    pub fn zeros() -> Self {
+        Matrix([[T::ZERO; C]; R])
+    }
+
+    /// Returns transposed matrix.
+    pub fn transpose(&self) -> Matrix<T, C, R> {
+        let mut result = Matrix::zeros();
+        for row in 0..R {
+            for column in 0..C {
+                result.0[column][row] = self.0[row][column];
+            }
+        }
+
+        result
+    }
+
+    /// Returns matrix with each element multiplied by a scalar.
+    ///
+    /// # Parameters
+    /// * `factor` - Scalar factor.
+    pub fn scale(&self, factor: T) -> Self {
+        self.map(|element| element * factor)
+    }
+
+    /// Returns matrix with given function applied to each element.
+    ///
+    /// # Parameters
+    /// * `f` - Function to apply.
+    pub fn map(&self, f: impl Fn(T) -> T) -> Self {
+        let mut result = *self;
+        result
+            .0
+            .iter_mut()
+            .flatten()
+            .for_each(|element| *element = f(*element));
+
+        result
+    }
+}
+
+impl<T: Scalar, const N: usize> Matrix<T, N, N> {
+    /// Creates identity matrix.
+    pub fn identity() -> Self {
+        let mut result = Self::zeros();
+        for i in 0..N {
+            result.0[i][i] = T::ONE;
+        }
+
+        result
+    }
+}
+
+impl<T: Scalar> Matrix<T, 2, 2> {
+    /// Returns inverse of the matrix, or `None` if the matrix is singular.
+    pub fn inverse(&self) -> Option<Self> {
+        let [[a, b], [c, d]] = self.0;
+        let determinant = a * d - b * c;
+        if determinant == T::ZERO {
+            return None;
+        }
+
+        Some(Matrix([[d, -b], [-c, a]]).scale(T::ONE / determinant))
+    }
+}
+
+impl<T: Scalar> Matrix<T, 3, 3> {
+    /// Returns inverse of the matrix, or `None` if the matrix is singular.
+    pub fn inverse(&self) -> Option<Self> {
+        let m = &self.0;
+        let cofactor = |r0: usize, r1: usize, c0: usize, c1: usize| {
+            m[r0][c0] * m[r1][c1] - m[r0][c1] * m[r1][c0]
+        };
+
+        // Adjugate matrix (transposed cofactor matrix).
+        let adjugate = Matrix([
+            [
+                cofactor(1, 2, 1, 2),
+                -cofactor(0, 2, 1, 2),
+                cofactor(0, 1, 1, 2),
+            ],
+            [
+                -cofactor(1, 2, 0, 2),
+                cofactor(0, 2, 0, 2),
+                -cofactor(0, 1, 0, 2),
+            ],
+            [
+                cofactor(1, 2, 0, 1),
+                -cofactor(0, 2, 0, 1),
+                cofactor(0, 1, 0, 1),
+            ],
+        ]);
+
+        let determinant =
+            m[0][0] * adjugate.0[0][0] + m[0][1] * adjugate.0[1][0] + m[0][2] * adjugate.0[2][0];
+        if determinant == T::ZERO {
+            return None;
+        }
+
+        Some(adjugate.scale(T::ONE / determinant))
+    }
+}
+
+impl<T: Scalar, const N: usize> Vector<T, N> {
+    /// Returns dot product of two vectors.
+    ///
+    /// # Parameters
+    /// * `other` - Second vector.
+    pub fn dot(&self, other: &Self) -> T {
+        self.0
+            .iter()
+            .zip(other.0.iter())
+            .fold(T::ZERO, |sum, (a, b)| sum + a[0] * b[0])
+    }
+
+    /// Returns Euclidean norm of the vector.
+    pub fn norm(&self) -> T {
+        self.dot(self).sqrt()
+    }
+}
+
+impl<T: Scalar, const R: usize, const C: usize> Add for Matrix<T, R, C> {
+    type Output = Self;
+
+    fn add(mut self, rhs: Self) -> Self {
+        self.0
+            .iter_mut()
+            .flatten()
+            .zip(rhs.0.iter().flatten())
+            .for_each(|(a, b)| *a = *a + *b);
+
+        self
+    }
+}
+
+impl<T: Scalar, const R: usize, const C: usize> Sub for Matrix<T, R, C> {
+    type Output = Self;
+
+    fn sub(mut self, rhs: Self) -> Self {
+        self.0
+            .iter_mut()
+            .flatten()
+            .zip(rhs.0.iter().flatten())
+            .for_each(|(a, b)| *a = *a - *b);
+
+        self
+    }
+}
+
+impl<T: Scalar, const R: usize, const N: usize, const C: usize> Mul<Matrix<T, N, C>>
+    for Matrix<T, R, N>
+{
+    type Output = Matrix<T, R, C>;
+
+    fn mul(self, rhs: Matrix<T, N, C>) -> Matrix<T, R, C> {
+        let mut result = Matrix::zeros();
+        for row in 0..R {
+            for column in 0..C {
+                result.0[row][column] =
+                    (0..N).fold(T::ZERO, |sum, i| sum + self.0[row][i] * rhs.0[i][column]);
+            }
+        }
+
+        result
+    }
+}
+
+impl<T: Scalar, const R: usize, const C: usize> Index<(usize, usize)> for Matrix<T, R, C> {
+    type Output = T;
+
+    fn index(&self, (row, column): (usize, usize)) -> &T {
+        &self.0[row][column]
+    }
+}
+
+impl<T: Scalar, const R: usize, const C: usize> IndexMut<(usize, usize)> for Matrix<T, R, C> {
+    fn index_mut(&mut self, (row, column): (usize, usize)) -> &mut T {
+        &mut self.0[row][column]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Q16;
+
+    #[test]
+    fn inverse_3x3() {
+        let matrix = Matrix([[2.0f32, 0.0, 1.0], [1.0, 3.0, 0.0], [0.0, 1.0, 4.0]]);
+        let product = matrix * matrix.inverse().unwrap();
+
+        let error = (product - Matrix::identity()).map(|e| e.abs());
+        assert!(error.0.iter().flatten().all(|e| *e < 1e-6));
+    }
+
+    #[test]
+    fn fixed_point_operations() {
+        let a = Matrix([
+            [Q16::from_int(1), Q16::from_int(2)],
+            [Q16::from_int(3), Q16::from_int(4)],
+        ]);
+        let v: Vector<Q16, 2> = Matrix([[Q16::from_int(1)], [Q16::from_int(-1)]]);
+
+        assert_eq!(a * v, Matrix([[Q16::from_int(-1)], [Q16::from_int(-1)]]));
+        assert_eq!(a.transpose()[(0, 1)], Q16::from_int(3));
+        assert_eq!(v.dot(&v), Q16::from_int(2));
+        assert_eq!(a * a.inverse().unwrap(), Matrix::identity());
+    }
+}
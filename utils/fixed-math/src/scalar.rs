@@ -0,0 +1,189 @@
+//! Scalar trait, implemented for fixed-point and floating-point numbers.
+
+use core::ops::{Add, Div, Mul, Neg, Sub};
+
+use crate::approx::{
+    atan2_f32, atan2_q30, isqrt, sin_f32, sin_q30, sqrt_f32, Q30_ONE, Q32_INV_2PI,
+};
+use crate::Fixed;
+
+/// Scalar number.
+///
+/// Provides arithmetic, trigonometric and square root functions with deterministic
+/// implementations, so generic algorithms give the same results on each platform.
+pub trait Scalar:
+    Copy
+    + PartialOrd
+    + Add<Output = Self>
+    + Sub<Output = Self>
+    + Mul<Output = Self>
+    + Div<Output = Self>
+    + Neg<Output = Self>
+{
+    /// Zero.
+    const ZERO: Self;
+    /// One. For formats that can't represent one, the largest value.
+    const ONE: Self;
+
+    /// Converts float to scalar.
+    fn from_f32(value: f32) -> Self;
+
+    /// Converts scalar to float.
+    fn to_f32(self) -> f32;
+
+    /// Returns absolute value.
+    fn abs(self) -> Self;
+
+    /// Returns sine of the angle in radians.
+    fn sin(self) -> Self;
+
+    /// Returns cosine of the angle in radians.
+    fn cos(self) -> Self;
+
+    /// Returns four-quadrant arctangent of `self / x`, in radians.
+    ///
+    /// # Parameters
+    /// * `x` - X coordinate, with `self` being Y coordinate.
+    fn atan2(self, x: Self) -> Self;
+
+    /// Returns square root, or zero for negative values.
+    fn sqrt(self) -> Self;
+}
+
+impl<const FRAC: u32> Fixed<FRAC> {
+    /// Converts angle in radians to a fraction of the full turn, in Q32 format.
+    fn to_turns(self) -> u32 {
+        // Truncation to `u32` wraps the angle to the full turn.
+        ((self.to_bits() as i64 * Q32_INV_2PI) >> FRAC) as u32
+    }
+}
+
+impl<const FRAC: u32> Scalar for Fixed<FRAC> {
+    const ZERO: Self = Fixed::ZERO;
+    const ONE: Self = Fixed::from_int(1);
+
+    fn from_f32(value: f32) -> Self {
+        Fixed::from_f32(value)
+    }
+
+    fn to_f32(self) -> f32 {
+        Fixed::to_f32(self)
+    }
+
+    fn abs(self) -> Self {
+        Fixed::abs(self)
+    }
+
+    fn sin(self) -> Self {
+        Fixed::from_q(sin_q30(self.to_turns()), 30)
+    }
+
+    fn cos(self) -> Self {
+        let quarter_turn = Q30_ONE as u32;
+        Fixed::from_q(sin_q30(self.to_turns().wrapping_add(quarter_turn)), 30)
+    }
+
+    fn atan2(self, x: Self) -> Self {
+        Fixed::from_q(atan2_q30(self.to_bits() as i64, x.to_bits() as i64), 30)
+    }
+
+    fn sqrt(self) -> Self {
+        if self.to_bits() <= 0 {
+            return Fixed::ZERO;
+        }
+
+        // sqrt(bits / 2^FRAC) = sqrt(bits * 2^FRAC) / 2^FRAC
+        let root = isqrt((self.to_bits() as u64) << FRAC);
+        Fixed::from_q(root as i64, FRAC)
+    }
+}
+
+impl Scalar for f32 {
+    const ZERO: Self = 0.0;
+    const ONE: Self = 1.0;
+
+    fn from_f32(value: f32) -> Self {
+        value
+    }
+
+    fn to_f32(self) -> f32 {
+        self
+    }
+
+    fn abs(self) -> Self {
+        if self < 0.0 {
+            -self
+        } else {
+            self
+        }
+    }
+
+    fn sin(self) -> Self {
+        sin_f32(self * core::f32::consts::FRAC_1_PI * 0.5)
+    }
+
+    fn cos(self) -> Self {
+        sin_f32(self * core::f32::consts::FRAC_1_PI * 0.5 + 0.25)
+    }
+
+    fn atan2(self, x: Self) -> Self {
+        atan2_f32(self, x)
+    }
+
+    fn sqrt(self) -> Self {
+        if self < 0.0 {
+            0.0
+        } else {
+            sqrt_f32(self)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    extern crate std;
+
+    use super::*;
+    use crate::{Q16, Q31};
+
+    /// Maximum error of the approximations.
+    const TOLERANCE: f32 = 3e-4;
+
+    fn assert_close(value: f32, expected: f32) {
+        assert!(
+            (value - expected).abs() < TOLERANCE,
+            "{} != {}",
+            value,
+            expected
+        );
+    }
+
+    #[test]
+    fn trigonometry() {
+        for step in -40..=40 {
+            let angle = step as f32 * 0.2;
+
+            assert_close(Q16::from_f32(angle).sin().to_f32(), angle.sin());
+            assert_close(Q16::from_f32(angle).cos().to_f32(), angle.cos());
+            assert_close(Scalar::sin(angle), angle.sin());
+            assert_close(Scalar::cos(angle), angle.cos());
+
+            let (y, x) = (angle.cos() * 3.0, angle.sin() * 2.0);
+            assert_close(
+                Q16::from_f32(y).atan2(Q16::from_f32(x)).to_f32(),
+                y.atan2(x),
+            );
+            assert_close(Scalar::atan2(y, x), y.atan2(x));
+        }
+
+        assert_close(Q31::from_f32(0.5).sin().to_f32(), 0.5f32.sin());
+    }
+
+    #[test]
+    fn square_root() {
+        assert_close(Q16::from_int(2).sqrt().to_f32(), 2f32.sqrt());
+        assert_close(Q31::from_f32(0.25).sqrt().to_f32(), 0.5);
+        assert_eq!(Scalar::sqrt(16.0f32), 4.0);
+        assert_eq!(Q16::from_int(-1).sqrt(), Q16::ZERO);
+    }
+}
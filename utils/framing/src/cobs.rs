@@ -0,0 +1,228 @@
+//! Consistent Overhead Byte Stuffing.
+//!
+//! COBS removes all zero bytes from the frame, so `0x00` can be used as a frame delimiter. Frame is
+//! split into blocks, each starting with a code byte equal to the length of the block plus one.
+//! Block shorter than 254 bytes is followed by an implicit zero in the payload, unless it's the
+//! last block of the frame.
+
+use crate::{Checksum, FrameBuffer, FrameDecoder, FrameEncoder, FrameWriter, FramingError};
+
+/// Frame delimiter.
+const DELIMITER: u8 = 0x00;
+/// Code of the block with maximum length, that isn't followed by an implicit zero.
+const MAX_CODE: u8 = 0xFF;
+
+/// COBS frame encoder.
+#[derive(Debug, Copy, Clone)]
+pub struct CobsEncoder {
+    /// Checksum appended to the payload.
+    checksum: Checksum,
+}
+
+impl CobsEncoder {
+    /// Creates new encoder.
+    ///
+    /// # Parameters
+    /// * `checksum` - Checksum appended to the payload.
+    pub const fn new(checksum: Checksum) -> Self {
+        Self { checksum }
+    }
+}
+
+impl FrameEncoder for CobsEncoder {
+    fn encode(&self, payload: &[u8], frame: &mut [u8]) -> Result<usize, FramingError> {
+        let checksum = self.checksum.calculate(payload);
+        let mut writer = FrameWriter::new(frame);
+
+        let mut code_position = writer.write(0)?;
+        let mut code = 1;
+        for &byte in payload.iter().chain(&checksum[..self.checksum.size()]) {
+            if byte != 0 {
+                writer.write(byte)?;
+                code += 1;
+            }
+
+            if byte == 0 || code == MAX_CODE {
+                writer.set(code_position, code);
+                code_position = writer.write(0)?;
+                code = 1;
+            }
+        }
+        writer.set(code_position, code);
+        writer.write(DELIMITER)?;
+
+        Ok(writer.length)
+    }
+
+    fn max_frame_length(&self, payload_length: usize) -> usize {
+        let length = payload_length + self.checksum.size();
+        length + length / (MAX_CODE as usize - 1) + 2
+    }
+}
+
+/// COBS frame decoder.
+///
+/// # Generic parameters
+/// * `N` - Maximum length of the decoded frame, including checksum.
+pub struct CobsDecoder<const N: usize> {
+    /// Checksum of the frame.
+    checksum: Checksum,
+    /// Decoded frame.
+    buffer: FrameBuffer<N>,
+    /// Number of bytes remaining in the current block.
+    remaining: u8,
+    /// Whether the current block is followed by an implicit zero.
+    zero_pending: bool,
+}
+
+impl<const N: usize> CobsDecoder<N> {
+    /// Creates new decoder.
+    ///
+    /// # Parameters
+    /// * `checksum` - Checksum of the frame.
+    pub const fn new(checksum: Checksum) -> Self {
+        Self {
+            checksum,
+            buffer: FrameBuffer::new(),
+            remaining: 0,
+            zero_pending: false,
+        }
+    }
+}
+
+impl<const N: usize> FrameDecoder for CobsDecoder<N> {
+    fn push(&mut self, byte: u8) -> Result<Option<&[u8]>, FramingError> {
+        if byte == DELIMITER {
+            let block_complete = self.remaining == 0;
+            self.remaining = 0;
+            self.zero_pending = false;
+
+            if !block_complete {
+                self.buffer.clear();
+                return Err(FramingError::InvalidEncoding);
+            }
+            return self.buffer.finish(self.checksum);
+        }
+
+        if self.remaining == 0 {
+            let zero_pending = self.zero_pending;
+            self.remaining = byte - 1;
+            self.zero_pending = byte != MAX_CODE;
+
+            if zero_pending {
+                self.buffer.push(0)?;
+            }
+        } else {
+            self.remaining -= 1;
+            self.buffer.push(byte)?;
+        }
+
+        Ok(None)
+    }
+
+    fn reset(&mut self) {
+        self.buffer.clear();
+        self.remaining = 0;
+        self.zero_pending = false;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Feeds the frame to the decoder, returning the first error or the decoded payload length.
+    fn decode<const N: usize>(
+        decoder: &mut CobsDecoder<N>,
+        frame: &[u8],
+        payload: &mut [u8],
+    ) -> Result<Option<usize>, FramingError> {
+        let mut result = Ok(None);
+        for &byte in frame {
+            match decoder.push(byte) {
+                Ok(Some(decoded)) => {
+                    payload[..decoded.len()].copy_from_slice(decoded);
+                    result = result.and(Ok(Some(decoded.len())));
+                }
+                Ok(None) => (),
+                Err(error) => result = result.and(Err(error)),
+            }
+        }
+        result
+    }
+
+    #[test]
+    fn encode_known_frames() {
+        let encoder = CobsEncoder::new(Checksum::None);
+        let mut frame = [0; 16];
+
+        let length = encoder.encode(&[0x00], &mut frame).unwrap();
+        assert_eq!(&frame[..length], &[0x01, 0x01, 0x00]);
+
+        let length = encoder
+            .encode(&[0x11, 0x22, 0x00, 0x33], &mut frame)
+            .unwrap();
+        assert_eq!(&frame[..length], &[0x03, 0x11, 0x22, 0x02, 0x33, 0x00]);
+
+        let length = encoder.encode(&[0x11, 0x00, 0x00], &mut frame).unwrap();
+        assert_eq!(&frame[..length], &[0x02, 0x11, 0x01, 0x01, 0x00]);
+
+        assert_eq!(
+            encoder.encode(&[0x11; 15], &mut frame),
+            Err(FramingError::BufferTooSmall)
+        );
+    }
+
+    #[test]
+    fn long_frame_round_trip() {
+        let encoder = CobsEncoder::new(Checksum::Crc32);
+        let mut decoder = CobsDecoder::<600>::new(Checksum::Crc32);
+        let mut payload = [0; 520];
+        for (index, byte) in payload.iter_mut().enumerate() {
+            *byte = (index % 300) as u8;
+        }
+        let mut frame = [0; 600];
+        let mut decoded = [0; 600];
+
+        let length = encoder.encode(&payload, &mut frame).unwrap();
+        assert!(length <= encoder.max_frame_length(payload.len()));
+        assert!(!frame[..length - 1].contains(&0));
+
+        let decoded_length = decode(&mut decoder, &frame[..length], &mut decoded);
+        assert_eq!(decoded_length, Ok(Some(payload.len())));
+        assert_eq!(&decoded[..payload.len()], &payload);
+    }
+
+    #[test]
+    fn decoder_recovers_after_errors() {
+        let encoder = CobsEncoder::new(Checksum::Crc16);
+        let mut decoder = CobsDecoder::<8>::new(Checksum::Crc16);
+        let mut frame = [0; 32];
+        let mut decoded = [0; 8];
+
+        let length = encoder.encode(&[0; 12], &mut frame).unwrap();
+        assert_eq!(
+            decode(&mut decoder, &frame[..length], &mut decoded),
+            Err(FramingError::BufferTooSmall)
+        );
+
+        let length = encoder.encode(&[1, 2, 3], &mut frame).unwrap();
+        frame[2] ^= 0xFF;
+        assert_eq!(
+            decode(&mut decoder, &frame[..length], &mut decoded),
+            Err(FramingError::InvalidChecksum)
+        );
+
+        assert_eq!(
+            decode(&mut decoder, &[0x05, 0x01, 0x00], &mut decoded),
+            Err(FramingError::InvalidEncoding)
+        );
+
+        let length = encoder.encode(&[1, 0, 3], &mut frame).unwrap();
+        assert_eq!(
+            decode(&mut decoder, &frame[..length], &mut decoded),
+            Ok(Some(3))
+        );
+        assert_eq!(&decoded[..3], &[1, 0, 3]);
+    }
+}
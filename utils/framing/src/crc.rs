@@ -0,0 +1,125 @@
+//! Frame checksums.
+
+use crate::FramingError;
+
+/// Checksum appended to the frame payload.
+///
+/// Checksum is stored in little-endian byte order.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Checksum {
+    /// No checksum.
+    None,
+    /// CRC-16/X-25, which is the frame check sequence of HDLC.
+    Crc16,
+    /// CRC-32 (IEEE 802.3).
+    Crc32,
+}
+
+impl Checksum {
+    /// Returns size of the checksum in bytes.
+    pub const fn size(&self) -> usize {
+        match self {
+            Checksum::None => 0,
+            Checksum::Crc16 => 2,
+            Checksum::Crc32 => 4,
+        }
+    }
+
+    /// Calculates checksum of the data.
+    ///
+    /// # Parameters
+    /// * `data` - Data to calculate checksum of.
+    ///
+    /// # Return
+    /// Checksum bytes, of which first [`size`](Checksum::size) are valid.
+    pub fn calculate(&self, data: &[u8]) -> [u8; 4] {
+        match self {
+            Checksum::None => [0; 4],
+            Checksum::Crc16 => {
+                let [low, high] = crc16(data).to_le_bytes();
+                [low, high, 0, 0]
+            }
+            Checksum::Crc32 => crc32(data).to_le_bytes(),
+        }
+    }
+
+    /// Verifies checksum at the end of the frame.
+    ///
+    /// # Parameters
+    /// * `frame` - Payload followed by the checksum.
+    ///
+    /// # Return
+    /// Payload, or [`FramingError`] if the checksum is invalid.
+    pub fn verify<'a>(&self, frame: &'a [u8]) -> Result<&'a [u8], FramingError> {
+        let payload_length = frame
+            .len()
+            .checked_sub(self.size())
+            .ok_or(FramingError::FrameTooShort)?;
+        let (payload, checksum) = frame.split_at(payload_length);
+
+        if checksum == &self.calculate(payload)[..self.size()] {
+            Ok(payload)
+        } else {
+            Err(FramingError::InvalidChecksum)
+        }
+    }
+}
+
+/// Calculates CRC-16/X-25.
+fn crc16(data: &[u8]) -> u16 {
+    /// Reversed CCITT polynomial.
+    const POLYNOMIAL: u16 = 0x8408;
+
+    !data.iter().fold(0xFFFF, |mut crc, byte| {
+        crc ^= *byte as u16;
+        for _ in 0..8 {
+            let mask = (crc & 1).wrapping_neg();
+            crc = (crc >> 1) ^ (POLYNOMIAL & mask);
+        }
+        crc
+    })
+}
+
+/// Calculates CRC-32 (IEEE 802.3).
+fn crc32(data: &[u8]) -> u32 {
+    /// Reversed CRC-32 polynomial.
+    const POLYNOMIAL: u32 = 0xEDB8_8320;
+
+    !data.iter().fold(0xFFFF_FFFF, |mut crc, byte| {
+        crc ^= *byte as u32;
+        for _ in 0..8 {
+            let mask = (crc & 1).wrapping_neg();
+            crc = (crc >> 1) ^ (POLYNOMIAL & mask);
+        }
+        crc
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn check_values() {
+        assert_eq!(crc16(b"123456789"), 0x906E);
+        assert_eq!(crc32(b"123456789"), 0xCBF4_3926);
+    }
+
+    #[test]
+    fn verify_checksum() {
+        let mut frame = [1, 2, 3, 0, 0];
+        let crc = Checksum::Crc16.calculate(&frame[..3]);
+        frame[3..].copy_from_slice(&crc[..2]);
+
+        assert_eq!(Checksum::Crc16.verify(&frame), Ok(&frame[..3]));
+        frame[0] = 0;
+        assert_eq!(
+            Checksum::Crc16.verify(&frame),
+            Err(FramingError::InvalidChecksum)
+        );
+        assert_eq!(
+            Checksum::Crc32.verify(&frame[..2]),
+            Err(FramingError::FrameTooShort)
+        );
+    }
+}
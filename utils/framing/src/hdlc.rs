@@ -0,0 +1,158 @@
+//! Asynchronous HDLC-like framing (RFC 1662).
+//!
+//! Frames are delimited with `FLAG` byte, and `FLAG` and `ESC` bytes in the payload are replaced
+//! with `ESC` followed by the byte XOR-ed with `0x20`. Address and control fields of HDLC are not
+//! used, frame contains only the payload and the checksum - [`Checksum::Crc16`] is the standard
+//! HDLC frame check sequence. Consecutive flags are allowed, and `ESC` followed by `FLAG` aborts
+//! the frame.
+
+use crate::{Checksum, FrameBuffer, FrameDecoder, FrameEncoder, FrameWriter, FramingError};
+
+/// Frame delimiter.
+const FLAG: u8 = 0x7E;
+/// Escape byte.
+const ESC: u8 = 0x7D;
+/// Value XOR-ed with escaped byte.
+const ESC_MASK: u8 = 0x20;
+
+/// HDLC frame encoder.
+#[derive(Debug, Copy, Clone)]
+pub struct HdlcEncoder {
+    /// Checksum appended to the payload.
+    checksum: Checksum,
+}
+
+impl HdlcEncoder {
+    /// Creates new encoder.
+    ///
+    /// # Parameters
+    /// * `checksum` - Checksum appended to the payload.
+    pub const fn new(checksum: Checksum) -> Self {
+        Self { checksum }
+    }
+}
+
+impl FrameEncoder for HdlcEncoder {
+    fn encode(&self, payload: &[u8], frame: &mut [u8]) -> Result<usize, FramingError> {
+        let checksum = self.checksum.calculate(payload);
+        let mut writer = FrameWriter::new(frame);
+
+        writer.write(FLAG)?;
+        for &byte in payload.iter().chain(&checksum[..self.checksum.size()]) {
+            if byte == FLAG || byte == ESC {
+                writer.write(ESC)?;
+                writer.write(byte ^ ESC_MASK)?;
+            } else {
+                writer.write(byte)?;
+            }
+        }
+        writer.write(FLAG)?;
+
+        Ok(writer.length)
+    }
+
+    fn max_frame_length(&self, payload_length: usize) -> usize {
+        2 * (payload_length + self.checksum.size()) + 2
+    }
+}
+
+/// HDLC frame decoder.
+///
+/// # Generic parameters
+/// * `N` - Maximum length of the decoded frame, including checksum.
+pub struct HdlcDecoder<const N: usize> {
+    /// Checksum of the frame.
+    checksum: Checksum,
+    /// Decoded frame.
+    buffer: FrameBuffer<N>,
+    /// Whether previous byte was `ESC`.
+    escaped: bool,
+}
+
+impl<const N: usize> HdlcDecoder<N> {
+    /// Creates new decoder.
+    ///
+    /// # Parameters
+    /// * `checksum` - Checksum of the frame.
+    pub const fn new(checksum: Checksum) -> Self {
+        Self {
+            checksum,
+            buffer: FrameBuffer::new(),
+            escaped: false,
+        }
+    }
+}
+
+impl<const N: usize> FrameDecoder for HdlcDecoder<N> {
+    fn push(&mut self, byte: u8) -> Result<Option<&[u8]>, FramingError> {
+        let escaped = core::mem::take(&mut self.escaped);
+
+        match (escaped, byte) {
+            (true, FLAG) => {
+                self.buffer.clear();
+                Err(FramingError::InvalidEncoding)
+            }
+            (false, FLAG) => self.buffer.finish(self.checksum),
+            (false, ESC) => {
+                self.escaped = true;
+                Ok(None)
+            }
+            (true, _) => self.buffer.push(byte ^ ESC_MASK).map(|_| None),
+            (false, _) => self.buffer.push(byte).map(|_| None),
+        }
+    }
+
+    fn reset(&mut self) {
+        self.buffer.clear();
+        self.escaped = false;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn encode_escapes() {
+        let encoder = HdlcEncoder::new(Checksum::None);
+        let mut frame = [0; 16];
+
+        let length = encoder.encode(&[0x01, FLAG, ESC], &mut frame).unwrap();
+        assert_eq!(&frame[..length], &[FLAG, 0x01, ESC, 0x5E, ESC, 0x5D, FLAG]);
+    }
+
+    #[test]
+    fn round_trip_with_checksum() {
+        let encoder = HdlcEncoder::new(Checksum::Crc16);
+        let mut decoder = HdlcDecoder::<8>::new(Checksum::Crc16);
+        let mut frame = [0; 32];
+        let payload = [FLAG, ESC, 0x00, 0xFF];
+
+        let length = encoder.encode(&payload, &mut frame).unwrap();
+        assert!(length <= encoder.max_frame_length(payload.len()));
+
+        let (last, bytes) = frame[..length].split_last().unwrap();
+        for &byte in bytes {
+            assert_eq!(decoder.push(byte), Ok(None));
+        }
+        assert_eq!(decoder.push(*last), Ok(Some(&payload[..])));
+    }
+
+    #[test]
+    fn aborted_and_corrupted_frames() {
+        let mut decoder = HdlcDecoder::<4>::new(Checksum::Crc16);
+
+        for &byte in &[FLAG, 0x01, 0x02, ESC] {
+            assert_eq!(decoder.push(byte), Ok(None));
+        }
+        assert_eq!(decoder.push(FLAG), Err(FramingError::InvalidEncoding));
+
+        for &byte in &[0x01, 0x02, 0x03] {
+            assert_eq!(decoder.push(byte), Ok(None));
+        }
+        assert_eq!(decoder.push(FLAG), Err(FramingError::InvalidChecksum));
+
+        assert_eq!(decoder.push(0x01), Ok(None));
+        assert_eq!(decoder.push(FLAG), Err(FramingError::FrameTooShort));
+    }
+}
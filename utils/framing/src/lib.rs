@@ -0,0 +1,188 @@
+#![no_std]
+//! Serial framing codecs.
+//!
+//! Byte streams, like UART, don't preserve message boundaries. This library turns messages into
+//! delimited frames and back, with one of the following framings:
+//! * [`cobs`] - Consistent Overhead Byte Stuffing, frames terminated with `0x00`, constant overhead
+//!   of one byte per 254 bytes of payload,
+//! * [`slip`] - Serial Line Internet Protocol (RFC 1055), frames terminated with `0xC0`,
+//! * [`hdlc`] - asynchronous HDLC framing (RFC 1662) without address and control fields, frames
+//!   delimited with `0x7E`.
+//!
+//! Each framing provides an encoder, writing the whole frame into a buffer, and a decoder, which is
+//! a state machine fed with received bytes one at a time, so it can be used directly with data
+//! coming from an interrupt handler or a queue. Frames can be protected with a [`Checksum`],
+//! which is appended to the payload by the encoder and verified and stripped by the decoder.
+
+pub mod cobs;
+mod crc;
+pub mod hdlc;
+pub mod slip;
+
+pub use crc::Checksum;
+
+/// Framing error.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum FramingError {
+    /// Output buffer of the encoder, or frame buffer of the decoder, is too small. Decoder
+    /// discards the rest of the frame.
+    BufferTooSmall,
+    /// Frame contains invalid byte sequence.
+    InvalidEncoding,
+    /// Frame is shorter than the checksum.
+    FrameTooShort,
+    /// Frame checksum doesn't match its content.
+    InvalidChecksum,
+}
+
+/// Frame encoder.
+pub trait FrameEncoder {
+    /// Encodes payload into a complete frame.
+    ///
+    /// # Parameters
+    /// * `payload` - Payload of the frame.
+    /// * `frame` - Buffer for the encoded frame.
+    ///
+    /// # Return
+    /// Length of the encoded frame, or [`FramingError::BufferTooSmall`] if it doesn't fit into
+    /// the buffer.
+    fn encode(&self, payload: &[u8], frame: &mut [u8]) -> Result<usize, FramingError>;
+
+    /// Returns maximum length of the frame with payload of given length.
+    ///
+    /// # Parameters
+    /// * `payload_length` - Length of the payload.
+    fn max_frame_length(&self, payload_length: usize) -> usize;
+}
+
+/// Frame decoder.
+pub trait FrameDecoder {
+    /// Feeds received byte to the decoder.
+    ///
+    /// Empty frames, and frames that overflowed the decoder's buffer or were invalid, are not
+    /// returned.
+    ///
+    /// # Parameters
+    /// * `byte` - Received byte.
+    ///
+    /// # Return
+    /// Payload of the frame, if the byte completed it, `None` if more bytes are needed, or
+    /// [`FramingError`] if the frame is invalid. Decoding of the next frame starts with the next
+    /// byte in every case.
+    fn push(&mut self, byte: u8) -> Result<Option<&[u8]>, FramingError>;
+
+    /// Discards partially received frame.
+    fn reset(&mut self);
+}
+
+/// Buffer for encoded frame.
+struct FrameWriter<'a> {
+    /// Output buffer.
+    frame: &'a mut [u8],
+    /// Number of bytes written.
+    length: usize,
+}
+
+impl<'a> FrameWriter<'a> {
+    /// Creates new frame writer.
+    fn new(frame: &'a mut [u8]) -> Self {
+        Self { frame, length: 0 }
+    }
+
+    /// Writes a byte at the end of the frame.
+    ///
+    /// # Return
+    /// Position of the written byte, or [`FramingError::BufferTooSmall`].
+    fn write(&mut self, byte: u8) -> Result<usize, FramingError> {
+        let position = self.length;
+        let slot = self
+            .frame
+            .get_mut(position)
+            .ok_or(FramingError::BufferTooSmall)?;
+        *slot = byte;
+        self.length += 1;
+        Ok(position)
+    }
+
+    /// Overwrites already written byte.
+    fn set(&mut self, position: usize, byte: u8) {
+        self.frame[position] = byte;
+    }
+}
+
+/// Buffer for decoded frame.
+struct FrameBuffer<const N: usize> {
+    /// Frame data.
+    data: [u8; N],
+    /// Number of received bytes.
+    length: usize,
+    /// Whether the frame is invalid or overflowed the buffer, and the rest of it should be
+    /// discarded.
+    discarding: bool,
+}
+
+impl<const N: usize> FrameBuffer<N> {
+    /// Creates new frame buffer.
+    const fn new() -> Self {
+        Self {
+            data: [0; N],
+            length: 0,
+            discarding: false,
+        }
+    }
+
+    /// Appends a byte to the frame.
+    ///
+    /// # Return
+    /// [`FramingError::BufferTooSmall`] when the frame overflows the buffer. Following bytes are
+    /// discarded until the end of the frame.
+    fn push(&mut self, byte: u8) -> Result<(), FramingError> {
+        if self.discarding {
+            return Ok(());
+        }
+
+        match self.data.get_mut(self.length) {
+            Some(slot) => {
+                *slot = byte;
+                self.length += 1;
+                Ok(())
+            }
+            None => {
+                self.discarding = true;
+                Err(FramingError::BufferTooSmall)
+            }
+        }
+    }
+
+    /// Finishes the frame and clears the buffer for the next one.
+    ///
+    /// # Parameters
+    /// * `checksum` - Checksum of the frame.
+    ///
+    /// # Return
+    /// Payload of the frame, `None` if the frame was empty or discarded, or [`FramingError`] if
+    /// the checksum is invalid.
+    fn finish(&mut self, checksum: Checksum) -> Result<Option<&[u8]>, FramingError> {
+        let length = core::mem::take(&mut self.length);
+        if core::mem::take(&mut self.discarding) || length == 0 {
+            return Ok(None);
+        }
+
+        checksum.verify(&self.data[..length]).map(Some)
+    }
+
+    /// Discards the rest of the current frame, until the end of the frame.
+    ///
+    /// # Return
+    /// [`FramingError::InvalidEncoding`], for convenience.
+    fn discard(&mut self) -> FramingError {
+        self.discarding = true;
+        FramingError::InvalidEncoding
+    }
+
+    /// Clears the buffer.
+    fn clear(&mut self) {
+        self.length = 0;
+        self.discarding = false;
+    }
+}
@@ -0,0 +1,173 @@
+//! Serial Line Internet Protocol framing (RFC 1055).
+//!
+//! Frames are terminated with `END` byte. Encoder also starts each frame with `END`, which flushes
+//! any noise received on the line before the frame. `END` and `ESC` bytes in the payload are
+//! replaced with two-byte escape sequences.
+
+use crate::{Checksum, FrameBuffer, FrameDecoder, FrameEncoder, FrameWriter, FramingError};
+
+/// Frame delimiter.
+const END: u8 = 0xC0;
+/// Escape byte.
+const ESC: u8 = 0xDB;
+/// Escaped `END` byte.
+const ESC_END: u8 = 0xDC;
+/// Escaped `ESC` byte.
+const ESC_ESC: u8 = 0xDD;
+
+/// SLIP frame encoder.
+#[derive(Debug, Copy, Clone)]
+pub struct SlipEncoder {
+    /// Checksum appended to the payload.
+    checksum: Checksum,
+}
+
+impl SlipEncoder {
+    /// Creates new encoder.
+    ///
+    /// # Parameters
+    /// * `checksum` - Checksum appended to the payload.
+    pub const fn new(checksum: Checksum) -> Self {
+        Self { checksum }
+    }
+}
+
+impl FrameEncoder for SlipEncoder {
+    fn encode(&self, payload: &[u8], frame: &mut [u8]) -> Result<usize, FramingError> {
+        let checksum = self.checksum.calculate(payload);
+        let mut writer = FrameWriter::new(frame);
+
+        writer.write(END)?;
+        for &byte in payload.iter().chain(&checksum[..self.checksum.size()]) {
+            match byte {
+                END => {
+                    writer.write(ESC)?;
+                    writer.write(ESC_END)?;
+                }
+                ESC => {
+                    writer.write(ESC)?;
+                    writer.write(ESC_ESC)?;
+                }
+                _ => {
+                    writer.write(byte)?;
+                }
+            }
+        }
+        writer.write(END)?;
+
+        Ok(writer.length)
+    }
+
+    fn max_frame_length(&self, payload_length: usize) -> usize {
+        2 * (payload_length + self.checksum.size()) + 2
+    }
+}
+
+/// SLIP frame decoder.
+///
+/// # Generic parameters
+/// * `N` - Maximum length of the decoded frame, including checksum.
+pub struct SlipDecoder<const N: usize> {
+    /// Checksum of the frame.
+    checksum: Checksum,
+    /// Decoded frame.
+    buffer: FrameBuffer<N>,
+    /// Whether previous byte was `ESC`.
+    escaped: bool,
+}
+
+impl<const N: usize> SlipDecoder<N> {
+    /// Creates new decoder.
+    ///
+    /// # Parameters
+    /// * `checksum` - Checksum of the frame.
+    pub const fn new(checksum: Checksum) -> Self {
+        Self {
+            checksum,
+            buffer: FrameBuffer::new(),
+            escaped: false,
+        }
+    }
+}
+
+impl<const N: usize> FrameDecoder for SlipDecoder<N> {
+    fn push(&mut self, byte: u8) -> Result<Option<&[u8]>, FramingError> {
+        let escaped = core::mem::take(&mut self.escaped);
+
+        match (escaped, byte) {
+            (true, END) => {
+                self.buffer.clear();
+                Err(FramingError::InvalidEncoding)
+            }
+            (false, END) => self.buffer.finish(self.checksum),
+            (false, ESC) => {
+                self.escaped = true;
+                Ok(None)
+            }
+            (true, ESC_END) => self.buffer.push(END).map(|_| None),
+            (true, ESC_ESC) => self.buffer.push(ESC).map(|_| None),
+            (true, _) => Err(self.buffer.discard()),
+            (false, _) => self.buffer.push(byte).map(|_| None),
+        }
+    }
+
+    fn reset(&mut self) {
+        self.buffer.clear();
+        self.escaped = false;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn encode_escapes() {
+        let encoder = SlipEncoder::new(Checksum::None);
+        let mut frame = [0; 16];
+
+        let length = encoder.encode(&[0x01, END, ESC, 0x02], &mut frame).unwrap();
+        assert_eq!(
+            &frame[..length],
+            &[END, 0x01, ESC, ESC_END, ESC, ESC_ESC, 0x02, END]
+        );
+        assert!(length <= encoder.max_frame_length(4));
+    }
+
+    #[test]
+    fn decode_stream() {
+        let encoder = SlipEncoder::new(Checksum::Crc16);
+        let mut decoder = SlipDecoder::<16>::new(Checksum::Crc16);
+        let mut stream = [0; 64];
+        let mut length = 0;
+
+        length += encoder
+            .encode(&[END, 1, ESC], &mut stream[length..])
+            .unwrap();
+        stream[length..length + 3].copy_from_slice(&[0x05, ESC, 0x06]);
+        length += 3;
+        length += encoder.encode(&[2, 3], &mut stream[length..]).unwrap();
+
+        let mut frames = 0;
+        let mut errors = 0;
+        for &byte in &stream[..length] {
+            match decoder.push(byte) {
+                Ok(Some(payload)) => {
+                    match frames {
+                        0 => assert_eq!(payload, &[END, 1, ESC]),
+                        _ => assert_eq!(payload, &[2, 3]),
+                    }
+                    frames += 1;
+                }
+                Ok(None) => (),
+                Err(error) => {
+                    assert_eq!(error, FramingError::InvalidEncoding);
+                    errors += 1;
+                }
+            }
+        }
+
+        assert_eq!(frames, 2);
+        assert_eq!(errors, 1);
+    }
+}
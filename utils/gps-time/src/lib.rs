@@ -0,0 +1,47 @@
+#![no_std]
+//! GPS time input library.
+//!
+//! GPS receivers output a PPS (pulse per second) signal, with an edge aligned to the beginning of
+//! each UTC second, and NMEA sentences describing which second it was. This library provides:
+//! * [`PpsLatch`] - storage for the system timestamp latched at the PPS edge, written from
+//!   the interrupt handler (ex. PIO edge interrupt),
+//! * [`nmea`] - parsing of NMEA sentences with UTC time,
+//! * [`system_time_offset`] - calculation of the offset that converts system time to UTC time,
+//!   which can be passed to the system time correction API.
+//!
+//! Timestamps are expected to come from the system timer (ex. `Hal::get_system_time()`), so they
+//! have microsecond accuracy.
+
+pub mod nmea;
+mod pps_latch;
+mod utc_time;
+
+pub use aerugo_hal::{Duration, Instant};
+pub use pps_latch::PpsLatch;
+pub use utc_time::UtcDateTime;
+
+/// Calculates offset between UTC time and time elapsed since the system start.
+///
+/// Adding returned offset to the time elapsed since the system start gives UTC time since Unix
+/// epoch. Timestamps of the PPS edge and of the current moment have to come from the same timer.
+///
+/// # Parameters
+/// * `utc` - UTC time of the PPS edge.
+/// * `pps_timestamp` - Timer timestamp latched at the PPS edge.
+/// * `now` - Current timer timestamp.
+/// * `elapsed_now` - Current time elapsed since the system start (ex. `RuntimeApi::get_elapsed_time`).
+///
+/// # Return
+/// Time offset, or `None` if timestamps are inconsistent (PPS edge is in the future, or before
+/// the system start) or UTC time is before the system start.
+pub fn system_time_offset(
+    utc: &UtcDateTime,
+    pps_timestamp: Instant,
+    now: Instant,
+    elapsed_now: Duration,
+) -> Option<Duration> {
+    let pps_age = now.checked_duration_since(pps_timestamp)?;
+    let elapsed_at_pps = elapsed_now.checked_sub(pps_age)?;
+
+    utc.since_unix_epoch().checked_sub(elapsed_at_pps)
+}
@@ -0,0 +1,190 @@
+//! NMEA 0183 sentence parsing.
+//!
+//! Only sentences carrying UTC time are supported: RMC (date and time, with fix status) and ZDA
+//! (date and time). Sentences from any talker (ex. `GP`, `GN`) are accepted.
+//!
+//! Most receivers send those sentences right after the PPS edge, with time of that edge. Check
+//! receiver's documentation to make sure that's the case.
+
+use crate::UtcDateTime;
+
+/// NMEA parsing error.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum NmeaError {
+    /// Sentence is malformed.
+    InvalidFormat,
+    /// Sentence checksum doesn't match its content.
+    InvalidChecksum,
+    /// Sentence type is not supported.
+    UnsupportedSentence,
+    /// Receiver doesn't have a valid fix, so the time is not reliable.
+    NoFix,
+}
+
+/// Parses NMEA sentence with UTC time.
+///
+/// # Parameters
+/// * `sentence` - Sentence, starting with `$` and ending with checksum. Trailing `\r\n` is allowed.
+///
+/// # Return
+/// UTC date and time from the sentence, or [`NmeaError`] if it can't be parsed.
+pub fn parse_time(sentence: &str) -> Result<UtcDateTime, NmeaError> {
+    let data = verify_checksum(sentence)?;
+    let mut fields = data.split(',');
+
+    let address = fields.next().ok_or(NmeaError::InvalidFormat)?;
+    let sentence_type = address.get(2..).ok_or(NmeaError::InvalidFormat)?;
+
+    match sentence_type {
+        "RMC" => parse_rmc(fields),
+        "ZDA" => parse_zda(fields),
+        _ => Err(NmeaError::UnsupportedSentence),
+    }
+}
+
+/// Verifies checksum of the sentence.
+///
+/// # Parameters
+/// * `sentence` - Sentence, starting with `$` and ending with checksum. Trailing `\r\n` is allowed.
+///
+/// # Return
+/// Sentence data (between `$` and `*`), or [`NmeaError`] if checksum is invalid.
+pub fn verify_checksum(sentence: &str) -> Result<&str, NmeaError> {
+    let sentence = sentence.trim_end_matches(['\r', '\n']);
+    let sentence = sentence.strip_prefix('$').ok_or(NmeaError::InvalidFormat)?;
+    let (data, checksum) = sentence.split_once('*').ok_or(NmeaError::InvalidFormat)?;
+
+    let expected = u8::from_str_radix(checksum, 16).map_err(|_| NmeaError::InvalidFormat)?;
+    let calculated = data.bytes().fold(0, |checksum, byte| checksum ^ byte);
+
+    if calculated == expected {
+        Ok(data)
+    } else {
+        Err(NmeaError::InvalidChecksum)
+    }
+}
+
+/// Parses RMC sentence fields (after the address field).
+fn parse_rmc<'a>(mut fields: impl Iterator<Item = &'a str>) -> Result<UtcDateTime, NmeaError> {
+    let time = fields.next().ok_or(NmeaError::InvalidFormat)?;
+    let status = fields.next().ok_or(NmeaError::InvalidFormat)?;
+    // Skip latitude, longitude, speed and course.
+    let date = fields.nth(6).ok_or(NmeaError::InvalidFormat)?;
+
+    if status != "A" {
+        return Err(NmeaError::NoFix);
+    }
+
+    let (hour, minute, second) = parse_hhmmss(time)?;
+    let day = parse_number(date.get(0..2))?;
+    let month = parse_number(date.get(2..4))?;
+    // Two-digit years are assumed to be in the 21st century.
+    let year = 2000 + parse_number::<u16>(date.get(4..6))?;
+
+    validate(UtcDateTime {
+        year,
+        month,
+        day,
+        hour,
+        minute,
+        second,
+    })
+}
+
+/// Parses ZDA sentence fields (after the address field).
+fn parse_zda<'a>(mut fields: impl Iterator<Item = &'a str>) -> Result<UtcDateTime, NmeaError> {
+    let time = fields.next().ok_or(NmeaError::InvalidFormat)?;
+    let day = parse_number(fields.next())?;
+    let month = parse_number(fields.next())?;
+    let year = parse_number(fields.next())?;
+
+    let (hour, minute, second) = parse_hhmmss(time)?;
+
+    validate(UtcDateTime {
+        year,
+        month,
+        day,
+        hour,
+        minute,
+        second,
+    })
+}
+
+/// Parses time in `hhmmss.ss` format, ignoring fraction of the second.
+fn parse_hhmmss(time: &str) -> Result<(u8, u8, u8), NmeaError> {
+    let hour = parse_number(time.get(0..2))?;
+    let minute = parse_number(time.get(2..4))?;
+    let second = parse_number(time.get(4..6))?;
+
+    Ok((hour, minute, second))
+}
+
+/// Parses decimal number from the field.
+fn parse_number<T: core::str::FromStr>(field: Option<&str>) -> Result<T, NmeaError> {
+    field
+        .and_then(|field| field.parse().ok())
+        .ok_or(NmeaError::InvalidFormat)
+}
+
+/// Checks if date and time values are in range.
+fn validate(time: UtcDateTime) -> Result<UtcDateTime, NmeaError> {
+    let is_valid = time.year >= 1970
+        && (1..=12).contains(&time.month)
+        && (1..=31).contains(&time.day)
+        && time.hour < 24
+        && time.minute < 60
+        && time.second <= 60;
+
+    if is_valid {
+        Ok(time)
+    } else {
+        Err(NmeaError::InvalidFormat)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_rmc_sentence() {
+        let time = parse_time(
+            "$GPRMC,123519.00,A,4807.038,N,01131.000,E,022.4,084.4,230323,003.1,W*48\r\n",
+        );
+
+        assert_eq!(
+            time,
+            Ok(UtcDateTime {
+                year: 2023,
+                month: 3,
+                day: 23,
+                hour: 12,
+                minute: 35,
+                second: 19,
+            })
+        );
+    }
+
+    #[test]
+    fn parse_zda_sentence() {
+        let time = parse_time("$GPZDA,201530.00,04,07,2002,00,00*60").unwrap();
+
+        assert_eq!(time.since_unix_epoch().to_secs(), 1_025_813_730);
+    }
+
+    #[test]
+    fn reject_invalid_sentences() {
+        assert_eq!(
+            parse_time("$GPZDA,201530.00,04,07,2002,00,00*61"),
+            Err(NmeaError::InvalidChecksum)
+        );
+        assert_eq!(
+            parse_time("$GPRMC,123519,V,,,,,,,230323,,,N*5D"),
+            Err(NmeaError::NoFix)
+        );
+        assert_eq!(
+            parse_time("$GPGGA,123519*77"),
+            Err(NmeaError::UnsupportedSentence)
+        );
+    }
+}
@@ -0,0 +1,62 @@
+//! PPS timestamp latch.
+
+use core::cell::Cell;
+
+use critical_section::Mutex;
+
+use crate::Instant;
+
+/// Latch storing system timestamp of the last PPS edge.
+///
+/// Latch should be a static variable, written from the PPS interrupt handler and read from
+/// the tasklet that processes NMEA sentences.
+pub struct PpsLatch {
+    /// Timestamp of the last PPS edge that wasn't taken yet.
+    timestamp: Mutex<Cell<Option<Instant>>>,
+    /// Number of latched PPS edges.
+    edge_count: Mutex<Cell<u32>>,
+}
+
+impl PpsLatch {
+    /// Creates new, empty latch.
+    pub const fn new() -> Self {
+        PpsLatch {
+            timestamp: Mutex::new(Cell::new(None)),
+            edge_count: Mutex::new(Cell::new(0)),
+        }
+    }
+
+    /// Latches timestamp of the PPS edge.
+    ///
+    /// This should be called in the PPS interrupt handler, as early as possible.
+    ///
+    /// # Parameters
+    /// * `timestamp` - Current system timer timestamp.
+    pub fn latch(&self, timestamp: Instant) {
+        critical_section::with(|cs| {
+            self.timestamp.borrow(cs).set(Some(timestamp));
+
+            let edge_count = self.edge_count.borrow(cs);
+            edge_count.set(edge_count.get().wrapping_add(1));
+        });
+    }
+
+    /// Takes the latched timestamp, leaving the latch empty.
+    ///
+    /// # Return
+    /// Timestamp of the last PPS edge, or `None` if no edge was latched since the last call.
+    pub fn take(&self) -> Option<Instant> {
+        critical_section::with(|cs| self.timestamp.borrow(cs).take())
+    }
+
+    /// Returns number of latched PPS edges.
+    pub fn edge_count(&self) -> u32 {
+        critical_section::with(|cs| self.edge_count.borrow(cs).get())
+    }
+}
+
+impl Default for PpsLatch {
+    fn default() -> Self {
+        Self::new()
+    }
+}
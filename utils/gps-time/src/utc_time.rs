@@ -0,0 +1,57 @@
+//! UTC date and time.
+
+use crate::Duration;
+
+/// UTC date and time with one second resolution.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct UtcDateTime {
+    /// Year (ex. 2023).
+    pub year: u16,
+    /// Month, `1` to `12`.
+    pub month: u8,
+    /// Day of the month, `1` to `31`.
+    pub day: u8,
+    /// Hour, `0` to `23`.
+    pub hour: u8,
+    /// Minute, `0` to `59`.
+    pub minute: u8,
+    /// Second, `0` to `60` (leap second).
+    pub second: u8,
+}
+
+impl UtcDateTime {
+    /// Returns time elapsed since Unix epoch (1970-01-01 00:00:00 UTC).
+    ///
+    /// Leap seconds are not counted, as in Unix time.
+    pub fn since_unix_epoch(&self) -> Duration {
+        let seconds = days_since_unix_epoch(self.year, self.month, self.day) * 86_400
+            + self.hour as u64 * 3_600
+            + self.minute as u64 * 60
+            + self.second as u64;
+
+        Duration::secs(seconds)
+    }
+}
+
+/// Returns number of days between Unix epoch and given date.
+///
+/// Uses days-from-civil algorithm, valid for all dates after the epoch.
+///
+/// # Parameters
+/// * `year` - Year.
+/// * `month` - Month, `1` to `12`.
+/// * `day` - Day of the month.
+fn days_since_unix_epoch(year: u16, month: u8, day: u8) -> u64 {
+    // Years start in March, so the leap day is at the end of the year.
+    let year = if month <= 2 { year - 1 } else { year } as u64;
+    let month = month as u64;
+    let day = day as u64;
+
+    let era = year / 400;
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+
+    // 719468 is the number of days between 0000-03-01 and 1970-01-01.
+    era * 146_097 + day_of_era - 719_468
+}
@@ -0,0 +1,86 @@
+//! Derive macro publishing tasklet context fields as aerugo telemetry.
+//!
+//! `#[derive(AerugoTelemetry)]` implements `aerugo::Telemetry` for a struct, reporting the current
+//! value of each field marked with `#[telemetry]` attribute under the name of that field. Marked
+//! fields have to be `Copy` and convertible into `aerugo::TelemetryValue` (booleans, integers and
+//! floating point numbers).
+//!
+//! ```ignore
+//! #[derive(AerugoTelemetry)]
+//! struct ThermalControlContext {
+//!     #[telemetry]
+//!     temperature: f32,
+//!     #[telemetry]
+//!     heater_on: bool,
+//!     history: [f32; 16],
+//! }
+//! ```
+
+extern crate proc_macro;
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{parse2, Data, DeriveInput, Error, Fields};
+
+#[proc_macro_derive(AerugoTelemetry, attributes(telemetry))]
+pub fn derive_aerugo_telemetry(item: TokenStream) -> TokenStream {
+    derive_aerugo_telemetry_impl(item.into())
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+pub(crate) fn derive_aerugo_telemetry_impl(item: TokenStream2) -> Result<TokenStream2, Error> {
+    let input: DeriveInput = parse2(item)?;
+    let name = &input.ident;
+    let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
+
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            _ => {
+                return Err(Error::new_spanned(
+                    &input,
+                    "AerugoTelemetry supports only structs with named fields",
+                ))
+            }
+        },
+        _ => {
+            return Err(Error::new_spanned(
+                &input,
+                "AerugoTelemetry can only be derived for structs",
+            ))
+        }
+    };
+
+    let mut samples = Vec::new();
+
+    for field in fields {
+        let Some(attribute) = field
+            .attrs
+            .iter()
+            .find(|attribute| attribute.path().is_ident("telemetry"))
+        else {
+            continue;
+        };
+
+        attribute.meta.require_path_only()?;
+
+        let ident = field
+            .ident
+            .as_ref()
+            .expect("named field without identifier");
+        let field_name = ident.to_string();
+        samples.push(quote! {
+            report(#field_name, ::aerugo::TelemetryValue::from(self.#ident));
+        });
+    }
+
+    Ok(quote! {
+        impl #impl_generics ::aerugo::Telemetry for #name #type_generics #where_clause {
+            fn sample(&self, report: &mut dyn FnMut(&'static str, ::aerugo::TelemetryValue)) {
+                #(#samples)*
+            }
+        }
+    })
+}